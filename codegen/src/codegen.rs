@@ -2,7 +2,8 @@
 
 use crate::lexicon::*;
 use heck::{ToSnakeCase, ToPascalCase};
-use std::collections::HashMap;
+use indexmap::IndexMap;
+use std::collections::{HashMap, HashSet};
 
 pub struct CodeGenerator {
     /// Map of NSID to lexicon doc
@@ -133,6 +134,7 @@ impl CodeGenerator {
             match def_value {
                 LexiconDef::Object { description, required, properties } => {
                     code.push_str(&self.generate_object_type_from_fields(
+                        doc,
                         type_name,
                         description.as_deref(),
                         required,
@@ -203,7 +205,8 @@ impl CodeGenerator {
 
         // Check if we need serde (params or output with schema)
         let needs_serde = parameters.is_some() ||
-            (output.is_some() && output.as_ref().unwrap().schema.is_some());
+            (output.is_some() && output.as_ref().unwrap().schema.is_some()) ||
+            Self::has_secondary_objects(doc);
 
         // Imports
         code.push_str("use crate::xrpc::{XrpcClient, XrpcRequest, XrpcResponse, XrpcError};\n");
@@ -218,15 +221,17 @@ impl CodeGenerator {
             code.push_str("#[derive(Debug, Clone, Serialize, Deserialize)]\n");
             code.push_str("pub struct QueryParams {\n");
 
+            let mut unions = String::new();
             for (name, prop) in &params.properties {
                 let is_required = params.required.contains(name);
-                let rust_type = prop.rust_type();
+                let rust_type = self.property_type(doc, "QueryParams", name, prop, &mut unions);
                 let description = self.get_property_description(prop);
 
                 self.generate_field(&mut code, name, &rust_type, is_required, description);
             }
 
             code.push_str("}\n\n");
+            code.push_str(&unions);
         }
 
         // Generate Output if it exists
@@ -236,18 +241,23 @@ impl CodeGenerator {
                 code.push_str("#[derive(Debug, Clone, Serialize, Deserialize)]\n");
                 code.push_str("pub struct Output {\n");
 
+                let mut unions = String::new();
                 for (name, prop) in &schema.properties {
                     let is_required = schema.required.contains(name);
-                    let rust_type = prop.rust_type();
+                    let rust_type = self.property_type(doc, "Output", name, prop, &mut unions);
                     let description = self.get_property_description(prop);
 
                     self.generate_field(&mut code, name, &rust_type, is_required, description);
                 }
 
                 code.push_str("}\n\n");
+                code.push_str("impl crate::xrpc::JsonBody for Output {}\n\n");
+                code.push_str(&unions);
             }
         }
 
+        code.push_str(&self.generate_secondary_objects(doc)?);
+
         // Generate error types
         for error in errors {
             let error_name = error.name.to_pascal_case();
@@ -286,21 +296,21 @@ impl CodeGenerator {
         code.push_str("(\n");
         code.push_str("    client: &impl XrpcClient,\n");
 
-        if parameters.is_some() {
+        // Lexicons may declare params without any properties
+        let adds_params = parameters.as_ref().is_some_and(|p| !p.properties.is_empty());
+        if adds_params {
             code.push_str("    params: QueryParams,\n");
+        } else if parameters.is_some() {
+            code.push_str("    _params: QueryParams,\n");
         }
 
-        // Use () if there's no output
-        let has_output = output.as_ref().and_then(|o| o.schema.as_ref()).is_some();
-        if has_output {
-            code.push_str(") -> Result<XrpcResponse<Output>, XrpcError> {\n");
-        } else {
-            code.push_str(") -> Result<XrpcResponse<()>, XrpcError> {\n");
-        }
+        code.push_str(") -> Result<XrpcResponse<");
+        code.push_str(&Self::body_type("Output", output.as_ref().map(|o| o.schema.is_some())));
+        code.push_str(">, XrpcError> {\n");
 
         // Build the request
         // Only make it mutable if we have parameters to add
-        if parameters.is_some() {
+        if adds_params {
             code.push_str("    let mut req = XrpcRequest::query(\"");
         } else {
             code.push_str("    let req = XrpcRequest::query(\"");
@@ -308,24 +318,15 @@ impl CodeGenerator {
         code.push_str(&doc.id);
         code.push_str("\");\n\n");
 
-        if parameters.is_some() {
-            code.push_str("    // Add query parameters\n");
-            code.push_str("    let params_json = serde_json::to_value(&params)\n");
-            code.push_str("        .map_err(XrpcError::Serialization)?;\n\n");
-            code.push_str("    if let Some(obj) = params_json.as_object() {\n");
-            code.push_str("        for (key, value) in obj {\n");
-            code.push_str("            if let Some(s) = value.as_str() {\n");
-            code.push_str("                req.params.insert(key.clone(), s.to_string());\n");
-            code.push_str("            } else {\n");
-            code.push_str("                req.params.insert(key.clone(), value.to_string());\n");
-            code.push_str("            }\n");
-            code.push_str("        }\n");
-            code.push_str("    }\n\n");
+        if let Some(params) = parameters.as_ref().filter(|_| adds_params) {
+            self.generate_param_serialization(&mut code, params);
         }
 
-        code.push_str("    client.request(req).await\n");
+        code.push_str("    client.request_body(req).await\n");
         code.push_str("}\n");
 
+        self.generate_endpoint(&mut code, doc, "Query", parameters.is_some(), None, output.as_ref());
+
         Ok(code)
     }
 
@@ -357,7 +358,8 @@ impl CodeGenerator {
 
         // Check if we need serde (input or output with schema)
         let needs_serde = (input.is_some() && input.as_ref().unwrap().schema.is_some()) ||
-            (output.is_some() && output.as_ref().unwrap().schema.is_some());
+            (output.is_some() && output.as_ref().unwrap().schema.is_some()) ||
+            Self::has_secondary_objects(doc);
 
         // Imports
         code.push_str("use crate::xrpc::{XrpcClient, XrpcRequest, XrpcResponse, XrpcError};\n");
@@ -373,15 +375,18 @@ impl CodeGenerator {
                 code.push_str("#[derive(Debug, Clone, Serialize, Deserialize)]\n");
                 code.push_str("pub struct Input {\n");
 
+                let mut unions = String::new();
                 for (name, prop) in &schema.properties {
                     let is_required = schema.required.contains(name);
-                    let rust_type = prop.rust_type();
+                    let rust_type = self.property_type(doc, "Input", name, prop, &mut unions);
                     let description = self.get_property_description(prop);
 
                     self.generate_field(&mut code, name, &rust_type, is_required, description);
                 }
 
                 code.push_str("}\n\n");
+                code.push_str("impl crate::xrpc::JsonBody for Input {}\n\n");
+                code.push_str(&unions);
             } else {
                 // Binary input (e.g., video/mp4)
                 code.push_str("/// Request input (binary data)\n");
@@ -396,18 +401,23 @@ impl CodeGenerator {
                 code.push_str("#[derive(Debug, Clone, Serialize, Deserialize)]\n");
                 code.push_str("pub struct Output {\n");
 
+                let mut unions = String::new();
                 for (name, prop) in &schema.properties {
                     let is_required = schema.required.contains(name);
-                    let rust_type = prop.rust_type();
+                    let rust_type = self.property_type(doc, "Output", name, prop, &mut unions);
                     let description = self.get_property_description(prop);
 
                     self.generate_field(&mut code, name, &rust_type, is_required, description);
                 }
 
                 code.push_str("}\n\n");
+                code.push_str("impl crate::xrpc::JsonBody for Output {}\n\n");
+                code.push_str(&unions);
             }
         }
 
+        code.push_str(&self.generate_secondary_objects(doc)?);
+
         // Generate the function
         let method_name = doc.method_name().to_snake_case();
 
@@ -424,35 +434,130 @@ impl CodeGenerator {
         code.push_str("(\n");
         code.push_str("    client: &impl XrpcClient,\n");
 
-        if input.is_some() {
+        if let Some(inp) = input {
             code.push_str("    input: Input,\n");
+            // Wildcard encodings (e.g. `*/*` for blobs) leave the type to the caller
+            if inp.schema.is_none() && inp.encoding.contains('*') {
+                code.push_str("    content_type: &str,\n");
+            }
         }
 
-        // Use () if there's no output
-        let has_output = output.as_ref().and_then(|o| o.schema.as_ref()).is_some();
-        if has_output {
-            code.push_str(") -> Result<XrpcResponse<Output>, XrpcError> {\n");
-        } else {
-            code.push_str(") -> Result<XrpcResponse<()>, XrpcError> {\n");
-        }
+        code.push_str(") -> Result<XrpcResponse<");
+        code.push_str(&Self::body_type("Output", output.as_ref().map(|o| o.schema.is_some())));
+        code.push_str(">, XrpcError> {\n");
 
         // Build the request
         code.push_str("    let req = XrpcRequest::procedure(\"");
         code.push_str(&doc.id);
         code.push_str("\")");
 
-        if input.is_some() {
-            code.push_str(".data(&input)?");
+        match input {
+            Some(inp) if inp.schema.is_some() => code.push_str(".data(&input)?"),
+            Some(inp) if inp.encoding.contains('*') => code.push_str(".binary(input, content_type)"),
+            Some(inp) => {
+                code.push_str(".binary(input, \"");
+                code.push_str(&inp.encoding);
+                code.push_str("\")");
+            }
+            None => {}
         }
 
         code.push_str(";\n\n");
 
-        code.push_str("    client.request(req).await\n");
+        code.push_str("    client.request_body(req).await\n");
         code.push_str("}\n");
 
+        self.generate_endpoint(&mut code, doc, "Procedure", false, input.as_ref(), output.as_ref());
+
         Ok(code)
     }
 
+    /// Generate the code copying `params` into `req.params`
+    ///
+    /// Params are strings, integers, booleans or arrays of those, all of which
+    /// format with `Display`. Arrays become one `key=value` pair per item.
+    fn generate_param_serialization(&self, code: &mut String, params: &LexiconParams) {
+        code.push_str("    // Add query parameters\n");
+
+        for (name, prop) in &params.properties {
+            let field = format!("params.{}", Self::escape_keyword(&name.to_snake_case()));
+            let is_required = params.required.contains(name);
+            let append = format!("req.params.append(\"{}\", value.to_string());", name);
+
+            match (prop, is_required) {
+                (LexiconProperty::Array { .. }, true) => {
+                    code.push_str(&format!("    for value in &{} {{\n        {}\n    }}\n", field, append));
+                }
+                (LexiconProperty::Array { .. }, false) => {
+                    code.push_str(&format!("    for value in {}.iter().flatten() {{\n        {}\n    }}\n", field, append));
+                }
+                (_, true) => {
+                    code.push_str(&format!("    req.params.append(\"{}\", {}.to_string());\n", name, field));
+                }
+                (_, false) => {
+                    code.push_str(&format!("    if let Some(value) = &{} {{\n        {}\n    }}\n", field, append));
+                }
+            }
+        }
+
+        code.push_str("\n");
+    }
+
+    /// Rust type of a request or response body
+    ///
+    /// Bodies without a schema are binary (`Vec<u8>`), missing ones are `()`.
+    fn body_type(name: &str, has_schema: Option<bool>) -> String {
+        match has_schema {
+            Some(true) => name.to_string(),
+            Some(false) => "Vec<u8>".to_string(),
+            None => "()".to_string(),
+        }
+    }
+
+    /// Generate the server-side `Endpoint` definition for a query or procedure
+    fn generate_endpoint(
+        &self,
+        code: &mut String,
+        doc: &LexiconDoc,
+        method: &str,
+        has_params: bool,
+        input: Option<&LexiconInput>,
+        output: Option<&LexiconOutput>,
+    ) {
+        let encoding = |encoding: Option<&String>| match encoding {
+            Some(encoding) => format!("Some(\"{}\")", encoding),
+            None => "None".to_string(),
+        };
+
+        code.push_str("\n/// Server-side definition of ");
+        code.push_str(&doc.id);
+        code.push_str("\n");
+        code.push_str("pub struct Endpoint;\n\n");
+        code.push_str("impl crate::xrpc::XrpcEndpoint for Endpoint {\n");
+        code.push_str("    const NSID: &'static str = \"");
+        code.push_str(&doc.id);
+        code.push_str("\";\n");
+        code.push_str("    const METHOD: crate::xrpc::XrpcMethod = crate::xrpc::XrpcMethod::");
+        code.push_str(method);
+        code.push_str(";\n");
+        code.push_str("    const INPUT_ENCODING: Option<&'static str> = ");
+        code.push_str(&encoding(input.map(|i| &i.encoding)));
+        code.push_str(";\n");
+        code.push_str("    const OUTPUT_ENCODING: Option<&'static str> = ");
+        code.push_str(&encoding(output.map(|o| &o.encoding)));
+        code.push_str(";\n");
+        code.push_str("    type Params = ");
+        code.push_str(if has_params { "QueryParams" } else { "()" });
+        code.push_str(";\n");
+        code.push_str("    type Input = ");
+        code.push_str(&Self::body_type("Input", input.map(|i| i.schema.is_some())));
+        code.push_str(";\n");
+        code.push_str("    type Output = ");
+        code.push_str(&Self::body_type("Output", output.as_ref().map(|o| o.schema.is_some())));
+        code.push_str(";\n");
+        code.push_str("}\n");
+    }
+
     /// Generate code for a subscription endpoint
     fn generate_subscription(&self, doc: &LexiconDoc, def: &LexiconDef) -> Result<String, String> {
         let LexiconDef::Subscription {
@@ -479,7 +584,7 @@ impl CodeGenerator {
         code.push_str("\n");
 
         // Imports
-        code.push_str("use crate::xrpc::{XrpcRequest, XrpcError};\n");
+        code.push_str("use crate::xrpc::XrpcRequest;\n");
         code.push_str("use crate::xrpc_subscription::{SubscriptionClient, SubscriptionEvent, SubscriptionResult};\n");
         code.push_str("use serde::{Deserialize, Serialize};\n");
         code.push_str("use futures::stream::Stream;\n");
@@ -491,15 +596,17 @@ impl CodeGenerator {
             code.push_str("#[derive(Debug, Clone, Serialize, Deserialize)]\n");
             code.push_str("pub struct QueryParams {\n");
 
+            let mut unions = String::new();
             for (name, prop) in &params.properties {
                 let is_required = params.required.contains(name);
-                let rust_type = prop.rust_type();
+                let rust_type = self.property_type(doc, "QueryParams", name, prop, &mut unions);
                 let description = self.get_property_description(prop);
 
                 self.generate_field(&mut code, name, &rust_type, is_required, description);
             }
 
             code.push_str("}\n\n");
+            code.push_str(&unions);
         }
 
         // Generate the subscription function
@@ -516,15 +623,19 @@ impl CodeGenerator {
         code.push_str("(\n");
         code.push_str("    client: &SubscriptionClient,\n");
 
-        if parameters.is_some() {
+        // Lexicons may declare params without any properties
+        let adds_params = parameters.as_ref().is_some_and(|p| !p.properties.is_empty());
+        if adds_params {
             code.push_str("    params: QueryParams,\n");
+        } else if parameters.is_some() {
+            code.push_str("    _params: QueryParams,\n");
         }
 
         code.push_str(") -> SubscriptionResult<Pin<Box<dyn Stream<Item = SubscriptionResult<SubscriptionEvent>> + Send>>> {\n");
 
         // Build the request
         // Only make it mutable if we have parameters to add
-        if parameters.is_some() {
+        if adds_params {
             code.push_str("    let mut req = XrpcRequest::query(\"");
         } else {
             code.push_str("    let req = XrpcRequest::query(\"");
@@ -533,20 +644,8 @@ impl CodeGenerator {
         code.push_str("\");\n\n");
 
         // Add parameters if they exist
-        if parameters.is_some() {
-            code.push_str("    // Add query parameters\n");
-            code.push_str("    let params_json = serde_json::to_value(&params)\n");
-            code.push_str("        .map_err(XrpcError::Serialization)?;\n\n");
-
-            code.push_str("    if let Some(obj) = params_json.as_object() {\n");
-            code.push_str("        for (key, value) in obj {\n");
-            code.push_str("            if let Some(s) = value.as_str() {\n");
-            code.push_str("                req.params.insert(key.clone(), s.to_string());\n");
-            code.push_str("            } else {\n");
-            code.push_str("                req.params.insert(key.clone(), value.to_string());\n");
-            code.push_str("            }\n");
-            code.push_str("        }\n");
-            code.push_str("    }\n\n");
+        if let Some(params) = parameters.as_ref().filter(|_| adds_params) {
+            self.generate_param_serialization(&mut code, params);
         }
 
         code.push_str("    client.subscribe(req).await\n");
@@ -591,12 +690,15 @@ impl CodeGenerator {
             code.push_str("\n");
         }
 
+        let record_name = Self::rename_conflicting_type(&record_name);
+
         code.push_str("#[derive(Debug, Clone, Serialize, Deserialize)]\n");
         code.push_str("pub struct ");
-        code.push_str(&Self::rename_conflicting_type(&record_name));
+        code.push_str(&record_name);
         code.push_str(" {\n");
 
         // Generate fields
+        let mut unions = String::new();
         for (name, prop) in &record.properties {
             if let Some(desc) = self.get_property_description(prop) {
                 code.push_str("    /// ");
@@ -605,7 +707,7 @@ impl CodeGenerator {
             }
 
             let is_required = record.required.contains(name);
-            let rust_type = prop.rust_type();
+            let rust_type = self.property_type(doc, &record_name, name, prop, &mut unions);
 
             // Handle serde rename for camelCase fields
             if name.contains(char::is_uppercase) || name.contains('_') {
@@ -635,6 +737,7 @@ impl CodeGenerator {
         }
 
         code.push_str("}\n\n");
+        code.push_str(&unions);
 
         // Generate any additional object types defined in defs
         for (def_name, def_value) in &doc.defs {
@@ -644,6 +747,7 @@ impl CodeGenerator {
 
             if let LexiconDef::Object { description, required, properties } = def_value {
                 code.push_str(&self.generate_object_type_from_fields(
+                    doc,
                     def_name,
                     description.as_deref(),
                     required,
@@ -657,8 +761,9 @@ impl CodeGenerator {
     }
 
     /// Generate a standalone object type
-    fn generate_object_type(&self, name: &str, obj: &LexiconObject) -> Result<String, String> {
+    fn generate_object_type(&self, doc: &LexiconDoc, name: &str, obj: &LexiconObject) -> Result<String, String> {
         self.generate_object_type_from_fields(
+            doc,
             name,
             None,
             &obj.required,
@@ -669,14 +774,15 @@ impl CodeGenerator {
     /// Generate a standalone object type from individual fields
     fn generate_object_type_from_fields(
         &self,
+        doc: &LexiconDoc,
         name: &str,
         description: Option<&str>,
         required: &[String],
-        properties: &HashMap<String, LexiconProperty>
+        properties: &IndexMap<String, LexiconProperty>
     ) -> Result<String, String> {
         let mut code = String::new();
 
-        let type_name = name.to_pascal_case();
+        let type_name = Self::rename_conflicting_type(&name.to_pascal_case());
 
         if let Some(desc) = description {
             code.push_str("/// ");
@@ -686,9 +792,10 @@ impl CodeGenerator {
 
         code.push_str("#[derive(Debug, Clone, Serialize, Deserialize)]\n");
         code.push_str("pub struct ");
-        code.push_str(&Self::rename_conflicting_type(&type_name));
+        code.push_str(&type_name);
         code.push_str(" {\n");
 
+        let mut unions = String::new();
         for (prop_name, prop) in properties {
             if let Some(desc) = self.get_property_description(prop) {
                 code.push_str("    /// ");
@@ -697,16 +804,253 @@ impl CodeGenerator {
             }
 
             let is_required = required.contains(prop_name);
-            let rust_type = prop.rust_type();
+            let rust_type = self.property_type(doc, &type_name, prop_name, prop, &mut unions);
 
             self.generate_field(&mut code, prop_name, &rust_type, is_required, description);
         }
 
         code.push_str("}\n");
 
+        if !unions.is_empty() {
+            code.push_str("\n");
+            code.push_str(unions.trim_end());
+            code.push_str("\n");
+        }
+
+        Ok(code)
+    }
+
+    /// Whether a query or procedure lexicon defines object types besides `main`
+    fn has_secondary_objects(doc: &LexiconDoc) -> bool {
+        doc.defs
+            .iter()
+            .any(|(name, def)| name != "main" && matches!(def, LexiconDef::Object { .. }))
+    }
+
+    /// Generate the object types a query or procedure defines besides `main`
+    fn generate_secondary_objects(&self, doc: &LexiconDoc) -> Result<String, String> {
+        let mut code = String::new();
+
+        for (def_name, def_value) in &doc.defs {
+            if def_name == "main" {
+                continue;
+            }
+
+            if let LexiconDef::Object { description, required, properties } = def_value {
+                code.push_str(&self.generate_object_type_from_fields(
+                    doc,
+                    def_name,
+                    description.as_deref(),
+                    required,
+                    properties
+                )?);
+                code.push_str("\n");
+            }
+        }
+
         Ok(code)
     }
 
+    /// Get the Rust type of a property of the `owner` type
+    ///
+    /// Unions become an enum named after the owner and property (with a
+    /// `Union` suffix if that names another definition), whose definition is
+    /// appended to `unions`.
+    fn property_type(
+        &self,
+        doc: &LexiconDoc,
+        owner: &str,
+        name: &str,
+        prop: &LexiconProperty,
+        unions: &mut String,
+    ) -> String {
+        match prop {
+            LexiconProperty::Union { refs, closed, description, .. } => {
+                let mut enum_name = format!("{}{}", owner, name.to_pascal_case());
+                // e.g. the `record` of `app.bsky.embed.record#view` vs `#viewRecord`
+                if doc.defs.keys().any(|def| Self::rename_conflicting_type(&def.to_pascal_case()) == enum_name) {
+                    enum_name.push_str("Union");
+                }
+                unions.push_str(&self.generate_union(doc, &enum_name, refs, *closed, description.as_deref()));
+                unions.push_str("\n");
+                enum_name
+            }
+            LexiconProperty::Array { items, .. } => {
+                format!("Vec<{}>", self.property_type(doc, owner, name, items, unions))
+            }
+            _ => prop.rust_type(),
+        }
+    }
+
+    /// Generate an enum for a union, tagged by `$type`
+    ///
+    /// Open unions get an `Unknown` variant holding any other value as raw
+    /// JSON, and a hand-written `Deserialize` so listed types that fail to
+    /// parse are still errors. Closed unions reject types they don't list.
+    fn generate_union(
+        &self,
+        doc: &LexiconDoc,
+        name: &str,
+        refs: &[String],
+        closed: bool,
+        description: Option<&str>,
+    ) -> String {
+        let members: Vec<(String, String, Option<String>)> = refs
+            .iter()
+            .map(|reference| {
+                let (nsid, def) = reference.split_once('#').unwrap_or((reference, "main"));
+                let nsid = if nsid.is_empty() { doc.id.as_str() } else { nsid };
+                let type_id = if def == "main" {
+                    nsid.to_string()
+                } else {
+                    format!("{}#{}", nsid, def)
+                };
+                (type_id, Self::union_variant_name(doc, nsid, def), self.resolve_type(doc, nsid, def))
+            })
+            .collect();
+
+        // Fall back to fully qualified variant names when short ones collide
+        let mut seen = HashSet::new();
+        let qualified = !members.iter().all(|(_, variant, _)| seen.insert(variant.clone()));
+
+        let mut code = String::new();
+
+        if let Some(desc) = description {
+            code.push_str("/// ");
+            code.push_str(desc);
+            code.push_str("\n");
+        }
+
+        let members: Vec<(String, String, Option<String>)> = members
+            .into_iter()
+            .map(|(type_id, variant, rust_type)| {
+                let variant = if qualified {
+                    type_id.replace(['.', '#'], "_").to_pascal_case()
+                } else {
+                    variant
+                };
+                (type_id, variant, rust_type)
+            })
+            .collect();
+
+        if closed {
+            code.push_str("#[derive(Debug, Clone, Serialize, Deserialize)]\n");
+        } else {
+            code.push_str("#[derive(Debug, Clone, Serialize)]\n");
+        }
+        code.push_str("#[serde(tag = \"$type\")]\n");
+        code.push_str("pub enum ");
+        code.push_str(name);
+        code.push_str(" {\n");
+
+        for (type_id, variant, rust_type) in &members {
+            code.push_str("    #[serde(rename = \"");
+            code.push_str(type_id);
+            code.push_str("\")]\n");
+            code.push_str("    ");
+            code.push_str(&variant);
+            code.push_str("(");
+            match rust_type {
+                Some(rust_type) => {
+                    code.push_str("Box<");
+                    code.push_str(rust_type);
+                    code.push_str(">");
+                }
+                None => code.push_str("serde_json::Value"),
+            }
+            code.push_str("),\n");
+        }
+
+        if !closed {
+            code.push_str("    /// A type not listed in the lexicon, kept as raw JSON\n");
+            code.push_str("    #[serde(untagged)]\n");
+            code.push_str("    Unknown(serde_json::Value),\n");
+        }
+
+        code.push_str("}\n");
+
+        if !closed {
+            code.push_str(&Self::generate_open_union_deserialize(name, &members));
+        }
+
+        code
+    }
+
+    /// `Deserialize` for an open union
+    ///
+    /// Dispatches on `$type` so a listed type with a bad payload is an error
+    /// rather than silently becoming `Unknown`.
+    fn generate_open_union_deserialize(name: &str, members: &[(String, String, Option<String>)]) -> String {
+        let mut code = String::new();
+
+        code.push_str("\nimpl<'de> Deserialize<'de> for ");
+        code.push_str(name);
+        code.push_str(" {\n");
+        code.push_str("    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> std::result::Result<Self, D::Error> {\n");
+        code.push_str("        let value = serde_json::Value::deserialize(deserializer)?;\n");
+        if members.is_empty() {
+            code.push_str("        Ok(Self::Unknown(value))\n");
+        } else {
+            code.push_str("        match value.get(\"$type\").and_then(|t| t.as_str()) {\n");
+            for (type_id, variant, _) in members {
+                code.push_str("            Some(\"");
+                code.push_str(type_id);
+                code.push_str("\") => serde_json::from_value(value).map(Self::");
+                code.push_str(variant);
+                code.push_str(").map_err(serde::de::Error::custom),\n");
+            }
+            code.push_str("            _ => Ok(Self::Unknown(value)),\n");
+            code.push_str("        }\n");
+        }
+        code.push_str("    }\n");
+        code.push_str("}\n");
+
+        code
+    }
+
+    /// Variant name for a union member
+    ///
+    /// Local refs and refs into `defs` lexicons use the definition name;
+    /// others are prefixed with the last NSID segment, e.g.
+    /// `app.bsky.embed.images#view` -> `ImagesView`.
+    fn union_variant_name(doc: &LexiconDoc, nsid: &str, def: &str) -> String {
+        let name = nsid.split('.').last().unwrap_or(nsid);
+        if nsid == doc.id || name == "defs" {
+            if def == "main" { name.to_pascal_case() } else { def.to_pascal_case() }
+        } else if def == "main" {
+            name.to_pascal_case()
+        } else {
+            format!("{}{}", name.to_pascal_case(), def.to_pascal_case())
+        }
+    }
+
+    /// Resolve a definition to the path of its generated Rust type
+    ///
+    /// Returns `None` for definitions that don't generate a struct.
+    fn resolve_type(&self, doc: &LexiconDoc, nsid: &str, def: &str) -> Option<String> {
+        let target = self.lexicons.get(nsid)?;
+        let type_name = match (target.main_def(), target.defs.get(def)?) {
+            (Some(LexiconDef::Subscription { .. }), _) => return None,
+            (_, LexiconDef::Record { .. }) => Self::rename_conflicting_type(&target.method_name().to_pascal_case()),
+            (_, LexiconDef::Object { .. }) if def == "main" => "Main".to_string(),
+            (_, LexiconDef::Object { .. }) => Self::rename_conflicting_type(&def.to_pascal_case()),
+            _ => return None,
+        };
+
+        if nsid == doc.id {
+            return Some(type_name);
+        }
+
+        let parts: Vec<&str> = nsid.split('.').collect();
+        let (module, namespace) = parts.split_last()?;
+        Some(format!(
+            "crate::client::{}::{}::{}",
+            namespace.join("::"),
+            module.to_snake_case(),
+            type_name
+        ))
+    }
+
     /// Get description from a property
     fn get_property_description<'a>(&self, prop: &'a LexiconProperty) -> Option<&'a str> {
         match prop {
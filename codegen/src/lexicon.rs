@@ -4,7 +4,7 @@
 //! for parsing JSON lexicon files and generating Rust code.
 
 use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
+use indexmap::IndexMap;
 
 /// Top-level lexicon document
 #[derive(Debug, Clone, Deserialize, Serialize)]
@@ -16,7 +16,7 @@ pub struct LexiconDoc {
     pub id: String,
 
     /// Schema definitions
-    pub defs: HashMap<String, LexiconDef>,
+    pub defs: IndexMap<String, LexiconDef>,
 }
 
 /// A definition within a lexicon
@@ -76,7 +76,7 @@ pub enum LexiconDef {
         required: Vec<String>,
 
         #[serde(default)]
-        properties: HashMap<String, LexiconProperty>,
+        properties: IndexMap<String, LexiconProperty>,
     },
 
     /// Array type
@@ -148,7 +148,7 @@ pub struct LexiconParams {
     #[serde(default)]
     pub required: Vec<String>,
 
-    pub properties: HashMap<String, LexiconProperty>,
+    pub properties: IndexMap<String, LexiconProperty>,
 }
 
 /// Input for procedures
@@ -179,34 +179,16 @@ pub struct LexiconObject {
     pub required: Vec<String>,
 
     #[serde(default)]
-    pub properties: HashMap<String, LexiconProperty>,
+    pub properties: IndexMap<String, LexiconProperty>,
 }
 
 /// Property definition
+///
+/// Variants are tried in order, so the shapes with a distinguishing key
+/// (`items`, `ref`, `refs`) come before the catch-all simple type.
 #[derive(Debug, Clone, Deserialize, Serialize)]
 #[serde(untagged)]
 pub enum LexiconProperty {
-    /// Simple type
-    Simple {
-        #[serde(rename = "type")]
-        type_name: String,
-
-        #[serde(default)]
-        description: Option<String>,
-
-        #[serde(default)]
-        format: Option<String>,
-
-        #[serde(default)]
-        default: Option<serde_json::Value>,
-
-        #[serde(default)]
-        minimum: Option<i64>,
-
-        #[serde(default)]
-        maximum: Option<i64>,
-    },
-
     /// Array type
     Array {
         #[serde(rename = "type")]
@@ -245,9 +227,34 @@ pub enum LexiconProperty {
 
         refs: Vec<String>,
 
+        /// Closed unions only ever contain the listed types
+        #[serde(default)]
+        closed: bool,
+
         #[serde(default)]
         description: Option<String>,
     },
+
+    /// Simple type
+    Simple {
+        #[serde(rename = "type")]
+        type_name: String,
+
+        #[serde(default)]
+        description: Option<String>,
+
+        #[serde(default)]
+        format: Option<String>,
+
+        #[serde(default)]
+        default: Option<serde_json::Value>,
+
+        #[serde(default)]
+        minimum: Option<i64>,
+
+        #[serde(default)]
+        maximum: Option<i64>,
+    },
 }
 
 /// Error definition
@@ -293,9 +300,16 @@ impl LexiconProperty {
                         if let Some(fmt) = format {
                             match fmt.as_str() {
                                 "did" => "crate::types::Did".to_string(),
-                                "handle" => "String".to_string(),
+                                "handle" => "crate::types::Handle".to_string(),
                                 "at-uri" => "crate::syntax::AtUri".to_string(),
-                                "datetime" => "String".to_string(), // TODO: Use chrono
+                                "datetime" => "crate::types::Datetime".to_string(),
+                                "nsid" => "crate::types::Nsid".to_string(),
+                                "tid" => "crate::tid::Tid".to_string(),
+                                "record-key" => "crate::types::RecordKey".to_string(),
+                                "language" => "crate::types::Language".to_string(),
+                                "cid" => "crate::types::CidLink".to_string(),
+                                "uri" => "crate::types::Uri".to_string(),
+                                // at-identifier may be a handle or a DID
                                 _ => "String".to_string(),
                             }
                         } else {
@@ -310,26 +324,12 @@ impl LexiconProperty {
             LexiconProperty::Array { items, .. } => {
                 format!("Vec<{}>", items.rust_type())
             }
-            LexiconProperty::Ref { ref_path, .. } => {
-                // Convert ref path to Rust module path
-                // e.g., "app.bsky.feed.defs#postView" -> "app::bsky::feed::defs::PostView"
-                // e.g., "#replyRef" -> "ReplyRef" (local reference)
-                if ref_path.starts_with('#') {
-                    // Local reference within the same file
-                    heck::AsPascalCase(&ref_path[1..]).to_string()
-                } else if ref_path.contains('#') {
-                    let parts: Vec<&str> = ref_path.split('#').collect();
-                    let module = parts[0].replace('.', "::");
-                    let type_name = heck::AsPascalCase(parts[1]).to_string();
-                    format!("{}::{}", module, type_name)
-                } else {
-                    // Full module path reference
-                    ref_path.replace('.', "::")
-                }
+            LexiconProperty::Ref { .. } => {
+                // References are not resolved to generated types yet
+                "serde_json::Value".to_string()
             }
             LexiconProperty::Union { .. } => {
-                // For now, use serde_json::Value for unions
-                // In the future, we could generate proper enum types
+                // Unions need a generated enum, see `CodeGenerator::property_type`
                 "serde_json::Value".to_string()
             }
         }
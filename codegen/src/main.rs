@@ -89,6 +89,12 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
         }
     }
 
+    println!("\nBundling lexicon documents...\n");
+    generate_lexicon_bundle(&lexicons, &lexicon_files, output_dir)?;
+    module_tree.entry(output_dir.to_path_buf())
+        .or_insert_with(HashSet::new)
+        .insert("lexicons".to_string());
+
     println!("\nGenerating module files...\n");
     generate_mod_files(&module_tree)?;
 
@@ -154,6 +160,31 @@ fn track_module(module_tree: &mut HashMap<PathBuf, HashSet<String>>, nsid: &str,
         .insert(file_name);
 }
 
+/// Generate lexicons.rs, which embeds every lexicon JSON file for runtime validation
+fn generate_lexicon_bundle(lexicons: &[LexiconDoc], files: &[PathBuf], output_dir: &Path) -> Result<(), Box<dyn std::error::Error>> {
+    let mut entries: Vec<(&str, &PathBuf)> = lexicons.iter().map(|doc| doc.id.as_str()).zip(files).collect();
+    entries.sort();
+
+    let mut content = String::new();
+    content.push_str("//! Lexicon documents embedded at build time\n");
+    content.push_str("//!\n");
+    content.push_str("//! Auto-generated by the codegen tool. Loaded by `validation::LexiconRegistry::bundled`.\n\n");
+    content.push_str("/// JSON source of every bundled lexicon, sorted by NSID\n");
+    content.push_str("pub static LEXICONS: &[&str] = &[\n");
+    for (_, file) in entries {
+        // include_str! paths are relative to src/client/lexicons.rs
+        let path = Path::new("../../codegen").join(file);
+        content.push_str(&format!("    include_str!(\"{}\"),\n", path.display()));
+    }
+    content.push_str("];\n");
+
+    let bundle_path = output_dir.join("lexicons.rs");
+    fs::write(&bundle_path, content)?;
+    println!("  -> {}", bundle_path.display());
+
+    Ok(())
+}
+
 /// Generate mod.rs files for all directories
 fn generate_mod_files(module_tree: &HashMap<PathBuf, HashSet<String>>) -> Result<(), Box<dyn std::error::Error>> {
     for (dir_path, modules) in module_tree {
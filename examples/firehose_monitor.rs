@@ -94,6 +94,15 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
                 println!("\n🔌 Connection closed by server");
                 break;
             }
+            Ok(SubscriptionEvent::Reconnected { attempt, cursor }) => {
+                println!("🔁 Reconnected after {} attempt(s), resuming from {:?}", attempt, cursor);
+            }
+            Ok(SubscriptionEvent::ReconnectFailed { attempt, error }) => {
+                eprintln!("⚠️  Reconnect attempt {} failed: {}", attempt, error);
+            }
+            Ok(SubscriptionEvent::CheckpointFailed { cursor, error }) => {
+                eprintln!("⚠️  Failed to save cursor {}: {}", cursor, error);
+            }
             Err(e) => {
                 error_count += 1;
                 eprintln!("❌ Stream error: {}", e);
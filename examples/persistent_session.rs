@@ -82,7 +82,7 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
     let session_data = AtpSessionData {
         refresh_jwt: response.data.refresh_jwt,
         access_jwt: response.data.access_jwt,
-        handle: response.data.handle,
+        handle: response.data.handle.to_string(),
         did: response.data.did.to_string(),
         email: response.data.email,
         email_confirmed: response.data.email_confirmed,
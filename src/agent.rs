@@ -32,7 +32,7 @@ use tokio::sync::Mutex as AsyncMutex;
 use crate::consts::BSKY_LABELER_DID;
 use crate::namespaces::{AppNS, ChatNS, ComNS, ToolsNS};
 use crate::session_manager::SessionManager;
-use crate::types::{AtpSessionData, AtprotoProxy, AtprotoServiceType, Did};
+use crate::types::{AtpSessionData, AtprotoProxy, AtprotoServiceType, CidLink, Datetime, Did, Nsid};
 use crate::xrpc::{XrpcClient, XrpcRequest, XrpcResponse};
 
 /// Error types for Agent operations
@@ -49,6 +49,9 @@ pub enum AgentError {
 
     #[error("Session error: {0}")]
     SessionError(String),
+
+    #[error("Invalid record: {0}")]
+    InvalidRecord(#[from] crate::validation::ValidationError),
 }
 
 /// Parameters for replying to a post with a link embed
@@ -104,6 +107,9 @@ pub struct Agent {
 
     /// Mutex for atomic preference updates
     prefs_lock: Arc<AsyncMutex<()>>,
+
+    /// Validate records against their lexicons before writing them
+    strict_validation: Arc<RwLock<bool>>,
 }
 
 impl Agent {
@@ -139,6 +145,7 @@ impl Agent {
             proxy: Arc::new(RwLock::new(None)),
             headers: Arc::new(RwLock::new(HashMap::new())),
             prefs_lock: Arc::new(AsyncMutex::new(())),
+            strict_validation: Arc::new(RwLock::new(false)),
         }
     }
 
@@ -270,6 +277,33 @@ impl Agent {
         self.headers.read().unwrap().clone()
     }
 
+    /// Configure strict validation
+    ///
+    /// When enabled, records are validated against the bundled lexicons (see
+    /// [`LexiconRegistry::bundled`](crate::validation::LexiconRegistry::bundled))
+    /// before `createRecord` or `putRecord`, and invalid records fail with
+    /// [`AgentError::InvalidRecord`] without a request being sent.
+    ///
+    /// # Arguments
+    ///
+    /// * `enabled` - Whether to validate records
+    pub fn configure_strict_validation(&self, enabled: bool) {
+        *self.strict_validation.write().unwrap() = enabled;
+    }
+
+    /// Whether strict validation is enabled
+    pub fn strict_validation(&self) -> bool {
+        *self.strict_validation.read().unwrap()
+    }
+
+    /// Validate a record for `collection` if strict validation is enabled
+    fn check_record(&self, collection: &Nsid, record: &serde_json::Value) -> Result<(), AgentError> {
+        if self.strict_validation() {
+            crate::validation::LexiconRegistry::bundled().validate_record(collection.as_str(), record)?;
+        }
+        Ok(())
+    }
+
     /// Clone this Agent into a new instance
     ///
     /// The new instance will have the same configuration but independent state.
@@ -280,6 +314,7 @@ impl Agent {
         new_agent.configure_labelers(self.labelers.read().unwrap().clone());
         new_agent.configure_app_labelers(self.app_labelers.read().unwrap().clone());
         new_agent.configure_proxy(self.proxy.read().unwrap().clone());
+        new_agent.configure_strict_validation(self.strict_validation());
 
         // Copy headers
         let headers = self.headers.read().unwrap();
@@ -440,6 +475,19 @@ impl Agent {
     ///
     /// * `request` - The XRPC request to make
     pub async fn request<T: serde::de::DeserializeOwned>(
+        &self,
+        request: XrpcRequest,
+    ) -> Result<XrpcResponse<T>, AgentError> {
+        let response = self.request_body::<Vec<u8>>(request).await?;
+        let data = serde_json::from_slice(&response.data).map_err(crate::xrpc::XrpcError::from)?;
+        Ok(XrpcResponse::new(data, response.headers))
+    }
+
+    /// Make an XRPC request, decoding the response according to `T`
+    ///
+    /// Like [`request`](Self::request), but also handles binary (`Vec<u8>`)
+    /// and empty (`()`) bodies.
+    pub async fn request_body<T: crate::xrpc::XrpcBody>(
         &self,
         mut request: XrpcRequest,
     ) -> Result<XrpcResponse<T>, AgentError> {
@@ -456,7 +504,7 @@ impl Agent {
 
         // Make request through XRPC client
         self.client
-            .request(request)
+            .request_body(request)
             .await
             .map_err(AgentError::from)
     }
@@ -500,7 +548,7 @@ impl Agent {
         // Create session data
         let session_data = AtpSessionData {
             did: response.data.did.to_string(),
-            handle: response.data.handle,
+            handle: response.data.handle.to_string(),
             email: response.data.email,
             email_confirmed: response.data.email_confirmed,
             email_auth_factor: response.data.email_auth_factor,
@@ -724,7 +772,8 @@ impl Agent {
         let clean_handle = handle.trim_start_matches('@');
 
         let params = resolve_handle::QueryParams {
-            handle: clean_handle.to_string(),
+            handle: crate::types::Handle::new(clean_handle)
+                .map_err(|e| AgentError::SessionError(e.to_string()))?,
         };
 
         let response = resolve_handle::resolve_handle(&*self.client, params).await?;
@@ -756,7 +805,7 @@ impl Agent {
     /// # Ok(())
     /// # }
     /// ```
-    async fn detect_facets(&self, text: &str) -> Result<(String, Option<serde_json::Value>), AgentError> {
+    async fn detect_facets(&self, text: &str) -> Result<(String, Option<Vec<serde_json::Value>>), AgentError> {
         use crate::rich_text::{UnicodeString, detect_facets, FacetFeature};
 
         let unicode_text = UnicodeString::new(text);
@@ -784,7 +833,10 @@ impl Agent {
             }
 
             // Convert to JSON
-            let facets_json = serde_json::to_value(&facets)
+            let facets_json = facets
+                .iter()
+                .map(serde_json::to_value)
+                .collect::<Result<Vec<_>, _>>()
                 .map_err(|e| AgentError::SessionError(format!("Failed to serialize facets: {}", e)))?;
 
             Ok((text.to_string(), Some(facets_json)))
@@ -826,12 +878,10 @@ impl Agent {
     /// # Ok(())
     /// # }
     /// ```
-    pub async fn upload_blob(&self, data: Vec<u8>, _content_type: &str) -> Result<serde_json::Value, AgentError> {
+    pub async fn upload_blob(&self, data: Vec<u8>, content_type: &str) -> Result<serde_json::Value, AgentError> {
         use crate::client::com::atproto::repo::upload_blob;
 
-        // Note: content_type header support needs to be added to XRPC client
-        // For now, the server will attempt to detect content type from the data
-        let response = upload_blob::upload_blob(&*self.client, data).await?;
+        let response = upload_blob::upload_blob(&*self.client, data, content_type).await?;
         Ok(response.data.blob)
     }
 
@@ -879,7 +929,7 @@ impl Agent {
         let (_text, facets) = self.detect_facets(text).await?;
 
         // Create the post record
-        let now = chrono::Utc::now().to_rfc3339();
+        let now = Datetime::now();
         let post_record = post::Post {
             text: text.to_string(),
             created_at: now,
@@ -898,13 +948,14 @@ impl Agent {
 
         let input = create_record::Input {
             repo: did,
-            collection: "app.bsky.feed.post".to_string(),
+            collection: Nsid::new_unchecked("app.bsky.feed.post"),
             rkey: None,
             validate: None,
             record: record_json,
             swap_commit: None,
         };
 
+        self.check_record(&input.collection, &input.record)?;
         let response = create_record::create_record(&*self.client, input).await?;
 
         Ok(response.data.uri.to_string())
@@ -978,13 +1029,12 @@ impl Agent {
         }
 
         // Create embed object
-        let embed = serde_json::json!({
-            "$type": "app.bsky.embed.images",
-            "images": image_blobs,
-        });
+        let embed = post::PostEmbed::Images(Box::new(crate::client::app::bsky::embed::images::Main {
+            images: image_blobs,
+        }));
 
         // Create the post record with embed
-        let now = chrono::Utc::now().to_rfc3339();
+        let now = Datetime::now();
         let post_record = post::Post {
             text: text.to_string(),
             created_at: now,
@@ -1003,13 +1053,14 @@ impl Agent {
 
         let input = create_record::Input {
             repo: did,
-            collection: "app.bsky.feed.post".to_string(),
+            collection: Nsid::new_unchecked("app.bsky.feed.post"),
             rkey: None,
             validate: None,
             record: record_json,
             swap_commit: None,
         };
 
+        self.check_record(&input.collection, &input.record)?;
         let response = create_record::create_record(&*self.client, input).await?;
 
         Ok(response.data.uri.to_string())
@@ -1072,11 +1123,11 @@ impl Agent {
         // Create reply reference
         let parent_ref = StrongRef {
             uri: crate::syntax::AtUri::try_from(parent_uri).map_err(|e| AgentError::SessionError(format!("Invalid parent URI: {}", e)))?,
-            cid: parent_cid.to_string(),
+            cid: CidLink::new(parent_cid).map_err(|e| AgentError::SessionError(format!("Invalid parent CID: {}", e)))?,
         };
         let root_ref = StrongRef {
             uri: crate::syntax::AtUri::try_from(root_uri).map_err(|e| AgentError::SessionError(format!("Invalid root URI: {}", e)))?,
-            cid: root_cid.to_string(),
+            cid: CidLink::new(root_cid).map_err(|e| AgentError::SessionError(format!("Invalid root CID: {}", e)))?,
         };
 
         let reply_ref = ReplyRef {
@@ -1089,7 +1140,7 @@ impl Agent {
         };
 
         // Create the post record with reply
-        let now = chrono::Utc::now().to_rfc3339();
+        let now = Datetime::now();
         let post_record = post::Post {
             text: text.to_string(),
             created_at: now,
@@ -1110,13 +1161,14 @@ impl Agent {
 
         let input = create_record::Input {
             repo: did,
-            collection: "app.bsky.feed.post".to_string(),
+            collection: Nsid::new_unchecked("app.bsky.feed.post"),
             rkey: None,
             validate: None,
             record: record_json,
             swap_commit: None,
         };
 
+        self.check_record(&input.collection, &input.record)?;
         let response = create_record::create_record(&*self.client, input).await?;
 
         Ok(response.data.uri.to_string())
@@ -1213,19 +1265,18 @@ impl Agent {
         }
 
         // Create embed object
-        let embed = serde_json::json!({
-            "$type": "app.bsky.embed.images",
-            "images": image_blobs,
-        });
+        let embed = post::PostEmbed::Images(Box::new(crate::client::app::bsky::embed::images::Main {
+            images: image_blobs,
+        }));
 
         // Create reply reference
         let parent_ref = StrongRef {
             uri: crate::syntax::AtUri::try_from(parent_uri).map_err(|e| AgentError::SessionError(format!("Invalid parent URI: {}", e)))?,
-            cid: parent_cid.to_string(),
+            cid: CidLink::new(parent_cid).map_err(|e| AgentError::SessionError(format!("Invalid parent CID: {}", e)))?,
         };
         let root_ref = StrongRef {
             uri: crate::syntax::AtUri::try_from(root_uri).map_err(|e| AgentError::SessionError(format!("Invalid root URI: {}", e)))?,
-            cid: root_cid.to_string(),
+            cid: CidLink::new(root_cid).map_err(|e| AgentError::SessionError(format!("Invalid root CID: {}", e)))?,
         };
 
         let reply_ref = ReplyRef {
@@ -1238,7 +1289,7 @@ impl Agent {
         };
 
         // Create the post record with reply and embed
-        let now = chrono::Utc::now().to_rfc3339();
+        let now = Datetime::now();
         let post_record = post::Post {
             text: text.to_string(),
             created_at: now,
@@ -1259,13 +1310,14 @@ impl Agent {
 
         let input = create_record::Input {
             repo: did,
-            collection: "app.bsky.feed.post".to_string(),
+            collection: Nsid::new_unchecked("app.bsky.feed.post"),
             rkey: None,
             validate: None,
             record: record_json,
             swap_commit: None,
         };
 
+        self.check_record(&input.collection, &input.record)?;
         let response = create_record::create_record(&*self.client, input).await?;
 
         Ok(response.data.uri.to_string())
@@ -1332,13 +1384,12 @@ impl Agent {
             "thumb": thumb_blob,
         });
 
-        let embed = serde_json::json!({
-            "$type": "app.bsky.embed.external",
-            "external": external,
-        });
+        let embed = post::PostEmbed::External(Box::new(crate::client::app::bsky::embed::external::Main {
+            external,
+        }));
 
         // Create the post record with embed
-        let now = chrono::Utc::now().to_rfc3339();
+        let now = Datetime::now();
         let post_record = post::Post {
             text: text.to_string(),
             created_at: now,
@@ -1357,13 +1408,14 @@ impl Agent {
 
         let input = create_record::Input {
             repo: did,
-            collection: "app.bsky.feed.post".to_string(),
+            collection: Nsid::new_unchecked("app.bsky.feed.post"),
             rkey: None,
             validate: None,
             record: record_json,
             swap_commit: None,
         };
 
+        self.check_record(&input.collection, &input.record)?;
         let response = create_record::create_record(&*self.client, input).await?;
 
         Ok(response.data.uri.to_string())
@@ -1492,19 +1544,18 @@ impl Agent {
             "thumb": params.thumb_blob,
         });
 
-        let embed = serde_json::json!({
-            "$type": "app.bsky.embed.external",
-            "external": external,
-        });
+        let embed = post::PostEmbed::External(Box::new(crate::client::app::bsky::embed::external::Main {
+            external,
+        }));
 
         // Create reply reference
         let parent_ref = StrongRef {
             uri: crate::syntax::AtUri::try_from(params.parent_uri).map_err(|e| AgentError::SessionError(format!("Invalid parent URI: {}", e)))?,
-            cid: params.parent_cid.to_string(),
+            cid: CidLink::new(params.parent_cid).map_err(|e| AgentError::SessionError(format!("Invalid parent CID: {}", e)))?,
         };
         let root_ref = StrongRef {
             uri: crate::syntax::AtUri::try_from(params.root_uri).map_err(|e| AgentError::SessionError(format!("Invalid root URI: {}", e)))?,
-            cid: params.root_cid.to_string(),
+            cid: CidLink::new(params.root_cid).map_err(|e| AgentError::SessionError(format!("Invalid root CID: {}", e)))?,
         };
 
         let reply_ref = ReplyRef {
@@ -1517,7 +1568,7 @@ impl Agent {
         };
 
         // Create the post record with reply and embed
-        let now = chrono::Utc::now().to_rfc3339();
+        let now = Datetime::now();
         let post_record = post::Post {
             text: text.to_string(),
             created_at: now,
@@ -1538,13 +1589,14 @@ impl Agent {
 
         let input = create_record::Input {
             repo: did,
-            collection: "app.bsky.feed.post".to_string(),
+            collection: Nsid::new_unchecked("app.bsky.feed.post"),
             rkey: None,
             validate: None,
             record: record_json,
             swap_commit: None,
         };
 
+        self.check_record(&input.collection, &input.record)?;
         let response = create_record::create_record(&*self.client, input).await?;
 
         Ok(response.data.uri.to_string())
@@ -1565,7 +1617,7 @@ impl Agent {
 
         let did = self.assert_did()?;
 
-        let now = chrono::Utc::now().to_rfc3339();
+        let now = Datetime::now();
         let subject_did_obj = Did::new(subject_did)
             .map_err(|_| AgentError::InvalidDid(subject_did.to_string()))?;
         let follow_record = follow::Follow {
@@ -1578,13 +1630,14 @@ impl Agent {
 
         let input = create_record::Input {
             repo: did,
-            collection: "app.bsky.graph.follow".to_string(),
+            collection: Nsid::new_unchecked("app.bsky.graph.follow"),
             rkey: None,
             validate: None,
             record: record_json,
             swap_commit: None,
         };
 
+        self.check_record(&input.collection, &input.record)?;
         let response = create_record::create_record(&*self.client, input).await?;
 
         Ok(response.data.uri.to_string())
@@ -1606,7 +1659,7 @@ impl Agent {
 
         let did = self.assert_did()?;
 
-        let now = chrono::Utc::now().to_rfc3339();
+        let now = Datetime::now();
 
         // Create subject JSON
         let subject = serde_json::json!({
@@ -1625,13 +1678,14 @@ impl Agent {
 
         let input = create_record::Input {
             repo: did,
-            collection: "app.bsky.feed.like".to_string(),
+            collection: Nsid::new_unchecked("app.bsky.feed.like"),
             rkey: None,
             validate: None,
             record: record_json,
             swap_commit: None,
         };
 
+        self.check_record(&input.collection, &input.record)?;
         let response = create_record::create_record(&*self.client, input).await?;
 
         Ok(response.data.uri.to_string())
@@ -1653,7 +1707,7 @@ impl Agent {
 
         let did = self.assert_did()?;
 
-        let now = chrono::Utc::now().to_rfc3339();
+        let now = Datetime::now();
 
         // Create subject JSON
         let subject = serde_json::json!({
@@ -1672,13 +1726,14 @@ impl Agent {
 
         let input = create_record::Input {
             repo: did,
-            collection: "app.bsky.feed.repost".to_string(),
+            collection: Nsid::new_unchecked("app.bsky.feed.repost"),
             rkey: None,
             validate: None,
             record: record_json,
             swap_commit: None,
         };
 
+        self.check_record(&input.collection, &input.record)?;
         let response = create_record::create_record(&*self.client, input).await?;
 
         Ok(response.data.uri.to_string())
@@ -1698,8 +1753,10 @@ impl Agent {
 
         let input = delete_record::Input {
             repo: at_uri.hostname().to_string(),
-            collection: at_uri.collection().to_string(),
-            rkey: at_uri.rkey().unwrap_or("").to_string(),
+            collection: Nsid::new(at_uri.collection())
+                .map_err(|e| AgentError::SessionError(format!("Invalid AT-URI: {}", e)))?,
+            rkey: crate::types::RecordKey::new(at_uri.rkey().unwrap_or(""))
+                .map_err(|e| AgentError::SessionError(format!("Invalid AT-URI: {}", e)))?,
             swap_record: None,
             swap_commit: None,
         };
@@ -1770,8 +1827,7 @@ impl Agent {
         use crate::client::app::bsky::actor::get_profiles;
 
         let params = get_profiles::QueryParams {
-            actors: serde_json::to_value(actors)
-                .map_err(|e| AgentError::SessionError(format!("Failed to serialize actors: {}", e)))?,
+            actors,
         };
 
         let response = get_profiles::get_profiles(&*self.client, params).await?;
@@ -1937,8 +1993,11 @@ impl Agent {
         use crate::client::app::bsky::feed::get_posts;
 
         let params = get_posts::QueryParams {
-            uris: serde_json::to_value(uris)
-                .map_err(|e| AgentError::SessionError(format!("Failed to serialize uris: {}", e)))?,
+            uris: uris
+                .iter()
+                .map(|uri| crate::syntax::AtUri::try_from(uri.as_str()))
+                .collect::<Result<Vec<_>, _>>()
+                .map_err(|e| AgentError::SessionError(format!("Invalid post URI: {}", e)))?,
         };
 
         let response = get_posts::get_posts(&*self.client, params).await?;
@@ -2003,7 +2062,10 @@ impl Agent {
         let params = get_likes::QueryParams {
             uri: AtUri::try_from(uri.to_string())
                 .map_err(|e| AgentError::SessionError(format!("Invalid AT-URI: {}", e)))?,
-            cid,
+            cid: cid
+                .map(CidLink::new)
+                .transpose()
+                .map_err(|e| AgentError::SessionError(e.to_string()))?,
             limit,
             cursor,
         };
@@ -2039,7 +2101,10 @@ impl Agent {
         let params = get_reposted_by::QueryParams {
             uri: AtUri::try_from(uri.to_string())
                 .map_err(|e| AgentError::SessionError(format!("Invalid AT-URI: {}", e)))?,
-            cid,
+            cid: cid
+                .map(CidLink::new)
+                .transpose()
+                .map_err(|e| AgentError::SessionError(e.to_string()))?,
             limit,
             cursor,
         };
@@ -2196,7 +2261,10 @@ impl Agent {
         let params = list_notifications::QueryParams {
             limit,
             cursor,
-            seen_at,
+            seen_at: seen_at
+                .map(Datetime::new)
+                .transpose()
+                .map_err(|e| AgentError::SessionError(e.to_string()))?,
             priority,
             reasons: None,
         };
@@ -2244,7 +2312,10 @@ impl Agent {
 
         let params = get_unread_count::QueryParams {
             priority,
-            seen_at,
+            seen_at: seen_at
+                .map(Datetime::new)
+                .transpose()
+                .map_err(|e| AgentError::SessionError(e.to_string()))?,
         };
 
         let response = get_unread_count::get_unread_count(&*self.client, params).await?;
@@ -2287,9 +2358,10 @@ impl Agent {
         self.assert_did()?;
 
         // Use provided timestamp or current time
-        let timestamp = seen_at.unwrap_or_else(|| {
-            chrono::Utc::now().to_rfc3339_opts(chrono::SecondsFormat::Millis, true)
-        });
+        let timestamp = match seen_at {
+            Some(seen_at) => Datetime::new(seen_at).map_err(|e| AgentError::SessionError(e.to_string()))?,
+            None => Datetime::now(),
+        };
 
         let input = update_seen::Input {
             seen_at: timestamp,
@@ -3623,8 +3695,8 @@ impl Agent {
             // Fetch current profile
             let params = get_record::QueryParams {
                 repo: did.clone(),
-                collection: "app.bsky.actor.profile".to_string(),
-                rkey: "self".to_string(),
+                collection: Nsid::new_unchecked("app.bsky.actor.profile"),
+                rkey: crate::types::RecordKey::new_unchecked("self"),
                 cid: None,
             };
 
@@ -3657,14 +3729,15 @@ impl Agent {
             // Attempt to put record
             let put_input = put_record::Input {
                 repo: did.clone(),
-                collection: "app.bsky.actor.profile".to_string(),
-                rkey: "self".to_string(),
+                collection: Nsid::new_unchecked("app.bsky.actor.profile"),
+                rkey: crate::types::RecordKey::new_unchecked("self"),
                 record: merged_profile,
                 swap_record,
                 swap_commit: None,
                 validate: Some(true),
             };
 
+            self.check_record(&put_input.collection, &put_input.record)?;
             let put_result = put_record::put_record(&*self.client, put_input).await;
 
             match put_result {
@@ -3911,13 +3984,14 @@ impl Agent {
 
         let input = create_record::Input {
             repo: did,
-            collection: "app.bsky.graph.listblock".to_string(),
+            collection: Nsid::new_unchecked("app.bsky.graph.listblock"),
             rkey: None,
             validate: Some(true),
             record: listblock,
             swap_commit: None,
         };
 
+        self.check_record(&input.collection, &input.record)?;
         create_record::create_record(&*self.client, input).await?;
 
         Ok(())
@@ -3975,10 +4049,10 @@ impl Agent {
                 // Delete the listblock record
                 let input = delete_record::Input {
                     repo: did,
-                    collection: "app.bsky.graph.listblock".to_string(),
+                    collection: Nsid::new_unchecked("app.bsky.graph.listblock"),
                     rkey: blocked_at_uri.rkey().ok_or_else(|| {
                         AgentError::SessionError("Blocked URI missing rkey".to_string())
-                    })?.to_string(),
+                    })?.parse().map_err(|e: crate::types::TypeError| AgentError::SessionError(e.to_string()))?,
                     swap_record: None,
                     swap_commit: None,
                 };
@@ -4041,7 +4115,8 @@ impl Agent {
         self.assert_did()?;
 
         let input = create_report::Input {
-            subject,
+            subject: serde_json::from_value(subject)
+                .map_err(|e| AgentError::SessionError(format!("Invalid report subject: {}", e)))?,
             reason_type: serde_json::json!(reason_type),
             reason,
             mod_tool: None,
@@ -4415,7 +4490,7 @@ mod tests {
 
         let parent_root_ref = StrongRef {
             uri: crate::syntax::AtUri::try_from("at://did:plc:abc123/app.bsky.feed.post/xyz789").unwrap(),
-            cid: "bafyreiabc123".to_string(),
+            cid: crate::types::CidLink::new_unchecked("bafyreiabc123"),
         };
 
         let reply_ref = ReplyRef {
@@ -4472,7 +4547,7 @@ mod tests {
 
         let strong_ref = StrongRef {
             uri: crate::syntax::AtUri::try_from("at://did:plc:test/app.bsky.feed.post/test123").unwrap(),
-            cid: "bafytest456".to_string(),
+            cid: crate::types::CidLink::new_unchecked("bafytest456"),
         };
 
         let json = serde_json::to_value(&strong_ref).unwrap();
@@ -4487,13 +4562,13 @@ mod tests {
 
         let json = serde_json::json!({
             "uri": "at://did:plc:example/app.bsky.feed.post/abc",
-            "cid": "bafyexample"
+            "cid": "bafyreie5737gdxlw5i64vzichcalba3z2v5n6icifvx5xytvske7mr3hpm"
         });
 
         let strong_ref: StrongRef = serde_json::from_value(json).unwrap();
 
         assert_eq!(strong_ref.uri.as_str(), "at://did:plc:example/app.bsky.feed.post/abc");
-        assert_eq!(strong_ref.cid, "bafyexample");
+        assert_eq!(strong_ref.cid.as_str(), "bafyreie5737gdxlw5i64vzichcalba3z2v5n6icifvx5xytvske7mr3hpm");
     }
 
     #[test]
@@ -4504,11 +4579,11 @@ mod tests {
         // Test case: replying to a reply (parent != root)
         let parent_ref = StrongRef {
             uri: crate::syntax::AtUri::try_from("at://did:plc:user1/app.bsky.feed.post/reply1").unwrap(),
-            cid: "bafyparent".to_string(),
+            cid: crate::types::CidLink::new_unchecked("bafyparent"),
         };
         let root_ref = StrongRef {
             uri: crate::syntax::AtUri::try_from("at://did:plc:user2/app.bsky.feed.post/original").unwrap(),
-            cid: "bafyroot".to_string(),
+            cid: crate::types::CidLink::new_unchecked("bafyroot"),
         };
 
         let reply_ref = ReplyRef {
@@ -4644,4 +4719,136 @@ mod tests {
             "Description with émojis 🦀 and ümlauts"
         );
     }
+
+    #[test]
+    fn test_post_embed_union() {
+        use crate::client::app::bsky::feed::post::{Post, PostEmbed};
+
+        let json = serde_json::json!({
+            "$type": "app.bsky.feed.post",
+            "text": "Check this out",
+            "createdAt": "2024-01-01T00:00:00Z",
+            "embed": {
+                "$type": "app.bsky.embed.external",
+                "external": {
+                    "uri": "https://rust-lang.org",
+                    "title": "Rust",
+                    "description": "A language empowering everyone",
+                }
+            }
+        });
+
+        let post: Post = serde_json::from_value(json).unwrap();
+        let Some(PostEmbed::External(embed)) = &post.embed else {
+            panic!("expected an external embed, got {:?}", post.embed);
+        };
+        assert_eq!(embed.external["uri"], "https://rust-lang.org");
+
+        // The tag is written back out
+        let json = serde_json::to_value(&post).unwrap();
+        assert_eq!(json["embed"]["$type"], "app.bsky.embed.external");
+    }
+
+    #[test]
+    fn test_open_union_keeps_unknown_types() {
+        use crate::client::app::bsky::feed::post::PostEmbed;
+
+        let json = serde_json::json!({
+            "$type": "com.example.embed.poll",
+            "options": ["yes", "no"],
+        });
+
+        let embed: PostEmbed = serde_json::from_value(json.clone()).unwrap();
+        assert!(matches!(embed, PostEmbed::Unknown(_)));
+        assert_eq!(serde_json::to_value(&embed).unwrap(), json);
+    }
+
+    #[test]
+    fn test_open_union_rejects_malformed_known_types() {
+        use crate::client::app::bsky::feed::post::PostEmbed;
+
+        let json = serde_json::json!({
+            "$type": "app.bsky.embed.external",
+            "wrong": 1,
+        });
+
+        assert!(serde_json::from_value::<PostEmbed>(json).is_err());
+    }
+
+    #[test]
+    fn test_closed_union_rejects_unknown_types() {
+        use crate::client::com::atproto::repo::apply_writes::InputWrites;
+
+        let delete = serde_json::json!({
+            "$type": "com.atproto.repo.applyWrites#delete",
+            "collection": "app.bsky.feed.post",
+            "rkey": "3k2a",
+        });
+        assert!(matches!(
+            serde_json::from_value::<InputWrites>(delete).unwrap(),
+            InputWrites::Delete(_)
+        ));
+
+        let unknown = serde_json::json!({
+            "$type": "com.example.repo#truncate",
+            "collection": "app.bsky.feed.post",
+        });
+        assert!(serde_json::from_value::<InputWrites>(unknown).is_err());
+    }
+
+    fn authenticated_agent(service: &str) -> Agent {
+        let agent = Agent::new(service.to_string());
+        *agent.session_data.write().unwrap() = Some(AtpSessionData {
+            did: "did:plc:test123".to_string(),
+            handle: "test.bsky.social".to_string(),
+            email: None,
+            email_confirmed: None,
+            email_auth_factor: None,
+            access_jwt: "access_token".to_string(),
+            refresh_jwt: "refresh_token".to_string(),
+            active: true,
+            status: None,
+        });
+        agent
+    }
+
+    #[tokio::test]
+    async fn test_strict_validation_rejects_invalid_records() {
+        // Nothing listens here, so any request that is sent fails with an XRPC error
+        let agent = authenticated_agent("http://127.0.0.1:9");
+        assert!(!agent.strict_validation());
+
+        let too_long = "🎉".repeat(301);
+        assert!(matches!(agent.post(&too_long).await, Err(AgentError::XrpcError(_))));
+
+        agent.configure_strict_validation(true);
+        assert!(agent.clone_agent().strict_validation());
+
+        let result = agent.post(&too_long).await;
+        assert!(matches!(
+            result,
+            Err(AgentError::InvalidRecord(crate::validation::ValidationError::TooManyGraphemes { .. }))
+        ));
+
+        let result = agent
+            .like("at://did:plc:test/app.bsky.feed.post/3jui7kd54zh2y", "not-a-cid")
+            .await;
+        assert!(matches!(result, Err(AgentError::InvalidRecord(_))));
+    }
+
+    #[tokio::test]
+    async fn test_strict_validation_accepts_valid_records() {
+        let agent = authenticated_agent("http://127.0.0.1:9");
+        agent.configure_strict_validation(true);
+
+        assert!(matches!(agent.post("Hello #rust").await, Err(AgentError::XrpcError(_))));
+
+        let result = agent
+            .like(
+                "at://did:plc:test/app.bsky.feed.post/3jui7kd54zh2y",
+                "bafyreie5737gdxlw5i64vzichcalba3z2v5n6icifvx5xytvske7mr3hpm",
+            )
+            .await;
+        assert!(matches!(result, Err(AgentError::XrpcError(_))));
+    }
 }
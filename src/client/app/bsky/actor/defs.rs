@@ -3,121 +3,81 @@
 use serde::{Deserialize, Serialize};
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct LabelersPref {
-    pub labelers: serde_json::Value,
-}
-
-
-#[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct FeedViewPref {
-    /// Hide quote posts in the feed.
+pub struct ProfileViewBasic {
+    pub did: crate::types::Did,
+    pub handle: crate::types::Handle,
     #[serde(skip_serializing_if = "Option::is_none")]
-    #[serde(rename = "hideQuotePosts")]
-    pub hide_quote_posts: Option<bool>,
-    /// Hide replies in the feed if they are not by followed users.
+    #[serde(rename = "displayName")]
+    pub display_name: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    #[serde(rename = "hideRepliesByUnfollowed")]
-    pub hide_replies_by_unfollowed: Option<bool>,
-    /// Hide replies in the feed.
+    pub pronouns: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    #[serde(rename = "hideReplies")]
-    pub hide_replies: Option<bool>,
-    /// Hide replies in the feed if they do not have this number of likes.
+    pub avatar: Option<crate::types::Uri>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    #[serde(rename = "hideRepliesByLikeCount")]
-    pub hide_replies_by_like_count: Option<i64>,
-    /// The URI of the feed, or an identifier which describes the feed.
-    pub feed: String,
-    /// Hide reposts in the feed.
+    pub associated: Option<serde_json::Value>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    #[serde(rename = "hideReposts")]
-    pub hide_reposts: Option<bool>,
+    pub viewer: Option<serde_json::Value>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub labels: Option<Vec<serde_json::Value>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(rename = "createdAt")]
+    pub created_at: Option<crate::types::Datetime>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub verification: Option<serde_json::Value>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status: Option<serde_json::Value>,
 }
 
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct ProfileViewBasic {
+pub struct ProfileView {
+    pub did: crate::types::Did,
+    pub handle: crate::types::Handle,
     #[serde(skip_serializing_if = "Option::is_none")]
-    #[serde(rename = "createdAt")]
-    pub created_at: Option<String>,
+    #[serde(rename = "displayName")]
+    pub display_name: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub avatar: Option<String>,
+    pub pronouns: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub associated: Option<serde_json::Value>,
-    pub handle: String,
-    pub did: crate::types::Did,
+    pub description: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub status: Option<serde_json::Value>,
+    pub avatar: Option<crate::types::Uri>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub labels: Option<serde_json::Value>,
+    pub associated: Option<serde_json::Value>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    #[serde(rename = "displayName")]
-    pub display_name: Option<String>,
+    #[serde(rename = "indexedAt")]
+    pub indexed_at: Option<crate::types::Datetime>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub verification: Option<serde_json::Value>,
+    #[serde(rename = "createdAt")]
+    pub created_at: Option<crate::types::Datetime>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub viewer: Option<serde_json::Value>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub pronouns: Option<String>,
-}
-
-
-/// Default post interaction settings for the account. These values should be applied as default values when creating new posts. These refs should mirror the threadgate and postgate records exactly.
-#[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct PostInteractionSettingsPref {
-    /// Matches threadgate record. List of rules defining who can reply to this users posts. If value is an empty array, no one can reply. If value is undefined, anyone can reply.
-    /// Default post interaction settings for the account. These values should be applied as default values when creating new posts. These refs should mirror the threadgate and postgate records exactly.
+    pub labels: Option<Vec<serde_json::Value>>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    #[serde(rename = "threadgateAllowRules")]
-    pub threadgate_allow_rules: Option<serde_json::Value>,
-    /// Matches postgate record. List of rules defining who can embed this users posts. If value is an empty array or is undefined, no particular rules apply and anyone can embed.
-    /// Default post interaction settings for the account. These values should be applied as default values when creating new posts. These refs should mirror the threadgate and postgate records exactly.
+    pub verification: Option<serde_json::Value>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    #[serde(rename = "postgateEmbeddingRules")]
-    pub postgate_embedding_rules: Option<serde_json::Value>,
+    pub status: Option<serde_json::Value>,
 }
 
 
-/// A grab bag of state that's specific to the bsky.app program. Third-party apps shouldn't use this.
 #[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct BskyAppStatePref {
-    /// An array of tokens which identify nudges (modals, popups, tours, highlight dots) that should be shown to the user.
-    /// A grab bag of state that's specific to the bsky.app program. Third-party apps shouldn't use this.
+pub struct ProfileViewDetailed {
+    pub did: crate::types::Did,
+    pub handle: crate::types::Handle,
     #[serde(skip_serializing_if = "Option::is_none")]
-    #[serde(rename = "queuedNudges")]
-    pub queued_nudges: Option<serde_json::Value>,
-    /// Storage for NUXs the user has encountered.
-    /// A grab bag of state that's specific to the bsky.app program. Third-party apps shouldn't use this.
+    #[serde(rename = "displayName")]
+    pub display_name: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub nuxs: Option<serde_json::Value>,
-    /// A grab bag of state that's specific to the bsky.app program. Third-party apps shouldn't use this.
+    pub description: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    #[serde(rename = "activeProgressGuide")]
-    pub active_progress_guide: Option<serde_json::Value>,
-}
-
-
-#[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct LabelerPrefItem {
-    pub did: crate::types::Did,
-}
-
-
-#[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct MutedWordsPref {
-    /// A list of words the account owner has muted.
-    pub items: serde_json::Value,
-}
-
-
-#[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct ProfileViewDetailed {
+    pub pronouns: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub avatar: Option<String>,
+    pub website: Option<crate::types::Uri>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub banner: Option<String>,
+    pub avatar: Option<crate::types::Uri>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub website: Option<String>,
+    pub banner: Option<crate::types::Uri>,
     #[serde(skip_serializing_if = "Option::is_none")]
     #[serde(rename = "followersCount")]
     pub followers_count: Option<i64>,
@@ -125,60 +85,49 @@ pub struct ProfileViewDetailed {
     #[serde(rename = "followsCount")]
     pub follows_count: Option<i64>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub labels: Option<serde_json::Value>,
+    #[serde(rename = "postsCount")]
+    pub posts_count: Option<i64>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub verification: Option<serde_json::Value>,
+    pub associated: Option<serde_json::Value>,
     #[serde(skip_serializing_if = "Option::is_none")]
     #[serde(rename = "joinedViaStarterPack")]
     pub joined_via_starter_pack: Option<serde_json::Value>,
     #[serde(skip_serializing_if = "Option::is_none")]
     #[serde(rename = "indexedAt")]
-    pub indexed_at: Option<String>,
-    pub did: crate::types::Did,
-    #[serde(skip_serializing_if = "Option::is_none")]
-    #[serde(rename = "displayName")]
-    pub display_name: Option<String>,
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub associated: Option<serde_json::Value>,
+    pub indexed_at: Option<crate::types::Datetime>,
     #[serde(skip_serializing_if = "Option::is_none")]
     #[serde(rename = "createdAt")]
-    pub created_at: Option<String>,
+    pub created_at: Option<crate::types::Datetime>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub viewer: Option<serde_json::Value>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub description: Option<String>,
-    #[serde(skip_serializing_if = "Option::is_none")]
-    #[serde(rename = "postsCount")]
-    pub posts_count: Option<i64>,
+    pub labels: Option<Vec<serde_json::Value>>,
     #[serde(skip_serializing_if = "Option::is_none")]
     #[serde(rename = "pinnedPost")]
     pub pinned_post: Option<serde_json::Value>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub pronouns: Option<String>,
+    pub verification: Option<serde_json::Value>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub status: Option<serde_json::Value>,
-    pub handle: String,
 }
 
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct SavedFeedsPref {
-    pub saved: serde_json::Value,
+pub struct ProfileAssociated {
     #[serde(skip_serializing_if = "Option::is_none")]
-    #[serde(rename = "timelineIndex")]
-    pub timeline_index: Option<i64>,
-    pub pinned: serde_json::Value,
-}
-
-
-/// Preferences for how verified accounts appear in the app.
-#[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct VerificationPrefs {
-    /// Hide the blue check badges for verified accounts and trusted verifiers.
-    /// Preferences for how verified accounts appear in the app.
+    pub lists: Option<i64>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    #[serde(rename = "hideBadges")]
-    pub hide_badges: Option<bool>,
+    pub feedgens: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(rename = "starterPacks")]
+    pub starter_packs: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub labeler: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub chat: Option<serde_json::Value>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(rename = "activitySubscription")]
+    pub activity_subscription: Option<serde_json::Value>,
 }
 
 
@@ -190,33 +139,9 @@ pub struct ProfileAssociatedChat {
 
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct ThreadViewPref {
-    /// Sorting mode for threads.
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub sort: Option<String>,
-    /// Show followed users at the top of all replies.
-    #[serde(skip_serializing_if = "Option::is_none")]
-    #[serde(rename = "prioritizeFollowedUsers")]
-    pub prioritize_followed_users: Option<bool>,
-}
-
-
-#[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct PersonalDetailsPref {
-    /// The birth date of account owner.
-    #[serde(skip_serializing_if = "Option::is_none")]
-    #[serde(rename = "birthDate")]
-    pub birth_date: Option<String>,
-}
-
-
-#[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct SavedFeed {
-    pub id: String,
-    pub value: String,
-    pub pinned: bool,
-    #[serde(rename = "type")]
-    pub r#type: String,
+pub struct ProfileAssociatedActivitySubscription {
+    #[serde(rename = "allowSubscriptions")]
+    pub allow_subscriptions: String,
 }
 
 
@@ -232,23 +157,18 @@ pub struct ViewerState {
     pub muted_by_list: Option<serde_json::Value>,
     /// Metadata about the requesting account's relationship with the subject account. Only has meaningful content for authed requests.
     #[serde(skip_serializing_if = "Option::is_none")]
-    #[serde(rename = "blockingByList")]
-    pub blocking_by_list: Option<serde_json::Value>,
-    /// Metadata about the requesting account's relationship with the subject account. Only has meaningful content for authed requests.
-    #[serde(skip_serializing_if = "Option::is_none")]
     #[serde(rename = "blockedBy")]
     pub blocked_by: Option<bool>,
     /// Metadata about the requesting account's relationship with the subject account. Only has meaningful content for authed requests.
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub following: Option<crate::syntax::AtUri>,
-    /// This property is present only in selected cases, as an optimization.
+    pub blocking: Option<crate::syntax::AtUri>,
     /// Metadata about the requesting account's relationship with the subject account. Only has meaningful content for authed requests.
     #[serde(skip_serializing_if = "Option::is_none")]
-    #[serde(rename = "knownFollowers")]
-    pub known_followers: Option<serde_json::Value>,
+    #[serde(rename = "blockingByList")]
+    pub blocking_by_list: Option<serde_json::Value>,
     /// Metadata about the requesting account's relationship with the subject account. Only has meaningful content for authed requests.
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub blocking: Option<crate::syntax::AtUri>,
+    pub following: Option<crate::syntax::AtUri>,
     /// Metadata about the requesting account's relationship with the subject account. Only has meaningful content for authed requests.
     #[serde(skip_serializing_if = "Option::is_none")]
     #[serde(rename = "followedBy")]
@@ -256,24 +176,60 @@ pub struct ViewerState {
     /// This property is present only in selected cases, as an optimization.
     /// Metadata about the requesting account's relationship with the subject account. Only has meaningful content for authed requests.
     #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(rename = "knownFollowers")]
+    pub known_followers: Option<serde_json::Value>,
+    /// This property is present only in selected cases, as an optimization.
+    /// Metadata about the requesting account's relationship with the subject account. Only has meaningful content for authed requests.
+    #[serde(skip_serializing_if = "Option::is_none")]
     #[serde(rename = "activitySubscription")]
     pub activity_subscription: Option<serde_json::Value>,
 }
 
 
+/// The subject's followers whom you also follow
 #[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct SavedFeedsPrefV2 {
-    pub items: serde_json::Value,
+pub struct KnownFollowers {
+    /// The subject's followers whom you also follow
+    pub count: i64,
+    /// The subject's followers whom you also follow
+    pub followers: Vec<serde_json::Value>,
 }
 
 
-pub type MutedWordTarget = String;
+/// Represents the verification information about the user this object is attached to.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct VerificationState {
+    /// All verifications issued by trusted verifiers on behalf of this user. Verifications by untrusted verifiers are not included.
+    /// Represents the verification information about the user this object is attached to.
+    pub verifications: Vec<serde_json::Value>,
+    /// The user's status as a verified account.
+    /// Represents the verification information about the user this object is attached to.
+    #[serde(rename = "verifiedStatus")]
+    pub verified_status: String,
+    /// The user's status as a trusted verifier.
+    /// Represents the verification information about the user this object is attached to.
+    #[serde(rename = "trustedVerifierStatus")]
+    pub trusted_verifier_status: String,
+}
 
-/// If set, an active progress guide. Once completed, can be set to undefined. Should have unspecced fields tracking progress.
+
+/// An individual verification for an associated subject.
 #[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct BskyAppProgressGuide {
-    /// If set, an active progress guide. Once completed, can be set to undefined. Should have unspecced fields tracking progress.
-    pub guide: String,
+pub struct VerificationView {
+    /// The user who issued this verification.
+    /// An individual verification for an associated subject.
+    pub issuer: crate::types::Did,
+    /// The AT-URI of the verification record.
+    /// An individual verification for an associated subject.
+    pub uri: crate::syntax::AtUri,
+    /// True if the verification passes validation, otherwise false.
+    /// An individual verification for an associated subject.
+    #[serde(rename = "isValid")]
+    pub is_valid: bool,
+    /// Timestamp when the verification was created.
+    /// An individual verification for an associated subject.
+    #[serde(rename = "createdAt")]
+    pub created_at: crate::types::Datetime,
 }
 
 
@@ -283,75 +239,112 @@ pub struct AdultContentPref {
 }
 
 
-/// A new user experiences (NUX) storage object
 #[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct Nux {
-    /// Arbitrary data for the NUX. The structure is defined by the NUX itself. Limited to 300 characters.
-    /// A new user experiences (NUX) storage object
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub data: Option<String>,
-    /// The date and time at which the NUX will expire and should be considered completed.
-    /// A new user experiences (NUX) storage object
+pub struct ContentLabelPref {
+    /// Which labeler does this preference apply to? If undefined, applies globally.
     #[serde(skip_serializing_if = "Option::is_none")]
-    #[serde(rename = "expiresAt")]
-    pub expires_at: Option<String>,
-    /// A new user experiences (NUX) storage object
+    #[serde(rename = "labelerDid")]
+    pub labeler_did: Option<crate::types::Did>,
+    pub label: String,
+    pub visibility: String,
+}
+
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SavedFeed {
     pub id: String,
-    /// A new user experiences (NUX) storage object
-    pub completed: bool,
+    #[serde(rename = "type")]
+    pub r#type: String,
+    pub value: String,
+    pub pinned: bool,
 }
 
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct ProfileAssociated {
+pub struct SavedFeedsPrefV2 {
+    pub items: Vec<serde_json::Value>,
+}
+
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SavedFeedsPref {
+    pub pinned: Vec<crate::syntax::AtUri>,
+    pub saved: Vec<crate::syntax::AtUri>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub feedgens: Option<i64>,
+    #[serde(rename = "timelineIndex")]
+    pub timeline_index: Option<i64>,
+}
+
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PersonalDetailsPref {
+    /// The birth date of account owner.
     #[serde(skip_serializing_if = "Option::is_none")]
-    #[serde(rename = "starterPacks")]
-    pub starter_packs: Option<i64>,
+    #[serde(rename = "birthDate")]
+    pub birth_date: Option<crate::types::Datetime>,
+}
+
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FeedViewPref {
+    /// The URI of the feed, or an identifier which describes the feed.
+    pub feed: String,
+    /// Hide replies in the feed.
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub labeler: Option<bool>,
+    #[serde(rename = "hideReplies")]
+    pub hide_replies: Option<bool>,
+    /// Hide replies in the feed if they are not by followed users.
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub chat: Option<serde_json::Value>,
+    #[serde(rename = "hideRepliesByUnfollowed")]
+    pub hide_replies_by_unfollowed: Option<bool>,
+    /// Hide replies in the feed if they do not have this number of likes.
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub lists: Option<i64>,
+    #[serde(rename = "hideRepliesByLikeCount")]
+    pub hide_replies_by_like_count: Option<i64>,
+    /// Hide reposts in the feed.
     #[serde(skip_serializing_if = "Option::is_none")]
-    #[serde(rename = "activitySubscription")]
-    pub activity_subscription: Option<serde_json::Value>,
+    #[serde(rename = "hideReposts")]
+    pub hide_reposts: Option<bool>,
+    /// Hide quote posts in the feed.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(rename = "hideQuotePosts")]
+    pub hide_quote_posts: Option<bool>,
 }
 
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct StatusView {
-    /// True if the status is not expired, false if it is expired. Only present if expiration was set.
-    #[serde(skip_serializing_if = "Option::is_none")]
-    #[serde(rename = "isActive")]
-    pub is_active: Option<bool>,
-    pub record: serde_json::Value,
-    /// The date when this status will expire. The application might choose to no longer return the status after expiration.
+pub struct ThreadViewPref {
+    /// Sorting mode for threads.
     #[serde(skip_serializing_if = "Option::is_none")]
-    #[serde(rename = "expiresAt")]
-    pub expires_at: Option<String>,
-    /// The status for the account.
-    pub status: String,
-    /// An optional embed associated with the status.
+    pub sort: Option<String>,
+    /// Show followed users at the top of all replies.
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub embed: Option<serde_json::Value>,
+    #[serde(rename = "prioritizeFollowedUsers")]
+    pub prioritize_followed_users: Option<bool>,
 }
 
 
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct InterestsPref {
+    /// A list of tags which describe the account owner's interests gathered during onboarding.
+    pub tags: Vec<String>,
+}
+
+
+pub type MutedWordTarget = String;
+
 /// A word that the account owner has muted.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct MutedWord {
-    /// The muted word itself.
-    /// A word that the account owner has muted.
-    pub value: String,
     /// A word that the account owner has muted.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub id: Option<String>,
+    /// The muted word itself.
+    /// A word that the account owner has muted.
+    pub value: String,
     /// The intended targets of the muted word.
     /// A word that the account owner has muted.
-    pub targets: serde_json::Value,
+    pub targets: Vec<serde_json::Value>,
     /// Groups of users to apply the muted word to. If undefined, applies to all users.
     /// A word that the account owner has muted.
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -361,118 +354,195 @@ pub struct MutedWord {
     /// A word that the account owner has muted.
     #[serde(skip_serializing_if = "Option::is_none")]
     #[serde(rename = "expiresAt")]
-    pub expires_at: Option<String>,
+    pub expires_at: Option<crate::types::Datetime>,
 }
 
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct ProfileAssociatedActivitySubscription {
-    #[serde(rename = "allowSubscriptions")]
-    pub allow_subscriptions: String,
+pub struct MutedWordsPref {
+    /// A list of words the account owner has muted.
+    pub items: Vec<serde_json::Value>,
 }
 
 
-/// Represents the verification information about the user this object is attached to.
 #[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct VerificationState {
-    /// The user's status as a trusted verifier.
-    /// Represents the verification information about the user this object is attached to.
-    #[serde(rename = "trustedVerifierStatus")]
-    pub trusted_verifier_status: String,
-    /// The user's status as a verified account.
-    /// Represents the verification information about the user this object is attached to.
-    #[serde(rename = "verifiedStatus")]
-    pub verified_status: String,
-    /// All verifications issued by trusted verifiers on behalf of this user. Verifications by untrusted verifiers are not included.
-    /// Represents the verification information about the user this object is attached to.
-    pub verifications: serde_json::Value,
+pub struct HiddenPostsPref {
+    /// A list of URIs of posts the account owner has hidden.
+    pub items: Vec<crate::syntax::AtUri>,
 }
 
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct InterestsPref {
-    /// A list of tags which describe the account owner's interests gathered during onboarding.
-    pub tags: serde_json::Value,
+pub struct LabelersPref {
+    pub labelers: Vec<serde_json::Value>,
 }
 
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct ProfileView {
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub description: Option<String>,
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub labels: Option<serde_json::Value>,
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub verification: Option<serde_json::Value>,
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub avatar: Option<String>,
-    #[serde(skip_serializing_if = "Option::is_none")]
-    #[serde(rename = "createdAt")]
-    pub created_at: Option<String>,
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub status: Option<serde_json::Value>,
+pub struct LabelerPrefItem {
     pub did: crate::types::Did,
+}
+
+
+/// A grab bag of state that's specific to the bsky.app program. Third-party apps shouldn't use this.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BskyAppStatePref {
+    /// A grab bag of state that's specific to the bsky.app program. Third-party apps shouldn't use this.
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub viewer: Option<serde_json::Value>,
-    pub handle: String,
+    #[serde(rename = "activeProgressGuide")]
+    pub active_progress_guide: Option<serde_json::Value>,
+    /// An array of tokens which identify nudges (modals, popups, tours, highlight dots) that should be shown to the user.
+    /// A grab bag of state that's specific to the bsky.app program. Third-party apps shouldn't use this.
     #[serde(skip_serializing_if = "Option::is_none")]
-    #[serde(rename = "displayName")]
-    pub display_name: Option<String>,
+    #[serde(rename = "queuedNudges")]
+    pub queued_nudges: Option<Vec<String>>,
+    /// Storage for NUXs the user has encountered.
+    /// A grab bag of state that's specific to the bsky.app program. Third-party apps shouldn't use this.
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub pronouns: Option<String>,
+    pub nuxs: Option<Vec<serde_json::Value>>,
+}
+
+
+/// If set, an active progress guide. Once completed, can be set to undefined. Should have unspecced fields tracking progress.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BskyAppProgressGuide {
+    /// If set, an active progress guide. Once completed, can be set to undefined. Should have unspecced fields tracking progress.
+    pub guide: String,
+}
+
+
+/// A new user experiences (NUX) storage object
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Nux {
+    /// A new user experiences (NUX) storage object
+    pub id: String,
+    /// A new user experiences (NUX) storage object
+    pub completed: bool,
+    /// Arbitrary data for the NUX. The structure is defined by the NUX itself. Limited to 300 characters.
+    /// A new user experiences (NUX) storage object
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub associated: Option<serde_json::Value>,
+    pub data: Option<String>,
+    /// The date and time at which the NUX will expire and should be considered completed.
+    /// A new user experiences (NUX) storage object
     #[serde(skip_serializing_if = "Option::is_none")]
-    #[serde(rename = "indexedAt")]
-    pub indexed_at: Option<String>,
+    #[serde(rename = "expiresAt")]
+    pub expires_at: Option<crate::types::Datetime>,
 }
 
 
-/// The subject's followers whom you also follow
+/// Preferences for how verified accounts appear in the app.
 #[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct KnownFollowers {
-    /// The subject's followers whom you also follow
-    pub count: i64,
-    /// The subject's followers whom you also follow
-    pub followers: serde_json::Value,
+pub struct VerificationPrefs {
+    /// Hide the blue check badges for verified accounts and trusted verifiers.
+    /// Preferences for how verified accounts appear in the app.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(rename = "hideBadges")]
+    pub hide_badges: Option<bool>,
 }
 
 
+/// Default post interaction settings for the account. These values should be applied as default values when creating new posts. These refs should mirror the threadgate and postgate records exactly.
 #[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct ContentLabelPref {
-    pub label: String,
-    pub visibility: String,
-    /// Which labeler does this preference apply to? If undefined, applies globally.
+pub struct PostInteractionSettingsPref {
+    /// Matches threadgate record. List of rules defining who can reply to this users posts. If value is an empty array, no one can reply. If value is undefined, anyone can reply.
+    /// Default post interaction settings for the account. These values should be applied as default values when creating new posts. These refs should mirror the threadgate and postgate records exactly.
     #[serde(skip_serializing_if = "Option::is_none")]
-    #[serde(rename = "labelerDid")]
-    pub labeler_did: Option<crate::types::Did>,
+    #[serde(rename = "threadgateAllowRules")]
+    pub threadgate_allow_rules: Option<Vec<PostInteractionSettingsPrefThreadgateAllowRules>>,
+    /// Matches postgate record. List of rules defining who can embed this users posts. If value is an empty array or is undefined, no particular rules apply and anyone can embed.
+    /// Default post interaction settings for the account. These values should be applied as default values when creating new posts. These refs should mirror the threadgate and postgate records exactly.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(rename = "postgateEmbeddingRules")]
+    pub postgate_embedding_rules: Option<Vec<PostInteractionSettingsPrefPostgateEmbeddingRules>>,
 }
 
+#[derive(Debug, Clone, Serialize)]
+#[serde(tag = "$type")]
+pub enum PostInteractionSettingsPrefThreadgateAllowRules {
+    #[serde(rename = "app.bsky.feed.threadgate#mentionRule")]
+    ThreadgateMentionRule(Box<crate::client::app::bsky::feed::threadgate::MentionRule>),
+    #[serde(rename = "app.bsky.feed.threadgate#followerRule")]
+    ThreadgateFollowerRule(Box<crate::client::app::bsky::feed::threadgate::FollowerRule>),
+    #[serde(rename = "app.bsky.feed.threadgate#followingRule")]
+    ThreadgateFollowingRule(Box<crate::client::app::bsky::feed::threadgate::FollowingRule>),
+    #[serde(rename = "app.bsky.feed.threadgate#listRule")]
+    ThreadgateListRule(Box<crate::client::app::bsky::feed::threadgate::ListRule>),
+    /// A type not listed in the lexicon, kept as raw JSON
+    #[serde(untagged)]
+    Unknown(serde_json::Value),
+}
 
-/// An individual verification for an associated subject.
-#[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct VerificationView {
-    /// The user who issued this verification.
-    /// An individual verification for an associated subject.
-    pub issuer: crate::types::Did,
-    /// The AT-URI of the verification record.
-    /// An individual verification for an associated subject.
-    pub uri: crate::syntax::AtUri,
-    /// True if the verification passes validation, otherwise false.
-    /// An individual verification for an associated subject.
-    #[serde(rename = "isValid")]
-    pub is_valid: bool,
-    /// Timestamp when the verification was created.
-    /// An individual verification for an associated subject.
-    #[serde(rename = "createdAt")]
-    pub created_at: String,
+impl<'de> Deserialize<'de> for PostInteractionSettingsPrefThreadgateAllowRules {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> std::result::Result<Self, D::Error> {
+        let value = serde_json::Value::deserialize(deserializer)?;
+        match value.get("$type").and_then(|t| t.as_str()) {
+            Some("app.bsky.feed.threadgate#mentionRule") => serde_json::from_value(value).map(Self::ThreadgateMentionRule).map_err(serde::de::Error::custom),
+            Some("app.bsky.feed.threadgate#followerRule") => serde_json::from_value(value).map(Self::ThreadgateFollowerRule).map_err(serde::de::Error::custom),
+            Some("app.bsky.feed.threadgate#followingRule") => serde_json::from_value(value).map(Self::ThreadgateFollowingRule).map_err(serde::de::Error::custom),
+            Some("app.bsky.feed.threadgate#listRule") => serde_json::from_value(value).map(Self::ThreadgateListRule).map_err(serde::de::Error::custom),
+            _ => Ok(Self::Unknown(value)),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(tag = "$type")]
+pub enum PostInteractionSettingsPrefPostgateEmbeddingRules {
+    #[serde(rename = "app.bsky.feed.postgate#disableRule")]
+    PostgateDisableRule(Box<crate::client::app::bsky::feed::postgate::DisableRule>),
+    /// A type not listed in the lexicon, kept as raw JSON
+    #[serde(untagged)]
+    Unknown(serde_json::Value),
+}
+
+impl<'de> Deserialize<'de> for PostInteractionSettingsPrefPostgateEmbeddingRules {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> std::result::Result<Self, D::Error> {
+        let value = serde_json::Value::deserialize(deserializer)?;
+        match value.get("$type").and_then(|t| t.as_str()) {
+            Some("app.bsky.feed.postgate#disableRule") => serde_json::from_value(value).map(Self::PostgateDisableRule).map_err(serde::de::Error::custom),
+            _ => Ok(Self::Unknown(value)),
+        }
+    }
 }
 
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct HiddenPostsPref {
-    /// A list of URIs of posts the account owner has hidden.
-    pub items: serde_json::Value,
+pub struct StatusView {
+    /// The status for the account.
+    pub status: String,
+    pub record: serde_json::Value,
+    /// An optional embed associated with the status.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub embed: Option<StatusViewEmbed>,
+    /// The date when this status will expire. The application might choose to no longer return the status after expiration.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(rename = "expiresAt")]
+    pub expires_at: Option<crate::types::Datetime>,
+    /// True if the status is not expired, false if it is expired. Only present if expiration was set.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(rename = "isActive")]
+    pub is_active: Option<bool>,
+}
+
+/// An optional embed associated with the status.
+#[derive(Debug, Clone, Serialize)]
+#[serde(tag = "$type")]
+pub enum StatusViewEmbed {
+    #[serde(rename = "app.bsky.embed.external#view")]
+    ExternalView(Box<crate::client::app::bsky::embed::external::View>),
+    /// A type not listed in the lexicon, kept as raw JSON
+    #[serde(untagged)]
+    Unknown(serde_json::Value),
+}
+
+impl<'de> Deserialize<'de> for StatusViewEmbed {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> std::result::Result<Self, D::Error> {
+        let value = serde_json::Value::deserialize(deserializer)?;
+        match value.get("$type").and_then(|t| t.as_str()) {
+            Some("app.bsky.embed.external#view") => serde_json::from_value(value).map(Self::ExternalView).map_err(serde::de::Error::custom),
+            _ => Ok(Self::Unknown(value)),
+        }
+    }
 }
 
 
@@ -17,6 +17,8 @@ pub struct QueryParams {
 pub struct Output {
 }
 
+impl crate::xrpc::JsonBody for Output {}
+
 /// Get detailed profile view of an actor. Does not require auth, but contains relevant metadata with auth.
 pub async fn get_profile(
     client: &impl XrpcClient,
@@ -25,18 +27,20 @@ pub async fn get_profile(
     let mut req = XrpcRequest::query("app.bsky.actor.getProfile");
 
     // Add query parameters
-    let params_json = serde_json::to_value(&params)
-        .map_err(XrpcError::Serialization)?;
-
-    if let Some(obj) = params_json.as_object() {
-        for (key, value) in obj {
-            if let Some(s) = value.as_str() {
-                req.params.insert(key.clone(), s.to_string());
-            } else {
-                req.params.insert(key.clone(), value.to_string());
-            }
-        }
-    }
-
-    client.request(req).await
+    req.params.append("actor", params.actor.to_string());
+
+    client.request_body(req).await
+}
+
+/// Server-side definition of app.bsky.actor.getProfile
+pub struct Endpoint;
+
+impl crate::xrpc::XrpcEndpoint for Endpoint {
+    const NSID: &'static str = "app.bsky.actor.getProfile";
+    const METHOD: crate::xrpc::XrpcMethod = crate::xrpc::XrpcMethod::Query;
+    const INPUT_ENCODING: Option<&'static str> = None;
+    const OUTPUT_ENCODING: Option<&'static str> = Some("application/json");
+    type Params = QueryParams;
+    type Input = ();
+    type Output = Output;
 }
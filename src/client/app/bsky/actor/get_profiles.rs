@@ -8,15 +8,17 @@ use serde::{Deserialize, Serialize};
 /// Query parameters
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct QueryParams {
-    pub actors: serde_json::Value,
+    pub actors: Vec<String>,
 }
 
 /// Response output
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Output {
-    pub profiles: serde_json::Value,
+    pub profiles: Vec<serde_json::Value>,
 }
 
+impl crate::xrpc::JsonBody for Output {}
+
 /// Get detailed profile views of multiple actors.
 pub async fn get_profiles(
     client: &impl XrpcClient,
@@ -25,18 +27,22 @@ pub async fn get_profiles(
     let mut req = XrpcRequest::query("app.bsky.actor.getProfiles");
 
     // Add query parameters
-    let params_json = serde_json::to_value(&params)
-        .map_err(XrpcError::Serialization)?;
-
-    if let Some(obj) = params_json.as_object() {
-        for (key, value) in obj {
-            if let Some(s) = value.as_str() {
-                req.params.insert(key.clone(), s.to_string());
-            } else {
-                req.params.insert(key.clone(), value.to_string());
-            }
-        }
+    for value in &params.actors {
+        req.params.append("actors", value.to_string());
     }
 
-    client.request(req).await
+    client.request_body(req).await
+}
+
+/// Server-side definition of app.bsky.actor.getProfiles
+pub struct Endpoint;
+
+impl crate::xrpc::XrpcEndpoint for Endpoint {
+    const NSID: &'static str = "app.bsky.actor.getProfiles";
+    const METHOD: crate::xrpc::XrpcMethod = crate::xrpc::XrpcMethod::Query;
+    const INPUT_ENCODING: Option<&'static str> = None;
+    const OUTPUT_ENCODING: Option<&'static str> = Some("application/json");
+    type Params = QueryParams;
+    type Input = ();
+    type Output = Output;
 }
@@ -8,24 +8,26 @@ use serde::{Deserialize, Serialize};
 /// Query parameters
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct QueryParams {
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub cursor: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub limit: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cursor: Option<String>,
 }
 
 /// Response output
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Output {
-    pub actors: serde_json::Value,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cursor: Option<String>,
+    pub actors: Vec<serde_json::Value>,
     /// Snowflake for this recommendation, use when submitting recommendation events.
     #[serde(skip_serializing_if = "Option::is_none")]
     #[serde(rename = "recId")]
     pub rec_id: Option<i64>,
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub cursor: Option<String>,
 }
 
+impl crate::xrpc::JsonBody for Output {}
+
 /// Get a list of suggested actors. Expected use is discovery of accounts to follow during new account onboarding.
 pub async fn get_suggestions(
     client: &impl XrpcClient,
@@ -34,18 +36,25 @@ pub async fn get_suggestions(
     let mut req = XrpcRequest::query("app.bsky.actor.getSuggestions");
 
     // Add query parameters
-    let params_json = serde_json::to_value(&params)
-        .map_err(XrpcError::Serialization)?;
-
-    if let Some(obj) = params_json.as_object() {
-        for (key, value) in obj {
-            if let Some(s) = value.as_str() {
-                req.params.insert(key.clone(), s.to_string());
-            } else {
-                req.params.insert(key.clone(), value.to_string());
-            }
-        }
+    if let Some(value) = &params.limit {
+        req.params.append("limit", value.to_string());
+    }
+    if let Some(value) = &params.cursor {
+        req.params.append("cursor", value.to_string());
     }
 
-    client.request(req).await
+    client.request_body(req).await
+}
+
+/// Server-side definition of app.bsky.actor.getSuggestions
+pub struct Endpoint;
+
+impl crate::xrpc::XrpcEndpoint for Endpoint {
+    const NSID: &'static str = "app.bsky.actor.getSuggestions";
+    const METHOD: crate::xrpc::XrpcMethod = crate::xrpc::XrpcMethod::Query;
+    const INPUT_ENCODING: Option<&'static str> = None;
+    const OUTPUT_ENCODING: Option<&'static str> = Some("application/json");
+    type Params = QueryParams;
+    type Input = ();
+    type Output = Output;
 }
@@ -10,31 +10,52 @@ pub struct Profile {
     #[serde(rename = "displayName")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub display_name: Option<String>,
-    #[serde(rename = "pinnedPost")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub pinned_post: Option<serde_json::Value>,
-    /// Self-label values, specific to the Bluesky application, on the overall account.
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub labels: Option<serde_json::Value>,
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub website: Option<String>,
-    #[serde(rename = "joinedViaStarterPack")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub joined_via_starter_pack: Option<serde_json::Value>,
     /// Free-form profile description text.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub description: Option<String>,
-    /// Larger horizontal image to display behind profile view.
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub banner: Option<serde_json::Value>,
     /// Free-form pronouns text.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub pronouns: Option<String>,
-    #[serde(rename = "createdAt")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub created_at: Option<String>,
+    pub website: Option<crate::types::Uri>,
     /// Small image to be displayed next to posts from account. AKA, 'profile picture'
     #[serde(skip_serializing_if = "Option::is_none")]
     pub avatar: Option<serde_json::Value>,
+    /// Larger horizontal image to display behind profile view.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub banner: Option<serde_json::Value>,
+    /// Self-label values, specific to the Bluesky application, on the overall account.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub labels: Option<ProfileLabels>,
+    #[serde(rename = "joinedViaStarterPack")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub joined_via_starter_pack: Option<serde_json::Value>,
+    #[serde(rename = "pinnedPost")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub pinned_post: Option<serde_json::Value>,
+    #[serde(rename = "createdAt")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub created_at: Option<crate::types::Datetime>,
+}
+
+/// Self-label values, specific to the Bluesky application, on the overall account.
+#[derive(Debug, Clone, Serialize)]
+#[serde(tag = "$type")]
+pub enum ProfileLabels {
+    #[serde(rename = "com.atproto.label.defs#selfLabels")]
+    SelfLabels(Box<crate::client::com::atproto::label::defs::SelfLabels>),
+    /// A type not listed in the lexicon, kept as raw JSON
+    #[serde(untagged)]
+    Unknown(serde_json::Value),
+}
+
+impl<'de> Deserialize<'de> for ProfileLabels {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> std::result::Result<Self, D::Error> {
+        let value = serde_json::Value::deserialize(deserializer)?;
+        match value.get("$type").and_then(|t| t.as_str()) {
+            Some("com.atproto.label.defs#selfLabels") => serde_json::from_value(value).map(Self::SelfLabels).map_err(serde::de::Error::custom),
+            _ => Ok(Self::Unknown(value)),
+        }
+    }
 }
 
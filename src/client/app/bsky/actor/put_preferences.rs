@@ -11,6 +11,8 @@ pub struct Input {
     pub preferences: serde_json::Value,
 }
 
+impl crate::xrpc::JsonBody for Input {}
+
 /// Set the private preferences attached to the account.
 pub async fn put_preferences(
     client: &impl XrpcClient,
@@ -18,5 +20,18 @@ pub async fn put_preferences(
 ) -> Result<XrpcResponse<()>, XrpcError> {
     let req = XrpcRequest::procedure("app.bsky.actor.putPreferences").data(&input)?;
 
-    client.request(req).await
+    client.request_body(req).await
+}
+
+/// Server-side definition of app.bsky.actor.putPreferences
+pub struct Endpoint;
+
+impl crate::xrpc::XrpcEndpoint for Endpoint {
+    const NSID: &'static str = "app.bsky.actor.putPreferences";
+    const METHOD: crate::xrpc::XrpcMethod = crate::xrpc::XrpcMethod::Procedure;
+    const INPUT_ENCODING: Option<&'static str> = Some("application/json");
+    const OUTPUT_ENCODING: Option<&'static str> = None;
+    type Params = ();
+    type Input = Input;
+    type Output = ();
 }
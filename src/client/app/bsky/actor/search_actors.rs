@@ -23,11 +23,13 @@ pub struct QueryParams {
 /// Response output
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Output {
-    pub actors: serde_json::Value,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub cursor: Option<String>,
+    pub actors: Vec<serde_json::Value>,
 }
 
+impl crate::xrpc::JsonBody for Output {}
+
 /// Find actors (profiles) matching search criteria. Does not require auth.
 pub async fn search_actors(
     client: &impl XrpcClient,
@@ -36,18 +38,31 @@ pub async fn search_actors(
     let mut req = XrpcRequest::query("app.bsky.actor.searchActors");
 
     // Add query parameters
-    let params_json = serde_json::to_value(&params)
-        .map_err(XrpcError::Serialization)?;
-
-    if let Some(obj) = params_json.as_object() {
-        for (key, value) in obj {
-            if let Some(s) = value.as_str() {
-                req.params.insert(key.clone(), s.to_string());
-            } else {
-                req.params.insert(key.clone(), value.to_string());
-            }
-        }
+    if let Some(value) = &params.term {
+        req.params.append("term", value.to_string());
+    }
+    if let Some(value) = &params.q {
+        req.params.append("q", value.to_string());
+    }
+    if let Some(value) = &params.limit {
+        req.params.append("limit", value.to_string());
+    }
+    if let Some(value) = &params.cursor {
+        req.params.append("cursor", value.to_string());
     }
 
-    client.request(req).await
+    client.request_body(req).await
+}
+
+/// Server-side definition of app.bsky.actor.searchActors
+pub struct Endpoint;
+
+impl crate::xrpc::XrpcEndpoint for Endpoint {
+    const NSID: &'static str = "app.bsky.actor.searchActors";
+    const METHOD: crate::xrpc::XrpcMethod = crate::xrpc::XrpcMethod::Query;
+    const INPUT_ENCODING: Option<&'static str> = None;
+    const OUTPUT_ENCODING: Option<&'static str> = Some("application/json");
+    type Params = QueryParams;
+    type Input = ();
+    type Output = Output;
 }
@@ -8,22 +8,24 @@ use serde::{Deserialize, Serialize};
 /// Query parameters
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct QueryParams {
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub limit: Option<i64>,
     /// DEPRECATED: use 'q' instead.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub term: Option<String>,
     /// Search query prefix; not a full query string.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub q: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit: Option<i64>,
 }
 
 /// Response output
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Output {
-    pub actors: serde_json::Value,
+    pub actors: Vec<serde_json::Value>,
 }
 
+impl crate::xrpc::JsonBody for Output {}
+
 /// Find actor suggestions for a prefix search term. Expected use is for auto-completion during text field entry. Does not require auth.
 pub async fn search_actors_typeahead(
     client: &impl XrpcClient,
@@ -32,18 +34,28 @@ pub async fn search_actors_typeahead(
     let mut req = XrpcRequest::query("app.bsky.actor.searchActorsTypeahead");
 
     // Add query parameters
-    let params_json = serde_json::to_value(&params)
-        .map_err(XrpcError::Serialization)?;
-
-    if let Some(obj) = params_json.as_object() {
-        for (key, value) in obj {
-            if let Some(s) = value.as_str() {
-                req.params.insert(key.clone(), s.to_string());
-            } else {
-                req.params.insert(key.clone(), value.to_string());
-            }
-        }
+    if let Some(value) = &params.term {
+        req.params.append("term", value.to_string());
     }
+    if let Some(value) = &params.q {
+        req.params.append("q", value.to_string());
+    }
+    if let Some(value) = &params.limit {
+        req.params.append("limit", value.to_string());
+    }
+
+    client.request_body(req).await
+}
+
+/// Server-side definition of app.bsky.actor.searchActorsTypeahead
+pub struct Endpoint;
 
-    client.request(req).await
+impl crate::xrpc::XrpcEndpoint for Endpoint {
+    const NSID: &'static str = "app.bsky.actor.searchActorsTypeahead";
+    const METHOD: crate::xrpc::XrpcMethod = crate::xrpc::XrpcMethod::Query;
+    const INPUT_ENCODING: Option<&'static str> = None;
+    const OUTPUT_ENCODING: Option<&'static str> = Some("application/json");
+    type Params = QueryParams;
+    type Input = ();
+    type Output = Output;
 }
@@ -7,16 +7,37 @@ use serde::{Deserialize, Serialize};
 /// A declaration of a Bluesky account status.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Status {
-    #[serde(rename = "createdAt")]
-    pub created_at: String,
-    /// The duration of the status in minutes. Applications can choose to impose minimum and maximum limits.
-    #[serde(rename = "durationMinutes")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub duration_minutes: Option<i64>,
     /// The status for the account.
     pub status: String,
     /// An optional embed associated with the status.
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub embed: Option<serde_json::Value>,
+    pub embed: Option<StatusEmbed>,
+    /// The duration of the status in minutes. Applications can choose to impose minimum and maximum limits.
+    #[serde(rename = "durationMinutes")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub duration_minutes: Option<i64>,
+    #[serde(rename = "createdAt")]
+    pub created_at: crate::types::Datetime,
+}
+
+/// An optional embed associated with the status.
+#[derive(Debug, Clone, Serialize)]
+#[serde(tag = "$type")]
+pub enum StatusEmbed {
+    #[serde(rename = "app.bsky.embed.external")]
+    External(Box<crate::client::app::bsky::embed::external::Main>),
+    /// A type not listed in the lexicon, kept as raw JSON
+    #[serde(untagged)]
+    Unknown(serde_json::Value),
+}
+
+impl<'de> Deserialize<'de> for StatusEmbed {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> std::result::Result<Self, D::Error> {
+        let value = serde_json::Value::deserialize(deserializer)?;
+        match value.get("$type").and_then(|t| t.as_str()) {
+            Some("app.bsky.embed.external") => serde_json::from_value(value).map(Self::External).map_err(serde::de::Error::custom),
+            _ => Ok(Self::Unknown(value)),
+        }
+    }
 }
 
@@ -9,9 +9,11 @@ use serde::{Deserialize, Serialize};
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Input {
     pub uri: crate::syntax::AtUri,
-    pub cid: String,
+    pub cid: crate::types::CidLink,
 }
 
+impl crate::xrpc::JsonBody for Input {}
+
 /// Creates a private bookmark for the specified record. Currently, only `app.bsky.feed.post` records are supported. Requires authentication.
 pub async fn create_bookmark(
     client: &impl XrpcClient,
@@ -19,5 +21,18 @@ pub async fn create_bookmark(
 ) -> Result<XrpcResponse<()>, XrpcError> {
     let req = XrpcRequest::procedure("app.bsky.bookmark.createBookmark").data(&input)?;
 
-    client.request(req).await
+    client.request_body(req).await
+}
+
+/// Server-side definition of app.bsky.bookmark.createBookmark
+pub struct Endpoint;
+
+impl crate::xrpc::XrpcEndpoint for Endpoint {
+    const NSID: &'static str = "app.bsky.bookmark.createBookmark";
+    const METHOD: crate::xrpc::XrpcMethod = crate::xrpc::XrpcMethod::Procedure;
+    const INPUT_ENCODING: Option<&'static str> = Some("application/json");
+    const OUTPUT_ENCODING: Option<&'static str> = None;
+    type Params = ();
+    type Input = Input;
+    type Output = ();
 }
@@ -13,12 +13,38 @@ pub struct Bookmark {
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct BookmarkView {
-    #[serde(skip_serializing_if = "Option::is_none")]
-    #[serde(rename = "createdAt")]
-    pub created_at: Option<String>,
     /// A strong ref to the bookmarked record.
     pub subject: serde_json::Value,
-    pub item: serde_json::Value,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(rename = "createdAt")]
+    pub created_at: Option<crate::types::Datetime>,
+    pub item: BookmarkViewItem,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(tag = "$type")]
+pub enum BookmarkViewItem {
+    #[serde(rename = "app.bsky.feed.defs#blockedPost")]
+    BlockedPost(Box<crate::client::app::bsky::feed::defs::BlockedPost>),
+    #[serde(rename = "app.bsky.feed.defs#notFoundPost")]
+    NotFoundPost(Box<crate::client::app::bsky::feed::defs::NotFoundPost>),
+    #[serde(rename = "app.bsky.feed.defs#postView")]
+    PostView(Box<crate::client::app::bsky::feed::defs::PostView>),
+    /// A type not listed in the lexicon, kept as raw JSON
+    #[serde(untagged)]
+    Unknown(serde_json::Value),
+}
+
+impl<'de> Deserialize<'de> for BookmarkViewItem {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> std::result::Result<Self, D::Error> {
+        let value = serde_json::Value::deserialize(deserializer)?;
+        match value.get("$type").and_then(|t| t.as_str()) {
+            Some("app.bsky.feed.defs#blockedPost") => serde_json::from_value(value).map(Self::BlockedPost).map_err(serde::de::Error::custom),
+            Some("app.bsky.feed.defs#notFoundPost") => serde_json::from_value(value).map(Self::NotFoundPost).map_err(serde::de::Error::custom),
+            Some("app.bsky.feed.defs#postView") => serde_json::from_value(value).map(Self::PostView).map_err(serde::de::Error::custom),
+            _ => Ok(Self::Unknown(value)),
+        }
+    }
 }
 
 
@@ -11,6 +11,8 @@ pub struct Input {
     pub uri: crate::syntax::AtUri,
 }
 
+impl crate::xrpc::JsonBody for Input {}
+
 /// Deletes a private bookmark for the specified record. Currently, only `app.bsky.feed.post` records are supported. Requires authentication.
 pub async fn delete_bookmark(
     client: &impl XrpcClient,
@@ -18,5 +20,18 @@ pub async fn delete_bookmark(
 ) -> Result<XrpcResponse<()>, XrpcError> {
     let req = XrpcRequest::procedure("app.bsky.bookmark.deleteBookmark").data(&input)?;
 
-    client.request(req).await
+    client.request_body(req).await
+}
+
+/// Server-side definition of app.bsky.bookmark.deleteBookmark
+pub struct Endpoint;
+
+impl crate::xrpc::XrpcEndpoint for Endpoint {
+    const NSID: &'static str = "app.bsky.bookmark.deleteBookmark";
+    const METHOD: crate::xrpc::XrpcMethod = crate::xrpc::XrpcMethod::Procedure;
+    const INPUT_ENCODING: Option<&'static str> = Some("application/json");
+    const OUTPUT_ENCODING: Option<&'static str> = None;
+    type Params = ();
+    type Input = Input;
+    type Output = ();
 }
@@ -19,9 +19,11 @@ pub struct QueryParams {
 pub struct Output {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub cursor: Option<String>,
-    pub bookmarks: serde_json::Value,
+    pub bookmarks: Vec<serde_json::Value>,
 }
 
+impl crate::xrpc::JsonBody for Output {}
+
 /// Gets views of records bookmarked by the authenticated user. Requires authentication.
 pub async fn get_bookmarks(
     client: &impl XrpcClient,
@@ -30,18 +32,25 @@ pub async fn get_bookmarks(
     let mut req = XrpcRequest::query("app.bsky.bookmark.getBookmarks");
 
     // Add query parameters
-    let params_json = serde_json::to_value(&params)
-        .map_err(XrpcError::Serialization)?;
-
-    if let Some(obj) = params_json.as_object() {
-        for (key, value) in obj {
-            if let Some(s) = value.as_str() {
-                req.params.insert(key.clone(), s.to_string());
-            } else {
-                req.params.insert(key.clone(), value.to_string());
-            }
-        }
+    if let Some(value) = &params.limit {
+        req.params.append("limit", value.to_string());
+    }
+    if let Some(value) = &params.cursor {
+        req.params.append("cursor", value.to_string());
     }
 
-    client.request(req).await
+    client.request_body(req).await
+}
+
+/// Server-side definition of app.bsky.bookmark.getBookmarks
+pub struct Endpoint;
+
+impl crate::xrpc::XrpcEndpoint for Endpoint {
+    const NSID: &'static str = "app.bsky.bookmark.getBookmarks";
+    const METHOD: crate::xrpc::XrpcMethod = crate::xrpc::XrpcMethod::Query;
+    const INPUT_ENCODING: Option<&'static str> = None;
+    const OUTPUT_ENCODING: Option<&'static str> = Some("application/json");
+    type Params = QueryParams;
+    type Input = ();
+    type Output = Output;
 }
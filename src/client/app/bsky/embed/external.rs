@@ -12,9 +12,9 @@ pub struct Main {
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct External {
-    pub description: String,
-    pub uri: String,
+    pub uri: crate::types::Uri,
     pub title: String,
+    pub description: String,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub thumb: Option<serde_json::Value>,
 }
@@ -28,11 +28,11 @@ pub struct View {
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ViewExternal {
-    pub uri: String,
+    pub uri: crate::types::Uri,
     pub title: String,
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub thumb: Option<String>,
     pub description: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub thumb: Option<crate::types::Uri>,
 }
 
 
@@ -10,35 +10,108 @@ pub struct Main {
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct View {
-    pub record: serde_json::Value,
+    pub record: ViewRecordUnion,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(tag = "$type")]
+pub enum ViewRecordUnion {
+    #[serde(rename = "app.bsky.embed.record#viewRecord")]
+    ViewRecord(Box<ViewRecord>),
+    #[serde(rename = "app.bsky.embed.record#viewNotFound")]
+    ViewNotFound(Box<ViewNotFound>),
+    #[serde(rename = "app.bsky.embed.record#viewBlocked")]
+    ViewBlocked(Box<ViewBlocked>),
+    #[serde(rename = "app.bsky.embed.record#viewDetached")]
+    ViewDetached(Box<ViewDetached>),
+    #[serde(rename = "app.bsky.feed.defs#generatorView")]
+    GeneratorView(Box<crate::client::app::bsky::feed::defs::GeneratorView>),
+    #[serde(rename = "app.bsky.graph.defs#listView")]
+    ListView(Box<crate::client::app::bsky::graph::defs::ListView>),
+    #[serde(rename = "app.bsky.labeler.defs#labelerView")]
+    LabelerView(Box<crate::client::app::bsky::labeler::defs::LabelerView>),
+    #[serde(rename = "app.bsky.graph.defs#starterPackViewBasic")]
+    StarterPackViewBasic(Box<crate::client::app::bsky::graph::defs::StarterPackViewBasic>),
+    /// A type not listed in the lexicon, kept as raw JSON
+    #[serde(untagged)]
+    Unknown(serde_json::Value),
+}
+
+impl<'de> Deserialize<'de> for ViewRecordUnion {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> std::result::Result<Self, D::Error> {
+        let value = serde_json::Value::deserialize(deserializer)?;
+        match value.get("$type").and_then(|t| t.as_str()) {
+            Some("app.bsky.embed.record#viewRecord") => serde_json::from_value(value).map(Self::ViewRecord).map_err(serde::de::Error::custom),
+            Some("app.bsky.embed.record#viewNotFound") => serde_json::from_value(value).map(Self::ViewNotFound).map_err(serde::de::Error::custom),
+            Some("app.bsky.embed.record#viewBlocked") => serde_json::from_value(value).map(Self::ViewBlocked).map_err(serde::de::Error::custom),
+            Some("app.bsky.embed.record#viewDetached") => serde_json::from_value(value).map(Self::ViewDetached).map_err(serde::de::Error::custom),
+            Some("app.bsky.feed.defs#generatorView") => serde_json::from_value(value).map(Self::GeneratorView).map_err(serde::de::Error::custom),
+            Some("app.bsky.graph.defs#listView") => serde_json::from_value(value).map(Self::ListView).map_err(serde::de::Error::custom),
+            Some("app.bsky.labeler.defs#labelerView") => serde_json::from_value(value).map(Self::LabelerView).map_err(serde::de::Error::custom),
+            Some("app.bsky.graph.defs#starterPackViewBasic") => serde_json::from_value(value).map(Self::StarterPackViewBasic).map_err(serde::de::Error::custom),
+            _ => Ok(Self::Unknown(value)),
+        }
+    }
 }
 
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ViewRecord {
-    #[serde(skip_serializing_if = "Option::is_none")]
-    #[serde(rename = "repostCount")]
-    pub repost_count: Option<i64>,
+    pub uri: crate::syntax::AtUri,
+    pub cid: crate::types::CidLink,
+    pub author: serde_json::Value,
     /// The record data itself.
     pub value: serde_json::Value,
-    pub cid: String,
-    #[serde(skip_serializing_if = "Option::is_none")]
-    #[serde(rename = "quoteCount")]
-    pub quote_count: Option<i64>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub labels: Option<serde_json::Value>,
-    pub uri: crate::syntax::AtUri,
+    pub labels: Option<Vec<serde_json::Value>>,
     #[serde(skip_serializing_if = "Option::is_none")]
     #[serde(rename = "replyCount")]
     pub reply_count: Option<i64>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub embeds: Option<serde_json::Value>,
-    #[serde(rename = "indexedAt")]
-    pub indexed_at: String,
+    #[serde(rename = "repostCount")]
+    pub repost_count: Option<i64>,
     #[serde(skip_serializing_if = "Option::is_none")]
     #[serde(rename = "likeCount")]
     pub like_count: Option<i64>,
-    pub author: serde_json::Value,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(rename = "quoteCount")]
+    pub quote_count: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub embeds: Option<Vec<ViewRecordEmbeds>>,
+    #[serde(rename = "indexedAt")]
+    pub indexed_at: crate::types::Datetime,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(tag = "$type")]
+pub enum ViewRecordEmbeds {
+    #[serde(rename = "app.bsky.embed.images#view")]
+    ImagesView(Box<crate::client::app::bsky::embed::images::View>),
+    #[serde(rename = "app.bsky.embed.video#view")]
+    VideoView(Box<crate::client::app::bsky::embed::video::View>),
+    #[serde(rename = "app.bsky.embed.external#view")]
+    ExternalView(Box<crate::client::app::bsky::embed::external::View>),
+    #[serde(rename = "app.bsky.embed.record#view")]
+    View(Box<View>),
+    #[serde(rename = "app.bsky.embed.recordWithMedia#view")]
+    RecordWithMediaView(Box<crate::client::app::bsky::embed::record_with_media::View>),
+    /// A type not listed in the lexicon, kept as raw JSON
+    #[serde(untagged)]
+    Unknown(serde_json::Value),
+}
+
+impl<'de> Deserialize<'de> for ViewRecordEmbeds {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> std::result::Result<Self, D::Error> {
+        let value = serde_json::Value::deserialize(deserializer)?;
+        match value.get("$type").and_then(|t| t.as_str()) {
+            Some("app.bsky.embed.images#view") => serde_json::from_value(value).map(Self::ImagesView).map_err(serde::de::Error::custom),
+            Some("app.bsky.embed.video#view") => serde_json::from_value(value).map(Self::VideoView).map_err(serde::de::Error::custom),
+            Some("app.bsky.embed.external#view") => serde_json::from_value(value).map(Self::ExternalView).map_err(serde::de::Error::custom),
+            Some("app.bsky.embed.record#view") => serde_json::from_value(value).map(Self::View).map_err(serde::de::Error::custom),
+            Some("app.bsky.embed.recordWithMedia#view") => serde_json::from_value(value).map(Self::RecordWithMediaView).map_err(serde::de::Error::custom),
+            _ => Ok(Self::Unknown(value)),
+        }
+    }
 }
 
 
@@ -52,16 +125,16 @@ pub struct ViewNotFound {
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ViewBlocked {
-    pub author: serde_json::Value,
     pub uri: crate::syntax::AtUri,
     pub blocked: bool,
+    pub author: serde_json::Value,
 }
 
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ViewDetached {
-    pub detached: bool,
     pub uri: crate::syntax::AtUri,
+    pub detached: bool,
 }
 
 
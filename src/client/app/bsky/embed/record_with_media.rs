@@ -5,14 +5,66 @@ use serde::{Deserialize, Serialize};
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Main {
     pub record: serde_json::Value,
-    pub media: serde_json::Value,
+    pub media: MainMedia,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(tag = "$type")]
+pub enum MainMedia {
+    #[serde(rename = "app.bsky.embed.images")]
+    Images(Box<crate::client::app::bsky::embed::images::Main>),
+    #[serde(rename = "app.bsky.embed.video")]
+    Video(Box<crate::client::app::bsky::embed::video::Main>),
+    #[serde(rename = "app.bsky.embed.external")]
+    External(Box<crate::client::app::bsky::embed::external::Main>),
+    /// A type not listed in the lexicon, kept as raw JSON
+    #[serde(untagged)]
+    Unknown(serde_json::Value),
+}
+
+impl<'de> Deserialize<'de> for MainMedia {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> std::result::Result<Self, D::Error> {
+        let value = serde_json::Value::deserialize(deserializer)?;
+        match value.get("$type").and_then(|t| t.as_str()) {
+            Some("app.bsky.embed.images") => serde_json::from_value(value).map(Self::Images).map_err(serde::de::Error::custom),
+            Some("app.bsky.embed.video") => serde_json::from_value(value).map(Self::Video).map_err(serde::de::Error::custom),
+            Some("app.bsky.embed.external") => serde_json::from_value(value).map(Self::External).map_err(serde::de::Error::custom),
+            _ => Ok(Self::Unknown(value)),
+        }
+    }
 }
 
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct View {
     pub record: serde_json::Value,
-    pub media: serde_json::Value,
+    pub media: ViewMedia,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(tag = "$type")]
+pub enum ViewMedia {
+    #[serde(rename = "app.bsky.embed.images#view")]
+    ImagesView(Box<crate::client::app::bsky::embed::images::View>),
+    #[serde(rename = "app.bsky.embed.video#view")]
+    VideoView(Box<crate::client::app::bsky::embed::video::View>),
+    #[serde(rename = "app.bsky.embed.external#view")]
+    ExternalView(Box<crate::client::app::bsky::embed::external::View>),
+    /// A type not listed in the lexicon, kept as raw JSON
+    #[serde(untagged)]
+    Unknown(serde_json::Value),
+}
+
+impl<'de> Deserialize<'de> for ViewMedia {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> std::result::Result<Self, D::Error> {
+        let value = serde_json::Value::deserialize(deserializer)?;
+        match value.get("$type").and_then(|t| t.as_str()) {
+            Some("app.bsky.embed.images#view") => serde_json::from_value(value).map(Self::ImagesView).map_err(serde::de::Error::custom),
+            Some("app.bsky.embed.video#view") => serde_json::from_value(value).map(Self::VideoView).map_err(serde::de::Error::custom),
+            Some("app.bsky.embed.external#view") => serde_json::from_value(value).map(Self::ExternalView).map_err(serde::de::Error::custom),
+            _ => Ok(Self::Unknown(value)),
+        }
+    }
 }
 
 
@@ -4,34 +4,34 @@ use serde::{Deserialize, Serialize};
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Main {
-    #[serde(skip_serializing_if = "Option::is_none")]
-    #[serde(rename = "aspectRatio")]
-    pub aspect_ratio: Option<serde_json::Value>,
     /// The mp4 video file. May be up to 100mb, formerly limited to 50mb.
     pub video: serde_json::Value,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub captions: Option<Vec<serde_json::Value>>,
     /// Alt text description of the video, for accessibility.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub alt: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub captions: Option<serde_json::Value>,
+    #[serde(rename = "aspectRatio")]
+    pub aspect_ratio: Option<serde_json::Value>,
 }
 
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Caption {
+    pub lang: crate::types::Language,
     pub file: serde_json::Value,
-    pub lang: String,
 }
 
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct View {
-    pub cid: String,
-    pub playlist: String,
+    pub cid: crate::types::CidLink,
+    pub playlist: crate::types::Uri,
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub alt: Option<String>,
+    pub thumbnail: Option<crate::types::Uri>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub thumbnail: Option<String>,
+    pub alt: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     #[serde(rename = "aspectRatio")]
     pub aspect_ratio: Option<serde_json::Value>,
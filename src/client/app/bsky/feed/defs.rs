@@ -2,140 +2,99 @@
 
 use serde::{Deserialize, Serialize};
 
-/// User reposted the feed item
-#[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct InteractionRepost;
-
-/// Declares the feed generator returns posts containing app.bsky.embed.video embeds.
-#[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct ContentModeVideo;
-
-/// User clicked through to the reposter of the feed item
-#[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct ClickthroughReposter;
-
 #[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct GeneratorView {
-    #[serde(rename = "indexedAt")]
-    pub indexed_at: String,
-    #[serde(rename = "displayName")]
-    pub display_name: String,
-    pub cid: String,
-    pub creator: serde_json::Value,
-    #[serde(skip_serializing_if = "Option::is_none")]
-    #[serde(rename = "descriptionFacets")]
-    pub description_facets: Option<serde_json::Value>,
-    #[serde(skip_serializing_if = "Option::is_none")]
-    #[serde(rename = "acceptsInteractions")]
-    pub accepts_interactions: Option<bool>,
+pub struct PostView {
+    pub uri: crate::syntax::AtUri,
+    pub cid: crate::types::CidLink,
+    pub author: serde_json::Value,
+    pub record: serde_json::Value,
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub avatar: Option<String>,
+    pub embed: Option<PostViewEmbed>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub description: Option<String>,
+    #[serde(rename = "bookmarkCount")]
+    pub bookmark_count: Option<i64>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub labels: Option<serde_json::Value>,
+    #[serde(rename = "replyCount")]
+    pub reply_count: Option<i64>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub viewer: Option<serde_json::Value>,
+    #[serde(rename = "repostCount")]
+    pub repost_count: Option<i64>,
     #[serde(skip_serializing_if = "Option::is_none")]
     #[serde(rename = "likeCount")]
     pub like_count: Option<i64>,
-    pub uri: crate::syntax::AtUri,
-    pub did: crate::types::Did,
     #[serde(skip_serializing_if = "Option::is_none")]
-    #[serde(rename = "contentMode")]
-    pub content_mode: Option<String>,
-}
-
-
-/// User clicked through to the feed item
-#[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct ClickthroughItem;
-
-/// Declares the feed generator returns any types of posts.
-#[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct ContentModeUnspecified;
-
-#[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct BlockedAuthor {
-    pub did: crate::types::Did,
+    #[serde(rename = "quoteCount")]
+    pub quote_count: Option<i64>,
+    #[serde(rename = "indexedAt")]
+    pub indexed_at: crate::types::Datetime,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub viewer: Option<serde_json::Value>,
-}
-
-
-/// Request that more content like the given feed item be shown in the feed
-#[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct RequestMore;
-
-/// User replied to the feed item
-#[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct InteractionReply;
-
-#[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct SkeletonReasonPin {
-}
-
-
-#[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct ReasonRepost {
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub uri: Option<crate::syntax::AtUri>,
-    pub by: serde_json::Value,
+    pub labels: Option<Vec<serde_json::Value>>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub cid: Option<String>,
-    #[serde(rename = "indexedAt")]
-    pub indexed_at: String,
+    pub threadgate: Option<serde_json::Value>,
 }
 
-
-#[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct ThreadgateView {
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub cid: Option<String>,
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub uri: Option<crate::syntax::AtUri>,
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub lists: Option<serde_json::Value>,
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub record: Option<serde_json::Value>,
+#[derive(Debug, Clone, Serialize)]
+#[serde(tag = "$type")]
+pub enum PostViewEmbed {
+    #[serde(rename = "app.bsky.embed.images#view")]
+    ImagesView(Box<crate::client::app::bsky::embed::images::View>),
+    #[serde(rename = "app.bsky.embed.video#view")]
+    VideoView(Box<crate::client::app::bsky::embed::video::View>),
+    #[serde(rename = "app.bsky.embed.external#view")]
+    ExternalView(Box<crate::client::app::bsky::embed::external::View>),
+    #[serde(rename = "app.bsky.embed.record#view")]
+    RecordView(Box<crate::client::app::bsky::embed::record::View>),
+    #[serde(rename = "app.bsky.embed.recordWithMedia#view")]
+    RecordWithMediaView(Box<crate::client::app::bsky::embed::record_with_media::View>),
+    /// A type not listed in the lexicon, kept as raw JSON
+    #[serde(untagged)]
+    Unknown(serde_json::Value),
 }
 
+impl<'de> Deserialize<'de> for PostViewEmbed {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> std::result::Result<Self, D::Error> {
+        let value = serde_json::Value::deserialize(deserializer)?;
+        match value.get("$type").and_then(|t| t.as_str()) {
+            Some("app.bsky.embed.images#view") => serde_json::from_value(value).map(Self::ImagesView).map_err(serde::de::Error::custom),
+            Some("app.bsky.embed.video#view") => serde_json::from_value(value).map(Self::VideoView).map_err(serde::de::Error::custom),
+            Some("app.bsky.embed.external#view") => serde_json::from_value(value).map(Self::ExternalView).map_err(serde::de::Error::custom),
+            Some("app.bsky.embed.record#view") => serde_json::from_value(value).map(Self::RecordView).map_err(serde::de::Error::custom),
+            Some("app.bsky.embed.recordWithMedia#view") => serde_json::from_value(value).map(Self::RecordWithMediaView).map_err(serde::de::Error::custom),
+            _ => Ok(Self::Unknown(value)),
+        }
+    }
+}
 
-/// User clicked through to the author of the feed item
-#[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct ClickthroughAuthor;
-
-/// Feed item was seen by user
-#[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct InteractionSeen;
 
 /// Metadata about the requesting account's relationship with the subject content. Only has meaningful content for authed requests.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ViewerState {
+    /// Metadata about the requesting account's relationship with the subject content. Only has meaningful content for authed requests.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub repost: Option<crate::syntax::AtUri>,
     /// Metadata about the requesting account's relationship with the subject content. Only has meaningful content for authed requests.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub like: Option<crate::syntax::AtUri>,
     /// Metadata about the requesting account's relationship with the subject content. Only has meaningful content for authed requests.
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub pinned: Option<bool>,
+    pub bookmarked: Option<bool>,
     /// Metadata about the requesting account's relationship with the subject content. Only has meaningful content for authed requests.
     #[serde(skip_serializing_if = "Option::is_none")]
     #[serde(rename = "threadMuted")]
     pub thread_muted: Option<bool>,
     /// Metadata about the requesting account's relationship with the subject content. Only has meaningful content for authed requests.
     #[serde(skip_serializing_if = "Option::is_none")]
-    #[serde(rename = "embeddingDisabled")]
-    pub embedding_disabled: Option<bool>,
-    /// Metadata about the requesting account's relationship with the subject content. Only has meaningful content for authed requests.
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub bookmarked: Option<bool>,
-    /// Metadata about the requesting account's relationship with the subject content. Only has meaningful content for authed requests.
-    #[serde(skip_serializing_if = "Option::is_none")]
     #[serde(rename = "replyDisabled")]
     pub reply_disabled: Option<bool>,
     /// Metadata about the requesting account's relationship with the subject content. Only has meaningful content for authed requests.
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub repost: Option<crate::syntax::AtUri>,
+    #[serde(rename = "embeddingDisabled")]
+    pub embedding_disabled: Option<bool>,
+    /// Metadata about the requesting account's relationship with the subject content. Only has meaningful content for authed requests.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub pinned: Option<bool>,
 }
 
 
@@ -150,117 +109,287 @@ pub struct ThreadContext {
 
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct ThreadViewPost {
-    #[serde(skip_serializing_if = "Option::is_none")]
-    #[serde(rename = "threadContext")]
-    pub thread_context: Option<serde_json::Value>,
+pub struct FeedViewPost {
     pub post: serde_json::Value,
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub parent: Option<serde_json::Value>,
+    pub reply: Option<serde_json::Value>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub replies: Option<serde_json::Value>,
+    pub reason: Option<FeedViewPostReason>,
+    /// Context provided by feed generator that may be passed back alongside interactions.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(rename = "feedContext")]
+    pub feed_context: Option<String>,
+    /// Unique identifier per request that may be passed back alongside interactions.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(rename = "reqId")]
+    pub req_id: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(tag = "$type")]
+pub enum FeedViewPostReason {
+    #[serde(rename = "app.bsky.feed.defs#reasonRepost")]
+    ReasonRepost(Box<ReasonRepost>),
+    #[serde(rename = "app.bsky.feed.defs#reasonPin")]
+    ReasonPin(Box<ReasonPin>),
+    /// A type not listed in the lexicon, kept as raw JSON
+    #[serde(untagged)]
+    Unknown(serde_json::Value),
+}
+
+impl<'de> Deserialize<'de> for FeedViewPostReason {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> std::result::Result<Self, D::Error> {
+        let value = serde_json::Value::deserialize(deserializer)?;
+        match value.get("$type").and_then(|t| t.as_str()) {
+            Some("app.bsky.feed.defs#reasonRepost") => serde_json::from_value(value).map(Self::ReasonRepost).map_err(serde::de::Error::custom),
+            Some("app.bsky.feed.defs#reasonPin") => serde_json::from_value(value).map(Self::ReasonPin).map_err(serde::de::Error::custom),
+            _ => Ok(Self::Unknown(value)),
+        }
+    }
 }
 
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct PostView {
-    pub uri: crate::syntax::AtUri,
-    #[serde(skip_serializing_if = "Option::is_none")]
-    #[serde(rename = "bookmarkCount")]
-    pub bookmark_count: Option<i64>,
-    pub cid: String,
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub embed: Option<serde_json::Value>,
-    #[serde(skip_serializing_if = "Option::is_none")]
-    #[serde(rename = "replyCount")]
-    pub reply_count: Option<i64>,
-    #[serde(skip_serializing_if = "Option::is_none")]
-    #[serde(rename = "repostCount")]
-    pub repost_count: Option<i64>,
-    #[serde(skip_serializing_if = "Option::is_none")]
-    #[serde(rename = "quoteCount")]
-    pub quote_count: Option<i64>,
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub viewer: Option<serde_json::Value>,
+pub struct ReplyRef {
+    pub root: ReplyRefRoot,
+    pub parent: ReplyRefParent,
+    /// When parent is a reply to another post, this is the author of that post.
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub labels: Option<serde_json::Value>,
+    #[serde(rename = "grandparentAuthor")]
+    pub grandparent_author: Option<serde_json::Value>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(tag = "$type")]
+pub enum ReplyRefRoot {
+    #[serde(rename = "app.bsky.feed.defs#postView")]
+    PostView(Box<PostView>),
+    #[serde(rename = "app.bsky.feed.defs#notFoundPost")]
+    NotFoundPost(Box<NotFoundPost>),
+    #[serde(rename = "app.bsky.feed.defs#blockedPost")]
+    BlockedPost(Box<BlockedPost>),
+    /// A type not listed in the lexicon, kept as raw JSON
+    #[serde(untagged)]
+    Unknown(serde_json::Value),
+}
+
+impl<'de> Deserialize<'de> for ReplyRefRoot {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> std::result::Result<Self, D::Error> {
+        let value = serde_json::Value::deserialize(deserializer)?;
+        match value.get("$type").and_then(|t| t.as_str()) {
+            Some("app.bsky.feed.defs#postView") => serde_json::from_value(value).map(Self::PostView).map_err(serde::de::Error::custom),
+            Some("app.bsky.feed.defs#notFoundPost") => serde_json::from_value(value).map(Self::NotFoundPost).map_err(serde::de::Error::custom),
+            Some("app.bsky.feed.defs#blockedPost") => serde_json::from_value(value).map(Self::BlockedPost).map_err(serde::de::Error::custom),
+            _ => Ok(Self::Unknown(value)),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(tag = "$type")]
+pub enum ReplyRefParent {
+    #[serde(rename = "app.bsky.feed.defs#postView")]
+    PostView(Box<PostView>),
+    #[serde(rename = "app.bsky.feed.defs#notFoundPost")]
+    NotFoundPost(Box<NotFoundPost>),
+    #[serde(rename = "app.bsky.feed.defs#blockedPost")]
+    BlockedPost(Box<BlockedPost>),
+    /// A type not listed in the lexicon, kept as raw JSON
+    #[serde(untagged)]
+    Unknown(serde_json::Value),
+}
+
+impl<'de> Deserialize<'de> for ReplyRefParent {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> std::result::Result<Self, D::Error> {
+        let value = serde_json::Value::deserialize(deserializer)?;
+        match value.get("$type").and_then(|t| t.as_str()) {
+            Some("app.bsky.feed.defs#postView") => serde_json::from_value(value).map(Self::PostView).map_err(serde::de::Error::custom),
+            Some("app.bsky.feed.defs#notFoundPost") => serde_json::from_value(value).map(Self::NotFoundPost).map_err(serde::de::Error::custom),
+            Some("app.bsky.feed.defs#blockedPost") => serde_json::from_value(value).map(Self::BlockedPost).map_err(serde::de::Error::custom),
+            _ => Ok(Self::Unknown(value)),
+        }
+    }
+}
+
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ReasonRepost {
+    pub by: serde_json::Value,
     #[serde(skip_serializing_if = "Option::is_none")]
-    #[serde(rename = "likeCount")]
-    pub like_count: Option<i64>,
-    pub record: serde_json::Value,
+    pub uri: Option<crate::syntax::AtUri>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub threadgate: Option<serde_json::Value>,
+    pub cid: Option<crate::types::CidLink>,
     #[serde(rename = "indexedAt")]
-    pub indexed_at: String,
-    pub author: serde_json::Value,
+    pub indexed_at: crate::types::Datetime,
 }
 
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct Interaction {
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub item: Option<crate::syntax::AtUri>,
-    /// Context on a feed item that was originally supplied by the feed generator on getFeedSkeleton.
+pub struct ReasonPin {
+}
+
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ThreadViewPost {
+    pub post: serde_json::Value,
     #[serde(skip_serializing_if = "Option::is_none")]
-    #[serde(rename = "feedContext")]
-    pub feed_context: Option<String>,
+    pub parent: Option<ThreadViewPostParent>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub event: Option<String>,
-    /// Unique identifier per request that may be passed back alongside interactions.
+    pub replies: Option<Vec<ThreadViewPostReplies>>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    #[serde(rename = "reqId")]
-    pub req_id: Option<String>,
+    #[serde(rename = "threadContext")]
+    pub thread_context: Option<serde_json::Value>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(tag = "$type")]
+pub enum ThreadViewPostParent {
+    #[serde(rename = "app.bsky.feed.defs#threadViewPost")]
+    ThreadViewPost(Box<ThreadViewPost>),
+    #[serde(rename = "app.bsky.feed.defs#notFoundPost")]
+    NotFoundPost(Box<NotFoundPost>),
+    #[serde(rename = "app.bsky.feed.defs#blockedPost")]
+    BlockedPost(Box<BlockedPost>),
+    /// A type not listed in the lexicon, kept as raw JSON
+    #[serde(untagged)]
+    Unknown(serde_json::Value),
+}
+
+impl<'de> Deserialize<'de> for ThreadViewPostParent {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> std::result::Result<Self, D::Error> {
+        let value = serde_json::Value::deserialize(deserializer)?;
+        match value.get("$type").and_then(|t| t.as_str()) {
+            Some("app.bsky.feed.defs#threadViewPost") => serde_json::from_value(value).map(Self::ThreadViewPost).map_err(serde::de::Error::custom),
+            Some("app.bsky.feed.defs#notFoundPost") => serde_json::from_value(value).map(Self::NotFoundPost).map_err(serde::de::Error::custom),
+            Some("app.bsky.feed.defs#blockedPost") => serde_json::from_value(value).map(Self::BlockedPost).map_err(serde::de::Error::custom),
+            _ => Ok(Self::Unknown(value)),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(tag = "$type")]
+pub enum ThreadViewPostReplies {
+    #[serde(rename = "app.bsky.feed.defs#threadViewPost")]
+    ThreadViewPost(Box<ThreadViewPost>),
+    #[serde(rename = "app.bsky.feed.defs#notFoundPost")]
+    NotFoundPost(Box<NotFoundPost>),
+    #[serde(rename = "app.bsky.feed.defs#blockedPost")]
+    BlockedPost(Box<BlockedPost>),
+    /// A type not listed in the lexicon, kept as raw JSON
+    #[serde(untagged)]
+    Unknown(serde_json::Value),
+}
+
+impl<'de> Deserialize<'de> for ThreadViewPostReplies {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> std::result::Result<Self, D::Error> {
+        let value = serde_json::Value::deserialize(deserializer)?;
+        match value.get("$type").and_then(|t| t.as_str()) {
+            Some("app.bsky.feed.defs#threadViewPost") => serde_json::from_value(value).map(Self::ThreadViewPost).map_err(serde::de::Error::custom),
+            Some("app.bsky.feed.defs#notFoundPost") => serde_json::from_value(value).map(Self::NotFoundPost).map_err(serde::de::Error::custom),
+            Some("app.bsky.feed.defs#blockedPost") => serde_json::from_value(value).map(Self::BlockedPost).map_err(serde::de::Error::custom),
+            _ => Ok(Self::Unknown(value)),
+        }
+    }
 }
 
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct NotFoundPost {
+    pub uri: crate::syntax::AtUri,
     #[serde(rename = "notFound")]
     pub not_found: bool,
-    pub uri: crate::syntax::AtUri,
 }
 
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct GeneratorViewerState {
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub like: Option<crate::syntax::AtUri>,
+pub struct BlockedPost {
+    pub uri: crate::syntax::AtUri,
+    pub blocked: bool,
+    pub author: serde_json::Value,
 }
 
 
-/// User clicked through to the embedded content of the feed item
 #[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct ClickthroughEmbed;
+pub struct BlockedAuthor {
+    pub did: crate::types::Did,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub viewer: Option<serde_json::Value>,
+}
 
-/// User quoted the feed item
-#[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct InteractionQuote;
 
-/// User liked the feed item
 #[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct InteractionLike;
+pub struct GeneratorView {
+    pub uri: crate::syntax::AtUri,
+    pub cid: crate::types::CidLink,
+    pub did: crate::types::Did,
+    pub creator: serde_json::Value,
+    #[serde(rename = "displayName")]
+    pub display_name: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(rename = "descriptionFacets")]
+    pub description_facets: Option<Vec<serde_json::Value>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub avatar: Option<crate::types::Uri>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(rename = "likeCount")]
+    pub like_count: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(rename = "acceptsInteractions")]
+    pub accepts_interactions: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub labels: Option<Vec<serde_json::Value>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub viewer: Option<serde_json::Value>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(rename = "contentMode")]
+    pub content_mode: Option<String>,
+    #[serde(rename = "indexedAt")]
+    pub indexed_at: crate::types::Datetime,
+}
 
-/// Request that less content like the given feed item be shown in the feed
-#[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct RequestLess;
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct BlockedPost {
-    pub author: serde_json::Value,
-    pub blocked: bool,
-    pub uri: crate::syntax::AtUri,
+pub struct GeneratorViewerState {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub like: Option<crate::syntax::AtUri>,
 }
 
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct SkeletonFeedPost {
+    pub post: crate::syntax::AtUri,
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub reason: Option<serde_json::Value>,
+    pub reason: Option<SkeletonFeedPostReason>,
     /// Context that will be passed through to client and may be passed to feed generator back alongside interactions.
     #[serde(skip_serializing_if = "Option::is_none")]
     #[serde(rename = "feedContext")]
     pub feed_context: Option<String>,
-    pub post: crate::syntax::AtUri,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(tag = "$type")]
+pub enum SkeletonFeedPostReason {
+    #[serde(rename = "app.bsky.feed.defs#skeletonReasonRepost")]
+    SkeletonReasonRepost(Box<SkeletonReasonRepost>),
+    #[serde(rename = "app.bsky.feed.defs#skeletonReasonPin")]
+    SkeletonReasonPin(Box<SkeletonReasonPin>),
+    /// A type not listed in the lexicon, kept as raw JSON
+    #[serde(untagged)]
+    Unknown(serde_json::Value),
+}
+
+impl<'de> Deserialize<'de> for SkeletonFeedPostReason {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> std::result::Result<Self, D::Error> {
+        let value = serde_json::Value::deserialize(deserializer)?;
+        match value.get("$type").and_then(|t| t.as_str()) {
+            Some("app.bsky.feed.defs#skeletonReasonRepost") => serde_json::from_value(value).map(Self::SkeletonReasonRepost).map_err(serde::de::Error::custom),
+            Some("app.bsky.feed.defs#skeletonReasonPin") => serde_json::from_value(value).map(Self::SkeletonReasonPin).map_err(serde::de::Error::custom),
+            _ => Ok(Self::Unknown(value)),
+        }
+    }
 }
 
 
@@ -271,16 +400,33 @@ pub struct SkeletonReasonRepost {
 
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct FeedViewPost {
+pub struct SkeletonReasonPin {
+}
+
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ThreadgateView {
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub reason: Option<serde_json::Value>,
-    /// Context provided by feed generator that may be passed back alongside interactions.
+    pub uri: Option<crate::syntax::AtUri>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cid: Option<crate::types::CidLink>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub record: Option<serde_json::Value>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub lists: Option<Vec<serde_json::Value>>,
+}
+
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Interaction {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub item: Option<crate::syntax::AtUri>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub event: Option<String>,
+    /// Context on a feed item that was originally supplied by the feed generator on getFeedSkeleton.
     #[serde(skip_serializing_if = "Option::is_none")]
     #[serde(rename = "feedContext")]
     pub feed_context: Option<String>,
-    pub post: serde_json::Value,
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub reply: Option<serde_json::Value>,
     /// Unique identifier per request that may be passed back alongside interactions.
     #[serde(skip_serializing_if = "Option::is_none")]
     #[serde(rename = "reqId")]
@@ -288,23 +434,59 @@ pub struct FeedViewPost {
 }
 
 
-/// User shared the feed item
+/// Request that less content like the given feed item be shown in the feed
 #[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct InteractionShare;
+pub struct RequestLess;
 
+/// Request that more content like the given feed item be shown in the feed
 #[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct ReasonPin {
-}
+pub struct RequestMore;
 
+/// User clicked through to the feed item
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ClickthroughItem;
 
+/// User clicked through to the author of the feed item
 #[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct ReplyRef {
-    /// When parent is a reply to another post, this is the author of that post.
-    #[serde(skip_serializing_if = "Option::is_none")]
-    #[serde(rename = "grandparentAuthor")]
-    pub grandparent_author: Option<serde_json::Value>,
-    pub parent: serde_json::Value,
-    pub root: serde_json::Value,
-}
+pub struct ClickthroughAuthor;
+
+/// User clicked through to the reposter of the feed item
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ClickthroughReposter;
+
+/// User clicked through to the embedded content of the feed item
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ClickthroughEmbed;
+
+/// Declares the feed generator returns any types of posts.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ContentModeUnspecified;
 
+/// Declares the feed generator returns posts containing app.bsky.embed.video embeds.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ContentModeVideo;
+
+/// Feed item was seen by user
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct InteractionSeen;
+
+/// User liked the feed item
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct InteractionLike;
+
+/// User reposted the feed item
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct InteractionRepost;
+
+/// User replied to the feed item
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct InteractionReply;
+
+/// User quoted the feed item
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct InteractionQuote;
+
+/// User shared the feed item
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct InteractionShare;
 
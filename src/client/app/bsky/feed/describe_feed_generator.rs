@@ -8,10 +8,27 @@ use serde::{Deserialize, Serialize};
 /// Response output
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Output {
+    pub did: crate::types::Did,
+    pub feeds: Vec<serde_json::Value>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub links: Option<serde_json::Value>,
-    pub feeds: serde_json::Value,
-    pub did: crate::types::Did,
+}
+
+impl crate::xrpc::JsonBody for Output {}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Feed {
+    pub uri: crate::syntax::AtUri,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Links {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(rename = "privacyPolicy")]
+    pub privacy_policy: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(rename = "termsOfService")]
+    pub terms_of_service: Option<String>,
 }
 
 /// Get information about a feed generator, including policies and offered feed URIs. Does not require auth; implemented by Feed Generator services (not App View).
@@ -20,5 +37,18 @@ pub async fn describe_feed_generator(
 ) -> Result<XrpcResponse<Output>, XrpcError> {
     let req = XrpcRequest::query("app.bsky.feed.describeFeedGenerator");
 
-    client.request(req).await
+    client.request_body(req).await
+}
+
+/// Server-side definition of app.bsky.feed.describeFeedGenerator
+pub struct Endpoint;
+
+impl crate::xrpc::XrpcEndpoint for Endpoint {
+    const NSID: &'static str = "app.bsky.feed.describeFeedGenerator";
+    const METHOD: crate::xrpc::XrpcMethod = crate::xrpc::XrpcMethod::Query;
+    const INPUT_ENCODING: Option<&'static str> = None;
+    const OUTPUT_ENCODING: Option<&'static str> = Some("application/json");
+    type Params = ();
+    type Input = ();
+    type Output = Output;
 }
@@ -8,26 +8,47 @@ use serde::{Deserialize, Serialize};
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Generator {
     pub did: crate::types::Did,
+    #[serde(rename = "displayName")]
+    pub display_name: String,
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub avatar: Option<serde_json::Value>,
-    #[serde(rename = "createdAt")]
-    pub created_at: String,
+    pub description: Option<String>,
     #[serde(rename = "descriptionFacets")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub description_facets: Option<serde_json::Value>,
+    pub description_facets: Option<Vec<serde_json::Value>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub avatar: Option<serde_json::Value>,
     /// Declaration that a feed accepts feedback interactions from a client through app.bsky.feed.sendInteractions
     #[serde(rename = "acceptsInteractions")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub accepts_interactions: Option<bool>,
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub description: Option<String>,
-    #[serde(rename = "displayName")]
-    pub display_name: String,
     /// Self-label values
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub labels: Option<serde_json::Value>,
+    pub labels: Option<GeneratorLabels>,
     #[serde(rename = "contentMode")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub content_mode: Option<String>,
+    #[serde(rename = "createdAt")]
+    pub created_at: crate::types::Datetime,
+}
+
+/// Self-label values
+#[derive(Debug, Clone, Serialize)]
+#[serde(tag = "$type")]
+pub enum GeneratorLabels {
+    #[serde(rename = "com.atproto.label.defs#selfLabels")]
+    SelfLabels(Box<crate::client::com::atproto::label::defs::SelfLabels>),
+    /// A type not listed in the lexicon, kept as raw JSON
+    #[serde(untagged)]
+    Unknown(serde_json::Value),
+}
+
+impl<'de> Deserialize<'de> for GeneratorLabels {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> std::result::Result<Self, D::Error> {
+        let value = serde_json::Value::deserialize(deserializer)?;
+        match value.get("$type").and_then(|t| t.as_str()) {
+            Some("com.atproto.label.defs#selfLabels") => serde_json::from_value(value).map(Self::SelfLabels).map_err(serde::de::Error::custom),
+            _ => Ok(Self::Unknown(value)),
+        }
+    }
 }
 
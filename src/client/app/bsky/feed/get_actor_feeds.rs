@@ -8,21 +8,23 @@ use serde::{Deserialize, Serialize};
 /// Query parameters
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct QueryParams {
+    pub actor: String,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub limit: Option<i64>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub cursor: Option<String>,
-    pub actor: String,
 }
 
 /// Response output
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Output {
-    pub feeds: serde_json::Value,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub cursor: Option<String>,
+    pub feeds: Vec<serde_json::Value>,
 }
 
+impl crate::xrpc::JsonBody for Output {}
+
 /// Get a list of feeds (feed generator records) created by the actor (in the actor's repo).
 pub async fn get_actor_feeds(
     client: &impl XrpcClient,
@@ -31,18 +33,26 @@ pub async fn get_actor_feeds(
     let mut req = XrpcRequest::query("app.bsky.feed.getActorFeeds");
 
     // Add query parameters
-    let params_json = serde_json::to_value(&params)
-        .map_err(XrpcError::Serialization)?;
-
-    if let Some(obj) = params_json.as_object() {
-        for (key, value) in obj {
-            if let Some(s) = value.as_str() {
-                req.params.insert(key.clone(), s.to_string());
-            } else {
-                req.params.insert(key.clone(), value.to_string());
-            }
-        }
+    req.params.append("actor", params.actor.to_string());
+    if let Some(value) = &params.limit {
+        req.params.append("limit", value.to_string());
+    }
+    if let Some(value) = &params.cursor {
+        req.params.append("cursor", value.to_string());
     }
 
-    client.request(req).await
+    client.request_body(req).await
+}
+
+/// Server-side definition of app.bsky.feed.getActorFeeds
+pub struct Endpoint;
+
+impl crate::xrpc::XrpcEndpoint for Endpoint {
+    const NSID: &'static str = "app.bsky.feed.getActorFeeds";
+    const METHOD: crate::xrpc::XrpcMethod = crate::xrpc::XrpcMethod::Query;
+    const INPUT_ENCODING: Option<&'static str> = None;
+    const OUTPUT_ENCODING: Option<&'static str> = Some("application/json");
+    type Params = QueryParams;
+    type Input = ();
+    type Output = Output;
 }
@@ -8,9 +8,9 @@ use serde::{Deserialize, Serialize};
 /// Query parameters
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct QueryParams {
+    pub actor: String,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub limit: Option<i64>,
-    pub actor: String,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub cursor: Option<String>,
 }
@@ -18,11 +18,13 @@ pub struct QueryParams {
 /// Response output
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Output {
-    pub feed: serde_json::Value,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub cursor: Option<String>,
+    pub feed: Vec<serde_json::Value>,
 }
 
+impl crate::xrpc::JsonBody for Output {}
+
 /// Error: BlockedActor
 #[derive(Debug, Clone, thiserror::Error)]
 #[error("BlockedActor")]
@@ -41,18 +43,26 @@ pub async fn get_actor_likes(
     let mut req = XrpcRequest::query("app.bsky.feed.getActorLikes");
 
     // Add query parameters
-    let params_json = serde_json::to_value(&params)
-        .map_err(XrpcError::Serialization)?;
-
-    if let Some(obj) = params_json.as_object() {
-        for (key, value) in obj {
-            if let Some(s) = value.as_str() {
-                req.params.insert(key.clone(), s.to_string());
-            } else {
-                req.params.insert(key.clone(), value.to_string());
-            }
-        }
+    req.params.append("actor", params.actor.to_string());
+    if let Some(value) = &params.limit {
+        req.params.append("limit", value.to_string());
+    }
+    if let Some(value) = &params.cursor {
+        req.params.append("cursor", value.to_string());
     }
 
-    client.request(req).await
+    client.request_body(req).await
+}
+
+/// Server-side definition of app.bsky.feed.getActorLikes
+pub struct Endpoint;
+
+impl crate::xrpc::XrpcEndpoint for Endpoint {
+    const NSID: &'static str = "app.bsky.feed.getActorLikes";
+    const METHOD: crate::xrpc::XrpcMethod = crate::xrpc::XrpcMethod::Query;
+    const INPUT_ENCODING: Option<&'static str> = None;
+    const OUTPUT_ENCODING: Option<&'static str> = Some("application/json");
+    type Params = QueryParams;
+    type Input = ();
+    type Output = Output;
 }
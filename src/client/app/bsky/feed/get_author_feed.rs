@@ -8,17 +8,17 @@ use serde::{Deserialize, Serialize};
 /// Query parameters
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct QueryParams {
-    /// Combinations of post/repost types to include in response.
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub filter: Option<String>,
     pub actor: String,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub limit: Option<i64>,
     #[serde(skip_serializing_if = "Option::is_none")]
+    pub cursor: Option<String>,
+    /// Combinations of post/repost types to include in response.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub filter: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     #[serde(rename = "includePins")]
     pub include_pins: Option<bool>,
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub cursor: Option<String>,
 }
 
 /// Response output
@@ -26,9 +26,11 @@ pub struct QueryParams {
 pub struct Output {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub cursor: Option<String>,
-    pub feed: serde_json::Value,
+    pub feed: Vec<serde_json::Value>,
 }
 
+impl crate::xrpc::JsonBody for Output {}
+
 /// Error: BlockedActor
 #[derive(Debug, Clone, thiserror::Error)]
 #[error("BlockedActor")]
@@ -47,18 +49,32 @@ pub async fn get_author_feed(
     let mut req = XrpcRequest::query("app.bsky.feed.getAuthorFeed");
 
     // Add query parameters
-    let params_json = serde_json::to_value(&params)
-        .map_err(XrpcError::Serialization)?;
-
-    if let Some(obj) = params_json.as_object() {
-        for (key, value) in obj {
-            if let Some(s) = value.as_str() {
-                req.params.insert(key.clone(), s.to_string());
-            } else {
-                req.params.insert(key.clone(), value.to_string());
-            }
-        }
+    req.params.append("actor", params.actor.to_string());
+    if let Some(value) = &params.limit {
+        req.params.append("limit", value.to_string());
+    }
+    if let Some(value) = &params.cursor {
+        req.params.append("cursor", value.to_string());
+    }
+    if let Some(value) = &params.filter {
+        req.params.append("filter", value.to_string());
     }
+    if let Some(value) = &params.include_pins {
+        req.params.append("includePins", value.to_string());
+    }
+
+    client.request_body(req).await
+}
+
+/// Server-side definition of app.bsky.feed.getAuthorFeed
+pub struct Endpoint;
 
-    client.request(req).await
+impl crate::xrpc::XrpcEndpoint for Endpoint {
+    const NSID: &'static str = "app.bsky.feed.getAuthorFeed";
+    const METHOD: crate::xrpc::XrpcMethod = crate::xrpc::XrpcMethod::Query;
+    const INPUT_ENCODING: Option<&'static str> = None;
+    const OUTPUT_ENCODING: Option<&'static str> = Some("application/json");
+    type Params = QueryParams;
+    type Input = ();
+    type Output = Output;
 }
@@ -8,21 +8,23 @@ use serde::{Deserialize, Serialize};
 /// Query parameters
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct QueryParams {
+    pub feed: crate::syntax::AtUri,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub limit: Option<i64>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub cursor: Option<String>,
-    pub feed: crate::syntax::AtUri,
 }
 
 /// Response output
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Output {
-    pub feed: serde_json::Value,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub cursor: Option<String>,
+    pub feed: Vec<serde_json::Value>,
 }
 
+impl crate::xrpc::JsonBody for Output {}
+
 /// Error: UnknownFeed
 #[derive(Debug, Clone, thiserror::Error)]
 #[error("UnknownFeed")]
@@ -36,18 +38,26 @@ pub async fn get_feed(
     let mut req = XrpcRequest::query("app.bsky.feed.getFeed");
 
     // Add query parameters
-    let params_json = serde_json::to_value(&params)
-        .map_err(XrpcError::Serialization)?;
-
-    if let Some(obj) = params_json.as_object() {
-        for (key, value) in obj {
-            if let Some(s) = value.as_str() {
-                req.params.insert(key.clone(), s.to_string());
-            } else {
-                req.params.insert(key.clone(), value.to_string());
-            }
-        }
+    req.params.append("feed", params.feed.to_string());
+    if let Some(value) = &params.limit {
+        req.params.append("limit", value.to_string());
     }
+    if let Some(value) = &params.cursor {
+        req.params.append("cursor", value.to_string());
+    }
+
+    client.request_body(req).await
+}
 
-    client.request(req).await
+/// Server-side definition of app.bsky.feed.getFeed
+pub struct Endpoint;
+
+impl crate::xrpc::XrpcEndpoint for Endpoint {
+    const NSID: &'static str = "app.bsky.feed.getFeed";
+    const METHOD: crate::xrpc::XrpcMethod = crate::xrpc::XrpcMethod::Query;
+    const INPUT_ENCODING: Option<&'static str> = None;
+    const OUTPUT_ENCODING: Option<&'static str> = Some("application/json");
+    type Params = QueryParams;
+    type Input = ();
+    type Output = Output;
 }
@@ -15,15 +15,17 @@ pub struct QueryParams {
 /// Response output
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Output {
-    /// Indicates whether the feed generator service is compatible with the record declaration.
-    #[serde(rename = "isValid")]
-    pub is_valid: bool,
     pub view: serde_json::Value,
     /// Indicates whether the feed generator service has been online recently, or else seems to be inactive.
     #[serde(rename = "isOnline")]
     pub is_online: bool,
+    /// Indicates whether the feed generator service is compatible with the record declaration.
+    #[serde(rename = "isValid")]
+    pub is_valid: bool,
 }
 
+impl crate::xrpc::JsonBody for Output {}
+
 /// Get information about a feed generator. Implemented by AppView.
 pub async fn get_feed_generator(
     client: &impl XrpcClient,
@@ -32,18 +34,20 @@ pub async fn get_feed_generator(
     let mut req = XrpcRequest::query("app.bsky.feed.getFeedGenerator");
 
     // Add query parameters
-    let params_json = serde_json::to_value(&params)
-        .map_err(XrpcError::Serialization)?;
-
-    if let Some(obj) = params_json.as_object() {
-        for (key, value) in obj {
-            if let Some(s) = value.as_str() {
-                req.params.insert(key.clone(), s.to_string());
-            } else {
-                req.params.insert(key.clone(), value.to_string());
-            }
-        }
-    }
-
-    client.request(req).await
+    req.params.append("feed", params.feed.to_string());
+
+    client.request_body(req).await
+}
+
+/// Server-side definition of app.bsky.feed.getFeedGenerator
+pub struct Endpoint;
+
+impl crate::xrpc::XrpcEndpoint for Endpoint {
+    const NSID: &'static str = "app.bsky.feed.getFeedGenerator";
+    const METHOD: crate::xrpc::XrpcMethod = crate::xrpc::XrpcMethod::Query;
+    const INPUT_ENCODING: Option<&'static str> = None;
+    const OUTPUT_ENCODING: Option<&'static str> = Some("application/json");
+    type Params = QueryParams;
+    type Input = ();
+    type Output = Output;
 }
@@ -8,26 +8,37 @@ use serde::{Deserialize, Serialize};
 /// Query parameters
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct QueryParams {
-    /// CID of the subject record (aka, specific version of record), to filter likes.
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub cid: Option<String>,
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub cursor: Option<String>,
     /// AT-URI of the subject (eg, a post record).
     pub uri: crate::syntax::AtUri,
+    /// CID of the subject record (aka, specific version of record), to filter likes.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cid: Option<crate::types::CidLink>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub limit: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cursor: Option<String>,
 }
 
 /// Response output
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Output {
+    pub uri: crate::syntax::AtUri,
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub cid: Option<String>,
+    pub cid: Option<crate::types::CidLink>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub cursor: Option<String>,
-    pub uri: crate::syntax::AtUri,
-    pub likes: serde_json::Value,
+    pub likes: Vec<serde_json::Value>,
+}
+
+impl crate::xrpc::JsonBody for Output {}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Like {
+    #[serde(rename = "indexedAt")]
+    pub indexed_at: crate::types::Datetime,
+    #[serde(rename = "createdAt")]
+    pub created_at: crate::types::Datetime,
+    pub actor: serde_json::Value,
 }
 
 /// Get like records which reference a subject (by AT-URI and CID).
@@ -38,18 +49,29 @@ pub async fn get_likes(
     let mut req = XrpcRequest::query("app.bsky.feed.getLikes");
 
     // Add query parameters
-    let params_json = serde_json::to_value(&params)
-        .map_err(XrpcError::Serialization)?;
-
-    if let Some(obj) = params_json.as_object() {
-        for (key, value) in obj {
-            if let Some(s) = value.as_str() {
-                req.params.insert(key.clone(), s.to_string());
-            } else {
-                req.params.insert(key.clone(), value.to_string());
-            }
-        }
+    req.params.append("uri", params.uri.to_string());
+    if let Some(value) = &params.cid {
+        req.params.append("cid", value.to_string());
+    }
+    if let Some(value) = &params.limit {
+        req.params.append("limit", value.to_string());
     }
+    if let Some(value) = &params.cursor {
+        req.params.append("cursor", value.to_string());
+    }
+
+    client.request_body(req).await
+}
+
+/// Server-side definition of app.bsky.feed.getLikes
+pub struct Endpoint;
 
-    client.request(req).await
+impl crate::xrpc::XrpcEndpoint for Endpoint {
+    const NSID: &'static str = "app.bsky.feed.getLikes";
+    const METHOD: crate::xrpc::XrpcMethod = crate::xrpc::XrpcMethod::Query;
+    const INPUT_ENCODING: Option<&'static str> = None;
+    const OUTPUT_ENCODING: Option<&'static str> = Some("application/json");
+    type Params = QueryParams;
+    type Input = ();
+    type Output = Output;
 }
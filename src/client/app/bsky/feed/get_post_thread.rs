@@ -8,6 +8,8 @@ use serde::{Deserialize, Serialize};
 /// Query parameters
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct QueryParams {
+    /// Reference (AT-URI) to post record.
+    pub uri: crate::syntax::AtUri,
     /// How many levels of reply depth should be included in response.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub depth: Option<i64>,
@@ -15,16 +17,42 @@ pub struct QueryParams {
     #[serde(skip_serializing_if = "Option::is_none")]
     #[serde(rename = "parentHeight")]
     pub parent_height: Option<i64>,
-    /// Reference (AT-URI) to post record.
-    pub uri: crate::syntax::AtUri,
 }
 
 /// Response output
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Output {
+    pub thread: OutputThread,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub threadgate: Option<serde_json::Value>,
-    pub thread: serde_json::Value,
+}
+
+impl crate::xrpc::JsonBody for Output {}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(tag = "$type")]
+pub enum OutputThread {
+    #[serde(rename = "app.bsky.feed.defs#threadViewPost")]
+    ThreadViewPost(Box<crate::client::app::bsky::feed::defs::ThreadViewPost>),
+    #[serde(rename = "app.bsky.feed.defs#notFoundPost")]
+    NotFoundPost(Box<crate::client::app::bsky::feed::defs::NotFoundPost>),
+    #[serde(rename = "app.bsky.feed.defs#blockedPost")]
+    BlockedPost(Box<crate::client::app::bsky::feed::defs::BlockedPost>),
+    /// A type not listed in the lexicon, kept as raw JSON
+    #[serde(untagged)]
+    Unknown(serde_json::Value),
+}
+
+impl<'de> Deserialize<'de> for OutputThread {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> std::result::Result<Self, D::Error> {
+        let value = serde_json::Value::deserialize(deserializer)?;
+        match value.get("$type").and_then(|t| t.as_str()) {
+            Some("app.bsky.feed.defs#threadViewPost") => serde_json::from_value(value).map(Self::ThreadViewPost).map_err(serde::de::Error::custom),
+            Some("app.bsky.feed.defs#notFoundPost") => serde_json::from_value(value).map(Self::NotFoundPost).map_err(serde::de::Error::custom),
+            Some("app.bsky.feed.defs#blockedPost") => serde_json::from_value(value).map(Self::BlockedPost).map_err(serde::de::Error::custom),
+            _ => Ok(Self::Unknown(value)),
+        }
+    }
 }
 
 /// Error: NotFound
@@ -40,18 +68,26 @@ pub async fn get_post_thread(
     let mut req = XrpcRequest::query("app.bsky.feed.getPostThread");
 
     // Add query parameters
-    let params_json = serde_json::to_value(&params)
-        .map_err(XrpcError::Serialization)?;
-
-    if let Some(obj) = params_json.as_object() {
-        for (key, value) in obj {
-            if let Some(s) = value.as_str() {
-                req.params.insert(key.clone(), s.to_string());
-            } else {
-                req.params.insert(key.clone(), value.to_string());
-            }
-        }
+    req.params.append("uri", params.uri.to_string());
+    if let Some(value) = &params.depth {
+        req.params.append("depth", value.to_string());
     }
+    if let Some(value) = &params.parent_height {
+        req.params.append("parentHeight", value.to_string());
+    }
+
+    client.request_body(req).await
+}
+
+/// Server-side definition of app.bsky.feed.getPostThread
+pub struct Endpoint;
 
-    client.request(req).await
+impl crate::xrpc::XrpcEndpoint for Endpoint {
+    const NSID: &'static str = "app.bsky.feed.getPostThread";
+    const METHOD: crate::xrpc::XrpcMethod = crate::xrpc::XrpcMethod::Query;
+    const INPUT_ENCODING: Option<&'static str> = None;
+    const OUTPUT_ENCODING: Option<&'static str> = Some("application/json");
+    type Params = QueryParams;
+    type Input = ();
+    type Output = Output;
 }
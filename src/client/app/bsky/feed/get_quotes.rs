@@ -12,7 +12,7 @@ pub struct QueryParams {
     pub uri: crate::syntax::AtUri,
     /// If supplied, filters to quotes of specific version (by CID) of the post record.
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub cid: Option<String>,
+    pub cid: Option<crate::types::CidLink>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub limit: Option<i64>,
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -22,14 +22,16 @@ pub struct QueryParams {
 /// Response output
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Output {
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub cid: Option<String>,
     pub uri: crate::syntax::AtUri,
     #[serde(skip_serializing_if = "Option::is_none")]
+    pub cid: Option<crate::types::CidLink>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub cursor: Option<String>,
-    pub posts: serde_json::Value,
+    pub posts: Vec<serde_json::Value>,
 }
 
+impl crate::xrpc::JsonBody for Output {}
+
 /// Get a list of quotes for a given post.
 pub async fn get_quotes(
     client: &impl XrpcClient,
@@ -38,18 +40,29 @@ pub async fn get_quotes(
     let mut req = XrpcRequest::query("app.bsky.feed.getQuotes");
 
     // Add query parameters
-    let params_json = serde_json::to_value(&params)
-        .map_err(XrpcError::Serialization)?;
-
-    if let Some(obj) = params_json.as_object() {
-        for (key, value) in obj {
-            if let Some(s) = value.as_str() {
-                req.params.insert(key.clone(), s.to_string());
-            } else {
-                req.params.insert(key.clone(), value.to_string());
-            }
-        }
+    req.params.append("uri", params.uri.to_string());
+    if let Some(value) = &params.cid {
+        req.params.append("cid", value.to_string());
+    }
+    if let Some(value) = &params.limit {
+        req.params.append("limit", value.to_string());
     }
+    if let Some(value) = &params.cursor {
+        req.params.append("cursor", value.to_string());
+    }
+
+    client.request_body(req).await
+}
+
+/// Server-side definition of app.bsky.feed.getQuotes
+pub struct Endpoint;
 
-    client.request(req).await
+impl crate::xrpc::XrpcEndpoint for Endpoint {
+    const NSID: &'static str = "app.bsky.feed.getQuotes";
+    const METHOD: crate::xrpc::XrpcMethod = crate::xrpc::XrpcMethod::Query;
+    const INPUT_ENCODING: Option<&'static str> = None;
+    const OUTPUT_ENCODING: Option<&'static str> = Some("application/json");
+    type Params = QueryParams;
+    type Input = ();
+    type Output = Output;
 }
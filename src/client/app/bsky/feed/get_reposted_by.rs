@@ -8,15 +8,15 @@ use serde::{Deserialize, Serialize};
 /// Query parameters
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct QueryParams {
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub limit: Option<i64>,
     /// Reference (AT-URI) of post record
     pub uri: crate::syntax::AtUri,
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub cursor: Option<String>,
     /// If supplied, filters to reposts of specific version (by CID) of the post record.
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub cid: Option<String>,
+    pub cid: Option<crate::types::CidLink>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cursor: Option<String>,
 }
 
 /// Response output
@@ -24,13 +24,15 @@ pub struct QueryParams {
 pub struct Output {
     pub uri: crate::syntax::AtUri,
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub cid: Option<String>,
-    #[serde(rename = "repostedBy")]
-    pub reposted_by: serde_json::Value,
+    pub cid: Option<crate::types::CidLink>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub cursor: Option<String>,
+    #[serde(rename = "repostedBy")]
+    pub reposted_by: Vec<serde_json::Value>,
 }
 
+impl crate::xrpc::JsonBody for Output {}
+
 /// Get a list of reposts for a given post.
 pub async fn get_reposted_by(
     client: &impl XrpcClient,
@@ -39,18 +41,29 @@ pub async fn get_reposted_by(
     let mut req = XrpcRequest::query("app.bsky.feed.getRepostedBy");
 
     // Add query parameters
-    let params_json = serde_json::to_value(&params)
-        .map_err(XrpcError::Serialization)?;
-
-    if let Some(obj) = params_json.as_object() {
-        for (key, value) in obj {
-            if let Some(s) = value.as_str() {
-                req.params.insert(key.clone(), s.to_string());
-            } else {
-                req.params.insert(key.clone(), value.to_string());
-            }
-        }
+    req.params.append("uri", params.uri.to_string());
+    if let Some(value) = &params.cid {
+        req.params.append("cid", value.to_string());
+    }
+    if let Some(value) = &params.limit {
+        req.params.append("limit", value.to_string());
     }
+    if let Some(value) = &params.cursor {
+        req.params.append("cursor", value.to_string());
+    }
+
+    client.request_body(req).await
+}
+
+/// Server-side definition of app.bsky.feed.getRepostedBy
+pub struct Endpoint;
 
-    client.request(req).await
+impl crate::xrpc::XrpcEndpoint for Endpoint {
+    const NSID: &'static str = "app.bsky.feed.getRepostedBy";
+    const METHOD: crate::xrpc::XrpcMethod = crate::xrpc::XrpcMethod::Query;
+    const INPUT_ENCODING: Option<&'static str> = None;
+    const OUTPUT_ENCODING: Option<&'static str> = Some("application/json");
+    type Params = QueryParams;
+    type Input = ();
+    type Output = Output;
 }
@@ -7,45 +7,89 @@ use serde::{Deserialize, Serialize};
 /// Record containing a Bluesky post.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Post {
+    /// The primary post content. May be an empty string, if there are embeds.
+    pub text: String,
+    /// DEPRECATED: replaced by app.bsky.richtext.facet.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub entities: Option<Vec<serde_json::Value>>,
     /// Annotations of text (mentions, URLs, hashtags, etc)
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub facets: Option<serde_json::Value>,
+    pub facets: Option<Vec<serde_json::Value>>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub embed: Option<serde_json::Value>,
-    /// Additional hashtags, in addition to any included in post text and facets.
+    pub reply: Option<serde_json::Value>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub tags: Option<serde_json::Value>,
+    pub embed: Option<PostEmbed>,
     /// Indicates human language of post primary text content.
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub langs: Option<serde_json::Value>,
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub reply: Option<serde_json::Value>,
-    /// The primary post content. May be an empty string, if there are embeds.
-    pub text: String,
-    /// DEPRECATED: replaced by app.bsky.richtext.facet.
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub entities: Option<serde_json::Value>,
+    pub langs: Option<Vec<crate::types::Language>>,
     /// Self-label values for this post. Effectively content warnings.
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub labels: Option<serde_json::Value>,
+    pub labels: Option<PostLabels>,
+    /// Additional hashtags, in addition to any included in post text and facets.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tags: Option<Vec<String>>,
     /// Client-declared timestamp when this post was originally created.
     #[serde(rename = "createdAt")]
-    pub created_at: String,
+    pub created_at: crate::types::Datetime,
 }
 
-/// Deprecated. Use app.bsky.richtext instead -- A text segment. Start is inclusive, end is exclusive. Indices are for utf16-encoded strings.
-#[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct TextSlice {
-    /// Deprecated. Use app.bsky.richtext instead -- A text segment. Start is inclusive, end is exclusive. Indices are for utf16-encoded strings.
-    pub start: i64,
-    /// Deprecated. Use app.bsky.richtext instead -- A text segment. Start is inclusive, end is exclusive. Indices are for utf16-encoded strings.
-    pub end: i64,
+#[derive(Debug, Clone, Serialize)]
+#[serde(tag = "$type")]
+pub enum PostEmbed {
+    #[serde(rename = "app.bsky.embed.images")]
+    Images(Box<crate::client::app::bsky::embed::images::Main>),
+    #[serde(rename = "app.bsky.embed.video")]
+    Video(Box<crate::client::app::bsky::embed::video::Main>),
+    #[serde(rename = "app.bsky.embed.external")]
+    External(Box<crate::client::app::bsky::embed::external::Main>),
+    #[serde(rename = "app.bsky.embed.record")]
+    Record(Box<crate::client::app::bsky::embed::record::Main>),
+    #[serde(rename = "app.bsky.embed.recordWithMedia")]
+    RecordWithMedia(Box<crate::client::app::bsky::embed::record_with_media::Main>),
+    /// A type not listed in the lexicon, kept as raw JSON
+    #[serde(untagged)]
+    Unknown(serde_json::Value),
+}
+
+impl<'de> Deserialize<'de> for PostEmbed {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> std::result::Result<Self, D::Error> {
+        let value = serde_json::Value::deserialize(deserializer)?;
+        match value.get("$type").and_then(|t| t.as_str()) {
+            Some("app.bsky.embed.images") => serde_json::from_value(value).map(Self::Images).map_err(serde::de::Error::custom),
+            Some("app.bsky.embed.video") => serde_json::from_value(value).map(Self::Video).map_err(serde::de::Error::custom),
+            Some("app.bsky.embed.external") => serde_json::from_value(value).map(Self::External).map_err(serde::de::Error::custom),
+            Some("app.bsky.embed.record") => serde_json::from_value(value).map(Self::Record).map_err(serde::de::Error::custom),
+            Some("app.bsky.embed.recordWithMedia") => serde_json::from_value(value).map(Self::RecordWithMedia).map_err(serde::de::Error::custom),
+            _ => Ok(Self::Unknown(value)),
+        }
+    }
+}
+
+/// Self-label values for this post. Effectively content warnings.
+#[derive(Debug, Clone, Serialize)]
+#[serde(tag = "$type")]
+pub enum PostLabels {
+    #[serde(rename = "com.atproto.label.defs#selfLabels")]
+    SelfLabels(Box<crate::client::com::atproto::label::defs::SelfLabels>),
+    /// A type not listed in the lexicon, kept as raw JSON
+    #[serde(untagged)]
+    Unknown(serde_json::Value),
+}
+
+impl<'de> Deserialize<'de> for PostLabels {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> std::result::Result<Self, D::Error> {
+        let value = serde_json::Value::deserialize(deserializer)?;
+        match value.get("$type").and_then(|t| t.as_str()) {
+            Some("com.atproto.label.defs#selfLabels") => serde_json::from_value(value).map(Self::SelfLabels).map_err(serde::de::Error::custom),
+            _ => Ok(Self::Unknown(value)),
+        }
+    }
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ReplyRef {
-    pub parent: serde_json::Value,
     pub root: serde_json::Value,
+    pub parent: serde_json::Value,
 }
 
 /// Deprecated: use facets instead.
@@ -61,3 +105,12 @@ pub struct Entity {
     pub value: String,
 }
 
+/// Deprecated. Use app.bsky.richtext instead -- A text segment. Start is inclusive, end is exclusive. Indices are for utf16-encoded strings.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TextSlice {
+    /// Deprecated. Use app.bsky.richtext instead -- A text segment. Start is inclusive, end is exclusive. Indices are for utf16-encoded strings.
+    pub start: i64,
+    /// Deprecated. Use app.bsky.richtext instead -- A text segment. Start is inclusive, end is exclusive. Indices are for utf16-encoded strings.
+    pub end: i64,
+}
+
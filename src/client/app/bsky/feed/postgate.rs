@@ -7,18 +7,38 @@ use serde::{Deserialize, Serialize};
 /// Record defining interaction rules for a post. The record key (rkey) of the postgate record must match the record key of the post, and that record must be in the same repository.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Postgate {
-    /// List of rules defining who can embed this post. If value is an empty array or is undefined, no particular rules apply and anyone can embed.
-    #[serde(rename = "embeddingRules")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub embedding_rules: Option<serde_json::Value>,
     #[serde(rename = "createdAt")]
-    pub created_at: String,
+    pub created_at: crate::types::Datetime,
     /// Reference (AT-URI) to the post record.
     pub post: crate::syntax::AtUri,
     /// List of AT-URIs embedding this post that the author has detached from.
     #[serde(rename = "detachedEmbeddingUris")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub detached_embedding_uris: Option<serde_json::Value>,
+    pub detached_embedding_uris: Option<Vec<crate::syntax::AtUri>>,
+    /// List of rules defining who can embed this post. If value is an empty array or is undefined, no particular rules apply and anyone can embed.
+    #[serde(rename = "embeddingRules")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub embedding_rules: Option<Vec<PostgateEmbeddingRules>>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(tag = "$type")]
+pub enum PostgateEmbeddingRules {
+    #[serde(rename = "app.bsky.feed.postgate#disableRule")]
+    DisableRule(Box<DisableRule>),
+    /// A type not listed in the lexicon, kept as raw JSON
+    #[serde(untagged)]
+    Unknown(serde_json::Value),
+}
+
+impl<'de> Deserialize<'de> for PostgateEmbeddingRules {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> std::result::Result<Self, D::Error> {
+        let value = serde_json::Value::deserialize(deserializer)?;
+        match value.get("$type").and_then(|t| t.as_str()) {
+            Some("app.bsky.feed.postgate#disableRule") => serde_json::from_value(value).map(Self::DisableRule).map_err(serde::de::Error::custom),
+            _ => Ok(Self::Unknown(value)),
+        }
+    }
 }
 
 /// Disables embedding of this post.
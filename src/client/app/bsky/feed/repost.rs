@@ -7,10 +7,10 @@ use serde::{Deserialize, Serialize};
 /// Record representing a 'repost' of an existing Bluesky post.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Repost {
+    pub subject: serde_json::Value,
     #[serde(rename = "createdAt")]
-    pub created_at: String,
+    pub created_at: crate::types::Datetime,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub via: Option<serde_json::Value>,
-    pub subject: serde_json::Value,
 }
 
@@ -8,14 +8,18 @@ use serde::{Deserialize, Serialize};
 /// Request input
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Input {
-    pub interactions: serde_json::Value,
+    pub interactions: Vec<serde_json::Value>,
 }
 
+impl crate::xrpc::JsonBody for Input {}
+
 /// Response output
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Output {
 }
 
+impl crate::xrpc::JsonBody for Output {}
+
 /// Send information about interactions with feed items back to the feed generator that served them.
 pub async fn send_interactions(
     client: &impl XrpcClient,
@@ -23,5 +27,18 @@ pub async fn send_interactions(
 ) -> Result<XrpcResponse<Output>, XrpcError> {
     let req = XrpcRequest::procedure("app.bsky.feed.sendInteractions").data(&input)?;
 
-    client.request(req).await
+    client.request_body(req).await
+}
+
+/// Server-side definition of app.bsky.feed.sendInteractions
+pub struct Endpoint;
+
+impl crate::xrpc::XrpcEndpoint for Endpoint {
+    const NSID: &'static str = "app.bsky.feed.sendInteractions";
+    const METHOD: crate::xrpc::XrpcMethod = crate::xrpc::XrpcMethod::Procedure;
+    const INPUT_ENCODING: Option<&'static str> = Some("application/json");
+    const OUTPUT_ENCODING: Option<&'static str> = Some("application/json");
+    type Params = ();
+    type Input = Input;
+    type Output = Output;
 }
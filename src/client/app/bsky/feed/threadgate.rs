@@ -7,17 +7,51 @@ use serde::{Deserialize, Serialize};
 /// Record defining interaction gating rules for a thread (aka, reply controls). The record key (rkey) of the threadgate record must match the record key of the thread's root post, and that record must be in the same repository.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Threadgate {
+    /// Reference (AT-URI) to the post record.
+    pub post: crate::syntax::AtUri,
     /// List of rules defining who can reply to this post. If value is an empty array, no one can reply. If value is undefined, anyone can reply.
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub allow: Option<serde_json::Value>,
+    pub allow: Option<Vec<ThreadgateAllow>>,
     #[serde(rename = "createdAt")]
-    pub created_at: String,
+    pub created_at: crate::types::Datetime,
     /// List of hidden reply URIs.
     #[serde(rename = "hiddenReplies")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub hidden_replies: Option<serde_json::Value>,
-    /// Reference (AT-URI) to the post record.
-    pub post: crate::syntax::AtUri,
+    pub hidden_replies: Option<Vec<crate::syntax::AtUri>>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(tag = "$type")]
+pub enum ThreadgateAllow {
+    #[serde(rename = "app.bsky.feed.threadgate#mentionRule")]
+    MentionRule(Box<MentionRule>),
+    #[serde(rename = "app.bsky.feed.threadgate#followerRule")]
+    FollowerRule(Box<FollowerRule>),
+    #[serde(rename = "app.bsky.feed.threadgate#followingRule")]
+    FollowingRule(Box<FollowingRule>),
+    #[serde(rename = "app.bsky.feed.threadgate#listRule")]
+    ListRule(Box<ListRule>),
+    /// A type not listed in the lexicon, kept as raw JSON
+    #[serde(untagged)]
+    Unknown(serde_json::Value),
+}
+
+impl<'de> Deserialize<'de> for ThreadgateAllow {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> std::result::Result<Self, D::Error> {
+        let value = serde_json::Value::deserialize(deserializer)?;
+        match value.get("$type").and_then(|t| t.as_str()) {
+            Some("app.bsky.feed.threadgate#mentionRule") => serde_json::from_value(value).map(Self::MentionRule).map_err(serde::de::Error::custom),
+            Some("app.bsky.feed.threadgate#followerRule") => serde_json::from_value(value).map(Self::FollowerRule).map_err(serde::de::Error::custom),
+            Some("app.bsky.feed.threadgate#followingRule") => serde_json::from_value(value).map(Self::FollowingRule).map_err(serde::de::Error::custom),
+            Some("app.bsky.feed.threadgate#listRule") => serde_json::from_value(value).map(Self::ListRule).map_err(serde::de::Error::custom),
+            _ => Ok(Self::Unknown(value)),
+        }
+    }
+}
+
+/// Allow replies from actors mentioned in your post.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MentionRule {
 }
 
 /// Allow replies from actors who follow you.
@@ -30,11 +64,6 @@ pub struct FollowerRule {
 pub struct FollowingRule {
 }
 
-/// Allow replies from actors mentioned in your post.
-#[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct MentionRule {
-}
-
 /// Allow replies from actors on a list.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ListRule {
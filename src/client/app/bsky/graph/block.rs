@@ -10,6 +10,6 @@ pub struct Block {
     /// DID of the account to be blocked.
     pub subject: crate::types::Did,
     #[serde(rename = "createdAt")]
-    pub created_at: String,
+    pub created_at: crate::types::Datetime,
 }
 
@@ -9,6 +9,6 @@ use serde::{Deserialize, Serialize};
 pub struct Follow {
     pub subject: crate::types::Did,
     #[serde(rename = "createdAt")]
-    pub created_at: String,
+    pub created_at: crate::types::Datetime,
 }
 
@@ -8,15 +8,15 @@ use serde::{Deserialize, Serialize};
 /// Query parameters
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct QueryParams {
-    /// Optional filter by list purpose. If not specified, all supported types are returned.
+    /// The account (actor) to enumerate lists from.
+    pub actor: String,
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub purposes: Option<serde_json::Value>,
+    pub limit: Option<i64>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub cursor: Option<String>,
+    /// Optional filter by list purpose. If not specified, all supported types are returned.
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub limit: Option<i64>,
-    /// The account (actor) to enumerate lists from.
-    pub actor: String,
+    pub purposes: Option<Vec<String>>,
 }
 
 /// Response output
@@ -24,9 +24,11 @@ pub struct QueryParams {
 pub struct Output {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub cursor: Option<String>,
-    pub lists: serde_json::Value,
+    pub lists: Vec<serde_json::Value>,
 }
 
+impl crate::xrpc::JsonBody for Output {}
+
 /// Enumerates the lists created by a specified account (actor).
 pub async fn get_lists(
     client: &impl XrpcClient,
@@ -35,18 +37,29 @@ pub async fn get_lists(
     let mut req = XrpcRequest::query("app.bsky.graph.getLists");
 
     // Add query parameters
-    let params_json = serde_json::to_value(&params)
-        .map_err(XrpcError::Serialization)?;
-
-    if let Some(obj) = params_json.as_object() {
-        for (key, value) in obj {
-            if let Some(s) = value.as_str() {
-                req.params.insert(key.clone(), s.to_string());
-            } else {
-                req.params.insert(key.clone(), value.to_string());
-            }
-        }
+    req.params.append("actor", params.actor.to_string());
+    if let Some(value) = &params.limit {
+        req.params.append("limit", value.to_string());
+    }
+    if let Some(value) = &params.cursor {
+        req.params.append("cursor", value.to_string());
+    }
+    for value in params.purposes.iter().flatten() {
+        req.params.append("purposes", value.to_string());
     }
 
-    client.request(req).await
+    client.request_body(req).await
+}
+
+/// Server-side definition of app.bsky.graph.getLists
+pub struct Endpoint;
+
+impl crate::xrpc::XrpcEndpoint for Endpoint {
+    const NSID: &'static str = "app.bsky.graph.getLists";
+    const METHOD: crate::xrpc::XrpcMethod = crate::xrpc::XrpcMethod::Query;
+    const INPUT_ENCODING: Option<&'static str> = None;
+    const OUTPUT_ENCODING: Option<&'static str> = Some("application/json");
+    type Params = QueryParams;
+    type Input = ();
+    type Output = Output;
 }
@@ -8,15 +8,15 @@ use serde::{Deserialize, Serialize};
 /// Query parameters
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct QueryParams {
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub limit: Option<i64>,
     /// The account (actor) to check for membership.
     pub actor: String,
     #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub cursor: Option<String>,
     /// Optional filter by list purpose. If not specified, all supported types are returned.
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub purposes: Option<serde_json::Value>,
+    pub purposes: Option<Vec<String>>,
 }
 
 /// Response output
@@ -25,7 +25,20 @@ pub struct Output {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub cursor: Option<String>,
     #[serde(rename = "listsWithMembership")]
-    pub lists_with_membership: serde_json::Value,
+    pub lists_with_membership: Vec<serde_json::Value>,
+}
+
+impl crate::xrpc::JsonBody for Output {}
+
+/// A list and an optional list item indicating membership of a target user to that list.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ListWithMembership {
+    /// A list and an optional list item indicating membership of a target user to that list.
+    pub list: serde_json::Value,
+    /// A list and an optional list item indicating membership of a target user to that list.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(rename = "listItem")]
+    pub list_item: Option<serde_json::Value>,
 }
 
 /// Enumerates the lists created by the session user, and includes membership information about `actor` in those lists. Only supports curation and moderation lists (no reference lists, used in starter packs). Requires auth.
@@ -36,18 +49,29 @@ pub async fn get_lists_with_membership(
     let mut req = XrpcRequest::query("app.bsky.graph.getListsWithMembership");
 
     // Add query parameters
-    let params_json = serde_json::to_value(&params)
-        .map_err(XrpcError::Serialization)?;
-
-    if let Some(obj) = params_json.as_object() {
-        for (key, value) in obj {
-            if let Some(s) = value.as_str() {
-                req.params.insert(key.clone(), s.to_string());
-            } else {
-                req.params.insert(key.clone(), value.to_string());
-            }
-        }
+    req.params.append("actor", params.actor.to_string());
+    if let Some(value) = &params.limit {
+        req.params.append("limit", value.to_string());
+    }
+    if let Some(value) = &params.cursor {
+        req.params.append("cursor", value.to_string());
+    }
+    for value in params.purposes.iter().flatten() {
+        req.params.append("purposes", value.to_string());
     }
 
-    client.request(req).await
+    client.request_body(req).await
+}
+
+/// Server-side definition of app.bsky.graph.getListsWithMembership
+pub struct Endpoint;
+
+impl crate::xrpc::XrpcEndpoint for Endpoint {
+    const NSID: &'static str = "app.bsky.graph.getListsWithMembership";
+    const METHOD: crate::xrpc::XrpcMethod = crate::xrpc::XrpcMethod::Query;
+    const INPUT_ENCODING: Option<&'static str> = None;
+    const OUTPUT_ENCODING: Option<&'static str> = Some("application/json");
+    type Params = QueryParams;
+    type Input = ();
+    type Output = Output;
 }
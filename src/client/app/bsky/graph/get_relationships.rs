@@ -12,7 +12,7 @@ pub struct QueryParams {
     pub actor: String,
     /// List of 'other' accounts to be related back to the primary.
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub others: Option<serde_json::Value>,
+    pub others: Option<Vec<String>>,
 }
 
 /// Response output
@@ -20,7 +20,32 @@ pub struct QueryParams {
 pub struct Output {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub actor: Option<crate::types::Did>,
-    pub relationships: serde_json::Value,
+    pub relationships: Vec<OutputRelationships>,
+}
+
+impl crate::xrpc::JsonBody for Output {}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(tag = "$type")]
+pub enum OutputRelationships {
+    #[serde(rename = "app.bsky.graph.defs#relationship")]
+    Relationship(Box<crate::client::app::bsky::graph::defs::Relationship>),
+    #[serde(rename = "app.bsky.graph.defs#notFoundActor")]
+    NotFoundActor(Box<crate::client::app::bsky::graph::defs::NotFoundActor>),
+    /// A type not listed in the lexicon, kept as raw JSON
+    #[serde(untagged)]
+    Unknown(serde_json::Value),
+}
+
+impl<'de> Deserialize<'de> for OutputRelationships {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> std::result::Result<Self, D::Error> {
+        let value = serde_json::Value::deserialize(deserializer)?;
+        match value.get("$type").and_then(|t| t.as_str()) {
+            Some("app.bsky.graph.defs#relationship") => serde_json::from_value(value).map(Self::Relationship).map_err(serde::de::Error::custom),
+            Some("app.bsky.graph.defs#notFoundActor") => serde_json::from_value(value).map(Self::NotFoundActor).map_err(serde::de::Error::custom),
+            _ => Ok(Self::Unknown(value)),
+        }
+    }
 }
 
 /// Error: ActorNotFound
@@ -37,18 +62,23 @@ pub async fn get_relationships(
     let mut req = XrpcRequest::query("app.bsky.graph.getRelationships");
 
     // Add query parameters
-    let params_json = serde_json::to_value(&params)
-        .map_err(XrpcError::Serialization)?;
-
-    if let Some(obj) = params_json.as_object() {
-        for (key, value) in obj {
-            if let Some(s) = value.as_str() {
-                req.params.insert(key.clone(), s.to_string());
-            } else {
-                req.params.insert(key.clone(), value.to_string());
-            }
-        }
+    req.params.append("actor", params.actor.to_string());
+    for value in params.others.iter().flatten() {
+        req.params.append("others", value.to_string());
     }
 
-    client.request(req).await
+    client.request_body(req).await
+}
+
+/// Server-side definition of app.bsky.graph.getRelationships
+pub struct Endpoint;
+
+impl crate::xrpc::XrpcEndpoint for Endpoint {
+    const NSID: &'static str = "app.bsky.graph.getRelationships";
+    const METHOD: crate::xrpc::XrpcMethod = crate::xrpc::XrpcMethod::Query;
+    const INPUT_ENCODING: Option<&'static str> = None;
+    const OUTPUT_ENCODING: Option<&'static str> = Some("application/json");
+    type Params = QueryParams;
+    type Input = ();
+    type Output = Output;
 }
@@ -20,6 +20,8 @@ pub struct Output {
     pub starter_pack: serde_json::Value,
 }
 
+impl crate::xrpc::JsonBody for Output {}
+
 /// Gets a view of a starter pack.
 pub async fn get_starter_pack(
     client: &impl XrpcClient,
@@ -28,18 +30,20 @@ pub async fn get_starter_pack(
     let mut req = XrpcRequest::query("app.bsky.graph.getStarterPack");
 
     // Add query parameters
-    let params_json = serde_json::to_value(&params)
-        .map_err(XrpcError::Serialization)?;
-
-    if let Some(obj) = params_json.as_object() {
-        for (key, value) in obj {
-            if let Some(s) = value.as_str() {
-                req.params.insert(key.clone(), s.to_string());
-            } else {
-                req.params.insert(key.clone(), value.to_string());
-            }
-        }
-    }
-
-    client.request(req).await
+    req.params.append("starterPack", params.starter_pack.to_string());
+
+    client.request_body(req).await
+}
+
+/// Server-side definition of app.bsky.graph.getStarterPack
+pub struct Endpoint;
+
+impl crate::xrpc::XrpcEndpoint for Endpoint {
+    const NSID: &'static str = "app.bsky.graph.getStarterPack";
+    const METHOD: crate::xrpc::XrpcMethod = crate::xrpc::XrpcMethod::Query;
+    const INPUT_ENCODING: Option<&'static str> = None;
+    const OUTPUT_ENCODING: Option<&'static str> = Some("application/json");
+    type Params = QueryParams;
+    type Input = ();
+    type Output = Output;
 }
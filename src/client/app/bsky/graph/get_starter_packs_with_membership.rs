@@ -22,7 +22,21 @@ pub struct Output {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub cursor: Option<String>,
     #[serde(rename = "starterPacksWithMembership")]
-    pub starter_packs_with_membership: serde_json::Value,
+    pub starter_packs_with_membership: Vec<serde_json::Value>,
+}
+
+impl crate::xrpc::JsonBody for Output {}
+
+/// A starter pack and an optional list item indicating membership of a target user to that starter pack.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct StarterPackWithMembership {
+    /// A starter pack and an optional list item indicating membership of a target user to that starter pack.
+    #[serde(rename = "starterPack")]
+    pub starter_pack: serde_json::Value,
+    /// A starter pack and an optional list item indicating membership of a target user to that starter pack.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(rename = "listItem")]
+    pub list_item: Option<serde_json::Value>,
 }
 
 /// Enumerates the starter packs created by the session user, and includes membership information about `actor` in those starter packs. Requires auth.
@@ -33,18 +47,26 @@ pub async fn get_starter_packs_with_membership(
     let mut req = XrpcRequest::query("app.bsky.graph.getStarterPacksWithMembership");
 
     // Add query parameters
-    let params_json = serde_json::to_value(&params)
-        .map_err(XrpcError::Serialization)?;
-
-    if let Some(obj) = params_json.as_object() {
-        for (key, value) in obj {
-            if let Some(s) = value.as_str() {
-                req.params.insert(key.clone(), s.to_string());
-            } else {
-                req.params.insert(key.clone(), value.to_string());
-            }
-        }
+    req.params.append("actor", params.actor.to_string());
+    if let Some(value) = &params.limit {
+        req.params.append("limit", value.to_string());
     }
+    if let Some(value) = &params.cursor {
+        req.params.append("cursor", value.to_string());
+    }
+
+    client.request_body(req).await
+}
+
+/// Server-side definition of app.bsky.graph.getStarterPacksWithMembership
+pub struct Endpoint;
 
-    client.request(req).await
+impl crate::xrpc::XrpcEndpoint for Endpoint {
+    const NSID: &'static str = "app.bsky.graph.getStarterPacksWithMembership";
+    const METHOD: crate::xrpc::XrpcMethod = crate::xrpc::XrpcMethod::Query;
+    const INPUT_ENCODING: Option<&'static str> = None;
+    const OUTPUT_ENCODING: Option<&'static str> = Some("application/json");
+    type Params = QueryParams;
+    type Input = ();
+    type Output = Output;
 }
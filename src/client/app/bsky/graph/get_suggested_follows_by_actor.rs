@@ -14,17 +14,19 @@ pub struct QueryParams {
 /// Response output
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Output {
-    pub suggestions: serde_json::Value,
-    /// Snowflake for this recommendation, use when submitting recommendation events.
-    #[serde(skip_serializing_if = "Option::is_none")]
-    #[serde(rename = "recId")]
-    pub rec_id: Option<i64>,
+    pub suggestions: Vec<serde_json::Value>,
     /// If true, response has fallen-back to generic results, and is not scoped using relativeToDid
     #[serde(skip_serializing_if = "Option::is_none")]
     #[serde(rename = "isFallback")]
     pub is_fallback: Option<bool>,
+    /// Snowflake for this recommendation, use when submitting recommendation events.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(rename = "recId")]
+    pub rec_id: Option<i64>,
 }
 
+impl crate::xrpc::JsonBody for Output {}
+
 /// Enumerates follows similar to a given account (actor). Expected use is to recommend additional accounts immediately after following one account.
 pub async fn get_suggested_follows_by_actor(
     client: &impl XrpcClient,
@@ -33,18 +35,20 @@ pub async fn get_suggested_follows_by_actor(
     let mut req = XrpcRequest::query("app.bsky.graph.getSuggestedFollowsByActor");
 
     // Add query parameters
-    let params_json = serde_json::to_value(&params)
-        .map_err(XrpcError::Serialization)?;
-
-    if let Some(obj) = params_json.as_object() {
-        for (key, value) in obj {
-            if let Some(s) = value.as_str() {
-                req.params.insert(key.clone(), s.to_string());
-            } else {
-                req.params.insert(key.clone(), value.to_string());
-            }
-        }
-    }
-
-    client.request(req).await
+    req.params.append("actor", params.actor.to_string());
+
+    client.request_body(req).await
+}
+
+/// Server-side definition of app.bsky.graph.getSuggestedFollowsByActor
+pub struct Endpoint;
+
+impl crate::xrpc::XrpcEndpoint for Endpoint {
+    const NSID: &'static str = "app.bsky.graph.getSuggestedFollowsByActor";
+    const METHOD: crate::xrpc::XrpcMethod = crate::xrpc::XrpcMethod::Query;
+    const INPUT_ENCODING: Option<&'static str> = None;
+    const OUTPUT_ENCODING: Option<&'static str> = Some("application/json");
+    type Params = QueryParams;
+    type Input = ();
+    type Output = Output;
 }
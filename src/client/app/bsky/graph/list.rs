@@ -7,20 +7,40 @@ use serde::{Deserialize, Serialize};
 /// Record representing a list of accounts (actors). Scope includes both moderation-oriented lists and curration-oriented lists.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct List {
+    /// Defines the purpose of the list (aka, moderation-oriented or curration-oriented)
+    pub purpose: serde_json::Value,
+    /// Display name for list; can not be empty.
+    pub name: String,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub description: Option<String>,
     #[serde(rename = "descriptionFacets")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub description_facets: Option<serde_json::Value>,
+    pub description_facets: Option<Vec<serde_json::Value>>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub avatar: Option<serde_json::Value>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub labels: Option<serde_json::Value>,
+    pub labels: Option<ListLabels>,
     #[serde(rename = "createdAt")]
-    pub created_at: String,
-    /// Display name for list; can not be empty.
-    pub name: String,
-    /// Defines the purpose of the list (aka, moderation-oriented or curration-oriented)
-    pub purpose: serde_json::Value,
+    pub created_at: crate::types::Datetime,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(tag = "$type")]
+pub enum ListLabels {
+    #[serde(rename = "com.atproto.label.defs#selfLabels")]
+    SelfLabels(Box<crate::client::com::atproto::label::defs::SelfLabels>),
+    /// A type not listed in the lexicon, kept as raw JSON
+    #[serde(untagged)]
+    Unknown(serde_json::Value),
+}
+
+impl<'de> Deserialize<'de> for ListLabels {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> std::result::Result<Self, D::Error> {
+        let value = serde_json::Value::deserialize(deserializer)?;
+        match value.get("$type").and_then(|t| t.as_str()) {
+            Some("com.atproto.label.defs#selfLabels") => serde_json::from_value(value).map(Self::SelfLabels).map_err(serde::de::Error::custom),
+            _ => Ok(Self::Unknown(value)),
+        }
+    }
 }
 
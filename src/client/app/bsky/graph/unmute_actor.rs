@@ -11,6 +11,8 @@ pub struct Input {
     pub actor: String,
 }
 
+impl crate::xrpc::JsonBody for Input {}
+
 /// Unmutes the specified account. Requires auth.
 pub async fn unmute_actor(
     client: &impl XrpcClient,
@@ -18,5 +20,18 @@ pub async fn unmute_actor(
 ) -> Result<XrpcResponse<()>, XrpcError> {
     let req = XrpcRequest::procedure("app.bsky.graph.unmuteActor").data(&input)?;
 
-    client.request(req).await
+    client.request_body(req).await
+}
+
+/// Server-side definition of app.bsky.graph.unmuteActor
+pub struct Endpoint;
+
+impl crate::xrpc::XrpcEndpoint for Endpoint {
+    const NSID: &'static str = "app.bsky.graph.unmuteActor";
+    const METHOD: crate::xrpc::XrpcMethod = crate::xrpc::XrpcMethod::Procedure;
+    const INPUT_ENCODING: Option<&'static str> = Some("application/json");
+    const OUTPUT_ENCODING: Option<&'static str> = None;
+    type Params = ();
+    type Input = Input;
+    type Output = ();
 }
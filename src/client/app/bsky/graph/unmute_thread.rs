@@ -11,6 +11,8 @@ pub struct Input {
     pub root: crate::syntax::AtUri,
 }
 
+impl crate::xrpc::JsonBody for Input {}
+
 /// Unmutes the specified thread. Requires auth.
 pub async fn unmute_thread(
     client: &impl XrpcClient,
@@ -18,5 +20,18 @@ pub async fn unmute_thread(
 ) -> Result<XrpcResponse<()>, XrpcError> {
     let req = XrpcRequest::procedure("app.bsky.graph.unmuteThread").data(&input)?;
 
-    client.request(req).await
+    client.request_body(req).await
+}
+
+/// Server-side definition of app.bsky.graph.unmuteThread
+pub struct Endpoint;
+
+impl crate::xrpc::XrpcEndpoint for Endpoint {
+    const NSID: &'static str = "app.bsky.graph.unmuteThread";
+    const METHOD: crate::xrpc::XrpcMethod = crate::xrpc::XrpcMethod::Procedure;
+    const INPUT_ENCODING: Option<&'static str> = Some("application/json");
+    const OUTPUT_ENCODING: Option<&'static str> = None;
+    type Params = ();
+    type Input = Input;
+    type Output = ();
 }
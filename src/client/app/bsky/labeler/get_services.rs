@@ -8,7 +8,7 @@ use serde::{Deserialize, Serialize};
 /// Query parameters
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct QueryParams {
-    pub dids: serde_json::Value,
+    pub dids: Vec<crate::types::Did>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub detailed: Option<bool>,
 }
@@ -16,7 +16,32 @@ pub struct QueryParams {
 /// Response output
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Output {
-    pub views: serde_json::Value,
+    pub views: Vec<OutputViews>,
+}
+
+impl crate::xrpc::JsonBody for Output {}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(tag = "$type")]
+pub enum OutputViews {
+    #[serde(rename = "app.bsky.labeler.defs#labelerView")]
+    LabelerView(Box<crate::client::app::bsky::labeler::defs::LabelerView>),
+    #[serde(rename = "app.bsky.labeler.defs#labelerViewDetailed")]
+    LabelerViewDetailed(Box<crate::client::app::bsky::labeler::defs::LabelerViewDetailed>),
+    /// A type not listed in the lexicon, kept as raw JSON
+    #[serde(untagged)]
+    Unknown(serde_json::Value),
+}
+
+impl<'de> Deserialize<'de> for OutputViews {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> std::result::Result<Self, D::Error> {
+        let value = serde_json::Value::deserialize(deserializer)?;
+        match value.get("$type").and_then(|t| t.as_str()) {
+            Some("app.bsky.labeler.defs#labelerView") => serde_json::from_value(value).map(Self::LabelerView).map_err(serde::de::Error::custom),
+            Some("app.bsky.labeler.defs#labelerViewDetailed") => serde_json::from_value(value).map(Self::LabelerViewDetailed).map_err(serde::de::Error::custom),
+            _ => Ok(Self::Unknown(value)),
+        }
+    }
 }
 
 /// Get information about a list of labeler services.
@@ -27,18 +52,25 @@ pub async fn get_services(
     let mut req = XrpcRequest::query("app.bsky.labeler.getServices");
 
     // Add query parameters
-    let params_json = serde_json::to_value(&params)
-        .map_err(XrpcError::Serialization)?;
-
-    if let Some(obj) = params_json.as_object() {
-        for (key, value) in obj {
-            if let Some(s) = value.as_str() {
-                req.params.insert(key.clone(), s.to_string());
-            } else {
-                req.params.insert(key.clone(), value.to_string());
-            }
-        }
+    for value in &params.dids {
+        req.params.append("dids", value.to_string());
+    }
+    if let Some(value) = &params.detailed {
+        req.params.append("detailed", value.to_string());
     }
 
-    client.request(req).await
+    client.request_body(req).await
+}
+
+/// Server-side definition of app.bsky.labeler.getServices
+pub struct Endpoint;
+
+impl crate::xrpc::XrpcEndpoint for Endpoint {
+    const NSID: &'static str = "app.bsky.labeler.getServices";
+    const METHOD: crate::xrpc::XrpcMethod = crate::xrpc::XrpcMethod::Query;
+    const INPUT_ENCODING: Option<&'static str> = None;
+    const OUTPUT_ENCODING: Option<&'static str> = Some("application/json");
+    type Params = QueryParams;
+    type Input = ();
+    type Output = Output;
 }
@@ -7,22 +7,42 @@ use serde::{Deserialize, Serialize};
 /// A declaration of the existence of labeler service.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Service {
+    pub policies: serde_json::Value,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub labels: Option<ServiceLabels>,
+    #[serde(rename = "createdAt")]
+    pub created_at: crate::types::Datetime,
     /// The set of report reason 'codes' which are in-scope for this service to review and action. These usually align to policy categories. If not defined (distinct from empty array), all reason types are allowed.
     #[serde(rename = "reasonTypes")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub reason_types: Option<serde_json::Value>,
-    pub policies: serde_json::Value,
-    #[serde(rename = "createdAt")]
-    pub created_at: String,
+    pub reason_types: Option<Vec<serde_json::Value>>,
     /// The set of subject types (account, record, etc) this service accepts reports on.
     #[serde(rename = "subjectTypes")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub subject_types: Option<serde_json::Value>,
+    pub subject_types: Option<Vec<serde_json::Value>>,
     /// Set of record types (collection NSIDs) which can be reported to this service. If not defined (distinct from empty array), default is any record type.
     #[serde(rename = "subjectCollections")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub subject_collections: Option<serde_json::Value>,
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub labels: Option<serde_json::Value>,
+    pub subject_collections: Option<Vec<crate::types::Nsid>>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(tag = "$type")]
+pub enum ServiceLabels {
+    #[serde(rename = "com.atproto.label.defs#selfLabels")]
+    SelfLabels(Box<crate::client::com::atproto::label::defs::SelfLabels>),
+    /// A type not listed in the lexicon, kept as raw JSON
+    #[serde(untagged)]
+    Unknown(serde_json::Value),
+}
+
+impl<'de> Deserialize<'de> for ServiceLabels {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> std::result::Result<Self, D::Error> {
+        let value = serde_json::Value::deserialize(deserializer)?;
+        match value.get("$type").and_then(|t| t.as_str()) {
+            Some("com.atproto.label.defs#selfLabels") => serde_json::from_value(value).map(Self::SelfLabels).map_err(serde::de::Error::custom),
+            _ => Ok(Self::Unknown(value)),
+        }
+    }
 }
 
@@ -8,46 +8,53 @@ pub struct RecordDeleted {
 
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct FilterablePreference {
+pub struct ChatPreference {
+    pub include: String,
     pub push: bool,
+}
+
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FilterablePreference {
     pub include: String,
     pub list: bool,
+    pub push: bool,
 }
 
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct ChatPreference {
+pub struct Preference {
+    pub list: bool,
     pub push: bool,
-    pub include: String,
 }
 
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Preferences {
-    pub repost: serde_json::Value,
-    pub unverified: serde_json::Value,
-    pub mention: serde_json::Value,
-    pub like: serde_json::Value,
-    pub verified: serde_json::Value,
     pub chat: serde_json::Value,
+    pub follow: serde_json::Value,
+    pub like: serde_json::Value,
     #[serde(rename = "likeViaRepost")]
     pub like_via_repost: serde_json::Value,
-    pub follow: serde_json::Value,
+    pub mention: serde_json::Value,
+    pub quote: serde_json::Value,
     pub reply: serde_json::Value,
+    pub repost: serde_json::Value,
     #[serde(rename = "repostViaRepost")]
     pub repost_via_repost: serde_json::Value,
-    #[serde(rename = "subscribedPost")]
-    pub subscribed_post: serde_json::Value,
     #[serde(rename = "starterpackJoined")]
     pub starterpack_joined: serde_json::Value,
-    pub quote: serde_json::Value,
+    #[serde(rename = "subscribedPost")]
+    pub subscribed_post: serde_json::Value,
+    pub unverified: serde_json::Value,
+    pub verified: serde_json::Value,
 }
 
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct Preference {
-    pub list: bool,
-    pub push: bool,
+pub struct ActivitySubscription {
+    pub post: bool,
+    pub reply: bool,
 }
 
 
@@ -62,10 +69,3 @@ pub struct SubjectActivitySubscription {
 }
 
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct ActivitySubscription {
-    pub reply: bool,
-    pub post: bool,
-}
-
-
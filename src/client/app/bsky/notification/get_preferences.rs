@@ -16,26 +16,27 @@ pub struct Output {
     pub preferences: serde_json::Value,
 }
 
+impl crate::xrpc::JsonBody for Output {}
+
 /// Get notification-related preferences for an account. Requires auth.
 pub async fn get_preferences(
     client: &impl XrpcClient,
-    params: QueryParams,
+    _params: QueryParams,
 ) -> Result<XrpcResponse<Output>, XrpcError> {
-    let mut req = XrpcRequest::query("app.bsky.notification.getPreferences");
-
-    // Add query parameters
-    let params_json = serde_json::to_value(&params)
-        .map_err(XrpcError::Serialization)?;
-
-    if let Some(obj) = params_json.as_object() {
-        for (key, value) in obj {
-            if let Some(s) = value.as_str() {
-                req.params.insert(key.clone(), s.to_string());
-            } else {
-                req.params.insert(key.clone(), value.to_string());
-            }
-        }
-    }
-
-    client.request(req).await
+    let req = XrpcRequest::query("app.bsky.notification.getPreferences");
+
+    client.request_body(req).await
+}
+
+/// Server-side definition of app.bsky.notification.getPreferences
+pub struct Endpoint;
+
+impl crate::xrpc::XrpcEndpoint for Endpoint {
+    const NSID: &'static str = "app.bsky.notification.getPreferences";
+    const METHOD: crate::xrpc::XrpcMethod = crate::xrpc::XrpcMethod::Query;
+    const INPUT_ENCODING: Option<&'static str> = None;
+    const OUTPUT_ENCODING: Option<&'static str> = Some("application/json");
+    type Params = QueryParams;
+    type Input = ();
+    type Output = Output;
 }
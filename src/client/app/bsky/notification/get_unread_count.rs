@@ -12,7 +12,7 @@ pub struct QueryParams {
     pub priority: Option<bool>,
     #[serde(skip_serializing_if = "Option::is_none")]
     #[serde(rename = "seenAt")]
-    pub seen_at: Option<String>,
+    pub seen_at: Option<crate::types::Datetime>,
 }
 
 /// Response output
@@ -21,6 +21,8 @@ pub struct Output {
     pub count: i64,
 }
 
+impl crate::xrpc::JsonBody for Output {}
+
 /// Count the number of unread notifications for the requesting account. Requires auth.
 pub async fn get_unread_count(
     client: &impl XrpcClient,
@@ -29,18 +31,25 @@ pub async fn get_unread_count(
     let mut req = XrpcRequest::query("app.bsky.notification.getUnreadCount");
 
     // Add query parameters
-    let params_json = serde_json::to_value(&params)
-        .map_err(XrpcError::Serialization)?;
-
-    if let Some(obj) = params_json.as_object() {
-        for (key, value) in obj {
-            if let Some(s) = value.as_str() {
-                req.params.insert(key.clone(), s.to_string());
-            } else {
-                req.params.insert(key.clone(), value.to_string());
-            }
-        }
+    if let Some(value) = &params.priority {
+        req.params.append("priority", value.to_string());
+    }
+    if let Some(value) = &params.seen_at {
+        req.params.append("seenAt", value.to_string());
     }
 
-    client.request(req).await
+    client.request_body(req).await
+}
+
+/// Server-side definition of app.bsky.notification.getUnreadCount
+pub struct Endpoint;
+
+impl crate::xrpc::XrpcEndpoint for Endpoint {
+    const NSID: &'static str = "app.bsky.notification.getUnreadCount";
+    const METHOD: crate::xrpc::XrpcMethod = crate::xrpc::XrpcMethod::Query;
+    const INPUT_ENCODING: Option<&'static str> = None;
+    const OUTPUT_ENCODING: Option<&'static str> = Some("application/json");
+    type Params = QueryParams;
+    type Input = ();
+    type Output = Output;
 }
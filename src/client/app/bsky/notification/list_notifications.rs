@@ -10,29 +10,50 @@ use serde::{Deserialize, Serialize};
 pub struct QueryParams {
     /// Notification reasons to include in response.
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub reasons: Option<serde_json::Value>,
+    pub reasons: Option<Vec<String>>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub limit: Option<i64>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub priority: Option<bool>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    #[serde(rename = "seenAt")]
-    pub seen_at: Option<String>,
-    #[serde(skip_serializing_if = "Option::is_none")]
     pub cursor: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(rename = "seenAt")]
+    pub seen_at: Option<crate::types::Datetime>,
 }
 
 /// Response output
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Output {
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub priority: Option<bool>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub cursor: Option<String>,
+    pub notifications: Vec<serde_json::Value>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub priority: Option<bool>,
     #[serde(skip_serializing_if = "Option::is_none")]
     #[serde(rename = "seenAt")]
-    pub seen_at: Option<String>,
-    pub notifications: serde_json::Value,
+    pub seen_at: Option<crate::types::Datetime>,
+}
+
+impl crate::xrpc::JsonBody for Output {}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Notification {
+    pub uri: crate::syntax::AtUri,
+    pub cid: crate::types::CidLink,
+    pub author: serde_json::Value,
+    /// The reason why this notification was delivered - e.g. your post was liked, or you received a new follower.
+    pub reason: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(rename = "reasonSubject")]
+    pub reason_subject: Option<crate::syntax::AtUri>,
+    pub record: serde_json::Value,
+    #[serde(rename = "isRead")]
+    pub is_read: bool,
+    #[serde(rename = "indexedAt")]
+    pub indexed_at: crate::types::Datetime,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub labels: Option<Vec<serde_json::Value>>,
 }
 
 /// Enumerate notifications for the requesting account. Requires auth.
@@ -43,18 +64,34 @@ pub async fn list_notifications(
     let mut req = XrpcRequest::query("app.bsky.notification.listNotifications");
 
     // Add query parameters
-    let params_json = serde_json::to_value(&params)
-        .map_err(XrpcError::Serialization)?;
-
-    if let Some(obj) = params_json.as_object() {
-        for (key, value) in obj {
-            if let Some(s) = value.as_str() {
-                req.params.insert(key.clone(), s.to_string());
-            } else {
-                req.params.insert(key.clone(), value.to_string());
-            }
-        }
+    for value in params.reasons.iter().flatten() {
+        req.params.append("reasons", value.to_string());
+    }
+    if let Some(value) = &params.limit {
+        req.params.append("limit", value.to_string());
+    }
+    if let Some(value) = &params.priority {
+        req.params.append("priority", value.to_string());
     }
+    if let Some(value) = &params.cursor {
+        req.params.append("cursor", value.to_string());
+    }
+    if let Some(value) = &params.seen_at {
+        req.params.append("seenAt", value.to_string());
+    }
+
+    client.request_body(req).await
+}
+
+/// Server-side definition of app.bsky.notification.listNotifications
+pub struct Endpoint;
 
-    client.request(req).await
+impl crate::xrpc::XrpcEndpoint for Endpoint {
+    const NSID: &'static str = "app.bsky.notification.listNotifications";
+    const METHOD: crate::xrpc::XrpcMethod = crate::xrpc::XrpcMethod::Query;
+    const INPUT_ENCODING: Option<&'static str> = None;
+    const OUTPUT_ENCODING: Option<&'static str> = Some("application/json");
+    type Params = QueryParams;
+    type Input = ();
+    type Output = Output;
 }
@@ -13,6 +13,8 @@ pub struct Input {
     pub activity_subscription: serde_json::Value,
 }
 
+impl crate::xrpc::JsonBody for Input {}
+
 /// Response output
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Output {
@@ -22,6 +24,8 @@ pub struct Output {
     pub activity_subscription: Option<serde_json::Value>,
 }
 
+impl crate::xrpc::JsonBody for Output {}
+
 /// Puts an activity subscription entry. The key should be omitted for creation and provided for updates. Requires auth.
 pub async fn put_activity_subscription(
     client: &impl XrpcClient,
@@ -29,5 +33,18 @@ pub async fn put_activity_subscription(
 ) -> Result<XrpcResponse<Output>, XrpcError> {
     let req = XrpcRequest::procedure("app.bsky.notification.putActivitySubscription").data(&input)?;
 
-    client.request(req).await
+    client.request_body(req).await
+}
+
+/// Server-side definition of app.bsky.notification.putActivitySubscription
+pub struct Endpoint;
+
+impl crate::xrpc::XrpcEndpoint for Endpoint {
+    const NSID: &'static str = "app.bsky.notification.putActivitySubscription";
+    const METHOD: crate::xrpc::XrpcMethod = crate::xrpc::XrpcMethod::Procedure;
+    const INPUT_ENCODING: Option<&'static str> = Some("application/json");
+    const OUTPUT_ENCODING: Option<&'static str> = Some("application/json");
+    type Params = ();
+    type Input = Input;
+    type Output = Output;
 }
@@ -11,15 +11,23 @@ pub struct Input {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub chat: Option<serde_json::Value>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    #[serde(rename = "repostViaRepost")]
-    pub repost_via_repost: Option<serde_json::Value>,
+    pub follow: Option<serde_json::Value>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub like: Option<serde_json::Value>,
     #[serde(skip_serializing_if = "Option::is_none")]
     #[serde(rename = "likeViaRepost")]
     pub like_via_repost: Option<serde_json::Value>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub follow: Option<serde_json::Value>,
+    pub mention: Option<serde_json::Value>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub quote: Option<serde_json::Value>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reply: Option<serde_json::Value>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub repost: Option<serde_json::Value>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(rename = "repostViaRepost")]
+    pub repost_via_repost: Option<serde_json::Value>,
     #[serde(skip_serializing_if = "Option::is_none")]
     #[serde(rename = "starterpackJoined")]
     pub starterpack_joined: Option<serde_json::Value>,
@@ -27,25 +35,21 @@ pub struct Input {
     #[serde(rename = "subscribedPost")]
     pub subscribed_post: Option<serde_json::Value>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub mention: Option<serde_json::Value>,
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub quote: Option<serde_json::Value>,
-    #[serde(skip_serializing_if = "Option::is_none")]
     pub unverified: Option<serde_json::Value>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub verified: Option<serde_json::Value>,
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub reply: Option<serde_json::Value>,
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub repost: Option<serde_json::Value>,
 }
 
+impl crate::xrpc::JsonBody for Input {}
+
 /// Response output
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Output {
     pub preferences: serde_json::Value,
 }
 
+impl crate::xrpc::JsonBody for Output {}
+
 /// Set notification-related preferences for an account. Requires auth.
 pub async fn put_preferences_v2(
     client: &impl XrpcClient,
@@ -53,5 +57,18 @@ pub async fn put_preferences_v2(
 ) -> Result<XrpcResponse<Output>, XrpcError> {
     let req = XrpcRequest::procedure("app.bsky.notification.putPreferencesV2").data(&input)?;
 
-    client.request(req).await
+    client.request_body(req).await
+}
+
+/// Server-side definition of app.bsky.notification.putPreferencesV2
+pub struct Endpoint;
+
+impl crate::xrpc::XrpcEndpoint for Endpoint {
+    const NSID: &'static str = "app.bsky.notification.putPreferencesV2";
+    const METHOD: crate::xrpc::XrpcMethod = crate::xrpc::XrpcMethod::Procedure;
+    const INPUT_ENCODING: Option<&'static str> = Some("application/json");
+    const OUTPUT_ENCODING: Option<&'static str> = Some("application/json");
+    type Params = ();
+    type Input = Input;
+    type Output = Output;
 }
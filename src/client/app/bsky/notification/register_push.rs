@@ -8,18 +8,20 @@ use serde::{Deserialize, Serialize};
 /// Request input
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Input {
-    /// Set to true when the actor is age restricted
-    #[serde(skip_serializing_if = "Option::is_none")]
-    #[serde(rename = "ageRestricted")]
-    pub age_restricted: Option<bool>,
     #[serde(rename = "serviceDid")]
     pub service_did: crate::types::Did,
     pub token: String,
+    pub platform: String,
     #[serde(rename = "appId")]
     pub app_id: String,
-    pub platform: String,
+    /// Set to true when the actor is age restricted
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(rename = "ageRestricted")]
+    pub age_restricted: Option<bool>,
 }
 
+impl crate::xrpc::JsonBody for Input {}
+
 /// Register to receive push notifications, via a specified service, for the requesting account. Requires auth.
 pub async fn register_push(
     client: &impl XrpcClient,
@@ -27,5 +29,18 @@ pub async fn register_push(
 ) -> Result<XrpcResponse<()>, XrpcError> {
     let req = XrpcRequest::procedure("app.bsky.notification.registerPush").data(&input)?;
 
-    client.request(req).await
+    client.request_body(req).await
+}
+
+/// Server-side definition of app.bsky.notification.registerPush
+pub struct Endpoint;
+
+impl crate::xrpc::XrpcEndpoint for Endpoint {
+    const NSID: &'static str = "app.bsky.notification.registerPush";
+    const METHOD: crate::xrpc::XrpcMethod = crate::xrpc::XrpcMethod::Procedure;
+    const INPUT_ENCODING: Option<&'static str> = Some("application/json");
+    const OUTPUT_ENCODING: Option<&'static str> = None;
+    type Params = ();
+    type Input = Input;
+    type Output = ();
 }
@@ -8,14 +8,16 @@ use serde::{Deserialize, Serialize};
 /// Request input
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Input {
-    pub platform: String,
-    #[serde(rename = "appId")]
-    pub app_id: String,
     #[serde(rename = "serviceDid")]
     pub service_did: crate::types::Did,
     pub token: String,
+    pub platform: String,
+    #[serde(rename = "appId")]
+    pub app_id: String,
 }
 
+impl crate::xrpc::JsonBody for Input {}
+
 /// The inverse of registerPush - inform a specified service that push notifications should no longer be sent to the given token for the requesting account. Requires auth.
 pub async fn unregister_push(
     client: &impl XrpcClient,
@@ -23,5 +25,18 @@ pub async fn unregister_push(
 ) -> Result<XrpcResponse<()>, XrpcError> {
     let req = XrpcRequest::procedure("app.bsky.notification.unregisterPush").data(&input)?;
 
-    client.request(req).await
+    client.request_body(req).await
+}
+
+/// Server-side definition of app.bsky.notification.unregisterPush
+pub struct Endpoint;
+
+impl crate::xrpc::XrpcEndpoint for Endpoint {
+    const NSID: &'static str = "app.bsky.notification.unregisterPush";
+    const METHOD: crate::xrpc::XrpcMethod = crate::xrpc::XrpcMethod::Procedure;
+    const INPUT_ENCODING: Option<&'static str> = Some("application/json");
+    const OUTPUT_ENCODING: Option<&'static str> = None;
+    type Params = ();
+    type Input = Input;
+    type Output = ();
 }
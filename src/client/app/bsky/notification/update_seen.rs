@@ -9,9 +9,11 @@ use serde::{Deserialize, Serialize};
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Input {
     #[serde(rename = "seenAt")]
-    pub seen_at: String,
+    pub seen_at: crate::types::Datetime,
 }
 
+impl crate::xrpc::JsonBody for Input {}
+
 /// Notify server that the requesting account has seen notifications. Requires auth.
 pub async fn update_seen(
     client: &impl XrpcClient,
@@ -19,5 +21,18 @@ pub async fn update_seen(
 ) -> Result<XrpcResponse<()>, XrpcError> {
     let req = XrpcRequest::procedure("app.bsky.notification.updateSeen").data(&input)?;
 
-    client.request(req).await
+    client.request_body(req).await
+}
+
+/// Server-side definition of app.bsky.notification.updateSeen
+pub struct Endpoint;
+
+impl crate::xrpc::XrpcEndpoint for Endpoint {
+    const NSID: &'static str = "app.bsky.notification.updateSeen";
+    const METHOD: crate::xrpc::XrpcMethod = crate::xrpc::XrpcMethod::Procedure;
+    const INPUT_ENCODING: Option<&'static str> = Some("application/json");
+    const OUTPUT_ENCODING: Option<&'static str> = None;
+    type Params = ();
+    type Input = Input;
+    type Output = ();
 }
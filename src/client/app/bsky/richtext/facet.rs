@@ -2,11 +2,55 @@
 
 use serde::{Deserialize, Serialize};
 
+/// Annotation of a sub-string within rich text.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Main {
+    /// Annotation of a sub-string within rich text.
+    pub index: serde_json::Value,
+    /// Annotation of a sub-string within rich text.
+    pub features: Vec<MainFeatures>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(tag = "$type")]
+pub enum MainFeatures {
+    #[serde(rename = "app.bsky.richtext.facet#mention")]
+    Mention(Box<Mention>),
+    #[serde(rename = "app.bsky.richtext.facet#link")]
+    Link(Box<Link>),
+    #[serde(rename = "app.bsky.richtext.facet#tag")]
+    Tag(Box<Tag>),
+    /// A type not listed in the lexicon, kept as raw JSON
+    #[serde(untagged)]
+    Unknown(serde_json::Value),
+}
+
+impl<'de> Deserialize<'de> for MainFeatures {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> std::result::Result<Self, D::Error> {
+        let value = serde_json::Value::deserialize(deserializer)?;
+        match value.get("$type").and_then(|t| t.as_str()) {
+            Some("app.bsky.richtext.facet#mention") => serde_json::from_value(value).map(Self::Mention).map_err(serde::de::Error::custom),
+            Some("app.bsky.richtext.facet#link") => serde_json::from_value(value).map(Self::Link).map_err(serde::de::Error::custom),
+            Some("app.bsky.richtext.facet#tag") => serde_json::from_value(value).map(Self::Tag).map_err(serde::de::Error::custom),
+            _ => Ok(Self::Unknown(value)),
+        }
+    }
+}
+
+
+/// Facet feature for mention of another account. The text is usually a handle, including a '@' prefix, but the facet reference is a DID.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Mention {
+    /// Facet feature for mention of another account. The text is usually a handle, including a '@' prefix, but the facet reference is a DID.
+    pub did: crate::types::Did,
+}
+
+
 /// Facet feature for a URL. The text URL may have been simplified or truncated, but the facet reference should be a complete URL.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Link {
     /// Facet feature for a URL. The text URL may have been simplified or truncated, but the facet reference should be a complete URL.
-    pub uri: String,
+    pub uri: crate::types::Uri,
 }
 
 
@@ -21,30 +65,12 @@ pub struct Tag {
 /// Specifies the sub-string range a facet feature applies to. Start index is inclusive, end index is exclusive. Indices are zero-indexed, counting bytes of the UTF-8 encoded text. NOTE: some languages, like Javascript, use UTF-16 or Unicode codepoints for string slice indexing; in these languages, convert to byte arrays before working with facets.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ByteSlice {
-    /// Specifies the sub-string range a facet feature applies to. Start index is inclusive, end index is exclusive. Indices are zero-indexed, counting bytes of the UTF-8 encoded text. NOTE: some languages, like Javascript, use UTF-16 or Unicode codepoints for string slice indexing; in these languages, convert to byte arrays before working with facets.
-    #[serde(rename = "byteEnd")]
-    pub byte_end: i64,
     /// Specifies the sub-string range a facet feature applies to. Start index is inclusive, end index is exclusive. Indices are zero-indexed, counting bytes of the UTF-8 encoded text. NOTE: some languages, like Javascript, use UTF-16 or Unicode codepoints for string slice indexing; in these languages, convert to byte arrays before working with facets.
     #[serde(rename = "byteStart")]
     pub byte_start: i64,
-}
-
-
-/// Annotation of a sub-string within rich text.
-#[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct Main {
-    /// Annotation of a sub-string within rich text.
-    pub features: serde_json::Value,
-    /// Annotation of a sub-string within rich text.
-    pub index: serde_json::Value,
-}
-
-
-/// Facet feature for mention of another account. The text is usually a handle, including a '@' prefix, but the facet reference is a DID.
-#[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct Mention {
-    /// Facet feature for mention of another account. The text is usually a handle, including a '@' prefix, but the facet reference is a DID.
-    pub did: crate::types::Did,
+    /// Specifies the sub-string range a facet feature applies to. Start index is inclusive, end index is exclusive. Indices are zero-indexed, counting bytes of the UTF-8 encoded text. NOTE: some languages, like Javascript, use UTF-16 or Unicode codepoints for string slice indexing; in these languages, convert to byte arrays before working with facets.
+    #[serde(rename = "byteEnd")]
+    pub byte_end: i64,
 }
 
 
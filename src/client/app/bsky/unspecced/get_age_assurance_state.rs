@@ -10,11 +10,26 @@ use serde::{Deserialize, Serialize};
 pub struct Output {
 }
 
+impl crate::xrpc::JsonBody for Output {}
+
 /// Returns the current state of the age assurance process for an account. This is used to check if the user has completed age assurance or if further action is required.
 pub async fn get_age_assurance_state(
     client: &impl XrpcClient,
 ) -> Result<XrpcResponse<Output>, XrpcError> {
     let req = XrpcRequest::query("app.bsky.unspecced.getAgeAssuranceState");
 
-    client.request(req).await
+    client.request_body(req).await
+}
+
+/// Server-side definition of app.bsky.unspecced.getAgeAssuranceState
+pub struct Endpoint;
+
+impl crate::xrpc::XrpcEndpoint for Endpoint {
+    const NSID: &'static str = "app.bsky.unspecced.getAgeAssuranceState";
+    const METHOD: crate::xrpc::XrpcMethod = crate::xrpc::XrpcMethod::Query;
+    const INPUT_ENCODING: Option<&'static str> = None;
+    const OUTPUT_ENCODING: Option<&'static str> = Some("application/json");
+    type Params = ();
+    type Input = ();
+    type Output = Output;
 }
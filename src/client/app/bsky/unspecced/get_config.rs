@@ -8,12 +8,20 @@ use serde::{Deserialize, Serialize};
 /// Response output
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Output {
-    #[serde(skip_serializing_if = "Option::is_none")]
-    #[serde(rename = "liveNow")]
-    pub live_now: Option<serde_json::Value>,
     #[serde(skip_serializing_if = "Option::is_none")]
     #[serde(rename = "checkEmailConfirmed")]
     pub check_email_confirmed: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(rename = "liveNow")]
+    pub live_now: Option<Vec<serde_json::Value>>,
+}
+
+impl crate::xrpc::JsonBody for Output {}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LiveNowConfig {
+    pub did: crate::types::Did,
+    pub domains: Vec<String>,
 }
 
 /// Get miscellaneous runtime configuration.
@@ -22,5 +30,18 @@ pub async fn get_config(
 ) -> Result<XrpcResponse<Output>, XrpcError> {
     let req = XrpcRequest::query("app.bsky.unspecced.getConfig");
 
-    client.request(req).await
+    client.request_body(req).await
+}
+
+/// Server-side definition of app.bsky.unspecced.getConfig
+pub struct Endpoint;
+
+impl crate::xrpc::XrpcEndpoint for Endpoint {
+    const NSID: &'static str = "app.bsky.unspecced.getConfig";
+    const METHOD: crate::xrpc::XrpcMethod = crate::xrpc::XrpcMethod::Query;
+    const INPUT_ENCODING: Option<&'static str> = None;
+    const OUTPUT_ENCODING: Option<&'static str> = Some("application/json");
+    type Params = ();
+    type Input = ();
+    type Output = Output;
 }
@@ -19,9 +19,11 @@ pub struct QueryParams {
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Output {
     #[serde(rename = "starterPacks")]
-    pub starter_packs: serde_json::Value,
+    pub starter_packs: Vec<crate::syntax::AtUri>,
 }
 
+impl crate::xrpc::JsonBody for Output {}
+
 /// Get a skeleton of suggested starterpacks for onboarding. Intended to be called and hydrated by app.bsky.unspecced.getOnboardingSuggestedStarterPacks
 pub async fn get_onboarding_suggested_starter_packs_skeleton(
     client: &impl XrpcClient,
@@ -30,18 +32,25 @@ pub async fn get_onboarding_suggested_starter_packs_skeleton(
     let mut req = XrpcRequest::query("app.bsky.unspecced.getOnboardingSuggestedStarterPacksSkeleton");
 
     // Add query parameters
-    let params_json = serde_json::to_value(&params)
-        .map_err(XrpcError::Serialization)?;
-
-    if let Some(obj) = params_json.as_object() {
-        for (key, value) in obj {
-            if let Some(s) = value.as_str() {
-                req.params.insert(key.clone(), s.to_string());
-            } else {
-                req.params.insert(key.clone(), value.to_string());
-            }
-        }
+    if let Some(value) = &params.viewer {
+        req.params.append("viewer", value.to_string());
+    }
+    if let Some(value) = &params.limit {
+        req.params.append("limit", value.to_string());
     }
 
-    client.request(req).await
+    client.request_body(req).await
+}
+
+/// Server-side definition of app.bsky.unspecced.getOnboardingSuggestedStarterPacksSkeleton
+pub struct Endpoint;
+
+impl crate::xrpc::XrpcEndpoint for Endpoint {
+    const NSID: &'static str = "app.bsky.unspecced.getOnboardingSuggestedStarterPacksSkeleton";
+    const METHOD: crate::xrpc::XrpcMethod = crate::xrpc::XrpcMethod::Query;
+    const INPUT_ENCODING: Option<&'static str> = None;
+    const OUTPUT_ENCODING: Option<&'static str> = Some("application/json");
+    type Params = QueryParams;
+    type Input = ();
+    type Output = Output;
 }
@@ -8,18 +8,11 @@ use serde::{Deserialize, Serialize};
 /// Query parameters
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct QueryParams {
-    /// Sorting for the thread replies.
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub sort: Option<String>,
+    /// Reference (AT-URI) to post record. This is the anchor post, and the thread will be built around it. It can be any post in the tree, not necessarily a root post.
+    pub anchor: crate::syntax::AtUri,
     /// Whether to include parents above the anchor.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub above: Option<bool>,
-    /// Whether to prioritize posts from followed users. It only has effect when the user is authenticated.
-    #[serde(skip_serializing_if = "Option::is_none")]
-    #[serde(rename = "prioritizeFollowedUsers")]
-    pub prioritize_followed_users: Option<bool>,
-    /// Reference (AT-URI) to post record. This is the anchor post, and the thread will be built around it. It can be any post in the tree, not necessarily a root post.
-    pub anchor: crate::syntax::AtUri,
     /// How many levels of replies to include below the anchor.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub below: Option<i64>,
@@ -27,13 +20,20 @@ pub struct QueryParams {
     #[serde(skip_serializing_if = "Option::is_none")]
     #[serde(rename = "branchingFactor")]
     pub branching_factor: Option<i64>,
+    /// Whether to prioritize posts from followed users. It only has effect when the user is authenticated.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(rename = "prioritizeFollowedUsers")]
+    pub prioritize_followed_users: Option<bool>,
+    /// Sorting for the thread replies.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sort: Option<String>,
 }
 
 /// Response output
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Output {
     /// A flat list of thread items. The depth of each item is indicated by the depth property inside the item.
-    pub thread: serde_json::Value,
+    pub thread: Vec<serde_json::Value>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub threadgate: Option<serde_json::Value>,
     /// Whether this thread has additional replies. If true, a call can be made to the `getPostThreadOtherV2` endpoint to retrieve them.
@@ -41,6 +41,45 @@ pub struct Output {
     pub has_other_replies: bool,
 }
 
+impl crate::xrpc::JsonBody for Output {}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ThreadItem {
+    pub uri: crate::syntax::AtUri,
+    /// The nesting level of this item in the thread. Depth 0 means the anchor item. Items above have negative depths, items below have positive depths.
+    pub depth: i64,
+    pub value: ThreadItemValue,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(tag = "$type")]
+pub enum ThreadItemValue {
+    #[serde(rename = "app.bsky.unspecced.defs#threadItemPost")]
+    ThreadItemPost(Box<crate::client::app::bsky::unspecced::defs::ThreadItemPost>),
+    #[serde(rename = "app.bsky.unspecced.defs#threadItemNoUnauthenticated")]
+    ThreadItemNoUnauthenticated(Box<crate::client::app::bsky::unspecced::defs::ThreadItemNoUnauthenticated>),
+    #[serde(rename = "app.bsky.unspecced.defs#threadItemNotFound")]
+    ThreadItemNotFound(Box<crate::client::app::bsky::unspecced::defs::ThreadItemNotFound>),
+    #[serde(rename = "app.bsky.unspecced.defs#threadItemBlocked")]
+    ThreadItemBlocked(Box<crate::client::app::bsky::unspecced::defs::ThreadItemBlocked>),
+    /// A type not listed in the lexicon, kept as raw JSON
+    #[serde(untagged)]
+    Unknown(serde_json::Value),
+}
+
+impl<'de> Deserialize<'de> for ThreadItemValue {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> std::result::Result<Self, D::Error> {
+        let value = serde_json::Value::deserialize(deserializer)?;
+        match value.get("$type").and_then(|t| t.as_str()) {
+            Some("app.bsky.unspecced.defs#threadItemPost") => serde_json::from_value(value).map(Self::ThreadItemPost).map_err(serde::de::Error::custom),
+            Some("app.bsky.unspecced.defs#threadItemNoUnauthenticated") => serde_json::from_value(value).map(Self::ThreadItemNoUnauthenticated).map_err(serde::de::Error::custom),
+            Some("app.bsky.unspecced.defs#threadItemNotFound") => serde_json::from_value(value).map(Self::ThreadItemNotFound).map_err(serde::de::Error::custom),
+            Some("app.bsky.unspecced.defs#threadItemBlocked") => serde_json::from_value(value).map(Self::ThreadItemBlocked).map_err(serde::de::Error::custom),
+            _ => Ok(Self::Unknown(value)),
+        }
+    }
+}
+
 /// (NOTE: this endpoint is under development and WILL change without notice. Don't use it until it is moved out of `unspecced` or your application WILL break) Get posts in a thread. It is based in an anchor post at any depth of the tree, and returns posts above it (recursively resolving the parent, without further branching to their replies) and below it (recursive replies, with branching to their replies). Does not require auth, but additional metadata and filtering will be applied for authed requests.
 pub async fn get_post_thread_v2(
     client: &impl XrpcClient,
@@ -49,18 +88,35 @@ pub async fn get_post_thread_v2(
     let mut req = XrpcRequest::query("app.bsky.unspecced.getPostThreadV2");
 
     // Add query parameters
-    let params_json = serde_json::to_value(&params)
-        .map_err(XrpcError::Serialization)?;
-
-    if let Some(obj) = params_json.as_object() {
-        for (key, value) in obj {
-            if let Some(s) = value.as_str() {
-                req.params.insert(key.clone(), s.to_string());
-            } else {
-                req.params.insert(key.clone(), value.to_string());
-            }
-        }
+    req.params.append("anchor", params.anchor.to_string());
+    if let Some(value) = &params.above {
+        req.params.append("above", value.to_string());
+    }
+    if let Some(value) = &params.below {
+        req.params.append("below", value.to_string());
+    }
+    if let Some(value) = &params.branching_factor {
+        req.params.append("branchingFactor", value.to_string());
+    }
+    if let Some(value) = &params.prioritize_followed_users {
+        req.params.append("prioritizeFollowedUsers", value.to_string());
     }
+    if let Some(value) = &params.sort {
+        req.params.append("sort", value.to_string());
+    }
+
+    client.request_body(req).await
+}
+
+/// Server-side definition of app.bsky.unspecced.getPostThreadV2
+pub struct Endpoint;
 
-    client.request(req).await
+impl crate::xrpc::XrpcEndpoint for Endpoint {
+    const NSID: &'static str = "app.bsky.unspecced.getPostThreadV2";
+    const METHOD: crate::xrpc::XrpcMethod = crate::xrpc::XrpcMethod::Query;
+    const INPUT_ENCODING: Option<&'static str> = None;
+    const OUTPUT_ENCODING: Option<&'static str> = Some("application/json");
+    type Params = QueryParams;
+    type Input = ();
+    type Output = Output;
 }
@@ -8,19 +8,21 @@ use serde::{Deserialize, Serialize};
 /// Query parameters
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct QueryParams {
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub limit: Option<i64>,
     /// DID of the account making the request (not included for public/unauthenticated queries).
     #[serde(skip_serializing_if = "Option::is_none")]
     pub viewer: Option<crate::types::Did>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit: Option<i64>,
 }
 
 /// Response output
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Output {
-    pub feeds: serde_json::Value,
+    pub feeds: Vec<crate::syntax::AtUri>,
 }
 
+impl crate::xrpc::JsonBody for Output {}
+
 /// Get a skeleton of suggested feeds. Intended to be called and hydrated by app.bsky.unspecced.getSuggestedFeeds
 pub async fn get_suggested_feeds_skeleton(
     client: &impl XrpcClient,
@@ -29,18 +31,25 @@ pub async fn get_suggested_feeds_skeleton(
     let mut req = XrpcRequest::query("app.bsky.unspecced.getSuggestedFeedsSkeleton");
 
     // Add query parameters
-    let params_json = serde_json::to_value(&params)
-        .map_err(XrpcError::Serialization)?;
-
-    if let Some(obj) = params_json.as_object() {
-        for (key, value) in obj {
-            if let Some(s) = value.as_str() {
-                req.params.insert(key.clone(), s.to_string());
-            } else {
-                req.params.insert(key.clone(), value.to_string());
-            }
-        }
+    if let Some(value) = &params.viewer {
+        req.params.append("viewer", value.to_string());
     }
+    if let Some(value) = &params.limit {
+        req.params.append("limit", value.to_string());
+    }
+
+    client.request_body(req).await
+}
+
+/// Server-side definition of app.bsky.unspecced.getSuggestedFeedsSkeleton
+pub struct Endpoint;
 
-    client.request(req).await
+impl crate::xrpc::XrpcEndpoint for Endpoint {
+    const NSID: &'static str = "app.bsky.unspecced.getSuggestedFeedsSkeleton";
+    const METHOD: crate::xrpc::XrpcMethod = crate::xrpc::XrpcMethod::Query;
+    const INPUT_ENCODING: Option<&'static str> = None;
+    const OUTPUT_ENCODING: Option<&'static str> = Some("application/json");
+    type Params = QueryParams;
+    type Input = ();
+    type Output = Output;
 }
@@ -16,9 +16,11 @@ pub struct QueryParams {
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Output {
     #[serde(rename = "starterPacks")]
-    pub starter_packs: serde_json::Value,
+    pub starter_packs: Vec<serde_json::Value>,
 }
 
+impl crate::xrpc::JsonBody for Output {}
+
 /// Get a list of suggested starterpacks
 pub async fn get_suggested_starter_packs(
     client: &impl XrpcClient,
@@ -27,18 +29,22 @@ pub async fn get_suggested_starter_packs(
     let mut req = XrpcRequest::query("app.bsky.unspecced.getSuggestedStarterPacks");
 
     // Add query parameters
-    let params_json = serde_json::to_value(&params)
-        .map_err(XrpcError::Serialization)?;
-
-    if let Some(obj) = params_json.as_object() {
-        for (key, value) in obj {
-            if let Some(s) = value.as_str() {
-                req.params.insert(key.clone(), s.to_string());
-            } else {
-                req.params.insert(key.clone(), value.to_string());
-            }
-        }
+    if let Some(value) = &params.limit {
+        req.params.append("limit", value.to_string());
     }
 
-    client.request(req).await
+    client.request_body(req).await
+}
+
+/// Server-side definition of app.bsky.unspecced.getSuggestedStarterPacks
+pub struct Endpoint;
+
+impl crate::xrpc::XrpcEndpoint for Endpoint {
+    const NSID: &'static str = "app.bsky.unspecced.getSuggestedStarterPacks";
+    const METHOD: crate::xrpc::XrpcMethod = crate::xrpc::XrpcMethod::Query;
+    const INPUT_ENCODING: Option<&'static str> = None;
+    const OUTPUT_ENCODING: Option<&'static str> = Some("application/json");
+    type Params = QueryParams;
+    type Input = ();
+    type Output = Output;
 }
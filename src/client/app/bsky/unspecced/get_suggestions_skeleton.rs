@@ -8,35 +8,37 @@ use serde::{Deserialize, Serialize};
 /// Query parameters
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct QueryParams {
-    /// DID of the account to get suggestions relative to. If not provided, suggestions will be based on the viewer.
-    #[serde(skip_serializing_if = "Option::is_none")]
-    #[serde(rename = "relativeToDid")]
-    pub relative_to_did: Option<crate::types::Did>,
     /// DID of the account making the request (not included for public/unauthenticated queries). Used to boost followed accounts in ranking.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub viewer: Option<crate::types::Did>,
     #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub cursor: Option<String>,
+    /// DID of the account to get suggestions relative to. If not provided, suggestions will be based on the viewer.
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub limit: Option<i64>,
+    #[serde(rename = "relativeToDid")]
+    pub relative_to_did: Option<crate::types::Did>,
 }
 
 /// Response output
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Output {
-    pub actors: serde_json::Value,
-    /// Snowflake for this recommendation, use when submitting recommendation events.
-    #[serde(skip_serializing_if = "Option::is_none")]
-    #[serde(rename = "recId")]
-    pub rec_id: Option<i64>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub cursor: Option<String>,
+    pub actors: Vec<serde_json::Value>,
     /// DID of the account these suggestions are relative to. If this is returned undefined, suggestions are based on the viewer.
     #[serde(skip_serializing_if = "Option::is_none")]
     #[serde(rename = "relativeToDid")]
     pub relative_to_did: Option<crate::types::Did>,
+    /// Snowflake for this recommendation, use when submitting recommendation events.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(rename = "recId")]
+    pub rec_id: Option<i64>,
 }
 
+impl crate::xrpc::JsonBody for Output {}
+
 /// Get a skeleton of suggested actors. Intended to be called and then hydrated through app.bsky.actor.getSuggestions
 pub async fn get_suggestions_skeleton(
     client: &impl XrpcClient,
@@ -45,18 +47,31 @@ pub async fn get_suggestions_skeleton(
     let mut req = XrpcRequest::query("app.bsky.unspecced.getSuggestionsSkeleton");
 
     // Add query parameters
-    let params_json = serde_json::to_value(&params)
-        .map_err(XrpcError::Serialization)?;
-
-    if let Some(obj) = params_json.as_object() {
-        for (key, value) in obj {
-            if let Some(s) = value.as_str() {
-                req.params.insert(key.clone(), s.to_string());
-            } else {
-                req.params.insert(key.clone(), value.to_string());
-            }
-        }
+    if let Some(value) = &params.viewer {
+        req.params.append("viewer", value.to_string());
     }
+    if let Some(value) = &params.limit {
+        req.params.append("limit", value.to_string());
+    }
+    if let Some(value) = &params.cursor {
+        req.params.append("cursor", value.to_string());
+    }
+    if let Some(value) = &params.relative_to_did {
+        req.params.append("relativeToDid", value.to_string());
+    }
+
+    client.request_body(req).await
+}
+
+/// Server-side definition of app.bsky.unspecced.getSuggestionsSkeleton
+pub struct Endpoint;
 
-    client.request(req).await
+impl crate::xrpc::XrpcEndpoint for Endpoint {
+    const NSID: &'static str = "app.bsky.unspecced.getSuggestionsSkeleton";
+    const METHOD: crate::xrpc::XrpcMethod = crate::xrpc::XrpcMethod::Query;
+    const INPUT_ENCODING: Option<&'static str> = None;
+    const OUTPUT_ENCODING: Option<&'static str> = Some("application/json");
+    type Params = QueryParams;
+    type Input = ();
+    type Output = Output;
 }
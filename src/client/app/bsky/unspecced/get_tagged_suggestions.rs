@@ -13,29 +13,38 @@ pub struct QueryParams {
 /// Response output
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Output {
-    pub suggestions: serde_json::Value,
+    pub suggestions: Vec<serde_json::Value>,
+}
+
+impl crate::xrpc::JsonBody for Output {}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Suggestion {
+    pub tag: String,
+    #[serde(rename = "subjectType")]
+    pub subject_type: String,
+    pub subject: crate::types::Uri,
 }
 
 /// Get a list of suggestions (feeds and users) tagged with categories
 pub async fn get_tagged_suggestions(
     client: &impl XrpcClient,
-    params: QueryParams,
+    _params: QueryParams,
 ) -> Result<XrpcResponse<Output>, XrpcError> {
-    let mut req = XrpcRequest::query("app.bsky.unspecced.getTaggedSuggestions");
-
-    // Add query parameters
-    let params_json = serde_json::to_value(&params)
-        .map_err(XrpcError::Serialization)?;
-
-    if let Some(obj) = params_json.as_object() {
-        for (key, value) in obj {
-            if let Some(s) = value.as_str() {
-                req.params.insert(key.clone(), s.to_string());
-            } else {
-                req.params.insert(key.clone(), value.to_string());
-            }
-        }
-    }
-
-    client.request(req).await
+    let req = XrpcRequest::query("app.bsky.unspecced.getTaggedSuggestions");
+
+    client.request_body(req).await
+}
+
+/// Server-side definition of app.bsky.unspecced.getTaggedSuggestions
+pub struct Endpoint;
+
+impl crate::xrpc::XrpcEndpoint for Endpoint {
+    const NSID: &'static str = "app.bsky.unspecced.getTaggedSuggestions";
+    const METHOD: crate::xrpc::XrpcMethod = crate::xrpc::XrpcMethod::Query;
+    const INPUT_ENCODING: Option<&'static str> = None;
+    const OUTPUT_ENCODING: Option<&'static str> = Some("application/json");
+    type Params = QueryParams;
+    type Input = ();
+    type Output = Output;
 }
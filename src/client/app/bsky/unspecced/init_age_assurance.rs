@@ -17,11 +17,15 @@ pub struct Input {
     pub country_code: String,
 }
 
+impl crate::xrpc::JsonBody for Input {}
+
 /// Response output
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Output {
 }
 
+impl crate::xrpc::JsonBody for Output {}
+
 /// Initiate age assurance for an account. This is a one-time action that will start the process of verifying the user's age.
 pub async fn init_age_assurance(
     client: &impl XrpcClient,
@@ -29,5 +33,18 @@ pub async fn init_age_assurance(
 ) -> Result<XrpcResponse<Output>, XrpcError> {
     let req = XrpcRequest::procedure("app.bsky.unspecced.initAgeAssurance").data(&input)?;
 
-    client.request(req).await
+    client.request_body(req).await
+}
+
+/// Server-side definition of app.bsky.unspecced.initAgeAssurance
+pub struct Endpoint;
+
+impl crate::xrpc::XrpcEndpoint for Endpoint {
+    const NSID: &'static str = "app.bsky.unspecced.initAgeAssurance";
+    const METHOD: crate::xrpc::XrpcMethod = crate::xrpc::XrpcMethod::Procedure;
+    const INPUT_ENCODING: Option<&'static str> = Some("application/json");
+    const OUTPUT_ENCODING: Option<&'static str> = Some("application/json");
+    type Params = ();
+    type Input = Input;
+    type Output = Output;
 }
@@ -8,16 +8,16 @@ use serde::{Deserialize, Serialize};
 /// Query parameters
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct QueryParams {
+    /// Search query string; syntax, phrase, boolean, and faceting is unspecified, but Lucene query syntax is recommended. For typeahead search, only simple term match is supported, not full syntax.
+    pub q: String,
     /// DID of the account making the request (not included for public/unauthenticated queries). Used to boost followed accounts in ranking.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub viewer: Option<crate::types::Did>,
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub limit: Option<i64>,
-    /// Search query string; syntax, phrase, boolean, and faceting is unspecified, but Lucene query syntax is recommended. For typeahead search, only simple term match is supported, not full syntax.
-    pub q: String,
     /// If true, acts as fast/simple 'typeahead' query.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub typeahead: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit: Option<i64>,
     /// Optional pagination mechanism; may not necessarily allow scrolling through entire result set.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub cursor: Option<String>,
@@ -32,9 +32,11 @@ pub struct Output {
     #[serde(skip_serializing_if = "Option::is_none")]
     #[serde(rename = "hitsTotal")]
     pub hits_total: Option<i64>,
-    pub actors: serde_json::Value,
+    pub actors: Vec<serde_json::Value>,
 }
 
+impl crate::xrpc::JsonBody for Output {}
+
 /// Error: BadQueryString
 #[derive(Debug, Clone, thiserror::Error)]
 #[error("BadQueryString")]
@@ -48,18 +50,32 @@ pub async fn search_actors_skeleton(
     let mut req = XrpcRequest::query("app.bsky.unspecced.searchActorsSkeleton");
 
     // Add query parameters
-    let params_json = serde_json::to_value(&params)
-        .map_err(XrpcError::Serialization)?;
-
-    if let Some(obj) = params_json.as_object() {
-        for (key, value) in obj {
-            if let Some(s) = value.as_str() {
-                req.params.insert(key.clone(), s.to_string());
-            } else {
-                req.params.insert(key.clone(), value.to_string());
-            }
-        }
+    req.params.append("q", params.q.to_string());
+    if let Some(value) = &params.viewer {
+        req.params.append("viewer", value.to_string());
+    }
+    if let Some(value) = &params.typeahead {
+        req.params.append("typeahead", value.to_string());
+    }
+    if let Some(value) = &params.limit {
+        req.params.append("limit", value.to_string());
     }
+    if let Some(value) = &params.cursor {
+        req.params.append("cursor", value.to_string());
+    }
+
+    client.request_body(req).await
+}
+
+/// Server-side definition of app.bsky.unspecced.searchActorsSkeleton
+pub struct Endpoint;
 
-    client.request(req).await
+impl crate::xrpc::XrpcEndpoint for Endpoint {
+    const NSID: &'static str = "app.bsky.unspecced.searchActorsSkeleton";
+    const METHOD: crate::xrpc::XrpcMethod = crate::xrpc::XrpcMethod::Query;
+    const INPUT_ENCODING: Option<&'static str> = None;
+    const OUTPUT_ENCODING: Option<&'static str> = Some("application/json");
+    type Params = QueryParams;
+    type Input = ();
+    type Output = Output;
 }
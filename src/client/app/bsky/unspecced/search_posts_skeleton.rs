@@ -8,57 +8,59 @@ use serde::{Deserialize, Serialize};
 /// Query parameters
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct QueryParams {
-    /// Filter results for posts before the indicated datetime (not inclusive). Expected to use 'sortAt' timestamp, which may not match 'createdAt'. Can be a datetime, or just an ISO date (YYY-MM-DD).
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub until: Option<String>,
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub limit: Option<i64>,
     /// Search query string; syntax, phrase, boolean, and faceting is unspecified, but Lucene query syntax is recommended.
     pub q: String,
-    /// Optional pagination mechanism; may not necessarily allow scrolling through entire result set.
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub cursor: Option<String>,
-    /// Filter to posts which mention the given account. Handles are resolved to DID before query-time. Only matches rich-text facet mentions.
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub mentions: Option<String>,
-    /// Filter to posts in the given language. Expected to be based on post language field, though server may override language detection.
+    /// Specifies the ranking order of results.
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub lang: Option<String>,
+    pub sort: Option<String>,
     /// Filter results for posts after the indicated datetime (inclusive). Expected to use 'sortAt' timestamp, which may not match 'createdAt'. Can be a datetime, or just an ISO date (YYYY-MM-DD).
     #[serde(skip_serializing_if = "Option::is_none")]
     pub since: Option<String>,
+    /// Filter results for posts before the indicated datetime (not inclusive). Expected to use 'sortAt' timestamp, which may not match 'createdAt'. Can be a datetime, or just an ISO date (YYY-MM-DD).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub until: Option<String>,
+    /// Filter to posts which mention the given account. Handles are resolved to DID before query-time. Only matches rich-text facet mentions.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub mentions: Option<String>,
     /// Filter to posts by the given account. Handles are resolved to DID before query-time.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub author: Option<String>,
+    /// Filter to posts in the given language. Expected to be based on post language field, though server may override language detection.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub lang: Option<crate::types::Language>,
     /// Filter to posts with URLs (facet links or embeds) linking to the given domain (hostname). Server may apply hostname normalization.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub domain: Option<String>,
     /// Filter to posts with links (facet links or embeds) pointing to this URL. Server may apply URL normalization or fuzzy matching.
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub url: Option<String>,
+    pub url: Option<crate::types::Uri>,
+    /// Filter to posts with the given tag (hashtag), based on rich-text facet or tag field. Do not include the hash (#) prefix. Multiple tags can be specified, with 'AND' matching.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tag: Option<Vec<String>>,
     /// DID of the account making the request (not included for public/unauthenticated queries). Used for 'from:me' queries.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub viewer: Option<crate::types::Did>,
-    /// Filter to posts with the given tag (hashtag), based on rich-text facet or tag field. Do not include the hash (#) prefix. Multiple tags can be specified, with 'AND' matching.
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub tag: Option<serde_json::Value>,
-    /// Specifies the ranking order of results.
+    pub limit: Option<i64>,
+    /// Optional pagination mechanism; may not necessarily allow scrolling through entire result set.
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub sort: Option<String>,
+    pub cursor: Option<String>,
 }
 
 /// Response output
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Output {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cursor: Option<String>,
     /// Count of search hits. Optional, may be rounded/truncated, and may not be possible to paginate through all hits.
     #[serde(skip_serializing_if = "Option::is_none")]
     #[serde(rename = "hitsTotal")]
     pub hits_total: Option<i64>,
-    pub posts: serde_json::Value,
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub cursor: Option<String>,
+    pub posts: Vec<serde_json::Value>,
 }
 
+impl crate::xrpc::JsonBody for Output {}
+
 /// Error: BadQueryString
 #[derive(Debug, Clone, thiserror::Error)]
 #[error("BadQueryString")]
@@ -72,18 +74,56 @@ pub async fn search_posts_skeleton(
     let mut req = XrpcRequest::query("app.bsky.unspecced.searchPostsSkeleton");
 
     // Add query parameters
-    let params_json = serde_json::to_value(&params)
-        .map_err(XrpcError::Serialization)?;
-
-    if let Some(obj) = params_json.as_object() {
-        for (key, value) in obj {
-            if let Some(s) = value.as_str() {
-                req.params.insert(key.clone(), s.to_string());
-            } else {
-                req.params.insert(key.clone(), value.to_string());
-            }
-        }
+    req.params.append("q", params.q.to_string());
+    if let Some(value) = &params.sort {
+        req.params.append("sort", value.to_string());
+    }
+    if let Some(value) = &params.since {
+        req.params.append("since", value.to_string());
+    }
+    if let Some(value) = &params.until {
+        req.params.append("until", value.to_string());
     }
+    if let Some(value) = &params.mentions {
+        req.params.append("mentions", value.to_string());
+    }
+    if let Some(value) = &params.author {
+        req.params.append("author", value.to_string());
+    }
+    if let Some(value) = &params.lang {
+        req.params.append("lang", value.to_string());
+    }
+    if let Some(value) = &params.domain {
+        req.params.append("domain", value.to_string());
+    }
+    if let Some(value) = &params.url {
+        req.params.append("url", value.to_string());
+    }
+    for value in params.tag.iter().flatten() {
+        req.params.append("tag", value.to_string());
+    }
+    if let Some(value) = &params.viewer {
+        req.params.append("viewer", value.to_string());
+    }
+    if let Some(value) = &params.limit {
+        req.params.append("limit", value.to_string());
+    }
+    if let Some(value) = &params.cursor {
+        req.params.append("cursor", value.to_string());
+    }
+
+    client.request_body(req).await
+}
+
+/// Server-side definition of app.bsky.unspecced.searchPostsSkeleton
+pub struct Endpoint;
 
-    client.request(req).await
+impl crate::xrpc::XrpcEndpoint for Endpoint {
+    const NSID: &'static str = "app.bsky.unspecced.searchPostsSkeleton";
+    const METHOD: crate::xrpc::XrpcMethod = crate::xrpc::XrpcMethod::Query;
+    const INPUT_ENCODING: Option<&'static str> = None;
+    const OUTPUT_ENCODING: Option<&'static str> = Some("application/json");
+    type Params = QueryParams;
+    type Input = ();
+    type Output = Output;
 }
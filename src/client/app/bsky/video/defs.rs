@@ -4,16 +4,16 @@ use serde::{Deserialize, Serialize};
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct JobStatus {
+    #[serde(rename = "jobId")]
+    pub job_id: String,
     pub did: crate::types::Did,
+    /// The state of the video processing job. All values not listed as a known value indicate that the job is in process.
+    pub state: String,
     /// Progress within the current processing state.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub progress: Option<i64>,
-    #[serde(rename = "jobId")]
-    pub job_id: String,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub blob: Option<serde_json::Value>,
-    /// The state of the video processing job. All values not listed as a known value indicate that the job is in process.
-    pub state: String,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub error: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -19,6 +19,8 @@ pub struct Output {
     pub job_status: serde_json::Value,
 }
 
+impl crate::xrpc::JsonBody for Output {}
+
 /// Get status details for a video processing job.
 pub async fn get_job_status(
     client: &impl XrpcClient,
@@ -27,18 +29,20 @@ pub async fn get_job_status(
     let mut req = XrpcRequest::query("app.bsky.video.getJobStatus");
 
     // Add query parameters
-    let params_json = serde_json::to_value(&params)
-        .map_err(XrpcError::Serialization)?;
-
-    if let Some(obj) = params_json.as_object() {
-        for (key, value) in obj {
-            if let Some(s) = value.as_str() {
-                req.params.insert(key.clone(), s.to_string());
-            } else {
-                req.params.insert(key.clone(), value.to_string());
-            }
-        }
-    }
-
-    client.request(req).await
+    req.params.append("jobId", params.job_id.to_string());
+
+    client.request_body(req).await
+}
+
+/// Server-side definition of app.bsky.video.getJobStatus
+pub struct Endpoint;
+
+impl crate::xrpc::XrpcEndpoint for Endpoint {
+    const NSID: &'static str = "app.bsky.video.getJobStatus";
+    const METHOD: crate::xrpc::XrpcMethod = crate::xrpc::XrpcMethod::Query;
+    const INPUT_ENCODING: Option<&'static str> = None;
+    const OUTPUT_ENCODING: Option<&'static str> = Some("application/json");
+    type Params = QueryParams;
+    type Input = ();
+    type Output = Output;
 }
@@ -8,25 +8,40 @@ use serde::{Deserialize, Serialize};
 /// Response output
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Output {
-    #[serde(skip_serializing_if = "Option::is_none")]
-    #[serde(rename = "remainingDailyBytes")]
-    pub remaining_daily_bytes: Option<i64>,
     #[serde(rename = "canUpload")]
     pub can_upload: bool,
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub message: Option<String>,
-    #[serde(skip_serializing_if = "Option::is_none")]
     #[serde(rename = "remainingDailyVideos")]
     pub remaining_daily_videos: Option<i64>,
     #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(rename = "remainingDailyBytes")]
+    pub remaining_daily_bytes: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub error: Option<String>,
 }
 
+impl crate::xrpc::JsonBody for Output {}
+
 /// Get video upload limits for the authenticated user.
 pub async fn get_upload_limits(
     client: &impl XrpcClient,
 ) -> Result<XrpcResponse<Output>, XrpcError> {
     let req = XrpcRequest::query("app.bsky.video.getUploadLimits");
 
-    client.request(req).await
+    client.request_body(req).await
+}
+
+/// Server-side definition of app.bsky.video.getUploadLimits
+pub struct Endpoint;
+
+impl crate::xrpc::XrpcEndpoint for Endpoint {
+    const NSID: &'static str = "app.bsky.video.getUploadLimits";
+    const METHOD: crate::xrpc::XrpcMethod = crate::xrpc::XrpcMethod::Query;
+    const INPUT_ENCODING: Option<&'static str> = None;
+    const OUTPUT_ENCODING: Option<&'static str> = Some("application/json");
+    type Params = ();
+    type Input = ();
+    type Output = Output;
 }
@@ -15,12 +15,27 @@ pub struct Output {
     pub job_status: serde_json::Value,
 }
 
+impl crate::xrpc::JsonBody for Output {}
+
 /// Upload a video to be processed then stored on the PDS.
 pub async fn upload_video(
     client: &impl XrpcClient,
     input: Input,
 ) -> Result<XrpcResponse<Output>, XrpcError> {
-    let req = XrpcRequest::procedure("app.bsky.video.uploadVideo").data(&input)?;
+    let req = XrpcRequest::procedure("app.bsky.video.uploadVideo").binary(input, "video/mp4");
+
+    client.request_body(req).await
+}
+
+/// Server-side definition of app.bsky.video.uploadVideo
+pub struct Endpoint;
 
-    client.request(req).await
+impl crate::xrpc::XrpcEndpoint for Endpoint {
+    const NSID: &'static str = "app.bsky.video.uploadVideo";
+    const METHOD: crate::xrpc::XrpcMethod = crate::xrpc::XrpcMethod::Procedure;
+    const INPUT_ENCODING: Option<&'static str> = Some("video/mp4");
+    const OUTPUT_ENCODING: Option<&'static str> = Some("application/json");
+    type Params = ();
+    type Input = Vec<u8>;
+    type Output = Output;
 }
@@ -9,11 +9,26 @@ use serde::{Deserialize, Serialize};
 pub struct Output {
 }
 
+impl crate::xrpc::JsonBody for Output {}
+
 /// chat.bsky.actor.deleteAccount
 pub async fn delete_account(
     client: &impl XrpcClient,
 ) -> Result<XrpcResponse<Output>, XrpcError> {
     let req = XrpcRequest::procedure("chat.bsky.actor.deleteAccount");
 
-    client.request(req).await
+    client.request_body(req).await
+}
+
+/// Server-side definition of chat.bsky.actor.deleteAccount
+pub struct Endpoint;
+
+impl crate::xrpc::XrpcEndpoint for Endpoint {
+    const NSID: &'static str = "chat.bsky.actor.deleteAccount";
+    const METHOD: crate::xrpc::XrpcMethod = crate::xrpc::XrpcMethod::Procedure;
+    const INPUT_ENCODING: Option<&'static str> = None;
+    const OUTPUT_ENCODING: Option<&'static str> = Some("application/json");
+    type Params = ();
+    type Input = ();
+    type Output = Output;
 }
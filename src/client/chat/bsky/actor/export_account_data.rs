@@ -6,8 +6,21 @@ use crate::xrpc::{XrpcClient, XrpcRequest, XrpcResponse, XrpcError};
 /// chat.bsky.actor.exportAccountData
 pub async fn export_account_data(
     client: &impl XrpcClient,
-) -> Result<XrpcResponse<()>, XrpcError> {
+) -> Result<XrpcResponse<Vec<u8>>, XrpcError> {
     let req = XrpcRequest::query("chat.bsky.actor.exportAccountData");
 
-    client.request(req).await
+    client.request_body(req).await
+}
+
+/// Server-side definition of chat.bsky.actor.exportAccountData
+pub struct Endpoint;
+
+impl crate::xrpc::XrpcEndpoint for Endpoint {
+    const NSID: &'static str = "chat.bsky.actor.exportAccountData";
+    const METHOD: crate::xrpc::XrpcMethod = crate::xrpc::XrpcMethod::Query;
+    const INPUT_ENCODING: Option<&'static str> = None;
+    const OUTPUT_ENCODING: Option<&'static str> = Some("application/jsonl");
+    type Params = ();
+    type Input = ();
+    type Output = Vec<u8>;
 }
@@ -11,6 +11,8 @@ pub struct Input {
     pub convo_id: String,
 }
 
+impl crate::xrpc::JsonBody for Input {}
+
 /// Response output
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Output {
@@ -19,6 +21,8 @@ pub struct Output {
     pub rev: Option<String>,
 }
 
+impl crate::xrpc::JsonBody for Output {}
+
 /// chat.bsky.convo.acceptConvo
 pub async fn accept_convo(
     client: &impl XrpcClient,
@@ -26,5 +30,18 @@ pub async fn accept_convo(
 ) -> Result<XrpcResponse<Output>, XrpcError> {
     let req = XrpcRequest::procedure("chat.bsky.convo.acceptConvo").data(&input)?;
 
-    client.request(req).await
+    client.request_body(req).await
+}
+
+/// Server-side definition of chat.bsky.convo.acceptConvo
+pub struct Endpoint;
+
+impl crate::xrpc::XrpcEndpoint for Endpoint {
+    const NSID: &'static str = "chat.bsky.convo.acceptConvo";
+    const METHOD: crate::xrpc::XrpcMethod = crate::xrpc::XrpcMethod::Procedure;
+    const INPUT_ENCODING: Option<&'static str> = Some("application/json");
+    const OUTPUT_ENCODING: Option<&'static str> = Some("application/json");
+    type Params = ();
+    type Input = Input;
+    type Output = Output;
 }
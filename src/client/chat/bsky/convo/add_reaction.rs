@@ -8,19 +8,23 @@ use serde::{Deserialize, Serialize};
 /// Request input
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Input {
-    pub value: String,
     #[serde(rename = "convoId")]
     pub convo_id: String,
     #[serde(rename = "messageId")]
     pub message_id: String,
+    pub value: String,
 }
 
+impl crate::xrpc::JsonBody for Input {}
+
 /// Response output
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Output {
     pub message: serde_json::Value,
 }
 
+impl crate::xrpc::JsonBody for Output {}
+
 /// Adds an emoji reaction to a message. Requires authentication. It is idempotent, so multiple calls from the same user with the same emoji result in a single reaction.
 pub async fn add_reaction(
     client: &impl XrpcClient,
@@ -28,5 +32,18 @@ pub async fn add_reaction(
 ) -> Result<XrpcResponse<Output>, XrpcError> {
     let req = XrpcRequest::procedure("chat.bsky.convo.addReaction").data(&input)?;
 
-    client.request(req).await
+    client.request_body(req).await
+}
+
+/// Server-side definition of chat.bsky.convo.addReaction
+pub struct Endpoint;
+
+impl crate::xrpc::XrpcEndpoint for Endpoint {
+    const NSID: &'static str = "chat.bsky.convo.addReaction";
+    const METHOD: crate::xrpc::XrpcMethod = crate::xrpc::XrpcMethod::Procedure;
+    const INPUT_ENCODING: Option<&'static str> = Some("application/json");
+    const OUTPUT_ENCODING: Option<&'static str> = Some("application/json");
+    type Params = ();
+    type Input = Input;
+    type Output = Output;
 }
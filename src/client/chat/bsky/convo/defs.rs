@@ -3,77 +3,190 @@
 use serde::{Deserialize, Serialize};
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct LogBeginConvo {
+pub struct MessageRef {
+    pub did: crate::types::Did,
     #[serde(rename = "convoId")]
     pub convo_id: String,
-    pub rev: String,
+    #[serde(rename = "messageId")]
+    pub message_id: String,
 }
 
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct LogCreateMessage {
-    pub message: serde_json::Value,
-    #[serde(rename = "convoId")]
-    pub convo_id: String,
+pub struct MessageInput {
+    pub text: String,
+    /// Annotations of text (mentions, URLs, hashtags, etc)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub facets: Option<Vec<serde_json::Value>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub embed: Option<MessageInputEmbed>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(tag = "$type")]
+pub enum MessageInputEmbed {
+    #[serde(rename = "app.bsky.embed.record")]
+    Record(Box<crate::client::app::bsky::embed::record::Main>),
+    /// A type not listed in the lexicon, kept as raw JSON
+    #[serde(untagged)]
+    Unknown(serde_json::Value),
+}
+
+impl<'de> Deserialize<'de> for MessageInputEmbed {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> std::result::Result<Self, D::Error> {
+        let value = serde_json::Value::deserialize(deserializer)?;
+        match value.get("$type").and_then(|t| t.as_str()) {
+            Some("app.bsky.embed.record") => serde_json::from_value(value).map(Self::Record).map_err(serde::de::Error::custom),
+            _ => Ok(Self::Unknown(value)),
+        }
+    }
+}
+
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MessageView {
+    pub id: String,
     pub rev: String,
+    pub text: String,
+    /// Annotations of text (mentions, URLs, hashtags, etc)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub facets: Option<Vec<serde_json::Value>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub embed: Option<MessageViewEmbed>,
+    /// Reactions to this message, in ascending order of creation time.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reactions: Option<Vec<serde_json::Value>>,
+    pub sender: serde_json::Value,
+    #[serde(rename = "sentAt")]
+    pub sent_at: crate::types::Datetime,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(tag = "$type")]
+pub enum MessageViewEmbed {
+    #[serde(rename = "app.bsky.embed.record#view")]
+    RecordView(Box<crate::client::app::bsky::embed::record::View>),
+    /// A type not listed in the lexicon, kept as raw JSON
+    #[serde(untagged)]
+    Unknown(serde_json::Value),
+}
+
+impl<'de> Deserialize<'de> for MessageViewEmbed {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> std::result::Result<Self, D::Error> {
+        let value = serde_json::Value::deserialize(deserializer)?;
+        match value.get("$type").and_then(|t| t.as_str()) {
+            Some("app.bsky.embed.record#view") => serde_json::from_value(value).map(Self::RecordView).map_err(serde::de::Error::custom),
+            _ => Ok(Self::Unknown(value)),
+        }
+    }
 }
 
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct LogAddReaction {
+pub struct DeletedMessageView {
+    pub id: String,
     pub rev: String,
-    #[serde(rename = "convoId")]
-    pub convo_id: String,
-    pub reaction: serde_json::Value,
-    pub message: serde_json::Value,
+    pub sender: serde_json::Value,
+    #[serde(rename = "sentAt")]
+    pub sent_at: crate::types::Datetime,
 }
 
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct LogRemoveReaction {
-    pub reaction: serde_json::Value,
-    #[serde(rename = "convoId")]
-    pub convo_id: String,
+pub struct MessageViewSender {
+    pub did: crate::types::Did,
+}
+
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ReactionView {
+    pub value: String,
+    pub sender: serde_json::Value,
+    #[serde(rename = "createdAt")]
+    pub created_at: crate::types::Datetime,
+}
+
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ReactionViewSender {
+    pub did: crate::types::Did,
+}
+
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MessageAndReactionView {
     pub message: serde_json::Value,
-    pub rev: String,
+    pub reaction: serde_json::Value,
 }
 
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ConvoView {
-    pub rev: String,
-    pub members: serde_json::Value,
-    #[serde(rename = "unreadCount")]
-    pub unread_count: i64,
     pub id: String,
+    pub rev: String,
+    pub members: Vec<serde_json::Value>,
     #[serde(skip_serializing_if = "Option::is_none")]
     #[serde(rename = "lastMessage")]
-    pub last_message: Option<serde_json::Value>,
+    pub last_message: Option<ConvoViewLastMessage>,
     #[serde(skip_serializing_if = "Option::is_none")]
     #[serde(rename = "lastReaction")]
-    pub last_reaction: Option<serde_json::Value>,
+    pub last_reaction: Option<ConvoViewLastReaction>,
     pub muted: bool,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub status: Option<String>,
+    #[serde(rename = "unreadCount")]
+    pub unread_count: i64,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(tag = "$type")]
+pub enum ConvoViewLastMessage {
+    #[serde(rename = "chat.bsky.convo.defs#messageView")]
+    MessageView(Box<MessageView>),
+    #[serde(rename = "chat.bsky.convo.defs#deletedMessageView")]
+    DeletedMessageView(Box<DeletedMessageView>),
+    /// A type not listed in the lexicon, kept as raw JSON
+    #[serde(untagged)]
+    Unknown(serde_json::Value),
+}
+
+impl<'de> Deserialize<'de> for ConvoViewLastMessage {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> std::result::Result<Self, D::Error> {
+        let value = serde_json::Value::deserialize(deserializer)?;
+        match value.get("$type").and_then(|t| t.as_str()) {
+            Some("chat.bsky.convo.defs#messageView") => serde_json::from_value(value).map(Self::MessageView).map_err(serde::de::Error::custom),
+            Some("chat.bsky.convo.defs#deletedMessageView") => serde_json::from_value(value).map(Self::DeletedMessageView).map_err(serde::de::Error::custom),
+            _ => Ok(Self::Unknown(value)),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(tag = "$type")]
+pub enum ConvoViewLastReaction {
+    #[serde(rename = "chat.bsky.convo.defs#messageAndReactionView")]
+    MessageAndReactionView(Box<MessageAndReactionView>),
+    /// A type not listed in the lexicon, kept as raw JSON
+    #[serde(untagged)]
+    Unknown(serde_json::Value),
+}
+
+impl<'de> Deserialize<'de> for ConvoViewLastReaction {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> std::result::Result<Self, D::Error> {
+        let value = serde_json::Value::deserialize(deserializer)?;
+        match value.get("$type").and_then(|t| t.as_str()) {
+            Some("chat.bsky.convo.defs#messageAndReactionView") => serde_json::from_value(value).map(Self::MessageAndReactionView).map_err(serde::de::Error::custom),
+            _ => Ok(Self::Unknown(value)),
+        }
+    }
 }
 
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct MessageView {
-    /// Annotations of text (mentions, URLs, hashtags, etc)
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub facets: Option<serde_json::Value>,
+pub struct LogBeginConvo {
     pub rev: String,
-    pub text: String,
-    /// Reactions to this message, in ascending order of creation time.
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub reactions: Option<serde_json::Value>,
-    pub sender: serde_json::Value,
-    pub id: String,
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub embed: Option<serde_json::Value>,
-    #[serde(rename = "sentAt")]
-    pub sent_at: String,
+    #[serde(rename = "convoId")]
+    pub convo_id: String,
 }
 
 
@@ -86,19 +199,16 @@ pub struct LogAcceptConvo {
 
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct DeletedMessageView {
-    pub sender: serde_json::Value,
-    pub id: String,
-    #[serde(rename = "sentAt")]
-    pub sent_at: String,
+pub struct LogLeaveConvo {
     pub rev: String,
+    #[serde(rename = "convoId")]
+    pub convo_id: String,
 }
 
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct LogDeleteMessage {
+pub struct LogMuteConvo {
     pub rev: String,
-    pub message: serde_json::Value,
     #[serde(rename = "convoId")]
     pub convo_id: String,
 }
@@ -113,76 +223,164 @@ pub struct LogUnmuteConvo {
 
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct LogLeaveConvo {
+pub struct LogCreateMessage {
     pub rev: String,
     #[serde(rename = "convoId")]
     pub convo_id: String,
+    pub message: LogCreateMessageMessage,
 }
 
+#[derive(Debug, Clone, Serialize)]
+#[serde(tag = "$type")]
+pub enum LogCreateMessageMessage {
+    #[serde(rename = "chat.bsky.convo.defs#messageView")]
+    MessageView(Box<MessageView>),
+    #[serde(rename = "chat.bsky.convo.defs#deletedMessageView")]
+    DeletedMessageView(Box<DeletedMessageView>),
+    /// A type not listed in the lexicon, kept as raw JSON
+    #[serde(untagged)]
+    Unknown(serde_json::Value),
+}
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct MessageInput {
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub embed: Option<serde_json::Value>,
-    pub text: String,
-    /// Annotations of text (mentions, URLs, hashtags, etc)
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub facets: Option<serde_json::Value>,
+impl<'de> Deserialize<'de> for LogCreateMessageMessage {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> std::result::Result<Self, D::Error> {
+        let value = serde_json::Value::deserialize(deserializer)?;
+        match value.get("$type").and_then(|t| t.as_str()) {
+            Some("chat.bsky.convo.defs#messageView") => serde_json::from_value(value).map(Self::MessageView).map_err(serde::de::Error::custom),
+            Some("chat.bsky.convo.defs#deletedMessageView") => serde_json::from_value(value).map(Self::DeletedMessageView).map_err(serde::de::Error::custom),
+            _ => Ok(Self::Unknown(value)),
+        }
+    }
 }
 
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct ReactionViewSender {
-    pub did: crate::types::Did,
+pub struct LogDeleteMessage {
+    pub rev: String,
+    #[serde(rename = "convoId")]
+    pub convo_id: String,
+    pub message: LogDeleteMessageMessage,
 }
 
+#[derive(Debug, Clone, Serialize)]
+#[serde(tag = "$type")]
+pub enum LogDeleteMessageMessage {
+    #[serde(rename = "chat.bsky.convo.defs#messageView")]
+    MessageView(Box<MessageView>),
+    #[serde(rename = "chat.bsky.convo.defs#deletedMessageView")]
+    DeletedMessageView(Box<DeletedMessageView>),
+    /// A type not listed in the lexicon, kept as raw JSON
+    #[serde(untagged)]
+    Unknown(serde_json::Value),
+}
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct ReactionView {
-    #[serde(rename = "createdAt")]
-    pub created_at: String,
-    pub value: String,
-    pub sender: serde_json::Value,
+impl<'de> Deserialize<'de> for LogDeleteMessageMessage {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> std::result::Result<Self, D::Error> {
+        let value = serde_json::Value::deserialize(deserializer)?;
+        match value.get("$type").and_then(|t| t.as_str()) {
+            Some("chat.bsky.convo.defs#messageView") => serde_json::from_value(value).map(Self::MessageView).map_err(serde::de::Error::custom),
+            Some("chat.bsky.convo.defs#deletedMessageView") => serde_json::from_value(value).map(Self::DeletedMessageView).map_err(serde::de::Error::custom),
+            _ => Ok(Self::Unknown(value)),
+        }
+    }
 }
 
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct LogMuteConvo {
+pub struct LogReadMessage {
     pub rev: String,
     #[serde(rename = "convoId")]
     pub convo_id: String,
+    pub message: LogReadMessageMessage,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(tag = "$type")]
+pub enum LogReadMessageMessage {
+    #[serde(rename = "chat.bsky.convo.defs#messageView")]
+    MessageView(Box<MessageView>),
+    #[serde(rename = "chat.bsky.convo.defs#deletedMessageView")]
+    DeletedMessageView(Box<DeletedMessageView>),
+    /// A type not listed in the lexicon, kept as raw JSON
+    #[serde(untagged)]
+    Unknown(serde_json::Value),
+}
+
+impl<'de> Deserialize<'de> for LogReadMessageMessage {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> std::result::Result<Self, D::Error> {
+        let value = serde_json::Value::deserialize(deserializer)?;
+        match value.get("$type").and_then(|t| t.as_str()) {
+            Some("chat.bsky.convo.defs#messageView") => serde_json::from_value(value).map(Self::MessageView).map_err(serde::de::Error::custom),
+            Some("chat.bsky.convo.defs#deletedMessageView") => serde_json::from_value(value).map(Self::DeletedMessageView).map_err(serde::de::Error::custom),
+            _ => Ok(Self::Unknown(value)),
+        }
+    }
 }
 
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct LogReadMessage {
-    pub message: serde_json::Value,
+pub struct LogAddReaction {
     pub rev: String,
     #[serde(rename = "convoId")]
     pub convo_id: String,
+    pub message: LogAddReactionMessage,
+    pub reaction: serde_json::Value,
 }
 
+#[derive(Debug, Clone, Serialize)]
+#[serde(tag = "$type")]
+pub enum LogAddReactionMessage {
+    #[serde(rename = "chat.bsky.convo.defs#messageView")]
+    MessageView(Box<MessageView>),
+    #[serde(rename = "chat.bsky.convo.defs#deletedMessageView")]
+    DeletedMessageView(Box<DeletedMessageView>),
+    /// A type not listed in the lexicon, kept as raw JSON
+    #[serde(untagged)]
+    Unknown(serde_json::Value),
+}
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct MessageViewSender {
-    pub did: crate::types::Did,
+impl<'de> Deserialize<'de> for LogAddReactionMessage {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> std::result::Result<Self, D::Error> {
+        let value = serde_json::Value::deserialize(deserializer)?;
+        match value.get("$type").and_then(|t| t.as_str()) {
+            Some("chat.bsky.convo.defs#messageView") => serde_json::from_value(value).map(Self::MessageView).map_err(serde::de::Error::custom),
+            Some("chat.bsky.convo.defs#deletedMessageView") => serde_json::from_value(value).map(Self::DeletedMessageView).map_err(serde::de::Error::custom),
+            _ => Ok(Self::Unknown(value)),
+        }
+    }
 }
 
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct MessageRef {
-    pub did: crate::types::Did,
+pub struct LogRemoveReaction {
+    pub rev: String,
     #[serde(rename = "convoId")]
     pub convo_id: String,
-    #[serde(rename = "messageId")]
-    pub message_id: String,
+    pub message: LogRemoveReactionMessage,
+    pub reaction: serde_json::Value,
 }
 
+#[derive(Debug, Clone, Serialize)]
+#[serde(tag = "$type")]
+pub enum LogRemoveReactionMessage {
+    #[serde(rename = "chat.bsky.convo.defs#messageView")]
+    MessageView(Box<MessageView>),
+    #[serde(rename = "chat.bsky.convo.defs#deletedMessageView")]
+    DeletedMessageView(Box<DeletedMessageView>),
+    /// A type not listed in the lexicon, kept as raw JSON
+    #[serde(untagged)]
+    Unknown(serde_json::Value),
+}
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct MessageAndReactionView {
-    pub message: serde_json::Value,
-    pub reaction: serde_json::Value,
+impl<'de> Deserialize<'de> for LogRemoveReactionMessage {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> std::result::Result<Self, D::Error> {
+        let value = serde_json::Value::deserialize(deserializer)?;
+        match value.get("$type").and_then(|t| t.as_str()) {
+            Some("chat.bsky.convo.defs#messageView") => serde_json::from_value(value).map(Self::MessageView).map_err(serde::de::Error::custom),
+            Some("chat.bsky.convo.defs#deletedMessageView") => serde_json::from_value(value).map(Self::DeletedMessageView).map_err(serde::de::Error::custom),
+            _ => Ok(Self::Unknown(value)),
+        }
+    }
 }
 
 
@@ -7,17 +7,21 @@ use serde::{Deserialize, Serialize};
 /// Request input
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Input {
-    #[serde(rename = "messageId")]
-    pub message_id: String,
     #[serde(rename = "convoId")]
     pub convo_id: String,
+    #[serde(rename = "messageId")]
+    pub message_id: String,
 }
 
+impl crate::xrpc::JsonBody for Input {}
+
 /// Response output
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Output {
 }
 
+impl crate::xrpc::JsonBody for Output {}
+
 /// chat.bsky.convo.deleteMessageForSelf
 pub async fn delete_message_for_self(
     client: &impl XrpcClient,
@@ -25,5 +29,18 @@ pub async fn delete_message_for_self(
 ) -> Result<XrpcResponse<Output>, XrpcError> {
     let req = XrpcRequest::procedure("chat.bsky.convo.deleteMessageForSelf").data(&input)?;
 
-    client.request(req).await
+    client.request_body(req).await
+}
+
+/// Server-side definition of chat.bsky.convo.deleteMessageForSelf
+pub struct Endpoint;
+
+impl crate::xrpc::XrpcEndpoint for Endpoint {
+    const NSID: &'static str = "chat.bsky.convo.deleteMessageForSelf";
+    const METHOD: crate::xrpc::XrpcMethod = crate::xrpc::XrpcMethod::Procedure;
+    const INPUT_ENCODING: Option<&'static str> = Some("application/json");
+    const OUTPUT_ENCODING: Option<&'static str> = Some("application/json");
+    type Params = ();
+    type Input = Input;
+    type Output = Output;
 }
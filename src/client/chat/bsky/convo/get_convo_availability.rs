@@ -8,7 +8,7 @@ use serde::{Deserialize, Serialize};
 /// Query parameters
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct QueryParams {
-    pub members: serde_json::Value,
+    pub members: Vec<crate::types::Did>,
 }
 
 /// Response output
@@ -20,6 +20,8 @@ pub struct Output {
     pub convo: Option<serde_json::Value>,
 }
 
+impl crate::xrpc::JsonBody for Output {}
+
 /// Get whether the requester and the other members can chat. If an existing convo is found for these members, it is returned.
 pub async fn get_convo_availability(
     client: &impl XrpcClient,
@@ -28,18 +30,22 @@ pub async fn get_convo_availability(
     let mut req = XrpcRequest::query("chat.bsky.convo.getConvoAvailability");
 
     // Add query parameters
-    let params_json = serde_json::to_value(&params)
-        .map_err(XrpcError::Serialization)?;
-
-    if let Some(obj) = params_json.as_object() {
-        for (key, value) in obj {
-            if let Some(s) = value.as_str() {
-                req.params.insert(key.clone(), s.to_string());
-            } else {
-                req.params.insert(key.clone(), value.to_string());
-            }
-        }
+    for value in &params.members {
+        req.params.append("members", value.to_string());
     }
 
-    client.request(req).await
+    client.request_body(req).await
+}
+
+/// Server-side definition of chat.bsky.convo.getConvoAvailability
+pub struct Endpoint;
+
+impl crate::xrpc::XrpcEndpoint for Endpoint {
+    const NSID: &'static str = "chat.bsky.convo.getConvoAvailability";
+    const METHOD: crate::xrpc::XrpcMethod = crate::xrpc::XrpcMethod::Query;
+    const INPUT_ENCODING: Option<&'static str> = None;
+    const OUTPUT_ENCODING: Option<&'static str> = Some("application/json");
+    type Params = QueryParams;
+    type Input = ();
+    type Output = Output;
 }
@@ -16,7 +16,56 @@ pub struct QueryParams {
 pub struct Output {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub cursor: Option<String>,
-    pub logs: serde_json::Value,
+    pub logs: Vec<OutputLogs>,
+}
+
+impl crate::xrpc::JsonBody for Output {}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(tag = "$type")]
+pub enum OutputLogs {
+    #[serde(rename = "chat.bsky.convo.defs#logBeginConvo")]
+    LogBeginConvo(Box<crate::client::chat::bsky::convo::defs::LogBeginConvo>),
+    #[serde(rename = "chat.bsky.convo.defs#logAcceptConvo")]
+    LogAcceptConvo(Box<crate::client::chat::bsky::convo::defs::LogAcceptConvo>),
+    #[serde(rename = "chat.bsky.convo.defs#logLeaveConvo")]
+    LogLeaveConvo(Box<crate::client::chat::bsky::convo::defs::LogLeaveConvo>),
+    #[serde(rename = "chat.bsky.convo.defs#logMuteConvo")]
+    LogMuteConvo(Box<crate::client::chat::bsky::convo::defs::LogMuteConvo>),
+    #[serde(rename = "chat.bsky.convo.defs#logUnmuteConvo")]
+    LogUnmuteConvo(Box<crate::client::chat::bsky::convo::defs::LogUnmuteConvo>),
+    #[serde(rename = "chat.bsky.convo.defs#logCreateMessage")]
+    LogCreateMessage(Box<crate::client::chat::bsky::convo::defs::LogCreateMessage>),
+    #[serde(rename = "chat.bsky.convo.defs#logDeleteMessage")]
+    LogDeleteMessage(Box<crate::client::chat::bsky::convo::defs::LogDeleteMessage>),
+    #[serde(rename = "chat.bsky.convo.defs#logReadMessage")]
+    LogReadMessage(Box<crate::client::chat::bsky::convo::defs::LogReadMessage>),
+    #[serde(rename = "chat.bsky.convo.defs#logAddReaction")]
+    LogAddReaction(Box<crate::client::chat::bsky::convo::defs::LogAddReaction>),
+    #[serde(rename = "chat.bsky.convo.defs#logRemoveReaction")]
+    LogRemoveReaction(Box<crate::client::chat::bsky::convo::defs::LogRemoveReaction>),
+    /// A type not listed in the lexicon, kept as raw JSON
+    #[serde(untagged)]
+    Unknown(serde_json::Value),
+}
+
+impl<'de> Deserialize<'de> for OutputLogs {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> std::result::Result<Self, D::Error> {
+        let value = serde_json::Value::deserialize(deserializer)?;
+        match value.get("$type").and_then(|t| t.as_str()) {
+            Some("chat.bsky.convo.defs#logBeginConvo") => serde_json::from_value(value).map(Self::LogBeginConvo).map_err(serde::de::Error::custom),
+            Some("chat.bsky.convo.defs#logAcceptConvo") => serde_json::from_value(value).map(Self::LogAcceptConvo).map_err(serde::de::Error::custom),
+            Some("chat.bsky.convo.defs#logLeaveConvo") => serde_json::from_value(value).map(Self::LogLeaveConvo).map_err(serde::de::Error::custom),
+            Some("chat.bsky.convo.defs#logMuteConvo") => serde_json::from_value(value).map(Self::LogMuteConvo).map_err(serde::de::Error::custom),
+            Some("chat.bsky.convo.defs#logUnmuteConvo") => serde_json::from_value(value).map(Self::LogUnmuteConvo).map_err(serde::de::Error::custom),
+            Some("chat.bsky.convo.defs#logCreateMessage") => serde_json::from_value(value).map(Self::LogCreateMessage).map_err(serde::de::Error::custom),
+            Some("chat.bsky.convo.defs#logDeleteMessage") => serde_json::from_value(value).map(Self::LogDeleteMessage).map_err(serde::de::Error::custom),
+            Some("chat.bsky.convo.defs#logReadMessage") => serde_json::from_value(value).map(Self::LogReadMessage).map_err(serde::de::Error::custom),
+            Some("chat.bsky.convo.defs#logAddReaction") => serde_json::from_value(value).map(Self::LogAddReaction).map_err(serde::de::Error::custom),
+            Some("chat.bsky.convo.defs#logRemoveReaction") => serde_json::from_value(value).map(Self::LogRemoveReaction).map_err(serde::de::Error::custom),
+            _ => Ok(Self::Unknown(value)),
+        }
+    }
 }
 
 /// chat.bsky.convo.getLog
@@ -27,18 +76,22 @@ pub async fn get_log(
     let mut req = XrpcRequest::query("chat.bsky.convo.getLog");
 
     // Add query parameters
-    let params_json = serde_json::to_value(&params)
-        .map_err(XrpcError::Serialization)?;
-
-    if let Some(obj) = params_json.as_object() {
-        for (key, value) in obj {
-            if let Some(s) = value.as_str() {
-                req.params.insert(key.clone(), s.to_string());
-            } else {
-                req.params.insert(key.clone(), value.to_string());
-            }
-        }
+    if let Some(value) = &params.cursor {
+        req.params.append("cursor", value.to_string());
     }
 
-    client.request(req).await
+    client.request_body(req).await
+}
+
+/// Server-side definition of chat.bsky.convo.getLog
+pub struct Endpoint;
+
+impl crate::xrpc::XrpcEndpoint for Endpoint {
+    const NSID: &'static str = "chat.bsky.convo.getLog";
+    const METHOD: crate::xrpc::XrpcMethod = crate::xrpc::XrpcMethod::Query;
+    const INPUT_ENCODING: Option<&'static str> = None;
+    const OUTPUT_ENCODING: Option<&'static str> = Some("application/json");
+    type Params = QueryParams;
+    type Input = ();
+    type Output = Output;
 }
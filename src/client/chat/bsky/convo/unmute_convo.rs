@@ -11,12 +11,16 @@ pub struct Input {
     pub convo_id: String,
 }
 
+impl crate::xrpc::JsonBody for Input {}
+
 /// Response output
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Output {
     pub convo: serde_json::Value,
 }
 
+impl crate::xrpc::JsonBody for Output {}
+
 /// chat.bsky.convo.unmuteConvo
 pub async fn unmute_convo(
     client: &impl XrpcClient,
@@ -24,5 +28,18 @@ pub async fn unmute_convo(
 ) -> Result<XrpcResponse<Output>, XrpcError> {
     let req = XrpcRequest::procedure("chat.bsky.convo.unmuteConvo").data(&input)?;
 
-    client.request(req).await
+    client.request_body(req).await
+}
+
+/// Server-side definition of chat.bsky.convo.unmuteConvo
+pub struct Endpoint;
+
+impl crate::xrpc::XrpcEndpoint for Endpoint {
+    const NSID: &'static str = "chat.bsky.convo.unmuteConvo";
+    const METHOD: crate::xrpc::XrpcMethod = crate::xrpc::XrpcMethod::Procedure;
+    const INPUT_ENCODING: Option<&'static str> = Some("application/json");
+    const OUTPUT_ENCODING: Option<&'static str> = Some("application/json");
+    type Params = ();
+    type Input = Input;
+    type Output = Output;
 }
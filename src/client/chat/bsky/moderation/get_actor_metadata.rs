@@ -13,9 +13,22 @@ pub struct QueryParams {
 /// Response output
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Output {
-    pub all: serde_json::Value,
-    pub month: serde_json::Value,
     pub day: serde_json::Value,
+    pub month: serde_json::Value,
+    pub all: serde_json::Value,
+}
+
+impl crate::xrpc::JsonBody for Output {}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Metadata {
+    #[serde(rename = "messagesSent")]
+    pub messages_sent: i64,
+    #[serde(rename = "messagesReceived")]
+    pub messages_received: i64,
+    pub convos: i64,
+    #[serde(rename = "convosStarted")]
+    pub convos_started: i64,
 }
 
 /// chat.bsky.moderation.getActorMetadata
@@ -26,18 +39,20 @@ pub async fn get_actor_metadata(
     let mut req = XrpcRequest::query("chat.bsky.moderation.getActorMetadata");
 
     // Add query parameters
-    let params_json = serde_json::to_value(&params)
-        .map_err(XrpcError::Serialization)?;
-
-    if let Some(obj) = params_json.as_object() {
-        for (key, value) in obj {
-            if let Some(s) = value.as_str() {
-                req.params.insert(key.clone(), s.to_string());
-            } else {
-                req.params.insert(key.clone(), value.to_string());
-            }
-        }
-    }
-
-    client.request(req).await
+    req.params.append("actor", params.actor.to_string());
+
+    client.request_body(req).await
+}
+
+/// Server-side definition of chat.bsky.moderation.getActorMetadata
+pub struct Endpoint;
+
+impl crate::xrpc::XrpcEndpoint for Endpoint {
+    const NSID: &'static str = "chat.bsky.moderation.getActorMetadata";
+    const METHOD: crate::xrpc::XrpcMethod = crate::xrpc::XrpcMethod::Query;
+    const INPUT_ENCODING: Option<&'static str> = None;
+    const OUTPUT_ENCODING: Option<&'static str> = Some("application/json");
+    type Params = QueryParams;
+    type Input = ();
+    type Output = Output;
 }
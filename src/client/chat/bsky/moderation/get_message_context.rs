@@ -11,18 +11,43 @@ pub struct QueryParams {
     #[serde(skip_serializing_if = "Option::is_none")]
     #[serde(rename = "convoId")]
     pub convo_id: Option<String>,
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub before: Option<i64>,
     #[serde(rename = "messageId")]
     pub message_id: String,
     #[serde(skip_serializing_if = "Option::is_none")]
+    pub before: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub after: Option<i64>,
 }
 
 /// Response output
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Output {
-    pub messages: serde_json::Value,
+    pub messages: Vec<OutputMessages>,
+}
+
+impl crate::xrpc::JsonBody for Output {}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(tag = "$type")]
+pub enum OutputMessages {
+    #[serde(rename = "chat.bsky.convo.defs#messageView")]
+    MessageView(Box<crate::client::chat::bsky::convo::defs::MessageView>),
+    #[serde(rename = "chat.bsky.convo.defs#deletedMessageView")]
+    DeletedMessageView(Box<crate::client::chat::bsky::convo::defs::DeletedMessageView>),
+    /// A type not listed in the lexicon, kept as raw JSON
+    #[serde(untagged)]
+    Unknown(serde_json::Value),
+}
+
+impl<'de> Deserialize<'de> for OutputMessages {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> std::result::Result<Self, D::Error> {
+        let value = serde_json::Value::deserialize(deserializer)?;
+        match value.get("$type").and_then(|t| t.as_str()) {
+            Some("chat.bsky.convo.defs#messageView") => serde_json::from_value(value).map(Self::MessageView).map_err(serde::de::Error::custom),
+            Some("chat.bsky.convo.defs#deletedMessageView") => serde_json::from_value(value).map(Self::DeletedMessageView).map_err(serde::de::Error::custom),
+            _ => Ok(Self::Unknown(value)),
+        }
+    }
 }
 
 /// chat.bsky.moderation.getMessageContext
@@ -33,18 +58,29 @@ pub async fn get_message_context(
     let mut req = XrpcRequest::query("chat.bsky.moderation.getMessageContext");
 
     // Add query parameters
-    let params_json = serde_json::to_value(&params)
-        .map_err(XrpcError::Serialization)?;
-
-    if let Some(obj) = params_json.as_object() {
-        for (key, value) in obj {
-            if let Some(s) = value.as_str() {
-                req.params.insert(key.clone(), s.to_string());
-            } else {
-                req.params.insert(key.clone(), value.to_string());
-            }
-        }
+    if let Some(value) = &params.convo_id {
+        req.params.append("convoId", value.to_string());
+    }
+    req.params.append("messageId", params.message_id.to_string());
+    if let Some(value) = &params.before {
+        req.params.append("before", value.to_string());
     }
+    if let Some(value) = &params.after {
+        req.params.append("after", value.to_string());
+    }
+
+    client.request_body(req).await
+}
+
+/// Server-side definition of chat.bsky.moderation.getMessageContext
+pub struct Endpoint;
 
-    client.request(req).await
+impl crate::xrpc::XrpcEndpoint for Endpoint {
+    const NSID: &'static str = "chat.bsky.moderation.getMessageContext";
+    const METHOD: crate::xrpc::XrpcMethod = crate::xrpc::XrpcMethod::Query;
+    const INPUT_ENCODING: Option<&'static str> = None;
+    const OUTPUT_ENCODING: Option<&'static str> = Some("application/json");
+    type Params = QueryParams;
+    type Input = ();
+    type Output = Output;
 }
@@ -7,14 +7,16 @@ use serde::{Deserialize, Serialize};
 /// Request input
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Input {
-    #[serde(skip_serializing_if = "Option::is_none")]
-    #[serde(rename = "ref")]
-    pub r#ref: Option<String>,
     pub actor: crate::types::Did,
     #[serde(rename = "allowAccess")]
     pub allow_access: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(rename = "ref")]
+    pub r#ref: Option<String>,
 }
 
+impl crate::xrpc::JsonBody for Input {}
+
 /// chat.bsky.moderation.updateActorAccess
 pub async fn update_actor_access(
     client: &impl XrpcClient,
@@ -22,5 +24,18 @@ pub async fn update_actor_access(
 ) -> Result<XrpcResponse<()>, XrpcError> {
     let req = XrpcRequest::procedure("chat.bsky.moderation.updateActorAccess").data(&input)?;
 
-    client.request(req).await
+    client.request_body(req).await
+}
+
+/// Server-side definition of chat.bsky.moderation.updateActorAccess
+pub struct Endpoint;
+
+impl crate::xrpc::XrpcEndpoint for Endpoint {
+    const NSID: &'static str = "chat.bsky.moderation.updateActorAccess";
+    const METHOD: crate::xrpc::XrpcMethod = crate::xrpc::XrpcMethod::Procedure;
+    const INPUT_ENCODING: Option<&'static str> = Some("application/json");
+    const OUTPUT_ENCODING: Option<&'static str> = None;
+    type Params = ();
+    type Input = Input;
+    type Output = ();
 }
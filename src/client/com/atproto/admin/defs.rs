@@ -2,37 +2,46 @@
 
 use serde::{Deserialize, Serialize};
 
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct StatusAttr {
+    pub applied: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(rename = "ref")]
+    pub r#ref: Option<String>,
+}
+
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct AccountView {
+    pub did: crate::types::Did,
+    pub handle: crate::types::Handle,
     #[serde(skip_serializing_if = "Option::is_none")]
-    #[serde(rename = "invitedBy")]
-    pub invited_by: Option<serde_json::Value>,
+    pub email: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    #[serde(rename = "threatSignatures")]
-    pub threat_signatures: Option<serde_json::Value>,
+    #[serde(rename = "relatedRecords")]
+    pub related_records: Option<Vec<serde_json::Value>>,
+    #[serde(rename = "indexedAt")]
+    pub indexed_at: crate::types::Datetime,
     #[serde(skip_serializing_if = "Option::is_none")]
-    #[serde(rename = "emailConfirmedAt")]
-    pub email_confirmed_at: Option<String>,
-    pub did: crate::types::Did,
+    #[serde(rename = "invitedBy")]
+    pub invited_by: Option<serde_json::Value>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    #[serde(rename = "deactivatedAt")]
-    pub deactivated_at: Option<String>,
+    pub invites: Option<Vec<serde_json::Value>>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub email: Option<String>,
-    #[serde(rename = "indexedAt")]
-    pub indexed_at: String,
+    #[serde(rename = "invitesDisabled")]
+    pub invites_disabled: Option<bool>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    #[serde(rename = "relatedRecords")]
-    pub related_records: Option<serde_json::Value>,
+    #[serde(rename = "emailConfirmedAt")]
+    pub email_confirmed_at: Option<crate::types::Datetime>,
     #[serde(skip_serializing_if = "Option::is_none")]
     #[serde(rename = "inviteNote")]
     pub invite_note: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub invites: Option<serde_json::Value>,
+    #[serde(rename = "deactivatedAt")]
+    pub deactivated_at: Option<crate::types::Datetime>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    #[serde(rename = "invitesDisabled")]
-    pub invites_disabled: Option<bool>,
-    pub handle: String,
+    #[serde(rename = "threatSignatures")]
+    pub threat_signatures: Option<Vec<serde_json::Value>>,
 }
 
 
@@ -44,8 +53,8 @@ pub struct RepoRef {
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct RepoBlobRef {
-    pub cid: String,
     pub did: crate::types::Did,
+    pub cid: crate::types::CidLink,
     #[serde(skip_serializing_if = "Option::is_none")]
     #[serde(rename = "recordUri")]
     pub record_uri: Option<crate::syntax::AtUri>,
@@ -59,12 +68,3 @@ pub struct ThreatSignature {
 }
 
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct StatusAttr {
-    pub applied: bool,
-    #[serde(skip_serializing_if = "Option::is_none")]
-    #[serde(rename = "ref")]
-    pub r#ref: Option<String>,
-}
-
-
@@ -11,6 +11,8 @@ pub struct Input {
     pub did: crate::types::Did,
 }
 
+impl crate::xrpc::JsonBody for Input {}
+
 /// Delete a user account as an administrator.
 pub async fn delete_account(
     client: &impl XrpcClient,
@@ -18,5 +20,18 @@ pub async fn delete_account(
 ) -> Result<XrpcResponse<()>, XrpcError> {
     let req = XrpcRequest::procedure("com.atproto.admin.deleteAccount").data(&input)?;
 
-    client.request(req).await
+    client.request_body(req).await
+}
+
+/// Server-side definition of com.atproto.admin.deleteAccount
+pub struct Endpoint;
+
+impl crate::xrpc::XrpcEndpoint for Endpoint {
+    const NSID: &'static str = "com.atproto.admin.deleteAccount";
+    const METHOD: crate::xrpc::XrpcMethod = crate::xrpc::XrpcMethod::Procedure;
+    const INPUT_ENCODING: Option<&'static str> = Some("application/json");
+    const OUTPUT_ENCODING: Option<&'static str> = None;
+    type Params = ();
+    type Input = Input;
+    type Output = ();
 }
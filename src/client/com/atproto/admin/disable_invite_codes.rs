@@ -9,11 +9,13 @@ use serde::{Deserialize, Serialize};
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Input {
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub codes: Option<serde_json::Value>,
+    pub codes: Option<Vec<String>>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub accounts: Option<serde_json::Value>,
+    pub accounts: Option<Vec<String>>,
 }
 
+impl crate::xrpc::JsonBody for Input {}
+
 /// Disable some set of codes and/or all codes associated with a set of users.
 pub async fn disable_invite_codes(
     client: &impl XrpcClient,
@@ -21,5 +23,18 @@ pub async fn disable_invite_codes(
 ) -> Result<XrpcResponse<()>, XrpcError> {
     let req = XrpcRequest::procedure("com.atproto.admin.disableInviteCodes").data(&input)?;
 
-    client.request(req).await
+    client.request_body(req).await
+}
+
+/// Server-side definition of com.atproto.admin.disableInviteCodes
+pub struct Endpoint;
+
+impl crate::xrpc::XrpcEndpoint for Endpoint {
+    const NSID: &'static str = "com.atproto.admin.disableInviteCodes";
+    const METHOD: crate::xrpc::XrpcMethod = crate::xrpc::XrpcMethod::Procedure;
+    const INPUT_ENCODING: Option<&'static str> = Some("application/json");
+    const OUTPUT_ENCODING: Option<&'static str> = None;
+    type Params = ();
+    type Input = Input;
+    type Output = ();
 }
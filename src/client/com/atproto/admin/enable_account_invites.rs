@@ -14,6 +14,8 @@ pub struct Input {
     pub note: Option<String>,
 }
 
+impl crate::xrpc::JsonBody for Input {}
+
 /// Re-enable an account's ability to receive invite codes.
 pub async fn enable_account_invites(
     client: &impl XrpcClient,
@@ -21,5 +23,18 @@ pub async fn enable_account_invites(
 ) -> Result<XrpcResponse<()>, XrpcError> {
     let req = XrpcRequest::procedure("com.atproto.admin.enableAccountInvites").data(&input)?;
 
-    client.request(req).await
+    client.request_body(req).await
+}
+
+/// Server-side definition of com.atproto.admin.enableAccountInvites
+pub struct Endpoint;
+
+impl crate::xrpc::XrpcEndpoint for Endpoint {
+    const NSID: &'static str = "com.atproto.admin.enableAccountInvites";
+    const METHOD: crate::xrpc::XrpcMethod = crate::xrpc::XrpcMethod::Procedure;
+    const INPUT_ENCODING: Option<&'static str> = Some("application/json");
+    const OUTPUT_ENCODING: Option<&'static str> = None;
+    type Params = ();
+    type Input = Input;
+    type Output = ();
 }
@@ -11,19 +11,47 @@ pub struct QueryParams {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub did: Option<crate::types::Did>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub blob: Option<String>,
-    #[serde(skip_serializing_if = "Option::is_none")]
     pub uri: Option<crate::syntax::AtUri>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub blob: Option<crate::types::CidLink>,
 }
 
 /// Response output
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Output {
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub deactivated: Option<serde_json::Value>,
+    pub subject: OutputSubject,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub takedown: Option<serde_json::Value>,
-    pub subject: serde_json::Value,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub deactivated: Option<serde_json::Value>,
+}
+
+impl crate::xrpc::JsonBody for Output {}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(tag = "$type")]
+pub enum OutputSubject {
+    #[serde(rename = "com.atproto.admin.defs#repoRef")]
+    RepoRef(Box<crate::client::com::atproto::admin::defs::RepoRef>),
+    #[serde(rename = "com.atproto.repo.strongRef")]
+    StrongRef(Box<crate::client::com::atproto::repo::strong_ref::Main>),
+    #[serde(rename = "com.atproto.admin.defs#repoBlobRef")]
+    RepoBlobRef(Box<crate::client::com::atproto::admin::defs::RepoBlobRef>),
+    /// A type not listed in the lexicon, kept as raw JSON
+    #[serde(untagged)]
+    Unknown(serde_json::Value),
+}
+
+impl<'de> Deserialize<'de> for OutputSubject {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> std::result::Result<Self, D::Error> {
+        let value = serde_json::Value::deserialize(deserializer)?;
+        match value.get("$type").and_then(|t| t.as_str()) {
+            Some("com.atproto.admin.defs#repoRef") => serde_json::from_value(value).map(Self::RepoRef).map_err(serde::de::Error::custom),
+            Some("com.atproto.repo.strongRef") => serde_json::from_value(value).map(Self::StrongRef).map_err(serde::de::Error::custom),
+            Some("com.atproto.admin.defs#repoBlobRef") => serde_json::from_value(value).map(Self::RepoBlobRef).map_err(serde::de::Error::custom),
+            _ => Ok(Self::Unknown(value)),
+        }
+    }
 }
 
 /// Get the service-specific admin status of a subject (account, record, or blob).
@@ -34,18 +62,28 @@ pub async fn get_subject_status(
     let mut req = XrpcRequest::query("com.atproto.admin.getSubjectStatus");
 
     // Add query parameters
-    let params_json = serde_json::to_value(&params)
-        .map_err(XrpcError::Serialization)?;
-
-    if let Some(obj) = params_json.as_object() {
-        for (key, value) in obj {
-            if let Some(s) = value.as_str() {
-                req.params.insert(key.clone(), s.to_string());
-            } else {
-                req.params.insert(key.clone(), value.to_string());
-            }
-        }
+    if let Some(value) = &params.did {
+        req.params.append("did", value.to_string());
+    }
+    if let Some(value) = &params.uri {
+        req.params.append("uri", value.to_string());
     }
+    if let Some(value) = &params.blob {
+        req.params.append("blob", value.to_string());
+    }
+
+    client.request_body(req).await
+}
+
+/// Server-side definition of com.atproto.admin.getSubjectStatus
+pub struct Endpoint;
 
-    client.request(req).await
+impl crate::xrpc::XrpcEndpoint for Endpoint {
+    const NSID: &'static str = "com.atproto.admin.getSubjectStatus";
+    const METHOD: crate::xrpc::XrpcMethod = crate::xrpc::XrpcMethod::Query;
+    const INPUT_ENCODING: Option<&'static str> = None;
+    const OUTPUT_ENCODING: Option<&'static str> = Some("application/json");
+    type Params = QueryParams;
+    type Input = ();
+    type Output = Output;
 }
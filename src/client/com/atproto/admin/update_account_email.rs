@@ -8,11 +8,13 @@ use serde::{Deserialize, Serialize};
 /// Request input
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Input {
-    pub email: String,
     /// The handle or DID of the repo.
     pub account: String,
+    pub email: String,
 }
 
+impl crate::xrpc::JsonBody for Input {}
+
 /// Administrative action to update an account's email.
 pub async fn update_account_email(
     client: &impl XrpcClient,
@@ -20,5 +22,18 @@ pub async fn update_account_email(
 ) -> Result<XrpcResponse<()>, XrpcError> {
     let req = XrpcRequest::procedure("com.atproto.admin.updateAccountEmail").data(&input)?;
 
-    client.request(req).await
+    client.request_body(req).await
+}
+
+/// Server-side definition of com.atproto.admin.updateAccountEmail
+pub struct Endpoint;
+
+impl crate::xrpc::XrpcEndpoint for Endpoint {
+    const NSID: &'static str = "com.atproto.admin.updateAccountEmail";
+    const METHOD: crate::xrpc::XrpcMethod = crate::xrpc::XrpcMethod::Procedure;
+    const INPUT_ENCODING: Option<&'static str> = Some("application/json");
+    const OUTPUT_ENCODING: Option<&'static str> = None;
+    type Params = ();
+    type Input = Input;
+    type Output = ();
 }
@@ -8,10 +8,12 @@ use serde::{Deserialize, Serialize};
 /// Request input
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Input {
-    pub handle: String,
     pub did: crate::types::Did,
+    pub handle: crate::types::Handle,
 }
 
+impl crate::xrpc::JsonBody for Input {}
+
 /// Administrative action to update an account's handle.
 pub async fn update_account_handle(
     client: &impl XrpcClient,
@@ -19,5 +21,18 @@ pub async fn update_account_handle(
 ) -> Result<XrpcResponse<()>, XrpcError> {
     let req = XrpcRequest::procedure("com.atproto.admin.updateAccountHandle").data(&input)?;
 
-    client.request(req).await
+    client.request_body(req).await
+}
+
+/// Server-side definition of com.atproto.admin.updateAccountHandle
+pub struct Endpoint;
+
+impl crate::xrpc::XrpcEndpoint for Endpoint {
+    const NSID: &'static str = "com.atproto.admin.updateAccountHandle";
+    const METHOD: crate::xrpc::XrpcMethod = crate::xrpc::XrpcMethod::Procedure;
+    const INPUT_ENCODING: Option<&'static str> = Some("application/json");
+    const OUTPUT_ENCODING: Option<&'static str> = None;
+    type Params = ();
+    type Input = Input;
+    type Output = ();
 }
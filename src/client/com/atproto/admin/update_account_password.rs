@@ -12,6 +12,8 @@ pub struct Input {
     pub password: String,
 }
 
+impl crate::xrpc::JsonBody for Input {}
+
 /// Update the password for a user account as an administrator.
 pub async fn update_account_password(
     client: &impl XrpcClient,
@@ -19,5 +21,18 @@ pub async fn update_account_password(
 ) -> Result<XrpcResponse<()>, XrpcError> {
     let req = XrpcRequest::procedure("com.atproto.admin.updateAccountPassword").data(&input)?;
 
-    client.request(req).await
+    client.request_body(req).await
+}
+
+/// Server-side definition of com.atproto.admin.updateAccountPassword
+pub struct Endpoint;
+
+impl crate::xrpc::XrpcEndpoint for Endpoint {
+    const NSID: &'static str = "com.atproto.admin.updateAccountPassword";
+    const METHOD: crate::xrpc::XrpcMethod = crate::xrpc::XrpcMethod::Procedure;
+    const INPUT_ENCODING: Option<&'static str> = Some("application/json");
+    const OUTPUT_ENCODING: Option<&'static str> = None;
+    type Params = ();
+    type Input = Input;
+    type Output = ();
 }
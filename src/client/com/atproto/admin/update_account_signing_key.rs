@@ -14,6 +14,8 @@ pub struct Input {
     pub signing_key: crate::types::Did,
 }
 
+impl crate::xrpc::JsonBody for Input {}
+
 /// Administrative action to update an account's signing key in their Did document.
 pub async fn update_account_signing_key(
     client: &impl XrpcClient,
@@ -21,5 +23,18 @@ pub async fn update_account_signing_key(
 ) -> Result<XrpcResponse<()>, XrpcError> {
     let req = XrpcRequest::procedure("com.atproto.admin.updateAccountSigningKey").data(&input)?;
 
-    client.request(req).await
+    client.request_body(req).await
+}
+
+/// Server-side definition of com.atproto.admin.updateAccountSigningKey
+pub struct Endpoint;
+
+impl crate::xrpc::XrpcEndpoint for Endpoint {
+    const NSID: &'static str = "com.atproto.admin.updateAccountSigningKey";
+    const METHOD: crate::xrpc::XrpcMethod = crate::xrpc::XrpcMethod::Procedure;
+    const INPUT_ENCODING: Option<&'static str> = Some("application/json");
+    const OUTPUT_ENCODING: Option<&'static str> = None;
+    type Params = ();
+    type Input = Input;
+    type Output = ();
 }
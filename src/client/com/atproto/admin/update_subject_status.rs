@@ -8,19 +8,75 @@ use serde::{Deserialize, Serialize};
 /// Request input
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Input {
-    pub subject: serde_json::Value,
+    pub subject: InputSubject,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub takedown: Option<serde_json::Value>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub deactivated: Option<serde_json::Value>,
 }
 
+impl crate::xrpc::JsonBody for Input {}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(tag = "$type")]
+pub enum InputSubject {
+    #[serde(rename = "com.atproto.admin.defs#repoRef")]
+    RepoRef(Box<crate::client::com::atproto::admin::defs::RepoRef>),
+    #[serde(rename = "com.atproto.repo.strongRef")]
+    StrongRef(Box<crate::client::com::atproto::repo::strong_ref::Main>),
+    #[serde(rename = "com.atproto.admin.defs#repoBlobRef")]
+    RepoBlobRef(Box<crate::client::com::atproto::admin::defs::RepoBlobRef>),
+    /// A type not listed in the lexicon, kept as raw JSON
+    #[serde(untagged)]
+    Unknown(serde_json::Value),
+}
+
+impl<'de> Deserialize<'de> for InputSubject {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> std::result::Result<Self, D::Error> {
+        let value = serde_json::Value::deserialize(deserializer)?;
+        match value.get("$type").and_then(|t| t.as_str()) {
+            Some("com.atproto.admin.defs#repoRef") => serde_json::from_value(value).map(Self::RepoRef).map_err(serde::de::Error::custom),
+            Some("com.atproto.repo.strongRef") => serde_json::from_value(value).map(Self::StrongRef).map_err(serde::de::Error::custom),
+            Some("com.atproto.admin.defs#repoBlobRef") => serde_json::from_value(value).map(Self::RepoBlobRef).map_err(serde::de::Error::custom),
+            _ => Ok(Self::Unknown(value)),
+        }
+    }
+}
+
 /// Response output
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Output {
+    pub subject: OutputSubject,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub takedown: Option<serde_json::Value>,
-    pub subject: serde_json::Value,
+}
+
+impl crate::xrpc::JsonBody for Output {}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(tag = "$type")]
+pub enum OutputSubject {
+    #[serde(rename = "com.atproto.admin.defs#repoRef")]
+    RepoRef(Box<crate::client::com::atproto::admin::defs::RepoRef>),
+    #[serde(rename = "com.atproto.repo.strongRef")]
+    StrongRef(Box<crate::client::com::atproto::repo::strong_ref::Main>),
+    #[serde(rename = "com.atproto.admin.defs#repoBlobRef")]
+    RepoBlobRef(Box<crate::client::com::atproto::admin::defs::RepoBlobRef>),
+    /// A type not listed in the lexicon, kept as raw JSON
+    #[serde(untagged)]
+    Unknown(serde_json::Value),
+}
+
+impl<'de> Deserialize<'de> for OutputSubject {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> std::result::Result<Self, D::Error> {
+        let value = serde_json::Value::deserialize(deserializer)?;
+        match value.get("$type").and_then(|t| t.as_str()) {
+            Some("com.atproto.admin.defs#repoRef") => serde_json::from_value(value).map(Self::RepoRef).map_err(serde::de::Error::custom),
+            Some("com.atproto.repo.strongRef") => serde_json::from_value(value).map(Self::StrongRef).map_err(serde::de::Error::custom),
+            Some("com.atproto.admin.defs#repoBlobRef") => serde_json::from_value(value).map(Self::RepoBlobRef).map_err(serde::de::Error::custom),
+            _ => Ok(Self::Unknown(value)),
+        }
+    }
 }
 
 /// Update the service-specific admin status of a subject (account, record, or blob).
@@ -30,5 +86,18 @@ pub async fn update_subject_status(
 ) -> Result<XrpcResponse<Output>, XrpcError> {
     let req = XrpcRequest::procedure("com.atproto.admin.updateSubjectStatus").data(&input)?;
 
-    client.request(req).await
+    client.request_body(req).await
+}
+
+/// Server-side definition of com.atproto.admin.updateSubjectStatus
+pub struct Endpoint;
+
+impl crate::xrpc::XrpcEndpoint for Endpoint {
+    const NSID: &'static str = "com.atproto.admin.updateSubjectStatus";
+    const METHOD: crate::xrpc::XrpcMethod = crate::xrpc::XrpcMethod::Procedure;
+    const INPUT_ENCODING: Option<&'static str> = Some("application/json");
+    const OUTPUT_ENCODING: Option<&'static str> = Some("application/json");
+    type Params = ();
+    type Input = Input;
+    type Output = Output;
 }
@@ -8,25 +8,40 @@ use serde::{Deserialize, Serialize};
 /// Response output
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Output {
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub services: Option<serde_json::Value>,
     /// Recommended rotation keys for PLC dids. Should be undefined (or ignored) for did:webs.
     #[serde(skip_serializing_if = "Option::is_none")]
     #[serde(rename = "rotationKeys")]
-    pub rotation_keys: Option<serde_json::Value>,
+    pub rotation_keys: Option<Vec<String>>,
     #[serde(skip_serializing_if = "Option::is_none")]
     #[serde(rename = "alsoKnownAs")]
-    pub also_known_as: Option<serde_json::Value>,
+    pub also_known_as: Option<Vec<String>>,
     #[serde(skip_serializing_if = "Option::is_none")]
     #[serde(rename = "verificationMethods")]
     pub verification_methods: Option<serde_json::Value>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub services: Option<serde_json::Value>,
 }
 
+impl crate::xrpc::JsonBody for Output {}
+
 /// Describe the credentials that should be included in the DID doc of an account that is migrating to this service.
 pub async fn get_recommended_did_credentials(
     client: &impl XrpcClient,
 ) -> Result<XrpcResponse<Output>, XrpcError> {
     let req = XrpcRequest::query("com.atproto.identity.getRecommendedDidCredentials");
 
-    client.request(req).await
+    client.request_body(req).await
+}
+
+/// Server-side definition of com.atproto.identity.getRecommendedDidCredentials
+pub struct Endpoint;
+
+impl crate::xrpc::XrpcEndpoint for Endpoint {
+    const NSID: &'static str = "com.atproto.identity.getRecommendedDidCredentials";
+    const METHOD: crate::xrpc::XrpcMethod = crate::xrpc::XrpcMethod::Query;
+    const INPUT_ENCODING: Option<&'static str> = None;
+    const OUTPUT_ENCODING: Option<&'static str> = Some("application/json");
+    type Params = ();
+    type Input = ();
+    type Output = Output;
 }
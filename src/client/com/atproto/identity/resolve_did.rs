@@ -20,6 +20,8 @@ pub struct Output {
     pub did_doc: serde_json::Value,
 }
 
+impl crate::xrpc::JsonBody for Output {}
+
 /// Error: DidNotFound
 /// The DID resolution process confirmed that there is no current DID.
 #[derive(Debug, Clone, thiserror::Error)]
@@ -40,18 +42,20 @@ pub async fn resolve_did(
     let mut req = XrpcRequest::query("com.atproto.identity.resolveDid");
 
     // Add query parameters
-    let params_json = serde_json::to_value(&params)
-        .map_err(XrpcError::Serialization)?;
-
-    if let Some(obj) = params_json.as_object() {
-        for (key, value) in obj {
-            if let Some(s) = value.as_str() {
-                req.params.insert(key.clone(), s.to_string());
-            } else {
-                req.params.insert(key.clone(), value.to_string());
-            }
-        }
-    }
-
-    client.request(req).await
+    req.params.append("did", params.did.to_string());
+
+    client.request_body(req).await
+}
+
+/// Server-side definition of com.atproto.identity.resolveDid
+pub struct Endpoint;
+
+impl crate::xrpc::XrpcEndpoint for Endpoint {
+    const NSID: &'static str = "com.atproto.identity.resolveDid";
+    const METHOD: crate::xrpc::XrpcMethod = crate::xrpc::XrpcMethod::Query;
+    const INPUT_ENCODING: Option<&'static str> = None;
+    const OUTPUT_ENCODING: Option<&'static str> = Some("application/json");
+    type Params = QueryParams;
+    type Input = ();
+    type Output = Output;
 }
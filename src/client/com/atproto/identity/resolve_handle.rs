@@ -9,7 +9,7 @@ use serde::{Deserialize, Serialize};
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct QueryParams {
     /// The handle to resolve.
-    pub handle: String,
+    pub handle: crate::types::Handle,
 }
 
 /// Response output
@@ -18,6 +18,8 @@ pub struct Output {
     pub did: crate::types::Did,
 }
 
+impl crate::xrpc::JsonBody for Output {}
+
 /// Error: HandleNotFound
 /// The resolution process confirmed that the handle does not resolve to any DID.
 #[derive(Debug, Clone, thiserror::Error)]
@@ -32,18 +34,20 @@ pub async fn resolve_handle(
     let mut req = XrpcRequest::query("com.atproto.identity.resolveHandle");
 
     // Add query parameters
-    let params_json = serde_json::to_value(&params)
-        .map_err(XrpcError::Serialization)?;
-
-    if let Some(obj) = params_json.as_object() {
-        for (key, value) in obj {
-            if let Some(s) = value.as_str() {
-                req.params.insert(key.clone(), s.to_string());
-            } else {
-                req.params.insert(key.clone(), value.to_string());
-            }
-        }
-    }
-
-    client.request(req).await
+    req.params.append("handle", params.handle.to_string());
+
+    client.request_body(req).await
+}
+
+/// Server-side definition of com.atproto.identity.resolveHandle
+pub struct Endpoint;
+
+impl crate::xrpc::XrpcEndpoint for Endpoint {
+    const NSID: &'static str = "com.atproto.identity.resolveHandle";
+    const METHOD: crate::xrpc::XrpcMethod = crate::xrpc::XrpcMethod::Query;
+    const INPUT_ENCODING: Option<&'static str> = None;
+    const OUTPUT_ENCODING: Option<&'static str> = Some("application/json");
+    type Params = QueryParams;
+    type Input = ();
+    type Output = Output;
 }
@@ -8,22 +8,24 @@ use serde::{Deserialize, Serialize};
 /// Request input
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Input {
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub services: Option<serde_json::Value>,
-    #[serde(skip_serializing_if = "Option::is_none")]
-    #[serde(rename = "verificationMethods")]
-    pub verification_methods: Option<serde_json::Value>,
-    #[serde(skip_serializing_if = "Option::is_none")]
-    #[serde(rename = "rotationKeys")]
-    pub rotation_keys: Option<serde_json::Value>,
     /// A token received through com.atproto.identity.requestPlcOperationSignature
     #[serde(skip_serializing_if = "Option::is_none")]
     pub token: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(rename = "rotationKeys")]
+    pub rotation_keys: Option<Vec<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     #[serde(rename = "alsoKnownAs")]
-    pub also_known_as: Option<serde_json::Value>,
+    pub also_known_as: Option<Vec<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(rename = "verificationMethods")]
+    pub verification_methods: Option<serde_json::Value>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub services: Option<serde_json::Value>,
 }
 
+impl crate::xrpc::JsonBody for Input {}
+
 /// Response output
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Output {
@@ -31,6 +33,8 @@ pub struct Output {
     pub operation: serde_json::Value,
 }
 
+impl crate::xrpc::JsonBody for Output {}
+
 /// Signs a PLC operation to update some value(s) in the requesting DID's document.
 pub async fn sign_plc_operation(
     client: &impl XrpcClient,
@@ -38,5 +42,18 @@ pub async fn sign_plc_operation(
 ) -> Result<XrpcResponse<Output>, XrpcError> {
     let req = XrpcRequest::procedure("com.atproto.identity.signPlcOperation").data(&input)?;
 
-    client.request(req).await
+    client.request_body(req).await
+}
+
+/// Server-side definition of com.atproto.identity.signPlcOperation
+pub struct Endpoint;
+
+impl crate::xrpc::XrpcEndpoint for Endpoint {
+    const NSID: &'static str = "com.atproto.identity.signPlcOperation";
+    const METHOD: crate::xrpc::XrpcMethod = crate::xrpc::XrpcMethod::Procedure;
+    const INPUT_ENCODING: Option<&'static str> = Some("application/json");
+    const OUTPUT_ENCODING: Option<&'static str> = Some("application/json");
+    type Params = ();
+    type Input = Input;
+    type Output = Output;
 }
@@ -11,6 +11,8 @@ pub struct Input {
     pub operation: serde_json::Value,
 }
 
+impl crate::xrpc::JsonBody for Input {}
+
 /// Validates a PLC operation to ensure that it doesn't violate a service's constraints or get the identity into a bad state, then submits it to the PLC registry
 pub async fn submit_plc_operation(
     client: &impl XrpcClient,
@@ -18,5 +20,18 @@ pub async fn submit_plc_operation(
 ) -> Result<XrpcResponse<()>, XrpcError> {
     let req = XrpcRequest::procedure("com.atproto.identity.submitPlcOperation").data(&input)?;
 
-    client.request(req).await
+    client.request_body(req).await
+}
+
+/// Server-side definition of com.atproto.identity.submitPlcOperation
+pub struct Endpoint;
+
+impl crate::xrpc::XrpcEndpoint for Endpoint {
+    const NSID: &'static str = "com.atproto.identity.submitPlcOperation";
+    const METHOD: crate::xrpc::XrpcMethod = crate::xrpc::XrpcMethod::Procedure;
+    const INPUT_ENCODING: Option<&'static str> = Some("application/json");
+    const OUTPUT_ENCODING: Option<&'static str> = None;
+    type Params = ();
+    type Input = Input;
+    type Output = ();
 }
@@ -2,7 +2,7 @@
 //!
 //! Subscribe to stream of labels (and negations). Public endpoint implemented by mod services. Uses same sequencing scheme as repo event stream.
 
-use crate::xrpc::{XrpcRequest, XrpcError};
+use crate::xrpc::XrpcRequest;
 use crate::xrpc_subscription::{SubscriptionClient, SubscriptionEvent, SubscriptionResult};
 use serde::{Deserialize, Serialize};
 use futures::stream::Stream;
@@ -24,17 +24,8 @@ pub async fn subscribe_labels(
     let mut req = XrpcRequest::query("com.atproto.label.subscribeLabels");
 
     // Add query parameters
-    let params_json = serde_json::to_value(&params)
-        .map_err(XrpcError::Serialization)?;
-
-    if let Some(obj) = params_json.as_object() {
-        for (key, value) in obj {
-            if let Some(s) = value.as_str() {
-                req.params.insert(key.clone(), s.to_string());
-            } else {
-                req.params.insert(key.clone(), value.to_string());
-            }
-        }
+    if let Some(value) = &params.cursor {
+        req.params.append("cursor", value.to_string());
     }
 
     client.subscribe(req).await
@@ -8,31 +8,93 @@ use serde::{Deserialize, Serialize};
 /// Request input
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Input {
-    pub subject: serde_json::Value,
-    #[serde(skip_serializing_if = "Option::is_none")]
-    #[serde(rename = "modTool")]
-    pub mod_tool: Option<serde_json::Value>,
     /// Indicates the broad category of violation the report is for.
     #[serde(rename = "reasonType")]
     pub reason_type: serde_json::Value,
     /// Additional context about the content and violation.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub reason: Option<String>,
+    pub subject: InputSubject,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(rename = "modTool")]
+    pub mod_tool: Option<serde_json::Value>,
+}
+
+impl crate::xrpc::JsonBody for Input {}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(tag = "$type")]
+pub enum InputSubject {
+    #[serde(rename = "com.atproto.admin.defs#repoRef")]
+    RepoRef(Box<crate::client::com::atproto::admin::defs::RepoRef>),
+    #[serde(rename = "com.atproto.repo.strongRef")]
+    StrongRef(Box<crate::client::com::atproto::repo::strong_ref::Main>),
+    /// A type not listed in the lexicon, kept as raw JSON
+    #[serde(untagged)]
+    Unknown(serde_json::Value),
+}
+
+impl<'de> Deserialize<'de> for InputSubject {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> std::result::Result<Self, D::Error> {
+        let value = serde_json::Value::deserialize(deserializer)?;
+        match value.get("$type").and_then(|t| t.as_str()) {
+            Some("com.atproto.admin.defs#repoRef") => serde_json::from_value(value).map(Self::RepoRef).map_err(serde::de::Error::custom),
+            Some("com.atproto.repo.strongRef") => serde_json::from_value(value).map(Self::StrongRef).map_err(serde::de::Error::custom),
+            _ => Ok(Self::Unknown(value)),
+        }
+    }
 }
 
 /// Response output
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Output {
+    pub id: i64,
     #[serde(rename = "reasonType")]
     pub reason_type: serde_json::Value,
-    pub id: i64,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub reason: Option<String>,
+    pub subject: OutputSubject,
     #[serde(rename = "reportedBy")]
     pub reported_by: crate::types::Did,
     #[serde(rename = "createdAt")]
-    pub created_at: String,
-    pub subject: serde_json::Value,
+    pub created_at: crate::types::Datetime,
+}
+
+impl crate::xrpc::JsonBody for Output {}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(tag = "$type")]
+pub enum OutputSubject {
+    #[serde(rename = "com.atproto.admin.defs#repoRef")]
+    RepoRef(Box<crate::client::com::atproto::admin::defs::RepoRef>),
+    #[serde(rename = "com.atproto.repo.strongRef")]
+    StrongRef(Box<crate::client::com::atproto::repo::strong_ref::Main>),
+    /// A type not listed in the lexicon, kept as raw JSON
+    #[serde(untagged)]
+    Unknown(serde_json::Value),
+}
+
+impl<'de> Deserialize<'de> for OutputSubject {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> std::result::Result<Self, D::Error> {
+        let value = serde_json::Value::deserialize(deserializer)?;
+        match value.get("$type").and_then(|t| t.as_str()) {
+            Some("com.atproto.admin.defs#repoRef") => serde_json::from_value(value).map(Self::RepoRef).map_err(serde::de::Error::custom),
+            Some("com.atproto.repo.strongRef") => serde_json::from_value(value).map(Self::StrongRef).map_err(serde::de::Error::custom),
+            _ => Ok(Self::Unknown(value)),
+        }
+    }
+}
+
+/// Moderation tool information for tracing the source of the action
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ModTool {
+    /// Name/identifier of the source (e.g., 'bsky-app/android', 'bsky-web/chrome')
+    /// Moderation tool information for tracing the source of the action
+    pub name: String,
+    /// Additional arbitrary metadata about the source
+    /// Moderation tool information for tracing the source of the action
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub meta: Option<serde_json::Value>,
 }
 
 /// Submit a moderation report regarding an atproto account or record. Implemented by moderation services (with PDS proxying), and requires auth.
@@ -42,5 +104,18 @@ pub async fn create_report(
 ) -> Result<XrpcResponse<Output>, XrpcError> {
     let req = XrpcRequest::procedure("com.atproto.moderation.createReport").data(&input)?;
 
-    client.request(req).await
+    client.request_body(req).await
+}
+
+/// Server-side definition of com.atproto.moderation.createReport
+pub struct Endpoint;
+
+impl crate::xrpc::XrpcEndpoint for Endpoint {
+    const NSID: &'static str = "com.atproto.moderation.createReport";
+    const METHOD: crate::xrpc::XrpcMethod = crate::xrpc::XrpcMethod::Procedure;
+    const INPUT_ENCODING: Option<&'static str> = Some("application/json");
+    const OUTPUT_ENCODING: Option<&'static str> = Some("application/json");
+    type Params = ();
+    type Input = Input;
+    type Output = Output;
 }
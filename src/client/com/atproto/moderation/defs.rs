@@ -2,6 +2,8 @@
 
 use serde::{Deserialize, Serialize};
 
+pub type ReasonType = String;
+
 /// Spam: frequent unwanted promotion, replies, mentions. Prefer new lexicon definition `tools.ozone.report.defs#reasonMisleadingSpam`.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ReasonSpam;
@@ -10,6 +12,10 @@ pub struct ReasonSpam;
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ReasonViolation;
 
+/// Misleading identity, affiliation, or content. Prefer new lexicon definition `tools.ozone.report.defs#reasonMisleadingOther`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ReasonMisleading;
+
 /// Unwanted or mislabeled sexual content. Prefer new lexicon definition `tools.ozone.report.defs#reasonSexualUnlabeled`.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ReasonSexual;
@@ -22,8 +28,6 @@ pub struct ReasonRude;
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ReasonOther;
 
-pub type ReasonType = String;
-
 /// Appeal a previously taken moderation action
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ReasonAppeal;
@@ -31,7 +35,3 @@ pub struct ReasonAppeal;
 /// Tag describing a type of subject that might be reported.
 pub type SubjectType = String;
 
-/// Misleading identity, affiliation, or content. Prefer new lexicon definition `tools.ozone.report.defs#reasonMisleadingOther`.
-#[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct ReasonMisleading;
-
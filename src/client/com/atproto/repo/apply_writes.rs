@@ -8,25 +8,106 @@ use serde::{Deserialize, Serialize};
 /// Request input
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Input {
-    /// If provided, the entire operation will fail if the current repo commit CID does not match this value. Used to prevent conflicting repo mutations.
-    #[serde(skip_serializing_if = "Option::is_none")]
-    #[serde(rename = "swapCommit")]
-    pub swap_commit: Option<String>,
-    pub writes: serde_json::Value,
     /// The handle or DID of the repo (aka, current account).
     pub repo: String,
     /// Can be set to 'false' to skip Lexicon schema validation of record data across all operations, 'true' to require it, or leave unset to validate only for known Lexicons.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub validate: Option<bool>,
+    pub writes: Vec<InputWrites>,
+    /// If provided, the entire operation will fail if the current repo commit CID does not match this value. Used to prevent conflicting repo mutations.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(rename = "swapCommit")]
+    pub swap_commit: Option<crate::types::CidLink>,
+}
+
+impl crate::xrpc::JsonBody for Input {}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "$type")]
+pub enum InputWrites {
+    #[serde(rename = "com.atproto.repo.applyWrites#create")]
+    Create(Box<Create>),
+    #[serde(rename = "com.atproto.repo.applyWrites#update")]
+    Update(Box<Update>),
+    #[serde(rename = "com.atproto.repo.applyWrites#delete")]
+    Delete(Box<Delete>),
 }
 
 /// Response output
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Output {
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub results: Option<serde_json::Value>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub commit: Option<serde_json::Value>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub results: Option<Vec<OutputResults>>,
+}
+
+impl crate::xrpc::JsonBody for Output {}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "$type")]
+pub enum OutputResults {
+    #[serde(rename = "com.atproto.repo.applyWrites#createResult")]
+    CreateResult(Box<CreateResult>),
+    #[serde(rename = "com.atproto.repo.applyWrites#updateResult")]
+    UpdateResult(Box<UpdateResult>),
+    #[serde(rename = "com.atproto.repo.applyWrites#deleteResult")]
+    DeleteResult(Box<DeleteResult>),
+}
+
+/// Operation which creates a new record.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Create {
+    /// Operation which creates a new record.
+    pub collection: crate::types::Nsid,
+    /// NOTE: maxLength is redundant with record-key format. Keeping it temporarily to ensure backwards compatibility.
+    /// Operation which creates a new record.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub rkey: Option<crate::types::RecordKey>,
+    /// Operation which creates a new record.
+    pub value: serde_json::Value,
+}
+
+/// Operation which updates an existing record.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Update {
+    /// Operation which updates an existing record.
+    pub collection: crate::types::Nsid,
+    /// Operation which updates an existing record.
+    pub rkey: crate::types::RecordKey,
+    /// Operation which updates an existing record.
+    pub value: serde_json::Value,
+}
+
+/// Operation which deletes an existing record.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Delete {
+    /// Operation which deletes an existing record.
+    pub collection: crate::types::Nsid,
+    /// Operation which deletes an existing record.
+    pub rkey: crate::types::RecordKey,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CreateResult {
+    pub uri: crate::syntax::AtUri,
+    pub cid: crate::types::CidLink,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(rename = "validationStatus")]
+    pub validation_status: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct UpdateResult {
+    pub uri: crate::syntax::AtUri,
+    pub cid: crate::types::CidLink,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(rename = "validationStatus")]
+    pub validation_status: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DeleteResult {
 }
 
 /// Apply a batch transaction of repository creates, updates, and deletes. Requires auth, implemented by PDS.
@@ -36,5 +117,18 @@ pub async fn apply_writes(
 ) -> Result<XrpcResponse<Output>, XrpcError> {
     let req = XrpcRequest::procedure("com.atproto.repo.applyWrites").data(&input)?;
 
-    client.request(req).await
+    client.request_body(req).await
+}
+
+/// Server-side definition of com.atproto.repo.applyWrites
+pub struct Endpoint;
+
+impl crate::xrpc::XrpcEndpoint for Endpoint {
+    const NSID: &'static str = "com.atproto.repo.applyWrites";
+    const METHOD: crate::xrpc::XrpcMethod = crate::xrpc::XrpcMethod::Procedure;
+    const INPUT_ENCODING: Option<&'static str> = Some("application/json");
+    const OUTPUT_ENCODING: Option<&'static str> = Some("application/json");
+    type Params = ();
+    type Input = Input;
+    type Output = Output;
 }
@@ -8,36 +8,40 @@ use serde::{Deserialize, Serialize};
 /// Request input
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Input {
-    /// Can be set to 'false' to skip Lexicon schema validation of record data, 'true' to require it, or leave unset to validate only for known Lexicons.
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub validate: Option<bool>,
     /// The handle or DID of the repo (aka, current account).
     pub repo: String,
     /// The NSID of the record collection.
-    pub collection: String,
+    pub collection: crate::types::Nsid,
     /// The Record Key.
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub rkey: Option<String>,
+    pub rkey: Option<crate::types::RecordKey>,
+    /// Can be set to 'false' to skip Lexicon schema validation of record data, 'true' to require it, or leave unset to validate only for known Lexicons.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub validate: Option<bool>,
     /// The record itself. Must contain a $type field.
     pub record: serde_json::Value,
     /// Compare and swap with the previous commit by CID.
     #[serde(skip_serializing_if = "Option::is_none")]
     #[serde(rename = "swapCommit")]
-    pub swap_commit: Option<String>,
+    pub swap_commit: Option<crate::types::CidLink>,
 }
 
+impl crate::xrpc::JsonBody for Input {}
+
 /// Response output
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Output {
     pub uri: crate::syntax::AtUri,
+    pub cid: crate::types::CidLink,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub commit: Option<serde_json::Value>,
-    pub cid: String,
     #[serde(skip_serializing_if = "Option::is_none")]
     #[serde(rename = "validationStatus")]
     pub validation_status: Option<String>,
 }
 
+impl crate::xrpc::JsonBody for Output {}
+
 /// Create a single new repository record. Requires auth, implemented by PDS.
 pub async fn create_record(
     client: &impl XrpcClient,
@@ -45,5 +49,18 @@ pub async fn create_record(
 ) -> Result<XrpcResponse<Output>, XrpcError> {
     let req = XrpcRequest::procedure("com.atproto.repo.createRecord").data(&input)?;
 
-    client.request(req).await
+    client.request_body(req).await
+}
+
+/// Server-side definition of com.atproto.repo.createRecord
+pub struct Endpoint;
+
+impl crate::xrpc::XrpcEndpoint for Endpoint {
+    const NSID: &'static str = "com.atproto.repo.createRecord";
+    const METHOD: crate::xrpc::XrpcMethod = crate::xrpc::XrpcMethod::Procedure;
+    const INPUT_ENCODING: Option<&'static str> = Some("application/json");
+    const OUTPUT_ENCODING: Option<&'static str> = Some("application/json");
+    type Params = ();
+    type Input = Input;
+    type Output = Output;
 }
@@ -4,8 +4,8 @@ use serde::{Deserialize, Serialize};
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct CommitMeta {
-    pub cid: String,
-    pub rev: String,
+    pub cid: crate::types::CidLink,
+    pub rev: crate::tid::Tid,
 }
 
 
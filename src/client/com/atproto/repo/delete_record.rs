@@ -8,22 +8,24 @@ use serde::{Deserialize, Serialize};
 /// Request input
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Input {
+    /// The handle or DID of the repo (aka, current account).
+    pub repo: String,
     /// The NSID of the record collection.
-    pub collection: String,
+    pub collection: crate::types::Nsid,
+    /// The Record Key.
+    pub rkey: crate::types::RecordKey,
     /// Compare and swap with the previous record by CID.
     #[serde(skip_serializing_if = "Option::is_none")]
     #[serde(rename = "swapRecord")]
-    pub swap_record: Option<String>,
-    /// The Record Key.
-    pub rkey: String,
+    pub swap_record: Option<crate::types::CidLink>,
     /// Compare and swap with the previous commit by CID.
     #[serde(skip_serializing_if = "Option::is_none")]
     #[serde(rename = "swapCommit")]
-    pub swap_commit: Option<String>,
-    /// The handle or DID of the repo (aka, current account).
-    pub repo: String,
+    pub swap_commit: Option<crate::types::CidLink>,
 }
 
+impl crate::xrpc::JsonBody for Input {}
+
 /// Response output
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Output {
@@ -31,6 +33,8 @@ pub struct Output {
     pub commit: Option<serde_json::Value>,
 }
 
+impl crate::xrpc::JsonBody for Output {}
+
 /// Delete a repository record, or ensure it doesn't exist. Requires auth, implemented by PDS.
 pub async fn delete_record(
     client: &impl XrpcClient,
@@ -38,5 +42,18 @@ pub async fn delete_record(
 ) -> Result<XrpcResponse<Output>, XrpcError> {
     let req = XrpcRequest::procedure("com.atproto.repo.deleteRecord").data(&input)?;
 
-    client.request(req).await
+    client.request_body(req).await
+}
+
+/// Server-side definition of com.atproto.repo.deleteRecord
+pub struct Endpoint;
+
+impl crate::xrpc::XrpcEndpoint for Endpoint {
+    const NSID: &'static str = "com.atproto.repo.deleteRecord";
+    const METHOD: crate::xrpc::XrpcMethod = crate::xrpc::XrpcMethod::Procedure;
+    const INPUT_ENCODING: Option<&'static str> = Some("application/json");
+    const OUTPUT_ENCODING: Option<&'static str> = Some("application/json");
+    type Params = ();
+    type Input = Input;
+    type Output = Output;
 }
@@ -15,18 +15,20 @@ pub struct QueryParams {
 /// Response output
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Output {
-    /// List of all the collections (NSIDs) for which this repo contains at least one record.
-    pub collections: serde_json::Value,
-    /// Indicates if handle is currently valid (resolves bi-directionally)
-    #[serde(rename = "handleIsCorrect")]
-    pub handle_is_correct: bool,
+    pub handle: crate::types::Handle,
     pub did: crate::types::Did,
-    pub handle: String,
     /// The complete DID document for this account.
     #[serde(rename = "didDoc")]
     pub did_doc: serde_json::Value,
+    /// List of all the collections (NSIDs) for which this repo contains at least one record.
+    pub collections: Vec<crate::types::Nsid>,
+    /// Indicates if handle is currently valid (resolves bi-directionally)
+    #[serde(rename = "handleIsCorrect")]
+    pub handle_is_correct: bool,
 }
 
+impl crate::xrpc::JsonBody for Output {}
+
 /// Get information about an account and repository, including the list of collections. Does not require auth.
 pub async fn describe_repo(
     client: &impl XrpcClient,
@@ -35,18 +37,20 @@ pub async fn describe_repo(
     let mut req = XrpcRequest::query("com.atproto.repo.describeRepo");
 
     // Add query parameters
-    let params_json = serde_json::to_value(&params)
-        .map_err(XrpcError::Serialization)?;
-
-    if let Some(obj) = params_json.as_object() {
-        for (key, value) in obj {
-            if let Some(s) = value.as_str() {
-                req.params.insert(key.clone(), s.to_string());
-            } else {
-                req.params.insert(key.clone(), value.to_string());
-            }
-        }
-    }
-
-    client.request(req).await
+    req.params.append("repo", params.repo.to_string());
+
+    client.request_body(req).await
+}
+
+/// Server-side definition of com.atproto.repo.describeRepo
+pub struct Endpoint;
+
+impl crate::xrpc::XrpcEndpoint for Endpoint {
+    const NSID: &'static str = "com.atproto.repo.describeRepo";
+    const METHOD: crate::xrpc::XrpcMethod = crate::xrpc::XrpcMethod::Query;
+    const INPUT_ENCODING: Option<&'static str> = None;
+    const OUTPUT_ENCODING: Option<&'static str> = Some("application/json");
+    type Params = QueryParams;
+    type Input = ();
+    type Output = Output;
 }
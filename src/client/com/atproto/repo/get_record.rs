@@ -8,15 +8,15 @@ use serde::{Deserialize, Serialize};
 /// Query parameters
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct QueryParams {
-    /// The NSID of the record collection.
-    pub collection: String,
-    /// The CID of the version of the record. If not specified, then return the most recent version.
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub cid: Option<String>,
     /// The handle or DID of the repo.
     pub repo: String,
+    /// The NSID of the record collection.
+    pub collection: crate::types::Nsid,
     /// The Record Key.
-    pub rkey: String,
+    pub rkey: crate::types::RecordKey,
+    /// The CID of the version of the record. If not specified, then return the most recent version.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cid: Option<crate::types::CidLink>,
 }
 
 /// Response output
@@ -24,10 +24,12 @@ pub struct QueryParams {
 pub struct Output {
     pub uri: crate::syntax::AtUri,
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub cid: Option<String>,
+    pub cid: Option<crate::types::CidLink>,
     pub value: serde_json::Value,
 }
 
+impl crate::xrpc::JsonBody for Output {}
+
 /// Error: RecordNotFound
 #[derive(Debug, Clone, thiserror::Error)]
 #[error("RecordNotFound")]
@@ -41,18 +43,25 @@ pub async fn get_record(
     let mut req = XrpcRequest::query("com.atproto.repo.getRecord");
 
     // Add query parameters
-    let params_json = serde_json::to_value(&params)
-        .map_err(XrpcError::Serialization)?;
-
-    if let Some(obj) = params_json.as_object() {
-        for (key, value) in obj {
-            if let Some(s) = value.as_str() {
-                req.params.insert(key.clone(), s.to_string());
-            } else {
-                req.params.insert(key.clone(), value.to_string());
-            }
-        }
+    req.params.append("repo", params.repo.to_string());
+    req.params.append("collection", params.collection.to_string());
+    req.params.append("rkey", params.rkey.to_string());
+    if let Some(value) = &params.cid {
+        req.params.append("cid", value.to_string());
     }
 
-    client.request(req).await
+    client.request_body(req).await
+}
+
+/// Server-side definition of com.atproto.repo.getRecord
+pub struct Endpoint;
+
+impl crate::xrpc::XrpcEndpoint for Endpoint {
+    const NSID: &'static str = "com.atproto.repo.getRecord";
+    const METHOD: crate::xrpc::XrpcMethod = crate::xrpc::XrpcMethod::Query;
+    const INPUT_ENCODING: Option<&'static str> = None;
+    const OUTPUT_ENCODING: Option<&'static str> = Some("application/json");
+    type Params = QueryParams;
+    type Input = ();
+    type Output = Output;
 }
@@ -12,7 +12,20 @@ pub async fn import_repo(
     client: &impl XrpcClient,
     input: Input,
 ) -> Result<XrpcResponse<()>, XrpcError> {
-    let req = XrpcRequest::procedure("com.atproto.repo.importRepo").data(&input)?;
+    let req = XrpcRequest::procedure("com.atproto.repo.importRepo").binary(input, "application/vnd.ipld.car");
 
-    client.request(req).await
+    client.request_body(req).await
+}
+
+/// Server-side definition of com.atproto.repo.importRepo
+pub struct Endpoint;
+
+impl crate::xrpc::XrpcEndpoint for Endpoint {
+    const NSID: &'static str = "com.atproto.repo.importRepo";
+    const METHOD: crate::xrpc::XrpcMethod = crate::xrpc::XrpcMethod::Procedure;
+    const INPUT_ENCODING: Option<&'static str> = Some("application/vnd.ipld.car");
+    const OUTPUT_ENCODING: Option<&'static str> = None;
+    type Params = ();
+    type Input = Vec<u8>;
+    type Output = ();
 }
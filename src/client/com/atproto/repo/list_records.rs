@@ -8,18 +8,18 @@ use serde::{Deserialize, Serialize};
 /// Query parameters
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct QueryParams {
+    /// The handle or DID of the repo.
+    pub repo: String,
     /// The NSID of the record type.
-    pub collection: String,
+    pub collection: crate::types::Nsid,
+    /// The number of records to return.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit: Option<i64>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub cursor: Option<String>,
     /// Flag to reverse the order of the returned records.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub reverse: Option<bool>,
-    /// The number of records to return.
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub limit: Option<i64>,
-    /// The handle or DID of the repo.
-    pub repo: String,
 }
 
 /// Response output
@@ -27,7 +27,16 @@ pub struct QueryParams {
 pub struct Output {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub cursor: Option<String>,
-    pub records: serde_json::Value,
+    pub records: Vec<serde_json::Value>,
+}
+
+impl crate::xrpc::JsonBody for Output {}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Record {
+    pub uri: crate::syntax::AtUri,
+    pub cid: crate::types::CidLink,
+    pub value: serde_json::Value,
 }
 
 /// List a range of records in a repository, matching a specific collection. Does not require auth.
@@ -38,18 +47,30 @@ pub async fn list_records(
     let mut req = XrpcRequest::query("com.atproto.repo.listRecords");
 
     // Add query parameters
-    let params_json = serde_json::to_value(&params)
-        .map_err(XrpcError::Serialization)?;
-
-    if let Some(obj) = params_json.as_object() {
-        for (key, value) in obj {
-            if let Some(s) = value.as_str() {
-                req.params.insert(key.clone(), s.to_string());
-            } else {
-                req.params.insert(key.clone(), value.to_string());
-            }
-        }
+    req.params.append("repo", params.repo.to_string());
+    req.params.append("collection", params.collection.to_string());
+    if let Some(value) = &params.limit {
+        req.params.append("limit", value.to_string());
+    }
+    if let Some(value) = &params.cursor {
+        req.params.append("cursor", value.to_string());
     }
+    if let Some(value) = &params.reverse {
+        req.params.append("reverse", value.to_string());
+    }
+
+    client.request_body(req).await
+}
+
+/// Server-side definition of com.atproto.repo.listRecords
+pub struct Endpoint;
 
-    client.request(req).await
+impl crate::xrpc::XrpcEndpoint for Endpoint {
+    const NSID: &'static str = "com.atproto.repo.listRecords";
+    const METHOD: crate::xrpc::XrpcMethod = crate::xrpc::XrpcMethod::Query;
+    const INPUT_ENCODING: Option<&'static str> = None;
+    const OUTPUT_ENCODING: Option<&'static str> = Some("application/json");
+    type Params = QueryParams;
+    type Input = ();
+    type Output = Output;
 }
@@ -8,39 +8,43 @@ use serde::{Deserialize, Serialize};
 /// Request input
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Input {
-    /// Compare and swap with the previous commit by CID.
-    #[serde(skip_serializing_if = "Option::is_none")]
-    #[serde(rename = "swapCommit")]
-    pub swap_commit: Option<String>,
-    /// Compare and swap with the previous record by CID. WARNING: nullable and optional field; may cause problems with golang implementation
-    #[serde(skip_serializing_if = "Option::is_none")]
-    #[serde(rename = "swapRecord")]
-    pub swap_record: Option<String>,
+    /// The handle or DID of the repo (aka, current account).
+    pub repo: String,
     /// The NSID of the record collection.
-    pub collection: String,
+    pub collection: crate::types::Nsid,
     /// The Record Key.
-    pub rkey: String,
+    pub rkey: crate::types::RecordKey,
     /// Can be set to 'false' to skip Lexicon schema validation of record data, 'true' to require it, or leave unset to validate only for known Lexicons.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub validate: Option<bool>,
-    /// The handle or DID of the repo (aka, current account).
-    pub repo: String,
     /// The record to write.
     pub record: serde_json::Value,
+    /// Compare and swap with the previous record by CID. WARNING: nullable and optional field; may cause problems with golang implementation
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(rename = "swapRecord")]
+    pub swap_record: Option<crate::types::CidLink>,
+    /// Compare and swap with the previous commit by CID.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(rename = "swapCommit")]
+    pub swap_commit: Option<crate::types::CidLink>,
 }
 
+impl crate::xrpc::JsonBody for Input {}
+
 /// Response output
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Output {
     pub uri: crate::syntax::AtUri,
+    pub cid: crate::types::CidLink,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub commit: Option<serde_json::Value>,
     #[serde(skip_serializing_if = "Option::is_none")]
     #[serde(rename = "validationStatus")]
     pub validation_status: Option<String>,
-    pub cid: String,
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub commit: Option<serde_json::Value>,
 }
 
+impl crate::xrpc::JsonBody for Output {}
+
 /// Write a repository record, creating or updating it as needed. Requires auth, implemented by PDS.
 pub async fn put_record(
     client: &impl XrpcClient,
@@ -48,5 +52,18 @@ pub async fn put_record(
 ) -> Result<XrpcResponse<Output>, XrpcError> {
     let req = XrpcRequest::procedure("com.atproto.repo.putRecord").data(&input)?;
 
-    client.request(req).await
+    client.request_body(req).await
+}
+
+/// Server-side definition of com.atproto.repo.putRecord
+pub struct Endpoint;
+
+impl crate::xrpc::XrpcEndpoint for Endpoint {
+    const NSID: &'static str = "com.atproto.repo.putRecord";
+    const METHOD: crate::xrpc::XrpcMethod = crate::xrpc::XrpcMethod::Procedure;
+    const INPUT_ENCODING: Option<&'static str> = Some("application/json");
+    const OUTPUT_ENCODING: Option<&'static str> = Some("application/json");
+    type Params = ();
+    type Input = Input;
+    type Output = Output;
 }
@@ -5,7 +5,7 @@ use serde::{Deserialize, Serialize};
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Main {
     pub uri: crate::syntax::AtUri,
-    pub cid: String,
+    pub cid: crate::types::CidLink,
 }
 
 
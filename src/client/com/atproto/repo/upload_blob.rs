@@ -14,12 +14,28 @@ pub struct Output {
     pub blob: serde_json::Value,
 }
 
+impl crate::xrpc::JsonBody for Output {}
+
 /// Upload a new blob, to be referenced from a repository record. The blob will be deleted if it is not referenced within a time window (eg, minutes). Blob restrictions (mimetype, size, etc) are enforced when the reference is created. Requires auth, implemented by PDS.
 pub async fn upload_blob(
     client: &impl XrpcClient,
     input: Input,
+    content_type: &str,
 ) -> Result<XrpcResponse<Output>, XrpcError> {
-    let req = XrpcRequest::procedure("com.atproto.repo.uploadBlob").data(&input)?;
+    let req = XrpcRequest::procedure("com.atproto.repo.uploadBlob").binary(input, content_type);
+
+    client.request_body(req).await
+}
+
+/// Server-side definition of com.atproto.repo.uploadBlob
+pub struct Endpoint;
 
-    client.request(req).await
+impl crate::xrpc::XrpcEndpoint for Endpoint {
+    const NSID: &'static str = "com.atproto.repo.uploadBlob";
+    const METHOD: crate::xrpc::XrpcMethod = crate::xrpc::XrpcMethod::Procedure;
+    const INPUT_ENCODING: Option<&'static str> = Some("*/*");
+    const OUTPUT_ENCODING: Option<&'static str> = Some("application/json");
+    type Params = ();
+    type Input = Vec<u8>;
+    type Output = Output;
 }
@@ -8,10 +8,12 @@ use serde::{Deserialize, Serialize};
 /// Request input
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Input {
-    pub token: String,
     pub email: String,
+    pub token: String,
 }
 
+impl crate::xrpc::JsonBody for Input {}
+
 /// Confirm an email using a token from com.atproto.server.requestEmailConfirmation.
 pub async fn confirm_email(
     client: &impl XrpcClient,
@@ -19,5 +21,18 @@ pub async fn confirm_email(
 ) -> Result<XrpcResponse<()>, XrpcError> {
     let req = XrpcRequest::procedure("com.atproto.server.confirmEmail").data(&input)?;
 
-    client.request(req).await
+    client.request_body(req).await
+}
+
+/// Server-side definition of com.atproto.server.confirmEmail
+pub struct Endpoint;
+
+impl crate::xrpc::XrpcEndpoint for Endpoint {
+    const NSID: &'static str = "com.atproto.server.confirmEmail";
+    const METHOD: crate::xrpc::XrpcMethod = crate::xrpc::XrpcMethod::Procedure;
+    const INPUT_ENCODING: Option<&'static str> = Some("application/json");
+    const OUTPUT_ENCODING: Option<&'static str> = None;
+    type Params = ();
+    type Input = Input;
+    type Output = ();
 }
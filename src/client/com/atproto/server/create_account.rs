@@ -8,23 +8,22 @@ use serde::{Deserialize, Serialize};
 /// Request input
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Input {
-    #[serde(skip_serializing_if = "Option::is_none")]
-    #[serde(rename = "inviteCode")]
-    pub invite_code: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub email: Option<String>,
+    /// Requested handle for the account.
+    pub handle: crate::types::Handle,
     /// Pre-existing atproto DID, being imported to a new account.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub did: Option<crate::types::Did>,
-    /// Requested handle for the account.
-    pub handle: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(rename = "inviteCode")]
+    pub invite_code: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     #[serde(rename = "verificationCode")]
     pub verification_code: Option<String>,
-    /// A signed DID PLC operation to be submitted as part of importing an existing account to this instance. NOTE: this optional field may be updated when full account migration is implemented.
     #[serde(skip_serializing_if = "Option::is_none")]
-    #[serde(rename = "plcOp")]
-    pub plc_op: Option<serde_json::Value>,
+    #[serde(rename = "verificationPhone")]
+    pub verification_phone: Option<String>,
     /// Initial account password. May need to meet instance-specific password strength requirements.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub password: Option<String>,
@@ -32,27 +31,32 @@ pub struct Input {
     #[serde(skip_serializing_if = "Option::is_none")]
     #[serde(rename = "recoveryKey")]
     pub recovery_key: Option<String>,
+    /// A signed DID PLC operation to be submitted as part of importing an existing account to this instance. NOTE: this optional field may be updated when full account migration is implemented.
     #[serde(skip_serializing_if = "Option::is_none")]
-    #[serde(rename = "verificationPhone")]
-    pub verification_phone: Option<String>,
+    #[serde(rename = "plcOp")]
+    pub plc_op: Option<serde_json::Value>,
 }
 
+impl crate::xrpc::JsonBody for Input {}
+
 /// Response output
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Output {
-    pub handle: String,
+    #[serde(rename = "accessJwt")]
+    pub access_jwt: String,
+    #[serde(rename = "refreshJwt")]
+    pub refresh_jwt: String,
+    pub handle: crate::types::Handle,
     /// The DID of the new account.
     pub did: crate::types::Did,
     /// Complete DID document.
     #[serde(skip_serializing_if = "Option::is_none")]
     #[serde(rename = "didDoc")]
     pub did_doc: Option<serde_json::Value>,
-    #[serde(rename = "accessJwt")]
-    pub access_jwt: String,
-    #[serde(rename = "refreshJwt")]
-    pub refresh_jwt: String,
 }
 
+impl crate::xrpc::JsonBody for Output {}
+
 /// Create an account. Implemented by PDS.
 pub async fn create_account(
     client: &impl XrpcClient,
@@ -60,5 +64,18 @@ pub async fn create_account(
 ) -> Result<XrpcResponse<Output>, XrpcError> {
     let req = XrpcRequest::procedure("com.atproto.server.createAccount").data(&input)?;
 
-    client.request(req).await
+    client.request_body(req).await
+}
+
+/// Server-side definition of com.atproto.server.createAccount
+pub struct Endpoint;
+
+impl crate::xrpc::XrpcEndpoint for Endpoint {
+    const NSID: &'static str = "com.atproto.server.createAccount";
+    const METHOD: crate::xrpc::XrpcMethod = crate::xrpc::XrpcMethod::Procedure;
+    const INPUT_ENCODING: Option<&'static str> = Some("application/json");
+    const OUTPUT_ENCODING: Option<&'static str> = Some("application/json");
+    type Params = ();
+    type Input = Input;
+    type Output = Output;
 }
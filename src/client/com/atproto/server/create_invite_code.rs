@@ -8,19 +8,23 @@ use serde::{Deserialize, Serialize};
 /// Request input
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Input {
+    #[serde(rename = "useCount")]
+    pub use_count: i64,
     #[serde(skip_serializing_if = "Option::is_none")]
     #[serde(rename = "forAccount")]
     pub for_account: Option<crate::types::Did>,
-    #[serde(rename = "useCount")]
-    pub use_count: i64,
 }
 
+impl crate::xrpc::JsonBody for Input {}
+
 /// Response output
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Output {
     pub code: String,
 }
 
+impl crate::xrpc::JsonBody for Output {}
+
 /// Create an invite code.
 pub async fn create_invite_code(
     client: &impl XrpcClient,
@@ -28,5 +32,18 @@ pub async fn create_invite_code(
 ) -> Result<XrpcResponse<Output>, XrpcError> {
     let req = XrpcRequest::procedure("com.atproto.server.createInviteCode").data(&input)?;
 
-    client.request(req).await
+    client.request_body(req).await
+}
+
+/// Server-side definition of com.atproto.server.createInviteCode
+pub struct Endpoint;
+
+impl crate::xrpc::XrpcEndpoint for Endpoint {
+    const NSID: &'static str = "com.atproto.server.createInviteCode";
+    const METHOD: crate::xrpc::XrpcMethod = crate::xrpc::XrpcMethod::Procedure;
+    const INPUT_ENCODING: Option<&'static str> = Some("application/json");
+    const OUTPUT_ENCODING: Option<&'static str> = Some("application/json");
+    type Params = ();
+    type Input = Input;
+    type Output = Output;
 }
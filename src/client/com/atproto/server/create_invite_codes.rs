@@ -14,13 +14,23 @@ pub struct Input {
     pub use_count: i64,
     #[serde(skip_serializing_if = "Option::is_none")]
     #[serde(rename = "forAccounts")]
-    pub for_accounts: Option<serde_json::Value>,
+    pub for_accounts: Option<Vec<crate::types::Did>>,
 }
 
+impl crate::xrpc::JsonBody for Input {}
+
 /// Response output
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Output {
-    pub codes: serde_json::Value,
+    pub codes: Vec<serde_json::Value>,
+}
+
+impl crate::xrpc::JsonBody for Output {}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AccountCodes {
+    pub account: String,
+    pub codes: Vec<String>,
 }
 
 /// Create invite codes.
@@ -30,5 +40,18 @@ pub async fn create_invite_codes(
 ) -> Result<XrpcResponse<Output>, XrpcError> {
     let req = XrpcRequest::procedure("com.atproto.server.createInviteCodes").data(&input)?;
 
-    client.request(req).await
+    client.request_body(req).await
+}
+
+/// Server-side definition of com.atproto.server.createInviteCodes
+pub struct Endpoint;
+
+impl crate::xrpc::XrpcEndpoint for Endpoint {
+    const NSID: &'static str = "com.atproto.server.createInviteCodes";
+    const METHOD: crate::xrpc::XrpcMethod = crate::xrpc::XrpcMethod::Procedure;
+    const INPUT_ENCODING: Option<&'static str> = Some("application/json");
+    const OUTPUT_ENCODING: Option<&'static str> = Some("application/json");
+    type Params = ();
+    type Input = Input;
+    type Output = Output;
 }
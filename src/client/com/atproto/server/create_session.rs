@@ -20,33 +20,37 @@ pub struct Input {
     pub allow_takendown: Option<bool>,
 }
 
+impl crate::xrpc::JsonBody for Input {}
+
 /// Response output
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Output {
+    #[serde(rename = "accessJwt")]
+    pub access_jwt: String,
+    #[serde(rename = "refreshJwt")]
+    pub refresh_jwt: String,
+    pub handle: crate::types::Handle,
     pub did: crate::types::Did,
     #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(rename = "didDoc")]
+    pub did_doc: Option<serde_json::Value>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub email: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub active: Option<bool>,
-    #[serde(rename = "accessJwt")]
-    pub access_jwt: String,
+    #[serde(rename = "emailConfirmed")]
+    pub email_confirmed: Option<bool>,
     #[serde(skip_serializing_if = "Option::is_none")]
     #[serde(rename = "emailAuthFactor")]
     pub email_auth_factor: Option<bool>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    #[serde(rename = "emailConfirmed")]
-    pub email_confirmed: Option<bool>,
+    pub active: Option<bool>,
     /// If active=false, this optional field indicates a possible reason for why the account is not active. If active=false and no status is supplied, then the host makes no claim for why the repository is no longer being hosted.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub status: Option<String>,
-    pub handle: String,
-    #[serde(rename = "refreshJwt")]
-    pub refresh_jwt: String,
-    #[serde(skip_serializing_if = "Option::is_none")]
-    #[serde(rename = "didDoc")]
-    pub did_doc: Option<serde_json::Value>,
 }
 
+impl crate::xrpc::JsonBody for Output {}
+
 /// Create an authentication session.
 pub async fn create_session(
     client: &impl XrpcClient,
@@ -54,5 +58,18 @@ pub async fn create_session(
 ) -> Result<XrpcResponse<Output>, XrpcError> {
     let req = XrpcRequest::procedure("com.atproto.server.createSession").data(&input)?;
 
-    client.request(req).await
+    client.request_body(req).await
+}
+
+/// Server-side definition of com.atproto.server.createSession
+pub struct Endpoint;
+
+impl crate::xrpc::XrpcEndpoint for Endpoint {
+    const NSID: &'static str = "com.atproto.server.createSession";
+    const METHOD: crate::xrpc::XrpcMethod = crate::xrpc::XrpcMethod::Procedure;
+    const INPUT_ENCODING: Option<&'static str> = Some("application/json");
+    const OUTPUT_ENCODING: Option<&'static str> = Some("application/json");
+    type Params = ();
+    type Input = Input;
+    type Output = Output;
 }
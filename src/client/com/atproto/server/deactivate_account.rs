@@ -11,9 +11,11 @@ pub struct Input {
     /// A recommendation to server as to how long they should hold onto the deactivated account before deleting.
     #[serde(skip_serializing_if = "Option::is_none")]
     #[serde(rename = "deleteAfter")]
-    pub delete_after: Option<String>,
+    pub delete_after: Option<crate::types::Datetime>,
 }
 
+impl crate::xrpc::JsonBody for Input {}
+
 /// Deactivates a currently active account. Stops serving of repo, and future writes to repo until reactivated. Used to finalize account migration with the old host after the account has been activated on the new host.
 pub async fn deactivate_account(
     client: &impl XrpcClient,
@@ -21,5 +23,18 @@ pub async fn deactivate_account(
 ) -> Result<XrpcResponse<()>, XrpcError> {
     let req = XrpcRequest::procedure("com.atproto.server.deactivateAccount").data(&input)?;
 
-    client.request(req).await
+    client.request_body(req).await
+}
+
+/// Server-side definition of com.atproto.server.deactivateAccount
+pub struct Endpoint;
+
+impl crate::xrpc::XrpcEndpoint for Endpoint {
+    const NSID: &'static str = "com.atproto.server.deactivateAccount";
+    const METHOD: crate::xrpc::XrpcMethod = crate::xrpc::XrpcMethod::Procedure;
+    const INPUT_ENCODING: Option<&'static str> = Some("application/json");
+    const OUTPUT_ENCODING: Option<&'static str> = None;
+    type Params = ();
+    type Input = Input;
+    type Output = ();
 }
@@ -8,11 +8,13 @@ use serde::{Deserialize, Serialize};
 /// Request input
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Input {
-    pub token: String,
     pub did: crate::types::Did,
     pub password: String,
+    pub token: String,
 }
 
+impl crate::xrpc::JsonBody for Input {}
+
 /// Delete an actor's account with a token and password. Can only be called after requesting a deletion token. Requires auth.
 pub async fn delete_account(
     client: &impl XrpcClient,
@@ -20,5 +22,18 @@ pub async fn delete_account(
 ) -> Result<XrpcResponse<()>, XrpcError> {
     let req = XrpcRequest::procedure("com.atproto.server.deleteAccount").data(&input)?;
 
-    client.request(req).await
+    client.request_body(req).await
+}
+
+/// Server-side definition of com.atproto.server.deleteAccount
+pub struct Endpoint;
+
+impl crate::xrpc::XrpcEndpoint for Endpoint {
+    const NSID: &'static str = "com.atproto.server.deleteAccount";
+    const METHOD: crate::xrpc::XrpcMethod = crate::xrpc::XrpcMethod::Procedure;
+    const INPUT_ENCODING: Option<&'static str> = Some("application/json");
+    const OUTPUT_ENCODING: Option<&'static str> = None;
+    type Params = ();
+    type Input = Input;
+    type Output = ();
 }
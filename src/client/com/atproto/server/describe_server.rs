@@ -8,31 +8,62 @@ use serde::{Deserialize, Serialize};
 /// Response output
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Output {
-    /// If true, a phone verification token must be supplied to create an account on this instance.
-    #[serde(skip_serializing_if = "Option::is_none")]
-    #[serde(rename = "phoneVerificationRequired")]
-    pub phone_verification_required: Option<bool>,
-    /// Contact information
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub contact: Option<serde_json::Value>,
     /// If true, an invite code must be supplied to create an account on this instance.
     #[serde(skip_serializing_if = "Option::is_none")]
     #[serde(rename = "inviteCodeRequired")]
     pub invite_code_required: Option<bool>,
+    /// If true, a phone verification token must be supplied to create an account on this instance.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(rename = "phoneVerificationRequired")]
+    pub phone_verification_required: Option<bool>,
     /// List of domain suffixes that can be used in account handles.
     #[serde(rename = "availableUserDomains")]
-    pub available_user_domains: serde_json::Value,
+    pub available_user_domains: Vec<String>,
     /// URLs of service policy documents.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub links: Option<serde_json::Value>,
+    /// Contact information
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub contact: Option<serde_json::Value>,
     pub did: crate::types::Did,
 }
 
+impl crate::xrpc::JsonBody for Output {}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Links {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(rename = "privacyPolicy")]
+    pub privacy_policy: Option<crate::types::Uri>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(rename = "termsOfService")]
+    pub terms_of_service: Option<crate::types::Uri>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Contact {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub email: Option<String>,
+}
+
 /// Describes the server's account creation requirements and capabilities. Implemented by PDS.
 pub async fn describe_server(
     client: &impl XrpcClient,
 ) -> Result<XrpcResponse<Output>, XrpcError> {
     let req = XrpcRequest::query("com.atproto.server.describeServer");
 
-    client.request(req).await
+    client.request_body(req).await
+}
+
+/// Server-side definition of com.atproto.server.describeServer
+pub struct Endpoint;
+
+impl crate::xrpc::XrpcEndpoint for Endpoint {
+    const NSID: &'static str = "com.atproto.server.describeServer";
+    const METHOD: crate::xrpc::XrpcMethod = crate::xrpc::XrpcMethod::Query;
+    const INPUT_ENCODING: Option<&'static str> = None;
+    const OUTPUT_ENCODING: Option<&'static str> = Some("application/json");
+    type Params = ();
+    type Input = ();
+    type Output = Output;
 }
@@ -8,14 +8,14 @@ use serde::{Deserialize, Serialize};
 /// Query parameters
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct QueryParams {
-    /// Lexicon (XRPC) method to bind the requested token to
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub lxm: Option<String>,
+    /// The DID of the service that the token will be used to authenticate with
+    pub aud: crate::types::Did,
     /// The time in Unix Epoch seconds that the JWT expires. Defaults to 60 seconds in the future. The service may enforce certain time bounds on tokens depending on the requested scope.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub exp: Option<i64>,
-    /// The DID of the service that the token will be used to authenticate with
-    pub aud: crate::types::Did,
+    /// Lexicon (XRPC) method to bind the requested token to
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub lxm: Option<crate::types::Nsid>,
 }
 
 /// Response output
@@ -24,6 +24,8 @@ pub struct Output {
     pub token: String,
 }
 
+impl crate::xrpc::JsonBody for Output {}
+
 /// Error: BadExpiration
 /// Indicates that the requested expiration date is not a valid. May be in the past or may be reliant on the requested scopes.
 #[derive(Debug, Clone, thiserror::Error)]
@@ -38,18 +40,26 @@ pub async fn get_service_auth(
     let mut req = XrpcRequest::query("com.atproto.server.getServiceAuth");
 
     // Add query parameters
-    let params_json = serde_json::to_value(&params)
-        .map_err(XrpcError::Serialization)?;
-
-    if let Some(obj) = params_json.as_object() {
-        for (key, value) in obj {
-            if let Some(s) = value.as_str() {
-                req.params.insert(key.clone(), s.to_string());
-            } else {
-                req.params.insert(key.clone(), value.to_string());
-            }
-        }
+    req.params.append("aud", params.aud.to_string());
+    if let Some(value) = &params.exp {
+        req.params.append("exp", value.to_string());
+    }
+    if let Some(value) = &params.lxm {
+        req.params.append("lxm", value.to_string());
     }
 
-    client.request(req).await
+    client.request_body(req).await
+}
+
+/// Server-side definition of com.atproto.server.getServiceAuth
+pub struct Endpoint;
+
+impl crate::xrpc::XrpcEndpoint for Endpoint {
+    const NSID: &'static str = "com.atproto.server.getServiceAuth";
+    const METHOD: crate::xrpc::XrpcMethod = crate::xrpc::XrpcMethod::Query;
+    const INPUT_ENCODING: Option<&'static str> = None;
+    const OUTPUT_ENCODING: Option<&'static str> = Some("application/json");
+    type Params = QueryParams;
+    type Input = ();
+    type Output = Output;
 }
@@ -8,9 +8,10 @@ use serde::{Deserialize, Serialize};
 /// Response output
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Output {
-    /// If active=false, this optional field indicates a possible reason for why the account is not active. If active=false and no status is supplied, then the host makes no claim for why the repository is no longer being hosted.
+    pub handle: crate::types::Handle,
+    pub did: crate::types::Did,
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub status: Option<String>,
+    pub email: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     #[serde(rename = "emailConfirmed")]
     pub email_confirmed: Option<bool>,
@@ -18,21 +19,35 @@ pub struct Output {
     #[serde(rename = "emailAuthFactor")]
     pub email_auth_factor: Option<bool>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub email: Option<String>,
-    pub handle: String,
-    pub did: crate::types::Did,
-    #[serde(skip_serializing_if = "Option::is_none")]
     #[serde(rename = "didDoc")]
     pub did_doc: Option<serde_json::Value>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub active: Option<bool>,
+    /// If active=false, this optional field indicates a possible reason for why the account is not active. If active=false and no status is supplied, then the host makes no claim for why the repository is no longer being hosted.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status: Option<String>,
 }
 
+impl crate::xrpc::JsonBody for Output {}
+
 /// Get information about the current auth session. Requires auth.
 pub async fn get_session(
     client: &impl XrpcClient,
 ) -> Result<XrpcResponse<Output>, XrpcError> {
     let req = XrpcRequest::query("com.atproto.server.getSession");
 
-    client.request(req).await
+    client.request_body(req).await
+}
+
+/// Server-side definition of com.atproto.server.getSession
+pub struct Endpoint;
+
+impl crate::xrpc::XrpcEndpoint for Endpoint {
+    const NSID: &'static str = "com.atproto.server.getSession";
+    const METHOD: crate::xrpc::XrpcMethod = crate::xrpc::XrpcMethod::Query;
+    const INPUT_ENCODING: Option<&'static str> = None;
+    const OUTPUT_ENCODING: Option<&'static str> = Some("application/json");
+    type Params = ();
+    type Input = ();
+    type Output = Output;
 }
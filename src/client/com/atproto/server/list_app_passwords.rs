@@ -8,7 +8,18 @@ use serde::{Deserialize, Serialize};
 /// Response output
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Output {
-    pub passwords: serde_json::Value,
+    pub passwords: Vec<serde_json::Value>,
+}
+
+impl crate::xrpc::JsonBody for Output {}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AppPassword {
+    pub name: String,
+    #[serde(rename = "createdAt")]
+    pub created_at: crate::types::Datetime,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub privileged: Option<bool>,
 }
 
 /// Error: AccountTakedown
@@ -22,5 +33,18 @@ pub async fn list_app_passwords(
 ) -> Result<XrpcResponse<Output>, XrpcError> {
     let req = XrpcRequest::query("com.atproto.server.listAppPasswords");
 
-    client.request(req).await
+    client.request_body(req).await
+}
+
+/// Server-side definition of com.atproto.server.listAppPasswords
+pub struct Endpoint;
+
+impl crate::xrpc::XrpcEndpoint for Endpoint {
+    const NSID: &'static str = "com.atproto.server.listAppPasswords";
+    const METHOD: crate::xrpc::XrpcMethod = crate::xrpc::XrpcMethod::Query;
+    const INPUT_ENCODING: Option<&'static str> = None;
+    const OUTPUT_ENCODING: Option<&'static str> = Some("application/json");
+    type Params = ();
+    type Input = ();
+    type Output = Output;
 }
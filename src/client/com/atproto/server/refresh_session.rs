@@ -8,27 +8,42 @@ use serde::{Deserialize, Serialize};
 /// Response output
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Output {
-    pub did: crate::types::Did,
-    /// Hosting status of the account. If not specified, then assume 'active'.
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub status: Option<String>,
+    #[serde(rename = "accessJwt")]
+    pub access_jwt: String,
     #[serde(rename = "refreshJwt")]
     pub refresh_jwt: String,
+    pub handle: crate::types::Handle,
+    pub did: crate::types::Did,
     #[serde(skip_serializing_if = "Option::is_none")]
     #[serde(rename = "didDoc")]
     pub did_doc: Option<serde_json::Value>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub active: Option<bool>,
-    #[serde(rename = "accessJwt")]
-    pub access_jwt: String,
-    pub handle: String,
+    /// Hosting status of the account. If not specified, then assume 'active'.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status: Option<String>,
 }
 
+impl crate::xrpc::JsonBody for Output {}
+
 /// Refresh an authentication session. Requires auth using the 'refreshJwt' (not the 'accessJwt').
 pub async fn refresh_session(
     client: &impl XrpcClient,
 ) -> Result<XrpcResponse<Output>, XrpcError> {
     let req = XrpcRequest::procedure("com.atproto.server.refreshSession");
 
-    client.request(req).await
+    client.request_body(req).await
+}
+
+/// Server-side definition of com.atproto.server.refreshSession
+pub struct Endpoint;
+
+impl crate::xrpc::XrpcEndpoint for Endpoint {
+    const NSID: &'static str = "com.atproto.server.refreshSession";
+    const METHOD: crate::xrpc::XrpcMethod = crate::xrpc::XrpcMethod::Procedure;
+    const INPUT_ENCODING: Option<&'static str> = None;
+    const OUTPUT_ENCODING: Option<&'static str> = Some("application/json");
+    type Params = ();
+    type Input = ();
+    type Output = Output;
 }
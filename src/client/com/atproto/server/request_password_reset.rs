@@ -11,6 +11,8 @@ pub struct Input {
     pub email: String,
 }
 
+impl crate::xrpc::JsonBody for Input {}
+
 /// Initiate a user account password reset via email.
 pub async fn request_password_reset(
     client: &impl XrpcClient,
@@ -18,5 +20,18 @@ pub async fn request_password_reset(
 ) -> Result<XrpcResponse<()>, XrpcError> {
     let req = XrpcRequest::procedure("com.atproto.server.requestPasswordReset").data(&input)?;
 
-    client.request(req).await
+    client.request_body(req).await
+}
+
+/// Server-side definition of com.atproto.server.requestPasswordReset
+pub struct Endpoint;
+
+impl crate::xrpc::XrpcEndpoint for Endpoint {
+    const NSID: &'static str = "com.atproto.server.requestPasswordReset";
+    const METHOD: crate::xrpc::XrpcMethod = crate::xrpc::XrpcMethod::Procedure;
+    const INPUT_ENCODING: Option<&'static str> = Some("application/json");
+    const OUTPUT_ENCODING: Option<&'static str> = None;
+    type Params = ();
+    type Input = Input;
+    type Output = ();
 }
@@ -12,6 +12,8 @@ pub struct Input {
     pub password: String,
 }
 
+impl crate::xrpc::JsonBody for Input {}
+
 /// Reset a user account password using a token.
 pub async fn reset_password(
     client: &impl XrpcClient,
@@ -19,5 +21,18 @@ pub async fn reset_password(
 ) -> Result<XrpcResponse<()>, XrpcError> {
     let req = XrpcRequest::procedure("com.atproto.server.resetPassword").data(&input)?;
 
-    client.request(req).await
+    client.request_body(req).await
+}
+
+/// Server-side definition of com.atproto.server.resetPassword
+pub struct Endpoint;
+
+impl crate::xrpc::XrpcEndpoint for Endpoint {
+    const NSID: &'static str = "com.atproto.server.resetPassword";
+    const METHOD: crate::xrpc::XrpcMethod = crate::xrpc::XrpcMethod::Procedure;
+    const INPUT_ENCODING: Option<&'static str> = Some("application/json");
+    const OUTPUT_ENCODING: Option<&'static str> = None;
+    type Params = ();
+    type Input = Input;
+    type Output = ();
 }
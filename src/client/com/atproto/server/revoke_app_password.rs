@@ -11,6 +11,8 @@ pub struct Input {
     pub name: String,
 }
 
+impl crate::xrpc::JsonBody for Input {}
+
 /// Revoke an App Password by name.
 pub async fn revoke_app_password(
     client: &impl XrpcClient,
@@ -18,5 +20,18 @@ pub async fn revoke_app_password(
 ) -> Result<XrpcResponse<()>, XrpcError> {
     let req = XrpcRequest::procedure("com.atproto.server.revokeAppPassword").data(&input)?;
 
-    client.request(req).await
+    client.request_body(req).await
+}
+
+/// Server-side definition of com.atproto.server.revokeAppPassword
+pub struct Endpoint;
+
+impl crate::xrpc::XrpcEndpoint for Endpoint {
+    const NSID: &'static str = "com.atproto.server.revokeAppPassword";
+    const METHOD: crate::xrpc::XrpcMethod = crate::xrpc::XrpcMethod::Procedure;
+    const INPUT_ENCODING: Option<&'static str> = Some("application/json");
+    const OUTPUT_ENCODING: Option<&'static str> = None;
+    type Params = ();
+    type Input = Input;
+    type Output = ();
 }
@@ -8,15 +8,17 @@ use serde::{Deserialize, Serialize};
 /// Request input
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Input {
+    pub email: String,
     #[serde(skip_serializing_if = "Option::is_none")]
     #[serde(rename = "emailAuthFactor")]
     pub email_auth_factor: Option<bool>,
     /// Requires a token from com.atproto.sever.requestEmailUpdate if the account's email has been confirmed.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub token: Option<String>,
-    pub email: String,
 }
 
+impl crate::xrpc::JsonBody for Input {}
+
 /// Update an account's email.
 pub async fn update_email(
     client: &impl XrpcClient,
@@ -24,5 +26,18 @@ pub async fn update_email(
 ) -> Result<XrpcResponse<()>, XrpcError> {
     let req = XrpcRequest::procedure("com.atproto.server.updateEmail").data(&input)?;
 
-    client.request(req).await
+    client.request_body(req).await
+}
+
+/// Server-side definition of com.atproto.server.updateEmail
+pub struct Endpoint;
+
+impl crate::xrpc::XrpcEndpoint for Endpoint {
+    const NSID: &'static str = "com.atproto.server.updateEmail";
+    const METHOD: crate::xrpc::XrpcMethod = crate::xrpc::XrpcMethod::Procedure;
+    const INPUT_ENCODING: Option<&'static str> = Some("application/json");
+    const OUTPUT_ENCODING: Option<&'static str> = None;
+    type Params = ();
+    type Input = Input;
+    type Output = ();
 }
@@ -8,10 +8,10 @@ use serde::{Deserialize, Serialize};
 /// Query parameters
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct QueryParams {
-    /// The CID of the blob to fetch
-    pub cid: String,
     /// The DID of the account.
     pub did: crate::types::Did,
+    /// The CID of the blob to fetch
+    pub cid: crate::types::CidLink,
 }
 
 /// Error: BlobNotFound
@@ -43,22 +43,25 @@ pub struct RepoDeactivatedError;
 pub async fn get_blob(
     client: &impl XrpcClient,
     params: QueryParams,
-) -> Result<XrpcResponse<()>, XrpcError> {
+) -> Result<XrpcResponse<Vec<u8>>, XrpcError> {
     let mut req = XrpcRequest::query("com.atproto.sync.getBlob");
 
     // Add query parameters
-    let params_json = serde_json::to_value(&params)
-        .map_err(XrpcError::Serialization)?;
+    req.params.append("did", params.did.to_string());
+    req.params.append("cid", params.cid.to_string());
+
+    client.request_body(req).await
+}
 
-    if let Some(obj) = params_json.as_object() {
-        for (key, value) in obj {
-            if let Some(s) = value.as_str() {
-                req.params.insert(key.clone(), s.to_string());
-            } else {
-                req.params.insert(key.clone(), value.to_string());
-            }
-        }
-    }
+/// Server-side definition of com.atproto.sync.getBlob
+pub struct Endpoint;
 
-    client.request(req).await
+impl crate::xrpc::XrpcEndpoint for Endpoint {
+    const NSID: &'static str = "com.atproto.sync.getBlob";
+    const METHOD: crate::xrpc::XrpcMethod = crate::xrpc::XrpcMethod::Query;
+    const INPUT_ENCODING: Option<&'static str> = None;
+    const OUTPUT_ENCODING: Option<&'static str> = Some("*/*");
+    type Params = QueryParams;
+    type Input = ();
+    type Output = Vec<u8>;
 }
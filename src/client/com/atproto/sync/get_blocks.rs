@@ -8,9 +8,9 @@ use serde::{Deserialize, Serialize};
 /// Query parameters
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct QueryParams {
-    pub cids: serde_json::Value,
     /// The DID of the repo.
     pub did: crate::types::Did,
+    pub cids: Vec<crate::types::CidLink>,
 }
 
 /// Error: BlockNotFound
@@ -42,22 +42,27 @@ pub struct RepoDeactivatedError;
 pub async fn get_blocks(
     client: &impl XrpcClient,
     params: QueryParams,
-) -> Result<XrpcResponse<()>, XrpcError> {
+) -> Result<XrpcResponse<Vec<u8>>, XrpcError> {
     let mut req = XrpcRequest::query("com.atproto.sync.getBlocks");
 
     // Add query parameters
-    let params_json = serde_json::to_value(&params)
-        .map_err(XrpcError::Serialization)?;
-
-    if let Some(obj) = params_json.as_object() {
-        for (key, value) in obj {
-            if let Some(s) = value.as_str() {
-                req.params.insert(key.clone(), s.to_string());
-            } else {
-                req.params.insert(key.clone(), value.to_string());
-            }
-        }
+    req.params.append("did", params.did.to_string());
+    for value in &params.cids {
+        req.params.append("cids", value.to_string());
     }
 
-    client.request(req).await
+    client.request_body(req).await
+}
+
+/// Server-side definition of com.atproto.sync.getBlocks
+pub struct Endpoint;
+
+impl crate::xrpc::XrpcEndpoint for Endpoint {
+    const NSID: &'static str = "com.atproto.sync.getBlocks";
+    const METHOD: crate::xrpc::XrpcMethod = crate::xrpc::XrpcMethod::Query;
+    const INPUT_ENCODING: Option<&'static str> = None;
+    const OUTPUT_ENCODING: Option<&'static str> = Some("application/vnd.ipld.car");
+    type Params = QueryParams;
+    type Input = ();
+    type Output = Vec<u8>;
 }
@@ -16,22 +16,24 @@ pub struct QueryParams {
 pub async fn get_checkout(
     client: &impl XrpcClient,
     params: QueryParams,
-) -> Result<XrpcResponse<()>, XrpcError> {
+) -> Result<XrpcResponse<Vec<u8>>, XrpcError> {
     let mut req = XrpcRequest::query("com.atproto.sync.getCheckout");
 
     // Add query parameters
-    let params_json = serde_json::to_value(&params)
-        .map_err(XrpcError::Serialization)?;
+    req.params.append("did", params.did.to_string());
 
-    if let Some(obj) = params_json.as_object() {
-        for (key, value) in obj {
-            if let Some(s) = value.as_str() {
-                req.params.insert(key.clone(), s.to_string());
-            } else {
-                req.params.insert(key.clone(), value.to_string());
-            }
-        }
-    }
+    client.request_body(req).await
+}
+
+/// Server-side definition of com.atproto.sync.getCheckout
+pub struct Endpoint;
 
-    client.request(req).await
+impl crate::xrpc::XrpcEndpoint for Endpoint {
+    const NSID: &'static str = "com.atproto.sync.getCheckout";
+    const METHOD: crate::xrpc::XrpcMethod = crate::xrpc::XrpcMethod::Query;
+    const INPUT_ENCODING: Option<&'static str> = None;
+    const OUTPUT_ENCODING: Option<&'static str> = Some("application/vnd.ipld.car");
+    type Params = QueryParams;
+    type Input = ();
+    type Output = Vec<u8>;
 }
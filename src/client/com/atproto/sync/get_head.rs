@@ -15,9 +15,11 @@ pub struct QueryParams {
 /// Response output
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Output {
-    pub root: String,
+    pub root: crate::types::CidLink,
 }
 
+impl crate::xrpc::JsonBody for Output {}
+
 /// Error: HeadNotFound
 #[derive(Debug, Clone, thiserror::Error)]
 #[error("HeadNotFound")]
@@ -31,18 +33,20 @@ pub async fn get_head(
     let mut req = XrpcRequest::query("com.atproto.sync.getHead");
 
     // Add query parameters
-    let params_json = serde_json::to_value(&params)
-        .map_err(XrpcError::Serialization)?;
-
-    if let Some(obj) = params_json.as_object() {
-        for (key, value) in obj {
-            if let Some(s) = value.as_str() {
-                req.params.insert(key.clone(), s.to_string());
-            } else {
-                req.params.insert(key.clone(), value.to_string());
-            }
-        }
-    }
-
-    client.request(req).await
+    req.params.append("did", params.did.to_string());
+
+    client.request_body(req).await
+}
+
+/// Server-side definition of com.atproto.sync.getHead
+pub struct Endpoint;
+
+impl crate::xrpc::XrpcEndpoint for Endpoint {
+    const NSID: &'static str = "com.atproto.sync.getHead";
+    const METHOD: crate::xrpc::XrpcMethod = crate::xrpc::XrpcMethod::Query;
+    const INPUT_ENCODING: Option<&'static str> = None;
+    const OUTPUT_ENCODING: Option<&'static str> = Some("application/json");
+    type Params = QueryParams;
+    type Input = ();
+    type Output = Output;
 }
@@ -15,18 +15,20 @@ pub struct QueryParams {
 /// Response output
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Output {
+    pub hostname: String,
+    /// Recent repo stream event sequence number. May be delayed from actual stream processing (eg, persisted cursor not in-memory cursor).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub seq: Option<i64>,
     /// Number of accounts on the server which are associated with the upstream host. Note that the upstream may actually have more accounts.
     #[serde(skip_serializing_if = "Option::is_none")]
     #[serde(rename = "accountCount")]
     pub account_count: Option<i64>,
-    pub hostname: String,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub status: Option<serde_json::Value>,
-    /// Recent repo stream event sequence number. May be delayed from actual stream processing (eg, persisted cursor not in-memory cursor).
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub seq: Option<i64>,
 }
 
+impl crate::xrpc::JsonBody for Output {}
+
 /// Error: HostNotFound
 #[derive(Debug, Clone, thiserror::Error)]
 #[error("HostNotFound")]
@@ -40,18 +42,20 @@ pub async fn get_host_status(
     let mut req = XrpcRequest::query("com.atproto.sync.getHostStatus");
 
     // Add query parameters
-    let params_json = serde_json::to_value(&params)
-        .map_err(XrpcError::Serialization)?;
-
-    if let Some(obj) = params_json.as_object() {
-        for (key, value) in obj {
-            if let Some(s) = value.as_str() {
-                req.params.insert(key.clone(), s.to_string());
-            } else {
-                req.params.insert(key.clone(), value.to_string());
-            }
-        }
-    }
-
-    client.request(req).await
+    req.params.append("hostname", params.hostname.to_string());
+
+    client.request_body(req).await
+}
+
+/// Server-side definition of com.atproto.sync.getHostStatus
+pub struct Endpoint;
+
+impl crate::xrpc::XrpcEndpoint for Endpoint {
+    const NSID: &'static str = "com.atproto.sync.getHostStatus";
+    const METHOD: crate::xrpc::XrpcMethod = crate::xrpc::XrpcMethod::Query;
+    const INPUT_ENCODING: Option<&'static str> = None;
+    const OUTPUT_ENCODING: Option<&'static str> = Some("application/json");
+    type Params = QueryParams;
+    type Input = ();
+    type Output = Output;
 }
@@ -15,10 +15,12 @@ pub struct QueryParams {
 /// Response output
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Output {
-    pub cid: String,
-    pub rev: String,
+    pub cid: crate::types::CidLink,
+    pub rev: crate::tid::Tid,
 }
 
+impl crate::xrpc::JsonBody for Output {}
+
 /// Error: RepoNotFound
 #[derive(Debug, Clone, thiserror::Error)]
 #[error("RepoNotFound")]
@@ -47,18 +49,20 @@ pub async fn get_latest_commit(
     let mut req = XrpcRequest::query("com.atproto.sync.getLatestCommit");
 
     // Add query parameters
-    let params_json = serde_json::to_value(&params)
-        .map_err(XrpcError::Serialization)?;
+    req.params.append("did", params.did.to_string());
+
+    client.request_body(req).await
+}
 
-    if let Some(obj) = params_json.as_object() {
-        for (key, value) in obj {
-            if let Some(s) = value.as_str() {
-                req.params.insert(key.clone(), s.to_string());
-            } else {
-                req.params.insert(key.clone(), value.to_string());
-            }
-        }
-    }
+/// Server-side definition of com.atproto.sync.getLatestCommit
+pub struct Endpoint;
 
-    client.request(req).await
+impl crate::xrpc::XrpcEndpoint for Endpoint {
+    const NSID: &'static str = "com.atproto.sync.getLatestCommit";
+    const METHOD: crate::xrpc::XrpcMethod = crate::xrpc::XrpcMethod::Query;
+    const INPUT_ENCODING: Option<&'static str> = None;
+    const OUTPUT_ENCODING: Option<&'static str> = Some("application/json");
+    type Params = QueryParams;
+    type Input = ();
+    type Output = Output;
 }
@@ -8,11 +8,11 @@ use serde::{Deserialize, Serialize};
 /// Query parameters
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct QueryParams {
-    /// The revision ('rev') of the repo to create a diff from.
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub since: Option<String>,
     /// The DID of the repo.
     pub did: crate::types::Did,
+    /// The revision ('rev') of the repo to create a diff from.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub since: Option<crate::tid::Tid>,
 }
 
 /// Error: RepoNotFound
@@ -39,22 +39,27 @@ pub struct RepoDeactivatedError;
 pub async fn get_repo(
     client: &impl XrpcClient,
     params: QueryParams,
-) -> Result<XrpcResponse<()>, XrpcError> {
+) -> Result<XrpcResponse<Vec<u8>>, XrpcError> {
     let mut req = XrpcRequest::query("com.atproto.sync.getRepo");
 
     // Add query parameters
-    let params_json = serde_json::to_value(&params)
-        .map_err(XrpcError::Serialization)?;
-
-    if let Some(obj) = params_json.as_object() {
-        for (key, value) in obj {
-            if let Some(s) = value.as_str() {
-                req.params.insert(key.clone(), s.to_string());
-            } else {
-                req.params.insert(key.clone(), value.to_string());
-            }
-        }
+    req.params.append("did", params.did.to_string());
+    if let Some(value) = &params.since {
+        req.params.append("since", value.to_string());
     }
 
-    client.request(req).await
+    client.request_body(req).await
+}
+
+/// Server-side definition of com.atproto.sync.getRepo
+pub struct Endpoint;
+
+impl crate::xrpc::XrpcEndpoint for Endpoint {
+    const NSID: &'static str = "com.atproto.sync.getRepo";
+    const METHOD: crate::xrpc::XrpcMethod = crate::xrpc::XrpcMethod::Query;
+    const INPUT_ENCODING: Option<&'static str> = None;
+    const OUTPUT_ENCODING: Option<&'static str> = Some("application/vnd.ipld.car");
+    type Params = QueryParams;
+    type Input = ();
+    type Output = Vec<u8>;
 }
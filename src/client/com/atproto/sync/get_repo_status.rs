@@ -17,14 +17,16 @@ pub struct QueryParams {
 pub struct Output {
     pub did: crate::types::Did,
     pub active: bool,
-    /// Optional field, the current rev of the repo, if active=true
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub rev: Option<String>,
     /// If active=false, this optional field indicates a possible reason for why the account is not active. If active=false and no status is supplied, then the host makes no claim for why the repository is no longer being hosted.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub status: Option<String>,
+    /// Optional field, the current rev of the repo, if active=true
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub rev: Option<crate::tid::Tid>,
 }
 
+impl crate::xrpc::JsonBody for Output {}
+
 /// Error: RepoNotFound
 #[derive(Debug, Clone, thiserror::Error)]
 #[error("RepoNotFound")]
@@ -38,18 +40,20 @@ pub async fn get_repo_status(
     let mut req = XrpcRequest::query("com.atproto.sync.getRepoStatus");
 
     // Add query parameters
-    let params_json = serde_json::to_value(&params)
-        .map_err(XrpcError::Serialization)?;
-
-    if let Some(obj) = params_json.as_object() {
-        for (key, value) in obj {
-            if let Some(s) = value.as_str() {
-                req.params.insert(key.clone(), s.to_string());
-            } else {
-                req.params.insert(key.clone(), value.to_string());
-            }
-        }
-    }
-
-    client.request(req).await
+    req.params.append("did", params.did.to_string());
+
+    client.request_body(req).await
+}
+
+/// Server-side definition of com.atproto.sync.getRepoStatus
+pub struct Endpoint;
+
+impl crate::xrpc::XrpcEndpoint for Endpoint {
+    const NSID: &'static str = "com.atproto.sync.getRepoStatus";
+    const METHOD: crate::xrpc::XrpcMethod = crate::xrpc::XrpcMethod::Query;
+    const INPUT_ENCODING: Option<&'static str> = None;
+    const OUTPUT_ENCODING: Option<&'static str> = Some("application/json");
+    type Params = QueryParams;
+    type Input = ();
+    type Output = Output;
 }
@@ -8,15 +8,15 @@ use serde::{Deserialize, Serialize};
 /// Query parameters
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct QueryParams {
+    /// The DID of the repo.
+    pub did: crate::types::Did,
     /// Optional revision of the repo to list blobs since.
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub since: Option<String>,
+    pub since: Option<crate::tid::Tid>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub limit: Option<i64>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub cursor: Option<String>,
-    /// The DID of the repo.
-    pub did: crate::types::Did,
 }
 
 /// Response output
@@ -24,9 +24,11 @@ pub struct QueryParams {
 pub struct Output {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub cursor: Option<String>,
-    pub cids: serde_json::Value,
+    pub cids: Vec<crate::types::CidLink>,
 }
 
+impl crate::xrpc::JsonBody for Output {}
+
 /// Error: RepoNotFound
 #[derive(Debug, Clone, thiserror::Error)]
 #[error("RepoNotFound")]
@@ -55,18 +57,29 @@ pub async fn list_blobs(
     let mut req = XrpcRequest::query("com.atproto.sync.listBlobs");
 
     // Add query parameters
-    let params_json = serde_json::to_value(&params)
-        .map_err(XrpcError::Serialization)?;
-
-    if let Some(obj) = params_json.as_object() {
-        for (key, value) in obj {
-            if let Some(s) = value.as_str() {
-                req.params.insert(key.clone(), s.to_string());
-            } else {
-                req.params.insert(key.clone(), value.to_string());
-            }
-        }
+    req.params.append("did", params.did.to_string());
+    if let Some(value) = &params.since {
+        req.params.append("since", value.to_string());
     }
+    if let Some(value) = &params.limit {
+        req.params.append("limit", value.to_string());
+    }
+    if let Some(value) = &params.cursor {
+        req.params.append("cursor", value.to_string());
+    }
+
+    client.request_body(req).await
+}
+
+/// Server-side definition of com.atproto.sync.listBlobs
+pub struct Endpoint;
 
-    client.request(req).await
+impl crate::xrpc::XrpcEndpoint for Endpoint {
+    const NSID: &'static str = "com.atproto.sync.listBlobs";
+    const METHOD: crate::xrpc::XrpcMethod = crate::xrpc::XrpcMethod::Query;
+    const INPUT_ENCODING: Option<&'static str> = None;
+    const OUTPUT_ENCODING: Option<&'static str> = Some("application/json");
+    type Params = QueryParams;
+    type Input = ();
+    type Output = Output;
 }
@@ -2,7 +2,7 @@
 //!
 //! Repository event stream, aka Firehose endpoint. Outputs repo commits with diff data, and identity update events, for all repositories on the current server. See the atproto specifications for details around stream sequencing, repo versioning, CAR diff format, and more. Public and does not require auth; implemented by PDS and Relay.
 
-use crate::xrpc::{XrpcRequest, XrpcError};
+use crate::xrpc::XrpcRequest;
 use crate::xrpc_subscription::{SubscriptionClient, SubscriptionEvent, SubscriptionResult};
 use serde::{Deserialize, Serialize};
 use futures::stream::Stream;
@@ -24,17 +24,8 @@ pub async fn subscribe_repos(
     let mut req = XrpcRequest::query("com.atproto.sync.subscribeRepos");
 
     // Add query parameters
-    let params_json = serde_json::to_value(&params)
-        .map_err(XrpcError::Serialization)?;
-
-    if let Some(obj) = params_json.as_object() {
-        for (key, value) in obj {
-            if let Some(s) = value.as_str() {
-                req.params.insert(key.clone(), s.to_string());
-            } else {
-                req.params.insert(key.clone(), value.to_string());
-            }
-        }
+    if let Some(value) = &params.cursor {
+        req.params.append("cursor", value.to_string());
     }
 
     client.subscribe(req).await
@@ -9,22 +9,67 @@ use serde::{Deserialize, Serialize};
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct QueryParams {
     /// Tentative handle. Will be checked for availability or used to build handle suggestions.
-    pub handle: String,
+    pub handle: crate::types::Handle,
     /// User-provided email. Might be used to build handle suggestions.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub email: Option<String>,
     /// User-provided birth date. Might be used to build handle suggestions.
     #[serde(skip_serializing_if = "Option::is_none")]
     #[serde(rename = "birthDate")]
-    pub birth_date: Option<String>,
+    pub birth_date: Option<crate::types::Datetime>,
 }
 
 /// Response output
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Output {
-    pub result: serde_json::Value,
     /// Echo of the input handle.
-    pub handle: String,
+    pub handle: crate::types::Handle,
+    pub result: OutputResult,
+}
+
+impl crate::xrpc::JsonBody for Output {}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(tag = "$type")]
+pub enum OutputResult {
+    #[serde(rename = "com.atproto.temp.checkHandleAvailability#resultAvailable")]
+    ResultAvailable(Box<ResultAvailable>),
+    #[serde(rename = "com.atproto.temp.checkHandleAvailability#resultUnavailable")]
+    ResultUnavailable(Box<ResultUnavailable>),
+    /// A type not listed in the lexicon, kept as raw JSON
+    #[serde(untagged)]
+    Unknown(serde_json::Value),
+}
+
+impl<'de> Deserialize<'de> for OutputResult {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> std::result::Result<Self, D::Error> {
+        let value = serde_json::Value::deserialize(deserializer)?;
+        match value.get("$type").and_then(|t| t.as_str()) {
+            Some("com.atproto.temp.checkHandleAvailability#resultAvailable") => serde_json::from_value(value).map(Self::ResultAvailable).map_err(serde::de::Error::custom),
+            Some("com.atproto.temp.checkHandleAvailability#resultUnavailable") => serde_json::from_value(value).map(Self::ResultUnavailable).map_err(serde::de::Error::custom),
+            _ => Ok(Self::Unknown(value)),
+        }
+    }
+}
+
+/// Indicates the provided handle is available.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ResultAvailable {
+}
+
+/// Indicates the provided handle is unavailable and gives suggestions of available handles.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ResultUnavailable {
+    /// List of suggested handles based on the provided inputs.
+    /// Indicates the provided handle is unavailable and gives suggestions of available handles.
+    pub suggestions: Vec<serde_json::Value>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Suggestion {
+    pub handle: crate::types::Handle,
+    /// Method used to build this suggestion. Should be considered opaque to clients. Can be used for metrics.
+    pub method: String,
 }
 
 /// Error: InvalidEmail
@@ -41,18 +86,26 @@ pub async fn check_handle_availability(
     let mut req = XrpcRequest::query("com.atproto.temp.checkHandleAvailability");
 
     // Add query parameters
-    let params_json = serde_json::to_value(&params)
-        .map_err(XrpcError::Serialization)?;
-
-    if let Some(obj) = params_json.as_object() {
-        for (key, value) in obj {
-            if let Some(s) = value.as_str() {
-                req.params.insert(key.clone(), s.to_string());
-            } else {
-                req.params.insert(key.clone(), value.to_string());
-            }
-        }
+    req.params.append("handle", params.handle.to_string());
+    if let Some(value) = &params.email {
+        req.params.append("email", value.to_string());
+    }
+    if let Some(value) = &params.birth_date {
+        req.params.append("birthDate", value.to_string());
     }
 
-    client.request(req).await
+    client.request_body(req).await
+}
+
+/// Server-side definition of com.atproto.temp.checkHandleAvailability
+pub struct Endpoint;
+
+impl crate::xrpc::XrpcEndpoint for Endpoint {
+    const NSID: &'static str = "com.atproto.temp.checkHandleAvailability";
+    const METHOD: crate::xrpc::XrpcMethod = crate::xrpc::XrpcMethod::Query;
+    const INPUT_ENCODING: Option<&'static str> = None;
+    const OUTPUT_ENCODING: Option<&'static str> = Some("application/json");
+    type Params = QueryParams;
+    type Input = ();
+    type Output = Output;
 }
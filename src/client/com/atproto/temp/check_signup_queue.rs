@@ -8,20 +8,35 @@ use serde::{Deserialize, Serialize};
 /// Response output
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Output {
+    pub activated: bool,
     #[serde(skip_serializing_if = "Option::is_none")]
     #[serde(rename = "placeInQueue")]
     pub place_in_queue: Option<i64>,
     #[serde(skip_serializing_if = "Option::is_none")]
     #[serde(rename = "estimatedTimeMs")]
     pub estimated_time_ms: Option<i64>,
-    pub activated: bool,
 }
 
+impl crate::xrpc::JsonBody for Output {}
+
 /// Check accounts location in signup queue.
 pub async fn check_signup_queue(
     client: &impl XrpcClient,
 ) -> Result<XrpcResponse<Output>, XrpcError> {
     let req = XrpcRequest::query("com.atproto.temp.checkSignupQueue");
 
-    client.request(req).await
+    client.request_body(req).await
+}
+
+/// Server-side definition of com.atproto.temp.checkSignupQueue
+pub struct Endpoint;
+
+impl crate::xrpc::XrpcEndpoint for Endpoint {
+    const NSID: &'static str = "com.atproto.temp.checkSignupQueue";
+    const METHOD: crate::xrpc::XrpcMethod = crate::xrpc::XrpcMethod::Query;
+    const INPUT_ENCODING: Option<&'static str> = None;
+    const OUTPUT_ENCODING: Option<&'static str> = Some("application/json");
+    type Params = ();
+    type Input = ();
+    type Output = Output;
 }
@@ -19,6 +19,8 @@ pub struct Output {
     pub scope: String,
 }
 
+impl crate::xrpc::JsonBody for Output {}
+
 /// Error: InvalidScopeReference
 /// An invalid scope reference was provided.
 #[derive(Debug, Clone, thiserror::Error)]
@@ -33,18 +35,20 @@ pub async fn dereference_scope(
     let mut req = XrpcRequest::query("com.atproto.temp.dereferenceScope");
 
     // Add query parameters
-    let params_json = serde_json::to_value(&params)
-        .map_err(XrpcError::Serialization)?;
-
-    if let Some(obj) = params_json.as_object() {
-        for (key, value) in obj {
-            if let Some(s) = value.as_str() {
-                req.params.insert(key.clone(), s.to_string());
-            } else {
-                req.params.insert(key.clone(), value.to_string());
-            }
-        }
-    }
-
-    client.request(req).await
+    req.params.append("scope", params.scope.to_string());
+
+    client.request_body(req).await
+}
+
+/// Server-side definition of com.atproto.temp.dereferenceScope
+pub struct Endpoint;
+
+impl crate::xrpc::XrpcEndpoint for Endpoint {
+    const NSID: &'static str = "com.atproto.temp.dereferenceScope";
+    const METHOD: crate::xrpc::XrpcMethod = crate::xrpc::XrpcMethod::Query;
+    const INPUT_ENCODING: Option<&'static str> = None;
+    const OUTPUT_ENCODING: Option<&'static str> = Some("application/json");
+    type Params = QueryParams;
+    type Input = ();
+    type Output = Output;
 }
@@ -12,6 +12,8 @@ pub struct Input {
     pub phone_number: String,
 }
 
+impl crate::xrpc::JsonBody for Input {}
+
 /// Request a verification code to be sent to the supplied phone number
 pub async fn request_phone_verification(
     client: &impl XrpcClient,
@@ -19,5 +21,18 @@ pub async fn request_phone_verification(
 ) -> Result<XrpcResponse<()>, XrpcError> {
     let req = XrpcRequest::procedure("com.atproto.temp.requestPhoneVerification").data(&input)?;
 
-    client.request(req).await
+    client.request_body(req).await
+}
+
+/// Server-side definition of com.atproto.temp.requestPhoneVerification
+pub struct Endpoint;
+
+impl crate::xrpc::XrpcEndpoint for Endpoint {
+    const NSID: &'static str = "com.atproto.temp.requestPhoneVerification";
+    const METHOD: crate::xrpc::XrpcMethod = crate::xrpc::XrpcMethod::Procedure;
+    const INPUT_ENCODING: Option<&'static str> = Some("application/json");
+    const OUTPUT_ENCODING: Option<&'static str> = None;
+    type Params = ();
+    type Input = Input;
+    type Output = ();
 }
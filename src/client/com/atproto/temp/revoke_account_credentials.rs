@@ -11,6 +11,8 @@ pub struct Input {
     pub account: String,
 }
 
+impl crate::xrpc::JsonBody for Input {}
+
 /// Revoke sessions, password, and app passwords associated with account. May be resolved by a password reset.
 pub async fn revoke_account_credentials(
     client: &impl XrpcClient,
@@ -18,5 +20,18 @@ pub async fn revoke_account_credentials(
 ) -> Result<XrpcResponse<()>, XrpcError> {
     let req = XrpcRequest::procedure("com.atproto.temp.revokeAccountCredentials").data(&input)?;
 
-    client.request(req).await
+    client.request_body(req).await
+}
+
+/// Server-side definition of com.atproto.temp.revokeAccountCredentials
+pub struct Endpoint;
+
+impl crate::xrpc::XrpcEndpoint for Endpoint {
+    const NSID: &'static str = "com.atproto.temp.revokeAccountCredentials";
+    const METHOD: crate::xrpc::XrpcMethod = crate::xrpc::XrpcMethod::Procedure;
+    const INPUT_ENCODING: Option<&'static str> = Some("application/json");
+    const OUTPUT_ENCODING: Option<&'static str> = None;
+    type Params = ();
+    type Input = Input;
+    type Output = ();
 }
@@ -3,4 +3,5 @@
 pub mod app;
 pub mod chat;
 pub mod com;
+pub mod lexicons;
 pub mod tools;
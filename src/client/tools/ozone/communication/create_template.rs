@@ -8,27 +8,31 @@ use serde::{Deserialize, Serialize};
 /// Request input
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Input {
+    /// Name of the template.
+    pub name: String,
+    /// Content of the template, markdown supported, can contain variable placeholders.
+    #[serde(rename = "contentMarkdown")]
+    pub content_markdown: String,
     /// Subject of the message, used in emails.
     pub subject: String,
     /// Message language.
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub lang: Option<String>,
+    pub lang: Option<crate::types::Language>,
     /// DID of the user who is creating the template.
     #[serde(skip_serializing_if = "Option::is_none")]
     #[serde(rename = "createdBy")]
     pub created_by: Option<crate::types::Did>,
-    /// Content of the template, markdown supported, can contain variable placeholders.
-    #[serde(rename = "contentMarkdown")]
-    pub content_markdown: String,
-    /// Name of the template.
-    pub name: String,
 }
 
+impl crate::xrpc::JsonBody for Input {}
+
 /// Response output
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Output {
 }
 
+impl crate::xrpc::JsonBody for Output {}
+
 /// Administrative action to create a new, re-usable communication (email for now) template.
 pub async fn create_template(
     client: &impl XrpcClient,
@@ -36,5 +40,18 @@ pub async fn create_template(
 ) -> Result<XrpcResponse<Output>, XrpcError> {
     let req = XrpcRequest::procedure("tools.ozone.communication.createTemplate").data(&input)?;
 
-    client.request(req).await
+    client.request_body(req).await
+}
+
+/// Server-side definition of tools.ozone.communication.createTemplate
+pub struct Endpoint;
+
+impl crate::xrpc::XrpcEndpoint for Endpoint {
+    const NSID: &'static str = "tools.ozone.communication.createTemplate";
+    const METHOD: crate::xrpc::XrpcMethod = crate::xrpc::XrpcMethod::Procedure;
+    const INPUT_ENCODING: Option<&'static str> = Some("application/json");
+    const OUTPUT_ENCODING: Option<&'static str> = Some("application/json");
+    type Params = ();
+    type Input = Input;
+    type Output = Output;
 }
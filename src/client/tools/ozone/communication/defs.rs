@@ -4,26 +4,26 @@ use serde::{Deserialize, Serialize};
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct TemplateView {
+    pub id: String,
     /// Name of the template.
     pub name: String,
-    /// Message language.
+    /// Content of the template, can contain markdown and variable placeholders.
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub lang: Option<String>,
-    pub disabled: bool,
+    pub subject: Option<String>,
     /// Subject of the message, used in emails.
     #[serde(rename = "contentMarkdown")]
     pub content_markdown: String,
-    #[serde(rename = "updatedAt")]
-    pub updated_at: String,
-    #[serde(rename = "createdAt")]
-    pub created_at: String,
+    pub disabled: bool,
+    /// Message language.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub lang: Option<crate::types::Language>,
     /// DID of the user who last updated the template.
     #[serde(rename = "lastUpdatedBy")]
     pub last_updated_by: crate::types::Did,
-    /// Content of the template, can contain markdown and variable placeholders.
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub subject: Option<String>,
-    pub id: String,
+    #[serde(rename = "createdAt")]
+    pub created_at: crate::types::Datetime,
+    #[serde(rename = "updatedAt")]
+    pub updated_at: crate::types::Datetime,
 }
 
 
@@ -11,6 +11,8 @@ pub struct Input {
     pub id: String,
 }
 
+impl crate::xrpc::JsonBody for Input {}
+
 /// Delete a communication template.
 pub async fn delete_template(
     client: &impl XrpcClient,
@@ -18,5 +20,18 @@ pub async fn delete_template(
 ) -> Result<XrpcResponse<()>, XrpcError> {
     let req = XrpcRequest::procedure("tools.ozone.communication.deleteTemplate").data(&input)?;
 
-    client.request(req).await
+    client.request_body(req).await
+}
+
+/// Server-side definition of tools.ozone.communication.deleteTemplate
+pub struct Endpoint;
+
+impl crate::xrpc::XrpcEndpoint for Endpoint {
+    const NSID: &'static str = "tools.ozone.communication.deleteTemplate";
+    const METHOD: crate::xrpc::XrpcMethod = crate::xrpc::XrpcMethod::Procedure;
+    const INPUT_ENCODING: Option<&'static str> = Some("application/json");
+    const OUTPUT_ENCODING: Option<&'static str> = None;
+    type Params = ();
+    type Input = Input;
+    type Output = ();
 }
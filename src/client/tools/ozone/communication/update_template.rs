@@ -8,6 +8,14 @@ use serde::{Deserialize, Serialize};
 /// Request input
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Input {
+    /// ID of the template to be updated.
+    pub id: String,
+    /// Name of the template.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    /// Message language.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub lang: Option<crate::types::Language>,
     /// Content of the template, markdown supported, can contain variable placeholders.
     #[serde(skip_serializing_if = "Option::is_none")]
     #[serde(rename = "contentMarkdown")]
@@ -21,21 +29,17 @@ pub struct Input {
     pub updated_by: Option<crate::types::Did>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub disabled: Option<bool>,
-    /// ID of the template to be updated.
-    pub id: String,
-    /// Name of the template.
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub name: Option<String>,
-    /// Message language.
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub lang: Option<String>,
 }
 
+impl crate::xrpc::JsonBody for Input {}
+
 /// Response output
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Output {
 }
 
+impl crate::xrpc::JsonBody for Output {}
+
 /// Administrative action to update an existing communication template. Allows passing partial fields to patch specific fields only.
 pub async fn update_template(
     client: &impl XrpcClient,
@@ -43,5 +47,18 @@ pub async fn update_template(
 ) -> Result<XrpcResponse<Output>, XrpcError> {
     let req = XrpcRequest::procedure("tools.ozone.communication.updateTemplate").data(&input)?;
 
-    client.request(req).await
+    client.request_body(req).await
+}
+
+/// Server-side definition of tools.ozone.communication.updateTemplate
+pub struct Endpoint;
+
+impl crate::xrpc::XrpcEndpoint for Endpoint {
+    const NSID: &'static str = "tools.ozone.communication.updateTemplate";
+    const METHOD: crate::xrpc::XrpcMethod = crate::xrpc::XrpcMethod::Procedure;
+    const INPUT_ENCODING: Option<&'static str> = Some("application/json");
+    const OUTPUT_ENCODING: Option<&'static str> = Some("application/json");
+    type Params = ();
+    type Input = Input;
+    type Output = Output;
 }
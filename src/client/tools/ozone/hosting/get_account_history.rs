@@ -8,11 +8,11 @@ use serde::{Deserialize, Serialize};
 /// Query parameters
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct QueryParams {
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub cursor: Option<String>,
     pub did: crate::types::Did,
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub events: Option<serde_json::Value>,
+    pub events: Option<Vec<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cursor: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub limit: Option<i64>,
 }
@@ -22,7 +22,77 @@ pub struct QueryParams {
 pub struct Output {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub cursor: Option<String>,
-    pub events: serde_json::Value,
+    pub events: Vec<serde_json::Value>,
+}
+
+impl crate::xrpc::JsonBody for Output {}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Event {
+    pub details: EventDetails,
+    #[serde(rename = "createdBy")]
+    pub created_by: String,
+    #[serde(rename = "createdAt")]
+    pub created_at: crate::types::Datetime,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(tag = "$type")]
+pub enum EventDetails {
+    #[serde(rename = "tools.ozone.hosting.getAccountHistory#accountCreated")]
+    AccountCreated(Box<AccountCreated>),
+    #[serde(rename = "tools.ozone.hosting.getAccountHistory#emailUpdated")]
+    EmailUpdated(Box<EmailUpdated>),
+    #[serde(rename = "tools.ozone.hosting.getAccountHistory#emailConfirmed")]
+    EmailConfirmed(Box<EmailConfirmed>),
+    #[serde(rename = "tools.ozone.hosting.getAccountHistory#passwordUpdated")]
+    PasswordUpdated(Box<PasswordUpdated>),
+    #[serde(rename = "tools.ozone.hosting.getAccountHistory#handleUpdated")]
+    HandleUpdated(Box<HandleUpdated>),
+    /// A type not listed in the lexicon, kept as raw JSON
+    #[serde(untagged)]
+    Unknown(serde_json::Value),
+}
+
+impl<'de> Deserialize<'de> for EventDetails {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> std::result::Result<Self, D::Error> {
+        let value = serde_json::Value::deserialize(deserializer)?;
+        match value.get("$type").and_then(|t| t.as_str()) {
+            Some("tools.ozone.hosting.getAccountHistory#accountCreated") => serde_json::from_value(value).map(Self::AccountCreated).map_err(serde::de::Error::custom),
+            Some("tools.ozone.hosting.getAccountHistory#emailUpdated") => serde_json::from_value(value).map(Self::EmailUpdated).map_err(serde::de::Error::custom),
+            Some("tools.ozone.hosting.getAccountHistory#emailConfirmed") => serde_json::from_value(value).map(Self::EmailConfirmed).map_err(serde::de::Error::custom),
+            Some("tools.ozone.hosting.getAccountHistory#passwordUpdated") => serde_json::from_value(value).map(Self::PasswordUpdated).map_err(serde::de::Error::custom),
+            Some("tools.ozone.hosting.getAccountHistory#handleUpdated") => serde_json::from_value(value).map(Self::HandleUpdated).map_err(serde::de::Error::custom),
+            _ => Ok(Self::Unknown(value)),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AccountCreated {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub email: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub handle: Option<crate::types::Handle>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EmailUpdated {
+    pub email: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EmailConfirmed {
+    pub email: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PasswordUpdated {
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct HandleUpdated {
+    pub handle: crate::types::Handle,
 }
 
 /// Get account history, e.g. log of updated email addresses or other identity information.
@@ -33,18 +103,29 @@ pub async fn get_account_history(
     let mut req = XrpcRequest::query("tools.ozone.hosting.getAccountHistory");
 
     // Add query parameters
-    let params_json = serde_json::to_value(&params)
-        .map_err(XrpcError::Serialization)?;
-
-    if let Some(obj) = params_json.as_object() {
-        for (key, value) in obj {
-            if let Some(s) = value.as_str() {
-                req.params.insert(key.clone(), s.to_string());
-            } else {
-                req.params.insert(key.clone(), value.to_string());
-            }
-        }
+    req.params.append("did", params.did.to_string());
+    for value in params.events.iter().flatten() {
+        req.params.append("events", value.to_string());
+    }
+    if let Some(value) = &params.cursor {
+        req.params.append("cursor", value.to_string());
     }
+    if let Some(value) = &params.limit {
+        req.params.append("limit", value.to_string());
+    }
+
+    client.request_body(req).await
+}
+
+/// Server-side definition of tools.ozone.hosting.getAccountHistory
+pub struct Endpoint;
 
-    client.request(req).await
+impl crate::xrpc::XrpcEndpoint for Endpoint {
+    const NSID: &'static str = "tools.ozone.hosting.getAccountHistory";
+    const METHOD: crate::xrpc::XrpcMethod = crate::xrpc::XrpcMethod::Query;
+    const INPUT_ENCODING: Option<&'static str> = None;
+    const OUTPUT_ENCODING: Option<&'static str> = Some("application/json");
+    type Params = QueryParams;
+    type Input = ();
+    type Output = Output;
 }
@@ -9,17 +9,38 @@ use serde::{Deserialize, Serialize};
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Input {
     /// Array of DID subjects to cancel scheduled actions for
-    pub subjects: serde_json::Value,
+    pub subjects: Vec<crate::types::Did>,
     /// Optional comment describing the reason for cancellation
     #[serde(skip_serializing_if = "Option::is_none")]
     pub comment: Option<String>,
 }
 
+impl crate::xrpc::JsonBody for Input {}
+
 /// Response output
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Output {
 }
 
+impl crate::xrpc::JsonBody for Output {}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CancellationResults {
+    /// DIDs for which all pending scheduled actions were successfully cancelled
+    pub succeeded: Vec<crate::types::Did>,
+    /// DIDs for which cancellation failed with error details
+    pub failed: Vec<serde_json::Value>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FailedCancellation {
+    pub did: crate::types::Did,
+    pub error: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(rename = "errorCode")]
+    pub error_code: Option<String>,
+}
+
 /// Cancel all pending scheduled moderation actions for specified subjects
 pub async fn cancel_scheduled_actions(
     client: &impl XrpcClient,
@@ -27,5 +48,18 @@ pub async fn cancel_scheduled_actions(
 ) -> Result<XrpcResponse<Output>, XrpcError> {
     let req = XrpcRequest::procedure("tools.ozone.moderation.cancelScheduledActions").data(&input)?;
 
-    client.request(req).await
+    client.request_body(req).await
+}
+
+/// Server-side definition of tools.ozone.moderation.cancelScheduledActions
+pub struct Endpoint;
+
+impl crate::xrpc::XrpcEndpoint for Endpoint {
+    const NSID: &'static str = "tools.ozone.moderation.cancelScheduledActions";
+    const METHOD: crate::xrpc::XrpcMethod = crate::xrpc::XrpcMethod::Procedure;
+    const INPUT_ENCODING: Option<&'static str> = Some("application/json");
+    const OUTPUT_ENCODING: Option<&'static str> = Some("application/json");
+    type Params = ();
+    type Input = Input;
+    type Output = Output;
 }
@@ -3,254 +3,636 @@
 use serde::{Deserialize, Serialize};
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct VideoDetails {
-    pub width: i64,
-    pub length: i64,
-    pub height: i64,
+pub struct ModEventView {
+    pub id: i64,
+    pub event: ModEventViewEvent,
+    pub subject: ModEventViewSubject,
+    #[serde(rename = "subjectBlobCids")]
+    pub subject_blob_cids: Vec<String>,
+    #[serde(rename = "createdBy")]
+    pub created_by: crate::types::Did,
+    #[serde(rename = "createdAt")]
+    pub created_at: crate::types::Datetime,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(rename = "creatorHandle")]
+    pub creator_handle: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(rename = "subjectHandle")]
+    pub subject_handle: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(rename = "modTool")]
+    pub mod_tool: Option<serde_json::Value>,
 }
 
+#[derive(Debug, Clone, Serialize)]
+#[serde(tag = "$type")]
+pub enum ModEventViewEvent {
+    #[serde(rename = "tools.ozone.moderation.defs#modEventTakedown")]
+    ModEventTakedown(Box<ModEventTakedown>),
+    #[serde(rename = "tools.ozone.moderation.defs#modEventReverseTakedown")]
+    ModEventReverseTakedown(Box<ModEventReverseTakedown>),
+    #[serde(rename = "tools.ozone.moderation.defs#modEventComment")]
+    ModEventComment(Box<ModEventComment>),
+    #[serde(rename = "tools.ozone.moderation.defs#modEventReport")]
+    ModEventReport(Box<ModEventReport>),
+    #[serde(rename = "tools.ozone.moderation.defs#modEventLabel")]
+    ModEventLabel(Box<ModEventLabel>),
+    #[serde(rename = "tools.ozone.moderation.defs#modEventAcknowledge")]
+    ModEventAcknowledge(Box<ModEventAcknowledge>),
+    #[serde(rename = "tools.ozone.moderation.defs#modEventEscalate")]
+    ModEventEscalate(Box<ModEventEscalate>),
+    #[serde(rename = "tools.ozone.moderation.defs#modEventMute")]
+    ModEventMute(Box<ModEventMute>),
+    #[serde(rename = "tools.ozone.moderation.defs#modEventUnmute")]
+    ModEventUnmute(Box<ModEventUnmute>),
+    #[serde(rename = "tools.ozone.moderation.defs#modEventMuteReporter")]
+    ModEventMuteReporter(Box<ModEventMuteReporter>),
+    #[serde(rename = "tools.ozone.moderation.defs#modEventUnmuteReporter")]
+    ModEventUnmuteReporter(Box<ModEventUnmuteReporter>),
+    #[serde(rename = "tools.ozone.moderation.defs#modEventEmail")]
+    ModEventEmail(Box<ModEventEmail>),
+    #[serde(rename = "tools.ozone.moderation.defs#modEventResolveAppeal")]
+    ModEventResolveAppeal(Box<ModEventResolveAppeal>),
+    #[serde(rename = "tools.ozone.moderation.defs#modEventDivert")]
+    ModEventDivert(Box<ModEventDivert>),
+    #[serde(rename = "tools.ozone.moderation.defs#modEventTag")]
+    ModEventTag(Box<ModEventTag>),
+    #[serde(rename = "tools.ozone.moderation.defs#accountEvent")]
+    AccountEvent(Box<AccountEvent>),
+    #[serde(rename = "tools.ozone.moderation.defs#identityEvent")]
+    IdentityEvent(Box<IdentityEvent>),
+    #[serde(rename = "tools.ozone.moderation.defs#recordEvent")]
+    RecordEvent(Box<RecordEvent>),
+    #[serde(rename = "tools.ozone.moderation.defs#modEventPriorityScore")]
+    ModEventPriorityScore(Box<ModEventPriorityScore>),
+    #[serde(rename = "tools.ozone.moderation.defs#ageAssuranceEvent")]
+    AgeAssuranceEvent(Box<AgeAssuranceEvent>),
+    #[serde(rename = "tools.ozone.moderation.defs#ageAssuranceOverrideEvent")]
+    AgeAssuranceOverrideEvent(Box<AgeAssuranceOverrideEvent>),
+    #[serde(rename = "tools.ozone.moderation.defs#revokeAccountCredentialsEvent")]
+    RevokeAccountCredentialsEvent(Box<RevokeAccountCredentialsEvent>),
+    #[serde(rename = "tools.ozone.moderation.defs#scheduleTakedownEvent")]
+    ScheduleTakedownEvent(Box<ScheduleTakedownEvent>),
+    #[serde(rename = "tools.ozone.moderation.defs#cancelScheduledTakedownEvent")]
+    CancelScheduledTakedownEvent(Box<CancelScheduledTakedownEvent>),
+    /// A type not listed in the lexicon, kept as raw JSON
+    #[serde(untagged)]
+    Unknown(serde_json::Value),
+}
+
+impl<'de> Deserialize<'de> for ModEventViewEvent {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> std::result::Result<Self, D::Error> {
+        let value = serde_json::Value::deserialize(deserializer)?;
+        match value.get("$type").and_then(|t| t.as_str()) {
+            Some("tools.ozone.moderation.defs#modEventTakedown") => serde_json::from_value(value).map(Self::ModEventTakedown).map_err(serde::de::Error::custom),
+            Some("tools.ozone.moderation.defs#modEventReverseTakedown") => serde_json::from_value(value).map(Self::ModEventReverseTakedown).map_err(serde::de::Error::custom),
+            Some("tools.ozone.moderation.defs#modEventComment") => serde_json::from_value(value).map(Self::ModEventComment).map_err(serde::de::Error::custom),
+            Some("tools.ozone.moderation.defs#modEventReport") => serde_json::from_value(value).map(Self::ModEventReport).map_err(serde::de::Error::custom),
+            Some("tools.ozone.moderation.defs#modEventLabel") => serde_json::from_value(value).map(Self::ModEventLabel).map_err(serde::de::Error::custom),
+            Some("tools.ozone.moderation.defs#modEventAcknowledge") => serde_json::from_value(value).map(Self::ModEventAcknowledge).map_err(serde::de::Error::custom),
+            Some("tools.ozone.moderation.defs#modEventEscalate") => serde_json::from_value(value).map(Self::ModEventEscalate).map_err(serde::de::Error::custom),
+            Some("tools.ozone.moderation.defs#modEventMute") => serde_json::from_value(value).map(Self::ModEventMute).map_err(serde::de::Error::custom),
+            Some("tools.ozone.moderation.defs#modEventUnmute") => serde_json::from_value(value).map(Self::ModEventUnmute).map_err(serde::de::Error::custom),
+            Some("tools.ozone.moderation.defs#modEventMuteReporter") => serde_json::from_value(value).map(Self::ModEventMuteReporter).map_err(serde::de::Error::custom),
+            Some("tools.ozone.moderation.defs#modEventUnmuteReporter") => serde_json::from_value(value).map(Self::ModEventUnmuteReporter).map_err(serde::de::Error::custom),
+            Some("tools.ozone.moderation.defs#modEventEmail") => serde_json::from_value(value).map(Self::ModEventEmail).map_err(serde::de::Error::custom),
+            Some("tools.ozone.moderation.defs#modEventResolveAppeal") => serde_json::from_value(value).map(Self::ModEventResolveAppeal).map_err(serde::de::Error::custom),
+            Some("tools.ozone.moderation.defs#modEventDivert") => serde_json::from_value(value).map(Self::ModEventDivert).map_err(serde::de::Error::custom),
+            Some("tools.ozone.moderation.defs#modEventTag") => serde_json::from_value(value).map(Self::ModEventTag).map_err(serde::de::Error::custom),
+            Some("tools.ozone.moderation.defs#accountEvent") => serde_json::from_value(value).map(Self::AccountEvent).map_err(serde::de::Error::custom),
+            Some("tools.ozone.moderation.defs#identityEvent") => serde_json::from_value(value).map(Self::IdentityEvent).map_err(serde::de::Error::custom),
+            Some("tools.ozone.moderation.defs#recordEvent") => serde_json::from_value(value).map(Self::RecordEvent).map_err(serde::de::Error::custom),
+            Some("tools.ozone.moderation.defs#modEventPriorityScore") => serde_json::from_value(value).map(Self::ModEventPriorityScore).map_err(serde::de::Error::custom),
+            Some("tools.ozone.moderation.defs#ageAssuranceEvent") => serde_json::from_value(value).map(Self::AgeAssuranceEvent).map_err(serde::de::Error::custom),
+            Some("tools.ozone.moderation.defs#ageAssuranceOverrideEvent") => serde_json::from_value(value).map(Self::AgeAssuranceOverrideEvent).map_err(serde::de::Error::custom),
+            Some("tools.ozone.moderation.defs#revokeAccountCredentialsEvent") => serde_json::from_value(value).map(Self::RevokeAccountCredentialsEvent).map_err(serde::de::Error::custom),
+            Some("tools.ozone.moderation.defs#scheduleTakedownEvent") => serde_json::from_value(value).map(Self::ScheduleTakedownEvent).map_err(serde::de::Error::custom),
+            Some("tools.ozone.moderation.defs#cancelScheduledTakedownEvent") => serde_json::from_value(value).map(Self::CancelScheduledTakedownEvent).map_err(serde::de::Error::custom),
+            _ => Ok(Self::Unknown(value)),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(tag = "$type")]
+pub enum ModEventViewSubject {
+    #[serde(rename = "com.atproto.admin.defs#repoRef")]
+    RepoRef(Box<crate::client::com::atproto::admin::defs::RepoRef>),
+    #[serde(rename = "com.atproto.repo.strongRef")]
+    StrongRef(Box<crate::client::com::atproto::repo::strong_ref::Main>),
+    #[serde(rename = "chat.bsky.convo.defs#messageRef")]
+    MessageRef(Box<crate::client::chat::bsky::convo::defs::MessageRef>),
+    /// A type not listed in the lexicon, kept as raw JSON
+    #[serde(untagged)]
+    Unknown(serde_json::Value),
+}
+
+impl<'de> Deserialize<'de> for ModEventViewSubject {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> std::result::Result<Self, D::Error> {
+        let value = serde_json::Value::deserialize(deserializer)?;
+        match value.get("$type").and_then(|t| t.as_str()) {
+            Some("com.atproto.admin.defs#repoRef") => serde_json::from_value(value).map(Self::RepoRef).map_err(serde::de::Error::custom),
+            Some("com.atproto.repo.strongRef") => serde_json::from_value(value).map(Self::StrongRef).map_err(serde::de::Error::custom),
+            Some("chat.bsky.convo.defs#messageRef") => serde_json::from_value(value).map(Self::MessageRef).map_err(serde::de::Error::custom),
+            _ => Ok(Self::Unknown(value)),
+        }
+    }
+}
 
-/// Moderation event timeline event for a PLC create operation
-#[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct TimelineEventPlcCreate;
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ModEventViewDetail {
-    pub subject: serde_json::Value,
     pub id: i64,
-    pub event: serde_json::Value,
+    pub event: ModEventViewDetailEvent,
+    pub subject: ModEventViewDetailSubject,
+    #[serde(rename = "subjectBlobs")]
+    pub subject_blobs: Vec<serde_json::Value>,
     #[serde(rename = "createdBy")]
     pub created_by: crate::types::Did,
+    #[serde(rename = "createdAt")]
+    pub created_at: crate::types::Datetime,
     #[serde(skip_serializing_if = "Option::is_none")]
     #[serde(rename = "modTool")]
     pub mod_tool: Option<serde_json::Value>,
-    #[serde(rename = "createdAt")]
-    pub created_at: String,
-    #[serde(rename = "subjectBlobs")]
-    pub subject_blobs: serde_json::Value,
 }
 
+#[derive(Debug, Clone, Serialize)]
+#[serde(tag = "$type")]
+pub enum ModEventViewDetailEvent {
+    #[serde(rename = "tools.ozone.moderation.defs#modEventTakedown")]
+    ModEventTakedown(Box<ModEventTakedown>),
+    #[serde(rename = "tools.ozone.moderation.defs#modEventReverseTakedown")]
+    ModEventReverseTakedown(Box<ModEventReverseTakedown>),
+    #[serde(rename = "tools.ozone.moderation.defs#modEventComment")]
+    ModEventComment(Box<ModEventComment>),
+    #[serde(rename = "tools.ozone.moderation.defs#modEventReport")]
+    ModEventReport(Box<ModEventReport>),
+    #[serde(rename = "tools.ozone.moderation.defs#modEventLabel")]
+    ModEventLabel(Box<ModEventLabel>),
+    #[serde(rename = "tools.ozone.moderation.defs#modEventAcknowledge")]
+    ModEventAcknowledge(Box<ModEventAcknowledge>),
+    #[serde(rename = "tools.ozone.moderation.defs#modEventEscalate")]
+    ModEventEscalate(Box<ModEventEscalate>),
+    #[serde(rename = "tools.ozone.moderation.defs#modEventMute")]
+    ModEventMute(Box<ModEventMute>),
+    #[serde(rename = "tools.ozone.moderation.defs#modEventUnmute")]
+    ModEventUnmute(Box<ModEventUnmute>),
+    #[serde(rename = "tools.ozone.moderation.defs#modEventMuteReporter")]
+    ModEventMuteReporter(Box<ModEventMuteReporter>),
+    #[serde(rename = "tools.ozone.moderation.defs#modEventUnmuteReporter")]
+    ModEventUnmuteReporter(Box<ModEventUnmuteReporter>),
+    #[serde(rename = "tools.ozone.moderation.defs#modEventEmail")]
+    ModEventEmail(Box<ModEventEmail>),
+    #[serde(rename = "tools.ozone.moderation.defs#modEventResolveAppeal")]
+    ModEventResolveAppeal(Box<ModEventResolveAppeal>),
+    #[serde(rename = "tools.ozone.moderation.defs#modEventDivert")]
+    ModEventDivert(Box<ModEventDivert>),
+    #[serde(rename = "tools.ozone.moderation.defs#modEventTag")]
+    ModEventTag(Box<ModEventTag>),
+    #[serde(rename = "tools.ozone.moderation.defs#accountEvent")]
+    AccountEvent(Box<AccountEvent>),
+    #[serde(rename = "tools.ozone.moderation.defs#identityEvent")]
+    IdentityEvent(Box<IdentityEvent>),
+    #[serde(rename = "tools.ozone.moderation.defs#recordEvent")]
+    RecordEvent(Box<RecordEvent>),
+    #[serde(rename = "tools.ozone.moderation.defs#modEventPriorityScore")]
+    ModEventPriorityScore(Box<ModEventPriorityScore>),
+    #[serde(rename = "tools.ozone.moderation.defs#ageAssuranceEvent")]
+    AgeAssuranceEvent(Box<AgeAssuranceEvent>),
+    #[serde(rename = "tools.ozone.moderation.defs#ageAssuranceOverrideEvent")]
+    AgeAssuranceOverrideEvent(Box<AgeAssuranceOverrideEvent>),
+    #[serde(rename = "tools.ozone.moderation.defs#revokeAccountCredentialsEvent")]
+    RevokeAccountCredentialsEvent(Box<RevokeAccountCredentialsEvent>),
+    #[serde(rename = "tools.ozone.moderation.defs#scheduleTakedownEvent")]
+    ScheduleTakedownEvent(Box<ScheduleTakedownEvent>),
+    #[serde(rename = "tools.ozone.moderation.defs#cancelScheduledTakedownEvent")]
+    CancelScheduledTakedownEvent(Box<CancelScheduledTakedownEvent>),
+    /// A type not listed in the lexicon, kept as raw JSON
+    #[serde(untagged)]
+    Unknown(serde_json::Value),
+}
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct RecordViewDetail {
-    pub cid: String,
-    pub repo: serde_json::Value,
-    pub blobs: serde_json::Value,
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub labels: Option<serde_json::Value>,
-    pub value: serde_json::Value,
-    #[serde(rename = "indexedAt")]
-    pub indexed_at: String,
-    pub moderation: serde_json::Value,
-    pub uri: crate::syntax::AtUri,
+impl<'de> Deserialize<'de> for ModEventViewDetailEvent {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> std::result::Result<Self, D::Error> {
+        let value = serde_json::Value::deserialize(deserializer)?;
+        match value.get("$type").and_then(|t| t.as_str()) {
+            Some("tools.ozone.moderation.defs#modEventTakedown") => serde_json::from_value(value).map(Self::ModEventTakedown).map_err(serde::de::Error::custom),
+            Some("tools.ozone.moderation.defs#modEventReverseTakedown") => serde_json::from_value(value).map(Self::ModEventReverseTakedown).map_err(serde::de::Error::custom),
+            Some("tools.ozone.moderation.defs#modEventComment") => serde_json::from_value(value).map(Self::ModEventComment).map_err(serde::de::Error::custom),
+            Some("tools.ozone.moderation.defs#modEventReport") => serde_json::from_value(value).map(Self::ModEventReport).map_err(serde::de::Error::custom),
+            Some("tools.ozone.moderation.defs#modEventLabel") => serde_json::from_value(value).map(Self::ModEventLabel).map_err(serde::de::Error::custom),
+            Some("tools.ozone.moderation.defs#modEventAcknowledge") => serde_json::from_value(value).map(Self::ModEventAcknowledge).map_err(serde::de::Error::custom),
+            Some("tools.ozone.moderation.defs#modEventEscalate") => serde_json::from_value(value).map(Self::ModEventEscalate).map_err(serde::de::Error::custom),
+            Some("tools.ozone.moderation.defs#modEventMute") => serde_json::from_value(value).map(Self::ModEventMute).map_err(serde::de::Error::custom),
+            Some("tools.ozone.moderation.defs#modEventUnmute") => serde_json::from_value(value).map(Self::ModEventUnmute).map_err(serde::de::Error::custom),
+            Some("tools.ozone.moderation.defs#modEventMuteReporter") => serde_json::from_value(value).map(Self::ModEventMuteReporter).map_err(serde::de::Error::custom),
+            Some("tools.ozone.moderation.defs#modEventUnmuteReporter") => serde_json::from_value(value).map(Self::ModEventUnmuteReporter).map_err(serde::de::Error::custom),
+            Some("tools.ozone.moderation.defs#modEventEmail") => serde_json::from_value(value).map(Self::ModEventEmail).map_err(serde::de::Error::custom),
+            Some("tools.ozone.moderation.defs#modEventResolveAppeal") => serde_json::from_value(value).map(Self::ModEventResolveAppeal).map_err(serde::de::Error::custom),
+            Some("tools.ozone.moderation.defs#modEventDivert") => serde_json::from_value(value).map(Self::ModEventDivert).map_err(serde::de::Error::custom),
+            Some("tools.ozone.moderation.defs#modEventTag") => serde_json::from_value(value).map(Self::ModEventTag).map_err(serde::de::Error::custom),
+            Some("tools.ozone.moderation.defs#accountEvent") => serde_json::from_value(value).map(Self::AccountEvent).map_err(serde::de::Error::custom),
+            Some("tools.ozone.moderation.defs#identityEvent") => serde_json::from_value(value).map(Self::IdentityEvent).map_err(serde::de::Error::custom),
+            Some("tools.ozone.moderation.defs#recordEvent") => serde_json::from_value(value).map(Self::RecordEvent).map_err(serde::de::Error::custom),
+            Some("tools.ozone.moderation.defs#modEventPriorityScore") => serde_json::from_value(value).map(Self::ModEventPriorityScore).map_err(serde::de::Error::custom),
+            Some("tools.ozone.moderation.defs#ageAssuranceEvent") => serde_json::from_value(value).map(Self::AgeAssuranceEvent).map_err(serde::de::Error::custom),
+            Some("tools.ozone.moderation.defs#ageAssuranceOverrideEvent") => serde_json::from_value(value).map(Self::AgeAssuranceOverrideEvent).map_err(serde::de::Error::custom),
+            Some("tools.ozone.moderation.defs#revokeAccountCredentialsEvent") => serde_json::from_value(value).map(Self::RevokeAccountCredentialsEvent).map_err(serde::de::Error::custom),
+            Some("tools.ozone.moderation.defs#scheduleTakedownEvent") => serde_json::from_value(value).map(Self::ScheduleTakedownEvent).map_err(serde::de::Error::custom),
+            Some("tools.ozone.moderation.defs#cancelScheduledTakedownEvent") => serde_json::from_value(value).map(Self::CancelScheduledTakedownEvent).map_err(serde::de::Error::custom),
+            _ => Ok(Self::Unknown(value)),
+        }
+    }
 }
 
+#[derive(Debug, Clone, Serialize)]
+#[serde(tag = "$type")]
+pub enum ModEventViewDetailSubject {
+    #[serde(rename = "tools.ozone.moderation.defs#repoView")]
+    RepoView(Box<RepoView>),
+    #[serde(rename = "tools.ozone.moderation.defs#repoViewNotFound")]
+    RepoViewNotFound(Box<RepoViewNotFound>),
+    #[serde(rename = "tools.ozone.moderation.defs#recordView")]
+    RecordView(Box<RecordView>),
+    #[serde(rename = "tools.ozone.moderation.defs#recordViewNotFound")]
+    RecordViewNotFound(Box<RecordViewNotFound>),
+    /// A type not listed in the lexicon, kept as raw JSON
+    #[serde(untagged)]
+    Unknown(serde_json::Value),
+}
 
-/// Add/Remove a tag on a subject
-#[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct ModEventTag {
-    /// Additional comment about added/removed tags.
-    /// Add/Remove a tag on a subject
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub comment: Option<String>,
-    /// Tags to be removed to the subject. Ignores a tag If it doesn't exist, won't be duplicated.
-    /// Add/Remove a tag on a subject
-    pub remove: serde_json::Value,
-    /// Tags to be added to the subject. If already exists, won't be duplicated.
-    /// Add/Remove a tag on a subject
-    pub add: serde_json::Value,
+impl<'de> Deserialize<'de> for ModEventViewDetailSubject {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> std::result::Result<Self, D::Error> {
+        let value = serde_json::Value::deserialize(deserializer)?;
+        match value.get("$type").and_then(|t| t.as_str()) {
+            Some("tools.ozone.moderation.defs#repoView") => serde_json::from_value(value).map(Self::RepoView).map_err(serde::de::Error::custom),
+            Some("tools.ozone.moderation.defs#repoViewNotFound") => serde_json::from_value(value).map(Self::RepoViewNotFound).map_err(serde::de::Error::custom),
+            Some("tools.ozone.moderation.defs#recordView") => serde_json::from_value(value).map(Self::RecordView).map_err(serde::de::Error::custom),
+            Some("tools.ozone.moderation.defs#recordViewNotFound") => serde_json::from_value(value).map(Self::RecordViewNotFound).map_err(serde::de::Error::custom),
+            _ => Ok(Self::Unknown(value)),
+        }
+    }
 }
 
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct AccountHosting {
-    pub status: String,
+pub struct SubjectStatusView {
+    pub id: i64,
+    pub subject: SubjectStatusViewSubject,
     #[serde(skip_serializing_if = "Option::is_none")]
-    #[serde(rename = "deactivatedAt")]
-    pub deactivated_at: Option<String>,
+    pub hosting: Option<SubjectStatusViewHosting>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(rename = "subjectBlobCids")]
+    pub subject_blob_cids: Option<Vec<crate::types::CidLink>>,
     #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(rename = "subjectRepoHandle")]
+    pub subject_repo_handle: Option<String>,
+    /// Timestamp referencing when the last update was made to the moderation status of the subject
     #[serde(rename = "updatedAt")]
-    pub updated_at: Option<String>,
+    pub updated_at: crate::types::Datetime,
+    /// Timestamp referencing the first moderation status impacting event was emitted on the subject
+    #[serde(rename = "createdAt")]
+    pub created_at: crate::types::Datetime,
+    #[serde(rename = "reviewState")]
+    pub review_state: serde_json::Value,
+    /// Sticky comment on the subject.
     #[serde(skip_serializing_if = "Option::is_none")]
-    #[serde(rename = "deletedAt")]
-    pub deleted_at: Option<String>,
+    pub comment: Option<String>,
+    /// Numeric value representing the level of priority. Higher score means higher priority.
     #[serde(skip_serializing_if = "Option::is_none")]
-    #[serde(rename = "reactivatedAt")]
-    pub reactivated_at: Option<String>,
+    #[serde(rename = "priorityScore")]
+    pub priority_score: Option<i64>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    #[serde(rename = "createdAt")]
-    pub created_at: Option<String>,
+    #[serde(rename = "muteUntil")]
+    pub mute_until: Option<crate::types::Datetime>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(rename = "muteReportingUntil")]
+    pub mute_reporting_until: Option<crate::types::Datetime>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(rename = "lastReviewedBy")]
+    pub last_reviewed_by: Option<crate::types::Did>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(rename = "lastReviewedAt")]
+    pub last_reviewed_at: Option<crate::types::Datetime>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(rename = "lastReportedAt")]
+    pub last_reported_at: Option<crate::types::Datetime>,
+    /// Timestamp referencing when the author of the subject appealed a moderation action
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(rename = "lastAppealedAt")]
+    pub last_appealed_at: Option<crate::types::Datetime>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub takendown: Option<bool>,
+    /// True indicates that the a previously taken moderator action was appealed against, by the author of the content. False indicates last appeal was resolved by moderators.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub appealed: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(rename = "suspendUntil")]
+    pub suspend_until: Option<crate::types::Datetime>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tags: Option<Vec<String>>,
+    /// Statistics related to the account subject
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(rename = "accountStats")]
+    pub account_stats: Option<serde_json::Value>,
+    /// Statistics related to the record subjects authored by the subject's account
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(rename = "recordsStats")]
+    pub records_stats: Option<serde_json::Value>,
+    /// Current age assurance state of the subject.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(rename = "ageAssuranceState")]
+    pub age_assurance_state: Option<String>,
+    /// Whether or not the last successful update to age assurance was made by the user or admin.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(rename = "ageAssuranceUpdatedBy")]
+    pub age_assurance_updated_by: Option<String>,
 }
 
+#[derive(Debug, Clone, Serialize)]
+#[serde(tag = "$type")]
+pub enum SubjectStatusViewSubject {
+    #[serde(rename = "com.atproto.admin.defs#repoRef")]
+    RepoRef(Box<crate::client::com::atproto::admin::defs::RepoRef>),
+    #[serde(rename = "com.atproto.repo.strongRef")]
+    StrongRef(Box<crate::client::com::atproto::repo::strong_ref::Main>),
+    #[serde(rename = "chat.bsky.convo.defs#messageRef")]
+    MessageRef(Box<crate::client::chat::bsky::convo::defs::MessageRef>),
+    /// A type not listed in the lexicon, kept as raw JSON
+    #[serde(untagged)]
+    Unknown(serde_json::Value),
+}
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct ModEventAcknowledge {
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub comment: Option<String>,
-    /// If true, all other reports on content authored by this account will be resolved (acknowledged).
-    #[serde(skip_serializing_if = "Option::is_none")]
-    #[serde(rename = "acknowledgeAccountSubjects")]
-    pub acknowledge_account_subjects: Option<bool>,
+impl<'de> Deserialize<'de> for SubjectStatusViewSubject {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> std::result::Result<Self, D::Error> {
+        let value = serde_json::Value::deserialize(deserializer)?;
+        match value.get("$type").and_then(|t| t.as_str()) {
+            Some("com.atproto.admin.defs#repoRef") => serde_json::from_value(value).map(Self::RepoRef).map_err(serde::de::Error::custom),
+            Some("com.atproto.repo.strongRef") => serde_json::from_value(value).map(Self::StrongRef).map_err(serde::de::Error::custom),
+            Some("chat.bsky.convo.defs#messageRef") => serde_json::from_value(value).map(Self::MessageRef).map_err(serde::de::Error::custom),
+            _ => Ok(Self::Unknown(value)),
+        }
+    }
 }
 
+#[derive(Debug, Clone, Serialize)]
+#[serde(tag = "$type")]
+pub enum SubjectStatusViewHosting {
+    #[serde(rename = "tools.ozone.moderation.defs#accountHosting")]
+    AccountHosting(Box<AccountHosting>),
+    #[serde(rename = "tools.ozone.moderation.defs#recordHosting")]
+    RecordHosting(Box<RecordHosting>),
+    /// A type not listed in the lexicon, kept as raw JSON
+    #[serde(untagged)]
+    Unknown(serde_json::Value),
+}
 
-/// Logs lifecycle event on a record subject. Normally captured by automod from the firehose and emitted to ozone for historical tracking.
+impl<'de> Deserialize<'de> for SubjectStatusViewHosting {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> std::result::Result<Self, D::Error> {
+        let value = serde_json::Value::deserialize(deserializer)?;
+        match value.get("$type").and_then(|t| t.as_str()) {
+            Some("tools.ozone.moderation.defs#accountHosting") => serde_json::from_value(value).map(Self::AccountHosting).map_err(serde::de::Error::custom),
+            Some("tools.ozone.moderation.defs#recordHosting") => serde_json::from_value(value).map(Self::RecordHosting).map_err(serde::de::Error::custom),
+            _ => Ok(Self::Unknown(value)),
+        }
+    }
+}
+
+
+/// Detailed view of a subject. For record subjects, the author's repo and profile will be returned.
 #[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct RecordEvent {
-    /// Logs lifecycle event on a record subject. Normally captured by automod from the firehose and emitted to ozone for historical tracking.
+pub struct SubjectView {
+    /// Detailed view of a subject. For record subjects, the author's repo and profile will be returned.
+    #[serde(rename = "type")]
+    pub r#type: serde_json::Value,
+    /// Detailed view of a subject. For record subjects, the author's repo and profile will be returned.
+    pub subject: String,
+    /// Detailed view of a subject. For record subjects, the author's repo and profile will be returned.
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub cid: Option<String>,
-    /// Logs lifecycle event on a record subject. Normally captured by automod from the firehose and emitted to ozone for historical tracking.
-    pub timestamp: String,
-    /// Logs lifecycle event on a record subject. Normally captured by automod from the firehose and emitted to ozone for historical tracking.
+    pub status: Option<serde_json::Value>,
+    /// Detailed view of a subject. For record subjects, the author's repo and profile will be returned.
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub comment: Option<String>,
-    /// Logs lifecycle event on a record subject. Normally captured by automod from the firehose and emitted to ozone for historical tracking.
-    pub op: String,
+    pub repo: Option<serde_json::Value>,
+    /// Detailed view of a subject. For record subjects, the author's repo and profile will be returned.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub profile: Option<SubjectViewProfile>,
+    /// Detailed view of a subject. For record subjects, the author's repo and profile will be returned.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub record: Option<serde_json::Value>,
 }
 
+#[derive(Debug, Clone, Serialize)]
+#[serde(tag = "$type")]
+pub enum SubjectViewProfile {
+    /// A type not listed in the lexicon, kept as raw JSON
+    #[serde(untagged)]
+    Unknown(serde_json::Value),
+}
 
-/// Moderator review status of a subject: Open. Indicates that the subject needs to be reviewed by a moderator
-#[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct ReviewOpen;
+impl<'de> Deserialize<'de> for SubjectViewProfile {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> std::result::Result<Self, D::Error> {
+        let value = serde_json::Value::deserialize(deserializer)?;
+        Ok(Self::Unknown(value))
+    }
+}
 
-/// Age assurance status override by moderators. Only works on DID subjects.
+
+/// Statistics about a particular account subject
 #[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct AgeAssuranceOverrideEvent {
-    /// Comment describing the reason for the override.
-    /// Age assurance status override by moderators. Only works on DID subjects.
-    pub comment: String,
-    /// The status to be set for the user decided by a moderator, overriding whatever value the user had previously. Use reset to default to original state.
-    /// Age assurance status override by moderators. Only works on DID subjects.
-    pub status: String,
+pub struct AccountStats {
+    /// Total number of reports on the account
+    /// Statistics about a particular account subject
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(rename = "reportCount")]
+    pub report_count: Option<i64>,
+    /// Total number of appeals against a moderation action on the account
+    /// Statistics about a particular account subject
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(rename = "appealCount")]
+    pub appeal_count: Option<i64>,
+    /// Number of times the account was suspended
+    /// Statistics about a particular account subject
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(rename = "suspendCount")]
+    pub suspend_count: Option<i64>,
+    /// Number of times the account was escalated
+    /// Statistics about a particular account subject
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(rename = "escalateCount")]
+    pub escalate_count: Option<i64>,
+    /// Number of times the account was taken down
+    /// Statistics about a particular account subject
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(rename = "takedownCount")]
+    pub takedown_count: Option<i64>,
 }
 
 
+/// Statistics about a set of record subject items
 #[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct RepoViewDetail {
-    #[serde(skip_serializing_if = "Option::is_none")]
-    #[serde(rename = "inviteNote")]
-    pub invite_note: Option<String>,
-    #[serde(rename = "relatedRecords")]
-    pub related_records: serde_json::Value,
-    pub did: crate::types::Did,
+pub struct RecordsStats {
+    /// Cumulative sum of the number of reports on the items in the set
+    /// Statistics about a set of record subject items
     #[serde(skip_serializing_if = "Option::is_none")]
-    #[serde(rename = "invitedBy")]
-    pub invited_by: Option<serde_json::Value>,
-    #[serde(rename = "indexedAt")]
-    pub indexed_at: String,
+    #[serde(rename = "totalReports")]
+    pub total_reports: Option<i64>,
+    /// Number of items that were reported at least once
+    /// Statistics about a set of record subject items
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub invites: Option<serde_json::Value>,
-    pub handle: String,
-    pub moderation: serde_json::Value,
+    #[serde(rename = "reportedCount")]
+    pub reported_count: Option<i64>,
+    /// Number of items that were escalated at least once
+    /// Statistics about a set of record subject items
     #[serde(skip_serializing_if = "Option::is_none")]
-    #[serde(rename = "deactivatedAt")]
-    pub deactivated_at: Option<String>,
+    #[serde(rename = "escalatedCount")]
+    pub escalated_count: Option<i64>,
+    /// Number of items that were appealed at least once
+    /// Statistics about a set of record subject items
     #[serde(skip_serializing_if = "Option::is_none")]
-    #[serde(rename = "invitesDisabled")]
-    pub invites_disabled: Option<bool>,
+    #[serde(rename = "appealedCount")]
+    pub appealed_count: Option<i64>,
+    /// Total number of item in the set
+    /// Statistics about a set of record subject items
     #[serde(skip_serializing_if = "Option::is_none")]
-    #[serde(rename = "emailConfirmedAt")]
-    pub email_confirmed_at: Option<String>,
+    #[serde(rename = "subjectCount")]
+    pub subject_count: Option<i64>,
+    /// Number of item currently in "reviewOpen" or "reviewEscalated" state
+    /// Statistics about a set of record subject items
     #[serde(skip_serializing_if = "Option::is_none")]
-    #[serde(rename = "threatSignatures")]
-    pub threat_signatures: Option<serde_json::Value>,
+    #[serde(rename = "pendingCount")]
+    pub pending_count: Option<i64>,
+    /// Number of item currently in "reviewNone" or "reviewClosed" state
+    /// Statistics about a set of record subject items
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub labels: Option<serde_json::Value>,
+    #[serde(rename = "processedCount")]
+    pub processed_count: Option<i64>,
+    /// Number of item currently taken down
+    /// Statistics about a set of record subject items
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub email: Option<String>,
+    #[serde(rename = "takendownCount")]
+    pub takendown_count: Option<i64>,
 }
 
 
+pub type SubjectReviewState = String;
+
+/// Moderator review status of a subject: Open. Indicates that the subject needs to be reviewed by a moderator
 #[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct BlobView {
-    pub size: i64,
-    #[serde(rename = "mimeType")]
-    pub mime_type: String,
-    #[serde(rename = "createdAt")]
-    pub created_at: String,
+pub struct ReviewOpen;
+
+/// Moderator review status of a subject: Escalated. Indicates that the subject was escalated for review by a moderator
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ReviewEscalated;
+
+/// Moderator review status of a subject: Closed. Indicates that the subject was already reviewed and resolved by a moderator
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ReviewClosed;
+
+/// Moderator review status of a subject: Unnecessary. Indicates that the subject does not need a review at the moment but there is probably some moderation related metadata available for it
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ReviewNone;
+
+/// Take down a subject permanently or temporarily
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ModEventTakedown {
+    /// Take down a subject permanently or temporarily
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub details: Option<serde_json::Value>,
+    pub comment: Option<String>,
+    /// Indicates how long the takedown should be in effect before automatically expiring.
+    /// Take down a subject permanently or temporarily
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub moderation: Option<serde_json::Value>,
-    pub cid: String,
+    #[serde(rename = "durationInHours")]
+    pub duration_in_hours: Option<i64>,
+    /// If true, all other reports on content authored by this account will be resolved (acknowledged).
+    /// Take down a subject permanently or temporarily
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(rename = "acknowledgeAccountSubjects")]
+    pub acknowledge_account_subjects: Option<bool>,
+    /// Names/Keywords of the policies that drove the decision.
+    /// Take down a subject permanently or temporarily
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub policies: Option<Vec<String>>,
 }
 
 
-/// View of a scheduled moderation action
+/// Revert take down action on a subject
 #[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct ScheduledActionView {
-    /// Serialized event object that will be propagated to the event when performed
-    /// View of a scheduled moderation action
-    #[serde(skip_serializing_if = "Option::is_none")]
-    #[serde(rename = "eventData")]
-    pub event_data: Option<serde_json::Value>,
-    /// Earliest time to execute the action (for randomized scheduling)
-    /// View of a scheduled moderation action
+pub struct ModEventReverseTakedown {
+    /// Describe reasoning behind the reversal.
+    /// Revert take down action on a subject
     #[serde(skip_serializing_if = "Option::is_none")]
-    #[serde(rename = "executeAfter")]
-    pub execute_after: Option<String>,
-    /// Whether execution time should be randomized within the specified range
-    /// View of a scheduled moderation action
+    pub comment: Option<String>,
+}
+
+
+/// Resolve appeal on a subject
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ModEventResolveAppeal {
+    /// Describe resolution.
+    /// Resolve appeal on a subject
     #[serde(skip_serializing_if = "Option::is_none")]
-    #[serde(rename = "randomizeExecution")]
-    pub randomize_execution: Option<bool>,
-    /// Subject DID for the action
-    /// View of a scheduled moderation action
-    pub did: crate::types::Did,
-    /// Current status of the scheduled action
-    /// View of a scheduled moderation action
-    pub status: String,
-    /// Auto-incrementing row ID
-    /// View of a scheduled moderation action
-    pub id: i64,
-    /// When the scheduled action was created
-    /// View of a scheduled moderation action
-    #[serde(rename = "createdAt")]
-    pub created_at: String,
-    /// Type of action to be executed
-    /// View of a scheduled moderation action
-    pub action: String,
-    /// Latest time to execute the action (for randomized scheduling)
-    /// View of a scheduled moderation action
+    pub comment: Option<String>,
+}
+
+
+/// Add a comment to a subject. An empty comment will clear any previously set sticky comment.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ModEventComment {
+    /// Add a comment to a subject. An empty comment will clear any previously set sticky comment.
     #[serde(skip_serializing_if = "Option::is_none")]
-    #[serde(rename = "executeUntil")]
-    pub execute_until: Option<String>,
-    /// Reason for the last execution failure
-    /// View of a scheduled moderation action
+    pub comment: Option<String>,
+    /// Make the comment persistent on the subject
+    /// Add a comment to a subject. An empty comment will clear any previously set sticky comment.
     #[serde(skip_serializing_if = "Option::is_none")]
-    #[serde(rename = "lastFailureReason")]
-    pub last_failure_reason: Option<String>,
-    /// Exact time to execute the action
-    /// View of a scheduled moderation action
+    pub sticky: Option<bool>,
+}
+
+
+/// Report a subject
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ModEventReport {
+    /// Report a subject
     #[serde(skip_serializing_if = "Option::is_none")]
-    #[serde(rename = "executeAt")]
-    pub execute_at: Option<String>,
-    /// When the action was last attempted to be executed
-    /// View of a scheduled moderation action
+    pub comment: Option<String>,
+    /// Set to true if the reporter was muted from reporting at the time of the event. These reports won't impact the reviewState of the subject.
+    /// Report a subject
     #[serde(skip_serializing_if = "Option::is_none")]
-    #[serde(rename = "lastExecutedAt")]
-    pub last_executed_at: Option<String>,
-    /// When the scheduled action was last updated
-    /// View of a scheduled moderation action
+    #[serde(rename = "isReporterMuted")]
+    pub is_reporter_muted: Option<bool>,
+    /// Report a subject
+    #[serde(rename = "reportType")]
+    pub report_type: serde_json::Value,
+}
+
+
+/// Apply/Negate labels on a subject
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ModEventLabel {
+    /// Apply/Negate labels on a subject
     #[serde(skip_serializing_if = "Option::is_none")]
-    #[serde(rename = "updatedAt")]
-    pub updated_at: Option<String>,
-    /// DID of the user who created this scheduled action
-    /// View of a scheduled moderation action
-    #[serde(rename = "createdBy")]
-    pub created_by: crate::types::Did,
-    /// ID of the moderation event created when action was successfully executed
-    /// View of a scheduled moderation action
+    pub comment: Option<String>,
+    /// Apply/Negate labels on a subject
+    #[serde(rename = "createLabelVals")]
+    pub create_label_vals: Vec<String>,
+    /// Apply/Negate labels on a subject
+    #[serde(rename = "negateLabelVals")]
+    pub negate_label_vals: Vec<String>,
+    /// Indicates how long the label will remain on the subject. Only applies on labels that are being added.
+    /// Apply/Negate labels on a subject
     #[serde(skip_serializing_if = "Option::is_none")]
-    #[serde(rename = "executionEventId")]
-    pub execution_event_id: Option<i64>,
+    #[serde(rename = "durationInHours")]
+    pub duration_in_hours: Option<i64>,
 }
 
 
+/// Set priority score of the subject. Higher score means higher priority.
 #[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct RepoViewNotFound {
-    pub did: crate::types::Did,
+pub struct ModEventPriorityScore {
+    /// Set priority score of the subject. Higher score means higher priority.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub comment: Option<String>,
+    /// Set priority score of the subject. Higher score means higher priority.
+    pub score: i64,
 }
 
 
@@ -260,10 +642,14 @@ pub struct AgeAssuranceEvent {
     /// The date and time of this write operation.
     /// Age assurance info coming directly from users. Only works on DID subjects.
     #[serde(rename = "createdAt")]
-    pub created_at: String,
+    pub created_at: crate::types::Datetime,
     /// The status of the age assurance process.
     /// Age assurance info coming directly from users. Only works on DID subjects.
     pub status: String,
+    /// The unique identifier for this instance of the age assurance flow, in UUID format.
+    /// Age assurance info coming directly from users. Only works on DID subjects.
+    #[serde(rename = "attemptId")]
+    pub attempt_id: String,
     /// The IP address used when initiating the AA flow.
     /// Age assurance info coming directly from users. Only works on DID subjects.
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -279,10 +665,6 @@ pub struct AgeAssuranceEvent {
     #[serde(skip_serializing_if = "Option::is_none")]
     #[serde(rename = "completeIp")]
     pub complete_ip: Option<String>,
-    /// The unique identifier for this instance of the age assurance flow, in UUID format.
-    /// Age assurance info coming directly from users. Only works on DID subjects.
-    #[serde(rename = "attemptId")]
-    pub attempt_id: String,
     /// The user agent used when completing the AA flow.
     /// Age assurance info coming directly from users. Only works on DID subjects.
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -291,98 +673,55 @@ pub struct AgeAssuranceEvent {
 }
 
 
-/// Detailed view of a subject. For record subjects, the author's repo and profile will be returned.
+/// Age assurance status override by moderators. Only works on DID subjects.
 #[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct SubjectView {
-    /// Detailed view of a subject. For record subjects, the author's repo and profile will be returned.
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub profile: Option<serde_json::Value>,
-    /// Detailed view of a subject. For record subjects, the author's repo and profile will be returned.
-    #[serde(rename = "type")]
-    pub r#type: serde_json::Value,
-    /// Detailed view of a subject. For record subjects, the author's repo and profile will be returned.
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub record: Option<serde_json::Value>,
-    /// Detailed view of a subject. For record subjects, the author's repo and profile will be returned.
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub status: Option<serde_json::Value>,
-    /// Detailed view of a subject. For record subjects, the author's repo and profile will be returned.
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub repo: Option<serde_json::Value>,
-    /// Detailed view of a subject. For record subjects, the author's repo and profile will be returned.
-    pub subject: String,
+pub struct AgeAssuranceOverrideEvent {
+    /// The status to be set for the user decided by a moderator, overriding whatever value the user had previously. Use reset to default to original state.
+    /// Age assurance status override by moderators. Only works on DID subjects.
+    pub status: String,
+    /// Comment describing the reason for the override.
+    /// Age assurance status override by moderators. Only works on DID subjects.
+    pub comment: String,
 }
 
 
-/// Report a subject
+/// Account credentials revocation by moderators. Only works on DID subjects.
 #[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct ModEventReport {
-    /// Report a subject
-    #[serde(rename = "reportType")]
-    pub report_type: serde_json::Value,
-    /// Report a subject
+pub struct RevokeAccountCredentialsEvent {
+    /// Comment describing the reason for the revocation.
+    /// Account credentials revocation by moderators. Only works on DID subjects.
+    pub comment: String,
+}
+
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ModEventAcknowledge {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub comment: Option<String>,
-    /// Set to true if the reporter was muted from reporting at the time of the event. These reports won't impact the reviewState of the subject.
-    /// Report a subject
+    /// If true, all other reports on content authored by this account will be resolved (acknowledged).
     #[serde(skip_serializing_if = "Option::is_none")]
-    #[serde(rename = "isReporterMuted")]
-    pub is_reporter_muted: Option<bool>,
+    #[serde(rename = "acknowledgeAccountSubjects")]
+    pub acknowledge_account_subjects: Option<bool>,
 }
 
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct ReporterStats {
-    /// The total number of reports made by the user on accounts.
-    #[serde(rename = "accountReportCount")]
-    pub account_report_count: i64,
-    /// The total number of reports made by the user on records.
-    #[serde(rename = "recordReportCount")]
-    pub record_report_count: i64,
-    /// The total number of records reported by the user.
-    #[serde(rename = "reportedRecordCount")]
-    pub reported_record_count: i64,
-    /// The total number of accounts labeled as a result of the user's reports.
-    #[serde(rename = "labeledAccountCount")]
-    pub labeled_account_count: i64,
-    /// The total number of records labeled as a result of the user's reports.
-    #[serde(rename = "labeledRecordCount")]
-    pub labeled_record_count: i64,
-    /// The total number of accounts reported by the user.
-    #[serde(rename = "reportedAccountCount")]
-    pub reported_account_count: i64,
-    /// The total number of accounts taken down as a result of the user's reports.
-    #[serde(rename = "takendownAccountCount")]
-    pub takendown_account_count: i64,
-    pub did: crate::types::Did,
-    /// The total number of records taken down as a result of the user's reports.
-    #[serde(rename = "takendownRecordCount")]
-    pub takendown_record_count: i64,
+pub struct ModEventEscalate {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub comment: Option<String>,
 }
 
 
-/// Moderation event timeline event for a PLC tombstone operation
-#[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct TimelineEventPlcTombstone;
-
-/// Logs identity related events on a repo subject. Normally captured by automod from the firehose and emitted to ozone for historical tracking.
+/// Mute incoming reports on a subject
 #[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct IdentityEvent {
-    /// Logs identity related events on a repo subject. Normally captured by automod from the firehose and emitted to ozone for historical tracking.
-    pub timestamp: String,
-    /// Logs identity related events on a repo subject. Normally captured by automod from the firehose and emitted to ozone for historical tracking.
+pub struct ModEventMute {
+    /// Mute incoming reports on a subject
     #[serde(skip_serializing_if = "Option::is_none")]
     pub comment: Option<String>,
-    /// Logs identity related events on a repo subject. Normally captured by automod from the firehose and emitted to ozone for historical tracking.
-    #[serde(skip_serializing_if = "Option::is_none")]
-    #[serde(rename = "pdsHost")]
-    pub pds_host: Option<String>,
-    /// Logs identity related events on a repo subject. Normally captured by automod from the firehose and emitted to ozone for historical tracking.
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub tombstone: Option<bool>,
-    /// Logs identity related events on a repo subject. Normally captured by automod from the firehose and emitted to ozone for historical tracking.
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub handle: Option<String>,
+    /// Indicates how long the subject should remain muted.
+    /// Mute incoming reports on a subject
+    #[serde(rename = "durationInHours")]
+    pub duration_in_hours: i64,
 }
 
 
@@ -396,49 +735,17 @@ pub struct ModEventUnmute {
 }
 
 
-/// Take down a subject permanently or temporarily
+/// Mute incoming reports from an account
 #[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct ModEventTakedown {
-    /// Indicates how long the takedown should be in effect before automatically expiring.
-    /// Take down a subject permanently or temporarily
-    #[serde(skip_serializing_if = "Option::is_none")]
-    #[serde(rename = "durationInHours")]
-    pub duration_in_hours: Option<i64>,
-    /// Take down a subject permanently or temporarily
+pub struct ModEventMuteReporter {
+    /// Mute incoming reports from an account
     #[serde(skip_serializing_if = "Option::is_none")]
     pub comment: Option<String>,
-    /// If true, all other reports on content authored by this account will be resolved (acknowledged).
-    /// Take down a subject permanently or temporarily
-    #[serde(skip_serializing_if = "Option::is_none")]
-    #[serde(rename = "acknowledgeAccountSubjects")]
-    pub acknowledge_account_subjects: Option<bool>,
-    /// Names/Keywords of the policies that drove the decision.
-    /// Take down a subject permanently or temporarily
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub policies: Option<serde_json::Value>,
-}
-
-
-pub type SubjectReviewState = String;
-
-/// Moderation tool information for tracing the source of the action
-#[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct ModTool {
-    /// Name/identifier of the source (e.g., 'automod', 'ozone/workspace')
-    /// Moderation tool information for tracing the source of the action
-    pub name: String,
-    /// Additional arbitrary metadata about the source
-    /// Moderation tool information for tracing the source of the action
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub meta: Option<serde_json::Value>,
-}
-
-
-#[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct ModerationDetail {
+    /// Indicates how long the account should remain muted. Falsy value here means a permanent mute.
+    /// Mute incoming reports from an account
     #[serde(skip_serializing_if = "Option::is_none")]
-    #[serde(rename = "subjectStatus")]
-    pub subject_status: Option<serde_json::Value>,
+    #[serde(rename = "durationInHours")]
+    pub duration_in_hours: Option<i64>,
 }
 
 
@@ -452,454 +759,467 @@ pub struct ModEventUnmuteReporter {
 }
 
 
-/// Statistics about a particular account subject
+/// Keep a log of outgoing email to a user
 #[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct AccountStats {
-    /// Number of times the account was taken down
-    /// Statistics about a particular account subject
-    #[serde(skip_serializing_if = "Option::is_none")]
-    #[serde(rename = "takedownCount")]
-    pub takedown_count: Option<i64>,
-    /// Total number of appeals against a moderation action on the account
-    /// Statistics about a particular account subject
-    #[serde(skip_serializing_if = "Option::is_none")]
-    #[serde(rename = "appealCount")]
-    pub appeal_count: Option<i64>,
-    /// Number of times the account was escalated
-    /// Statistics about a particular account subject
-    #[serde(skip_serializing_if = "Option::is_none")]
-    #[serde(rename = "escalateCount")]
-    pub escalate_count: Option<i64>,
-    /// Total number of reports on the account
-    /// Statistics about a particular account subject
+pub struct ModEventEmail {
+    /// The subject line of the email sent to the user.
+    /// Keep a log of outgoing email to a user
+    #[serde(rename = "subjectLine")]
+    pub subject_line: String,
+    /// The content of the email sent to the user.
+    /// Keep a log of outgoing email to a user
     #[serde(skip_serializing_if = "Option::is_none")]
-    #[serde(rename = "reportCount")]
-    pub report_count: Option<i64>,
-    /// Number of times the account was suspended
-    /// Statistics about a particular account subject
+    pub content: Option<String>,
+    /// Additional comment about the outgoing comm.
+    /// Keep a log of outgoing email to a user
     #[serde(skip_serializing_if = "Option::is_none")]
-    #[serde(rename = "suspendCount")]
-    pub suspend_count: Option<i64>,
+    pub comment: Option<String>,
 }
 
 
-/// Moderation event timeline event for generic PLC operation
-#[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct TimelineEventPlcOperation;
-
-/// Add a comment to a subject. An empty comment will clear any previously set sticky comment.
+/// Divert a record's blobs to a 3rd party service for further scanning/tagging
 #[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct ModEventComment {
-    /// Make the comment persistent on the subject
-    /// Add a comment to a subject. An empty comment will clear any previously set sticky comment.
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub sticky: Option<bool>,
-    /// Add a comment to a subject. An empty comment will clear any previously set sticky comment.
+pub struct ModEventDivert {
+    /// Divert a record's blobs to a 3rd party service for further scanning/tagging
     #[serde(skip_serializing_if = "Option::is_none")]
     pub comment: Option<String>,
 }
 
 
-/// Mute incoming reports on a subject
+/// Add/Remove a tag on a subject
 #[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct ModEventMute {
-    /// Mute incoming reports on a subject
+pub struct ModEventTag {
+    /// Tags to be added to the subject. If already exists, won't be duplicated.
+    /// Add/Remove a tag on a subject
+    pub add: Vec<String>,
+    /// Tags to be removed to the subject. Ignores a tag If it doesn't exist, won't be duplicated.
+    /// Add/Remove a tag on a subject
+    pub remove: Vec<String>,
+    /// Additional comment about added/removed tags.
+    /// Add/Remove a tag on a subject
     #[serde(skip_serializing_if = "Option::is_none")]
     pub comment: Option<String>,
-    /// Indicates how long the subject should remain muted.
-    /// Mute incoming reports on a subject
-    #[serde(rename = "durationInHours")]
-    pub duration_in_hours: i64,
 }
 
 
+/// Logs account status related events on a repo subject. Normally captured by automod from the firehose and emitted to ozone for historical tracking.
 #[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct RepoView {
-    pub handle: String,
-    pub did: crate::types::Did,
+pub struct AccountEvent {
+    /// Logs account status related events on a repo subject. Normally captured by automod from the firehose and emitted to ozone for historical tracking.
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub email: Option<String>,
+    pub comment: Option<String>,
+    /// Indicates that the account has a repository which can be fetched from the host that emitted this event.
+    /// Logs account status related events on a repo subject. Normally captured by automod from the firehose and emitted to ozone for historical tracking.
+    pub active: bool,
+    /// Logs account status related events on a repo subject. Normally captured by automod from the firehose and emitted to ozone for historical tracking.
     #[serde(skip_serializing_if = "Option::is_none")]
-    #[serde(rename = "invitedBy")]
-    pub invited_by: Option<serde_json::Value>,
-    #[serde(rename = "indexedAt")]
-    pub indexed_at: String,
+    pub status: Option<String>,
+    /// Logs account status related events on a repo subject. Normally captured by automod from the firehose and emitted to ozone for historical tracking.
+    pub timestamp: crate::types::Datetime,
+}
+
+
+/// Logs identity related events on a repo subject. Normally captured by automod from the firehose and emitted to ozone for historical tracking.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct IdentityEvent {
+    /// Logs identity related events on a repo subject. Normally captured by automod from the firehose and emitted to ozone for historical tracking.
     #[serde(skip_serializing_if = "Option::is_none")]
-    #[serde(rename = "invitesDisabled")]
-    pub invites_disabled: Option<bool>,
+    pub comment: Option<String>,
+    /// Logs identity related events on a repo subject. Normally captured by automod from the firehose and emitted to ozone for historical tracking.
     #[serde(skip_serializing_if = "Option::is_none")]
-    #[serde(rename = "inviteNote")]
-    pub invite_note: Option<String>,
+    pub handle: Option<crate::types::Handle>,
+    /// Logs identity related events on a repo subject. Normally captured by automod from the firehose and emitted to ozone for historical tracking.
     #[serde(skip_serializing_if = "Option::is_none")]
-    #[serde(rename = "threatSignatures")]
-    pub threat_signatures: Option<serde_json::Value>,
+    #[serde(rename = "pdsHost")]
+    pub pds_host: Option<crate::types::Uri>,
+    /// Logs identity related events on a repo subject. Normally captured by automod from the firehose and emitted to ozone for historical tracking.
     #[serde(skip_serializing_if = "Option::is_none")]
-    #[serde(rename = "deactivatedAt")]
-    pub deactivated_at: Option<String>,
-    pub moderation: serde_json::Value,
-    #[serde(rename = "relatedRecords")]
-    pub related_records: serde_json::Value,
+    pub tombstone: Option<bool>,
+    /// Logs identity related events on a repo subject. Normally captured by automod from the firehose and emitted to ozone for historical tracking.
+    pub timestamp: crate::types::Datetime,
 }
 
 
+/// Logs lifecycle event on a record subject. Normally captured by automod from the firehose and emitted to ozone for historical tracking.
 #[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct RecordView {
-    #[serde(rename = "indexedAt")]
-    pub indexed_at: String,
-    pub moderation: serde_json::Value,
-    pub repo: serde_json::Value,
-    #[serde(rename = "blobCids")]
-    pub blob_cids: serde_json::Value,
-    pub cid: String,
-    pub uri: crate::syntax::AtUri,
-    pub value: serde_json::Value,
+pub struct RecordEvent {
+    /// Logs lifecycle event on a record subject. Normally captured by automod from the firehose and emitted to ozone for historical tracking.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub comment: Option<String>,
+    /// Logs lifecycle event on a record subject. Normally captured by automod from the firehose and emitted to ozone for historical tracking.
+    pub op: String,
+    /// Logs lifecycle event on a record subject. Normally captured by automod from the firehose and emitted to ozone for historical tracking.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cid: Option<crate::types::CidLink>,
+    /// Logs lifecycle event on a record subject. Normally captured by automod from the firehose and emitted to ozone for historical tracking.
+    pub timestamp: crate::types::Datetime,
 }
 
 
+/// Logs a scheduled takedown action for an account.
 #[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct RecordHosting {
-    pub status: String,
+pub struct ScheduleTakedownEvent {
+    /// Logs a scheduled takedown action for an account.
     #[serde(skip_serializing_if = "Option::is_none")]
-    #[serde(rename = "updatedAt")]
-    pub updated_at: Option<String>,
+    pub comment: Option<String>,
+    /// Logs a scheduled takedown action for an account.
     #[serde(skip_serializing_if = "Option::is_none")]
-    #[serde(rename = "createdAt")]
-    pub created_at: Option<String>,
+    #[serde(rename = "executeAt")]
+    pub execute_at: Option<crate::types::Datetime>,
+    /// Logs a scheduled takedown action for an account.
     #[serde(skip_serializing_if = "Option::is_none")]
-    #[serde(rename = "deletedAt")]
-    pub deleted_at: Option<String>,
+    #[serde(rename = "executeAfter")]
+    pub execute_after: Option<crate::types::Datetime>,
+    /// Logs a scheduled takedown action for an account.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(rename = "executeUntil")]
+    pub execute_until: Option<crate::types::Datetime>,
 }
 
 
-/// Keep a log of outgoing email to a user
+/// Logs cancellation of a scheduled takedown action for an account.
 #[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct ModEventEmail {
-    /// Additional comment about the outgoing comm.
-    /// Keep a log of outgoing email to a user
+pub struct CancelScheduledTakedownEvent {
+    /// Logs cancellation of a scheduled takedown action for an account.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub comment: Option<String>,
-    /// The content of the email sent to the user.
-    /// Keep a log of outgoing email to a user
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub content: Option<String>,
-    /// The subject line of the email sent to the user.
-    /// Keep a log of outgoing email to a user
-    #[serde(rename = "subjectLine")]
-    pub subject_line: String,
 }
 
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct SubjectStatusView {
-    pub id: i64,
-    #[serde(skip_serializing_if = "Option::is_none")]
-    #[serde(rename = "subjectBlobCids")]
-    pub subject_blob_cids: Option<serde_json::Value>,
-    #[serde(rename = "reviewState")]
-    pub review_state: serde_json::Value,
-    /// Numeric value representing the level of priority. Higher score means higher priority.
-    #[serde(skip_serializing_if = "Option::is_none")]
-    #[serde(rename = "priorityScore")]
-    pub priority_score: Option<i64>,
-    /// Timestamp referencing the first moderation status impacting event was emitted on the subject
-    #[serde(rename = "createdAt")]
-    pub created_at: String,
-    #[serde(skip_serializing_if = "Option::is_none")]
-    #[serde(rename = "muteUntil")]
-    pub mute_until: Option<String>,
-    #[serde(skip_serializing_if = "Option::is_none")]
-    #[serde(rename = "muteReportingUntil")]
-    pub mute_reporting_until: Option<String>,
-    #[serde(skip_serializing_if = "Option::is_none")]
-    #[serde(rename = "suspendUntil")]
-    pub suspend_until: Option<String>,
-    /// Statistics related to the record subjects authored by the subject's account
+pub struct RepoView {
+    pub did: crate::types::Did,
+    pub handle: crate::types::Handle,
     #[serde(skip_serializing_if = "Option::is_none")]
-    #[serde(rename = "recordsStats")]
-    pub records_stats: Option<serde_json::Value>,
-    /// Whether or not the last successful update to age assurance was made by the user or admin.
+    pub email: Option<String>,
+    #[serde(rename = "relatedRecords")]
+    pub related_records: Vec<serde_json::Value>,
+    #[serde(rename = "indexedAt")]
+    pub indexed_at: crate::types::Datetime,
+    pub moderation: serde_json::Value,
     #[serde(skip_serializing_if = "Option::is_none")]
-    #[serde(rename = "ageAssuranceUpdatedBy")]
-    pub age_assurance_updated_by: Option<String>,
+    #[serde(rename = "invitedBy")]
+    pub invited_by: Option<serde_json::Value>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    #[serde(rename = "lastReviewedAt")]
-    pub last_reviewed_at: Option<String>,
+    #[serde(rename = "invitesDisabled")]
+    pub invites_disabled: Option<bool>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub tags: Option<serde_json::Value>,
+    #[serde(rename = "inviteNote")]
+    pub invite_note: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    #[serde(rename = "lastReportedAt")]
-    pub last_reported_at: Option<String>,
-    pub subject: serde_json::Value,
-    /// Statistics related to the account subject
+    #[serde(rename = "deactivatedAt")]
+    pub deactivated_at: Option<crate::types::Datetime>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    #[serde(rename = "accountStats")]
-    pub account_stats: Option<serde_json::Value>,
+    #[serde(rename = "threatSignatures")]
+    pub threat_signatures: Option<Vec<serde_json::Value>>,
+}
+
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RepoViewDetail {
+    pub did: crate::types::Did,
+    pub handle: crate::types::Handle,
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub hosting: Option<serde_json::Value>,
-    /// Timestamp referencing when the author of the subject appealed a moderation action
+    pub email: Option<String>,
+    #[serde(rename = "relatedRecords")]
+    pub related_records: Vec<serde_json::Value>,
+    #[serde(rename = "indexedAt")]
+    pub indexed_at: crate::types::Datetime,
+    pub moderation: serde_json::Value,
     #[serde(skip_serializing_if = "Option::is_none")]
-    #[serde(rename = "lastAppealedAt")]
-    pub last_appealed_at: Option<String>,
-    /// Timestamp referencing when the last update was made to the moderation status of the subject
-    #[serde(rename = "updatedAt")]
-    pub updated_at: String,
+    pub labels: Option<Vec<serde_json::Value>>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    #[serde(rename = "lastReviewedBy")]
-    pub last_reviewed_by: Option<crate::types::Did>,
-    /// True indicates that the a previously taken moderator action was appealed against, by the author of the content. False indicates last appeal was resolved by moderators.
+    #[serde(rename = "invitedBy")]
+    pub invited_by: Option<serde_json::Value>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub appealed: Option<bool>,
-    /// Sticky comment on the subject.
+    pub invites: Option<Vec<serde_json::Value>>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub comment: Option<String>,
-    /// Current age assurance state of the subject.
+    #[serde(rename = "invitesDisabled")]
+    pub invites_disabled: Option<bool>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    #[serde(rename = "ageAssuranceState")]
-    pub age_assurance_state: Option<String>,
+    #[serde(rename = "inviteNote")]
+    pub invite_note: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    #[serde(rename = "subjectRepoHandle")]
-    pub subject_repo_handle: Option<String>,
+    #[serde(rename = "emailConfirmedAt")]
+    pub email_confirmed_at: Option<crate::types::Datetime>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub takendown: Option<bool>,
-}
-
-
-/// Moderator review status of a subject: Closed. Indicates that the subject was already reviewed and resolved by a moderator
-#[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct ReviewClosed;
-
-/// Set priority score of the subject. Higher score means higher priority.
-#[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct ModEventPriorityScore {
-    /// Set priority score of the subject. Higher score means higher priority.
-    pub score: i64,
-    /// Set priority score of the subject. Higher score means higher priority.
+    #[serde(rename = "deactivatedAt")]
+    pub deactivated_at: Option<crate::types::Datetime>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub comment: Option<String>,
+    #[serde(rename = "threatSignatures")]
+    pub threat_signatures: Option<Vec<serde_json::Value>>,
 }
 
 
-/// Mute incoming reports from an account
 #[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct ModEventMuteReporter {
-    /// Mute incoming reports from an account
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub comment: Option<String>,
-    /// Indicates how long the account should remain muted. Falsy value here means a permanent mute.
-    /// Mute incoming reports from an account
-    #[serde(skip_serializing_if = "Option::is_none")]
-    #[serde(rename = "durationInHours")]
-    pub duration_in_hours: Option<i64>,
+pub struct RepoViewNotFound {
+    pub did: crate::types::Did,
 }
 
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct Moderation {
-    #[serde(skip_serializing_if = "Option::is_none")]
-    #[serde(rename = "subjectStatus")]
-    pub subject_status: Option<serde_json::Value>,
+pub struct RecordView {
+    pub uri: crate::syntax::AtUri,
+    pub cid: crate::types::CidLink,
+    pub value: serde_json::Value,
+    #[serde(rename = "blobCids")]
+    pub blob_cids: Vec<crate::types::CidLink>,
+    #[serde(rename = "indexedAt")]
+    pub indexed_at: crate::types::Datetime,
+    pub moderation: serde_json::Value,
+    pub repo: serde_json::Value,
 }
 
 
-/// Moderator review status of a subject: Unnecessary. Indicates that the subject does not need a review at the moment but there is probably some moderation related metadata available for it
-#[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct ReviewNone;
-
 #[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct ModEventView {
-    pub id: i64,
-    #[serde(rename = "subjectBlobCids")]
-    pub subject_blob_cids: serde_json::Value,
-    #[serde(rename = "createdBy")]
-    pub created_by: crate::types::Did,
-    pub subject: serde_json::Value,
-    #[serde(rename = "createdAt")]
-    pub created_at: String,
-    #[serde(skip_serializing_if = "Option::is_none")]
-    #[serde(rename = "subjectHandle")]
-    pub subject_handle: Option<String>,
-    pub event: serde_json::Value,
-    #[serde(skip_serializing_if = "Option::is_none")]
-    #[serde(rename = "creatorHandle")]
-    pub creator_handle: Option<String>,
-    #[serde(skip_serializing_if = "Option::is_none")]
-    #[serde(rename = "modTool")]
-    pub mod_tool: Option<serde_json::Value>,
+pub struct RecordViewDetail {
+    pub uri: crate::syntax::AtUri,
+    pub cid: crate::types::CidLink,
+    pub value: serde_json::Value,
+    pub blobs: Vec<serde_json::Value>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub labels: Option<Vec<serde_json::Value>>,
+    #[serde(rename = "indexedAt")]
+    pub indexed_at: crate::types::Datetime,
+    pub moderation: serde_json::Value,
+    pub repo: serde_json::Value,
 }
 
 
-/// Revert take down action on a subject
 #[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct ModEventReverseTakedown {
-    /// Describe reasoning behind the reversal.
-    /// Revert take down action on a subject
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub comment: Option<String>,
+pub struct RecordViewNotFound {
+    pub uri: crate::syntax::AtUri,
 }
 
 
-/// Divert a record's blobs to a 3rd party service for further scanning/tagging
 #[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct ModEventDivert {
-    /// Divert a record's blobs to a 3rd party service for further scanning/tagging
+pub struct Moderation {
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub comment: Option<String>,
+    #[serde(rename = "subjectStatus")]
+    pub subject_status: Option<serde_json::Value>,
 }
 
 
-/// Statistics about a set of record subject items
 #[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct RecordsStats {
-    /// Number of item currently in "reviewOpen" or "reviewEscalated" state
-    /// Statistics about a set of record subject items
-    #[serde(skip_serializing_if = "Option::is_none")]
-    #[serde(rename = "pendingCount")]
-    pub pending_count: Option<i64>,
-    /// Number of items that were reported at least once
-    /// Statistics about a set of record subject items
-    #[serde(skip_serializing_if = "Option::is_none")]
-    #[serde(rename = "reportedCount")]
-    pub reported_count: Option<i64>,
-    /// Number of item currently in "reviewNone" or "reviewClosed" state
-    /// Statistics about a set of record subject items
-    #[serde(skip_serializing_if = "Option::is_none")]
-    #[serde(rename = "processedCount")]
-    pub processed_count: Option<i64>,
-    /// Total number of item in the set
-    /// Statistics about a set of record subject items
-    #[serde(skip_serializing_if = "Option::is_none")]
-    #[serde(rename = "subjectCount")]
-    pub subject_count: Option<i64>,
-    /// Cumulative sum of the number of reports on the items in the set
-    /// Statistics about a set of record subject items
-    #[serde(skip_serializing_if = "Option::is_none")]
-    #[serde(rename = "totalReports")]
-    pub total_reports: Option<i64>,
-    /// Number of items that were escalated at least once
-    /// Statistics about a set of record subject items
+pub struct ModerationDetail {
     #[serde(skip_serializing_if = "Option::is_none")]
-    #[serde(rename = "escalatedCount")]
-    pub escalated_count: Option<i64>,
-    /// Number of items that were appealed at least once
-    /// Statistics about a set of record subject items
+    #[serde(rename = "subjectStatus")]
+    pub subject_status: Option<serde_json::Value>,
+}
+
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BlobView {
+    pub cid: crate::types::CidLink,
+    #[serde(rename = "mimeType")]
+    pub mime_type: String,
+    pub size: i64,
+    #[serde(rename = "createdAt")]
+    pub created_at: crate::types::Datetime,
     #[serde(skip_serializing_if = "Option::is_none")]
-    #[serde(rename = "appealedCount")]
-    pub appealed_count: Option<i64>,
-    /// Number of item currently taken down
-    /// Statistics about a set of record subject items
+    pub details: Option<BlobViewDetails>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    #[serde(rename = "takendownCount")]
-    pub takendown_count: Option<i64>,
+    pub moderation: Option<serde_json::Value>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(tag = "$type")]
+pub enum BlobViewDetails {
+    #[serde(rename = "tools.ozone.moderation.defs#imageDetails")]
+    ImageDetails(Box<ImageDetails>),
+    #[serde(rename = "tools.ozone.moderation.defs#videoDetails")]
+    VideoDetails(Box<VideoDetails>),
+    /// A type not listed in the lexicon, kept as raw JSON
+    #[serde(untagged)]
+    Unknown(serde_json::Value),
 }
 
+impl<'de> Deserialize<'de> for BlobViewDetails {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> std::result::Result<Self, D::Error> {
+        let value = serde_json::Value::deserialize(deserializer)?;
+        match value.get("$type").and_then(|t| t.as_str()) {
+            Some("tools.ozone.moderation.defs#imageDetails") => serde_json::from_value(value).map(Self::ImageDetails).map_err(serde::de::Error::custom),
+            Some("tools.ozone.moderation.defs#videoDetails") => serde_json::from_value(value).map(Self::VideoDetails).map_err(serde::de::Error::custom),
+            _ => Ok(Self::Unknown(value)),
+        }
+    }
+}
 
-/// Moderator review status of a subject: Escalated. Indicates that the subject was escalated for review by a moderator
-#[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct ReviewEscalated;
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ImageDetails {
-    pub height: i64,
     pub width: i64,
+    pub height: i64,
 }
 
 
-/// Account credentials revocation by moderators. Only works on DID subjects.
 #[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct RevokeAccountCredentialsEvent {
-    /// Comment describing the reason for the revocation.
-    /// Account credentials revocation by moderators. Only works on DID subjects.
-    pub comment: String,
+pub struct VideoDetails {
+    pub width: i64,
+    pub height: i64,
+    pub length: i64,
 }
 
 
-/// Resolve appeal on a subject
 #[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct ModEventResolveAppeal {
-    /// Describe resolution.
-    /// Resolve appeal on a subject
+pub struct AccountHosting {
+    pub status: String,
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub comment: Option<String>,
+    #[serde(rename = "updatedAt")]
+    pub updated_at: Option<crate::types::Datetime>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(rename = "createdAt")]
+    pub created_at: Option<crate::types::Datetime>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(rename = "deletedAt")]
+    pub deleted_at: Option<crate::types::Datetime>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(rename = "deactivatedAt")]
+    pub deactivated_at: Option<crate::types::Datetime>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(rename = "reactivatedAt")]
+    pub reactivated_at: Option<crate::types::Datetime>,
 }
 
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct RecordViewNotFound {
-    pub uri: crate::syntax::AtUri,
+pub struct RecordHosting {
+    pub status: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(rename = "updatedAt")]
+    pub updated_at: Option<crate::types::Datetime>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(rename = "createdAt")]
+    pub created_at: Option<crate::types::Datetime>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(rename = "deletedAt")]
+    pub deleted_at: Option<crate::types::Datetime>,
 }
 
 
-/// Apply/Negate labels on a subject
 #[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct ModEventLabel {
-    /// Apply/Negate labels on a subject
-    #[serde(rename = "negateLabelVals")]
-    pub negate_label_vals: serde_json::Value,
-    /// Indicates how long the label will remain on the subject. Only applies on labels that are being added.
-    /// Apply/Negate labels on a subject
-    #[serde(skip_serializing_if = "Option::is_none")]
-    #[serde(rename = "durationInHours")]
-    pub duration_in_hours: Option<i64>,
-    /// Apply/Negate labels on a subject
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub comment: Option<String>,
-    /// Apply/Negate labels on a subject
-    #[serde(rename = "createLabelVals")]
-    pub create_label_vals: serde_json::Value,
+pub struct ReporterStats {
+    pub did: crate::types::Did,
+    /// The total number of reports made by the user on accounts.
+    #[serde(rename = "accountReportCount")]
+    pub account_report_count: i64,
+    /// The total number of reports made by the user on records.
+    #[serde(rename = "recordReportCount")]
+    pub record_report_count: i64,
+    /// The total number of accounts reported by the user.
+    #[serde(rename = "reportedAccountCount")]
+    pub reported_account_count: i64,
+    /// The total number of records reported by the user.
+    #[serde(rename = "reportedRecordCount")]
+    pub reported_record_count: i64,
+    /// The total number of accounts taken down as a result of the user's reports.
+    #[serde(rename = "takendownAccountCount")]
+    pub takendown_account_count: i64,
+    /// The total number of records taken down as a result of the user's reports.
+    #[serde(rename = "takendownRecordCount")]
+    pub takendown_record_count: i64,
+    /// The total number of accounts labeled as a result of the user's reports.
+    #[serde(rename = "labeledAccountCount")]
+    pub labeled_account_count: i64,
+    /// The total number of records labeled as a result of the user's reports.
+    #[serde(rename = "labeledRecordCount")]
+    pub labeled_record_count: i64,
 }
 
 
+/// Moderation tool information for tracing the source of the action
 #[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct ModEventEscalate {
+pub struct ModTool {
+    /// Name/identifier of the source (e.g., 'automod', 'ozone/workspace')
+    /// Moderation tool information for tracing the source of the action
+    pub name: String,
+    /// Additional arbitrary metadata about the source
+    /// Moderation tool information for tracing the source of the action
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub comment: Option<String>,
+    pub meta: Option<serde_json::Value>,
 }
 
 
-/// Logs account status related events on a repo subject. Normally captured by automod from the firehose and emitted to ozone for historical tracking.
+/// Moderation event timeline event for a PLC create operation
 #[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct AccountEvent {
-    /// Logs account status related events on a repo subject. Normally captured by automod from the firehose and emitted to ozone for historical tracking.
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub status: Option<String>,
-    /// Logs account status related events on a repo subject. Normally captured by automod from the firehose and emitted to ozone for historical tracking.
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub comment: Option<String>,
-    /// Logs account status related events on a repo subject. Normally captured by automod from the firehose and emitted to ozone for historical tracking.
-    pub timestamp: String,
-    /// Indicates that the account has a repository which can be fetched from the host that emitted this event.
-    /// Logs account status related events on a repo subject. Normally captured by automod from the firehose and emitted to ozone for historical tracking.
-    pub active: bool,
-}
+pub struct TimelineEventPlcCreate;
 
+/// Moderation event timeline event for generic PLC operation
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TimelineEventPlcOperation;
 
-/// Logs a scheduled takedown action for an account.
+/// Moderation event timeline event for a PLC tombstone operation
 #[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct ScheduleTakedownEvent {
-    /// Logs a scheduled takedown action for an account.
+pub struct TimelineEventPlcTombstone;
+
+/// View of a scheduled moderation action
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ScheduledActionView {
+    /// Auto-incrementing row ID
+    /// View of a scheduled moderation action
+    pub id: i64,
+    /// Type of action to be executed
+    /// View of a scheduled moderation action
+    pub action: String,
+    /// Serialized event object that will be propagated to the event when performed
+    /// View of a scheduled moderation action
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(rename = "eventData")]
+    pub event_data: Option<serde_json::Value>,
+    /// Subject DID for the action
+    /// View of a scheduled moderation action
+    pub did: crate::types::Did,
+    /// Exact time to execute the action
+    /// View of a scheduled moderation action
     #[serde(skip_serializing_if = "Option::is_none")]
     #[serde(rename = "executeAt")]
-    pub execute_at: Option<String>,
-    /// Logs a scheduled takedown action for an account.
+    pub execute_at: Option<crate::types::Datetime>,
+    /// Earliest time to execute the action (for randomized scheduling)
+    /// View of a scheduled moderation action
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub comment: Option<String>,
-    /// Logs a scheduled takedown action for an account.
+    #[serde(rename = "executeAfter")]
+    pub execute_after: Option<crate::types::Datetime>,
+    /// Latest time to execute the action (for randomized scheduling)
+    /// View of a scheduled moderation action
     #[serde(skip_serializing_if = "Option::is_none")]
     #[serde(rename = "executeUntil")]
-    pub execute_until: Option<String>,
-    /// Logs a scheduled takedown action for an account.
+    pub execute_until: Option<crate::types::Datetime>,
+    /// Whether execution time should be randomized within the specified range
+    /// View of a scheduled moderation action
     #[serde(skip_serializing_if = "Option::is_none")]
-    #[serde(rename = "executeAfter")]
-    pub execute_after: Option<String>,
-}
-
-
-/// Logs cancellation of a scheduled takedown action for an account.
-#[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct CancelScheduledTakedownEvent {
-    /// Logs cancellation of a scheduled takedown action for an account.
+    #[serde(rename = "randomizeExecution")]
+    pub randomize_execution: Option<bool>,
+    /// DID of the user who created this scheduled action
+    /// View of a scheduled moderation action
+    #[serde(rename = "createdBy")]
+    pub created_by: crate::types::Did,
+    /// When the scheduled action was created
+    /// View of a scheduled moderation action
+    #[serde(rename = "createdAt")]
+    pub created_at: crate::types::Datetime,
+    /// When the scheduled action was last updated
+    /// View of a scheduled moderation action
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub comment: Option<String>,
+    #[serde(rename = "updatedAt")]
+    pub updated_at: Option<crate::types::Datetime>,
+    /// Current status of the scheduled action
+    /// View of a scheduled moderation action
+    pub status: String,
+    /// When the action was last attempted to be executed
+    /// View of a scheduled moderation action
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(rename = "lastExecutedAt")]
+    pub last_executed_at: Option<crate::types::Datetime>,
+    /// Reason for the last execution failure
+    /// View of a scheduled moderation action
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(rename = "lastFailureReason")]
+    pub last_failure_reason: Option<String>,
+    /// ID of the moderation event created when action was successfully executed
+    /// View of a scheduled moderation action
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(rename = "executionEventId")]
+    pub execution_event_id: Option<i64>,
 }
 
 
@@ -8,11 +8,11 @@ use serde::{Deserialize, Serialize};
 /// Request input
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Input {
-    pub subject: serde_json::Value,
-    pub event: serde_json::Value,
+    pub event: InputEvent,
+    pub subject: InputSubject,
     #[serde(skip_serializing_if = "Option::is_none")]
     #[serde(rename = "subjectBlobCids")]
-    pub subject_blob_cids: Option<serde_json::Value>,
+    pub subject_blob_cids: Option<Vec<crate::types::CidLink>>,
     #[serde(rename = "createdBy")]
     pub created_by: crate::types::Did,
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -24,11 +24,127 @@ pub struct Input {
     pub external_id: Option<String>,
 }
 
+impl crate::xrpc::JsonBody for Input {}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(tag = "$type")]
+pub enum InputEvent {
+    #[serde(rename = "tools.ozone.moderation.defs#modEventTakedown")]
+    ModEventTakedown(Box<crate::client::tools::ozone::moderation::defs::ModEventTakedown>),
+    #[serde(rename = "tools.ozone.moderation.defs#modEventAcknowledge")]
+    ModEventAcknowledge(Box<crate::client::tools::ozone::moderation::defs::ModEventAcknowledge>),
+    #[serde(rename = "tools.ozone.moderation.defs#modEventEscalate")]
+    ModEventEscalate(Box<crate::client::tools::ozone::moderation::defs::ModEventEscalate>),
+    #[serde(rename = "tools.ozone.moderation.defs#modEventComment")]
+    ModEventComment(Box<crate::client::tools::ozone::moderation::defs::ModEventComment>),
+    #[serde(rename = "tools.ozone.moderation.defs#modEventLabel")]
+    ModEventLabel(Box<crate::client::tools::ozone::moderation::defs::ModEventLabel>),
+    #[serde(rename = "tools.ozone.moderation.defs#modEventReport")]
+    ModEventReport(Box<crate::client::tools::ozone::moderation::defs::ModEventReport>),
+    #[serde(rename = "tools.ozone.moderation.defs#modEventMute")]
+    ModEventMute(Box<crate::client::tools::ozone::moderation::defs::ModEventMute>),
+    #[serde(rename = "tools.ozone.moderation.defs#modEventUnmute")]
+    ModEventUnmute(Box<crate::client::tools::ozone::moderation::defs::ModEventUnmute>),
+    #[serde(rename = "tools.ozone.moderation.defs#modEventMuteReporter")]
+    ModEventMuteReporter(Box<crate::client::tools::ozone::moderation::defs::ModEventMuteReporter>),
+    #[serde(rename = "tools.ozone.moderation.defs#modEventUnmuteReporter")]
+    ModEventUnmuteReporter(Box<crate::client::tools::ozone::moderation::defs::ModEventUnmuteReporter>),
+    #[serde(rename = "tools.ozone.moderation.defs#modEventReverseTakedown")]
+    ModEventReverseTakedown(Box<crate::client::tools::ozone::moderation::defs::ModEventReverseTakedown>),
+    #[serde(rename = "tools.ozone.moderation.defs#modEventResolveAppeal")]
+    ModEventResolveAppeal(Box<crate::client::tools::ozone::moderation::defs::ModEventResolveAppeal>),
+    #[serde(rename = "tools.ozone.moderation.defs#modEventEmail")]
+    ModEventEmail(Box<crate::client::tools::ozone::moderation::defs::ModEventEmail>),
+    #[serde(rename = "tools.ozone.moderation.defs#modEventDivert")]
+    ModEventDivert(Box<crate::client::tools::ozone::moderation::defs::ModEventDivert>),
+    #[serde(rename = "tools.ozone.moderation.defs#modEventTag")]
+    ModEventTag(Box<crate::client::tools::ozone::moderation::defs::ModEventTag>),
+    #[serde(rename = "tools.ozone.moderation.defs#accountEvent")]
+    AccountEvent(Box<crate::client::tools::ozone::moderation::defs::AccountEvent>),
+    #[serde(rename = "tools.ozone.moderation.defs#identityEvent")]
+    IdentityEvent(Box<crate::client::tools::ozone::moderation::defs::IdentityEvent>),
+    #[serde(rename = "tools.ozone.moderation.defs#recordEvent")]
+    RecordEvent(Box<crate::client::tools::ozone::moderation::defs::RecordEvent>),
+    #[serde(rename = "tools.ozone.moderation.defs#modEventPriorityScore")]
+    ModEventPriorityScore(Box<crate::client::tools::ozone::moderation::defs::ModEventPriorityScore>),
+    #[serde(rename = "tools.ozone.moderation.defs#ageAssuranceEvent")]
+    AgeAssuranceEvent(Box<crate::client::tools::ozone::moderation::defs::AgeAssuranceEvent>),
+    #[serde(rename = "tools.ozone.moderation.defs#ageAssuranceOverrideEvent")]
+    AgeAssuranceOverrideEvent(Box<crate::client::tools::ozone::moderation::defs::AgeAssuranceOverrideEvent>),
+    #[serde(rename = "tools.ozone.moderation.defs#revokeAccountCredentialsEvent")]
+    RevokeAccountCredentialsEvent(Box<crate::client::tools::ozone::moderation::defs::RevokeAccountCredentialsEvent>),
+    #[serde(rename = "tools.ozone.moderation.defs#scheduleTakedownEvent")]
+    ScheduleTakedownEvent(Box<crate::client::tools::ozone::moderation::defs::ScheduleTakedownEvent>),
+    #[serde(rename = "tools.ozone.moderation.defs#cancelScheduledTakedownEvent")]
+    CancelScheduledTakedownEvent(Box<crate::client::tools::ozone::moderation::defs::CancelScheduledTakedownEvent>),
+    /// A type not listed in the lexicon, kept as raw JSON
+    #[serde(untagged)]
+    Unknown(serde_json::Value),
+}
+
+impl<'de> Deserialize<'de> for InputEvent {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> std::result::Result<Self, D::Error> {
+        let value = serde_json::Value::deserialize(deserializer)?;
+        match value.get("$type").and_then(|t| t.as_str()) {
+            Some("tools.ozone.moderation.defs#modEventTakedown") => serde_json::from_value(value).map(Self::ModEventTakedown).map_err(serde::de::Error::custom),
+            Some("tools.ozone.moderation.defs#modEventAcknowledge") => serde_json::from_value(value).map(Self::ModEventAcknowledge).map_err(serde::de::Error::custom),
+            Some("tools.ozone.moderation.defs#modEventEscalate") => serde_json::from_value(value).map(Self::ModEventEscalate).map_err(serde::de::Error::custom),
+            Some("tools.ozone.moderation.defs#modEventComment") => serde_json::from_value(value).map(Self::ModEventComment).map_err(serde::de::Error::custom),
+            Some("tools.ozone.moderation.defs#modEventLabel") => serde_json::from_value(value).map(Self::ModEventLabel).map_err(serde::de::Error::custom),
+            Some("tools.ozone.moderation.defs#modEventReport") => serde_json::from_value(value).map(Self::ModEventReport).map_err(serde::de::Error::custom),
+            Some("tools.ozone.moderation.defs#modEventMute") => serde_json::from_value(value).map(Self::ModEventMute).map_err(serde::de::Error::custom),
+            Some("tools.ozone.moderation.defs#modEventUnmute") => serde_json::from_value(value).map(Self::ModEventUnmute).map_err(serde::de::Error::custom),
+            Some("tools.ozone.moderation.defs#modEventMuteReporter") => serde_json::from_value(value).map(Self::ModEventMuteReporter).map_err(serde::de::Error::custom),
+            Some("tools.ozone.moderation.defs#modEventUnmuteReporter") => serde_json::from_value(value).map(Self::ModEventUnmuteReporter).map_err(serde::de::Error::custom),
+            Some("tools.ozone.moderation.defs#modEventReverseTakedown") => serde_json::from_value(value).map(Self::ModEventReverseTakedown).map_err(serde::de::Error::custom),
+            Some("tools.ozone.moderation.defs#modEventResolveAppeal") => serde_json::from_value(value).map(Self::ModEventResolveAppeal).map_err(serde::de::Error::custom),
+            Some("tools.ozone.moderation.defs#modEventEmail") => serde_json::from_value(value).map(Self::ModEventEmail).map_err(serde::de::Error::custom),
+            Some("tools.ozone.moderation.defs#modEventDivert") => serde_json::from_value(value).map(Self::ModEventDivert).map_err(serde::de::Error::custom),
+            Some("tools.ozone.moderation.defs#modEventTag") => serde_json::from_value(value).map(Self::ModEventTag).map_err(serde::de::Error::custom),
+            Some("tools.ozone.moderation.defs#accountEvent") => serde_json::from_value(value).map(Self::AccountEvent).map_err(serde::de::Error::custom),
+            Some("tools.ozone.moderation.defs#identityEvent") => serde_json::from_value(value).map(Self::IdentityEvent).map_err(serde::de::Error::custom),
+            Some("tools.ozone.moderation.defs#recordEvent") => serde_json::from_value(value).map(Self::RecordEvent).map_err(serde::de::Error::custom),
+            Some("tools.ozone.moderation.defs#modEventPriorityScore") => serde_json::from_value(value).map(Self::ModEventPriorityScore).map_err(serde::de::Error::custom),
+            Some("tools.ozone.moderation.defs#ageAssuranceEvent") => serde_json::from_value(value).map(Self::AgeAssuranceEvent).map_err(serde::de::Error::custom),
+            Some("tools.ozone.moderation.defs#ageAssuranceOverrideEvent") => serde_json::from_value(value).map(Self::AgeAssuranceOverrideEvent).map_err(serde::de::Error::custom),
+            Some("tools.ozone.moderation.defs#revokeAccountCredentialsEvent") => serde_json::from_value(value).map(Self::RevokeAccountCredentialsEvent).map_err(serde::de::Error::custom),
+            Some("tools.ozone.moderation.defs#scheduleTakedownEvent") => serde_json::from_value(value).map(Self::ScheduleTakedownEvent).map_err(serde::de::Error::custom),
+            Some("tools.ozone.moderation.defs#cancelScheduledTakedownEvent") => serde_json::from_value(value).map(Self::CancelScheduledTakedownEvent).map_err(serde::de::Error::custom),
+            _ => Ok(Self::Unknown(value)),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(tag = "$type")]
+pub enum InputSubject {
+    #[serde(rename = "com.atproto.admin.defs#repoRef")]
+    RepoRef(Box<crate::client::com::atproto::admin::defs::RepoRef>),
+    #[serde(rename = "com.atproto.repo.strongRef")]
+    StrongRef(Box<crate::client::com::atproto::repo::strong_ref::Main>),
+    /// A type not listed in the lexicon, kept as raw JSON
+    #[serde(untagged)]
+    Unknown(serde_json::Value),
+}
+
+impl<'de> Deserialize<'de> for InputSubject {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> std::result::Result<Self, D::Error> {
+        let value = serde_json::Value::deserialize(deserializer)?;
+        match value.get("$type").and_then(|t| t.as_str()) {
+            Some("com.atproto.admin.defs#repoRef") => serde_json::from_value(value).map(Self::RepoRef).map_err(serde::de::Error::custom),
+            Some("com.atproto.repo.strongRef") => serde_json::from_value(value).map(Self::StrongRef).map_err(serde::de::Error::custom),
+            _ => Ok(Self::Unknown(value)),
+        }
+    }
+}
+
 /// Response output
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Output {
 }
 
+impl crate::xrpc::JsonBody for Output {}
+
 /// Take a moderation action on an actor.
 pub async fn emit_event(
     client: &impl XrpcClient,
@@ -36,5 +152,18 @@ pub async fn emit_event(
 ) -> Result<XrpcResponse<Output>, XrpcError> {
     let req = XrpcRequest::procedure("tools.ozone.moderation.emitEvent").data(&input)?;
 
-    client.request(req).await
+    client.request_body(req).await
+}
+
+/// Server-side definition of tools.ozone.moderation.emitEvent
+pub struct Endpoint;
+
+impl crate::xrpc::XrpcEndpoint for Endpoint {
+    const NSID: &'static str = "tools.ozone.moderation.emitEvent";
+    const METHOD: crate::xrpc::XrpcMethod = crate::xrpc::XrpcMethod::Procedure;
+    const INPUT_ENCODING: Option<&'static str> = Some("application/json");
+    const OUTPUT_ENCODING: Option<&'static str> = Some("application/json");
+    type Params = ();
+    type Input = Input;
+    type Output = Output;
 }
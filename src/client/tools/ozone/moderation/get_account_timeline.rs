@@ -14,7 +14,24 @@ pub struct QueryParams {
 /// Response output
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Output {
-    pub timeline: serde_json::Value,
+    pub timeline: Vec<serde_json::Value>,
+}
+
+impl crate::xrpc::JsonBody for Output {}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TimelineItem {
+    pub day: String,
+    pub summary: Vec<serde_json::Value>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TimelineItemSummary {
+    #[serde(rename = "eventSubjectType")]
+    pub event_subject_type: String,
+    #[serde(rename = "eventType")]
+    pub event_type: String,
+    pub count: i64,
 }
 
 /// Error: RepoNotFound
@@ -30,18 +47,20 @@ pub async fn get_account_timeline(
     let mut req = XrpcRequest::query("tools.ozone.moderation.getAccountTimeline");
 
     // Add query parameters
-    let params_json = serde_json::to_value(&params)
-        .map_err(XrpcError::Serialization)?;
-
-    if let Some(obj) = params_json.as_object() {
-        for (key, value) in obj {
-            if let Some(s) = value.as_str() {
-                req.params.insert(key.clone(), s.to_string());
-            } else {
-                req.params.insert(key.clone(), value.to_string());
-            }
-        }
-    }
-
-    client.request(req).await
+    req.params.append("did", params.did.to_string());
+
+    client.request_body(req).await
+}
+
+/// Server-side definition of tools.ozone.moderation.getAccountTimeline
+pub struct Endpoint;
+
+impl crate::xrpc::XrpcEndpoint for Endpoint {
+    const NSID: &'static str = "tools.ozone.moderation.getAccountTimeline";
+    const METHOD: crate::xrpc::XrpcMethod = crate::xrpc::XrpcMethod::Query;
+    const INPUT_ENCODING: Option<&'static str> = None;
+    const OUTPUT_ENCODING: Option<&'static str> = Some("application/json");
+    type Params = QueryParams;
+    type Input = ();
+    type Output = Output;
 }
@@ -16,6 +16,8 @@ pub struct QueryParams {
 pub struct Output {
 }
 
+impl crate::xrpc::JsonBody for Output {}
+
 /// Get details about a moderation event.
 pub async fn get_event(
     client: &impl XrpcClient,
@@ -24,18 +26,20 @@ pub async fn get_event(
     let mut req = XrpcRequest::query("tools.ozone.moderation.getEvent");
 
     // Add query parameters
-    let params_json = serde_json::to_value(&params)
-        .map_err(XrpcError::Serialization)?;
-
-    if let Some(obj) = params_json.as_object() {
-        for (key, value) in obj {
-            if let Some(s) = value.as_str() {
-                req.params.insert(key.clone(), s.to_string());
-            } else {
-                req.params.insert(key.clone(), value.to_string());
-            }
-        }
-    }
-
-    client.request(req).await
+    req.params.append("id", params.id.to_string());
+
+    client.request_body(req).await
+}
+
+/// Server-side definition of tools.ozone.moderation.getEvent
+pub struct Endpoint;
+
+impl crate::xrpc::XrpcEndpoint for Endpoint {
+    const NSID: &'static str = "tools.ozone.moderation.getEvent";
+    const METHOD: crate::xrpc::XrpcMethod = crate::xrpc::XrpcMethod::Query;
+    const INPUT_ENCODING: Option<&'static str> = None;
+    const OUTPUT_ENCODING: Option<&'static str> = Some("application/json");
+    type Params = QueryParams;
+    type Input = ();
+    type Output = Output;
 }
@@ -8,9 +8,9 @@ use serde::{Deserialize, Serialize};
 /// Query parameters
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct QueryParams {
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub cid: Option<String>,
     pub uri: crate::syntax::AtUri,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cid: Option<crate::types::CidLink>,
 }
 
 /// Response output
@@ -18,6 +18,8 @@ pub struct QueryParams {
 pub struct Output {
 }
 
+impl crate::xrpc::JsonBody for Output {}
+
 /// Error: RecordNotFound
 #[derive(Debug, Clone, thiserror::Error)]
 #[error("RecordNotFound")]
@@ -31,18 +33,23 @@ pub async fn get_record(
     let mut req = XrpcRequest::query("tools.ozone.moderation.getRecord");
 
     // Add query parameters
-    let params_json = serde_json::to_value(&params)
-        .map_err(XrpcError::Serialization)?;
-
-    if let Some(obj) = params_json.as_object() {
-        for (key, value) in obj {
-            if let Some(s) = value.as_str() {
-                req.params.insert(key.clone(), s.to_string());
-            } else {
-                req.params.insert(key.clone(), value.to_string());
-            }
-        }
+    req.params.append("uri", params.uri.to_string());
+    if let Some(value) = &params.cid {
+        req.params.append("cid", value.to_string());
     }
 
-    client.request(req).await
+    client.request_body(req).await
+}
+
+/// Server-side definition of tools.ozone.moderation.getRecord
+pub struct Endpoint;
+
+impl crate::xrpc::XrpcEndpoint for Endpoint {
+    const NSID: &'static str = "tools.ozone.moderation.getRecord";
+    const METHOD: crate::xrpc::XrpcMethod = crate::xrpc::XrpcMethod::Query;
+    const INPUT_ENCODING: Option<&'static str> = None;
+    const OUTPUT_ENCODING: Option<&'static str> = Some("application/json");
+    type Params = QueryParams;
+    type Input = ();
+    type Output = Output;
 }
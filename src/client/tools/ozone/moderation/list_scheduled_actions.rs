@@ -8,36 +8,40 @@ use serde::{Deserialize, Serialize};
 /// Request input
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Input {
-    /// Maximum number of results to return
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub limit: Option<i64>,
-    /// Filter actions by status
-    pub statuses: serde_json::Value,
-    /// Cursor for pagination
+    /// Filter actions scheduled to execute after this time
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub cursor: Option<String>,
+    #[serde(rename = "startsAfter")]
+    pub starts_after: Option<crate::types::Datetime>,
     /// Filter actions scheduled to execute before this time
     #[serde(skip_serializing_if = "Option::is_none")]
     #[serde(rename = "endsBefore")]
-    pub ends_before: Option<String>,
+    pub ends_before: Option<crate::types::Datetime>,
     /// Filter actions for specific DID subjects
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub subjects: Option<serde_json::Value>,
-    /// Filter actions scheduled to execute after this time
+    pub subjects: Option<Vec<crate::types::Did>>,
+    /// Filter actions by status
+    pub statuses: Vec<String>,
+    /// Maximum number of results to return
     #[serde(skip_serializing_if = "Option::is_none")]
-    #[serde(rename = "startsAfter")]
-    pub starts_after: Option<String>,
+    pub limit: Option<i64>,
+    /// Cursor for pagination
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cursor: Option<String>,
 }
 
+impl crate::xrpc::JsonBody for Input {}
+
 /// Response output
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Output {
-    pub actions: serde_json::Value,
+    pub actions: Vec<serde_json::Value>,
     /// Cursor for next page of results
     #[serde(skip_serializing_if = "Option::is_none")]
     pub cursor: Option<String>,
 }
 
+impl crate::xrpc::JsonBody for Output {}
+
 /// List scheduled moderation actions with optional filtering
 pub async fn list_scheduled_actions(
     client: &impl XrpcClient,
@@ -45,5 +49,18 @@ pub async fn list_scheduled_actions(
 ) -> Result<XrpcResponse<Output>, XrpcError> {
     let req = XrpcRequest::procedure("tools.ozone.moderation.listScheduledActions").data(&input)?;
 
-    client.request(req).await
+    client.request_body(req).await
+}
+
+/// Server-side definition of tools.ozone.moderation.listScheduledActions
+pub struct Endpoint;
+
+impl crate::xrpc::XrpcEndpoint for Endpoint {
+    const NSID: &'static str = "tools.ozone.moderation.listScheduledActions";
+    const METHOD: crate::xrpc::XrpcMethod = crate::xrpc::XrpcMethod::Procedure;
+    const INPUT_ENCODING: Option<&'static str> = Some("application/json");
+    const OUTPUT_ENCODING: Option<&'static str> = Some("application/json");
+    type Params = ();
+    type Input = Input;
+    type Output = Output;
 }
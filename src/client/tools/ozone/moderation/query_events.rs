@@ -8,6 +8,12 @@ use serde::{Deserialize, Serialize};
 /// Query parameters
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct QueryParams {
+    /// The types of events (fully qualified string in the format of tools.ozone.moderation.defs#modEvent<name>) to filter by. If not specified, all events are returned.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub types: Option<Vec<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(rename = "createdBy")]
+    pub created_by: Option<crate::types::Did>,
     /// Sort direction for the events. Defaults to descending order of created at timestamp.
     #[serde(skip_serializing_if = "Option::is_none")]
     #[serde(rename = "sortDirection")]
@@ -15,29 +21,16 @@ pub struct QueryParams {
     /// Retrieve events created after a given timestamp
     #[serde(skip_serializing_if = "Option::is_none")]
     #[serde(rename = "createdAfter")]
-    pub created_after: Option<String>,
-    /// The types of events (fully qualified string in the format of tools.ozone.moderation.defs#modEvent<name>) to filter by. If not specified, all events are returned.
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub types: Option<serde_json::Value>,
-    /// If specified, only events where all of these tags were added are returned
-    #[serde(skip_serializing_if = "Option::is_none")]
-    #[serde(rename = "addedTags")]
-    pub added_tags: Option<serde_json::Value>,
-    /// If specified, only events where all of these tags were removed are returned
-    #[serde(skip_serializing_if = "Option::is_none")]
-    #[serde(rename = "removedTags")]
-    pub removed_tags: Option<serde_json::Value>,
-    #[serde(skip_serializing_if = "Option::is_none")]
-    #[serde(rename = "reportTypes")]
-    pub report_types: Option<serde_json::Value>,
+    pub created_after: Option<crate::types::Datetime>,
+    /// Retrieve events created before a given timestamp
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub policies: Option<serde_json::Value>,
+    #[serde(rename = "createdBefore")]
+    pub created_before: Option<crate::types::Datetime>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub limit: Option<i64>,
-    /// If specified, only events where the modTool name matches any of the given values are returned
+    pub subject: Option<crate::types::Uri>,
+    /// If specified, only events where the subject belongs to the given collections will be returned. When subjectType is set to 'account', this will be ignored.
     #[serde(skip_serializing_if = "Option::is_none")]
-    #[serde(rename = "modTool")]
-    pub mod_tool: Option<serde_json::Value>,
+    pub collections: Option<Vec<crate::types::Nsid>>,
     /// If specified, only events where the subject is of the given type (account or record) will be returned. When this is set to 'account' the 'collections' parameter will be ignored. When includeAllUserRecords or subject is set, this will be ignored.
     #[serde(skip_serializing_if = "Option::is_none")]
     #[serde(rename = "subjectType")]
@@ -46,43 +39,50 @@ pub struct QueryParams {
     #[serde(skip_serializing_if = "Option::is_none")]
     #[serde(rename = "includeAllUserRecords")]
     pub include_all_user_records: Option<bool>,
-    /// If specified, only events where the batchId matches the given value are returned
     #[serde(skip_serializing_if = "Option::is_none")]
-    #[serde(rename = "batchId")]
-    pub batch_id: Option<String>,
-    /// If specified, only events where the age assurance state matches the given value are returned
+    pub limit: Option<i64>,
+    /// If true, only events with comments are returned
     #[serde(skip_serializing_if = "Option::is_none")]
-    #[serde(rename = "ageAssuranceState")]
-    pub age_assurance_state: Option<String>,
+    #[serde(rename = "hasComment")]
+    pub has_comment: Option<bool>,
+    /// If specified, only events with comments containing the keyword are returned. Apply || separator to use multiple keywords and match using OR condition.
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub cursor: Option<String>,
+    pub comment: Option<String>,
+    /// If specified, only events where all of these labels were added are returned
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(rename = "addedLabels")]
+    pub added_labels: Option<Vec<String>>,
     /// If specified, only events where all of these labels were removed are returned
     #[serde(skip_serializing_if = "Option::is_none")]
     #[serde(rename = "removedLabels")]
-    pub removed_labels: Option<serde_json::Value>,
-    /// If specified, only events where all of these labels were added are returned
+    pub removed_labels: Option<Vec<String>>,
+    /// If specified, only events where all of these tags were added are returned
     #[serde(skip_serializing_if = "Option::is_none")]
-    #[serde(rename = "addedLabels")]
-    pub added_labels: Option<serde_json::Value>,
+    #[serde(rename = "addedTags")]
+    pub added_tags: Option<Vec<String>>,
+    /// If specified, only events where all of these tags were removed are returned
     #[serde(skip_serializing_if = "Option::is_none")]
-    #[serde(rename = "createdBy")]
-    pub created_by: Option<crate::types::Did>,
-    /// If specified, only events where the subject belongs to the given collections will be returned. When subjectType is set to 'account', this will be ignored.
+    #[serde(rename = "removedTags")]
+    pub removed_tags: Option<Vec<String>>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub collections: Option<serde_json::Value>,
-    /// If specified, only events with comments containing the keyword are returned. Apply || separator to use multiple keywords and match using OR condition.
+    #[serde(rename = "reportTypes")]
+    pub report_types: Option<Vec<String>>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub comment: Option<String>,
-    /// If true, only events with comments are returned
+    pub policies: Option<Vec<String>>,
+    /// If specified, only events where the modTool name matches any of the given values are returned
     #[serde(skip_serializing_if = "Option::is_none")]
-    #[serde(rename = "hasComment")]
-    pub has_comment: Option<bool>,
+    #[serde(rename = "modTool")]
+    pub mod_tool: Option<Vec<String>>,
+    /// If specified, only events where the batchId matches the given value are returned
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub subject: Option<String>,
-    /// Retrieve events created before a given timestamp
+    #[serde(rename = "batchId")]
+    pub batch_id: Option<String>,
+    /// If specified, only events where the age assurance state matches the given value are returned
     #[serde(skip_serializing_if = "Option::is_none")]
-    #[serde(rename = "createdBefore")]
-    pub created_before: Option<String>,
+    #[serde(rename = "ageAssuranceState")]
+    pub age_assurance_state: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cursor: Option<String>,
 }
 
 /// Response output
@@ -90,9 +90,11 @@ pub struct QueryParams {
 pub struct Output {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub cursor: Option<String>,
-    pub events: serde_json::Value,
+    pub events: Vec<serde_json::Value>,
 }
 
+impl crate::xrpc::JsonBody for Output {}
+
 /// List moderation events related to a subject.
 pub async fn query_events(
     client: &impl XrpcClient,
@@ -101,18 +103,85 @@ pub async fn query_events(
     let mut req = XrpcRequest::query("tools.ozone.moderation.queryEvents");
 
     // Add query parameters
-    let params_json = serde_json::to_value(&params)
-        .map_err(XrpcError::Serialization)?;
-
-    if let Some(obj) = params_json.as_object() {
-        for (key, value) in obj {
-            if let Some(s) = value.as_str() {
-                req.params.insert(key.clone(), s.to_string());
-            } else {
-                req.params.insert(key.clone(), value.to_string());
-            }
-        }
+    for value in params.types.iter().flatten() {
+        req.params.append("types", value.to_string());
+    }
+    if let Some(value) = &params.created_by {
+        req.params.append("createdBy", value.to_string());
+    }
+    if let Some(value) = &params.sort_direction {
+        req.params.append("sortDirection", value.to_string());
     }
+    if let Some(value) = &params.created_after {
+        req.params.append("createdAfter", value.to_string());
+    }
+    if let Some(value) = &params.created_before {
+        req.params.append("createdBefore", value.to_string());
+    }
+    if let Some(value) = &params.subject {
+        req.params.append("subject", value.to_string());
+    }
+    for value in params.collections.iter().flatten() {
+        req.params.append("collections", value.to_string());
+    }
+    if let Some(value) = &params.subject_type {
+        req.params.append("subjectType", value.to_string());
+    }
+    if let Some(value) = &params.include_all_user_records {
+        req.params.append("includeAllUserRecords", value.to_string());
+    }
+    if let Some(value) = &params.limit {
+        req.params.append("limit", value.to_string());
+    }
+    if let Some(value) = &params.has_comment {
+        req.params.append("hasComment", value.to_string());
+    }
+    if let Some(value) = &params.comment {
+        req.params.append("comment", value.to_string());
+    }
+    for value in params.added_labels.iter().flatten() {
+        req.params.append("addedLabels", value.to_string());
+    }
+    for value in params.removed_labels.iter().flatten() {
+        req.params.append("removedLabels", value.to_string());
+    }
+    for value in params.added_tags.iter().flatten() {
+        req.params.append("addedTags", value.to_string());
+    }
+    for value in params.removed_tags.iter().flatten() {
+        req.params.append("removedTags", value.to_string());
+    }
+    for value in params.report_types.iter().flatten() {
+        req.params.append("reportTypes", value.to_string());
+    }
+    for value in params.policies.iter().flatten() {
+        req.params.append("policies", value.to_string());
+    }
+    for value in params.mod_tool.iter().flatten() {
+        req.params.append("modTool", value.to_string());
+    }
+    if let Some(value) = &params.batch_id {
+        req.params.append("batchId", value.to_string());
+    }
+    if let Some(value) = &params.age_assurance_state {
+        req.params.append("ageAssuranceState", value.to_string());
+    }
+    if let Some(value) = &params.cursor {
+        req.params.append("cursor", value.to_string());
+    }
+
+    client.request_body(req).await
+}
+
+/// Server-side definition of tools.ozone.moderation.queryEvents
+pub struct Endpoint;
 
-    client.request(req).await
+impl crate::xrpc::XrpcEndpoint for Endpoint {
+    const NSID: &'static str = "tools.ozone.moderation.queryEvents";
+    const METHOD: crate::xrpc::XrpcMethod = crate::xrpc::XrpcMethod::Query;
+    const INPUT_ENCODING: Option<&'static str> = None;
+    const OUTPUT_ENCODING: Option<&'static str> = Some("application/json");
+    type Params = QueryParams;
+    type Input = ();
+    type Output = Output;
 }
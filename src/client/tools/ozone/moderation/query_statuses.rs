@@ -8,142 +8,144 @@ use serde::{Deserialize, Serialize};
 /// Query parameters
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct QueryParams {
-    /// Search subjects where the associated record/account was updated before a given timestamp
-    #[serde(skip_serializing_if = "Option::is_none")]
-    #[serde(rename = "hostingUpdatedBefore")]
-    pub hosting_updated_before: Option<String>,
-    #[serde(skip_serializing_if = "Option::is_none")]
-    #[serde(rename = "sortField")]
-    pub sort_field: Option<String>,
-    /// If specified, only subjects that belong to an account that has at least this many reported records will be returned.
-    #[serde(skip_serializing_if = "Option::is_none")]
-    #[serde(rename = "minReportedRecordsCount")]
-    pub min_reported_records_count: Option<i64>,
-    /// Search subjects reported before a given timestamp
-    #[serde(skip_serializing_if = "Option::is_none")]
-    #[serde(rename = "reportedBefore")]
-    pub reported_before: Option<String>,
-    /// Get subjects that were taken down
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub takendown: Option<bool>,
-    /// All subjects, or subjects from given 'collections' param, belonging to the account specified in the 'subject' param will be returned.
+    /// Number of queues being used by moderators. Subjects will be split among all queues.
     #[serde(skip_serializing_if = "Option::is_none")]
-    #[serde(rename = "includeAllUserRecords")]
-    pub include_all_user_records: Option<bool>,
-    /// If specified, only subjects that belong to an account that has at least this many suspensions will be returned.
+    #[serde(rename = "queueCount")]
+    pub queue_count: Option<i64>,
+    /// Index of the queue to fetch subjects from. Works only when queueCount value is specified.
     #[serde(skip_serializing_if = "Option::is_none")]
-    #[serde(rename = "minAccountSuspendCount")]
-    pub min_account_suspend_count: Option<i64>,
+    #[serde(rename = "queueIndex")]
+    pub queue_index: Option<i64>,
     /// A seeder to shuffle/balance the queue items.
     #[serde(skip_serializing_if = "Option::is_none")]
     #[serde(rename = "queueSeed")]
     pub queue_seed: Option<String>,
-    /// Index of the queue to fetch subjects from. Works only when queueCount value is specified.
+    /// All subjects, or subjects from given 'collections' param, belonging to the account specified in the 'subject' param will be returned.
     #[serde(skip_serializing_if = "Option::is_none")]
-    #[serde(rename = "queueIndex")]
-    pub queue_index: Option<i64>,
+    #[serde(rename = "includeAllUserRecords")]
+    pub include_all_user_records: Option<bool>,
     /// The subject to get the status for.
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub subject: Option<String>,
-    /// By default, we don't include muted subjects in the results. Set this to true to include them.
-    #[serde(skip_serializing_if = "Option::is_none")]
-    #[serde(rename = "includeMuted")]
-    pub include_muted: Option<bool>,
-    /// If specified, subjects of the given type (account or record) will be returned. When this is set to 'account' the 'collections' parameter will be ignored. When includeAllUserRecords or subject is set, this will be ignored.
+    pub subject: Option<crate::types::Uri>,
+    /// Search subjects by keyword from comments
     #[serde(skip_serializing_if = "Option::is_none")]
-    #[serde(rename = "subjectType")]
-    pub subject_type: Option<String>,
-    /// If specified, only subjects that belong to an account that has at least this many taken down records will be returned.
+    pub comment: Option<String>,
+    /// Search subjects reported after a given timestamp
     #[serde(skip_serializing_if = "Option::is_none")]
-    #[serde(rename = "minTakendownRecordsCount")]
-    pub min_takendown_records_count: Option<i64>,
-    /// Number of queues being used by moderators. Subjects will be split among all queues.
+    #[serde(rename = "reportedAfter")]
+    pub reported_after: Option<crate::types::Datetime>,
+    /// Search subjects reported before a given timestamp
     #[serde(skip_serializing_if = "Option::is_none")]
-    #[serde(rename = "queueCount")]
-    pub queue_count: Option<i64>,
-    /// Get subjects in unresolved appealed status
+    #[serde(rename = "reportedBefore")]
+    pub reported_before: Option<crate::types::Datetime>,
+    /// Search subjects reviewed after a given timestamp
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub appealed: Option<bool>,
+    #[serde(rename = "reviewedAfter")]
+    pub reviewed_after: Option<crate::types::Datetime>,
     /// Search subjects where the associated record/account was deleted after a given timestamp
     #[serde(skip_serializing_if = "Option::is_none")]
     #[serde(rename = "hostingDeletedAfter")]
-    pub hosting_deleted_after: Option<String>,
-    /// Search subjects by keyword from comments
+    pub hosting_deleted_after: Option<crate::types::Datetime>,
+    /// Search subjects where the associated record/account was deleted before a given timestamp
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub comment: Option<String>,
-    /// If specified, only subjects that have priority score value above the given value will be returned.
+    #[serde(rename = "hostingDeletedBefore")]
+    pub hosting_deleted_before: Option<crate::types::Datetime>,
+    /// Search subjects where the associated record/account was updated after a given timestamp
     #[serde(skip_serializing_if = "Option::is_none")]
-    #[serde(rename = "minPriorityScore")]
-    pub min_priority_score: Option<i64>,
+    #[serde(rename = "hostingUpdatedAfter")]
+    pub hosting_updated_after: Option<crate::types::Datetime>,
+    /// Search subjects where the associated record/account was updated before a given timestamp
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub cursor: Option<String>,
-    /// Get all subject statuses that were reviewed by a specific moderator
+    #[serde(rename = "hostingUpdatedBefore")]
+    pub hosting_updated_before: Option<crate::types::Datetime>,
+    /// Search subjects by the status of the associated record/account
     #[serde(skip_serializing_if = "Option::is_none")]
-    #[serde(rename = "lastReviewedBy")]
-    pub last_reviewed_by: Option<crate::types::Did>,
-    /// Search subjects where the associated record/account was updated after a given timestamp
+    #[serde(rename = "hostingStatuses")]
+    pub hosting_statuses: Option<Vec<String>>,
+    /// Search subjects reviewed before a given timestamp
     #[serde(skip_serializing_if = "Option::is_none")]
-    #[serde(rename = "hostingUpdatedAfter")]
-    pub hosting_updated_after: Option<String>,
+    #[serde(rename = "reviewedBefore")]
+    pub reviewed_before: Option<crate::types::Datetime>,
+    /// By default, we don't include muted subjects in the results. Set this to true to include them.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(rename = "includeMuted")]
+    pub include_muted: Option<bool>,
     /// When set to true, only muted subjects and reporters will be returned.
     #[serde(skip_serializing_if = "Option::is_none")]
     #[serde(rename = "onlyMuted")]
     pub only_muted: Option<bool>,
-    /// Search subjects reported after a given timestamp
-    #[serde(skip_serializing_if = "Option::is_none")]
-    #[serde(rename = "reportedAfter")]
-    pub reported_after: Option<String>,
     /// Specify when fetching subjects in a certain state
     #[serde(skip_serializing_if = "Option::is_none")]
     #[serde(rename = "reviewState")]
     pub review_state: Option<String>,
-    /// Search subjects reviewed after a given timestamp
     #[serde(skip_serializing_if = "Option::is_none")]
-    #[serde(rename = "reviewedAfter")]
-    pub reviewed_after: Option<String>,
+    #[serde(rename = "ignoreSubjects")]
+    pub ignore_subjects: Option<Vec<crate::types::Uri>>,
+    /// Get all subject statuses that were reviewed by a specific moderator
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub tags: Option<serde_json::Value>,
-    /// If specified, subjects belonging to the given collections will be returned. When subjectType is set to 'account', this will be ignored.
+    #[serde(rename = "lastReviewedBy")]
+    pub last_reviewed_by: Option<crate::types::Did>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub collections: Option<serde_json::Value>,
-    /// If specified, only subjects with the given age assurance state will be returned.
+    #[serde(rename = "sortField")]
+    pub sort_field: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    #[serde(rename = "ageAssuranceState")]
-    pub age_assurance_state: Option<String>,
+    #[serde(rename = "sortDirection")]
+    pub sort_direction: Option<String>,
+    /// Get subjects that were taken down
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub takendown: Option<bool>,
+    /// Get subjects in unresolved appealed status
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub appealed: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tags: Option<Vec<String>>,
     #[serde(skip_serializing_if = "Option::is_none")]
     #[serde(rename = "excludeTags")]
-    pub exclude_tags: Option<serde_json::Value>,
-    /// Search subjects by the status of the associated record/account
+    pub exclude_tags: Option<Vec<String>>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    #[serde(rename = "hostingStatuses")]
-    pub hosting_statuses: Option<serde_json::Value>,
-    /// Search subjects where the associated record/account was deleted before a given timestamp
+    pub cursor: Option<String>,
+    /// If specified, subjects belonging to the given collections will be returned. When subjectType is set to 'account', this will be ignored.
     #[serde(skip_serializing_if = "Option::is_none")]
-    #[serde(rename = "hostingDeletedBefore")]
-    pub hosting_deleted_before: Option<String>,
-    /// Search subjects reviewed before a given timestamp
+    pub collections: Option<Vec<crate::types::Nsid>>,
+    /// If specified, subjects of the given type (account or record) will be returned. When this is set to 'account' the 'collections' parameter will be ignored. When includeAllUserRecords or subject is set, this will be ignored.
     #[serde(skip_serializing_if = "Option::is_none")]
-    #[serde(rename = "reviewedBefore")]
-    pub reviewed_before: Option<String>,
+    #[serde(rename = "subjectType")]
+    pub subject_type: Option<String>,
+    /// If specified, only subjects that belong to an account that has at least this many suspensions will be returned.
     #[serde(skip_serializing_if = "Option::is_none")]
-    #[serde(rename = "ignoreSubjects")]
-    pub ignore_subjects: Option<serde_json::Value>,
+    #[serde(rename = "minAccountSuspendCount")]
+    pub min_account_suspend_count: Option<i64>,
+    /// If specified, only subjects that belong to an account that has at least this many reported records will be returned.
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub limit: Option<i64>,
+    #[serde(rename = "minReportedRecordsCount")]
+    pub min_reported_records_count: Option<i64>,
+    /// If specified, only subjects that belong to an account that has at least this many taken down records will be returned.
     #[serde(skip_serializing_if = "Option::is_none")]
-    #[serde(rename = "sortDirection")]
-    pub sort_direction: Option<String>,
+    #[serde(rename = "minTakendownRecordsCount")]
+    pub min_takendown_records_count: Option<i64>,
+    /// If specified, only subjects that have priority score value above the given value will be returned.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(rename = "minPriorityScore")]
+    pub min_priority_score: Option<i64>,
+    /// If specified, only subjects with the given age assurance state will be returned.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(rename = "ageAssuranceState")]
+    pub age_assurance_state: Option<String>,
 }
 
 /// Response output
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Output {
-    #[serde(rename = "subjectStatuses")]
-    pub subject_statuses: serde_json::Value,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub cursor: Option<String>,
+    #[serde(rename = "subjectStatuses")]
+    pub subject_statuses: Vec<serde_json::Value>,
 }
 
+impl crate::xrpc::JsonBody for Output {}
+
 /// View moderation statuses of subjects (record or repo).
 pub async fn query_statuses(
     client: &impl XrpcClient,
@@ -152,18 +154,124 @@ pub async fn query_statuses(
     let mut req = XrpcRequest::query("tools.ozone.moderation.queryStatuses");
 
     // Add query parameters
-    let params_json = serde_json::to_value(&params)
-        .map_err(XrpcError::Serialization)?;
-
-    if let Some(obj) = params_json.as_object() {
-        for (key, value) in obj {
-            if let Some(s) = value.as_str() {
-                req.params.insert(key.clone(), s.to_string());
-            } else {
-                req.params.insert(key.clone(), value.to_string());
-            }
-        }
+    if let Some(value) = &params.queue_count {
+        req.params.append("queueCount", value.to_string());
+    }
+    if let Some(value) = &params.queue_index {
+        req.params.append("queueIndex", value.to_string());
+    }
+    if let Some(value) = &params.queue_seed {
+        req.params.append("queueSeed", value.to_string());
+    }
+    if let Some(value) = &params.include_all_user_records {
+        req.params.append("includeAllUserRecords", value.to_string());
+    }
+    if let Some(value) = &params.subject {
+        req.params.append("subject", value.to_string());
+    }
+    if let Some(value) = &params.comment {
+        req.params.append("comment", value.to_string());
+    }
+    if let Some(value) = &params.reported_after {
+        req.params.append("reportedAfter", value.to_string());
+    }
+    if let Some(value) = &params.reported_before {
+        req.params.append("reportedBefore", value.to_string());
+    }
+    if let Some(value) = &params.reviewed_after {
+        req.params.append("reviewedAfter", value.to_string());
+    }
+    if let Some(value) = &params.hosting_deleted_after {
+        req.params.append("hostingDeletedAfter", value.to_string());
+    }
+    if let Some(value) = &params.hosting_deleted_before {
+        req.params.append("hostingDeletedBefore", value.to_string());
+    }
+    if let Some(value) = &params.hosting_updated_after {
+        req.params.append("hostingUpdatedAfter", value.to_string());
+    }
+    if let Some(value) = &params.hosting_updated_before {
+        req.params.append("hostingUpdatedBefore", value.to_string());
+    }
+    for value in params.hosting_statuses.iter().flatten() {
+        req.params.append("hostingStatuses", value.to_string());
+    }
+    if let Some(value) = &params.reviewed_before {
+        req.params.append("reviewedBefore", value.to_string());
+    }
+    if let Some(value) = &params.include_muted {
+        req.params.append("includeMuted", value.to_string());
     }
+    if let Some(value) = &params.only_muted {
+        req.params.append("onlyMuted", value.to_string());
+    }
+    if let Some(value) = &params.review_state {
+        req.params.append("reviewState", value.to_string());
+    }
+    for value in params.ignore_subjects.iter().flatten() {
+        req.params.append("ignoreSubjects", value.to_string());
+    }
+    if let Some(value) = &params.last_reviewed_by {
+        req.params.append("lastReviewedBy", value.to_string());
+    }
+    if let Some(value) = &params.sort_field {
+        req.params.append("sortField", value.to_string());
+    }
+    if let Some(value) = &params.sort_direction {
+        req.params.append("sortDirection", value.to_string());
+    }
+    if let Some(value) = &params.takendown {
+        req.params.append("takendown", value.to_string());
+    }
+    if let Some(value) = &params.appealed {
+        req.params.append("appealed", value.to_string());
+    }
+    if let Some(value) = &params.limit {
+        req.params.append("limit", value.to_string());
+    }
+    for value in params.tags.iter().flatten() {
+        req.params.append("tags", value.to_string());
+    }
+    for value in params.exclude_tags.iter().flatten() {
+        req.params.append("excludeTags", value.to_string());
+    }
+    if let Some(value) = &params.cursor {
+        req.params.append("cursor", value.to_string());
+    }
+    for value in params.collections.iter().flatten() {
+        req.params.append("collections", value.to_string());
+    }
+    if let Some(value) = &params.subject_type {
+        req.params.append("subjectType", value.to_string());
+    }
+    if let Some(value) = &params.min_account_suspend_count {
+        req.params.append("minAccountSuspendCount", value.to_string());
+    }
+    if let Some(value) = &params.min_reported_records_count {
+        req.params.append("minReportedRecordsCount", value.to_string());
+    }
+    if let Some(value) = &params.min_takendown_records_count {
+        req.params.append("minTakendownRecordsCount", value.to_string());
+    }
+    if let Some(value) = &params.min_priority_score {
+        req.params.append("minPriorityScore", value.to_string());
+    }
+    if let Some(value) = &params.age_assurance_state {
+        req.params.append("ageAssuranceState", value.to_string());
+    }
+
+    client.request_body(req).await
+}
+
+/// Server-side definition of tools.ozone.moderation.queryStatuses
+pub struct Endpoint;
 
-    client.request(req).await
+impl crate::xrpc::XrpcEndpoint for Endpoint {
+    const NSID: &'static str = "tools.ozone.moderation.queryStatuses";
+    const METHOD: crate::xrpc::XrpcMethod = crate::xrpc::XrpcMethod::Query;
+    const INPUT_ENCODING: Option<&'static str> = None;
+    const OUTPUT_ENCODING: Option<&'static str> = Some("application/json");
+    type Params = QueryParams;
+    type Input = ();
+    type Output = Output;
 }
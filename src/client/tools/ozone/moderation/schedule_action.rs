@@ -8,16 +8,38 @@ use serde::{Deserialize, Serialize};
 /// Request input
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Input {
-    pub scheduling: serde_json::Value,
+    pub action: InputAction,
+    /// Array of DID subjects to schedule the action for
+    pub subjects: Vec<crate::types::Did>,
     #[serde(rename = "createdBy")]
     pub created_by: crate::types::Did,
-    /// Array of DID subjects to schedule the action for
-    pub subjects: serde_json::Value,
+    pub scheduling: serde_json::Value,
     /// This will be propagated to the moderation event when it is applied
     #[serde(skip_serializing_if = "Option::is_none")]
     #[serde(rename = "modTool")]
     pub mod_tool: Option<serde_json::Value>,
-    pub action: serde_json::Value,
+}
+
+impl crate::xrpc::JsonBody for Input {}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(tag = "$type")]
+pub enum InputAction {
+    #[serde(rename = "tools.ozone.moderation.scheduleAction#takedown")]
+    Takedown(Box<Takedown>),
+    /// A type not listed in the lexicon, kept as raw JSON
+    #[serde(untagged)]
+    Unknown(serde_json::Value),
+}
+
+impl<'de> Deserialize<'de> for InputAction {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> std::result::Result<Self, D::Error> {
+        let value = serde_json::Value::deserialize(deserializer)?;
+        match value.get("$type").and_then(|t| t.as_str()) {
+            Some("tools.ozone.moderation.scheduleAction#takedown") => serde_json::from_value(value).map(Self::Takedown).map_err(serde::de::Error::custom),
+            _ => Ok(Self::Unknown(value)),
+        }
+    }
 }
 
 /// Response output
@@ -25,6 +47,65 @@ pub struct Input {
 pub struct Output {
 }
 
+impl crate::xrpc::JsonBody for Output {}
+
+/// Schedule a takedown action
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Takedown {
+    /// Schedule a takedown action
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub comment: Option<String>,
+    /// Indicates how long the takedown should be in effect before automatically expiring.
+    /// Schedule a takedown action
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(rename = "durationInHours")]
+    pub duration_in_hours: Option<i64>,
+    /// If true, all other reports on content authored by this account will be resolved (acknowledged).
+    /// Schedule a takedown action
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(rename = "acknowledgeAccountSubjects")]
+    pub acknowledge_account_subjects: Option<bool>,
+    /// Names/Keywords of the policies that drove the decision.
+    /// Schedule a takedown action
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub policies: Option<Vec<String>>,
+}
+
+/// Configuration for when the action should be executed
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SchedulingConfig {
+    /// Exact time to execute the action
+    /// Configuration for when the action should be executed
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(rename = "executeAt")]
+    pub execute_at: Option<crate::types::Datetime>,
+    /// Earliest time to execute the action (for randomized scheduling)
+    /// Configuration for when the action should be executed
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(rename = "executeAfter")]
+    pub execute_after: Option<crate::types::Datetime>,
+    /// Latest time to execute the action (for randomized scheduling)
+    /// Configuration for when the action should be executed
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(rename = "executeUntil")]
+    pub execute_until: Option<crate::types::Datetime>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ScheduledActionResults {
+    pub succeeded: Vec<crate::types::Did>,
+    pub failed: Vec<serde_json::Value>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FailedScheduling {
+    pub subject: crate::types::Did,
+    pub error: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(rename = "errorCode")]
+    pub error_code: Option<String>,
+}
+
 /// Schedule a moderation action to be executed at a future time
 pub async fn schedule_action(
     client: &impl XrpcClient,
@@ -32,5 +113,18 @@ pub async fn schedule_action(
 ) -> Result<XrpcResponse<Output>, XrpcError> {
     let req = XrpcRequest::procedure("tools.ozone.moderation.scheduleAction").data(&input)?;
 
-    client.request(req).await
+    client.request_body(req).await
+}
+
+/// Server-side definition of tools.ozone.moderation.scheduleAction
+pub struct Endpoint;
+
+impl crate::xrpc::XrpcEndpoint for Endpoint {
+    const NSID: &'static str = "tools.ozone.moderation.scheduleAction";
+    const METHOD: crate::xrpc::XrpcMethod = crate::xrpc::XrpcMethod::Procedure;
+    const INPUT_ENCODING: Option<&'static str> = Some("application/json");
+    const OUTPUT_ENCODING: Option<&'static str> = Some("application/json");
+    type Params = ();
+    type Input = Input;
+    type Output = Output;
 }
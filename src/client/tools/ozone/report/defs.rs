@@ -2,165 +2,165 @@
 
 use serde::{Deserialize, Serialize};
 
-/// Other misleading content
-#[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct ReasonMisleadingOther;
+pub type ReasonType = String;
 
-/// Animal welfare violations
+/// Appeal a previously taken moderation action
 #[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct ReasonViolenceAnimal;
+pub struct ReasonAppeal;
 
-/// False information about elections
+/// An issue not included in these options
 #[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct ReasonMisleadingElections;
+pub struct ReasonOther;
 
-/// Privacy violation involving a minor
+/// Animal welfare violations
 #[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct ReasonChildSafetyPrivacy;
+pub struct ReasonViolenceAnimal;
 
-/// Other harassing or hateful content
+/// Threats or incitement
 #[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct ReasonHarassmentOther;
+pub struct ReasonViolenceThreats;
 
-/// Non-consensual intimate imagery
+/// Graphic violent content
 #[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct ReasonSexualNcii;
+pub struct ReasonViolenceGraphicContent;
 
-/// Promoting or selling prohibited items or services
+/// Glorification of violence
 #[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct ReasonRuleProhibitedSales;
+pub struct ReasonViolenceGlorification;
 
-/// Hate speech
+/// Extremist content. These reports will be sent only be sent to the application's Moderation Authority.
 #[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct ReasonHarassmentHateSpeech;
+pub struct ReasonViolenceExtremistContent;
 
 /// Human trafficking
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ReasonViolenceTrafficking;
 
-/// Unlabelled adult content
-#[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct ReasonSexualUnlabeled;
-
-/// Other sexual violence content
-#[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct ReasonSexualOther;
-
-pub type ReasonType = String;
-
 /// Other violent content
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ReasonViolenceOther;
 
-/// Graphic violent content
+/// Adult sexual abuse content
 #[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct ReasonViolenceGraphicContent;
+pub struct ReasonSexualAbuseContent;
 
-/// Scam
+/// Non-consensual intimate imagery
 #[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct ReasonMisleadingScam;
+pub struct ReasonSexualNcii;
 
-/// Hacking or system attacks
+/// Deepfake adult content
 #[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct ReasonRuleSiteSecurity;
+pub struct ReasonSexualDeepfake;
 
-/// Grooming or predatory behavior. These reports will be sent only be sent to the application's Moderation Authority.
+/// Animal sexual abuse
 #[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct ReasonChildSafetyGroom;
+pub struct ReasonSexualAnimal;
 
-/// Deepfake adult content
+/// Unlabelled adult content
 #[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct ReasonSexualDeepfake;
+pub struct ReasonSexualUnlabeled;
 
-/// Other
+/// Other sexual violence content
 #[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct ReasonRuleOther;
+pub struct ReasonSexualOther;
 
-/// Glorification of violence
+/// Child sexual abuse material (CSAM). These reports will be sent only be sent to the application's Moderation Authority.
 #[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct ReasonViolenceGlorification;
+pub struct ReasonChildSafetyCsam;
 
-/// Adult sexual abuse content
+/// Grooming or predatory behavior. These reports will be sent only be sent to the application's Moderation Authority.
 #[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct ReasonSexualAbuseContent;
+pub struct ReasonChildSafetyGroom;
 
-/// Dangerous substances or drug abuse
+/// Privacy violation involving a minor
 #[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct ReasonSelfHarmSubstances;
+pub struct ReasonChildSafetyPrivacy;
 
-/// Other dangerous content
+/// Harassment or bullying of minors
 #[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct ReasonSelfHarmOther;
+pub struct ReasonChildSafetyHarassment;
 
 /// Other child safety. These reports will be sent only be sent to the application's Moderation Authority.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ReasonChildSafetyOther;
 
-/// Animal sexual abuse
-#[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct ReasonSexualAnimal;
-
-/// Child sexual abuse material (CSAM). These reports will be sent only be sent to the application's Moderation Authority.
+/// Trolling
 #[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct ReasonChildSafetyCsam;
+pub struct ReasonHarassmentTroll;
 
-/// Harassment or bullying of minors
+/// Targeted harassment
 #[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct ReasonChildSafetyHarassment;
+pub struct ReasonHarassmentTargeted;
 
-/// Threats or incitement
+/// Hate speech
 #[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct ReasonViolenceThreats;
+pub struct ReasonHarassmentHateSpeech;
 
 /// Doxxing
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ReasonHarassmentDoxxing;
 
-/// Extremist content. These reports will be sent only be sent to the application's Moderation Authority.
+/// Other harassing or hateful content
 #[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct ReasonViolenceExtremistContent;
+pub struct ReasonHarassmentOther;
 
 /// Fake account or bot
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ReasonMisleadingBot;
 
-/// Trolling
+/// Impersonation
 #[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct ReasonHarassmentTroll;
+pub struct ReasonMisleadingImpersonation;
 
-/// An issue not included in these options
+/// Spam
 #[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct ReasonOther;
+pub struct ReasonMisleadingSpam;
 
-/// Dangerous challenges or activities
+/// Scam
 #[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct ReasonSelfHarmStunts;
+pub struct ReasonMisleadingScam;
 
-/// Targeted harassment
+/// False information about elections
 #[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct ReasonHarassmentTargeted;
+pub struct ReasonMisleadingElections;
 
-/// Eating disorders
+/// Other misleading content
 #[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct ReasonSelfHarmEd;
+pub struct ReasonMisleadingOther;
 
-/// Appeal a previously taken moderation action
+/// Hacking or system attacks
 #[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct ReasonAppeal;
+pub struct ReasonRuleSiteSecurity;
 
-/// Banned user returning
+/// Promoting or selling prohibited items or services
 #[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct ReasonRuleBanEvasion;
+pub struct ReasonRuleProhibitedSales;
 
-/// Spam
+/// Banned user returning
 #[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct ReasonMisleadingSpam;
+pub struct ReasonRuleBanEvasion;
 
-/// Impersonation
+/// Other
 #[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct ReasonMisleadingImpersonation;
+pub struct ReasonRuleOther;
 
 /// Content promoting or depicting self-harm
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ReasonSelfHarmContent;
 
+/// Eating disorders
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ReasonSelfHarmEd;
+
+/// Dangerous challenges or activities
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ReasonSelfHarmStunts;
+
+/// Dangerous substances or drug abuse
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ReasonSelfHarmSubstances;
+
+/// Other dangerous content
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ReasonSelfHarmOther;
+
@@ -8,12 +8,15 @@ use serde::{Deserialize, Serialize};
 /// Request input
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Input {
+    /// Cursor for pagination
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cursor: Option<String>,
     /// Maximum number of results to return
     #[serde(skip_serializing_if = "Option::is_none")]
     pub limit: Option<i64>,
     /// Filter by specific URLs or domains
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub urls: Option<serde_json::Value>,
+    pub urls: Option<Vec<String>>,
     /// Filter by pattern type
     #[serde(skip_serializing_if = "Option::is_none")]
     #[serde(rename = "patternType")]
@@ -22,20 +25,21 @@ pub struct Input {
     #[serde(skip_serializing_if = "Option::is_none")]
     #[serde(rename = "sortDirection")]
     pub sort_direction: Option<String>,
-    /// Cursor for pagination
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub cursor: Option<String>,
 }
 
+impl crate::xrpc::JsonBody for Input {}
+
 /// Response output
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Output {
-    pub events: serde_json::Value,
     /// Next cursor for pagination. Only present if there are more results.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub cursor: Option<String>,
+    pub events: Vec<serde_json::Value>,
 }
 
+impl crate::xrpc::JsonBody for Output {}
+
 /// Query URL safety audit events
 pub async fn query_events(
     client: &impl XrpcClient,
@@ -43,5 +47,18 @@ pub async fn query_events(
 ) -> Result<XrpcResponse<Output>, XrpcError> {
     let req = XrpcRequest::procedure("tools.ozone.safelink.queryEvents").data(&input)?;
 
-    client.request(req).await
+    client.request_body(req).await
+}
+
+/// Server-side definition of tools.ozone.safelink.queryEvents
+pub struct Endpoint;
+
+impl crate::xrpc::XrpcEndpoint for Endpoint {
+    const NSID: &'static str = "tools.ozone.safelink.queryEvents";
+    const METHOD: crate::xrpc::XrpcMethod = crate::xrpc::XrpcMethod::Procedure;
+    const INPUT_ENCODING: Option<&'static str> = Some("application/json");
+    const OUTPUT_ENCODING: Option<&'static str> = Some("application/json");
+    type Params = ();
+    type Input = Input;
+    type Output = Output;
 }
@@ -8,44 +8,48 @@ use serde::{Deserialize, Serialize};
 /// Request input
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Input {
-    /// Filter by rule creator
-    #[serde(skip_serializing_if = "Option::is_none")]
-    #[serde(rename = "createdBy")]
-    pub created_by: Option<crate::types::Did>,
-    /// Filter by reason type
+    /// Cursor for pagination
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub reason: Option<String>,
+    pub cursor: Option<String>,
     /// Maximum number of results to return
     #[serde(skip_serializing_if = "Option::is_none")]
     pub limit: Option<i64>,
-    /// Cursor for pagination
+    /// Filter by specific URLs or domains
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub cursor: Option<String>,
+    pub urls: Option<Vec<String>>,
     /// Filter by pattern type
     #[serde(skip_serializing_if = "Option::is_none")]
     #[serde(rename = "patternType")]
     pub pattern_type: Option<String>,
     /// Filter by action types
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub actions: Option<serde_json::Value>,
-    /// Filter by specific URLs or domains
+    pub actions: Option<Vec<String>>,
+    /// Filter by reason type
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reason: Option<String>,
+    /// Filter by rule creator
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub urls: Option<serde_json::Value>,
+    #[serde(rename = "createdBy")]
+    pub created_by: Option<crate::types::Did>,
     /// Sort direction
     #[serde(skip_serializing_if = "Option::is_none")]
     #[serde(rename = "sortDirection")]
     pub sort_direction: Option<String>,
 }
 
+impl crate::xrpc::JsonBody for Input {}
+
 /// Response output
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Output {
-    pub rules: serde_json::Value,
     /// Next cursor for pagination. Only present if there are more results.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub cursor: Option<String>,
+    pub rules: Vec<serde_json::Value>,
 }
 
+impl crate::xrpc::JsonBody for Output {}
+
 /// Query URL safety rules
 pub async fn query_rules(
     client: &impl XrpcClient,
@@ -53,5 +57,18 @@ pub async fn query_rules(
 ) -> Result<XrpcResponse<Output>, XrpcError> {
     let req = XrpcRequest::procedure("tools.ozone.safelink.queryRules").data(&input)?;
 
-    client.request(req).await
+    client.request_body(req).await
+}
+
+/// Server-side definition of tools.ozone.safelink.queryRules
+pub struct Endpoint;
+
+impl crate::xrpc::XrpcEndpoint for Endpoint {
+    const NSID: &'static str = "tools.ozone.safelink.queryRules";
+    const METHOD: crate::xrpc::XrpcMethod = crate::xrpc::XrpcMethod::Procedure;
+    const INPUT_ENCODING: Option<&'static str> = Some("application/json");
+    const OUTPUT_ENCODING: Option<&'static str> = Some("application/json");
+    type Params = ();
+    type Input = Input;
+    type Output = Output;
 }
@@ -10,21 +10,25 @@ use serde::{Deserialize, Serialize};
 pub struct Input {
     /// The URL or domain to remove the rule for
     pub url: String,
-    /// Optional DID of the user. Only respected when using admin auth.
-    #[serde(skip_serializing_if = "Option::is_none")]
-    #[serde(rename = "createdBy")]
-    pub created_by: Option<crate::types::Did>,
     pub pattern: serde_json::Value,
     /// Optional comment about why the rule is being removed
     #[serde(skip_serializing_if = "Option::is_none")]
     pub comment: Option<String>,
+    /// Optional DID of the user. Only respected when using admin auth.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(rename = "createdBy")]
+    pub created_by: Option<crate::types::Did>,
 }
 
+impl crate::xrpc::JsonBody for Input {}
+
 /// Response output
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Output {
 }
 
+impl crate::xrpc::JsonBody for Output {}
+
 /// Remove an existing URL safety rule
 pub async fn remove_rule(
     client: &impl XrpcClient,
@@ -32,5 +36,18 @@ pub async fn remove_rule(
 ) -> Result<XrpcResponse<Output>, XrpcError> {
     let req = XrpcRequest::procedure("tools.ozone.safelink.removeRule").data(&input)?;
 
-    client.request(req).await
+    client.request_body(req).await
+}
+
+/// Server-side definition of tools.ozone.safelink.removeRule
+pub struct Endpoint;
+
+impl crate::xrpc::XrpcEndpoint for Endpoint {
+    const NSID: &'static str = "tools.ozone.safelink.removeRule";
+    const METHOD: crate::xrpc::XrpcMethod = crate::xrpc::XrpcMethod::Procedure;
+    const INPUT_ENCODING: Option<&'static str> = Some("application/json");
+    const OUTPUT_ENCODING: Option<&'static str> = Some("application/json");
+    type Params = ();
+    type Input = Input;
+    type Output = Output;
 }
@@ -8,25 +8,29 @@ use serde::{Deserialize, Serialize};
 /// Request input
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Input {
-    /// Optional DID to credit as the creator. Only respected for admin_token authentication.
-    #[serde(skip_serializing_if = "Option::is_none")]
-    #[serde(rename = "createdBy")]
-    pub created_by: Option<crate::types::Did>,
-    pub reason: serde_json::Value,
     /// The URL or domain to update the rule for
     pub url: String,
+    pub pattern: serde_json::Value,
+    pub action: serde_json::Value,
+    pub reason: serde_json::Value,
     /// Optional comment about the update
     #[serde(skip_serializing_if = "Option::is_none")]
     pub comment: Option<String>,
-    pub action: serde_json::Value,
-    pub pattern: serde_json::Value,
+    /// Optional DID to credit as the creator. Only respected for admin_token authentication.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(rename = "createdBy")]
+    pub created_by: Option<crate::types::Did>,
 }
 
+impl crate::xrpc::JsonBody for Input {}
+
 /// Response output
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Output {
 }
 
+impl crate::xrpc::JsonBody for Output {}
+
 /// Update an existing URL safety rule
 pub async fn update_rule(
     client: &impl XrpcClient,
@@ -34,5 +38,18 @@ pub async fn update_rule(
 ) -> Result<XrpcResponse<Output>, XrpcError> {
     let req = XrpcRequest::procedure("tools.ozone.safelink.updateRule").data(&input)?;
 
-    client.request(req).await
+    client.request_body(req).await
+}
+
+/// Server-side definition of tools.ozone.safelink.updateRule
+pub struct Endpoint;
+
+impl crate::xrpc::XrpcEndpoint for Endpoint {
+    const NSID: &'static str = "tools.ozone.safelink.updateRule";
+    const METHOD: crate::xrpc::XrpcMethod = crate::xrpc::XrpcMethod::Procedure;
+    const INPUT_ENCODING: Option<&'static str> = Some("application/json");
+    const OUTPUT_ENCODING: Option<&'static str> = Some("application/json");
+    type Params = ();
+    type Input = Input;
+    type Output = Output;
 }
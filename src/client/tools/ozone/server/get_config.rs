@@ -8,10 +8,10 @@ use serde::{Deserialize, Serialize};
 /// Response output
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Output {
-    /// The did of the verifier used for verification.
     #[serde(skip_serializing_if = "Option::is_none")]
-    #[serde(rename = "verifierDid")]
-    pub verifier_did: Option<crate::types::Did>,
+    pub appview: Option<serde_json::Value>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub pds: Option<serde_json::Value>,
     #[serde(skip_serializing_if = "Option::is_none")]
     #[serde(rename = "blobDivert")]
     pub blob_divert: Option<serde_json::Value>,
@@ -19,10 +19,24 @@ pub struct Output {
     pub chat: Option<serde_json::Value>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub viewer: Option<serde_json::Value>,
+    /// The did of the verifier used for verification.
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub pds: Option<serde_json::Value>,
+    #[serde(rename = "verifierDid")]
+    pub verifier_did: Option<crate::types::Did>,
+}
+
+impl crate::xrpc::JsonBody for Output {}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ServiceConfig {
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub appview: Option<serde_json::Value>,
+    pub url: Option<crate::types::Uri>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ViewerConfig {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub role: Option<String>,
 }
 
 /// Get details about ozone's server configuration.
@@ -31,5 +45,18 @@ pub async fn get_config(
 ) -> Result<XrpcResponse<Output>, XrpcError> {
     let req = XrpcRequest::query("tools.ozone.server.getConfig");
 
-    client.request(req).await
+    client.request_body(req).await
+}
+
+/// Server-side definition of tools.ozone.server.getConfig
+pub struct Endpoint;
+
+impl crate::xrpc::XrpcEndpoint for Endpoint {
+    const NSID: &'static str = "tools.ozone.server.getConfig";
+    const METHOD: crate::xrpc::XrpcMethod = crate::xrpc::XrpcMethod::Query;
+    const INPUT_ENCODING: Option<&'static str> = None;
+    const OUTPUT_ENCODING: Option<&'static str> = Some("application/json");
+    type Params = ();
+    type Input = ();
+    type Output = Output;
 }
@@ -11,9 +11,11 @@ pub struct Input {
     /// Name of the set to add values to
     pub name: String,
     /// Array of string values to add to the set
-    pub values: serde_json::Value,
+    pub values: Vec<String>,
 }
 
+impl crate::xrpc::JsonBody for Input {}
+
 /// Add values to a specific set. Attempting to add values to a set that does not exist will result in an error.
 pub async fn add_values(
     client: &impl XrpcClient,
@@ -21,5 +23,18 @@ pub async fn add_values(
 ) -> Result<XrpcResponse<()>, XrpcError> {
     let req = XrpcRequest::procedure("tools.ozone.set.addValues").data(&input)?;
 
-    client.request(req).await
+    client.request_body(req).await
+}
+
+/// Server-side definition of tools.ozone.set.addValues
+pub struct Endpoint;
+
+impl crate::xrpc::XrpcEndpoint for Endpoint {
+    const NSID: &'static str = "tools.ozone.set.addValues";
+    const METHOD: crate::xrpc::XrpcMethod = crate::xrpc::XrpcMethod::Procedure;
+    const INPUT_ENCODING: Option<&'static str> = Some("application/json");
+    const OUTPUT_ENCODING: Option<&'static str> = None;
+    type Params = ();
+    type Input = Input;
+    type Output = ();
 }
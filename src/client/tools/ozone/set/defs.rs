@@ -12,15 +12,15 @@ pub struct Set {
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct SetView {
+    pub name: String,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub description: Option<String>,
-    pub name: String,
     #[serde(rename = "setSize")]
     pub set_size: i64,
     #[serde(rename = "createdAt")]
-    pub created_at: String,
+    pub created_at: crate::types::Datetime,
     #[serde(rename = "updatedAt")]
-    pub updated_at: String,
+    pub updated_at: crate::types::Datetime,
 }
 
 
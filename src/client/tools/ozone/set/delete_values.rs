@@ -11,9 +11,11 @@ pub struct Input {
     /// Name of the set to delete values from
     pub name: String,
     /// Array of string values to delete from the set
-    pub values: serde_json::Value,
+    pub values: Vec<String>,
 }
 
+impl crate::xrpc::JsonBody for Input {}
+
 /// Delete values from a specific set. Attempting to delete values that are not in the set will not result in an error
 pub async fn delete_values(
     client: &impl XrpcClient,
@@ -21,5 +23,18 @@ pub async fn delete_values(
 ) -> Result<XrpcResponse<()>, XrpcError> {
     let req = XrpcRequest::procedure("tools.ozone.set.deleteValues").data(&input)?;
 
-    client.request(req).await
+    client.request_body(req).await
+}
+
+/// Server-side definition of tools.ozone.set.deleteValues
+pub struct Endpoint;
+
+impl crate::xrpc::XrpcEndpoint for Endpoint {
+    const NSID: &'static str = "tools.ozone.set.deleteValues";
+    const METHOD: crate::xrpc::XrpcMethod = crate::xrpc::XrpcMethod::Procedure;
+    const INPUT_ENCODING: Option<&'static str> = Some("application/json");
+    const OUTPUT_ENCODING: Option<&'static str> = None;
+    type Params = ();
+    type Input = Input;
+    type Output = ();
 }
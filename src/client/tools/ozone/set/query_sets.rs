@@ -8,11 +8,11 @@ use serde::{Deserialize, Serialize};
 /// Query parameters
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct QueryParams {
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub cursor: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub limit: Option<i64>,
     #[serde(skip_serializing_if = "Option::is_none")]
+    pub cursor: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     #[serde(rename = "namePrefix")]
     pub name_prefix: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -27,11 +27,13 @@ pub struct QueryParams {
 /// Response output
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Output {
+    pub sets: Vec<serde_json::Value>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub cursor: Option<String>,
-    pub sets: serde_json::Value,
 }
 
+impl crate::xrpc::JsonBody for Output {}
+
 /// Query available sets
 pub async fn query_sets(
     client: &impl XrpcClient,
@@ -40,18 +42,34 @@ pub async fn query_sets(
     let mut req = XrpcRequest::query("tools.ozone.set.querySets");
 
     // Add query parameters
-    let params_json = serde_json::to_value(&params)
-        .map_err(XrpcError::Serialization)?;
-
-    if let Some(obj) = params_json.as_object() {
-        for (key, value) in obj {
-            if let Some(s) = value.as_str() {
-                req.params.insert(key.clone(), s.to_string());
-            } else {
-                req.params.insert(key.clone(), value.to_string());
-            }
-        }
+    if let Some(value) = &params.limit {
+        req.params.append("limit", value.to_string());
+    }
+    if let Some(value) = &params.cursor {
+        req.params.append("cursor", value.to_string());
     }
+    if let Some(value) = &params.name_prefix {
+        req.params.append("namePrefix", value.to_string());
+    }
+    if let Some(value) = &params.sort_by {
+        req.params.append("sortBy", value.to_string());
+    }
+    if let Some(value) = &params.sort_direction {
+        req.params.append("sortDirection", value.to_string());
+    }
+
+    client.request_body(req).await
+}
+
+/// Server-side definition of tools.ozone.set.querySets
+pub struct Endpoint;
 
-    client.request(req).await
+impl crate::xrpc::XrpcEndpoint for Endpoint {
+    const NSID: &'static str = "tools.ozone.set.querySets";
+    const METHOD: crate::xrpc::XrpcMethod = crate::xrpc::XrpcMethod::Query;
+    const INPUT_ENCODING: Option<&'static str> = None;
+    const OUTPUT_ENCODING: Option<&'static str> = Some("application/json");
+    type Params = QueryParams;
+    type Input = ();
+    type Output = Output;
 }
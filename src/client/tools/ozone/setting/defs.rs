@@ -4,25 +4,25 @@ use serde::{Deserialize, Serialize};
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct OptionSetting {
-    #[serde(skip_serializing_if = "Option::is_none")]
-    #[serde(rename = "managerRole")]
-    pub manager_role: Option<String>,
-    pub key: String,
+    pub key: crate::types::Nsid,
+    pub did: crate::types::Did,
+    pub value: serde_json::Value,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub description: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(rename = "createdAt")]
+    pub created_at: Option<crate::types::Datetime>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     #[serde(rename = "updatedAt")]
-    pub updated_at: Option<String>,
+    pub updated_at: Option<crate::types::Datetime>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(rename = "managerRole")]
+    pub manager_role: Option<String>,
     pub scope: String,
     #[serde(rename = "createdBy")]
     pub created_by: crate::types::Did,
-    pub did: crate::types::Did,
-    #[serde(skip_serializing_if = "Option::is_none")]
-    #[serde(rename = "createdAt")]
-    pub created_at: Option<String>,
     #[serde(rename = "lastUpdatedBy")]
     pub last_updated_by: crate::types::Did,
-    pub value: serde_json::Value,
 }
 
 
@@ -8,18 +8,18 @@ use serde::{Deserialize, Serialize};
 /// Query parameters
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct QueryParams {
-    /// Filter for only the specified keys. Ignored if prefix is provided
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub keys: Option<serde_json::Value>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub limit: Option<i64>,
-    /// Filter keys by prefix
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub prefix: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub cursor: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub scope: Option<String>,
+    /// Filter keys by prefix
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub prefix: Option<String>,
+    /// Filter for only the specified keys. Ignored if prefix is provided
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub keys: Option<Vec<crate::types::Nsid>>,
 }
 
 /// Response output
@@ -27,9 +27,11 @@ pub struct QueryParams {
 pub struct Output {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub cursor: Option<String>,
-    pub options: serde_json::Value,
+    pub options: Vec<serde_json::Value>,
 }
 
+impl crate::xrpc::JsonBody for Output {}
+
 /// List settings with optional filtering
 pub async fn list_options(
     client: &impl XrpcClient,
@@ -38,18 +40,34 @@ pub async fn list_options(
     let mut req = XrpcRequest::query("tools.ozone.setting.listOptions");
 
     // Add query parameters
-    let params_json = serde_json::to_value(&params)
-        .map_err(XrpcError::Serialization)?;
-
-    if let Some(obj) = params_json.as_object() {
-        for (key, value) in obj {
-            if let Some(s) = value.as_str() {
-                req.params.insert(key.clone(), s.to_string());
-            } else {
-                req.params.insert(key.clone(), value.to_string());
-            }
-        }
+    if let Some(value) = &params.limit {
+        req.params.append("limit", value.to_string());
+    }
+    if let Some(value) = &params.cursor {
+        req.params.append("cursor", value.to_string());
+    }
+    if let Some(value) = &params.scope {
+        req.params.append("scope", value.to_string());
+    }
+    if let Some(value) = &params.prefix {
+        req.params.append("prefix", value.to_string());
     }
+    for value in params.keys.iter().flatten() {
+        req.params.append("keys", value.to_string());
+    }
+
+    client.request_body(req).await
+}
+
+/// Server-side definition of tools.ozone.setting.listOptions
+pub struct Endpoint;
 
-    client.request(req).await
+impl crate::xrpc::XrpcEndpoint for Endpoint {
+    const NSID: &'static str = "tools.ozone.setting.listOptions";
+    const METHOD: crate::xrpc::XrpcMethod = crate::xrpc::XrpcMethod::Query;
+    const INPUT_ENCODING: Option<&'static str> = None;
+    const OUTPUT_ENCODING: Option<&'static str> = Some("application/json");
+    type Params = QueryParams;
+    type Input = ();
+    type Output = Output;
 }
@@ -8,22 +8,26 @@ use serde::{Deserialize, Serialize};
 /// Request input
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Input {
+    pub key: crate::types::Nsid,
     pub scope: String,
+    pub value: serde_json::Value,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub description: Option<String>,
-    pub key: String,
     #[serde(skip_serializing_if = "Option::is_none")]
     #[serde(rename = "managerRole")]
     pub manager_role: Option<String>,
-    pub value: serde_json::Value,
 }
 
+impl crate::xrpc::JsonBody for Input {}
+
 /// Response output
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Output {
     pub option: serde_json::Value,
 }
 
+impl crate::xrpc::JsonBody for Output {}
+
 /// Create or update setting option
 pub async fn upsert_option(
     client: &impl XrpcClient,
@@ -31,5 +35,18 @@ pub async fn upsert_option(
 ) -> Result<XrpcResponse<Output>, XrpcError> {
     let req = XrpcRequest::procedure("tools.ozone.setting.upsertOption").data(&input)?;
 
-    client.request(req).await
+    client.request_body(req).await
+}
+
+/// Server-side definition of tools.ozone.setting.upsertOption
+pub struct Endpoint;
+
+impl crate::xrpc::XrpcEndpoint for Endpoint {
+    const NSID: &'static str = "tools.ozone.setting.upsertOption";
+    const METHOD: crate::xrpc::XrpcMethod = crate::xrpc::XrpcMethod::Procedure;
+    const INPUT_ENCODING: Option<&'static str> = Some("application/json");
+    const OUTPUT_ENCODING: Option<&'static str> = Some("application/json");
+    type Params = ();
+    type Input = Input;
+    type Output = Output;
 }
@@ -11,6 +11,8 @@ pub struct Input {
     pub did: crate::types::Did,
 }
 
+impl crate::xrpc::JsonBody for Input {}
+
 /// Delete a member from ozone team. Requires admin role.
 pub async fn delete_member(
     client: &impl XrpcClient,
@@ -18,5 +20,18 @@ pub async fn delete_member(
 ) -> Result<XrpcResponse<()>, XrpcError> {
     let req = XrpcRequest::procedure("tools.ozone.team.deleteMember").data(&input)?;
 
-    client.request(req).await
+    client.request_body(req).await
+}
+
+/// Server-side definition of tools.ozone.team.deleteMember
+pub struct Endpoint;
+
+impl crate::xrpc::XrpcEndpoint for Endpoint {
+    const NSID: &'static str = "tools.ozone.team.deleteMember";
+    const METHOD: crate::xrpc::XrpcMethod = crate::xrpc::XrpcMethod::Procedure;
+    const INPUT_ENCODING: Option<&'static str> = Some("application/json");
+    const OUTPUT_ENCODING: Option<&'static str> = None;
+    type Params = ();
+    type Input = Input;
+    type Output = ();
 }
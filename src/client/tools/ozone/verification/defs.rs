@@ -5,57 +5,133 @@ use serde::{Deserialize, Serialize};
 /// Verification data for the associated subject.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct VerificationView {
+    /// The user who issued this verification.
     /// Verification data for the associated subject.
-    #[serde(skip_serializing_if = "Option::is_none")]
-    #[serde(rename = "subjectRepo")]
-    pub subject_repo: Option<serde_json::Value>,
-    /// Verification data for the associated subject.
-    #[serde(skip_serializing_if = "Option::is_none")]
-    #[serde(rename = "issuerProfile")]
-    pub issuer_profile: Option<serde_json::Value>,
-    /// Verification data for the associated subject.
-    #[serde(skip_serializing_if = "Option::is_none")]
-    #[serde(rename = "subjectProfile")]
-    pub subject_profile: Option<serde_json::Value>,
-    /// The user who revoked this verification.
+    pub issuer: crate::types::Did,
+    /// The AT-URI of the verification record.
     /// Verification data for the associated subject.
-    #[serde(skip_serializing_if = "Option::is_none")]
-    #[serde(rename = "revokedBy")]
-    pub revoked_by: Option<crate::types::Did>,
+    pub uri: crate::syntax::AtUri,
+    /// The subject of the verification.
     /// Verification data for the associated subject.
-    #[serde(skip_serializing_if = "Option::is_none")]
-    #[serde(rename = "issuerRepo")]
-    pub issuer_repo: Option<serde_json::Value>,
+    pub subject: crate::types::Did,
     /// Handle of the subject the verification applies to at the moment of verifying, which might not be the same at the time of viewing. The verification is only valid if the current handle matches the one at the time of verifying.
     /// Verification data for the associated subject.
-    pub handle: String,
-    /// The user who issued this verification.
+    pub handle: crate::types::Handle,
+    /// Display name of the subject the verification applies to at the moment of verifying, which might not be the same at the time of viewing. The verification is only valid if the current displayName matches the one at the time of verifying.
     /// Verification data for the associated subject.
-    pub issuer: crate::types::Did,
+    #[serde(rename = "displayName")]
+    pub display_name: String,
     /// Timestamp when the verification was created.
     /// Verification data for the associated subject.
     #[serde(rename = "createdAt")]
-    pub created_at: String,
-    /// The subject of the verification.
-    /// Verification data for the associated subject.
-    pub subject: crate::types::Did,
+    pub created_at: crate::types::Datetime,
     /// Describes the reason for revocation, also indicating that the verification is no longer valid.
     /// Verification data for the associated subject.
     #[serde(skip_serializing_if = "Option::is_none")]
     #[serde(rename = "revokeReason")]
     pub revoke_reason: Option<String>,
-    /// The AT-URI of the verification record.
-    /// Verification data for the associated subject.
-    pub uri: crate::syntax::AtUri,
     /// Timestamp when the verification was revoked.
     /// Verification data for the associated subject.
     #[serde(skip_serializing_if = "Option::is_none")]
     #[serde(rename = "revokedAt")]
-    pub revoked_at: Option<String>,
-    /// Display name of the subject the verification applies to at the moment of verifying, which might not be the same at the time of viewing. The verification is only valid if the current displayName matches the one at the time of verifying.
+    pub revoked_at: Option<crate::types::Datetime>,
+    /// The user who revoked this verification.
     /// Verification data for the associated subject.
-    #[serde(rename = "displayName")]
-    pub display_name: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(rename = "revokedBy")]
+    pub revoked_by: Option<crate::types::Did>,
+    /// Verification data for the associated subject.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(rename = "subjectProfile")]
+    pub subject_profile: Option<VerificationViewSubjectProfile>,
+    /// Verification data for the associated subject.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(rename = "issuerProfile")]
+    pub issuer_profile: Option<VerificationViewIssuerProfile>,
+    /// Verification data for the associated subject.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(rename = "subjectRepo")]
+    pub subject_repo: Option<VerificationViewSubjectRepo>,
+    /// Verification data for the associated subject.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(rename = "issuerRepo")]
+    pub issuer_repo: Option<VerificationViewIssuerRepo>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(tag = "$type")]
+pub enum VerificationViewSubjectProfile {
+    /// A type not listed in the lexicon, kept as raw JSON
+    #[serde(untagged)]
+    Unknown(serde_json::Value),
+}
+
+impl<'de> Deserialize<'de> for VerificationViewSubjectProfile {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> std::result::Result<Self, D::Error> {
+        let value = serde_json::Value::deserialize(deserializer)?;
+        Ok(Self::Unknown(value))
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(tag = "$type")]
+pub enum VerificationViewIssuerProfile {
+    /// A type not listed in the lexicon, kept as raw JSON
+    #[serde(untagged)]
+    Unknown(serde_json::Value),
+}
+
+impl<'de> Deserialize<'de> for VerificationViewIssuerProfile {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> std::result::Result<Self, D::Error> {
+        let value = serde_json::Value::deserialize(deserializer)?;
+        Ok(Self::Unknown(value))
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(tag = "$type")]
+pub enum VerificationViewSubjectRepo {
+    #[serde(rename = "tools.ozone.moderation.defs#repoViewDetail")]
+    RepoViewDetail(Box<crate::client::tools::ozone::moderation::defs::RepoViewDetail>),
+    #[serde(rename = "tools.ozone.moderation.defs#repoViewNotFound")]
+    RepoViewNotFound(Box<crate::client::tools::ozone::moderation::defs::RepoViewNotFound>),
+    /// A type not listed in the lexicon, kept as raw JSON
+    #[serde(untagged)]
+    Unknown(serde_json::Value),
+}
+
+impl<'de> Deserialize<'de> for VerificationViewSubjectRepo {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> std::result::Result<Self, D::Error> {
+        let value = serde_json::Value::deserialize(deserializer)?;
+        match value.get("$type").and_then(|t| t.as_str()) {
+            Some("tools.ozone.moderation.defs#repoViewDetail") => serde_json::from_value(value).map(Self::RepoViewDetail).map_err(serde::de::Error::custom),
+            Some("tools.ozone.moderation.defs#repoViewNotFound") => serde_json::from_value(value).map(Self::RepoViewNotFound).map_err(serde::de::Error::custom),
+            _ => Ok(Self::Unknown(value)),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(tag = "$type")]
+pub enum VerificationViewIssuerRepo {
+    #[serde(rename = "tools.ozone.moderation.defs#repoViewDetail")]
+    RepoViewDetail(Box<crate::client::tools::ozone::moderation::defs::RepoViewDetail>),
+    #[serde(rename = "tools.ozone.moderation.defs#repoViewNotFound")]
+    RepoViewNotFound(Box<crate::client::tools::ozone::moderation::defs::RepoViewNotFound>),
+    /// A type not listed in the lexicon, kept as raw JSON
+    #[serde(untagged)]
+    Unknown(serde_json::Value),
+}
+
+impl<'de> Deserialize<'de> for VerificationViewIssuerRepo {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> std::result::Result<Self, D::Error> {
+        let value = serde_json::Value::deserialize(deserializer)?;
+        match value.get("$type").and_then(|t| t.as_str()) {
+            Some("tools.ozone.moderation.defs#repoViewDetail") => serde_json::from_value(value).map(Self::RepoViewDetail).map_err(serde::de::Error::custom),
+            Some("tools.ozone.moderation.defs#repoViewNotFound") => serde_json::from_value(value).map(Self::RepoViewNotFound).map_err(serde::de::Error::custom),
+            _ => Ok(Self::Unknown(value)),
+        }
+    }
 }
 
 
@@ -9,15 +9,45 @@ use serde::{Deserialize, Serialize};
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Input {
     /// Array of verification requests to process
-    pub verifications: serde_json::Value,
+    pub verifications: Vec<serde_json::Value>,
 }
 
+impl crate::xrpc::JsonBody for Input {}
+
 /// Response output
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Output {
+    pub verifications: Vec<serde_json::Value>,
     #[serde(rename = "failedVerifications")]
-    pub failed_verifications: serde_json::Value,
-    pub verifications: serde_json::Value,
+    pub failed_verifications: Vec<serde_json::Value>,
+}
+
+impl crate::xrpc::JsonBody for Output {}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct VerificationInput {
+    /// The did of the subject being verified
+    pub subject: crate::types::Did,
+    /// Handle of the subject the verification applies to at the moment of verifying.
+    pub handle: crate::types::Handle,
+    /// Display name of the subject the verification applies to at the moment of verifying.
+    #[serde(rename = "displayName")]
+    pub display_name: String,
+    /// Timestamp for verification record. Defaults to current time when not specified.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(rename = "createdAt")]
+    pub created_at: Option<crate::types::Datetime>,
+}
+
+/// Error object for failed verifications.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GrantError {
+    /// Error message describing the reason for failure.
+    /// Error object for failed verifications.
+    pub error: String,
+    /// The did of the subject being verified
+    /// Error object for failed verifications.
+    pub subject: crate::types::Did,
 }
 
 /// Grant verifications to multiple subjects. Allows batch processing of up to 100 verifications at once.
@@ -27,5 +57,18 @@ pub async fn grant_verifications(
 ) -> Result<XrpcResponse<Output>, XrpcError> {
     let req = XrpcRequest::procedure("tools.ozone.verification.grantVerifications").data(&input)?;
 
-    client.request(req).await
+    client.request_body(req).await
+}
+
+/// Server-side definition of tools.ozone.verification.grantVerifications
+pub struct Endpoint;
+
+impl crate::xrpc::XrpcEndpoint for Endpoint {
+    const NSID: &'static str = "tools.ozone.verification.grantVerifications";
+    const METHOD: crate::xrpc::XrpcMethod = crate::xrpc::XrpcMethod::Procedure;
+    const INPUT_ENCODING: Option<&'static str> = Some("application/json");
+    const OUTPUT_ENCODING: Option<&'static str> = Some("application/json");
+    type Params = ();
+    type Input = Input;
+    type Output = Output;
 }
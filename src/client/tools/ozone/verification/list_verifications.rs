@@ -8,34 +8,34 @@ use serde::{Deserialize, Serialize};
 /// Query parameters
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct QueryParams {
-    /// Sort direction for creation date
-    #[serde(skip_serializing_if = "Option::is_none")]
-    #[serde(rename = "sortDirection")]
-    pub sort_direction: Option<String>,
-    /// Filter to verifications from specific issuers
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub issuers: Option<serde_json::Value>,
-    /// Filter to specific verified DIDs
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub subjects: Option<serde_json::Value>,
-    /// Filter to verifications that are revoked or not. By default, includes both.
-    #[serde(skip_serializing_if = "Option::is_none")]
-    #[serde(rename = "isRevoked")]
-    pub is_revoked: Option<bool>,
     /// Pagination cursor
     #[serde(skip_serializing_if = "Option::is_none")]
     pub cursor: Option<String>,
     /// Maximum number of results to return
     #[serde(skip_serializing_if = "Option::is_none")]
     pub limit: Option<i64>,
+    /// Filter to verifications created after this timestamp
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(rename = "createdAfter")]
+    pub created_after: Option<crate::types::Datetime>,
     /// Filter to verifications created before this timestamp
     #[serde(skip_serializing_if = "Option::is_none")]
     #[serde(rename = "createdBefore")]
-    pub created_before: Option<String>,
-    /// Filter to verifications created after this timestamp
+    pub created_before: Option<crate::types::Datetime>,
+    /// Filter to verifications from specific issuers
     #[serde(skip_serializing_if = "Option::is_none")]
-    #[serde(rename = "createdAfter")]
-    pub created_after: Option<String>,
+    pub issuers: Option<Vec<crate::types::Did>>,
+    /// Filter to specific verified DIDs
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub subjects: Option<Vec<crate::types::Did>>,
+    /// Sort direction for creation date
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(rename = "sortDirection")]
+    pub sort_direction: Option<String>,
+    /// Filter to verifications that are revoked or not. By default, includes both.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(rename = "isRevoked")]
+    pub is_revoked: Option<bool>,
 }
 
 /// Response output
@@ -43,9 +43,11 @@ pub struct QueryParams {
 pub struct Output {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub cursor: Option<String>,
-    pub verifications: serde_json::Value,
+    pub verifications: Vec<serde_json::Value>,
 }
 
+impl crate::xrpc::JsonBody for Output {}
+
 /// List verifications
 pub async fn list_verifications(
     client: &impl XrpcClient,
@@ -54,18 +56,43 @@ pub async fn list_verifications(
     let mut req = XrpcRequest::query("tools.ozone.verification.listVerifications");
 
     // Add query parameters
-    let params_json = serde_json::to_value(&params)
-        .map_err(XrpcError::Serialization)?;
-
-    if let Some(obj) = params_json.as_object() {
-        for (key, value) in obj {
-            if let Some(s) = value.as_str() {
-                req.params.insert(key.clone(), s.to_string());
-            } else {
-                req.params.insert(key.clone(), value.to_string());
-            }
-        }
+    if let Some(value) = &params.cursor {
+        req.params.append("cursor", value.to_string());
     }
+    if let Some(value) = &params.limit {
+        req.params.append("limit", value.to_string());
+    }
+    if let Some(value) = &params.created_after {
+        req.params.append("createdAfter", value.to_string());
+    }
+    if let Some(value) = &params.created_before {
+        req.params.append("createdBefore", value.to_string());
+    }
+    for value in params.issuers.iter().flatten() {
+        req.params.append("issuers", value.to_string());
+    }
+    for value in params.subjects.iter().flatten() {
+        req.params.append("subjects", value.to_string());
+    }
+    if let Some(value) = &params.sort_direction {
+        req.params.append("sortDirection", value.to_string());
+    }
+    if let Some(value) = &params.is_revoked {
+        req.params.append("isRevoked", value.to_string());
+    }
+
+    client.request_body(req).await
+}
+
+/// Server-side definition of tools.ozone.verification.listVerifications
+pub struct Endpoint;
 
-    client.request(req).await
+impl crate::xrpc::XrpcEndpoint for Endpoint {
+    const NSID: &'static str = "tools.ozone.verification.listVerifications";
+    const METHOD: crate::xrpc::XrpcMethod = crate::xrpc::XrpcMethod::Query;
+    const INPUT_ENCODING: Option<&'static str> = None;
+    const OUTPUT_ENCODING: Option<&'static str> = Some("application/json");
+    type Params = QueryParams;
+    type Input = ();
+    type Output = Output;
 }
@@ -9,22 +9,37 @@ use serde::{Deserialize, Serialize};
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Input {
     /// Array of verification record uris to revoke
-    pub uris: serde_json::Value,
+    pub uris: Vec<crate::syntax::AtUri>,
     /// Reason for revoking the verification. This is optional and can be omitted if not needed.
     #[serde(skip_serializing_if = "Option::is_none")]
     #[serde(rename = "revokeReason")]
     pub revoke_reason: Option<String>,
 }
 
+impl crate::xrpc::JsonBody for Input {}
+
 /// Response output
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Output {
     /// List of verification uris successfully revoked
     #[serde(rename = "revokedVerifications")]
-    pub revoked_verifications: serde_json::Value,
+    pub revoked_verifications: Vec<crate::syntax::AtUri>,
     /// List of verification uris that couldn't be revoked, including failure reasons
     #[serde(rename = "failedRevocations")]
-    pub failed_revocations: serde_json::Value,
+    pub failed_revocations: Vec<serde_json::Value>,
+}
+
+impl crate::xrpc::JsonBody for Output {}
+
+/// Error object for failed revocations
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RevokeError {
+    /// The AT-URI of the verification record that failed to revoke.
+    /// Error object for failed revocations
+    pub uri: crate::syntax::AtUri,
+    /// Description of the error that occurred during revocation.
+    /// Error object for failed revocations
+    pub error: String,
 }
 
 /// Revoke previously granted verifications in batches of up to 100.
@@ -34,5 +49,18 @@ pub async fn revoke_verifications(
 ) -> Result<XrpcResponse<Output>, XrpcError> {
     let req = XrpcRequest::procedure("tools.ozone.verification.revokeVerifications").data(&input)?;
 
-    client.request(req).await
+    client.request_body(req).await
+}
+
+/// Server-side definition of tools.ozone.verification.revokeVerifications
+pub struct Endpoint;
+
+impl crate::xrpc::XrpcEndpoint for Endpoint {
+    const NSID: &'static str = "tools.ozone.verification.revokeVerifications";
+    const METHOD: crate::xrpc::XrpcMethod = crate::xrpc::XrpcMethod::Procedure;
+    const INPUT_ENCODING: Option<&'static str> = Some("application/json");
+    const OUTPUT_ENCODING: Option<&'static str> = Some("application/json");
+    type Params = ();
+    type Input = Input;
+    type Output = Output;
 }
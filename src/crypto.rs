@@ -0,0 +1,515 @@
+//! Atproto cryptography
+//!
+//! Atproto signs repository commits, labels and service-auth tokens with ECDSA
+//! over one of two curves:
+//!
+//! - **K-256** (secp256k1, "ES256K")
+//! - **P-256** (secp256r1/NIST P-256, "ES256")
+//!
+//! Signatures are 64-byte compact `r || s` values over the SHA-256 hash of the
+//! signed bytes, and must use the low-S form.
+//!
+//! Public keys are published in DID documents as `publicKeyMultibase` strings:
+//! base58btc multibase (`z` prefix) of a multicodec-prefixed compressed point.
+//! The same encoding behind a `did:key:` prefix gives the key's `did:key`.
+//!
+//! # Example
+//!
+//! ```
+//! use atproto::crypto::{KeyAlgorithm, Keypair, PublicKey};
+//!
+//! let keypair = Keypair::generate(KeyAlgorithm::K256);
+//! let sig = keypair.sign(b"hello");
+//!
+//! // Publish the did:key, verify with the parsed key
+//! let did_key = keypair.public_key().to_did_key();
+//! let public_key = PublicKey::from_did_key(&did_key).unwrap();
+//! assert!(public_key.verify(b"hello", &sig).is_ok());
+//! ```
+//!
+//! ## References
+//!
+//! - Spec: https://atproto.com/specs/cryptography
+
+use crate::did_doc::VerificationMethod;
+use k256::ecdsa::signature::hazmat::{PrehashSigner, PrehashVerifier};
+use k256::ecdsa::signature::{Signer, Verifier};
+use thiserror::Error;
+
+/// Multicodec prefix for secp256k1 public keys (`secp256k1-pub`, 0xe7)
+const K256_MULTICODEC: [u8; 2] = [0xe7, 0x01];
+
+/// Multicodec prefix for P-256 public keys (`p256-pub`, 0x1200)
+const P256_MULTICODEC: [u8; 2] = [0x80, 0x24];
+
+/// Prefix of `did:key` identifiers
+const DID_KEY_PREFIX: &str = "did:key:";
+
+/// Error types for cryptographic operations
+#[derive(Debug, Error, PartialEq)]
+pub enum CryptoError {
+    #[error("Invalid multibase string: {0}")]
+    InvalidMultibase(String),
+
+    #[error("Invalid did:key: {0}")]
+    InvalidDidKey(String),
+
+    #[error("Unsupported key type: {0}")]
+    UnsupportedKeyType(String),
+
+    #[error("Invalid key: {0}")]
+    InvalidKey(String),
+
+    #[error("Invalid signature encoding")]
+    MalformedSignature,
+
+    #[error("Signature verification failed")]
+    InvalidSignature,
+
+    #[error("Signing failed: {0}")]
+    Signing(String),
+}
+
+/// Result type for cryptographic operations
+pub type Result<T> = std::result::Result<T, CryptoError>;
+
+/// Curve used by an atproto key
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum KeyAlgorithm {
+    /// secp256k1
+    K256,
+    /// NIST P-256
+    P256,
+}
+
+impl KeyAlgorithm {
+    /// JWT `alg` name for signatures made with this curve
+    pub fn jwt_alg(&self) -> &'static str {
+        match self {
+            KeyAlgorithm::K256 => "ES256K",
+            KeyAlgorithm::P256 => "ES256",
+        }
+    }
+}
+
+/// A public key on one of the atproto curves
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PublicKey {
+    K256(k256::ecdsa::VerifyingKey),
+    P256(p256::ecdsa::VerifyingKey),
+}
+
+impl PublicKey {
+    /// Parse a `publicKeyMultibase` value with a multicodec prefix
+    ///
+    /// This is the `Multikey` encoding used by current DID documents.
+    pub fn from_multibase(multibase: &str) -> Result<Self> {
+        let bytes = decode_base58btc(multibase)?;
+
+        if let Some(key) = bytes.strip_prefix(&K256_MULTICODEC) {
+            Self::from_sec1_k256(key)
+        } else if let Some(key) = bytes.strip_prefix(&P256_MULTICODEC) {
+            Self::from_sec1_p256(key)
+        } else {
+            Err(CryptoError::UnsupportedKeyType(format!(
+                "unknown multicodec prefix {:02x?}",
+                &bytes[..bytes.len().min(2)]
+            )))
+        }
+    }
+
+    /// Parse a `did:key:z...` identifier
+    pub fn from_did_key(did_key: &str) -> Result<Self> {
+        let multibase = did_key
+            .strip_prefix(DID_KEY_PREFIX)
+            .ok_or_else(|| CryptoError::InvalidDidKey(did_key.to_string()))?;
+        Self::from_multibase(multibase)
+    }
+
+    /// Parse the public key of a DID document verification method
+    ///
+    /// Supports `Multikey` as well as the legacy
+    /// `EcdsaSecp256k1VerificationKey2019` and `EcdsaSecp256r1VerificationKey2019`
+    /// types, whose multibase value carries no multicodec prefix.
+    pub fn from_verification_method(method: &VerificationMethod) -> Result<Self> {
+        let multibase = method
+            .public_key_multibase
+            .as_deref()
+            .ok_or_else(|| CryptoError::InvalidKey(format!("{} has no publicKeyMultibase", method.id)))?;
+
+        match method.key_type.as_str() {
+            "Multikey" => Self::from_multibase(multibase),
+            "EcdsaSecp256k1VerificationKey2019" => Self::from_sec1_k256(&decode_base58btc(multibase)?),
+            "EcdsaSecp256r1VerificationKey2019" => Self::from_sec1_p256(&decode_base58btc(multibase)?),
+            other => Err(CryptoError::UnsupportedKeyType(other.to_string())),
+        }
+    }
+
+    /// Curve of this key
+    pub fn algorithm(&self) -> KeyAlgorithm {
+        match self {
+            PublicKey::K256(_) => KeyAlgorithm::K256,
+            PublicKey::P256(_) => KeyAlgorithm::P256,
+        }
+    }
+
+    /// Compressed SEC1 encoding of the key (33 bytes)
+    pub fn to_bytes(&self) -> Vec<u8> {
+        match self {
+            PublicKey::K256(key) => key.to_encoded_point(true).as_bytes().to_vec(),
+            PublicKey::P256(key) => key.to_encoded_point(true).as_bytes().to_vec(),
+        }
+    }
+
+    /// Encode as a `publicKeyMultibase` value (compressed point, multicodec prefix)
+    pub fn to_multibase(&self) -> String {
+        let prefix = match self {
+            PublicKey::K256(_) => K256_MULTICODEC,
+            PublicKey::P256(_) => P256_MULTICODEC,
+        };
+
+        let mut bytes = prefix.to_vec();
+        bytes.extend_from_slice(&self.to_bytes());
+        format!("z{}", bs58::encode(bytes).into_string())
+    }
+
+    /// Encode as a `did:key:z...` identifier
+    pub fn to_did_key(&self) -> String {
+        format!("{}{}", DID_KEY_PREFIX, self.to_multibase())
+    }
+
+    /// Verify a compact 64-byte signature over `data`
+    ///
+    /// High-S signatures are rejected on both curves.
+    pub fn verify(&self, data: &[u8], signature: &[u8]) -> Result<()> {
+        match self {
+            PublicKey::K256(key) => {
+                let sig = parse_k256_signature(signature)?;
+                key.verify(data, &sig).map_err(|_| CryptoError::InvalidSignature)
+            }
+            PublicKey::P256(key) => {
+                let sig = parse_p256_signature(signature)?;
+                key.verify(data, &sig).map_err(|_| CryptoError::InvalidSignature)
+            }
+        }
+    }
+
+    /// Verify a compact 64-byte signature over an already computed SHA-256 hash
+    ///
+    /// High-S signatures are rejected on both curves.
+    pub fn verify_hash(&self, hash: &[u8; 32], signature: &[u8]) -> Result<()> {
+        match self {
+            PublicKey::K256(key) => {
+                let sig = parse_k256_signature(signature)?;
+                key.verify_prehash(hash, &sig).map_err(|_| CryptoError::InvalidSignature)
+            }
+            PublicKey::P256(key) => {
+                let sig = parse_p256_signature(signature)?;
+                key.verify_prehash(hash, &sig).map_err(|_| CryptoError::InvalidSignature)
+            }
+        }
+    }
+
+    fn from_sec1_k256(bytes: &[u8]) -> Result<Self> {
+        k256::ecdsa::VerifyingKey::from_sec1_bytes(bytes)
+            .map(PublicKey::K256)
+            .map_err(|e| CryptoError::InvalidKey(e.to_string()))
+    }
+
+    fn from_sec1_p256(bytes: &[u8]) -> Result<Self> {
+        p256::ecdsa::VerifyingKey::from_sec1_bytes(bytes)
+            .map(PublicKey::P256)
+            .map_err(|e| CryptoError::InvalidKey(e.to_string()))
+    }
+}
+
+/// A signing keypair on one of the atproto curves
+///
+/// Signatures are always returned in compact, low-S form.
+#[derive(Clone)]
+pub enum Keypair {
+    K256(k256::ecdsa::SigningKey),
+    P256(p256::ecdsa::SigningKey),
+}
+
+impl Keypair {
+    /// Generate a new random keypair
+    pub fn generate(algorithm: KeyAlgorithm) -> Self {
+        let mut rng = rand::rngs::OsRng;
+        match algorithm {
+            KeyAlgorithm::K256 => Keypair::K256(k256::ecdsa::SigningKey::random(&mut rng)),
+            KeyAlgorithm::P256 => Keypair::P256(p256::ecdsa::SigningKey::random(&mut rng)),
+        }
+    }
+
+    /// Load a keypair from a 32-byte private key
+    pub fn from_private_key(algorithm: KeyAlgorithm, bytes: &[u8]) -> Result<Self> {
+        match algorithm {
+            KeyAlgorithm::K256 => k256::ecdsa::SigningKey::from_slice(bytes)
+                .map(Keypair::K256)
+                .map_err(|e| CryptoError::InvalidKey(e.to_string())),
+            KeyAlgorithm::P256 => p256::ecdsa::SigningKey::from_slice(bytes)
+                .map(Keypair::P256)
+                .map_err(|e| CryptoError::InvalidKey(e.to_string())),
+        }
+    }
+
+    /// Export the 32-byte private key
+    pub fn private_key_bytes(&self) -> Vec<u8> {
+        match self {
+            Keypair::K256(key) => key.to_bytes().to_vec(),
+            Keypair::P256(key) => key.to_bytes().to_vec(),
+        }
+    }
+
+    /// Curve of this keypair
+    pub fn algorithm(&self) -> KeyAlgorithm {
+        match self {
+            Keypair::K256(_) => KeyAlgorithm::K256,
+            Keypair::P256(_) => KeyAlgorithm::P256,
+        }
+    }
+
+    /// Public half of the keypair
+    pub fn public_key(&self) -> PublicKey {
+        match self {
+            Keypair::K256(key) => PublicKey::K256(*key.verifying_key()),
+            Keypair::P256(key) => PublicKey::P256(*key.verifying_key()),
+        }
+    }
+
+    /// `did:key` of the public key
+    pub fn did_key(&self) -> String {
+        self.public_key().to_did_key()
+    }
+
+    /// Sign `data` (hashed with SHA-256), returning a 64-byte signature
+    pub fn sign(&self, data: &[u8]) -> Vec<u8> {
+        match self {
+            Keypair::K256(key) => {
+                let sig: k256::ecdsa::Signature = key.sign(data);
+                sig.normalize_s().unwrap_or(sig).to_bytes().to_vec()
+            }
+            Keypair::P256(key) => {
+                let sig: p256::ecdsa::Signature = key.sign(data);
+                sig.normalize_s().unwrap_or(sig).to_bytes().to_vec()
+            }
+        }
+    }
+
+    /// Sign an already computed SHA-256 hash, returning a 64-byte signature
+    ///
+    /// Use this with [`UnsignedCommit::signing_hash`](crate::repo::UnsignedCommit::signing_hash).
+    pub fn sign_hash(&self, hash: &[u8; 32]) -> Result<Vec<u8>> {
+        match self {
+            Keypair::K256(key) => {
+                let sig: k256::ecdsa::Signature = key
+                    .sign_prehash(hash)
+                    .map_err(|e| CryptoError::Signing(e.to_string()))?;
+                Ok(sig.normalize_s().unwrap_or(sig).to_bytes().to_vec())
+            }
+            Keypair::P256(key) => {
+                let sig: p256::ecdsa::Signature = key
+                    .sign_prehash(hash)
+                    .map_err(|e| CryptoError::Signing(e.to_string()))?;
+                Ok(sig.normalize_s().unwrap_or(sig).to_bytes().to_vec())
+            }
+        }
+    }
+}
+
+impl std::fmt::Debug for Keypair {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        // Never print the private key
+        f.debug_struct("Keypair")
+            .field("algorithm", &self.algorithm())
+            .field("public_key", &self.did_key())
+            .finish()
+    }
+}
+
+/// Parse a compact K-256 signature, rejecting high-S values
+fn parse_k256_signature(signature: &[u8]) -> Result<k256::ecdsa::Signature> {
+    let sig = k256::ecdsa::Signature::from_slice(signature)
+        .map_err(|_| CryptoError::MalformedSignature)?;
+    if sig.normalize_s().is_some() {
+        return Err(CryptoError::InvalidSignature);
+    }
+    Ok(sig)
+}
+
+/// Parse a compact P-256 signature, rejecting high-S values
+fn parse_p256_signature(signature: &[u8]) -> Result<p256::ecdsa::Signature> {
+    let sig = p256::ecdsa::Signature::from_slice(signature)
+        .map_err(|_| CryptoError::MalformedSignature)?;
+    if sig.normalize_s().is_some() {
+        return Err(CryptoError::InvalidSignature);
+    }
+    Ok(sig)
+}
+
+/// Decode a base58btc multibase string (`z` prefix)
+fn decode_base58btc(multibase: &str) -> Result<Vec<u8>> {
+    let encoded = multibase
+        .strip_prefix('z')
+        .ok_or_else(|| CryptoError::InvalidMultibase(format!("expected base58btc ('z') prefix: {}", multibase)))?;
+
+    bs58::decode(encoded)
+        .into_vec()
+        .map_err(|e| CryptoError::InvalidMultibase(e.to_string()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use sha2::{Digest, Sha256};
+
+    // Test vectors from https://atproto.com/specs/cryptography
+    const K256_MULTIBASE: &str = "zQ3shqwJEJyMBsBXCWyCBpUBMqxcon9oHB7mCvx4sSpMdLJwc";
+    const P256_MULTIBASE: &str = "zDnaembgSGUhZULN2Caob4HLJPaxBh92N7rtH21TErzqf8HQo";
+    const K256_DID_KEY: &str = "did:key:zQ3shqwJEJyMBsBXCWyCBpUBMqxcon9oHB7mCvx4sSpMdLJwc";
+    const P256_DID_KEY: &str = "did:key:zDnaembgSGUhZULN2Caob4HLJPaxBh92N7rtH21TErzqf8HQo";
+
+    #[test]
+    fn test_multibase_round_trip() {
+        for multibase in [K256_MULTIBASE, P256_MULTIBASE] {
+            let key = PublicKey::from_multibase(multibase).unwrap();
+            assert_eq!(key.to_multibase(), multibase);
+        }
+
+        assert!(matches!(PublicKey::from_multibase(K256_MULTIBASE).unwrap(), PublicKey::K256(_)));
+        assert!(matches!(PublicKey::from_multibase(P256_MULTIBASE).unwrap(), PublicKey::P256(_)));
+    }
+
+    #[test]
+    fn test_did_key_round_trip() {
+        let key = PublicKey::from_did_key(K256_DID_KEY).unwrap();
+        assert_eq!(key.algorithm(), KeyAlgorithm::K256);
+        assert_eq!(key.to_did_key(), K256_DID_KEY);
+
+        let key = PublicKey::from_did_key(P256_DID_KEY).unwrap();
+        assert_eq!(key.algorithm(), KeyAlgorithm::P256);
+        assert_eq!(key.to_did_key(), P256_DID_KEY);
+
+        assert!(matches!(
+            PublicKey::from_did_key(K256_MULTIBASE),
+            Err(CryptoError::InvalidDidKey(_))
+        ));
+    }
+
+    #[test]
+    fn test_invalid_multibase() {
+        assert!(matches!(
+            PublicKey::from_multibase("Q3shqwJEJyMBsBXCWyCBpUBMqxcon9oHB7mCvx4sSpMdLJwc"),
+            Err(CryptoError::InvalidMultibase(_))
+        ));
+        assert!(matches!(
+            PublicKey::from_multibase("z11111"),
+            Err(CryptoError::UnsupportedKeyType(_))
+        ));
+    }
+
+    #[test]
+    fn test_verify_k256() {
+        let signing_key = k256::ecdsa::SigningKey::from_slice(&[7u8; 32]).unwrap();
+        let key = PublicKey::K256(*signing_key.verifying_key());
+
+        let sig: k256::ecdsa::Signature = signing_key.sign(b"hello");
+        assert_eq!(key.verify(b"hello", &sig.to_bytes()), Ok(()));
+        assert_eq!(key.verify(b"goodbye", &sig.to_bytes()), Err(CryptoError::InvalidSignature));
+        assert_eq!(key.verify(b"hello", &[0u8; 10]), Err(CryptoError::MalformedSignature));
+    }
+
+    #[test]
+    fn test_verify_rejects_high_s() {
+        let signing_key = p256::ecdsa::SigningKey::from_slice(&[7u8; 32]).unwrap();
+        let key = PublicKey::P256(*signing_key.verifying_key());
+
+        let sig: p256::ecdsa::Signature = signing_key.sign(b"hello");
+        let low_s = sig.normalize_s().unwrap_or(sig);
+        assert_eq!(key.verify(b"hello", &low_s.to_bytes()), Ok(()));
+
+        // Same signature with s negated is mathematically valid but not allowed
+        let (r, s) = low_s.split_scalars();
+        let high_s = p256::ecdsa::Signature::from_scalars(r, -*s).unwrap();
+        assert_eq!(key.verify(b"hello", &high_s.to_bytes()), Err(CryptoError::InvalidSignature));
+    }
+
+    #[test]
+    fn test_from_verification_method() {
+        let method = VerificationMethod {
+            id: "did:plc:abc#atproto_label".to_string(),
+            key_type: "Multikey".to_string(),
+            controller: "did:plc:abc".to_string(),
+            public_key_multibase: Some(K256_MULTIBASE.to_string()),
+        };
+        assert!(PublicKey::from_verification_method(&method).is_ok());
+
+        let unsupported = VerificationMethod {
+            key_type: "Ed25519VerificationKey2020".to_string(),
+            ..method
+        };
+        assert!(matches!(
+            PublicKey::from_verification_method(&unsupported),
+            Err(CryptoError::UnsupportedKeyType(_))
+        ));
+    }
+
+    #[test]
+    fn test_keypair_sign_and_verify() {
+        for algorithm in [KeyAlgorithm::K256, KeyAlgorithm::P256] {
+            let keypair = Keypair::generate(algorithm);
+            assert_eq!(keypair.algorithm(), algorithm);
+
+            // Many signatures so at least some would be high-S without normalization
+            for i in 0..32u8 {
+                let data = [i; 16];
+                let sig = keypair.sign(&data);
+                assert_eq!(sig.len(), 64);
+                assert_eq!(keypair.public_key().verify(&data, &sig), Ok(()));
+            }
+        }
+    }
+
+    #[test]
+    fn test_keypair_sign_hash() {
+        for algorithm in [KeyAlgorithm::K256, KeyAlgorithm::P256] {
+            let keypair = Keypair::generate(algorithm);
+            let hash: [u8; 32] = Sha256::digest(b"commit bytes").into();
+
+            let sig = keypair.sign_hash(&hash).unwrap();
+            assert_eq!(keypair.public_key().verify_hash(&hash, &sig), Ok(()));
+
+            // Signing the hash is the same as signing the data
+            assert_eq!(keypair.public_key().verify(b"commit bytes", &sig), Ok(()));
+        }
+    }
+
+    #[test]
+    fn test_keypair_private_key_round_trip() {
+        for algorithm in [KeyAlgorithm::K256, KeyAlgorithm::P256] {
+            let keypair = Keypair::generate(algorithm);
+            let restored = Keypair::from_private_key(algorithm, &keypair.private_key_bytes()).unwrap();
+            assert_eq!(restored.did_key(), keypair.did_key());
+        }
+
+        assert!(matches!(
+            Keypair::from_private_key(KeyAlgorithm::K256, &[0u8; 32]),
+            Err(CryptoError::InvalidKey(_))
+        ));
+    }
+
+    #[test]
+    fn test_keypair_debug_hides_private_key() {
+        let keypair = Keypair::from_private_key(KeyAlgorithm::K256, &[7u8; 32]).unwrap();
+        let debug = format!("{:?}", keypair);
+        assert!(debug.contains("did:key:zQ3s"));
+        assert!(!debug.contains("0707"));
+    }
+
+    #[test]
+    fn test_jwt_alg() {
+        assert_eq!(KeyAlgorithm::K256.jwt_alg(), "ES256K");
+        assert_eq!(KeyAlgorithm::P256.jwt_alg(), "ES256");
+    }
+}
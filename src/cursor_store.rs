@@ -0,0 +1,248 @@
+//! Cursor persistence for event stream consumers
+//!
+//! This module lets subscription consumers survive restarts without replaying
+//! or skipping events. A [`CursorStore`] holds the `seq` of the last event the
+//! consumer finished handling; the subscription client checkpoints to it
+//! periodically and resumes from it on startup.
+//!
+//! # Example
+//!
+//! ```no_run
+//! use atproto::cursor_store::{CheckpointConfig, FileCursorStore};
+//! use atproto::xrpc::XrpcRequest;
+//! use atproto::xrpc_subscription::SubscriptionClient;
+//! use std::sync::Arc;
+//!
+//! # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+//! let store = Arc::new(FileCursorStore::new("./firehose.cursor"));
+//!
+//! let client = SubscriptionClient::new("wss://bsky.network".to_string())
+//!     .with_cursor_store(store, CheckpointConfig::default());
+//!
+//! // Resumes from the stored cursor, if any
+//! let request = XrpcRequest::query("com.atproto.sync.subscribeRepos");
+//! let stream = client.subscribe_resilient(request).await?;
+//! # Ok(())
+//! # }
+//! ```
+
+use async_trait::async_trait;
+use parking_lot::RwLock;
+use std::path::PathBuf;
+use std::sync::Arc;
+use std::time::Duration;
+
+/// Error type for cursor store operations
+#[derive(Debug, thiserror::Error)]
+pub enum CursorStoreError {
+    #[error("IO error: {0}")]
+    Io(#[from] std::io::Error),
+
+    #[error("Invalid cursor: {0}")]
+    InvalidCursor(String),
+}
+
+/// Result type for cursor store operations
+pub type Result<T> = std::result::Result<T, CursorStoreError>;
+
+/// Storage for an event stream cursor
+///
+/// Implementations must be safe to share across tasks.
+#[async_trait]
+pub trait CursorStore: Send + Sync {
+    /// Loads the stored cursor, or None if nothing has been saved yet
+    async fn load_cursor(&self) -> Result<Option<i64>>;
+
+    /// Saves the cursor, replacing any previous value
+    async fn save_cursor(&self, cursor: i64) -> Result<()>;
+}
+
+/// How often the subscription client checkpoints its cursor
+///
+/// A checkpoint is written when either threshold is reached, whichever comes
+/// first.
+#[derive(Debug, Clone)]
+pub struct CheckpointConfig {
+    /// Checkpoint after this many events (0 = disabled)
+    pub every_events: u64,
+
+    /// Checkpoint once this much time has passed since the last checkpoint
+    pub every_interval: Duration,
+}
+
+impl Default for CheckpointConfig {
+    fn default() -> Self {
+        Self {
+            every_events: 1000,
+            every_interval: Duration::from_secs(10),
+        }
+    }
+}
+
+/// In-memory cursor store
+///
+/// Useful for testing, or for sharing a cursor between subscriptions within a
+/// single process.
+///
+/// # Example
+///
+/// ```
+/// use atproto::cursor_store::MemoryCursorStore;
+///
+/// let store = MemoryCursorStore::with_cursor(42);
+/// assert_eq!(store.cursor(), Some(42));
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct MemoryCursorStore {
+    cursor: Arc<RwLock<Option<i64>>>,
+}
+
+impl MemoryCursorStore {
+    /// Creates an empty store
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Creates a store holding an initial cursor
+    pub fn with_cursor(cursor: i64) -> Self {
+        Self {
+            cursor: Arc::new(RwLock::new(Some(cursor))),
+        }
+    }
+
+    /// Returns the current cursor
+    pub fn cursor(&self) -> Option<i64> {
+        *self.cursor.read()
+    }
+}
+
+#[async_trait]
+impl CursorStore for MemoryCursorStore {
+    async fn load_cursor(&self) -> Result<Option<i64>> {
+        Ok(self.cursor())
+    }
+
+    async fn save_cursor(&self, cursor: i64) -> Result<()> {
+        *self.cursor.write() = Some(cursor);
+        Ok(())
+    }
+}
+
+/// File-backed cursor store
+///
+/// Stores the cursor as a decimal string. Writes go to a temporary file that
+/// is then renamed over the target, so a crash mid-write never leaves a
+/// truncated cursor behind.
+#[derive(Debug, Clone)]
+pub struct FileCursorStore {
+    path: PathBuf,
+}
+
+impl FileCursorStore {
+    /// Creates a store backed by the file at `path`
+    ///
+    /// The file and its parent directory are created on first save.
+    pub fn new(path: impl Into<PathBuf>) -> Self {
+        Self { path: path.into() }
+    }
+
+    /// Returns the path of the cursor file
+    pub fn path(&self) -> &PathBuf {
+        &self.path
+    }
+}
+
+#[async_trait]
+impl CursorStore for FileCursorStore {
+    async fn load_cursor(&self) -> Result<Option<i64>> {
+        let contents = match tokio::fs::read_to_string(&self.path).await {
+            Ok(contents) => contents,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(None),
+            Err(e) => return Err(e.into()),
+        };
+
+        let trimmed = contents.trim();
+        if trimmed.is_empty() {
+            return Ok(None);
+        }
+
+        trimmed
+            .parse::<i64>()
+            .map(Some)
+            .map_err(|_| CursorStoreError::InvalidCursor(trimmed.to_string()))
+    }
+
+    async fn save_cursor(&self, cursor: i64) -> Result<()> {
+        if let Some(parent) = self.path.parent() {
+            if !parent.as_os_str().is_empty() {
+                tokio::fs::create_dir_all(parent).await?;
+            }
+        }
+
+        let mut tmp_path = self.path.clone().into_os_string();
+        tmp_path.push(".tmp");
+
+        tokio::fs::write(&tmp_path, cursor.to_string()).await?;
+        tokio::fs::rename(&tmp_path, &self.path).await?;
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn temp_cursor_path(name: &str) -> PathBuf {
+        std::env::temp_dir()
+            .join(format!("atproto-cursor-test-{}-{}", std::process::id(), name))
+            .join("cursor")
+    }
+
+    #[tokio::test]
+    async fn test_memory_store() {
+        let store = MemoryCursorStore::new();
+        assert_eq!(store.load_cursor().await.unwrap(), None);
+
+        store.save_cursor(100).await.unwrap();
+        assert_eq!(store.load_cursor().await.unwrap(), Some(100));
+
+        // Clones share the same cursor
+        let clone = store.clone();
+        clone.save_cursor(200).await.unwrap();
+        assert_eq!(store.cursor(), Some(200));
+    }
+
+    #[tokio::test]
+    async fn test_file_store_round_trip() {
+        let path = temp_cursor_path("round-trip");
+        let _ = std::fs::remove_dir_all(path.parent().unwrap());
+
+        let store = FileCursorStore::new(&path);
+        assert_eq!(store.load_cursor().await.unwrap(), None);
+
+        store.save_cursor(123456789).await.unwrap();
+        assert_eq!(store.load_cursor().await.unwrap(), Some(123456789));
+
+        // A fresh store over the same file sees the saved cursor
+        let reopened = FileCursorStore::new(&path);
+        assert_eq!(reopened.load_cursor().await.unwrap(), Some(123456789));
+
+        std::fs::remove_dir_all(path.parent().unwrap()).unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_file_store_invalid_contents() {
+        let path = temp_cursor_path("invalid");
+        std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+        std::fs::write(&path, "not-a-number").unwrap();
+
+        let store = FileCursorStore::new(&path);
+        assert!(matches!(
+            store.load_cursor().await,
+            Err(CursorStoreError::InvalidCursor(_))
+        ));
+
+        std::fs::remove_dir_all(path.parent().unwrap()).unwrap();
+    }
+}
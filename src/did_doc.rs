@@ -1,7 +1,8 @@
 //! DID Document types and resolution
 //!
 //! This module provides types and utilities for working with DID (Decentralized Identifier)
-//! documents in ATProto. It supports both did:plc and did:web methods.
+//! documents in ATProto. It supports the did:plc, did:web and did:key methods, and
+//! custom methods through [`DidMethodResolver`].
 //!
 //! ## DID Document Structure
 //!
@@ -15,7 +16,11 @@
 //! - Spec: https://atproto.com/specs/did
 //! - Identity Guide: https://atproto.com/guides/identity
 
+use crate::crypto::PublicKey;
+use async_trait::async_trait;
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::sync::Arc;
 use thiserror::Error;
 
 /// Error types for DID document operations
@@ -134,6 +139,15 @@ impl DidDocument {
             .iter()
             .find(|vm| vm.id.ends_with("#atproto"))
     }
+
+    /// Get the label signing key of a labeler
+    ///
+    /// Searches for a verification method with id ending in `#atproto_label`.
+    pub fn get_label_key(&self) -> Option<&VerificationMethod> {
+        self.verification_method
+            .iter()
+            .find(|vm| vm.id.ends_with("#atproto_label"))
+    }
 }
 
 /// A service endpoint in a DID document
@@ -178,15 +192,28 @@ pub struct VerificationMethod {
     pub public_key_multibase: Option<String>,
 }
 
+/// Resolves DIDs of a single method to documents
+///
+/// Implement this to plug custom DID methods into [`DidResolver`].
+#[async_trait]
+pub trait DidMethodResolver: Send + Sync {
+    /// The method name, e.g. "plc" for `did:plc:...`
+    fn method(&self) -> &str;
+
+    /// Resolve a DID of this method to its document
+    async fn resolve(&self, did: &str) -> Result<DidDocument, DidDocError>;
+}
+
 /// Resolver for fetching and parsing DID documents
 ///
-/// This handles resolution of both did:plc and did:web DIDs.
+/// Supports did:plc, did:web and did:key out of the box. Other methods can be
+/// added with [`DidResolver::with_method`].
 pub struct DidResolver {
-    /// HTTP client for fetching DID documents
-    client: reqwest::Client,
-
     /// PLC directory URL (for did:plc resolution)
     plc_directory: String,
+
+    /// Resolvers by DID method name
+    methods: HashMap<String, Arc<dyn DidMethodResolver>>,
 }
 
 impl DidResolver {
@@ -196,10 +223,14 @@ impl DidResolver {
     ///
     /// * `plc_directory` - URL of the PLC directory server (e.g., "https://plc.directory")
     pub fn new(plc_directory: String) -> Self {
+        let client = reqwest::Client::new();
         Self {
-            client: reqwest::Client::new(),
-            plc_directory,
+            plc_directory: plc_directory.clone(),
+            methods: HashMap::new(),
         }
+        .with_method(PlcResolver::new(client.clone(), plc_directory))
+        .with_method(WebResolver::new(client))
+        .with_method(KeyResolver)
     }
 
     /// Create a resolver with the default PLC directory
@@ -207,6 +238,24 @@ impl DidResolver {
         Self::new("https://plc.directory".to_string())
     }
 
+    /// URL of the PLC directory used for did:plc
+    pub fn plc_directory(&self) -> &str {
+        &self.plc_directory
+    }
+
+    /// Register a resolver for a DID method, replacing any existing one
+    pub fn with_method(mut self, resolver: impl DidMethodResolver + 'static) -> Self {
+        self.methods.insert(resolver.method().to_string(), Arc::new(resolver));
+        self
+    }
+
+    /// Fetch did:web documents for `localhost` and `127.0.0.1` over plain HTTP
+    ///
+    /// Intended for local test stacks such as `did:web:localhost%3A3000`.
+    pub fn with_insecure_localhost(self) -> Self {
+        self.with_method(WebResolver::new(reqwest::Client::new()).with_insecure_localhost())
+    }
+
     /// Resolve a DID to its document
     ///
     /// # Arguments
@@ -215,83 +264,212 @@ impl DidResolver {
     ///
     /// # Errors
     ///
-    /// Returns an error if the DID cannot be resolved or parsed.
+    /// Returns an error if the DID cannot be resolved or parsed, or if the
+    /// resolved document is for a different DID.
     pub async fn resolve(&self, did: &str) -> Result<DidDocument, DidDocError> {
-        if did.starts_with("did:plc:") {
-            self.resolve_plc(did).await
-        } else if did.starts_with("did:web:") {
-            self.resolve_web(did).await
-        } else {
-            Err(DidDocError::InvalidDocument(format!(
-                "Unsupported DID method: {}",
-                did
-            )))
+        let method = did
+            .strip_prefix("did:")
+            .and_then(|rest| rest.split_once(':'))
+            .map(|(method, _)| method)
+            .ok_or_else(|| DidDocError::InvalidDocument(format!("Invalid DID: {}", did)))?;
+
+        let resolver = self.methods.get(method).ok_or_else(|| {
+            DidDocError::InvalidDocument(format!("Unsupported DID method: {}", did))
+        })?;
+
+        let doc = resolver.resolve(did).await?;
+        if doc.id != did {
+            return Err(DidDocError::InvalidDocument(format!(
+                "Document id {} does not match {}",
+                doc.id, did
+            )));
         }
+
+        Ok(doc)
+    }
+}
+
+/// did:plc resolution via a PLC directory
+pub struct PlcResolver {
+    client: reqwest::Client,
+    plc_directory: String,
+}
+
+impl PlcResolver {
+    /// Create a resolver for a PLC directory (e.g., "https://plc.directory")
+    pub fn new(client: reqwest::Client, plc_directory: String) -> Self {
+        Self { client, plc_directory }
+    }
+}
+
+#[async_trait]
+impl DidMethodResolver for PlcResolver {
+    fn method(&self) -> &str {
+        "plc"
     }
 
-    /// Resolve a did:plc DID
-    ///
     /// Fetches the DID document from the PLC directory.
-    async fn resolve_plc(&self, did: &str) -> Result<DidDocument, DidDocError> {
+    async fn resolve(&self, did: &str) -> Result<DidDocument, DidDocError> {
         let url = format!("{}/{}", self.plc_directory, did);
+        fetch_document(&self.client, &url, "PLC directory").await
+    }
+}
 
-        let response = self
-            .client
-            .get(&url)
-            .send()
-            .await
-            .map_err(|e| DidDocError::InvalidDocument(format!("HTTP error: {}", e)))?;
+/// did:web resolution
+pub struct WebResolver {
+    client: reqwest::Client,
+    insecure_localhost: bool,
+}
 
-        if !response.status().is_success() {
-            return Err(DidDocError::InvalidDocument(format!(
-                "HTTP {} from PLC directory",
-                response.status()
-            )));
+impl WebResolver {
+    /// Create a did:web resolver that only uses HTTPS
+    pub fn new(client: reqwest::Client) -> Self {
+        Self {
+            client,
+            insecure_localhost: false,
         }
+    }
 
-        let json = response
-            .text()
-            .await
-            .map_err(|e| DidDocError::InvalidDocument(format!("Failed to read response: {}", e)))?;
+    /// Use plain HTTP for `localhost` and `127.0.0.1` hosts
+    pub fn with_insecure_localhost(mut self) -> Self {
+        self.insecure_localhost = true;
+        self
+    }
+}
 
-        DidDocument::from_json(&json)
+#[async_trait]
+impl DidMethodResolver for WebResolver {
+    fn method(&self) -> &str {
+        "web"
     }
 
-    /// Resolve a did:web DID
-    ///
-    /// Fetches the DID document from the web server at /.well-known/did.json
-    async fn resolve_web(&self, did: &str) -> Result<DidDocument, DidDocError> {
-        // Extract domain from did:web:example.com
-        let domain = did
-            .strip_prefix("did:web:")
-            .ok_or_else(|| DidDocError::InvalidDocument("Invalid did:web format".to_string()))?;
-
-        // did:web uses /.well-known/did.json
-        let url = format!("https://{}/.well-known/did.json", domain);
-
-        let response = self
-            .client
-            .get(&url)
-            .send()
-            .await
-            .map_err(|e| DidDocError::InvalidDocument(format!("HTTP error: {}", e)))?;
-
-        if !response.status().is_success() {
-            return Err(DidDocError::InvalidDocument(format!(
-                "HTTP {} from did:web server",
-                response.status()
-            )));
-        }
+    /// Fetches the DID document from the URL given by [`did_web_url`].
+    async fn resolve(&self, did: &str) -> Result<DidDocument, DidDocError> {
+        let url = did_web_url(did, self.insecure_localhost)?;
+        fetch_document(&self.client, &url, "did:web server").await
+    }
+}
 
-        let json = response
-            .text()
-            .await
-            .map_err(|e| DidDocError::InvalidDocument(format!("Failed to read response: {}", e)))?;
+/// did:key resolution
+///
+/// The document is derived from the key itself: a single `#atproto` Multikey
+/// verification method.
+pub struct KeyResolver;
+
+#[async_trait]
+impl DidMethodResolver for KeyResolver {
+    fn method(&self) -> &str {
+        "key"
+    }
 
-        DidDocument::from_json(&json)
+    async fn resolve(&self, did: &str) -> Result<DidDocument, DidDocError> {
+        let key = PublicKey::from_did_key(did)
+            .map_err(|e| DidDocError::InvalidDocument(format!("Invalid did:key: {}", e)))?;
+
+        Ok(DidDocument {
+            context: Some(serde_json::json!([
+                "https://www.w3.org/ns/did/v1",
+                "https://w3id.org/security/multikey/v1",
+            ])),
+            id: did.to_string(),
+            also_known_as: Vec::new(),
+            service: Vec::new(),
+            verification_method: vec![VerificationMethod {
+                id: format!("{}#atproto", did),
+                key_type: "Multikey".to_string(),
+                controller: did.to_string(),
+                public_key_multibase: Some(key.to_multibase()),
+            }],
+        })
     }
 }
 
+/// Get the URL of a did:web document
+///
+/// The first segment is the host, with a percent-encoded port
+/// (`localhost%3A3000`). Further colon-separated segments form a path, in which
+/// case the document is at `{path}/did.json` instead of `/.well-known/did.json`.
+///
+/// With `insecure_localhost`, `localhost` and `127.0.0.1` use `http://`.
+///
+/// # Examples
+///
+/// ```
+/// use atproto::did_doc::did_web_url;
+///
+/// assert_eq!(
+///     did_web_url("did:web:example.com", false).unwrap(),
+///     "https://example.com/.well-known/did.json"
+/// );
+/// assert_eq!(
+///     did_web_url("did:web:localhost%3A3000", true).unwrap(),
+///     "http://localhost:3000/.well-known/did.json"
+/// );
+/// assert_eq!(
+///     did_web_url("did:web:example.com:user:alice", false).unwrap(),
+///     "https://example.com/user/alice/did.json"
+/// );
+/// ```
+pub fn did_web_url(did: &str, insecure_localhost: bool) -> Result<String, DidDocError> {
+    let identifier = did
+        .strip_prefix("did:web:")
+        .ok_or_else(|| DidDocError::InvalidDocument("Invalid did:web format".to_string()))?;
+
+    let segments = identifier
+        .split(':')
+        .map(|segment| {
+            urlencoding::decode(segment)
+                .map(|s| s.into_owned())
+                .map_err(|_| DidDocError::InvalidDocument(format!("Invalid did:web encoding: {}", did)))
+        })
+        .collect::<Result<Vec<String>, DidDocError>>()?;
+
+    let (host, path) = segments.split_first().expect("split yields at least one segment");
+    if host.is_empty()
+        || host.contains(['/', '?', '#', '@'])
+        || path.iter().any(|s| s.is_empty() || s.contains(['/', '?', '#']))
+    {
+        return Err(DidDocError::InvalidDocument(format!("Invalid did:web: {}", did)));
+    }
+
+    let hostname = host.rsplit_once(':').map_or(host.as_str(), |(name, _)| name);
+    let scheme = if insecure_localhost && (hostname == "localhost" || hostname == "127.0.0.1") {
+        "http"
+    } else {
+        "https"
+    };
+
+    if path.is_empty() {
+        Ok(format!("{}://{}/.well-known/did.json", scheme, host))
+    } else {
+        Ok(format!("{}://{}/{}/did.json", scheme, host, path.join("/")))
+    }
+}
+
+/// Fetch and parse a DID document
+async fn fetch_document(client: &reqwest::Client, url: &str, source: &str) -> Result<DidDocument, DidDocError> {
+    let response = client
+        .get(url)
+        .send()
+        .await
+        .map_err(|e| DidDocError::InvalidDocument(format!("HTTP error: {}", e)))?;
+
+    if !response.status().is_success() {
+        return Err(DidDocError::InvalidDocument(format!(
+            "HTTP {} from {}",
+            response.status(),
+            source
+        )));
+    }
+
+    let json = response
+        .text()
+        .await
+        .map_err(|e| DidDocError::InvalidDocument(format!("Failed to read response: {}", e)))?;
+
+    DidDocument::from_json(&json)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -383,6 +561,25 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_get_label_key() {
+        let mut doc = DidDocument::from_json(EXAMPLE_DID_DOC).unwrap();
+        assert!(doc.get_label_key().is_none());
+
+        doc.verification_method.push(VerificationMethod {
+            id: "did:plc:ewvi7nxzyoun6zhxrhs64oiz#atproto_label".to_string(),
+            key_type: "Multikey".to_string(),
+            controller: "did:plc:ewvi7nxzyoun6zhxrhs64oiz".to_string(),
+            public_key_multibase: Some("zQ3shqwJEJyMBsBXCWyCBpUBMqxcon9oHB7mCvx4sSpMdLJwc".to_string()),
+        });
+
+        let key = doc.get_label_key().unwrap();
+        assert_eq!(key.id, "did:plc:ewvi7nxzyoun6zhxrhs64oiz#atproto_label");
+
+        // The repo signing key is still found separately
+        assert!(doc.get_signing_key().unwrap().id.ends_with("#atproto"));
+    }
+
     #[test]
     fn test_service_serialization() {
         let service = Service {
@@ -447,6 +644,89 @@ mod tests {
         assert_eq!(resolver.plc_directory, "https://plc.directory");
     }
 
-    // Note: Network tests would require mocking or actual network access
-    // These are omitted for now but could be added with proper test infrastructure
+    #[test]
+    fn test_did_web_url() {
+        assert_eq!(
+            did_web_url("did:web:example.com", false).unwrap(),
+            "https://example.com/.well-known/did.json"
+        );
+        assert_eq!(
+            did_web_url("did:web:localhost%3A3000", false).unwrap(),
+            "https://localhost:3000/.well-known/did.json"
+        );
+        assert_eq!(
+            did_web_url("did:web:localhost%3A3000", true).unwrap(),
+            "http://localhost:3000/.well-known/did.json"
+        );
+        assert_eq!(
+            did_web_url("did:web:127.0.0.1%3A2583:users:alice", true).unwrap(),
+            "http://127.0.0.1:2583/users/alice/did.json"
+        );
+        // Only local hosts may use http
+        assert_eq!(
+            did_web_url("did:web:example.com%3A8080", true).unwrap(),
+            "https://example.com:8080/.well-known/did.json"
+        );
+
+        assert!(did_web_url("did:plc:abc", false).is_err());
+        assert!(did_web_url("did:web:", false).is_err());
+        assert!(did_web_url("did:web:example.com::alice", false).is_err());
+        assert!(did_web_url("did:web:evil.com%2Fpath", false).is_err());
+    }
+
+    #[tokio::test]
+    async fn test_resolve_did_key() {
+        let did = "did:key:zQ3shqwJEJyMBsBXCWyCBpUBMqxcon9oHB7mCvx4sSpMdLJwc";
+        let resolver = DidResolver::with_default_plc();
+
+        let doc = resolver.resolve(did).await.unwrap();
+        assert_eq!(doc.id, did);
+        let key = doc.get_signing_key().unwrap();
+        assert_eq!(key.id, format!("{}#atproto", did));
+        assert_eq!(
+            key.public_key_multibase.as_deref(),
+            Some("zQ3shqwJEJyMBsBXCWyCBpUBMqxcon9oHB7mCvx4sSpMdLJwc")
+        );
+
+        assert!(resolver.resolve("did:key:z123").await.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_resolve_did_web_localhost() {
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let port = listener.local_addr().unwrap().port();
+        let did = format!("did:web:localhost%3A{}", port);
+
+        let body = format!(r#"{{"id": "{}", "alsoKnownAs": ["at://alice.test"]}}"#, did);
+        crate::test_support::stub_http_server(listener, vec![("/.well-known/did.json".to_string(), body)]);
+
+        let resolver = DidResolver::with_default_plc().with_insecure_localhost();
+        let doc = resolver.resolve(&did).await.unwrap();
+        assert_eq!(doc.get_handle().as_deref(), Some("alice.test"));
+
+        // Without test mode the resolver insists on https
+        assert!(DidResolver::with_default_plc().resolve(&did).await.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_custom_method() {
+        struct ExampleResolver;
+
+        #[async_trait]
+        impl DidMethodResolver for ExampleResolver {
+            fn method(&self) -> &str {
+                "example"
+            }
+
+            async fn resolve(&self, did: &str) -> Result<DidDocument, DidDocError> {
+                DidDocument::from_json(&format!(r#"{{"id": "{}"}}"#, did))
+            }
+        }
+
+        let resolver = DidResolver::with_default_plc();
+        assert!(resolver.resolve("did:example:123").await.is_err());
+
+        let resolver = resolver.with_method(ExampleResolver);
+        assert_eq!(resolver.resolve("did:example:123").await.unwrap().id, "did:example:123");
+    }
 }
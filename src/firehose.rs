@@ -0,0 +1,1132 @@
+//! Typed firehose events
+//!
+//! This module decodes the message bodies of the `com.atproto.sync.subscribeRepos`
+//! event stream into strongly typed values, so consumers don't have to deal with
+//! raw DAG-CBOR themselves.
+//!
+//! # Message Types
+//!
+//! - `#commit` - Repository commit with ops and a CAR diff of changed blocks
+//! - `#sync` - Repository state reset to a new commit
+//! - `#identity` - Handle, signing key, or PDS change for an account
+//! - `#account` - Account hosting status change
+//! - `#info` - Informational message from the server (e.g. `OutdatedCursor`)
+//!
+//! # Record Streams
+//!
+//! [`subscribe_records`] goes one step further and expands each commit into
+//! per-record events, with the record body decoded from the commit's blocks.
+//!
+//! ```no_run
+//! use atproto::client::com::atproto::sync::subscribe_repos;
+//! use atproto::firehose::{self, RecordFilter, RepoOpAction};
+//! use atproto::xrpc_subscription::SubscriptionClient;
+//! use futures::StreamExt;
+//!
+//! # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+//! let client = SubscriptionClient::new("wss://bsky.network".to_string());
+//! let params = subscribe_repos::QueryParams { cursor: None };
+//! let filter = RecordFilter::new()
+//!     .with_collection("app.bsky.feed.post")
+//!     .with_actions(&[RepoOpAction::Create]);
+//!
+//! let mut posts = firehose::subscribe_records(&client, params, filter).await?;
+//! while let Some(post) = posts.next().await {
+//!     let post = post?;
+//!     println!("{}: {:?}", post.uri, post.record.as_ref().and_then(|r| r.get("text")));
+//! }
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! # Example
+//!
+//! ```no_run
+//! use atproto::client::com::atproto::sync::subscribe_repos;
+//! use atproto::firehose::{self, RepoEvent};
+//! use atproto::xrpc_subscription::SubscriptionClient;
+//! use futures::StreamExt;
+//!
+//! # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+//! let client = SubscriptionClient::new("wss://bsky.network".to_string());
+//! let params = subscribe_repos::QueryParams { cursor: None };
+//!
+//! let mut events = firehose::subscribe_repos(&client, params).await?;
+//! while let Some(event) = events.next().await {
+//!     if let RepoEvent::Commit(commit) = event? {
+//!         println!("{} ops from {}", commit.ops.len(), commit.repo);
+//!     }
+//! }
+//! # Ok(())
+//! # }
+//! ```
+
+use crate::car::{CarError, CarReader};
+use crate::client::com::atproto::sync::subscribe_repos as subscribe_repos_lexicon;
+use crate::repo::SignedCommit;
+use crate::syntax::AtUri;
+use crate::types::Did;
+use crate::xrpc::XrpcError;
+use crate::xrpc_subscription::{SubscriptionClient, SubscriptionEvent, SubscriptionResult};
+use futures::stream::{self, Stream, StreamExt};
+use libipld::cid::Cid;
+use libipld::codec::Codec;
+use libipld::Ipld;
+use std::collections::BTreeMap;
+use std::pin::Pin;
+use thiserror::Error;
+
+/// Error types for firehose decoding
+#[derive(Error, Debug)]
+pub enum FirehoseError {
+    #[error("CBOR error: {0}")]
+    Cbor(String),
+
+    #[error("CAR error: {0}")]
+    Car(#[from] CarError),
+
+    #[error("Invalid message: {0}")]
+    InvalidMessage(String),
+}
+
+/// Result type for firehose operations
+pub type Result<T> = std::result::Result<T, FirehoseError>;
+
+/// Stream of typed firehose events
+pub type RepoEventStream = Pin<Box<dyn Stream<Item = SubscriptionResult<RepoEvent>> + Send>>;
+
+/// Stream of record-level firehose events
+pub type RecordEventStream = Pin<Box<dyn Stream<Item = SubscriptionResult<RecordEvent>> + Send>>;
+
+/// Blocks carried in a commit or sync message
+///
+/// The `blocks` field of these messages is an embedded CAR file; this holds
+/// its header roots and every block keyed by CID.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct CommitBlocks {
+    /// Root CIDs from the CAR header (the commit CID comes first)
+    pub roots: Vec<Cid>,
+
+    /// Block data by CID
+    pub blocks: BTreeMap<Cid, Vec<u8>>,
+}
+
+impl CommitBlocks {
+    /// Parse an embedded CAR file
+    ///
+    /// An empty byte string (as sent for `tooBig` commits) yields no blocks.
+    pub fn from_car_bytes(bytes: &[u8]) -> Result<Self> {
+        if bytes.is_empty() {
+            return Ok(Self::default());
+        }
+
+        let reader = CarReader::new(bytes)?;
+        let roots = reader.roots().to_vec();
+
+        let mut blocks = BTreeMap::new();
+        for block in reader.blocks() {
+            let (cid, data) = block?;
+            blocks.insert(cid, data);
+        }
+
+        Ok(Self { roots, blocks })
+    }
+
+    /// Get a block by CID
+    pub fn get(&self, cid: &Cid) -> Option<&[u8]> {
+        self.blocks.get(cid).map(|v| v.as_slice())
+    }
+
+    /// Number of blocks
+    pub fn len(&self) -> usize {
+        self.blocks.len()
+    }
+
+    /// Check if there are no blocks
+    pub fn is_empty(&self) -> bool {
+        self.blocks.is_empty()
+    }
+}
+
+/// Action performed by a repository operation
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum RepoOpAction {
+    Create,
+    Update,
+    Delete,
+}
+
+impl RepoOpAction {
+    /// Get the lexicon string value
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            RepoOpAction::Create => "create",
+            RepoOpAction::Update => "update",
+            RepoOpAction::Delete => "delete",
+        }
+    }
+
+    /// Parse from the lexicon string value
+    pub fn parse(s: &str) -> Option<Self> {
+        match s {
+            "create" => Some(RepoOpAction::Create),
+            "update" => Some(RepoOpAction::Update),
+            "delete" => Some(RepoOpAction::Delete),
+            _ => None,
+        }
+    }
+}
+
+/// A single record mutation within a commit (`#repoOp`)
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RepoOp {
+    /// Operation performed on the record
+    pub action: RepoOpAction,
+
+    /// Repository path (`collection/rkey`)
+    pub path: String,
+
+    /// CID of the new record (None for deletes)
+    pub cid: Option<Cid>,
+
+    /// CID of the previous record (for updates and deletes)
+    pub prev: Option<Cid>,
+}
+
+impl RepoOp {
+    /// Collection NSID portion of the path
+    pub fn collection(&self) -> &str {
+        self.path.split_once('/').map(|(c, _)| c).unwrap_or(&self.path)
+    }
+
+    /// Record key portion of the path
+    pub fn rkey(&self) -> &str {
+        self.path.split_once('/').map(|(_, r)| r).unwrap_or("")
+    }
+
+    fn from_ipld(ipld: &Ipld) -> Result<Self> {
+        let map = as_map(ipld, "repoOp")?;
+
+        let action_str = get_string(map, "action")?;
+        let action = RepoOpAction::parse(&action_str)
+            .ok_or_else(|| FirehoseError::InvalidMessage(format!("Unknown op action: {}", action_str)))?;
+
+        Ok(Self {
+            action,
+            path: get_string(map, "path")?,
+            cid: get_optional_link(map, "cid")?,
+            prev: get_optional_link(map, "prev")?,
+        })
+    }
+}
+
+/// Repository commit event (`#commit`)
+#[derive(Debug, Clone)]
+pub struct Commit {
+    /// Stream sequence number
+    pub seq: i64,
+
+    /// Deprecated, always false
+    pub rebase: bool,
+
+    /// Deprecated; the commit was too large and `blocks`/`ops` may be incomplete
+    pub too_big: bool,
+
+    /// Repository this commit belongs to
+    pub repo: Did,
+
+    /// CID of the commit object
+    pub commit: Cid,
+
+    /// Revision (TID) of this commit
+    pub rev: String,
+
+    /// Revision of the previous commit from this repo, if any
+    pub since: Option<String>,
+
+    /// CAR diff of changed blocks
+    pub blocks: CommitBlocks,
+
+    /// Record mutations in this commit
+    pub ops: Vec<RepoOp>,
+
+    /// Deprecated list of new blob CIDs
+    pub blobs: Vec<Cid>,
+
+    /// MST root CID of the previous commit
+    pub prev_data: Option<Cid>,
+
+    /// Timestamp the event was broadcast
+    pub time: String,
+}
+
+impl Commit {
+    fn from_ipld(ipld: &Ipld) -> Result<Self> {
+        let map = as_map(ipld, "commit")?;
+
+        let ops = match map.get("ops") {
+            Some(Ipld::List(list)) => list.iter().map(RepoOp::from_ipld).collect::<Result<Vec<_>>>()?,
+            _ => return Err(missing_field("ops")),
+        };
+
+        let blobs = match map.get("blobs") {
+            Some(Ipld::List(list)) => list
+                .iter()
+                .map(|item| match item {
+                    Ipld::Link(cid) => Ok(*cid),
+                    _ => Err(FirehoseError::InvalidMessage("Blob is not a CID link".to_string())),
+                })
+                .collect::<Result<Vec<_>>>()?,
+            None => Vec::new(),
+            _ => return Err(invalid_field("blobs")),
+        };
+
+        Ok(Self {
+            seq: get_int(map, "seq")?,
+            rebase: get_optional_bool(map, "rebase")?.unwrap_or(false),
+            too_big: get_optional_bool(map, "tooBig")?.unwrap_or(false),
+            repo: get_did(map, "repo")?,
+            commit: get_link(map, "commit")?,
+            rev: get_string(map, "rev")?,
+            since: get_optional_string(map, "since")?,
+            blocks: CommitBlocks::from_car_bytes(&get_bytes(map, "blocks")?)?,
+            ops,
+            blobs,
+            prev_data: get_optional_link(map, "prevData")?,
+            time: get_string(map, "time")?,
+        })
+    }
+
+    /// Decode the signed commit object from `blocks`
+    ///
+    /// Pass the result to [`verify_commit`](crate::repo::verify_commit) with the
+    /// DID document of `repo` to check the commit signature.
+    pub fn signed_commit(&self) -> Result<SignedCommit> {
+        let block = self.blocks.get(&self.commit).ok_or_else(|| {
+            FirehoseError::InvalidMessage(format!("Commit block {} not in blocks", self.commit))
+        })?;
+        SignedCommit::from_cbor(block).map_err(|e| FirehoseError::InvalidMessage(e.to_string()))
+    }
+}
+
+/// Repository sync event (`#sync`)
+#[derive(Debug, Clone)]
+pub struct Sync {
+    /// Stream sequence number
+    pub seq: i64,
+
+    /// Account this repository belongs to
+    pub did: Did,
+
+    /// CAR containing the commit block
+    pub blocks: CommitBlocks,
+
+    /// Revision of the commit
+    pub rev: String,
+
+    /// Timestamp the event was broadcast
+    pub time: String,
+}
+
+impl Sync {
+    fn from_ipld(ipld: &Ipld) -> Result<Self> {
+        let map = as_map(ipld, "sync")?;
+
+        Ok(Self {
+            seq: get_int(map, "seq")?,
+            did: get_did(map, "did")?,
+            blocks: CommitBlocks::from_car_bytes(&get_bytes(map, "blocks")?)?,
+            rev: get_string(map, "rev")?,
+            time: get_string(map, "time")?,
+        })
+    }
+}
+
+/// Identity change event (`#identity`)
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Identity {
+    /// Stream sequence number
+    pub seq: i64,
+
+    /// Account whose identity changed
+    pub did: Did,
+
+    /// Timestamp the event was broadcast
+    pub time: String,
+
+    /// Current handle, if provided
+    pub handle: Option<String>,
+}
+
+impl Identity {
+    fn from_ipld(ipld: &Ipld) -> Result<Self> {
+        let map = as_map(ipld, "identity")?;
+
+        Ok(Self {
+            seq: get_int(map, "seq")?,
+            did: get_did(map, "did")?,
+            time: get_string(map, "time")?,
+            handle: get_optional_string(map, "handle")?,
+        })
+    }
+}
+
+/// Account status event (`#account`)
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Account {
+    /// Stream sequence number
+    pub seq: i64,
+
+    /// Account whose status changed
+    pub did: Did,
+
+    /// Timestamp the event was broadcast
+    pub time: String,
+
+    /// Whether the account is active on the host
+    pub active: bool,
+
+    /// Reason the account is inactive (e.g. "takendown", "deactivated")
+    pub status: Option<String>,
+}
+
+impl Account {
+    fn from_ipld(ipld: &Ipld) -> Result<Self> {
+        let map = as_map(ipld, "account")?;
+
+        Ok(Self {
+            seq: get_int(map, "seq")?,
+            did: get_did(map, "did")?,
+            time: get_string(map, "time")?,
+            active: get_optional_bool(map, "active")?
+                .ok_or_else(|| missing_field("active"))?,
+            status: get_optional_string(map, "status")?,
+        })
+    }
+}
+
+/// Informational event (`#info`)
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Info {
+    /// Info name (e.g. "OutdatedCursor")
+    pub name: String,
+
+    /// Human-readable message
+    pub message: Option<String>,
+}
+
+impl Info {
+    pub(crate) fn from_ipld(ipld: &Ipld) -> Result<Self> {
+        let map = as_map(ipld, "info")?;
+
+        Ok(Self {
+            name: get_string(map, "name")?,
+            message: get_optional_string(map, "message")?,
+        })
+    }
+}
+
+/// A decoded `subscribeRepos` message
+#[derive(Debug, Clone)]
+pub enum RepoEvent {
+    Commit(Box<Commit>),
+    Sync(Sync),
+    Identity(Identity),
+    Account(Account),
+    Info(Info),
+    /// Message type not known to this client
+    Unknown {
+        message_type: String,
+        body: Vec<u8>,
+    },
+}
+
+impl RepoEvent {
+    /// Decode a message body given its frame header type (e.g. "#commit")
+    pub fn decode(message_type: &str, body: &[u8]) -> Result<Self> {
+        let decode_body = || {
+            libipld_cbor::DagCborCodec
+                .decode::<Ipld>(body)
+                .map_err(|e| FirehoseError::Cbor(format!("Failed to decode message body: {}", e)))
+        };
+
+        match message_type {
+            "#commit" => Ok(RepoEvent::Commit(Box::new(Commit::from_ipld(&decode_body()?)?))),
+            "#sync" => Ok(RepoEvent::Sync(Sync::from_ipld(&decode_body()?)?)),
+            "#identity" => Ok(RepoEvent::Identity(Identity::from_ipld(&decode_body()?)?)),
+            "#account" => Ok(RepoEvent::Account(Account::from_ipld(&decode_body()?)?)),
+            "#info" => Ok(RepoEvent::Info(Info::from_ipld(&decode_body()?)?)),
+            _ => Ok(RepoEvent::Unknown {
+                message_type: message_type.to_string(),
+                body: body.to_vec(),
+            }),
+        }
+    }
+
+    /// Stream sequence number, for events that carry one
+    pub fn seq(&self) -> Option<i64> {
+        match self {
+            RepoEvent::Commit(c) => Some(c.seq),
+            RepoEvent::Sync(s) => Some(s.seq),
+            RepoEvent::Identity(i) => Some(i.seq),
+            RepoEvent::Account(a) => Some(a.seq),
+            RepoEvent::Info(_) | RepoEvent::Unknown { .. } => None,
+        }
+    }
+}
+
+/// Subscribe to `com.atproto.sync.subscribeRepos` and decode events
+///
+/// Error frames are surfaced as [`XrpcError::Xrpc`] and the stream ends when
+/// the connection closes.
+pub async fn subscribe_repos(
+    client: &SubscriptionClient,
+    params: subscribe_repos_lexicon::QueryParams,
+) -> SubscriptionResult<RepoEventStream> {
+    let stream = subscribe_repos_lexicon::subscribe_repos(client, params).await?;
+    Ok(repo_events(stream))
+}
+
+/// Convert a raw subscription stream into typed repo events
+///
+/// Works with both [`SubscriptionClient::subscribe`] and
+/// [`SubscriptionClient::subscribe_resilient`] streams.
+pub fn repo_events<S>(stream: S) -> RepoEventStream
+where
+    S: Stream<Item = SubscriptionResult<SubscriptionEvent>> + Send + 'static,
+{
+    let events = stream
+        .take_while(|event| futures::future::ready(!matches!(event, Ok(SubscriptionEvent::Closed))))
+        .filter_map(|event| {
+            let event = match event {
+                Ok(SubscriptionEvent::Message { message_type, body }) => Some(
+                    RepoEvent::decode(&message_type, &body)
+                        .map_err(|e| XrpcError::Deserialization(e.to_string())),
+                ),
+                Ok(SubscriptionEvent::Error { error, message }) => Some(Err(XrpcError::Xrpc {
+                    error,
+                    message: message.unwrap_or_default(),
+                })),
+                // Reconnects are transparent to consumers of typed events
+                Ok(SubscriptionEvent::Closed)
+                | Ok(SubscriptionEvent::Reconnected { .. })
+                | Ok(SubscriptionEvent::ReconnectFailed { .. }) => None,
+                Ok(SubscriptionEvent::CheckpointFailed { cursor, error }) => Some(Err(XrpcError::Network(
+                    format!("Failed to save cursor {}: {}", cursor, error),
+                ))),
+                Err(e) => Some(Err(e)),
+            };
+            futures::future::ready(event)
+        });
+
+    Box::pin(events)
+}
+
+/// A single record change extracted from a `#commit` event
+#[derive(Debug, Clone)]
+pub struct RecordEvent {
+    /// Sequence number of the commit this change belongs to
+    ///
+    /// Events from [`crate::jetstream`] carry their `time_us` cursor here.
+    pub seq: i64,
+
+    /// Repository the record lives in
+    pub did: Did,
+
+    /// Collection NSID
+    pub collection: String,
+
+    /// Record key
+    pub rkey: String,
+
+    /// AT URI of the record
+    pub uri: AtUri,
+
+    /// Operation performed on the record
+    pub action: RepoOpAction,
+
+    /// CID of the new record (None for deletes)
+    pub cid: Option<Cid>,
+
+    /// Decoded record (None for deletes, or when the block was not included)
+    pub record: Option<serde_json::Value>,
+
+    /// Revision of the commit
+    pub rev: String,
+
+    /// Timestamp the commit was broadcast
+    pub time: String,
+}
+
+impl RecordEvent {
+    /// Expand a commit into per-record events matching `filter`
+    pub fn from_commit(commit: &Commit, filter: &RecordFilter) -> Result<Vec<Self>> {
+        let mut events = Vec::new();
+
+        for op in commit.ops.iter().filter(|op| filter.matches(op)) {
+            let collection = op.collection().to_string();
+            let rkey = op.rkey().to_string();
+
+            let uri = AtUri::from_parts(commit.repo.as_str(), collection.as_str(), Some(rkey.as_str()))
+                .map_err(|e| FirehoseError::InvalidMessage(format!("Invalid record path {}: {}", op.path, e)))?;
+
+            let record = match op.cid.as_ref().and_then(|cid| commit.blocks.get(cid)) {
+                Some(block) => Some(record_to_json(block)?),
+                None => None,
+            };
+
+            events.push(Self {
+                seq: commit.seq,
+                did: commit.repo.clone(),
+                collection,
+                rkey,
+                uri,
+                action: op.action,
+                cid: op.cid,
+                record,
+                rev: commit.rev.clone(),
+                time: commit.time.clone(),
+            });
+        }
+
+        Ok(events)
+    }
+}
+
+/// Filter for record-level firehose events
+///
+/// An empty filter matches every op.
+#[derive(Debug, Clone, Default)]
+pub struct RecordFilter {
+    /// Collection NSID prefixes to include
+    collections: Vec<String>,
+
+    /// Actions to include (None = all)
+    actions: Option<Vec<RepoOpAction>>,
+}
+
+impl RecordFilter {
+    /// Create a filter that matches every op
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Include collections starting with `prefix`
+    ///
+    /// An exact NSID (`app.bsky.feed.post`) matches that collection only; a
+    /// prefix ending in `.` (`app.bsky.feed.`) matches the whole namespace.
+    pub fn with_collection(mut self, prefix: impl Into<String>) -> Self {
+        self.collections.push(prefix.into());
+        self
+    }
+
+    /// Only include the given actions
+    pub fn with_actions(mut self, actions: &[RepoOpAction]) -> Self {
+        self.actions = Some(actions.to_vec());
+        self
+    }
+
+    /// Check whether an op passes this filter
+    pub fn matches(&self, op: &RepoOp) -> bool {
+        if let Some(ref actions) = self.actions {
+            if !actions.contains(&op.action) {
+                return false;
+            }
+        }
+
+        if self.collections.is_empty() {
+            return true;
+        }
+
+        let collection = op.collection();
+        self.collections.iter().any(|prefix| {
+            if prefix.ends_with('.') {
+                collection.starts_with(prefix.as_str())
+            } else {
+                collection == prefix
+            }
+        })
+    }
+}
+
+/// Subscribe to `com.atproto.sync.subscribeRepos` and yield individual record changes
+pub async fn subscribe_records(
+    client: &SubscriptionClient,
+    params: subscribe_repos_lexicon::QueryParams,
+    filter: RecordFilter,
+) -> SubscriptionResult<RecordEventStream> {
+    let events = subscribe_repos(client, params).await?;
+    Ok(record_events(events, filter))
+}
+
+/// Convert a typed repo event stream into record-level events
+///
+/// Non-commit events are dropped.
+pub fn record_events(events: RepoEventStream, filter: RecordFilter) -> RecordEventStream {
+    let records = events.flat_map(move |event| {
+        let items: Vec<SubscriptionResult<RecordEvent>> = match event {
+            Ok(RepoEvent::Commit(commit)) => match RecordEvent::from_commit(&commit, &filter) {
+                Ok(records) => records.into_iter().map(Ok).collect(),
+                Err(e) => vec![Err(XrpcError::Deserialization(e.to_string()))],
+            },
+            Ok(_) => Vec::new(),
+            Err(e) => vec![Err(e)],
+        };
+        stream::iter(items)
+    });
+
+    Box::pin(records)
+}
+
+/// Decode a DAG-CBOR record block into JSON
+///
+/// Uses the atproto JSON representation for CID links (`{"$link": ...}`) and
+/// bytes (`{"$bytes": ...}`).
+pub fn record_to_json(block: &[u8]) -> Result<serde_json::Value> {
+    let ipld: Ipld = libipld_cbor::DagCborCodec
+        .decode(block)
+        .map_err(|e| FirehoseError::Cbor(format!("Failed to decode record: {}", e)))?;
+    ipld_to_json(&ipld)
+}
+
+pub(crate) fn ipld_to_json(ipld: &Ipld) -> Result<serde_json::Value> {
+    use base64::{engine::general_purpose::STANDARD_NO_PAD, Engine};
+    use serde_json::Value;
+
+    Ok(match ipld {
+        Ipld::Null => Value::Null,
+        Ipld::Bool(b) => Value::Bool(*b),
+        Ipld::Integer(i) => {
+            let i = i64::try_from(*i)
+                .map_err(|_| FirehoseError::InvalidMessage(format!("Integer out of range: {}", i)))?;
+            Value::from(i)
+        }
+        Ipld::Float(f) => Value::from(*f),
+        Ipld::String(s) => Value::String(s.clone()),
+        Ipld::Bytes(b) => serde_json::json!({ "$bytes": STANDARD_NO_PAD.encode(b) }),
+        Ipld::Link(cid) => serde_json::json!({ "$link": cid.to_string() }),
+        Ipld::List(list) => Value::Array(list.iter().map(ipld_to_json).collect::<Result<Vec<_>>>()?),
+        Ipld::Map(map) => {
+            let mut obj = serde_json::Map::new();
+            for (k, v) in map {
+                obj.insert(k.clone(), ipld_to_json(v)?);
+            }
+            Value::Object(obj)
+        }
+    })
+}
+
+fn missing_field(field: &str) -> FirehoseError {
+    FirehoseError::InvalidMessage(format!("Missing field: {}", field))
+}
+
+fn invalid_field(field: &str) -> FirehoseError {
+    FirehoseError::InvalidMessage(format!("Invalid field: {}", field))
+}
+
+fn as_map<'a>(ipld: &'a Ipld, name: &str) -> Result<&'a BTreeMap<String, Ipld>> {
+    match ipld {
+        Ipld::Map(map) => Ok(map),
+        _ => Err(FirehoseError::InvalidMessage(format!("{} is not a map", name))),
+    }
+}
+
+fn get_int(map: &BTreeMap<String, Ipld>, field: &str) -> Result<i64> {
+    match map.get(field) {
+        Some(Ipld::Integer(i)) => i64::try_from(*i).map_err(|_| invalid_field(field)),
+        Some(_) => Err(invalid_field(field)),
+        None => Err(missing_field(field)),
+    }
+}
+
+fn get_string(map: &BTreeMap<String, Ipld>, field: &str) -> Result<String> {
+    get_optional_string(map, field)?.ok_or_else(|| missing_field(field))
+}
+
+fn get_optional_string(map: &BTreeMap<String, Ipld>, field: &str) -> Result<Option<String>> {
+    match map.get(field) {
+        Some(Ipld::String(s)) => Ok(Some(s.clone())),
+        Some(Ipld::Null) | None => Ok(None),
+        Some(_) => Err(invalid_field(field)),
+    }
+}
+
+fn get_optional_bool(map: &BTreeMap<String, Ipld>, field: &str) -> Result<Option<bool>> {
+    match map.get(field) {
+        Some(Ipld::Bool(b)) => Ok(Some(*b)),
+        Some(Ipld::Null) | None => Ok(None),
+        Some(_) => Err(invalid_field(field)),
+    }
+}
+
+fn get_did(map: &BTreeMap<String, Ipld>, field: &str) -> Result<Did> {
+    let s = get_string(map, field)?;
+    Did::new(s).map_err(|e| FirehoseError::InvalidMessage(e.to_string()))
+}
+
+fn get_link(map: &BTreeMap<String, Ipld>, field: &str) -> Result<Cid> {
+    get_optional_link(map, field)?.ok_or_else(|| missing_field(field))
+}
+
+fn get_optional_link(map: &BTreeMap<String, Ipld>, field: &str) -> Result<Option<Cid>> {
+    match map.get(field) {
+        Some(Ipld::Link(cid)) => Ok(Some(*cid)),
+        Some(Ipld::Null) | None => Ok(None),
+        Some(_) => Err(invalid_field(field)),
+    }
+}
+
+fn get_bytes(map: &BTreeMap<String, Ipld>, field: &str) -> Result<Vec<u8>> {
+    match map.get(field) {
+        Some(Ipld::Bytes(b)) => Ok(b.clone()),
+        Some(_) => Err(invalid_field(field)),
+        None => Err(missing_field(field)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::car::CarWriter;
+    use libipld::multihash::Multihash;
+    use sha2::{Digest, Sha256};
+
+    fn create_test_cid(data: &[u8]) -> Cid {
+        let hash = Sha256::digest(data);
+        let multihash = Multihash::wrap(0x12, &hash).unwrap();
+        Cid::new_v1(0x71, multihash)
+    }
+
+    fn encode(ipld: Ipld) -> Vec<u8> {
+        libipld_cbor::DagCborCodec.encode(&ipld).unwrap()
+    }
+
+    fn ipld_map(entries: Vec<(&str, Ipld)>) -> Ipld {
+        Ipld::Map(entries.into_iter().map(|(k, v)| (k.to_string(), v)).collect())
+    }
+
+    fn commit_body(record_cid: Cid, car_bytes: Vec<u8>, commit_cid: Cid) -> Vec<u8> {
+        encode(ipld_map(vec![
+            ("seq", Ipld::Integer(42)),
+            ("rebase", Ipld::Bool(false)),
+            ("tooBig", Ipld::Bool(false)),
+            ("repo", Ipld::String("did:plc:abc123".to_string())),
+            ("commit", Ipld::Link(commit_cid)),
+            ("rev", Ipld::String("3jzfcijpj2z2a".to_string())),
+            ("since", Ipld::Null),
+            ("blocks", Ipld::Bytes(car_bytes)),
+            (
+                "ops",
+                Ipld::List(vec![ipld_map(vec![
+                    ("action", Ipld::String("create".to_string())),
+                    ("path", Ipld::String("app.bsky.feed.post/3jzfcijpj2z2a".to_string())),
+                    ("cid", Ipld::Link(record_cid)),
+                ])]),
+            ),
+            ("blobs", Ipld::List(vec![])),
+            ("time", Ipld::String("2024-01-01T00:00:00.000Z".to_string())),
+        ]))
+    }
+
+    #[test]
+    fn test_decode_commit() {
+        let commit_cid = create_test_cid(b"commit");
+        let record = encode(ipld_map(vec![("text", Ipld::String("hello".to_string()))]));
+        let record_cid = create_test_cid(&record);
+
+        let mut writer = CarWriter::with_roots(Vec::new(), vec![commit_cid]);
+        writer.write_block(&record_cid, &record).unwrap();
+        let car_bytes = writer.finish().unwrap();
+
+        let body = commit_body(record_cid, car_bytes, commit_cid);
+        let event = RepoEvent::decode("#commit", &body).unwrap();
+
+        let RepoEvent::Commit(commit) = event else {
+            panic!("Expected commit event");
+        };
+
+        assert_eq!(commit.seq, 42);
+        assert_eq!(commit.repo.as_str(), "did:plc:abc123");
+        assert_eq!(commit.commit, commit_cid);
+        assert_eq!(commit.rev, "3jzfcijpj2z2a");
+        assert!(commit.since.is_none());
+        assert!(!commit.too_big);
+
+        assert_eq!(commit.ops.len(), 1);
+        let op = &commit.ops[0];
+        assert_eq!(op.action, RepoOpAction::Create);
+        assert_eq!(op.collection(), "app.bsky.feed.post");
+        assert_eq!(op.rkey(), "3jzfcijpj2z2a");
+        assert_eq!(op.cid, Some(record_cid));
+
+        assert_eq!(commit.blocks.roots, vec![commit_cid]);
+        assert_eq!(commit.blocks.get(&record_cid), Some(record.as_slice()));
+    }
+
+    #[test]
+    fn test_decode_too_big_commit_with_empty_blocks() {
+        let commit_cid = create_test_cid(b"commit");
+        let record_cid = create_test_cid(b"record");
+
+        let body = commit_body(record_cid, Vec::new(), commit_cid);
+        let RepoEvent::Commit(commit) = RepoEvent::decode("#commit", &body).unwrap() else {
+            panic!("Expected commit event");
+        };
+
+        assert!(commit.blocks.is_empty());
+    }
+
+    #[test]
+    fn test_signed_commit() {
+        use crate::crypto::{KeyAlgorithm, Keypair};
+        use crate::repo::{verify_commit, RepoError, UnsignedCommit};
+
+        let key = Keypair::generate(KeyAlgorithm::K256);
+        let unsigned = UnsignedCommit {
+            did: "did:plc:abc123".to_string(),
+            version: 3,
+            data: create_test_cid(b"mst root"),
+            rev: "3jzfcijpj2z2a".to_string(),
+            prev: None,
+        };
+        let sig = key.sign_hash(&unsigned.signing_hash().unwrap()).unwrap();
+        let signed = SignedCommit { commit: unsigned, sig };
+        let commit_bytes = signed.to_cbor().unwrap();
+        let commit_cid = signed.to_cid().unwrap();
+
+        let mut writer = CarWriter::with_roots(Vec::new(), vec![commit_cid]);
+        writer.write_block(&commit_cid, &commit_bytes).unwrap();
+        let car_bytes = writer.finish().unwrap();
+
+        let body = commit_body(create_test_cid(b"record"), car_bytes, commit_cid);
+        let RepoEvent::Commit(commit) = RepoEvent::decode("#commit", &body).unwrap() else {
+            panic!("Expected commit event");
+        };
+
+        let doc = crate::did_doc::DidDocument::from_json(&format!(
+            r#"{{"id": "did:plc:abc123", "verificationMethod": [{{
+                "id": "did:plc:abc123#atproto", "type": "Multikey",
+                "controller": "did:plc:abc123", "publicKeyMultibase": "{}"
+            }}]}}"#,
+            key.public_key().to_multibase()
+        ))
+        .unwrap();
+
+        let signed = commit.signed_commit().unwrap();
+        assert!(verify_commit(&signed, &doc).is_ok());
+
+        let other = Keypair::generate(KeyAlgorithm::K256);
+        let doc = crate::did_doc::DidDocument::from_json(
+            &serde_json::to_string(&doc).unwrap().replace(
+                &key.public_key().to_multibase(),
+                &other.public_key().to_multibase(),
+            ),
+        )
+        .unwrap();
+        assert!(matches!(verify_commit(&signed, &doc), Err(RepoError::SignatureMismatch(_))));
+    }
+
+    #[test]
+    fn test_signed_commit_missing_block() {
+        let commit_cid = create_test_cid(b"commit");
+        let body = commit_body(create_test_cid(b"record"), Vec::new(), commit_cid);
+        let RepoEvent::Commit(commit) = RepoEvent::decode("#commit", &body).unwrap() else {
+            panic!("Expected commit event");
+        };
+
+        assert!(matches!(commit.signed_commit(), Err(FirehoseError::InvalidMessage(_))));
+    }
+
+    #[test]
+    fn test_decode_identity() {
+        let body = encode(ipld_map(vec![
+            ("seq", Ipld::Integer(7)),
+            ("did", Ipld::String("did:plc:abc123".to_string())),
+            ("time", Ipld::String("2024-01-01T00:00:00.000Z".to_string())),
+            ("handle", Ipld::String("alice.bsky.social".to_string())),
+        ]));
+
+        let event = RepoEvent::decode("#identity", &body).unwrap();
+        assert_eq!(event.seq(), Some(7));
+
+        let RepoEvent::Identity(identity) = event else {
+            panic!("Expected identity event");
+        };
+        assert_eq!(identity.handle.as_deref(), Some("alice.bsky.social"));
+    }
+
+    #[test]
+    fn test_decode_account() {
+        let body = encode(ipld_map(vec![
+            ("seq", Ipld::Integer(8)),
+            ("did", Ipld::String("did:plc:abc123".to_string())),
+            ("time", Ipld::String("2024-01-01T00:00:00.000Z".to_string())),
+            ("active", Ipld::Bool(false)),
+            ("status", Ipld::String("takendown".to_string())),
+        ]));
+
+        let RepoEvent::Account(account) = RepoEvent::decode("#account", &body).unwrap() else {
+            panic!("Expected account event");
+        };
+        assert!(!account.active);
+        assert_eq!(account.status.as_deref(), Some("takendown"));
+    }
+
+    #[test]
+    fn test_decode_info() {
+        let body = encode(ipld_map(vec![("name", Ipld::String("OutdatedCursor".to_string()))]));
+
+        let event = RepoEvent::decode("#info", &body).unwrap();
+        assert!(event.seq().is_none());
+
+        let RepoEvent::Info(info) = event else {
+            panic!("Expected info event");
+        };
+        assert_eq!(info.name, "OutdatedCursor");
+        assert!(info.message.is_none());
+    }
+
+    #[test]
+    fn test_decode_unknown_type() {
+        let event = RepoEvent::decode("#handle", b"\xa0").unwrap();
+        assert!(matches!(event, RepoEvent::Unknown { ref message_type, .. } if message_type == "#handle"));
+    }
+
+    #[test]
+    fn test_decode_missing_field() {
+        let body = encode(ipld_map(vec![("seq", Ipld::Integer(1))]));
+        let result = RepoEvent::decode("#identity", &body);
+        assert!(matches!(result, Err(FirehoseError::InvalidMessage(_))));
+    }
+
+    #[tokio::test]
+    async fn test_repo_events_stream() {
+        let info = encode(ipld_map(vec![("name", Ipld::String("OutdatedCursor".to_string()))]));
+
+        let raw = stream::iter(vec![
+            Ok(SubscriptionEvent::Message { message_type: "#info".to_string(), body: info }),
+            Ok(SubscriptionEvent::Error { error: "FutureCursor".to_string(), message: None }),
+            Ok(SubscriptionEvent::Closed),
+            Ok(SubscriptionEvent::Message { message_type: "#info".to_string(), body: vec![] }),
+        ]);
+
+        let events: Vec<_> = repo_events(raw).collect().await;
+        assert_eq!(events.len(), 2);
+        assert!(matches!(events[0], Ok(RepoEvent::Info(_))));
+        assert!(matches!(events[1], Err(XrpcError::Xrpc { ref error, .. }) if error == "FutureCursor"));
+    }
+
+    fn record_commit() -> (Commit, Cid) {
+        let commit_cid = create_test_cid(b"commit");
+        let image_cid = create_test_cid(b"image");
+        let record = encode(ipld_map(vec![
+            ("$type", Ipld::String("app.bsky.feed.post".to_string())),
+            ("text", Ipld::String("hello".to_string())),
+            ("image", Ipld::Link(image_cid)),
+            ("raw", Ipld::Bytes(vec![1, 2, 3])),
+        ]));
+        let record_cid = create_test_cid(&record);
+
+        let mut writer = CarWriter::with_roots(Vec::new(), vec![commit_cid]);
+        writer.write_block(&record_cid, &record).unwrap();
+        let car_bytes = writer.finish().unwrap();
+
+        let body = encode(ipld_map(vec![
+            ("seq", Ipld::Integer(100)),
+            ("rebase", Ipld::Bool(false)),
+            ("tooBig", Ipld::Bool(false)),
+            ("repo", Ipld::String("did:plc:abc123".to_string())),
+            ("commit", Ipld::Link(commit_cid)),
+            ("rev", Ipld::String("3jzfcijpj2z2a".to_string())),
+            ("since", Ipld::Null),
+            ("blocks", Ipld::Bytes(car_bytes)),
+            (
+                "ops",
+                Ipld::List(vec![
+                    ipld_map(vec![
+                        ("action", Ipld::String("create".to_string())),
+                        ("path", Ipld::String("app.bsky.feed.post/3jzfcijpj2z2a".to_string())),
+                        ("cid", Ipld::Link(record_cid)),
+                    ]),
+                    ipld_map(vec![
+                        ("action", Ipld::String("delete".to_string())),
+                        ("path", Ipld::String("app.bsky.feed.like/3jzfcijpj2z2b".to_string())),
+                        ("cid", Ipld::Null),
+                    ]),
+                ]),
+            ),
+            ("blobs", Ipld::List(vec![])),
+            ("time", Ipld::String("2024-01-01T00:00:00.000Z".to_string())),
+        ]));
+
+        let RepoEvent::Commit(commit) = RepoEvent::decode("#commit", &body).unwrap() else {
+            panic!("Expected commit event");
+        };
+        (*commit, image_cid)
+    }
+
+    #[test]
+    fn test_record_events_from_commit() {
+        let (commit, image_cid) = record_commit();
+
+        let records = RecordEvent::from_commit(&commit, &RecordFilter::new()).unwrap();
+        assert_eq!(records.len(), 2);
+
+        let post = &records[0];
+        assert_eq!(post.did.as_str(), "did:plc:abc123");
+        assert_eq!(post.collection, "app.bsky.feed.post");
+        assert_eq!(post.rkey, "3jzfcijpj2z2a");
+        assert_eq!(post.uri.to_string(), "at://did:plc:abc123/app.bsky.feed.post/3jzfcijpj2z2a");
+        assert_eq!(post.action, RepoOpAction::Create);
+
+        let record = post.record.as_ref().unwrap();
+        assert_eq!(record["text"], "hello");
+        assert_eq!(record["image"]["$link"], image_cid.to_string());
+        assert_eq!(record["raw"]["$bytes"], "AQID");
+
+        let like = &records[1];
+        assert_eq!(like.action, RepoOpAction::Delete);
+        assert!(like.cid.is_none());
+        assert!(like.record.is_none());
+    }
+
+    #[test]
+    fn test_record_filter() {
+        let (commit, _) = record_commit();
+
+        let posts = RecordFilter::new().with_collection("app.bsky.feed.post");
+        let records = RecordEvent::from_commit(&commit, &posts).unwrap();
+        assert_eq!(records.len(), 1);
+        assert_eq!(records[0].collection, "app.bsky.feed.post");
+
+        let feed_namespace = RecordFilter::new().with_collection("app.bsky.feed.");
+        assert_eq!(RecordEvent::from_commit(&commit, &feed_namespace).unwrap().len(), 2);
+
+        let partial_nsid = RecordFilter::new().with_collection("app.bsky.feed.po");
+        assert!(RecordEvent::from_commit(&commit, &partial_nsid).unwrap().is_empty());
+
+        let deletes = RecordFilter::new().with_actions(&[RepoOpAction::Delete]);
+        let records = RecordEvent::from_commit(&commit, &deletes).unwrap();
+        assert_eq!(records.len(), 1);
+        assert_eq!(records[0].collection, "app.bsky.feed.like");
+    }
+
+    #[tokio::test]
+    async fn test_record_events_stream() {
+        let (commit, _) = record_commit();
+        let info = Info { name: "OutdatedCursor".to_string(), message: None };
+
+        let events: RepoEventStream = Box::pin(stream::iter(vec![
+            Ok(RepoEvent::Info(info)),
+            Ok(RepoEvent::Commit(Box::new(commit))),
+        ]));
+
+        let filter = RecordFilter::new()
+            .with_collection("app.bsky.feed.post")
+            .with_actions(&[RepoOpAction::Create]);
+        let records: Vec<_> = record_events(events, filter).collect().await;
+
+        assert_eq!(records.len(), 1);
+        assert_eq!(records[0].as_ref().unwrap().rkey, "3jzfcijpj2z2a");
+    }
+}
@@ -17,6 +17,9 @@
 //!
 //! The DNS method is preferred. If both methods return different DIDs, DNS takes precedence.
 //!
+//! DNS lookups use the system nameservers by default. Use
+//! [`HandleResolver::with_nameservers`] to query specific servers instead.
+//!
 //! ## Example
 //!
 //! ```no_run
@@ -31,6 +34,11 @@
 //! ```
 
 use crate::types::Did;
+use hickory_resolver::config::{NameServerConfig, Protocol, ResolverConfig, ResolverOpts};
+use hickory_resolver::error::ResolveErrorKind;
+use hickory_resolver::TokioAsyncResolver;
+use std::net::SocketAddr;
+use std::sync::OnceLock;
 
 /// Error types for handle resolution
 #[derive(Debug, thiserror::Error)]
@@ -50,6 +58,9 @@ pub enum HandleError {
     #[error("Invalid DID returned: {0}")]
     InvalidDid(String),
 
+    #[error("Conflicting _atproto TXT records for {0}")]
+    ConflictingRecords(String),
+
     #[error("No valid resolution method succeeded")]
     ResolutionFailed,
 }
@@ -61,6 +72,9 @@ pub struct HandleResolver {
     /// HTTP client for HTTPS resolution
     client: reqwest::Client,
 
+    /// DNS resolver for TXT lookups, built from the system config on first use
+    dns: OnceLock<TokioAsyncResolver>,
+
     /// Whether to attempt DNS resolution (default: true)
     enable_dns: bool,
 
@@ -81,6 +95,7 @@ impl HandleResolver {
     pub fn new() -> Self {
         Self {
             client: reqwest::Client::new(),
+            dns: OnceLock::new(),
             enable_dns: true,
             enable_https: true,
         }
@@ -90,6 +105,7 @@ impl HandleResolver {
     pub fn dns_only() -> Self {
         Self {
             client: reqwest::Client::new(),
+            dns: OnceLock::new(),
             enable_dns: true,
             enable_https: false,
         }
@@ -99,11 +115,38 @@ impl HandleResolver {
     pub fn https_only() -> Self {
         Self {
             client: reqwest::Client::new(),
+            dns: OnceLock::new(),
             enable_dns: false,
             enable_https: true,
         }
     }
 
+    /// Send DNS queries to the given nameservers instead of the system ones
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use atproto::handle::HandleResolver;
+    ///
+    /// let resolver = HandleResolver::new()
+    ///     .with_nameservers(vec!["1.1.1.1:53".parse().unwrap()]);
+    /// ```
+    pub fn with_nameservers(mut self, nameservers: Vec<SocketAddr>) -> Self {
+        let configs: Vec<NameServerConfig> = nameservers
+            .into_iter()
+            .flat_map(|addr| {
+                [
+                    NameServerConfig::new(addr, Protocol::Udp),
+                    NameServerConfig::new(addr, Protocol::Tcp),
+                ]
+            })
+            .collect();
+
+        let config = ResolverConfig::from_parts(None, Vec::new(), configs);
+        self.dns = OnceLock::from(TokioAsyncResolver::tokio(config, ResolverOpts::default()));
+        self
+    }
+
     /// Resolve a handle to a DID
     ///
     /// Tries DNS TXT record first (if enabled), then falls back to HTTPS well-known (if enabled).
@@ -156,18 +199,30 @@ impl HandleResolver {
     /// Resolve a handle via DNS TXT record
     ///
     /// Queries `_atproto.{handle}` for a TXT record with format `did={did}`.
-    async fn resolve_dns(&self, _handle: &str) -> Result<Did, HandleError> {
-        // For now, we'll use the trust-dns-resolver crate or similar
-        // Since this requires additional dependencies, we'll implement a basic version
-        // that could be enhanced with actual DNS resolution
+    /// TXT records without a `did=` prefix are ignored; several records with
+    /// different DIDs are an error.
+    pub async fn resolve_dns(&self, handle: &str) -> Result<Did, HandleError> {
+        let normalized = normalize_handle(handle)?;
+        // Trailing dot makes the name fully qualified, skipping search domains
+        let name = format!("_atproto.{}.", normalized);
 
-        // DNS resolution requires platform-specific libraries
-        // For a minimal implementation, we'll return an error
-        // In a full implementation, we would use trust-dns-resolver or hickory-dns
+        let dns = self.dns.get_or_init(system_dns_resolver);
+        let lookup = dns.txt_lookup(name).await.map_err(|e| match e.kind() {
+            ResolveErrorKind::NoRecordsFound { .. } => HandleError::HandleNotFound(handle.to_string()),
+            _ => HandleError::DnsError(e.to_string()),
+        })?;
 
-        Err(HandleError::DnsError(
-            "DNS resolution not yet implemented - use HTTPS resolution".to_string(),
-        ))
+        // A record may be split into several character-strings
+        let records: Vec<String> = lookup
+            .iter()
+            .map(|txt| {
+                txt.iter()
+                    .map(|part| String::from_utf8_lossy(part))
+                    .collect::<String>()
+            })
+            .collect();
+
+        parse_dns_records(handle, &records)
     }
 
     /// Resolve a handle via HTTPS well-known
@@ -195,6 +250,31 @@ impl Default for HandleResolver {
     }
 }
 
+/// Build a DNS resolver from the system configuration
+///
+/// Falls back to the resolver's default public nameservers when the system
+/// configuration can't be read.
+fn system_dns_resolver() -> TokioAsyncResolver {
+    TokioAsyncResolver::tokio_from_system_conf()
+        .unwrap_or_else(|_| TokioAsyncResolver::tokio(ResolverConfig::default(), ResolverOpts::default()))
+}
+
+/// Extract the DID from the values of `_atproto` TXT records
+fn parse_dns_records(handle: &str, records: &[String]) -> Result<Did, HandleError> {
+    let mut dids: Vec<&str> = records
+        .iter()
+        .filter_map(|record| record.trim().strip_prefix("did="))
+        .collect();
+    dids.sort_unstable();
+    dids.dedup();
+
+    match dids.as_slice() {
+        [] => Err(HandleError::HandleNotFound(handle.to_string())),
+        [did] => Did::new(*did).map_err(|_| HandleError::InvalidDid(did.to_string())),
+        _ => Err(HandleError::ConflictingRecords(handle.to_string())),
+    }
+}
+
 /// Validate and normalize a handle
 ///
 /// Handles must:
@@ -335,6 +415,7 @@ fn is_valid_label(label: &str) -> bool {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::test_support::stub_dns_server;
 
     #[test]
     fn test_normalize_handle_lowercase() {
@@ -491,6 +572,8 @@ mod tests {
         let resolver = HandleResolver::https_only();
         assert!(!resolver.enable_dns);
         assert!(resolver.enable_https);
+        // No system DNS config is read until a DNS lookup is made
+        assert!(resolver.dns.get().is_none());
     }
 
     #[test]
@@ -507,8 +590,63 @@ mod tests {
         assert!(result.is_err());
     }
 
-    // Note: Real resolution tests would require either:
-    // 1. A mock DNS/HTTP server
-    // 2. Integration tests with real handles
-    // 3. DNS resolution library integration
+    #[test]
+    fn test_parse_dns_records() {
+        let records = vec![
+            "v=spf1 -all".to_string(),
+            "did=did:plc:ewvi7nxzyoun6zhxrhs64oiz".to_string(),
+        ];
+        assert_eq!(
+            parse_dns_records("alice.com", &records).unwrap().as_str(),
+            "did:plc:ewvi7nxzyoun6zhxrhs64oiz"
+        );
+
+        // Duplicate records with the same DID are fine
+        let records = vec!["did=did:plc:abc".to_string(), "did=did:plc:abc".to_string()];
+        assert!(parse_dns_records("alice.com", &records).is_ok());
+
+        let records = vec!["did=did:plc:abc".to_string(), "did=did:plc:xyz".to_string()];
+        assert!(matches!(
+            parse_dns_records("alice.com", &records),
+            Err(HandleError::ConflictingRecords(_))
+        ));
+
+        assert!(matches!(
+            parse_dns_records("alice.com", &["v=spf1 -all".to_string()]),
+            Err(HandleError::HandleNotFound(_))
+        ));
+        assert!(matches!(
+            parse_dns_records("alice.com", &["did=not-a-did".to_string()]),
+            Err(HandleError::InvalidDid(_))
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_resolve_dns_with_stub_server() {
+        let addr = stub_dns_server(vec![
+            ("_atproto.alice.test.", vec!["did=did:plc:alice"]),
+            ("_atproto.bob.test.", vec!["did=did:plc:bob", "did=did:plc:mallory"]),
+            ("_atproto.carol.test.", vec!["v=spf1 -all"]),
+        ])
+        .await;
+
+        let resolver = HandleResolver::dns_only().with_nameservers(vec![addr]);
+
+        let did = resolver.resolve("Alice.Test").await.unwrap();
+        assert_eq!(did.as_str(), "did:plc:alice");
+
+        assert!(matches!(
+            resolver.resolve_dns("bob.test").await,
+            Err(HandleError::ConflictingRecords(_))
+        ));
+        assert!(matches!(
+            resolver.resolve_dns("carol.test").await,
+            Err(HandleError::HandleNotFound(_))
+        ));
+        assert!(matches!(
+            resolver.resolve_dns("nobody.test").await,
+            Err(HandleError::HandleNotFound(_))
+        ));
+        assert!(resolver.resolve("bob.test").await.is_err());
+    }
 }
@@ -0,0 +1,734 @@
+//! Identity resolution with bidirectional handle verification
+//!
+//! An atproto identity is only trustworthy when the handle and the DID agree:
+//! the handle must resolve to the DID, and the DID document must claim the
+//! handle in `alsoKnownAs`. [`IdResolver`] combines [`HandleResolver`] and
+//! [`DidResolver`] to perform both lookups and returns a verified [`Identity`].
+//!
+//! ## Caching
+//!
+//! Handle and DID document lookups are cached with stale-while-revalidate
+//! semantics (see [`CacheConfig`]):
+//!
+//! - Younger than `stale_ttl`: served from the cache
+//! - Between `stale_ttl` and `max_ttl`: served from the cache while a background
+//!   task refreshes the entry (at most one refresh per entry at a time)
+//! - Older than `max_ttl`: resolved again before returning
+//!
+//! The cache lives behind the [`IdentityCache`] trait, so it can be backed by
+//! Redis, a database, etc. [`MemoryIdentityCache`] is used by default.
+//!
+//! ## Example
+//!
+//! ```no_run
+//! use atproto::identity::IdResolver;
+//!
+//! # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+//! let resolver = IdResolver::new();
+//!
+//! let identity = resolver.resolve("atproto.com").await?;
+//! println!("{} is hosted at {:?}", identity.did, identity.pds);
+//! # Ok(())
+//! # }
+//! ```
+
+use crate::crypto::PublicKey;
+use crate::did_doc::{DidDocError, DidDocument, DidResolver};
+use crate::handle::{normalize_handle, HandleError, HandleResolver};
+use crate::types::Did;
+use async_trait::async_trait;
+use parking_lot::{Mutex, RwLock};
+use std::collections::{HashMap, HashSet};
+use std::future::Future;
+use std::sync::Arc;
+use std::time::{Duration, SystemTime};
+use thiserror::Error;
+
+/// Error types for identity resolution
+#[derive(Debug, Error)]
+pub enum IdentityError {
+    #[error("Handle resolution error: {0}")]
+    Handle(#[from] HandleError),
+
+    #[error("DID resolution error: {0}")]
+    DidDoc(#[from] DidDocError),
+
+    #[error("Invalid DID: {0}")]
+    InvalidDid(String),
+
+    #[error("Handle {handle} resolves to {did}, but the DID document does not claim it")]
+    HandleMismatch { handle: String, did: String },
+}
+
+/// Result type for identity resolution
+pub type Result<T> = std::result::Result<T, IdentityError>;
+
+/// A resolved and verified atproto identity
+#[derive(Debug, Clone)]
+pub struct Identity {
+    /// The account DID
+    pub did: Did,
+
+    /// Handle, if the DID document claims one and it resolves back to the DID
+    pub handle: Option<String>,
+
+    /// PDS endpoint from the DID document
+    pub pds: Option<String>,
+
+    /// Repository signing key (`#atproto`) from the DID document
+    pub signing_key: Option<PublicKey>,
+
+    /// The full DID document
+    pub doc: DidDocument,
+}
+
+/// A cached value and the time it was stored
+#[derive(Debug, Clone)]
+pub struct CachedEntry<T> {
+    /// The cached value
+    pub value: T,
+
+    /// When the value was resolved
+    pub cached_at: SystemTime,
+}
+
+impl<T> CachedEntry<T> {
+    /// Wraps a freshly resolved value
+    pub fn new(value: T) -> Self {
+        Self {
+            value,
+            cached_at: SystemTime::now(),
+        }
+    }
+
+    /// Time since the value was resolved
+    pub fn age(&self) -> Duration {
+        SystemTime::now()
+            .duration_since(self.cached_at)
+            .unwrap_or_default()
+    }
+}
+
+/// Backing store for resolved handles and DID documents
+///
+/// Implementations must be safe to share across tasks. Failed lookups are
+/// never cached.
+#[async_trait]
+pub trait IdentityCache: Send + Sync {
+    /// Returns the cached DID for a (normalized) handle
+    async fn get_handle(&self, handle: &str) -> Option<CachedEntry<Did>>;
+
+    /// Stores the DID a handle resolved to
+    async fn put_handle(&self, handle: &str, entry: CachedEntry<Did>);
+
+    /// Returns the cached document for a DID
+    async fn get_did_doc(&self, did: &str) -> Option<CachedEntry<DidDocument>>;
+
+    /// Stores the document a DID resolved to
+    async fn put_did_doc(&self, did: &str, entry: CachedEntry<DidDocument>);
+
+    /// Removes any cached data for a handle
+    async fn clear_handle(&self, handle: &str);
+
+    /// Removes any cached document for a DID
+    async fn clear_did_doc(&self, did: &str);
+}
+
+/// How long cached identity data is used
+#[derive(Debug, Clone)]
+pub struct CacheConfig {
+    /// Entries older than this are refreshed in the background
+    pub stale_ttl: Duration,
+
+    /// Entries older than this are resolved again before use
+    pub max_ttl: Duration,
+}
+
+impl Default for CacheConfig {
+    fn default() -> Self {
+        Self {
+            stale_ttl: Duration::from_secs(60 * 60),
+            max_ttl: Duration::from_secs(24 * 60 * 60),
+        }
+    }
+}
+
+/// In-memory identity cache
+///
+/// Entries older than `max_age` (24 hours by default, matching
+/// [`CacheConfig`]) are dropped when looked up, and swept out periodically as new entries are
+/// stored. With a capacity set, the oldest entries are evicted to make room.
+///
+/// # Example
+///
+/// ```
+/// use atproto::identity::{IdResolver, MemoryIdentityCache};
+/// use std::sync::Arc;
+///
+/// let cache = Arc::new(MemoryIdentityCache::new().with_capacity(100_000));
+/// let resolver = IdResolver::new().with_cache(cache);
+/// ```
+#[derive(Debug)]
+pub struct MemoryIdentityCache {
+    handles: RwLock<CacheEntries<Did>>,
+    did_docs: RwLock<CacheEntries<DidDocument>>,
+    max_age: Duration,
+    capacity: Option<usize>,
+}
+
+impl Default for MemoryIdentityCache {
+    fn default() -> Self {
+        Self {
+            handles: RwLock::default(),
+            did_docs: RwLock::default(),
+            max_age: CacheConfig::default().max_ttl,
+            capacity: None,
+        }
+    }
+}
+
+impl MemoryIdentityCache {
+    /// Creates an empty cache
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Drop entries older than `max_age`
+    ///
+    /// Should be at least the resolver's `max_ttl`, since older entries are
+    /// resolved again anyway.
+    pub fn with_max_age(mut self, max_age: Duration) -> Self {
+        self.max_age = max_age;
+        self
+    }
+
+    /// Keep at most `capacity` handles and `capacity` DID documents
+    pub fn with_capacity(mut self, capacity: usize) -> Self {
+        self.capacity = Some(capacity);
+        self
+    }
+
+    fn get<T: Clone>(&self, entries: &RwLock<CacheEntries<T>>, key: &str) -> Option<CachedEntry<T>> {
+        let entry = entries.read().map.get(key).cloned()?;
+        if entry.age() <= self.max_age {
+            return Some(entry);
+        }
+
+        // Re-check under the write lock in case the entry was just replaced
+        let mut entries = entries.write();
+        if entries.map.get(key).is_some_and(|entry| entry.age() > self.max_age) {
+            entries.map.remove(key);
+        }
+        None
+    }
+
+    fn put<T>(&self, entries: &RwLock<CacheEntries<T>>, key: &str, entry: CachedEntry<T>) {
+        let mut entries = entries.write();
+        entries.map.insert(key.to_string(), entry);
+
+        let over_capacity = self.capacity.is_some_and(|capacity| entries.map.len() > capacity);
+        if entries.map.len() >= entries.sweep_at || over_capacity {
+            entries.prune(self.max_age, self.capacity);
+        }
+    }
+}
+
+/// Map size below which inserts never sweep out expired entries
+const MIN_SWEEP: usize = 1024;
+
+/// One map of a [`MemoryIdentityCache`]
+#[derive(Debug)]
+struct CacheEntries<T> {
+    map: HashMap<String, CachedEntry<T>>,
+
+    /// Size at which the next insert sweeps out expired entries
+    ///
+    /// Doubles with the live entry count so sweeps stay amortized O(1).
+    sweep_at: usize,
+}
+
+impl<T> Default for CacheEntries<T> {
+    fn default() -> Self {
+        Self {
+            map: HashMap::new(),
+            sweep_at: MIN_SWEEP,
+        }
+    }
+}
+
+impl<T> CacheEntries<T> {
+    /// Drop expired entries, then the oldest ones while over capacity
+    fn prune(&mut self, max_age: Duration, capacity: Option<usize>) {
+        self.map.retain(|_, entry| entry.age() <= max_age);
+
+        if let Some(capacity) = capacity.filter(|capacity| self.map.len() > *capacity) {
+            // Evict an extra tenth so a full cache isn't pruned on every insert
+            let keep = capacity - capacity / 10;
+            let mut by_age: Vec<_> = self.map.iter().map(|(key, entry)| (entry.cached_at, key.clone())).collect();
+            by_age.sort_unstable();
+            for (_, key) in &by_age[..self.map.len() - keep] {
+                self.map.remove(key);
+            }
+        }
+
+        self.sweep_at = (self.map.len() * 2).max(MIN_SWEEP);
+    }
+}
+
+#[async_trait]
+impl IdentityCache for MemoryIdentityCache {
+    async fn get_handle(&self, handle: &str) -> Option<CachedEntry<Did>> {
+        self.get(&self.handles, handle)
+    }
+
+    async fn put_handle(&self, handle: &str, entry: CachedEntry<Did>) {
+        self.put(&self.handles, handle, entry);
+    }
+
+    async fn get_did_doc(&self, did: &str) -> Option<CachedEntry<DidDocument>> {
+        self.get(&self.did_docs, did)
+    }
+
+    async fn put_did_doc(&self, did: &str, entry: CachedEntry<DidDocument>) {
+        self.put(&self.did_docs, did, entry);
+    }
+
+    async fn clear_handle(&self, handle: &str) {
+        self.handles.write().map.remove(handle);
+    }
+
+    async fn clear_did_doc(&self, did: &str) {
+        self.did_docs.write().map.remove(did);
+    }
+}
+
+/// Resolves handles and DIDs to verified identities
+///
+/// Cloning is cheap; clones share the resolvers and the cache.
+#[derive(Clone)]
+pub struct IdResolver {
+    handles: Arc<HandleResolver>,
+    dids: Arc<DidResolver>,
+    cache: Arc<dyn IdentityCache>,
+    config: CacheConfig,
+
+    /// Handles and DIDs with a background refresh in flight
+    refreshing: Arc<Mutex<HashSet<String>>>,
+}
+
+impl IdResolver {
+    /// Create a resolver using DNS/HTTPS handle resolution, plc.directory and
+    /// an in-memory cache
+    pub fn new() -> Self {
+        Self {
+            handles: Arc::new(HandleResolver::new()),
+            dids: Arc::new(DidResolver::with_default_plc()),
+            cache: Arc::new(MemoryIdentityCache::new()),
+            config: CacheConfig::default(),
+            refreshing: Arc::new(Mutex::new(HashSet::new())),
+        }
+    }
+
+    /// Use a custom handle resolver
+    pub fn with_handle_resolver(mut self, resolver: HandleResolver) -> Self {
+        self.handles = Arc::new(resolver);
+        self
+    }
+
+    /// Use a custom DID resolver
+    pub fn with_did_resolver(mut self, resolver: DidResolver) -> Self {
+        self.dids = Arc::new(resolver);
+        self
+    }
+
+    /// Use a custom cache store
+    pub fn with_cache(mut self, cache: Arc<dyn IdentityCache>) -> Self {
+        self.cache = cache;
+        self
+    }
+
+    /// Set the cache lifetimes
+    pub fn with_cache_config(mut self, config: CacheConfig) -> Self {
+        self.config = config;
+        self
+    }
+
+    /// Resolve a handle or DID to a verified identity
+    pub async fn resolve(&self, identifier: &str) -> Result<Identity> {
+        if identifier.starts_with("did:") {
+            self.resolve_did(identifier).await
+        } else {
+            self.resolve_handle(identifier).await
+        }
+    }
+
+    /// Resolve a handle to a verified identity
+    ///
+    /// Fails with [`IdentityError::HandleMismatch`] if the DID document the
+    /// handle points to does not claim the handle.
+    pub async fn resolve_handle(&self, handle: &str) -> Result<Identity> {
+        let handle = normalize_handle(handle)?;
+        let did = self.handle_to_did(&handle).await?;
+        let doc = self.did_to_doc(did.as_str()).await?;
+
+        if !claims_handle(&doc, &handle) {
+            return Err(IdentityError::HandleMismatch {
+                handle,
+                did: did.to_string(),
+            });
+        }
+
+        Ok(Identity::from_doc(did, Some(handle), doc))
+    }
+
+    /// Resolve a DID to an identity
+    ///
+    /// The handle claimed by the DID document is only included if it resolves
+    /// back to the same DID.
+    pub async fn resolve_did(&self, did: &str) -> Result<Identity> {
+        let did = Did::new(did).map_err(|_| IdentityError::InvalidDid(did.to_string()))?;
+        let doc = self.did_to_doc(did.as_str()).await?;
+
+        let handle = match doc.get_handle().map(|h| normalize_handle(&h)) {
+            Some(Ok(handle)) => match self.handle_to_did(&handle).await {
+                Ok(resolved) if resolved == did => Some(handle),
+                _ => None,
+            },
+            _ => None,
+        };
+
+        Ok(Identity::from_doc(did, handle, doc))
+    }
+
+    /// Drop cached data for a DID, e.g. after an `#identity` firehose event
+    pub async fn purge_did(&self, did: &str) {
+        self.cache.clear_did_doc(did).await;
+    }
+
+    /// Drop the cached DID for a handle
+    pub async fn purge_handle(&self, handle: &str) {
+        if let Ok(handle) = normalize_handle(handle) {
+            self.cache.clear_handle(&handle).await;
+        }
+    }
+
+    /// Resolve a normalized handle to a DID, using the cache
+    async fn handle_to_did(&self, handle: &str) -> Result<Did> {
+        if let Some(entry) = self.cache.get_handle(handle).await {
+            let age = entry.age();
+            if age < self.config.stale_ttl {
+                return Ok(entry.value);
+            }
+            if age < self.config.max_ttl {
+                let resolver = self.clone();
+                let key = handle.to_string();
+                self.spawn_refresh(handle, async move {
+                    let _ = resolver.fetch_handle(&key).await;
+                });
+                return Ok(entry.value);
+            }
+        }
+
+        self.fetch_handle(handle).await
+    }
+
+    /// Resolve a DID to its document, using the cache
+    async fn did_to_doc(&self, did: &str) -> Result<DidDocument> {
+        if let Some(entry) = self.cache.get_did_doc(did).await {
+            let age = entry.age();
+            if age < self.config.stale_ttl {
+                return Ok(entry.value);
+            }
+            if age < self.config.max_ttl {
+                let resolver = self.clone();
+                let key = did.to_string();
+                self.spawn_refresh(did, async move {
+                    let _ = resolver.fetch_did_doc(&key).await;
+                });
+                return Ok(entry.value);
+            }
+        }
+
+        self.fetch_did_doc(did).await
+    }
+
+    /// Run `refresh` in the background unless one is already running for `key`
+    ///
+    /// Handles never start with `did:`, so both kinds of key share one set.
+    fn spawn_refresh(&self, key: &str, refresh: impl Future<Output = ()> + Send + 'static) {
+        if !self.refreshing.lock().insert(key.to_string()) {
+            return;
+        }
+
+        let refreshing = self.refreshing.clone();
+        let key = key.to_string();
+        tokio::spawn(async move {
+            refresh.await;
+            refreshing.lock().remove(&key);
+        });
+    }
+
+    async fn fetch_handle(&self, handle: &str) -> Result<Did> {
+        let did = self.handles.resolve(handle).await?;
+        self.cache.put_handle(handle, CachedEntry::new(did.clone())).await;
+        Ok(did)
+    }
+
+    async fn fetch_did_doc(&self, did: &str) -> Result<DidDocument> {
+        // DidResolver::resolve already rejects documents for a different DID
+        let doc = self.dids.resolve(did).await?;
+        self.cache.put_did_doc(did, CachedEntry::new(doc.clone())).await;
+        Ok(doc)
+    }
+}
+
+impl Default for IdResolver {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Identity {
+    fn from_doc(did: Did, handle: Option<String>, doc: DidDocument) -> Self {
+        let signing_key = doc
+            .get_signing_key()
+            .and_then(|method| PublicKey::from_verification_method(method).ok());
+
+        Self {
+            did,
+            handle,
+            pds: doc.get_pds_endpoint().ok(),
+            signing_key,
+            doc,
+        }
+    }
+}
+
+/// Whether a DID document lists `handle` in `alsoKnownAs`
+fn claims_handle(doc: &DidDocument, handle: &str) -> bool {
+    doc.also_known_as
+        .iter()
+        .filter_map(|aka| aka.strip_prefix("at://"))
+        .any(|aka| aka.eq_ignore_ascii_case(handle))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::{stub_dns_server, stub_http_server};
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    const SIGNING_KEY: &str = "zQ3shunBKsXixLxKtC5qeSG9E4J5RkGN57im31pcTzbNQnm5w";
+
+    fn did_doc_json(did: &str, handle: &str) -> String {
+        format!(
+            r##"{{
+                "id": "{did}",
+                "alsoKnownAs": ["at://{handle}"],
+                "service": [{{
+                    "id": "#atproto_pds",
+                    "type": "AtprotoPersonalDataServer",
+                    "serviceEndpoint": "https://pds.example.com"
+                }}],
+                "verificationMethod": [{{
+                    "id": "{did}#atproto",
+                    "type": "Multikey",
+                    "controller": "{did}",
+                    "publicKeyMultibase": "{SIGNING_KEY}"
+                }}]
+            }}"##
+        )
+    }
+
+    async fn test_resolver() -> (IdResolver, Arc<AtomicUsize>) {
+        let dns = stub_dns_server(vec![
+            ("_atproto.alice.test.", vec!["did=did:plc:alice"]),
+            ("_atproto.mallory.test.", vec!["did=did:plc:alice"]),
+            ("_atproto.bob.test.", vec!["did=did:plc:carol"]),
+        ])
+        .await;
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let plc = format!("http://{}", listener.local_addr().unwrap());
+        let hits = stub_http_server(
+            listener,
+            vec![
+                ("/did:plc:alice".to_string(), did_doc_json("did:plc:alice", "alice.test")),
+                ("/did:plc:bob".to_string(), did_doc_json("did:plc:bob", "bob.test")),
+            ],
+        );
+
+        let resolver = IdResolver::new()
+            .with_handle_resolver(HandleResolver::dns_only().with_nameservers(vec![dns]))
+            .with_did_resolver(DidResolver::new(plc));
+        (resolver, hits)
+    }
+
+    #[tokio::test]
+    async fn test_resolve_handle_bidirectional() {
+        let (resolver, _) = test_resolver().await;
+
+        let identity = resolver.resolve("Alice.Test").await.unwrap();
+        assert_eq!(identity.did.as_str(), "did:plc:alice");
+        assert_eq!(identity.handle.as_deref(), Some("alice.test"));
+        assert_eq!(identity.pds.as_deref(), Some("https://pds.example.com"));
+        assert_eq!(identity.signing_key.unwrap().to_multibase(), SIGNING_KEY);
+
+        // Points at alice's DID, but alice's document doesn't claim it
+        assert!(matches!(
+            resolver.resolve_handle("mallory.test").await,
+            Err(IdentityError::HandleMismatch { .. })
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_resolve_did() {
+        let (resolver, _) = test_resolver().await;
+
+        let identity = resolver.resolve("did:plc:alice").await.unwrap();
+        assert_eq!(identity.handle.as_deref(), Some("alice.test"));
+
+        // bob.test resolves to a different DID, so the handle is not verified
+        let identity = resolver.resolve("did:plc:bob").await.unwrap();
+        assert_eq!(identity.did.as_str(), "did:plc:bob");
+        assert!(identity.handle.is_none());
+
+        assert!(matches!(
+            resolver.resolve("did:plc:nobody").await,
+            Err(IdentityError::DidDoc(_))
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_cache_fresh_entries() {
+        let (resolver, hits) = test_resolver().await;
+
+        resolver.resolve_did("did:plc:alice").await.unwrap();
+        resolver.resolve_did("did:plc:alice").await.unwrap();
+        resolver.resolve_handle("alice.test").await.unwrap();
+        assert_eq!(hits.load(Ordering::SeqCst), 1);
+
+        resolver.purge_did("did:plc:alice").await;
+        resolver.resolve_did("did:plc:alice").await.unwrap();
+        assert_eq!(hits.load(Ordering::SeqCst), 2);
+    }
+
+    #[tokio::test]
+    async fn test_cache_stale_while_revalidate() {
+        let cache = Arc::new(MemoryIdentityCache::new());
+        let (resolver, hits) = test_resolver().await;
+        let resolver = resolver.with_cache(cache.clone());
+
+        // Stale entry with an outdated handle is served, then refreshed
+        let stale_doc = DidDocument::from_json(&did_doc_json("did:plc:alice", "old.test")).unwrap();
+        let stale_at = SystemTime::now() - Duration::from_secs(2 * 60 * 60);
+        cache
+            .put_did_doc("did:plc:alice", CachedEntry { value: stale_doc, cached_at: stale_at })
+            .await;
+
+        let identity = resolver.resolve_did("did:plc:alice").await.unwrap();
+        assert_eq!(identity.doc.get_handle().as_deref(), Some("old.test"));
+
+        for _ in 0..50 {
+            if hits.load(Ordering::SeqCst) > 0 {
+                break;
+            }
+            tokio::time::sleep(Duration::from_millis(10)).await;
+        }
+        tokio::time::sleep(Duration::from_millis(50)).await;
+
+        let refreshed = cache.get_did_doc("did:plc:alice").await.unwrap();
+        assert_eq!(refreshed.value.get_handle().as_deref(), Some("alice.test"));
+        assert!(refreshed.age() < Duration::from_secs(60));
+    }
+
+    #[tokio::test]
+    async fn test_cache_refreshes_stale_entry_once() {
+        let cache = Arc::new(MemoryIdentityCache::new());
+        let (resolver, hits) = test_resolver().await;
+        let resolver = resolver.with_cache(cache.clone());
+
+        let stale_doc = DidDocument::from_json(&did_doc_json("did:plc:alice", "alice.test")).unwrap();
+        let stale_at = SystemTime::now() - Duration::from_secs(2 * 60 * 60);
+        cache
+            .put_did_doc("did:plc:alice", CachedEntry { value: stale_doc, cached_at: stale_at })
+            .await;
+
+        let lookups = (0..20).map(|_| resolver.resolve_did("did:plc:alice"));
+        for identity in futures::future::join_all(lookups).await {
+            assert_eq!(identity.unwrap().did.as_str(), "did:plc:alice");
+        }
+
+        for _ in 0..50 {
+            if resolver.refreshing.lock().is_empty() {
+                break;
+            }
+            tokio::time::sleep(Duration::from_millis(10)).await;
+        }
+
+        assert!(resolver.refreshing.lock().is_empty());
+        assert_eq!(hits.load(Ordering::SeqCst), 1);
+        assert!(cache.get_did_doc("did:plc:alice").await.unwrap().age() < Duration::from_secs(60));
+    }
+
+    #[tokio::test]
+    async fn test_cache_expired_entries() {
+        let cache = Arc::new(MemoryIdentityCache::new());
+        let (resolver, hits) = test_resolver().await;
+        let resolver = resolver.with_cache(cache.clone());
+
+        let expired_doc = DidDocument::from_json(&did_doc_json("did:plc:alice", "old.test")).unwrap();
+        let expired_at = SystemTime::now() - Duration::from_secs(2 * 24 * 60 * 60);
+        cache
+            .put_did_doc("did:plc:alice", CachedEntry { value: expired_doc, cached_at: expired_at })
+            .await;
+
+        let identity = resolver.resolve_did("did:plc:alice").await.unwrap();
+        assert_eq!(identity.doc.get_handle().as_deref(), Some("alice.test"));
+        assert_eq!(hits.load(Ordering::SeqCst), 1);
+    }
+
+    #[tokio::test]
+    async fn test_memory_cache_evicts_expired_entries() {
+        let cache = MemoryIdentityCache::new().with_max_age(Duration::from_secs(60 * 60));
+        let did = Did::new("did:plc:alice").unwrap();
+        let expired_at = SystemTime::now() - Duration::from_secs(2 * 60 * 60);
+
+        cache.put_handle("old.test", CachedEntry { value: did.clone(), cached_at: expired_at }).await;
+        cache.put_handle("alice.test", CachedEntry::new(did.clone())).await;
+        assert!(cache.get_handle("old.test").await.is_none());
+        assert!(cache.get_handle("alice.test").await.is_some());
+        assert_eq!(cache.handles.read().map.len(), 1);
+
+        // Expired entries that are never looked up again are swept on insert
+        for i in 0..MIN_SWEEP {
+            let entry = CachedEntry { value: did.clone(), cached_at: expired_at };
+            cache.put_handle(&format!("user{}.test", i), entry).await;
+        }
+        assert!(cache.handles.read().map.len() < MIN_SWEEP);
+    }
+
+    #[tokio::test]
+    async fn test_memory_cache_capacity() {
+        let cache = MemoryIdentityCache::new().with_capacity(10);
+        let did = Did::new("did:plc:alice").unwrap();
+        let start = SystemTime::now() - Duration::from_secs(60);
+
+        for i in 0..25u64 {
+            let entry = CachedEntry { value: did.clone(), cached_at: start + Duration::from_secs(i) };
+            cache.put_handle(&format!("user{}.test", i), entry).await;
+            assert!(cache.handles.read().map.len() <= 10);
+        }
+
+        // The most recent entries survive
+        assert!(cache.get_handle("user24.test").await.is_some());
+        assert!(cache.get_handle("user0.test").await.is_none());
+    }
+
+    #[test]
+    fn test_claims_handle() {
+        let doc = DidDocument::from_json(&did_doc_json("did:plc:alice", "alice.test")).unwrap();
+        assert!(claims_handle(&doc, "alice.test"));
+        assert!(claims_handle(&doc, "ALICE.test"));
+        assert!(!claims_handle(&doc, "bob.test"));
+    }
+}
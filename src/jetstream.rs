@@ -0,0 +1,749 @@
+//! Jetstream client
+//!
+//! [Jetstream](https://github.com/bluesky-social/jetstream) re-encodes the
+//! repository firehose as JSON, with server-side filtering by collection and
+//! DID. It is a lighter alternative to [`crate::firehose`] for consumers that
+//! only need record contents and don't want to verify commits.
+//!
+//! Events are decoded into the same [`RecordEvent`], [`Identity`] and
+//! [`Account`] types used by the CBOR firehose, so handlers can be shared
+//! between the two.
+//!
+//! # Cursors
+//!
+//! Jetstream cursors are Unix timestamps in microseconds (`time_us`). The
+//! client tracks the `time_us` of the last event and resumes from it when the
+//! connection drops.
+//!
+//! # Compression
+//!
+//! Jetstream can send zstd-compressed frames, which cuts bandwidth by roughly
+//! half. The frames are compressed with a custom dictionary published in the
+//! Jetstream repository; pass it to
+//! [`with_zstd_dictionary`](JetstreamClient::with_zstd_dictionary) to enable
+//! compression.
+//!
+//! # Example
+//!
+//! ```no_run
+//! use atproto::jetstream::{JetstreamClient, JetstreamEvent};
+//! use futures::StreamExt;
+//!
+//! # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+//! let client = JetstreamClient::new("wss://jetstream2.us-east.bsky.network".to_string())
+//!     .with_collection("app.bsky.feed.post");
+//!
+//! let mut events = client.subscribe().await?;
+//! while let Some(event) = events.next().await {
+//!     if let JetstreamEvent::Commit { record, .. } = event? {
+//!         println!("{}: {:?}", record.uri, record.record);
+//!     }
+//! }
+//! # Ok(())
+//! # }
+//! ```
+
+use crate::firehose::{Account, Identity, RecordEvent, RepoOpAction};
+use crate::syntax::AtUri;
+use crate::types::Did;
+use crate::xrpc_subscription::ReconnectConfig;
+use futures::stream::{self, Stream, StreamExt};
+use libipld::Cid;
+use serde::Deserialize;
+use std::io::Read;
+use std::pin::Pin;
+use tokio::time::sleep;
+use tokio_tungstenite::{connect_async, tungstenite::Message};
+
+/// Error type for Jetstream operations
+#[derive(Debug, thiserror::Error)]
+pub enum JetstreamError {
+    #[error("Network error: {0}")]
+    Network(String),
+
+    #[error("JSON error: {0}")]
+    Json(#[from] serde_json::Error),
+
+    #[error("Decompression error: {0}")]
+    Decompression(String),
+
+    #[error("Invalid event: {0}")]
+    InvalidEvent(String),
+}
+
+/// Result type for Jetstream operations
+pub type Result<T> = std::result::Result<T, JetstreamError>;
+
+/// Stream of Jetstream events
+pub type JetstreamStream = Pin<Box<dyn Stream<Item = Result<JetstreamEvent>> + Send>>;
+
+/// WebSocket connection used by the Jetstream client
+type WsStream = tokio_tungstenite::WebSocketStream<tokio_tungstenite::MaybeTlsStream<tokio::net::TcpStream>>;
+
+/// A decoded Jetstream event
+#[derive(Debug, Clone)]
+pub enum JetstreamEvent {
+    /// A record was created, updated or deleted
+    Commit {
+        /// Event cursor (microseconds since the Unix epoch)
+        time_us: i64,
+        record: Box<RecordEvent>,
+    },
+
+    /// An account's identity changed
+    Identity {
+        /// Event cursor (microseconds since the Unix epoch)
+        time_us: i64,
+        identity: Identity,
+    },
+
+    /// An account's hosting status changed
+    Account {
+        /// Event cursor (microseconds since the Unix epoch)
+        time_us: i64,
+        account: Account,
+    },
+
+    /// Event kind not known to this client
+    Unknown {
+        /// Event cursor (microseconds since the Unix epoch)
+        time_us: i64,
+        kind: String,
+    },
+
+    /// The connection was re-established after dropping
+    Reconnected {
+        /// Reconnection attempt that succeeded (1 = first retry)
+        attempt: u32,
+        /// Cursor the stream resumed from
+        cursor: Option<i64>,
+    },
+
+    /// A reconnection attempt failed; the stream retries until its
+    /// reconnect attempts run out
+    ReconnectFailed {
+        /// Reconnection attempt that failed (1 = first retry)
+        attempt: u32,
+        /// Why the connection could not be made
+        error: String,
+    },
+}
+
+impl JetstreamEvent {
+    /// Decode a JSON event
+    pub fn from_json(json: &str) -> Result<Self> {
+        let raw: RawEvent = serde_json::from_str(json)?;
+        let did = Did::new(raw.did.as_str())
+            .map_err(|e| JetstreamError::InvalidEvent(e.to_string()))?;
+
+        match raw.kind.as_str() {
+            "commit" => {
+                let commit = raw.commit.ok_or_else(|| missing_field("commit"))?;
+                Ok(Self::Commit {
+                    time_us: raw.time_us,
+                    record: Box::new(commit.into_record_event(did, raw.time_us)?),
+                })
+            }
+            "identity" => {
+                let identity = raw.identity.ok_or_else(|| missing_field("identity"))?;
+                Ok(Self::Identity {
+                    time_us: raw.time_us,
+                    identity: Identity {
+                        seq: identity.seq,
+                        did,
+                        time: identity.time,
+                        handle: identity.handle,
+                    },
+                })
+            }
+            "account" => {
+                let account = raw.account.ok_or_else(|| missing_field("account"))?;
+                Ok(Self::Account {
+                    time_us: raw.time_us,
+                    account: Account {
+                        seq: account.seq,
+                        did,
+                        time: account.time,
+                        active: account.active,
+                        status: account.status,
+                    },
+                })
+            }
+            _ => Ok(Self::Unknown {
+                time_us: raw.time_us,
+                kind: raw.kind,
+            }),
+        }
+    }
+
+    /// Cursor of this event, `None` for connection events
+    pub fn time_us(&self) -> Option<i64> {
+        match self {
+            Self::Commit { time_us, .. }
+            | Self::Identity { time_us, .. }
+            | Self::Account { time_us, .. }
+            | Self::Unknown { time_us, .. } => Some(*time_us),
+            Self::Reconnected { .. } | Self::ReconnectFailed { .. } => None,
+        }
+    }
+}
+
+/// Top-level Jetstream event envelope
+#[derive(Deserialize)]
+struct RawEvent {
+    did: String,
+    time_us: i64,
+    kind: String,
+    commit: Option<RawCommit>,
+    identity: Option<RawIdentity>,
+    account: Option<RawAccount>,
+}
+
+#[derive(Deserialize)]
+struct RawCommit {
+    rev: String,
+    operation: String,
+    collection: String,
+    rkey: String,
+    record: Option<serde_json::Value>,
+    cid: Option<String>,
+}
+
+impl RawCommit {
+    fn into_record_event(self, did: Did, time_us: i64) -> Result<RecordEvent> {
+        let action = RepoOpAction::parse(&self.operation).ok_or_else(|| {
+            JetstreamError::InvalidEvent(format!("Unknown operation: {}", self.operation))
+        })?;
+
+        let uri = AtUri::from_parts(did.as_str(), self.collection.as_str(), Some(self.rkey.as_str()))
+            .map_err(|e| JetstreamError::InvalidEvent(e.to_string()))?;
+
+        let cid = self
+            .cid
+            .map(|cid| {
+                Cid::try_from(cid.as_str())
+                    .map_err(|e| JetstreamError::InvalidEvent(format!("Invalid CID {}: {}", cid, e)))
+            })
+            .transpose()?;
+
+        let time = chrono::DateTime::from_timestamp_micros(time_us)
+            .ok_or_else(|| JetstreamError::InvalidEvent(format!("Invalid time_us: {}", time_us)))?
+            .to_rfc3339_opts(chrono::SecondsFormat::Micros, true);
+
+        Ok(RecordEvent {
+            seq: time_us,
+            did,
+            collection: self.collection,
+            rkey: self.rkey,
+            uri,
+            action,
+            cid,
+            record: self.record,
+            rev: self.rev,
+            time,
+        })
+    }
+}
+
+#[derive(Deserialize)]
+struct RawIdentity {
+    seq: i64,
+    time: String,
+    handle: Option<String>,
+}
+
+#[derive(Deserialize)]
+struct RawAccount {
+    seq: i64,
+    time: String,
+    active: bool,
+    status: Option<String>,
+}
+
+fn missing_field(field: &str) -> JetstreamError {
+    JetstreamError::InvalidEvent(format!("Missing field: {}", field))
+}
+
+/// Jetstream WebSocket client
+#[derive(Debug, Clone)]
+pub struct JetstreamClient {
+    endpoint: String,
+    wanted_collections: Vec<String>,
+    wanted_dids: Vec<String>,
+    cursor: Option<i64>,
+    zstd_dictionary: Option<Vec<u8>>,
+    reconnect_config: ReconnectConfig,
+}
+
+impl JetstreamClient {
+    /// Create a new Jetstream client
+    ///
+    /// `endpoint` is the instance root (e.g. `wss://jetstream2.us-east.bsky.network`);
+    /// `/subscribe` is appended when connecting.
+    pub fn new(endpoint: String) -> Self {
+        Self {
+            endpoint,
+            wanted_collections: Vec::new(),
+            wanted_dids: Vec::new(),
+            cursor: None,
+            zstd_dictionary: None,
+            reconnect_config: ReconnectConfig::default(),
+        }
+    }
+
+    /// Only receive commits for this collection
+    ///
+    /// Jetstream accepts full NSIDs or prefixes ending in `.*`
+    /// (e.g. `app.bsky.graph.*`). Identity and account events are always sent.
+    pub fn with_collection(mut self, collection: impl Into<String>) -> Self {
+        self.wanted_collections.push(collection.into());
+        self
+    }
+
+    /// Only receive events for this repository
+    pub fn with_did(mut self, did: impl Into<String>) -> Self {
+        self.wanted_dids.push(did.into());
+        self
+    }
+
+    /// Start from a cursor (microseconds since the Unix epoch)
+    pub fn with_cursor(mut self, time_us: i64) -> Self {
+        self.cursor = Some(time_us);
+        self
+    }
+
+    /// Request zstd-compressed frames, decoded with Jetstream's dictionary
+    pub fn with_zstd_dictionary(mut self, dictionary: Vec<u8>) -> Self {
+        self.zstd_dictionary = Some(dictionary);
+        self
+    }
+
+    /// Set reconnection configuration
+    pub fn with_reconnect_config(mut self, config: ReconnectConfig) -> Self {
+        self.reconnect_config = config;
+        self
+    }
+
+    /// Connect and stream events
+    ///
+    /// The stream reconnects with the last seen cursor when the connection
+    /// drops, and ends with an error once reconnection attempts are exhausted.
+    pub async fn subscribe(&self) -> Result<JetstreamStream> {
+        let url = self.build_url(self.cursor);
+        let (ws, _) = connect_async(&url)
+            .await
+            .map_err(|e| JetstreamError::Network(format!("Failed to connect to Jetstream: {}", e)))?;
+
+        let state = StreamState {
+            client: self.clone(),
+            cursor: self.cursor,
+            ws: Some(ws),
+            attempt: 0,
+            finished: false,
+        };
+
+        Ok(Box::pin(stream::unfold(state, StreamState::next_event)))
+    }
+
+    /// Build the subscription URL
+    fn build_url(&self, cursor: Option<i64>) -> String {
+        let mut url = self.endpoint.trim_end_matches('/').to_string();
+
+        // Convert https:// to wss:// or http:// to ws://
+        if url.starts_with("https://") {
+            url = url.replace("https://", "wss://");
+        } else if url.starts_with("http://") {
+            url = url.replace("http://", "ws://");
+        } else if !url.starts_with("ws://") && !url.starts_with("wss://") {
+            url = format!("wss://{}", url);
+        }
+
+        if !url.ends_with("/subscribe") {
+            url.push_str("/subscribe");
+        }
+
+        let mut params = Vec::new();
+        for collection in &self.wanted_collections {
+            params.push(format!("wantedCollections={}", urlencoding::encode(collection)));
+        }
+        for did in &self.wanted_dids {
+            params.push(format!("wantedDids={}", urlencoding::encode(did)));
+        }
+        if let Some(cursor) = cursor {
+            params.push(format!("cursor={}", cursor));
+        }
+        if self.zstd_dictionary.is_some() {
+            params.push("compress=true".to_string());
+        }
+
+        if !params.is_empty() {
+            url = format!("{}?{}", url, params.join("&"));
+        }
+
+        url
+    }
+
+    /// Decode a frame into its JSON text
+    fn decode_frame(&self, message: Message) -> Result<Option<String>> {
+        match message {
+            Message::Text(text) => Ok(Some(text)),
+            Message::Binary(data) => {
+                let dictionary = self.zstd_dictionary.as_deref().ok_or_else(|| {
+                    JetstreamError::Decompression("Received compressed frame without a dictionary".to_string())
+                })?;
+                decompress(&data, dictionary).map(Some)
+            }
+            _ => Ok(None), // Ignore ping, pong, close
+        }
+    }
+
+    /// Check if should retry connection
+    fn should_retry(&self, attempt: u32) -> bool {
+        self.reconnect_config.max_attempts == 0 || attempt < self.reconnect_config.max_attempts
+    }
+}
+
+/// Decompress a zstd frame using Jetstream's dictionary
+fn decompress(data: &[u8], dictionary: &[u8]) -> Result<String> {
+    let mut decoder = zstd::stream::read::Decoder::with_dictionary(data, dictionary)
+        .map_err(|e| JetstreamError::Decompression(e.to_string()))?;
+
+    let mut json = String::new();
+    decoder
+        .read_to_string(&mut json)
+        .map_err(|e| JetstreamError::Decompression(e.to_string()))?;
+
+    Ok(json)
+}
+
+/// State driving a Jetstream stream
+struct StreamState {
+    client: JetstreamClient,
+
+    /// Last seen `time_us`, used as the cursor on reconnect
+    cursor: Option<i64>,
+
+    /// Current connection (None while reconnecting)
+    ws: Option<WsStream>,
+
+    /// Consecutive reconnection attempts since the last received event
+    attempt: u32,
+
+    finished: bool,
+}
+
+impl StreamState {
+    async fn next_event(mut self) -> Option<(Result<JetstreamEvent>, Self)> {
+        if self.finished {
+            return None;
+        }
+
+        loop {
+            let Some(ws) = self.ws.as_mut() else {
+                match self.reconnect().await {
+                    Ok(event) => return Some((Ok(event), self)),
+                    Err(e) => {
+                        self.finished = true;
+                        return Some((Err(e), self));
+                    }
+                }
+            };
+
+            match ws.next().await {
+                Some(Ok(Message::Close(_))) | Some(Err(_)) | None => {
+                    // Connection dropped; reconnect on the next iteration
+                    self.ws = None;
+                }
+                Some(Ok(message)) => {
+                    let event = match self.client.decode_frame(message) {
+                        Ok(Some(json)) => JetstreamEvent::from_json(&json),
+                        Ok(None) => continue,
+                        Err(e) => Err(e),
+                    };
+
+                    self.attempt = 0;
+                    if let Some(time_us) = event.as_ref().ok().and_then(JetstreamEvent::time_us) {
+                        self.cursor = Some(time_us);
+                    }
+
+                    return Some((event, self));
+                }
+            }
+        }
+    }
+
+    /// Make one reconnection attempt
+    ///
+    /// Returns `Reconnected` on success, `ReconnectFailed` if the attempt
+    /// failed and will be retried, or an error once attempts are exhausted.
+    async fn reconnect(&mut self) -> Result<JetstreamEvent> {
+        if !self.client.should_retry(self.attempt) {
+            return Err(JetstreamError::Network(format!(
+                "Jetstream connection lost after {} reconnection attempts",
+                self.attempt
+            )));
+        }
+
+        sleep(self.client.reconnect_config.calculate_delay(self.attempt)).await;
+        self.attempt += 1;
+
+        match connect_async(&self.client.build_url(self.cursor)).await {
+            Ok((ws, _)) => {
+                self.ws = Some(ws);
+                Ok(JetstreamEvent::Reconnected {
+                    attempt: self.attempt,
+                    cursor: self.cursor,
+                })
+            }
+            Err(e) => Ok(JetstreamEvent::ReconnectFailed {
+                attempt: self.attempt,
+                error: e.to_string(),
+            }),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use futures::SinkExt;
+    use std::time::Duration;
+    use tokio::net::TcpListener;
+    use tokio_tungstenite::tungstenite::handshake::server::{Request, Response};
+
+    const COMMIT_EVENT: &str = r#"{
+        "did": "did:plc:eygmaihciaxprqvxpfvl6flk",
+        "time_us": 1725911162329308,
+        "kind": "commit",
+        "commit": {
+            "rev": "3l3qo2vutsw2b",
+            "operation": "create",
+            "collection": "app.bsky.feed.post",
+            "rkey": "3l3qo2vuowo2b",
+            "record": {"$type": "app.bsky.feed.post", "text": "hello", "createdAt": "2024-09-09T19:46:02.102Z"},
+            "cid": "bafyreidwaivazkwu67xztlmuobx35hs2lnfh3kolmgfmucldvhd3sgzcqi"
+        }
+    }"#;
+
+    const IDENTITY_EVENT: &str = r#"{
+        "did": "did:plc:ufbl4k27gp6kzas5glhz7fim",
+        "time_us": 1725516665234703,
+        "kind": "identity",
+        "identity": {"did": "did:plc:ufbl4k27gp6kzas5glhz7fim", "handle": "yohenrique.bsky.social", "seq": 1409752997, "time": "2024-09-05T06:11:04.870Z"}
+    }"#;
+
+    const ACCOUNT_EVENT: &str = r#"{
+        "did": "did:plc:ufbl4k27gp6kzas5glhz7fim",
+        "time_us": 1725516665333808,
+        "kind": "account",
+        "account": {"active": false, "did": "did:plc:ufbl4k27gp6kzas5glhz7fim", "seq": 1409753013, "status": "deactivated", "time": "2024-09-05T06:11:04.870Z"}
+    }"#;
+
+    fn fast_reconnect(max_attempts: u32) -> ReconnectConfig {
+        ReconnectConfig {
+            max_attempts,
+            initial_delay: Duration::from_millis(10),
+            max_delay: Duration::from_millis(10),
+            backoff_multiplier: 1.0,
+        }
+    }
+
+    #[test]
+    fn test_decode_commit() {
+        let event = JetstreamEvent::from_json(COMMIT_EVENT).unwrap();
+        assert_eq!(event.time_us(), Some(1725911162329308));
+
+        let JetstreamEvent::Commit { record, .. } = event else {
+            panic!("expected commit");
+        };
+        assert_eq!(record.seq, 1725911162329308);
+        assert_eq!(record.did.as_str(), "did:plc:eygmaihciaxprqvxpfvl6flk");
+        assert_eq!(record.action, RepoOpAction::Create);
+        assert_eq!(
+            record.uri.to_string(),
+            "at://did:plc:eygmaihciaxprqvxpfvl6flk/app.bsky.feed.post/3l3qo2vuowo2b"
+        );
+        assert_eq!(
+            record.cid.unwrap().to_string(),
+            "bafyreidwaivazkwu67xztlmuobx35hs2lnfh3kolmgfmucldvhd3sgzcqi"
+        );
+        assert_eq!(record.record.unwrap()["text"], "hello");
+        assert_eq!(record.rev, "3l3qo2vutsw2b");
+        assert_eq!(record.time, "2024-09-09T19:46:02.329308Z");
+    }
+
+    #[test]
+    fn test_decode_delete() {
+        let json = r#"{"did":"did:plc:eygmaihciaxprqvxpfvl6flk","time_us":1,"kind":"commit",
+            "commit":{"rev":"3l3qo2vutsw2b","operation":"delete","collection":"app.bsky.feed.like","rkey":"3l3qo2vuowo2b"}}"#;
+
+        let JetstreamEvent::Commit { record, .. } = JetstreamEvent::from_json(json).unwrap() else {
+            panic!("expected commit");
+        };
+        assert_eq!(record.action, RepoOpAction::Delete);
+        assert!(record.cid.is_none());
+        assert!(record.record.is_none());
+    }
+
+    #[test]
+    fn test_decode_identity_and_account() {
+        let JetstreamEvent::Identity { identity, .. } = JetstreamEvent::from_json(IDENTITY_EVENT).unwrap() else {
+            panic!("expected identity");
+        };
+        assert_eq!(identity.seq, 1409752997);
+        assert_eq!(identity.handle.as_deref(), Some("yohenrique.bsky.social"));
+
+        let JetstreamEvent::Account { account, .. } = JetstreamEvent::from_json(ACCOUNT_EVENT).unwrap() else {
+            panic!("expected account");
+        };
+        assert!(!account.active);
+        assert_eq!(account.status.as_deref(), Some("deactivated"));
+    }
+
+    #[test]
+    fn test_decode_unknown_kind() {
+        let json = r#"{"did":"did:plc:eygmaihciaxprqvxpfvl6flk","time_us":5,"kind":"mystery"}"#;
+        assert!(matches!(
+            JetstreamEvent::from_json(json).unwrap(),
+            JetstreamEvent::Unknown { time_us: 5, ref kind } if kind == "mystery"
+        ));
+    }
+
+    #[test]
+    fn test_decode_invalid_operation() {
+        let json = r#"{"did":"did:plc:eygmaihciaxprqvxpfvl6flk","time_us":1,"kind":"commit",
+            "commit":{"rev":"r","operation":"explode","collection":"app.bsky.feed.like","rkey":"k"}}"#;
+        assert!(matches!(
+            JetstreamEvent::from_json(json),
+            Err(JetstreamError::InvalidEvent(_))
+        ));
+    }
+
+    #[test]
+    fn test_build_url() {
+        let client = JetstreamClient::new("https://jetstream.example.com/".to_string())
+            .with_collection("app.bsky.feed.post")
+            .with_collection("app.bsky.graph.*")
+            .with_did("did:plc:abc")
+            .with_cursor(1725911162329308)
+            .with_zstd_dictionary(vec![]);
+
+        assert_eq!(
+            client.build_url(client.cursor),
+            "wss://jetstream.example.com/subscribe?wantedCollections=app.bsky.feed.post\
+             &wantedCollections=app.bsky.graph.%2A&wantedDids=did%3Aplc%3Aabc\
+             &cursor=1725911162329308&compress=true"
+        );
+
+        let client = JetstreamClient::new("ws://localhost:6008/subscribe".to_string());
+        assert_eq!(client.build_url(None), "ws://localhost:6008/subscribe");
+    }
+
+    #[tokio::test]
+    async fn test_subscribe_resumes_with_time_us() {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+
+        let server = tokio::spawn(async move {
+            let mut uris = Vec::new();
+
+            // Each connection sends one event then drops
+            for events in [vec![COMMIT_EVENT], vec![IDENTITY_EVENT]] {
+                let (tcp, _) = listener.accept().await.unwrap();
+                let mut uri = String::new();
+                let mut ws = tokio_tungstenite::accept_hdr_async(tcp, |req: &Request, resp: Response| {
+                    uri = req.uri().to_string();
+                    Ok(resp)
+                })
+                .await
+                .unwrap();
+
+                for event in events {
+                    ws.send(Message::Text(event.to_string())).await.unwrap();
+                }
+                ws.close(None).await.unwrap();
+                uris.push(uri);
+            }
+
+            uris
+        });
+
+        let client = JetstreamClient::new(format!("ws://{}", addr))
+            .with_collection("app.bsky.feed.post")
+            .with_reconnect_config(fast_reconnect(1));
+
+        let mut stream = client.subscribe().await.unwrap();
+
+        assert!(matches!(stream.next().await, Some(Ok(JetstreamEvent::Commit { .. }))));
+        assert!(matches!(
+            stream.next().await,
+            Some(Ok(JetstreamEvent::Reconnected { attempt: 1, cursor: Some(1725911162329308) }))
+        ));
+        assert!(matches!(stream.next().await, Some(Ok(JetstreamEvent::Identity { .. }))));
+
+        // Server is gone: the single retry fails, then the stream gives up
+        assert!(matches!(
+            stream.next().await,
+            Some(Ok(JetstreamEvent::ReconnectFailed { attempt: 1, .. }))
+        ));
+        assert!(matches!(stream.next().await, Some(Err(JetstreamError::Network(_)))));
+        assert!(stream.next().await.is_none());
+
+        let uris = server.await.unwrap();
+        assert_eq!(uris[0], "/subscribe?wantedCollections=app.bsky.feed.post");
+        assert_eq!(
+            uris[1],
+            "/subscribe?wantedCollections=app.bsky.feed.post&cursor=1725911162329308"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_subscribe_compressed() {
+        let dictionary = ACCOUNT_EVENT.repeat(4).into_bytes();
+        let compressed = zstd::bulk::Compressor::with_dictionary(3, &dictionary)
+            .unwrap()
+            .compress(ACCOUNT_EVENT.as_bytes())
+            .unwrap();
+
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+
+        let server = tokio::spawn(async move {
+            let (tcp, _) = listener.accept().await.unwrap();
+            let mut uri = String::new();
+            let mut ws = tokio_tungstenite::accept_hdr_async(tcp, |req: &Request, resp: Response| {
+                uri = req.uri().to_string();
+                Ok(resp)
+            })
+            .await
+            .unwrap();
+
+            ws.send(Message::Binary(compressed)).await.unwrap();
+            ws.close(None).await.unwrap();
+            uri
+        });
+
+        let client = JetstreamClient::new(format!("ws://{}", addr))
+            .with_zstd_dictionary(dictionary)
+            .with_reconnect_config(fast_reconnect(1));
+
+        let mut stream = client.subscribe().await.unwrap();
+
+        let Some(Ok(JetstreamEvent::Account { account, time_us })) = stream.next().await else {
+            panic!("expected account event");
+        };
+        assert_eq!(time_us, 1725516665333808);
+        assert_eq!(account.status.as_deref(), Some("deactivated"));
+
+        assert_eq!(server.await.unwrap(), "/subscribe?compress=true");
+    }
+
+    #[test]
+    fn test_compressed_frame_without_dictionary() {
+        let client = JetstreamClient::new("ws://localhost".to_string());
+        assert!(matches!(
+            client.decode_frame(Message::Binary(vec![1, 2, 3])),
+            Err(JetstreamError::Decompression(_))
+        ));
+    }
+}
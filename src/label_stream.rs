@@ -0,0 +1,550 @@
+//! Typed label subscription stream
+//!
+//! This module decodes the `com.atproto.label.subscribeLabels` event stream
+//! into [`Label`] values, and can verify each label's signature against the
+//! labeler's `#atproto_label` key before handing it on.
+//!
+//! # Message Types
+//!
+//! - `#labels` - A batch of labels (and negations) with a stream sequence number
+//! - `#info` - Informational message from the server (e.g. `OutdatedCursor`)
+//!
+//! # Signatures
+//!
+//! A label's `sig` is an ECDSA signature over the DAG-CBOR encoding of the
+//! label with `sig` removed. [`LabelVerifier`] holds the label keys of the
+//! labelers you trust, usually taken from their DID documents. Labelers sign
+//! with [`sign_label`].
+//!
+//! # Example
+//!
+//! ```no_run
+//! use atproto::client::com::atproto::label::subscribe_labels;
+//! use atproto::did_doc::DidDocument;
+//! use atproto::label_stream::{self, LabelEvent, LabelVerifier};
+//! use atproto::moderation::{apply_subject_label, ModerationPrefs};
+//! use atproto::xrpc_subscription::SubscriptionClient;
+//! use futures::StreamExt;
+//!
+//! # async fn example(labeler_doc: DidDocument, prefs: ModerationPrefs) -> Result<(), Box<dyn std::error::Error>> {
+//! let client = SubscriptionClient::new("wss://mod.bsky.app".to_string());
+//! let params = subscribe_labels::QueryParams { cursor: None };
+//! let verifier = LabelVerifier::new().with_labeler(&labeler_doc)?;
+//!
+//! let events = label_stream::subscribe_labels(&client, params).await?;
+//! let mut events = label_stream::verified_label_events(events, verifier);
+//!
+//! while let Some(event) = events.next().await {
+//!     if let LabelEvent::Labels { labels, .. } = event? {
+//!         for label in &labels {
+//!             if let Some(cause) = apply_subject_label(label, &prefs) {
+//!                 println!("{} -> {:?}", label.uri, cause.cause_type());
+//!             }
+//!         }
+//!     }
+//! }
+//! # Ok(())
+//! # }
+//! ```
+
+use crate::client::com::atproto::label::defs::Label;
+use crate::client::com::atproto::label::subscribe_labels as subscribe_labels_lexicon;
+use crate::crypto::{CryptoError, Keypair, PublicKey};
+use crate::did_doc::DidDocument;
+use crate::firehose::{ipld_to_json, Info};
+use crate::xrpc::XrpcError;
+use crate::xrpc_subscription::{SubscriptionClient, SubscriptionEvent, SubscriptionResult};
+use futures::stream::{self, Stream, StreamExt};
+use libipld::codec::Codec;
+use libipld::Ipld;
+use std::collections::{BTreeMap, HashMap};
+use std::pin::Pin;
+use thiserror::Error;
+
+/// Error name reported for labels that fail verification
+pub const INVALID_LABEL_SIGNATURE: &str = "InvalidLabelSignature";
+
+/// Error types for label decoding and verification
+#[derive(Error, Debug)]
+pub enum LabelError {
+    #[error("CBOR error: {0}")]
+    Cbor(String),
+
+    #[error("Invalid message: {0}")]
+    InvalidMessage(String),
+
+    #[error("Label has no signature")]
+    MissingSignature,
+
+    #[error("No label key known for labeler {0}")]
+    UnknownLabeler(String),
+
+    #[error("DID document for {0} has no #atproto_label key")]
+    NoLabelKey(String),
+
+    #[error("Crypto error: {0}")]
+    Crypto(#[from] CryptoError),
+}
+
+/// Result type for label operations
+pub type Result<T> = std::result::Result<T, LabelError>;
+
+/// Stream of typed label events
+pub type LabelEventStream = Pin<Box<dyn Stream<Item = SubscriptionResult<LabelEvent>> + Send>>;
+
+/// A decoded `subscribeLabels` message
+#[derive(Debug, Clone)]
+pub enum LabelEvent {
+    /// Batch of labels (`#labels`)
+    Labels {
+        /// Stream sequence number
+        seq: i64,
+        labels: Vec<Label>,
+    },
+    Info(Info),
+    /// Message type not known to this client
+    Unknown {
+        message_type: String,
+        body: Vec<u8>,
+    },
+}
+
+impl LabelEvent {
+    /// Decode a message body given its frame header type (e.g. "#labels")
+    pub fn decode(message_type: &str, body: &[u8]) -> Result<Self> {
+        let decode_body = || {
+            libipld_cbor::DagCborCodec
+                .decode::<Ipld>(body)
+                .map_err(|e| LabelError::Cbor(format!("Failed to decode message body: {}", e)))
+        };
+
+        match message_type {
+            "#labels" => {
+                let json = ipld_to_json(&decode_body()?)
+                    .map_err(|e| LabelError::InvalidMessage(e.to_string()))?;
+
+                let seq = json
+                    .get("seq")
+                    .and_then(|v| v.as_i64())
+                    .ok_or_else(|| LabelError::InvalidMessage("Missing field: seq".to_string()))?;
+                let labels = json
+                    .get("labels")
+                    .cloned()
+                    .ok_or_else(|| LabelError::InvalidMessage("Missing field: labels".to_string()))?;
+                let labels = serde_json::from_value(labels)
+                    .map_err(|e| LabelError::InvalidMessage(format!("Invalid label: {}", e)))?;
+
+                Ok(LabelEvent::Labels { seq, labels })
+            }
+            "#info" => Info::from_ipld(&decode_body()?)
+                .map(LabelEvent::Info)
+                .map_err(|e| LabelError::InvalidMessage(e.to_string())),
+            _ => Ok(LabelEvent::Unknown {
+                message_type: message_type.to_string(),
+                body: body.to_vec(),
+            }),
+        }
+    }
+
+    /// Encode the message body, returning it with its frame header type
+    ///
+    /// The inverse of [`LabelEvent::decode`].
+    pub fn encode(&self) -> Result<(String, Vec<u8>)> {
+        let body = match self {
+            LabelEvent::Labels { seq, labels } => {
+                let labels = labels.iter().map(label_to_ipld).collect::<Result<Vec<_>>>()?;
+                let mut map = BTreeMap::new();
+                map.insert("seq".to_string(), Ipld::Integer(*seq as i128));
+                map.insert("labels".to_string(), Ipld::List(labels));
+                ("#labels", Ipld::Map(map))
+            }
+            LabelEvent::Info(info) => {
+                let mut map = BTreeMap::new();
+                map.insert("name".to_string(), Ipld::String(info.name.clone()));
+                if let Some(message) = &info.message {
+                    map.insert("message".to_string(), Ipld::String(message.clone()));
+                }
+                ("#info", Ipld::Map(map))
+            }
+            LabelEvent::Unknown { message_type, body } => return Ok((message_type.clone(), body.clone())),
+        };
+
+        let (message_type, ipld) = body;
+        let bytes = libipld_cbor::DagCborCodec
+            .encode(&ipld)
+            .map_err(|e| LabelError::Cbor(format!("Failed to encode message body: {}", e)))?;
+        Ok((message_type.to_string(), bytes))
+    }
+
+    /// Stream sequence number, for events that carry one
+    pub fn seq(&self) -> Option<i64> {
+        match self {
+            LabelEvent::Labels { seq, .. } => Some(*seq),
+            LabelEvent::Info(_) | LabelEvent::Unknown { .. } => None,
+        }
+    }
+}
+
+/// Subscribe to `com.atproto.label.subscribeLabels` and decode events
+///
+/// Error frames are surfaced as [`XrpcError::Xrpc`] and the stream ends when
+/// the connection closes. Signatures are not checked; wrap the stream with
+/// [`verified_label_events`] to do so.
+pub async fn subscribe_labels(
+    client: &SubscriptionClient,
+    params: subscribe_labels_lexicon::QueryParams,
+) -> SubscriptionResult<LabelEventStream> {
+    let stream = subscribe_labels_lexicon::subscribe_labels(client, params).await?;
+    Ok(label_events(stream))
+}
+
+/// Convert a raw subscription stream into typed label events
+///
+/// Works with both [`SubscriptionClient::subscribe`] and
+/// [`SubscriptionClient::subscribe_resilient`] streams.
+pub fn label_events<S>(stream: S) -> LabelEventStream
+where
+    S: Stream<Item = SubscriptionResult<SubscriptionEvent>> + Send + 'static,
+{
+    let events = stream
+        .take_while(|event| futures::future::ready(!matches!(event, Ok(SubscriptionEvent::Closed))))
+        .filter_map(|event| {
+            let event = match event {
+                Ok(SubscriptionEvent::Message { message_type, body }) => Some(
+                    LabelEvent::decode(&message_type, &body)
+                        .map_err(|e| XrpcError::Deserialization(e.to_string())),
+                ),
+                Ok(SubscriptionEvent::Error { error, message }) => Some(Err(XrpcError::Xrpc {
+                    error,
+                    message: message.unwrap_or_default(),
+                })),
+                // Reconnects are transparent to consumers of typed events
+                Ok(SubscriptionEvent::Closed)
+                | Ok(SubscriptionEvent::Reconnected { .. })
+                | Ok(SubscriptionEvent::ReconnectFailed { .. }) => None,
+                Ok(SubscriptionEvent::CheckpointFailed { cursor, error }) => Some(Err(XrpcError::Network(
+                    format!("Failed to save cursor {}: {}", cursor, error),
+                ))),
+                Err(e) => Some(Err(e)),
+            };
+            futures::future::ready(event)
+        });
+
+    Box::pin(events)
+}
+
+/// Verify label signatures in a typed label stream
+///
+/// Labels that fail verification are removed from their batch and reported
+/// as separate [`XrpcError::Xrpc`] items named [`INVALID_LABEL_SIGNATURE`], so
+/// one bad label doesn't discard the rest of the batch.
+pub fn verified_label_events(events: LabelEventStream, verifier: LabelVerifier) -> LabelEventStream {
+    let verified = events.flat_map(move |event| {
+        let items: Vec<SubscriptionResult<LabelEvent>> = match event {
+            Ok(LabelEvent::Labels { seq, labels }) => {
+                let mut items = Vec::new();
+                let mut valid = Vec::with_capacity(labels.len());
+
+                for label in labels {
+                    match verifier.verify(&label) {
+                        Ok(()) => valid.push(label),
+                        Err(e) => items.push(Err(XrpcError::Xrpc {
+                            error: INVALID_LABEL_SIGNATURE.to_string(),
+                            message: format!("{} on {} from {}: {}", label.val, label.uri, label.src.as_str(), e),
+                        })),
+                    }
+                }
+
+                if !valid.is_empty() {
+                    items.insert(0, Ok(LabelEvent::Labels { seq, labels: valid }));
+                }
+                items
+            }
+            other => vec![other],
+        };
+        stream::iter(items)
+    });
+
+    Box::pin(verified)
+}
+
+/// Label keys of trusted labelers, keyed by labeler DID
+#[derive(Debug, Clone, Default)]
+pub struct LabelVerifier {
+    keys: HashMap<String, PublicKey>,
+}
+
+impl LabelVerifier {
+    /// Create a verifier that trusts no labelers
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Trust a labeler's `#atproto_label` key from its DID document
+    pub fn with_labeler(self, did_doc: &DidDocument) -> Result<Self> {
+        let method = did_doc
+            .get_label_key()
+            .ok_or_else(|| LabelError::NoLabelKey(did_doc.id.clone()))?;
+        let key = PublicKey::from_verification_method(method)?;
+        Ok(self.with_key(did_doc.id.clone(), key))
+    }
+
+    /// Trust a labeler key directly
+    pub fn with_key(mut self, did: impl Into<String>, key: PublicKey) -> Self {
+        self.keys.insert(did.into(), key);
+        self
+    }
+
+    /// Verify a label against the key of its `src` labeler
+    pub fn verify(&self, label: &Label) -> Result<()> {
+        let key = self
+            .keys
+            .get(label.src.as_str())
+            .ok_or_else(|| LabelError::UnknownLabeler(label.src.as_str().to_string()))?;
+        verify_label(label, key)
+    }
+}
+
+/// Verify a label's signature against a labeler key
+pub fn verify_label(label: &Label, key: &PublicKey) -> Result<()> {
+    let sig = label_signature(label)?;
+    let bytes = label_signing_bytes(label)?;
+    key.verify(&bytes, &sig)?;
+    Ok(())
+}
+
+/// Sign a label with a labeler's `#atproto_label` key, replacing any existing `sig`
+pub fn sign_label(label: &mut Label, keypair: &Keypair) -> Result<()> {
+    use base64::{engine::general_purpose::STANDARD_NO_PAD, Engine};
+
+    let bytes = label_signing_bytes(label)?;
+    let sig = keypair.sign(&bytes);
+    label.sig = Some(serde_json::json!({ "$bytes": STANDARD_NO_PAD.encode(sig) }));
+    Ok(())
+}
+
+/// Wire (DAG-CBOR) form of a label, with `sig` as raw bytes
+pub fn label_to_ipld(label: &Label) -> Result<Ipld> {
+    let mut map = unsigned_label_ipld(label);
+    if label.sig.is_some() {
+        map.insert("sig".to_string(), Ipld::Bytes(label_signature(label)?));
+    }
+    Ok(Ipld::Map(map))
+}
+
+/// DAG-CBOR encoding of a label without its `sig`, as covered by the signature
+pub fn label_signing_bytes(label: &Label) -> Result<Vec<u8>> {
+    libipld_cbor::DagCborCodec
+        .encode(&Ipld::Map(unsigned_label_ipld(label)))
+        .map_err(|e| LabelError::Cbor(format!("Failed to encode label: {}", e)))
+}
+
+/// Raw signature bytes of a label
+///
+/// Labels decoded from the event stream carry `sig` in the atproto JSON form
+/// for bytes (`{"$bytes": "<base64>"}`).
+fn label_signature(label: &Label) -> Result<Vec<u8>> {
+    use base64::{engine::general_purpose::STANDARD_NO_PAD, Engine};
+
+    let encoded = label
+        .sig
+        .as_ref()
+        .ok_or(LabelError::MissingSignature)?
+        .get("$bytes")
+        .and_then(|v| v.as_str())
+        .ok_or_else(|| LabelError::InvalidMessage("sig is not a $bytes object".to_string()))?;
+
+    STANDARD_NO_PAD
+        .decode(encoded.trim_end_matches('='))
+        .map_err(|e| LabelError::InvalidMessage(format!("Invalid sig encoding: {}", e)))
+}
+
+fn unsigned_label_ipld(label: &Label) -> BTreeMap<String, Ipld> {
+    let mut map = BTreeMap::new();
+
+    if let Some(ver) = label.ver {
+        map.insert("ver".to_string(), Ipld::Integer(ver as i128));
+    }
+    map.insert("src".to_string(), Ipld::String(label.src.as_str().to_string()));
+    map.insert("uri".to_string(), Ipld::String(label.uri.to_string()));
+    if let Some(cid) = &label.cid {
+        map.insert("cid".to_string(), Ipld::String(cid.to_string()));
+    }
+    map.insert("val".to_string(), Ipld::String(label.val.clone()));
+    if let Some(neg) = label.neg {
+        map.insert("neg".to_string(), Ipld::Bool(neg));
+    }
+    map.insert("cts".to_string(), Ipld::String(label.cts.to_string()));
+    if let Some(exp) = &label.exp {
+        map.insert("exp".to_string(), Ipld::String(exp.to_string()));
+    }
+
+    map
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::did_doc::VerificationMethod;
+    use crate::types::{Datetime, Did, Uri};
+    use k256::ecdsa::signature::Signer;
+
+    fn test_signing_key() -> k256::ecdsa::SigningKey {
+        k256::ecdsa::SigningKey::from_slice(&[42u8; 32]).unwrap()
+    }
+
+    fn unsigned_label(val: &str) -> Label {
+        Label {
+            ver: Some(1),
+            sig: None,
+            uri: Uri::new_unchecked("at://did:plc:author/app.bsky.feed.post/3k2la"),
+            neg: None,
+            src: Did::new("did:plc:labeler").unwrap(),
+            cid: None,
+            cts: Datetime::new_unchecked("2024-01-01T00:00:00.000Z"),
+            val: val.to_string(),
+            exp: None,
+        }
+    }
+
+    /// Encode a signed label the way it appears on the wire
+    fn signed_label_ipld(label: &Label, key: &k256::ecdsa::SigningKey) -> Ipld {
+        let sig: k256::ecdsa::Signature = key.sign(&label_signing_bytes(label).unwrap());
+        let mut map = unsigned_label_ipld(label);
+        map.insert("sig".to_string(), Ipld::Bytes(sig.to_bytes().to_vec()));
+        Ipld::Map(map)
+    }
+
+    fn labels_body(seq: i64, labels: Vec<Ipld>) -> Vec<u8> {
+        let mut map = BTreeMap::new();
+        map.insert("seq".to_string(), Ipld::Integer(seq as i128));
+        map.insert("labels".to_string(), Ipld::List(labels));
+        libipld_cbor::DagCborCodec.encode(&Ipld::Map(map)).unwrap()
+    }
+
+    fn test_verifier() -> LabelVerifier {
+        LabelVerifier::new().with_key("did:plc:labeler", PublicKey::K256(*test_signing_key().verifying_key()))
+    }
+
+    #[test]
+    fn test_decode_and_verify_labels() {
+        let key = test_signing_key();
+        let body = labels_body(7, vec![signed_label_ipld(&unsigned_label("spam"), &key)]);
+
+        let LabelEvent::Labels { seq, labels } = LabelEvent::decode("#labels", &body).unwrap() else {
+            panic!("expected labels");
+        };
+        assert_eq!(seq, 7);
+        assert_eq!(labels[0].val, "spam");
+        assert!(labels[0].sig.as_ref().unwrap().get("$bytes").is_some());
+
+        assert!(test_verifier().verify(&labels[0]).is_ok());
+    }
+
+    #[test]
+    fn test_verify_rejects_tampered_label() {
+        let body = labels_body(1, vec![signed_label_ipld(&unsigned_label("spam"), &test_signing_key())]);
+        let LabelEvent::Labels { mut labels, .. } = LabelEvent::decode("#labels", &body).unwrap() else {
+            panic!("expected labels");
+        };
+
+        labels[0].val = "porn".to_string();
+        assert!(matches!(
+            test_verifier().verify(&labels[0]),
+            Err(LabelError::Crypto(CryptoError::InvalidSignature))
+        ));
+    }
+
+    #[test]
+    fn test_sign_label_and_encode() {
+        let keypair = Keypair::from_private_key(crate::crypto::KeyAlgorithm::K256, &[42u8; 32]).unwrap();
+        let mut label = unsigned_label("spam");
+        sign_label(&mut label, &keypair).unwrap();
+        assert!(test_verifier().verify(&label).is_ok());
+
+        // Round-trips through the wire encoding with the signature intact
+        let event = LabelEvent::Labels { seq: 3, labels: vec![label] };
+        let (message_type, body) = event.encode().unwrap();
+        assert_eq!(message_type, "#labels");
+        let LabelEvent::Labels { seq, labels } = LabelEvent::decode(&message_type, &body).unwrap() else {
+            panic!("expected labels");
+        };
+        assert_eq!(seq, 3);
+        assert!(test_verifier().verify(&labels[0]).is_ok());
+    }
+
+    #[test]
+    fn test_verify_unknown_labeler_and_missing_sig() {
+        let label = unsigned_label("spam");
+        assert!(matches!(LabelVerifier::new().verify(&label), Err(LabelError::UnknownLabeler(_))));
+        assert!(matches!(test_verifier().verify(&label), Err(LabelError::MissingSignature)));
+    }
+
+    #[test]
+    fn test_verifier_from_did_document() {
+        let public_key = PublicKey::K256(*test_signing_key().verifying_key());
+        let mut doc = DidDocument::from_json(
+            r#"{"id": "did:plc:labeler", "alsoKnownAs": [], "verificationMethod": [], "service": []}"#,
+        )
+        .unwrap();
+
+        assert!(matches!(LabelVerifier::new().with_labeler(&doc), Err(LabelError::NoLabelKey(_))));
+
+        doc.verification_method.push(VerificationMethod {
+            id: "did:plc:labeler#atproto_label".to_string(),
+            key_type: "Multikey".to_string(),
+            controller: "did:plc:labeler".to_string(),
+            public_key_multibase: Some(public_key.to_multibase()),
+        });
+
+        let verifier = LabelVerifier::new().with_labeler(&doc).unwrap();
+        let body = labels_body(1, vec![signed_label_ipld(&unsigned_label("spam"), &test_signing_key())]);
+        let LabelEvent::Labels { labels, .. } = LabelEvent::decode("#labels", &body).unwrap() else {
+            panic!("expected labels");
+        };
+        assert!(verifier.verify(&labels[0]).is_ok());
+    }
+
+    #[test]
+    fn test_decode_info() {
+        let mut map = BTreeMap::new();
+        map.insert("name".to_string(), Ipld::String("OutdatedCursor".to_string()));
+        let body = libipld_cbor::DagCborCodec.encode(&Ipld::Map(map)).unwrap();
+
+        let LabelEvent::Info(info) = LabelEvent::decode("#info", &body).unwrap() else {
+            panic!("expected info");
+        };
+        assert_eq!(info.name, "OutdatedCursor");
+    }
+
+    #[tokio::test]
+    async fn test_verified_label_events_stream() {
+        let key = test_signing_key();
+        let other_key = k256::ecdsa::SigningKey::from_slice(&[9u8; 32]).unwrap();
+
+        let body = labels_body(
+            3,
+            vec![
+                signed_label_ipld(&unsigned_label("spam"), &key),
+                signed_label_ipld(&unsigned_label("porn"), &other_key),
+            ],
+        );
+
+        let raw = stream::iter(vec![
+            Ok(SubscriptionEvent::Message { message_type: "#labels".to_string(), body }),
+            Ok(SubscriptionEvent::Closed),
+        ]);
+
+        let events: Vec<_> = verified_label_events(label_events(raw), test_verifier()).collect().await;
+        assert_eq!(events.len(), 2);
+
+        let Ok(LabelEvent::Labels { seq, labels }) = &events[0] else {
+            panic!("expected labels");
+        };
+        assert_eq!(*seq, 3);
+        assert_eq!(labels.len(), 1);
+        assert_eq!(labels[0].val, "spam");
+
+        assert!(matches!(&events[1], Err(XrpcError::Xrpc { error, .. }) if error == INVALID_LABEL_SIGNATURE));
+    }
+}
@@ -0,0 +1,403 @@
+//! Labeler services
+//!
+//! A labeler publishes signed `com.atproto.label.defs#label` records about
+//! accounts and content. [`Labeler`] creates and signs labels with the
+//! labeler's `#atproto_label` key, assigns them sequence numbers through a
+//! [`LabelStore`], and broadcasts them to live subscribers.
+//!
+//! With the `server` feature, `server::LabelerService` serves the labels
+//! through `com.atproto.label.queryLabels` and `com.atproto.label.subscribeLabels`.
+//!
+//! # Storage
+//!
+//! Labels are an append-only log: negations are new entries rather than
+//! deletions, so subscribers can replay the log from any cursor. The store
+//! lives behind the [`LabelStore`] trait so it can be backed by a database;
+//! [`MemoryLabelStore`] is used by default.
+//!
+//! # Example
+//!
+//! ```no_run
+//! use atproto::crypto::{KeyAlgorithm, Keypair};
+//! use atproto::labeler::{Labeler, NewLabel};
+//! use atproto::types::Did;
+//!
+//! # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+//! let keypair = Keypair::generate(KeyAlgorithm::K256);
+//! let labeler = Labeler::new(Did::new("did:plc:labeler")?, keypair);
+//!
+//! let post = "at://did:plc:alice/app.bsky.feed.post/3k2a";
+//! labeler.emit(NewLabel::new(post, "spam")).await?;
+//!
+//! // Later, retract it
+//! labeler.emit(NewLabel::new(post, "spam").negated()).await?;
+//! # Ok(())
+//! # }
+//! ```
+
+use crate::client::com::atproto::label::defs::Label;
+use crate::crypto::{Keypair, PublicKey};
+use crate::label_stream::{sign_label, LabelError};
+use crate::types::{CidLink, Datetime, Did, Uri};
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use parking_lot::RwLock;
+use std::sync::Arc;
+use thiserror::Error;
+use tokio::sync::{broadcast, Mutex};
+
+/// Longest allowed label value, in bytes
+pub const MAX_LABEL_VALUE_LEN: usize = 128;
+
+/// Number of labels buffered for slow live subscribers
+const BROADCAST_CAPACITY: usize = 1024;
+
+/// Error types for labelers
+#[derive(Debug, Error)]
+pub enum LabelerError {
+    #[error("Invalid label: {0}")]
+    InvalidLabel(String),
+
+    #[error("Label error: {0}")]
+    Label(#[from] LabelError),
+
+    #[error("Label store error: {0}")]
+    Store(String),
+}
+
+/// Result type for labeler operations
+pub type Result<T> = std::result::Result<T, LabelerError>;
+
+/// A label with its position in the labeler's log
+#[derive(Debug, Clone)]
+pub struct SequencedLabel {
+    pub seq: i64,
+    pub label: Label,
+}
+
+/// Filter for [`LabelStore::query`], mirroring `com.atproto.label.queryLabels`
+#[derive(Debug, Clone)]
+pub struct LabelQuery {
+    /// URIs to match; a trailing `*` matches by prefix
+    pub uri_patterns: Vec<String>,
+
+    /// Labeler DIDs to match; empty matches any source
+    pub sources: Vec<String>,
+
+    /// Only return labels with a greater sequence number
+    pub cursor: Option<i64>,
+
+    /// Maximum number of labels to return
+    pub limit: usize,
+}
+
+impl LabelQuery {
+    /// Create a query for labels on URIs matching `uri_patterns`
+    pub fn new(uri_patterns: Vec<String>) -> Self {
+        Self {
+            uri_patterns,
+            sources: Vec::new(),
+            cursor: None,
+            limit: 50,
+        }
+    }
+
+    /// Whether a label matches the URI patterns and sources (ignoring cursor and limit)
+    pub fn matches(&self, label: &Label) -> bool {
+        let uri_matches = self.uri_patterns.iter().any(|pattern| match pattern.strip_suffix('*') {
+            Some(prefix) => label.uri.as_str().starts_with(prefix),
+            None => label.uri.as_str() == pattern,
+        });
+        let source_matches = self.sources.is_empty() || self.sources.iter().any(|src| src == label.src.as_str());
+
+        uri_matches && source_matches
+    }
+}
+
+/// Storage for a labeler's label log
+///
+/// Sequence numbers start at 1 and increase with every appended label.
+#[async_trait]
+pub trait LabelStore: Send + Sync {
+    /// Persist a signed label, assigning it the next sequence number
+    async fn append(&self, label: Label) -> Result<SequencedLabel>;
+
+    /// Labels with a sequence number greater than `cursor`, oldest first
+    async fn after(&self, cursor: i64, limit: usize) -> Result<Vec<SequencedLabel>>;
+
+    /// Labels matching `query`, oldest first
+    async fn query(&self, query: &LabelQuery) -> Result<Vec<SequencedLabel>>;
+
+    /// The highest assigned sequence number, if any label was stored
+    async fn latest_seq(&self) -> Result<Option<i64>>;
+}
+
+/// In-memory label store
+#[derive(Debug, Default)]
+pub struct MemoryLabelStore {
+    labels: RwLock<Vec<SequencedLabel>>,
+}
+
+impl MemoryLabelStore {
+    /// Create an empty store
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+#[async_trait]
+impl LabelStore for MemoryLabelStore {
+    async fn append(&self, label: Label) -> Result<SequencedLabel> {
+        let mut labels = self.labels.write();
+        let entry = SequencedLabel {
+            seq: labels.len() as i64 + 1,
+            label,
+        };
+        labels.push(entry.clone());
+        Ok(entry)
+    }
+
+    async fn after(&self, cursor: i64, limit: usize) -> Result<Vec<SequencedLabel>> {
+        // Sequence numbers are positions in the log, starting at 1
+        let start = cursor.max(0) as usize;
+        Ok(self.labels.read().iter().skip(start).take(limit).cloned().collect())
+    }
+
+    async fn query(&self, query: &LabelQuery) -> Result<Vec<SequencedLabel>> {
+        let start = query.cursor.unwrap_or(0).max(0) as usize;
+        Ok(self
+            .labels
+            .read()
+            .iter()
+            .skip(start)
+            .filter(|entry| query.matches(&entry.label))
+            .take(query.limit)
+            .cloned()
+            .collect())
+    }
+
+    async fn latest_seq(&self) -> Result<Option<i64>> {
+        let len = self.labels.read().len() as i64;
+        Ok((len > 0).then_some(len))
+    }
+}
+
+/// A label to be created and signed by a [`Labeler`]
+#[derive(Debug, Clone)]
+pub struct NewLabel {
+    uri: String,
+    val: String,
+    cid: Option<String>,
+    neg: bool,
+    exp: Option<DateTime<Utc>>,
+}
+
+impl NewLabel {
+    /// Label the account or record at `uri` (a DID or AT URI) with `val`
+    pub fn new(uri: impl Into<String>, val: impl Into<String>) -> Self {
+        Self {
+            uri: uri.into(),
+            val: val.into(),
+            cid: None,
+            neg: false,
+            exp: None,
+        }
+    }
+
+    /// Only label a specific version of the record
+    pub fn with_cid(mut self, cid: impl Into<String>) -> Self {
+        self.cid = Some(cid.into());
+        self
+    }
+
+    /// Make the label expire at `exp`
+    pub fn with_expiry(mut self, exp: DateTime<Utc>) -> Self {
+        self.exp = Some(exp);
+        self
+    }
+
+    /// Retract a previously applied label instead of applying it
+    pub fn negated(mut self) -> Self {
+        self.neg = true;
+        self
+    }
+
+    fn validate(&self) -> Result<()> {
+        if !(self.uri.starts_with("at://") || self.uri.starts_with("did:")) {
+            return Err(LabelerError::InvalidLabel(format!("Subject must be a DID or AT URI: {}", self.uri)));
+        }
+        if self.val.is_empty() || self.val.len() > MAX_LABEL_VALUE_LEN {
+            return Err(LabelerError::InvalidLabel(format!(
+                "Value must be 1-{} bytes: {:?}",
+                MAX_LABEL_VALUE_LEN, self.val
+            )));
+        }
+        Ok(())
+    }
+}
+
+/// A labeler: signs labels, stores them and broadcasts them to subscribers
+pub struct Labeler {
+    did: Did,
+    keypair: Keypair,
+    store: Arc<dyn LabelStore>,
+    events: broadcast::Sender<SequencedLabel>,
+    /// Keeps appends and broadcasts in sequence order
+    emit_lock: Mutex<()>,
+}
+
+impl Labeler {
+    /// Create a labeler signing with `keypair`, its `#atproto_label` key
+    pub fn new(did: Did, keypair: Keypair) -> Self {
+        let (events, _) = broadcast::channel(BROADCAST_CAPACITY);
+        Self {
+            did,
+            keypair,
+            store: Arc::new(MemoryLabelStore::new()),
+            events,
+            emit_lock: Mutex::new(()),
+        }
+    }
+
+    /// Use a custom label store
+    pub fn with_store(mut self, store: impl LabelStore + 'static) -> Self {
+        self.store = Arc::new(store);
+        self
+    }
+
+    /// The labeler DID
+    pub fn did(&self) -> &Did {
+        &self.did
+    }
+
+    /// The label signing key, to publish as `#atproto_label` in the DID document
+    pub fn public_key(&self) -> PublicKey {
+        self.keypair.public_key()
+    }
+
+    /// The label store
+    pub fn store(&self) -> &dyn LabelStore {
+        self.store.as_ref()
+    }
+
+    /// Create and sign a label without storing it
+    pub fn create(&self, new: NewLabel) -> Result<Label> {
+        new.validate()?;
+
+        let mut label = Label {
+            ver: Some(1),
+            sig: None,
+            uri: Uri::new(new.uri).map_err(|e| LabelerError::InvalidLabel(e.to_string()))?,
+            neg: new.neg.then_some(true),
+            src: self.did.clone(),
+            cid: new
+                .cid
+                .map(CidLink::new)
+                .transpose()
+                .map_err(|e| LabelerError::InvalidLabel(e.to_string()))?,
+            cts: Datetime::now(),
+            val: new.val,
+            exp: new.exp.map(Datetime::from),
+        };
+        sign_label(&mut label, &self.keypair)?;
+        Ok(label)
+    }
+
+    /// Create, sign and store a label, then broadcast it to subscribers
+    pub async fn emit(&self, new: NewLabel) -> Result<SequencedLabel> {
+        let label = self.create(new)?;
+
+        let _guard = self.emit_lock.lock().await;
+        let entry = self.store.append(label).await?;
+        // No receivers is fine; the label is in the store for backfill
+        let _ = self.events.send(entry.clone());
+        Ok(entry)
+    }
+
+    /// Receive labels as they are emitted
+    ///
+    /// Subscribe before backfilling from the store, and skip labels whose
+    /// sequence number was already seen, to avoid gaps.
+    pub fn subscribe(&self) -> broadcast::Receiver<SequencedLabel> {
+        self.events.subscribe()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::crypto::KeyAlgorithm;
+    use crate::label_stream::verify_label;
+
+    const POST: &str = "at://did:plc:alice/app.bsky.feed.post/3k2a";
+
+    fn labeler() -> Labeler {
+        Labeler::new(Did::new("did:plc:labeler").unwrap(), Keypair::generate(KeyAlgorithm::K256))
+    }
+
+    #[tokio::test]
+    async fn test_emit_signs_and_sequences() {
+        let labeler = labeler();
+        let mut events = labeler.subscribe();
+
+        let first = labeler.emit(NewLabel::new(POST, "spam")).await.unwrap();
+        let second = labeler
+            .emit(NewLabel::new("did:plc:alice", "impersonation").with_expiry(Utc::now()))
+            .await
+            .unwrap();
+        let third = labeler.emit(NewLabel::new(POST, "spam").negated()).await.unwrap();
+
+        assert_eq!((first.seq, second.seq, third.seq), (1, 2, 3));
+        assert_eq!(first.label.src.as_str(), "did:plc:labeler");
+        assert_eq!(first.label.ver, Some(1));
+        assert_eq!(first.label.neg, None);
+        assert_eq!(third.label.neg, Some(true));
+        assert!(second.label.exp.as_ref().unwrap().as_str().ends_with('Z'));
+        for entry in [&first, &second, &third] {
+            verify_label(&entry.label, &labeler.public_key()).unwrap();
+        }
+
+        assert_eq!(events.recv().await.unwrap().seq, 1);
+        assert_eq!(labeler.store().latest_seq().await.unwrap(), Some(3));
+        let after: Vec<i64> = labeler.store().after(1, 10).await.unwrap().iter().map(|e| e.seq).collect();
+        assert_eq!(after, vec![2, 3]);
+    }
+
+    #[tokio::test]
+    async fn test_invalid_labels() {
+        let labeler = labeler();
+        assert!(matches!(labeler.create(NewLabel::new("https://example.com", "spam")), Err(LabelerError::InvalidLabel(_))));
+        assert!(matches!(labeler.create(NewLabel::new(POST, "")), Err(LabelerError::InvalidLabel(_))));
+        assert!(matches!(
+            labeler.create(NewLabel::new(POST, "x".repeat(MAX_LABEL_VALUE_LEN + 1))),
+            Err(LabelerError::InvalidLabel(_))
+        ));
+        assert_eq!(labeler.store().latest_seq().await.unwrap(), None);
+    }
+
+    #[tokio::test]
+    async fn test_query() {
+        let labeler = labeler();
+        labeler.emit(NewLabel::new(POST, "spam")).await.unwrap();
+        labeler.emit(NewLabel::new("at://did:plc:alice/app.bsky.feed.post/3k2b", "rude")).await.unwrap();
+        labeler.emit(NewLabel::new("at://did:plc:bob/app.bsky.feed.post/3k2c", "spam")).await.unwrap();
+        let store = labeler.store();
+
+        let exact = store.query(&LabelQuery::new(vec![POST.to_string()])).await.unwrap();
+        assert_eq!(exact.len(), 1);
+
+        let mut query = LabelQuery::new(vec!["at://did:plc:alice/*".to_string()]);
+        assert_eq!(store.query(&query).await.unwrap().len(), 2);
+
+        query.limit = 1;
+        let page = store.query(&query).await.unwrap();
+        assert_eq!(page[0].seq, 1);
+        query.cursor = Some(page[0].seq);
+        let page = store.query(&query).await.unwrap();
+        assert_eq!(page[0].seq, 2);
+
+        let mut other_source = LabelQuery::new(vec!["*".to_string()]);
+        other_source.sources = vec!["did:plc:other".to_string()];
+        assert!(store.query(&other_source).await.unwrap().is_empty());
+    }
+}
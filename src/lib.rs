@@ -27,18 +27,31 @@ pub mod blob;
 pub mod car;
 pub mod client;
 pub mod consts;
+pub mod crypto;
+pub mod cursor_store;
 pub mod did_doc;
+pub mod firehose;
 pub mod handle;
+pub mod identity;
+pub mod jetstream;
+pub mod label_stream;
+pub mod labeler;
 pub mod moderation;
 pub mod mst;
 pub mod namespaces;
 pub mod oauth;
+pub mod plc;
 pub mod preferences;
 pub mod repo;
 pub mod rich_text;
+#[cfg(feature = "server")]
+pub mod server;
 pub mod server_auth;
+pub mod service_auth;
 pub mod session_manager;
 pub mod syntax;
+#[cfg(test)]
+pub(crate) mod test_support;
 pub mod tid;
 pub mod types;
 pub mod util;
@@ -86,7 +99,7 @@ pub use preferences::{
 };
 
 // Re-export validation types
-pub use validation::{LexiconSchema, ValidationError, ValidationResult, StringFormat};
+pub use validation::{LexiconRegistry, LexiconSchema, ValidationError, ValidationResult, StringFormat};
 
 // Re-export server auth types
 pub use server_auth::{
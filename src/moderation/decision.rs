@@ -5,6 +5,7 @@
 use super::labels::*;
 use super::types::*;
 use super::ui::*;
+use crate::client::com::atproto::label::defs::Label;
 
 /// A single reason why content is being moderated
 #[derive(Debug, Clone)]
@@ -342,6 +343,43 @@ pub fn apply_label(
     })
 }
 
+/// Apply a label object, such as one received from a labeler's stream
+///
+/// The target is derived from the label's subject URI and the labeler from
+/// its `src`. Negations and expired labels produce no cause.
+pub fn apply_subject_label(label: &Label, prefs: &ModerationPrefs) -> Option<ModerationCause> {
+    if label.neg == Some(true) {
+        return None;
+    }
+
+    if let Some(exp) = &label.exp {
+        let expired = chrono::DateTime::parse_from_rfc3339(exp.as_str())
+            .map(|exp| exp < chrono::Utc::now())
+            .unwrap_or(false);
+        if expired {
+            return None;
+        }
+    }
+
+    apply_label(&label.val, label_target(label.uri.as_str()), prefs, Some(label.src.as_str()))
+}
+
+/// Determine what a label applies to from its subject URI
+///
+/// Bare DIDs and repository URIs target the account, the profile record
+/// targets the profile, and any other record targets content.
+pub fn label_target(uri: &str) -> LabelTarget {
+    let Some(path) = uri.strip_prefix("at://") else {
+        return LabelTarget::Account;
+    };
+
+    match path.split_once('/') {
+        None => LabelTarget::Account,
+        Some((_, rest)) if rest.starts_with("app.bsky.actor.profile/") => LabelTarget::Profile,
+        Some(_) => LabelTarget::Content,
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -354,6 +392,46 @@ mod tests {
         assert!(decision.causes.is_empty());
     }
 
+    fn subject_label(uri: &str, val: &str) -> Label {
+        Label {
+            ver: Some(1),
+            sig: None,
+            uri: crate::types::Uri::new_unchecked(uri),
+            neg: None,
+            src: crate::types::Did::new("did:plc:labeler").unwrap(),
+            cid: None,
+            cts: crate::types::Datetime::new_unchecked("2024-01-01T00:00:00.000Z"),
+            val: val.to_string(),
+            exp: None,
+        }
+    }
+
+    #[test]
+    fn test_label_target() {
+        assert_eq!(label_target("did:plc:abc"), LabelTarget::Account);
+        assert_eq!(label_target("at://did:plc:abc"), LabelTarget::Account);
+        assert_eq!(label_target("at://did:plc:abc/app.bsky.actor.profile/self"), LabelTarget::Profile);
+        assert_eq!(label_target("at://did:plc:abc/app.bsky.feed.post/3k2la"), LabelTarget::Content);
+    }
+
+    #[test]
+    fn test_apply_subject_label() {
+        let prefs = ModerationPrefs::default();
+        let label = subject_label("at://did:plc:abc/app.bsky.feed.post/3k2la", "!hide");
+
+        let Some(ModerationCause::Label { source, target, .. }) = apply_subject_label(&label, &prefs) else {
+            panic!("Expected Label cause");
+        };
+        assert_eq!(target, LabelTarget::Content);
+        assert!(matches!(source, ModerationCauseSource::Labeler { did } if did == "did:plc:labeler"));
+
+        let negated = Label { neg: Some(true), ..label.clone() };
+        assert!(apply_subject_label(&negated, &prefs).is_none());
+
+        let expired = Label { exp: Some(crate::types::Datetime::new_unchecked("2000-01-01T00:00:00Z")), ..label };
+        assert!(apply_subject_label(&expired, &prefs).is_none());
+    }
+
     #[test]
     fn test_apply_label_porn_hide() {
         let mut prefs = ModerationPrefs::default();
@@ -7,11 +7,28 @@
 //! # Specification
 //!
 //! - Uses SHA-256 hashing
-//! - Fanout of 16 (counting 2 bits at a time for layer calculation)
+//! - Fanout of 4 (counting leading zeros 2 bits at a time for layer calculation)
 //! - Keys are lexically sorted
-//! - Nodes serialized with DAG-CBOR
+//! - Nodes serialized with DAG-CBOR, with keys prefix-compressed within a node
 //! - Content-addressed with CIDs
 //!
+//! # Node Format
+//!
+//! ```text
+//! {
+//!   "l": <CID|null>,         // subtree with keys before the first entry
+//!   "e": [{
+//!     "p": <int>,            // bytes shared with the previous entry's key
+//!     "k": <bytes>,          // remainder of the key
+//!     "v": <CID>,            // record value
+//!     "t": <CID|null>        // subtree with keys between this entry and the next
+//!   }]
+//! }
+//! ```
+//!
+//! The shape of the tree depends only on the set of keys, so the same records
+//! always produce the same root CID.
+//!
 //! # References
 //!
 //! - ATProto Repository Spec: https://atproto.com/specs/repository
@@ -21,7 +38,8 @@ use libipld::cid::Cid;
 use libipld::codec::Codec;
 use libipld::Ipld;
 use sha2::{Digest, Sha256};
-use std::collections::BTreeMap;
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::sync::OnceLock;
 use thiserror::Error;
 
 /// Error types for MST operations
@@ -41,69 +59,83 @@ pub enum MstError {
 
     #[error("Invalid node structure: {0}")]
     InvalidNode(String),
+
+    #[error("Missing block: {0}")]
+    MissingBlock(String),
 }
 
 /// Result type for MST operations
 pub type Result<T> = std::result::Result<T, MstError>;
 
-/// Fanout parameter for MST (2^4 = 16)
-/// Currently unused but part of MST specification for future enhancements
+/// Fanout parameter for MST (2^2 = 4)
 #[allow(dead_code)]
-const FANOUT: u8 = 16;
+const FANOUT: u8 = 4;
 
 /// Number of bits to count at a time (log2(FANOUT))
-/// Currently unused but part of MST specification for future enhancements
-#[allow(dead_code)]
-const BITS_PER_LAYER: u8 = 4;
-
-/// Maximum number of entries per node (security limit against key mining attacks)
-/// Will be used when split_node is enabled (currently disabled per TODO at line 375)
-#[allow(dead_code)]
-const MAX_ENTRIES_PER_NODE: usize = 32;
+const BITS_PER_LAYER: u32 = 2;
 
-/// MST Tree Entry - represents either a leaf value or a subtree pointer
+/// MST Tree Entry - a key/value pair plus the subtree that follows it
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct MstEntry {
-    /// Key prefix for this entry
+    /// Full key (prefix-compressed only when serialized)
     pub key: String,
 
-    /// CID pointing to the value (for leaf entries) or subtree (for tree entries)
+    /// CID pointing to the value
     pub value_cid: Cid,
 
-    /// CID of subtree node (only present for tree entries)
+    /// CID of the subtree holding keys between this entry and the next
     pub tree_cid: Option<Cid>,
 }
 
 impl MstEntry {
-    /// Convert to IPLD representation
-    fn to_ipld(&self) -> Ipld {
+    /// Convert to IPLD representation, compressing the key against `prev_key`
+    fn to_ipld(&self, prev_key: &str) -> Ipld {
+        let prefix_len = common_prefix_len(prev_key.as_bytes(), self.key.as_bytes());
+
         let mut map = BTreeMap::new();
-        map.insert("k".to_string(), Ipld::String(self.key.clone()));
+        map.insert("p".to_string(), Ipld::Integer(prefix_len as i128));
+        map.insert("k".to_string(), Ipld::Bytes(self.key.as_bytes()[prefix_len..].to_vec()));
         map.insert("v".to_string(), Ipld::Link(self.value_cid));
-        if let Some(ref tree_cid) = self.tree_cid {
-            map.insert("t".to_string(), Ipld::Link(*tree_cid));
-        }
+        map.insert(
+            "t".to_string(),
+            self.tree_cid.map(Ipld::Link).unwrap_or(Ipld::Null),
+        );
         Ipld::Map(map)
     }
 
-    /// Create from IPLD representation
-    fn from_ipld(ipld: &Ipld) -> Result<Self> {
+    /// Create from IPLD representation, expanding the key against `prev_key`
+    fn from_ipld(ipld: &Ipld, prev_key: &str) -> Result<Self> {
         if let Ipld::Map(map) = ipld {
-            let key = match map.get("k") {
-                Some(Ipld::String(s)) => s.clone(),
+            let prefix_len = match map.get("p") {
+                Some(Ipld::Integer(p)) => usize::try_from(*p)
+                    .map_err(|_| MstError::InvalidNode(format!("Invalid prefix length: {}", p)))?,
+                _ => return Err(MstError::InvalidNode("Missing or invalid prefix length in entry".to_string())),
+            };
+
+            let suffix = match map.get("k") {
+                Some(Ipld::Bytes(b)) => b,
                 _ => return Err(MstError::InvalidNode("Missing or invalid key in entry".to_string())),
             };
 
+            if prefix_len > prev_key.len() {
+                return Err(MstError::InvalidNode(format!(
+                    "Prefix length {} exceeds previous key length {}",
+                    prefix_len,
+                    prev_key.len()
+                )));
+            }
+
+            let mut key_bytes = prev_key.as_bytes()[..prefix_len].to_vec();
+            key_bytes.extend_from_slice(suffix);
+            let key = String::from_utf8(key_bytes)
+                .map_err(|_| MstError::InvalidNode("Key is not valid UTF-8".to_string()))?;
+
             let value_cid = match map.get("v") {
                 Some(Ipld::Link(cid)) => *cid,
                 _ => return Err(MstError::InvalidNode("Missing or invalid value CID in entry".to_string())),
             };
 
-            let tree_cid = match map.get("t") {
-                Some(Ipld::Link(cid)) => Some(*cid),
-                None => None,
-                _ => return Err(MstError::InvalidNode("Invalid tree CID in entry".to_string())),
-            };
+            let tree_cid = get_optional_link(map, "t")?;
 
             Ok(Self {
                 key,
@@ -120,8 +152,13 @@ impl MstEntry {
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct MstNode {
     /// Layer number (0 = leaf layer)
+    ///
+    /// Not serialized; it is implied by the keys of the node's entries.
     pub layer: u32,
 
+    /// CID of the subtree holding keys before the first entry
+    pub left: Option<Cid>,
+
     /// Entries in this node (sorted by key)
     pub entries: Vec<MstEntry>,
 }
@@ -131,45 +168,57 @@ impl MstNode {
     pub fn new(layer: u32) -> Self {
         Self {
             layer,
+            left: None,
             entries: Vec::new(),
         }
     }
 
     /// Create a new MST node with entries
     pub fn with_entries(layer: u32, entries: Vec<MstEntry>) -> Self {
-        Self { layer, entries }
+        Self {
+            layer,
+            left: None,
+            entries,
+        }
     }
 
     /// Convert to IPLD representation
     fn to_ipld(&self) -> Ipld {
+        let mut entries = Vec::with_capacity(self.entries.len());
+        let mut prev_key = "";
+        for entry in &self.entries {
+            entries.push(entry.to_ipld(prev_key));
+            prev_key = &entry.key;
+        }
+
         let mut map = BTreeMap::new();
-        map.insert("l".to_string(), Ipld::Integer(self.layer as i128));
-        map.insert(
-            "e".to_string(),
-            Ipld::List(self.entries.iter().map(|e| e.to_ipld()).collect()),
-        );
+        map.insert("l".to_string(), self.left.map(Ipld::Link).unwrap_or(Ipld::Null));
+        map.insert("e".to_string(), Ipld::List(entries));
         Ipld::Map(map)
     }
 
     /// Create from IPLD representation
+    ///
+    /// The layer is derived from the first entry's key (0 for a node without
+    /// entries).
     fn from_ipld(ipld: &Ipld) -> Result<Self> {
         if let Ipld::Map(map) = ipld {
-            let layer = match map.get("l") {
-                Some(Ipld::Integer(i)) => *i as u32,
-                _ => return Err(MstError::InvalidNode("Missing or invalid layer field".to_string())),
-            };
+            let left = get_optional_link(map, "l")?;
 
             let entries_ipld = match map.get("e") {
                 Some(Ipld::List(list)) => list,
                 _ => return Err(MstError::InvalidNode("Missing or invalid entries field".to_string())),
             };
 
-            let mut entries = Vec::new();
+            let mut entries: Vec<MstEntry> = Vec::with_capacity(entries_ipld.len());
             for entry_ipld in entries_ipld {
-                entries.push(MstEntry::from_ipld(entry_ipld)?);
+                let prev_key = entries.last().map(|e| e.key.as_str()).unwrap_or("");
+                entries.push(MstEntry::from_ipld(entry_ipld, prev_key)?);
             }
 
-            Ok(Self { layer, entries })
+            let layer = entries.first().map(|e| calculate_key_layer(&e.key)).unwrap_or(0);
+
+            Ok(Self { layer, left, entries })
         } else {
             Err(MstError::InvalidNode("Node is not a map".to_string()))
         }
@@ -249,72 +298,247 @@ impl MstNode {
 
 /// Calculate the layer for a given key based on leading zero bits in its hash
 ///
-/// The layer is determined by counting leading zeros in 2-bit chunks (fanout=16).
-/// This creates a probabilistic distribution where higher layers are exponentially
-/// less likely.
+/// The layer is the number of leading zero bits in the SHA-256 hash of the
+/// key, divided by 2 and rounded down (fanout=4). This creates a probabilistic
+/// distribution where each layer is four times less likely than the one below.
 pub fn calculate_key_layer(key: &str) -> u32 {
     let hash = Sha256::digest(key.as_bytes());
 
-    let mut layer = 0u32;
-
-    // Count leading zeros in 2-bit chunks
+    let mut leading_zeros = 0u32;
     for byte in hash.iter() {
-        // Process each byte in 2-bit chunks (4 chunks per byte)
-        for shift in (0..8).step_by(2).rev() {
-            let two_bits = (byte >> shift) & 0b11;
-
-            if two_bits == 0 {
-                layer += 1;
-            } else {
-                // Found non-zero chunk, stop counting
-                return layer;
+        leading_zeros += byte.leading_zeros();
+        if *byte != 0 {
+            break;
+        }
+    }
+
+    leading_zeros / BITS_PER_LAYER
+}
+
+/// Number of leading bytes shared by two keys
+fn common_prefix_len(a: &[u8], b: &[u8]) -> usize {
+    a.iter().zip(b).take_while(|(x, y)| x == y).count()
+}
+
+fn get_optional_link(map: &BTreeMap<String, Ipld>, field: &str) -> Result<Option<Cid>> {
+    match map.get(field) {
+        Some(Ipld::Link(cid)) => Ok(Some(*cid)),
+        Some(Ipld::Null) | None => Ok(None),
+        _ => Err(MstError::InvalidNode(format!("Invalid {} field", field))),
+    }
+}
+
+/// Node structure of an MST, derived from its key set
+struct Tree {
+    /// CID of the root node
+    root_cid: Cid,
+
+    /// All nodes by CID, including the root
+    nodes: BTreeMap<Cid, MstNode>,
+}
+
+impl Tree {
+    /// Build the canonical tree for a sorted set of entries
+    fn build(entries: &BTreeMap<String, Cid>) -> Result<Self> {
+        let items: Vec<(&str, Cid, u32)> = entries
+            .iter()
+            .map(|(key, cid)| (key.as_str(), *cid, calculate_key_layer(key)))
+            .collect();
+
+        let layer = items.iter().map(|(_, _, layer)| *layer).max().unwrap_or(0);
+
+        let mut nodes = BTreeMap::new();
+        let root = Self::build_node(&items, layer, &mut nodes)?;
+        let root_cid = root.to_cid()?;
+        nodes.insert(root_cid, root);
+
+        Ok(Self { root_cid, nodes })
+    }
+
+    /// Build the node at `layer` holding `items`, storing its subtrees
+    ///
+    /// Items above `layer` must already have been split out by the caller.
+    fn build_node(
+        items: &[(&str, Cid, u32)],
+        layer: u32,
+        nodes: &mut BTreeMap<Cid, MstNode>,
+    ) -> Result<MstNode> {
+        let mut node = MstNode::new(layer);
+        let mut segment_start = 0;
+
+        for (i, (key, cid, key_layer)) in items.iter().enumerate() {
+            if *key_layer < layer {
+                continue;
             }
+
+            let subtree = Self::build_subtree(&items[segment_start..i], layer, nodes)?;
+            match node.entries.last_mut() {
+                Some(prev) => prev.tree_cid = subtree,
+                None => node.left = subtree,
+            }
+
+            node.entries.push(MstEntry {
+                key: key.to_string(),
+                value_cid: *cid,
+                tree_cid: None,
+            });
+            segment_start = i + 1;
         }
+
+        let subtree = Self::build_subtree(&items[segment_start..], layer, nodes)?;
+        match node.entries.last_mut() {
+            Some(prev) => prev.tree_cid = subtree,
+            None => node.left = subtree,
+        }
+
+        Ok(node)
     }
 
-    layer
+    /// Build the subtree one layer below `layer` for the keys between two entries
+    fn build_subtree(
+        items: &[(&str, Cid, u32)],
+        layer: u32,
+        nodes: &mut BTreeMap<Cid, MstNode>,
+    ) -> Result<Option<Cid>> {
+        if items.is_empty() || layer == 0 {
+            return Ok(None);
+        }
+
+        let node = Self::build_node(items, layer - 1, nodes)?;
+        let cid = node.to_cid()?;
+        nodes.insert(cid, node);
+        Ok(Some(cid))
+    }
 }
 
 /// In-memory MST implementation
 ///
-/// This provides a complete, mutable MST that can be manipulated and
-/// then serialized to CID-addressed nodes for persistence.
+/// Records are kept in key order; the node structure is derived from them
+/// when first needed after a change (for example by [`Mst::root_cid`]) and
+/// cached until the next change.
 pub struct Mst {
-    /// Root node of the tree
-    root: MstNode,
-
-    /// In-memory storage of all nodes by CID
-    nodes: BTreeMap<Cid, MstNode>,
+    /// Keys and the CIDs of their values
+    entries: BTreeMap<String, Cid>,
 
     /// In-memory storage of leaf values by CID
     leaves: BTreeMap<Cid, Vec<u8>>,
+
+    /// Number of keys pointing at each leaf
+    leaf_refs: HashMap<Cid, usize>,
+
+    /// Node structure for the current entries
+    tree: OnceLock<Tree>,
 }
 
 impl Mst {
     /// Create a new empty MST
     pub fn new() -> Self {
         Self {
-            root: MstNode::new(0),
-            nodes: BTreeMap::new(),
+            entries: BTreeMap::new(),
             leaves: BTreeMap::new(),
+            leaf_refs: HashMap::new(),
+            tree: OnceLock::new(),
         }
     }
 
+    /// Rebuild an MST from its stored blocks
+    ///
+    /// `blocks` must contain the node at `root`, every node reachable from it,
+    /// and every record leaf. Nodes are decoded directly rather than
+    /// re-inserting their keys, each one must re-encode to the CID it was
+    /// stored under, and the keys must be in order and laid out on the layers
+    /// their hashes call for. Leaf bytes are taken as-is; callers loading
+    /// untrusted data should check block hashes first.
+    pub fn from_blocks(root: Cid, blocks: &BTreeMap<Cid, Vec<u8>>) -> Result<Self> {
+        let mut mst = Self::new();
+        let mut nodes = BTreeMap::new();
+        mst.load_node(&root, None, blocks, &mut nodes)?;
+
+        // A tree with misplaced keys would produce a different root
+        let tree = Tree { root_cid: root, nodes };
+        if Tree::build(&mst.entries)?.root_cid != root {
+            return Err(MstError::InvalidNode(format!("Tree under {} is not in canonical form", root)));
+        }
+        let _ = mst.tree.set(tree);
+
+        Ok(mst)
+    }
+
+    /// Decode a node and everything below it from `blocks`
+    fn load_node(
+        &mut self,
+        cid: &Cid,
+        expected_layer: Option<u32>,
+        blocks: &BTreeMap<Cid, Vec<u8>>,
+        nodes: &mut BTreeMap<Cid, MstNode>,
+    ) -> Result<()> {
+        let bytes = blocks
+            .get(cid)
+            .ok_or_else(|| MstError::MissingBlock(cid.to_string()))?;
+        let mut node = MstNode::from_cbor(bytes)?;
+
+        if node.to_cid()? != *cid {
+            return Err(MstError::InvalidNode(format!("Node {} is not canonically encoded", cid)));
+        }
+
+        // Nodes without entries take their layer from their position
+        if let Some(layer) = expected_layer {
+            if !node.entries.is_empty() && node.layer != layer {
+                return Err(MstError::InvalidNode(format!("Node {} is on the wrong layer", cid)));
+            }
+            node.layer = layer;
+        }
+
+        let child_layer = node.layer.checked_sub(1);
+        let has_subtrees = node.left.is_some() || node.entries.iter().any(|e| e.tree_cid.is_some());
+        if has_subtrees && child_layer.is_none() {
+            return Err(MstError::InvalidNode(format!("Layer 0 node {} has subtrees", cid)));
+        }
+
+        if let Some(left) = node.left {
+            self.load_node(&left, child_layer, blocks, nodes)?;
+        }
+
+        for entry in &node.entries {
+            if let Some(last) = self.entries.keys().next_back() {
+                if entry.key.as_str() <= last.as_str() {
+                    return Err(MstError::InvalidNode(format!("Key {} is out of order", entry.key)));
+                }
+            }
+
+            let leaf = blocks
+                .get(&entry.value_cid)
+                .ok_or_else(|| MstError::MissingBlock(entry.value_cid.to_string()))?;
+            self.leaves.insert(entry.value_cid, leaf.clone());
+            self.insert_cid(entry.key.clone(), entry.value_cid);
+
+            if let Some(ref tree_cid) = entry.tree_cid {
+                self.load_node(tree_cid, child_layer, blocks, nodes)?;
+            }
+        }
+
+        nodes.insert(*cid, node);
+        Ok(())
+    }
+
+    /// Node structure for the current entries, building it if needed
+    fn tree(&self) -> Result<&Tree> {
+        if let Some(tree) = self.tree.get() {
+            return Ok(tree);
+        }
+
+        let tree = Tree::build(&self.entries)?;
+        Ok(self.tree.get_or_init(|| tree))
+    }
+
     /// Get the CID of the root node
     pub fn root_cid(&self) -> Result<Cid> {
-        self.root.to_cid()
+        Ok(self.tree()?.root_cid)
     }
 
     /// Get a node by CID
     pub fn get_node(&self, cid: &Cid) -> Option<&MstNode> {
-        self.nodes.get(cid)
-    }
-
-    /// Store a node and return its CID
-    pub fn put_node(&mut self, node: MstNode) -> Result<Cid> {
-        let cid = node.to_cid()?;
-        self.nodes.insert(cid, node);
-        Ok(cid)
+        self.tree().ok()?.nodes.get(cid)
     }
 
     /// Get a leaf value by CID
@@ -334,7 +558,7 @@ impl Mst {
         Ok(cid)
     }
 
-    /// Insert a key/value pair into the MST with proper tree management
+    /// Insert a key/value pair into the MST
     ///
     /// # Arguments
     ///
@@ -351,209 +575,124 @@ impl Mst {
 
         // Store the value and get its CID
         let value_cid = self.put_leaf(value)?;
-
-        // Insert into the tree structure
-        let new_root = self.insert_into_node(self.root.clone(), &key, value_cid)?;
-        self.root = new_root;
+        self.insert_cid(key, value_cid);
 
         Ok(value_cid)
     }
 
-    /// Insert a key/value into a node, handling splitting and layer management
-    fn insert_into_node(&mut self, mut node: MstNode, key: &str, value_cid: Cid) -> Result<MstNode> {
-        // Check if key already exists and update it
-        for entry in &mut node.entries {
-            if entry.key == key {
-                entry.value_cid = value_cid;
-                return Ok(node);
+    /// Point a key at a value CID, dropping any leaf it no longer uses
+    fn insert_cid(&mut self, key: String, value_cid: Cid) {
+        let old_cid = self.entries.insert(key, value_cid);
+        if old_cid != Some(value_cid) {
+            *self.leaf_refs.entry(value_cid).or_default() += 1;
+            if let Some(old_cid) = old_cid {
+                self.release_leaf(&old_cid);
             }
         }
-
-        // Insert at this level
-        let new_entry = MstEntry {
-            key: key.to_string(),
-            value_cid,
-            tree_cid: None,
-        };
-
-        node.insert_entry(new_entry);
-
-        // TODO: Implement proper MST splitting based on ATProto spec
-        // For now, disable splitting to ensure basic operations work correctly
-        // if node.entries.len() > MAX_ENTRIES_PER_NODE {
-        //     node = self.split_node(node)?;
-        // }
-
-        Ok(node)
+        self.tree = OnceLock::new();
     }
 
-    /// Split a node that has too many entries
-    /// Currently not used (splitting disabled at line 375-379) but kept for future implementation
-    #[allow(dead_code)]
-    fn split_node(&mut self, node: MstNode) -> Result<MstNode> {
-        if node.entries.len() <= MAX_ENTRIES_PER_NODE {
-            return Ok(node);
-        }
-
-        // Create a new node at a higher layer
-        let new_layer = node.layer + 1;
-        let mut new_node = MstNode::new(new_layer);
-
-        // Split entries based on their layer heights
-        let mut current_subtree = MstNode::new(node.layer);
-        let mut pending_subtree_cid: Option<Cid> = None;
-
-        for entry in node.entries {
-            let entry_layer = calculate_key_layer(&entry.key);
-
-            if entry_layer >= new_layer {
-                // This entry goes in the new node
-                if !current_subtree.entries.is_empty() {
-                    // Store current subtree
-                    pending_subtree_cid = Some(self.put_node(current_subtree.clone())?);
-                    current_subtree = MstNode::new(node.layer);
-                }
-
-                // Create new entry and attach pending subtree if we have one
-                let mut new_entry = entry.clone();
-                if pending_subtree_cid.is_some() {
-                    new_entry.tree_cid = pending_subtree_cid.take();
-                }
-                new_node.insert_entry(new_entry);
-            } else {
-                // This entry stays in a subtree
-                current_subtree.insert_entry(entry);
+    /// Drop one key's reference to a leaf, removing the leaf once no key
+    /// refers to it
+    fn release_leaf(&mut self, cid: &Cid) -> Option<Vec<u8>> {
+        match self.leaf_refs.get_mut(cid) {
+            Some(refs) if *refs > 1 => {
+                *refs -= 1;
+                self.leaves.get(cid).cloned()
             }
-        }
-
-        // Handle remaining subtree
-        if !current_subtree.entries.is_empty() {
-            let subtree_cid = self.put_node(current_subtree)?;
-            if let Some(last_entry) = new_node.entries.last_mut() {
-                last_entry.tree_cid = Some(subtree_cid);
+            _ => {
+                self.leaf_refs.remove(cid);
+                self.leaves.remove(cid)
             }
         }
-
-        Ok(new_node)
     }
 
-    /// Get a value by key, traversing subtrees as needed
+    /// Get a value by key
     pub fn get(&self, key: &str) -> Option<&[u8]> {
-        self.get_from_node(&self.root, key)
-    }
-
-    /// Get a value from a specific node, recursively searching subtrees
-    fn get_from_node(&self, node: &MstNode, key: &str) -> Option<&[u8]> {
-        // First check entries in this node
-        for entry in &node.entries {
-            if entry.key == key {
-                return self.get_leaf(&entry.value_cid);
-            }
-        }
-
-        // Then check all subtrees (brute force for now)
-        for entry in &node.entries {
-            if let Some(ref tree_cid) = entry.tree_cid {
-                if let Some(subtree) = self.nodes.get(tree_cid) {
-                    if let Some(value) = self.get_from_node(subtree, key) {
-                        return Some(value);
-                    }
-                }
-            }
-        }
-
-        None
+        self.entries.get(key).and_then(|cid| self.get_leaf(cid))
     }
 
     /// Delete a key from the MST
     pub fn delete(&mut self, key: &str) -> Option<Vec<u8>> {
-        let (new_root, deleted_value) = self.delete_from_node(self.root.clone(), key)?;
-        self.root = new_root;
-        deleted_value
+        let cid = self.entries.remove(key)?;
+        self.tree = OnceLock::new();
+        self.release_leaf(&cid)
     }
 
-    /// Delete a key from a node, returning the updated node and deleted value
-    fn delete_from_node(&mut self, mut node: MstNode, key: &str) -> Option<(MstNode, Option<Vec<u8>>)> {
-        let mut deleted_value = None;
+    /// List all keys in sorted order
+    pub fn list_keys(&self) -> Vec<String> {
+        self.entries.keys().cloned().collect()
+    }
 
-        for i in 0..node.entries.len() {
-            let entry = &node.entries[i];
+    /// List all keys with their value CIDs in sorted order
+    pub fn list_entries(&self) -> Vec<(String, Cid)> {
+        self.entries
+            .iter()
+            .map(|(key, cid)| (key.clone(), *cid))
+            .collect()
+    }
 
-            if entry.key == key {
-                // Found the key, remove it
-                let removed_entry = node.entries.remove(i);
-                deleted_value = self.leaves.remove(&removed_entry.value_cid);
-                return Some((node, deleted_value));
-            }
+    /// Collect every block reachable from the root, in canonical CAR order
+    ///
+    /// Blocks are emitted depth-first: each node's DAG-CBOR comes first,
+    /// followed by its left subtree and then, for each entry in turn, its
+    /// record leaf and the subtree after it. Blocks shared by several entries
+    /// are emitted once.
+    pub fn blocks(&self) -> Result<Vec<(Cid, Vec<u8>)>> {
+        let tree = self.tree()?;
+        let mut blocks = Vec::new();
+        let mut seen = HashSet::new();
+        self.collect_blocks_from_node(tree, &tree.root_cid, &mut blocks, &mut seen)?;
+        Ok(blocks)
+    }
 
-            // Check subtree
-            if entry.key.as_str() < key {
-                if let Some(ref tree_cid) = entry.tree_cid {
-                    if let Some(subtree) = self.nodes.get(tree_cid).cloned() {
-                        if let Some((updated_subtree, value)) = self.delete_from_node(subtree, key) {
-                            // Update the subtree
-                            if updated_subtree.entries.is_empty() {
-                                // Subtree is now empty, remove the tree pointer
-                                node.entries[i].tree_cid = None;
-                            } else {
-                                let new_tree_cid = self.put_node(updated_subtree).ok()?;
-                                node.entries[i].tree_cid = Some(new_tree_cid);
-                            }
-                            return Some((node, value));
-                        }
-                    }
-                }
-            }
+    /// Recursively collect blocks from a node and its subtrees
+    fn collect_blocks_from_node(
+        &self,
+        tree: &Tree,
+        cid: &Cid,
+        blocks: &mut Vec<(Cid, Vec<u8>)>,
+        seen: &mut HashSet<Cid>,
+    ) -> Result<()> {
+        if !seen.insert(*cid) {
+            return Ok(());
         }
 
-        Some((node, deleted_value))
-    }
+        let node = tree
+            .nodes
+            .get(cid)
+            .ok_or_else(|| MstError::MissingBlock(cid.to_string()))?;
+        blocks.push((*cid, node.to_cbor()?));
 
-    /// List all keys in sorted order, traversing the entire tree
-    pub fn list_keys(&self) -> Vec<String> {
-        let mut keys = Vec::new();
-        self.collect_keys_from_node(&self.root, &mut keys);
-        keys
-    }
+        if let Some(ref left) = node.left {
+            self.collect_blocks_from_node(tree, left, blocks, seen)?;
+        }
 
-    /// Recursively collect keys from a node and its subtrees
-    fn collect_keys_from_node(&self, node: &MstNode, keys: &mut Vec<String>) {
         for entry in &node.entries {
-            // First, collect keys from left subtree if it exists
-            if let Some(ref tree_cid) = entry.tree_cid {
-                if let Some(subtree) = self.nodes.get(tree_cid) {
-                    self.collect_keys_from_node(subtree, keys);
-                }
+            if seen.insert(entry.value_cid) {
+                let leaf = self
+                    .leaves
+                    .get(&entry.value_cid)
+                    .ok_or_else(|| MstError::MissingBlock(entry.value_cid.to_string()))?;
+                blocks.push((entry.value_cid, leaf.clone()));
             }
 
-            // Then add this key
-            keys.push(entry.key.clone());
+            if let Some(ref tree_cid) = entry.tree_cid {
+                self.collect_blocks_from_node(tree, tree_cid, blocks, seen)?;
+            }
         }
+
+        Ok(())
     }
 
     /// Get the total number of entries in the entire tree
     pub fn len(&self) -> usize {
-        self.count_entries_in_node(&self.root)
-    }
-
-    /// Recursively count entries in a node and its subtrees
-    fn count_entries_in_node(&self, node: &MstNode) -> usize {
-        let mut count = node.entries.len();
-
-        for entry in &node.entries {
-            if let Some(ref tree_cid) = entry.tree_cid {
-                if let Some(subtree) = self.nodes.get(tree_cid) {
-                    count += self.count_entries_in_node(subtree);
-                }
-            }
-        }
-
-        count
+        self.entries.len()
     }
 
     /// Check if the MST is empty
     pub fn is_empty(&self) -> bool {
-        self.root.is_empty()
+        self.entries.is_empty()
     }
 }
 
@@ -685,6 +824,25 @@ mod tests {
         assert!(mst.get("test_key").is_none());
     }
 
+    #[test]
+    fn test_mst_shared_leaf() {
+        let mut mst = Mst::new();
+
+        let value = b"shared value".to_vec();
+        let cid = mst.insert("key1".to_string(), value.clone()).unwrap();
+        mst.insert("key2".to_string(), value.clone()).unwrap();
+
+        // Rewriting a key with its current value keeps the leaf
+        mst.insert("key1".to_string(), value.clone()).unwrap();
+        assert_eq!(mst.delete("key1"), Some(value.clone()));
+        assert_eq!(mst.get_leaf(&cid), Some(value.as_slice()));
+
+        // Overwriting the last key using it drops the leaf
+        mst.insert("key2".to_string(), b"other value".to_vec()).unwrap();
+        assert!(mst.get_leaf(&cid).is_none());
+        assert!(mst.leaf_refs.get(&cid).is_none());
+    }
+
     #[test]
     fn test_mst_list_keys() {
         let mut mst = Mst::new();
@@ -833,23 +991,23 @@ mod tests {
     fn test_mst_large_node() {
         let mut mst = Mst::new();
 
-        // Insert more than MAX_ENTRIES_PER_NODE
-        // (Splitting is TODO, so this will keep everything in root for now)
-        for i in 0..(MAX_ENTRIES_PER_NODE + 10) {
+        // Enough keys that some land above layer 0
+        const ENTRIES: usize = 42;
+        for i in 0..ENTRIES {
             let key = format!("key_{:04}", i);
             let value = format!("value_{}", i).into_bytes();
             mst.insert(key, value).unwrap();
         }
 
         // Verify all entries are still accessible
-        for i in 0..(MAX_ENTRIES_PER_NODE + 10) {
+        for i in 0..ENTRIES {
             let key = format!("key_{:04}", i);
             let value = format!("value_{}", i);
             assert_eq!(mst.get(&key).unwrap(), value.as_bytes());
         }
 
         // Verify total count
-        assert_eq!(mst.len(), MAX_ENTRIES_PER_NODE + 10);
+        assert_eq!(mst.len(), ENTRIES);
     }
 
     #[test]
@@ -907,4 +1065,166 @@ mod tests {
 
         assert!(low_layer_count > 50, "Expected most keys at low layers, got {}/100", low_layer_count);
     }
+
+    // Interop test vectors from the atproto reference implementation
+    // (https://github.com/bluesky-social/atproto-interop-tests)
+
+    fn interop_value_cid() -> Cid {
+        Cid::try_from("bafyreie5cvv4h45feadgeuwhbcutmh6t2ceseocckahdoe6uat64zmz454").unwrap()
+    }
+
+    fn tree_with_keys(keys: &[&str]) -> Mst {
+        let mut mst = Mst::new();
+        for key in keys {
+            mst.insert_cid(key.to_string(), interop_value_cid());
+        }
+        mst
+    }
+
+    #[test]
+    fn test_key_layer_vectors() {
+        assert_eq!(calculate_key_layer("asdf"), 0);
+        assert_eq!(calculate_key_layer("blue"), 1);
+        assert_eq!(calculate_key_layer("2653ae71"), 0);
+        assert_eq!(calculate_key_layer("88bfafc7"), 2);
+        assert_eq!(calculate_key_layer("2a92d355"), 4);
+        assert_eq!(calculate_key_layer("884976f5"), 6);
+        assert_eq!(calculate_key_layer("app.bsky.feed.post/454397e440ec"), 4);
+        assert_eq!(calculate_key_layer("app.bsky.feed.post/9adeb165882c"), 8);
+    }
+
+    #[test]
+    fn test_empty_tree_root_cid() {
+        let mst = Mst::new();
+        assert_eq!(
+            mst.root_cid().unwrap().to_string(),
+            "bafyreie5737gdxlw5i64vzichcalba3z2v5n6icifvx5xytvske7mr3hpm"
+        );
+    }
+
+    #[test]
+    fn test_trivial_tree_root_cid() {
+        let mst = tree_with_keys(&["com.example.record/3jqfcqzm3fo2j"]);
+        assert_eq!(
+            mst.root_cid().unwrap().to_string(),
+            "bafyreibj4lsc3aqnrvphp5xmrnfoorvru4wynt6lwidqbm2623a6tatzdu"
+        );
+    }
+
+    #[test]
+    fn test_single_layer2_tree_root_cid() {
+        let mst = tree_with_keys(&["com.example.record/3jqfcqzm3fx2j"]);
+        assert_eq!(
+            mst.root_cid().unwrap().to_string(),
+            "bafyreih7wfei65pxzhauoibu3ls7jgmkju4bspy4t2ha2qdjnzqvoy33ai"
+        );
+    }
+
+    #[test]
+    fn test_simple_tree_root_cid() {
+        let mst = tree_with_keys(&[
+            "com.example.record/3jqfcqzm3fp2j", // level 0
+            "com.example.record/3jqfcqzm3fr2j", // level 0
+            "com.example.record/3jqfcqzm3fs2j", // level 1
+            "com.example.record/3jqfcqzm3ft2j", // level 0
+            "com.example.record/3jqfcqzm4fc2j", // level 0
+        ]);
+        assert_eq!(
+            mst.root_cid().unwrap().to_string(),
+            "bafyreicmahysq4n6wfuxo522m6dpiy7z7qzym3dzs756t5n7nfdgccwq7m"
+        );
+    }
+
+    #[test]
+    fn test_root_cid_independent_of_insertion_order() {
+        let keys: Vec<String> = (0..200).map(|i| format!("app.bsky.feed.post/{:04}", i)).collect();
+
+        let mut forward = Mst::new();
+        for key in &keys {
+            forward.insert(key.clone(), key.as_bytes().to_vec()).unwrap();
+        }
+
+        let mut reverse = Mst::new();
+        for key in keys.iter().rev() {
+            reverse.insert(key.clone(), key.as_bytes().to_vec()).unwrap();
+        }
+
+        assert_eq!(forward.root_cid().unwrap(), reverse.root_cid().unwrap());
+
+        // Deleting back down to one key gives the same root as inserting it alone
+        for key in &keys[1..] {
+            forward.delete(key);
+        }
+        let mut single = Mst::new();
+        single.insert(keys[0].clone(), keys[0].as_bytes().to_vec()).unwrap();
+        assert_eq!(forward.root_cid().unwrap(), single.root_cid().unwrap());
+    }
+
+    #[test]
+    fn test_node_key_prefix_compression() {
+        let mut node = MstNode::new(0);
+        for key in ["com.example.record/3jqfcqzm3fp2j", "com.example.record/3jqfcqzm3fr2j"] {
+            node.insert_entry(MstEntry {
+                key: key.to_string(),
+                value_cid: interop_value_cid(),
+                tree_cid: None,
+            });
+        }
+
+        let ipld: Ipld = libipld_cbor::DagCborCodec.decode(&node.to_cbor().unwrap()).unwrap();
+        let Ipld::Map(map) = ipld else { panic!("node is not a map") };
+        assert_eq!(map.get("l"), Some(&Ipld::Null));
+
+        let Some(Ipld::List(entries)) = map.get("e") else { panic!("missing entries") };
+        let Ipld::Map(second) = &entries[1] else { panic!("entry is not a map") };
+        assert_eq!(second.get("p"), Some(&Ipld::Integer(29)));
+        assert_eq!(second.get("k"), Some(&Ipld::Bytes(b"r2j".to_vec())));
+        assert_eq!(second.get("t"), Some(&Ipld::Null));
+
+        assert_eq!(MstNode::from_cbor(&node.to_cbor().unwrap()).unwrap(), node);
+    }
+
+    #[test]
+    fn test_from_blocks_round_trip() {
+        let mut mst = Mst::new();
+        for i in 0..100 {
+            mst.insert(format!("app.bsky.feed.post/{:04}", i), format!("value_{}", i).into_bytes()).unwrap();
+        }
+
+        let root = mst.root_cid().unwrap();
+        let blocks: BTreeMap<Cid, Vec<u8>> = mst.blocks().unwrap().into_iter().collect();
+        assert!(blocks.len() > 101, "expected a multi-node tree");
+
+        let loaded = Mst::from_blocks(root, &blocks).unwrap();
+        assert_eq!(loaded.root_cid().unwrap(), root);
+        assert_eq!(loaded.list_entries(), mst.list_entries());
+        assert_eq!(loaded.get("app.bsky.feed.post/0042"), Some(&b"value_42"[..]));
+    }
+
+    #[test]
+    fn test_from_blocks_rejects_non_canonical_tree() {
+        // Two layer-0 keys forced into separate nodes under a layer-1 key
+        let value = interop_value_cid();
+        let mut blocks = BTreeMap::new();
+
+        let leaf = MstNode::with_entries(0, vec![MstEntry {
+            key: "com.example.record/3jqfcqzm3fp2j".to_string(),
+            value_cid: value,
+            tree_cid: None,
+        }]);
+        let leaf_cid = leaf.to_cid().unwrap();
+        blocks.insert(leaf_cid, leaf.to_cbor().unwrap());
+
+        let mut root = MstNode::with_entries(0, vec![MstEntry {
+            key: "com.example.record/3jqfcqzm3fr2j".to_string(),
+            value_cid: value,
+            tree_cid: None,
+        }]);
+        root.left = Some(leaf_cid);
+        let root_cid = root.to_cid().unwrap();
+        blocks.insert(root_cid, root.to_cbor().unwrap());
+        blocks.insert(value, vec![0xa0]);
+
+        assert!(matches!(Mst::from_blocks(root_cid, &blocks), Err(MstError::InvalidNode(_))));
+    }
 }
@@ -904,7 +904,7 @@ impl ChatBskyActorNS {
     }
 
     /// Call crate::client::chat::bsky::actor::export_account_data::export_account_data
-    pub async fn export_account_data(&self) -> Result<crate::xrpc::XrpcResponse<()>, crate::xrpc::XrpcError> {
+    pub async fn export_account_data(&self) -> Result<crate::xrpc::XrpcResponse<Vec<u8>>, crate::xrpc::XrpcError> {
         crate::client::chat::bsky::actor::export_account_data::export_account_data(&*self.client).await
     }
 
@@ -1205,8 +1205,8 @@ impl ComAtprotoRepoNS {
     }
 
     /// Call crate::client::com::atproto::repo::upload_blob::upload_blob
-    pub async fn upload_blob(&self, input: crate::client::com::atproto::repo::upload_blob::Input) -> Result<crate::xrpc::XrpcResponse<crate::client::com::atproto::repo::upload_blob::Output>, crate::xrpc::XrpcError> {
-        crate::client::com::atproto::repo::upload_blob::upload_blob(&*self.client, input).await
+    pub async fn upload_blob(&self, input: crate::client::com::atproto::repo::upload_blob::Input, content_type: &str) -> Result<crate::xrpc::XrpcResponse<crate::client::com::atproto::repo::upload_blob::Output>, crate::xrpc::XrpcError> {
+        crate::client::com::atproto::repo::upload_blob::upload_blob(&*self.client, input, content_type).await
     }
 
 }
@@ -1341,17 +1341,17 @@ impl ComAtprotoServerNS {
 
 impl ComAtprotoSyncNS {
     /// Call crate::client::com::atproto::sync::get_blob::get_blob
-    pub async fn get_blob(&self, params: crate::client::com::atproto::sync::get_blob::QueryParams) -> Result<crate::xrpc::XrpcResponse<()>, crate::xrpc::XrpcError> {
+    pub async fn get_blob(&self, params: crate::client::com::atproto::sync::get_blob::QueryParams) -> Result<crate::xrpc::XrpcResponse<Vec<u8>>, crate::xrpc::XrpcError> {
         crate::client::com::atproto::sync::get_blob::get_blob(&*self.client, params).await
     }
 
     /// Call crate::client::com::atproto::sync::get_blocks::get_blocks
-    pub async fn get_blocks(&self, params: crate::client::com::atproto::sync::get_blocks::QueryParams) -> Result<crate::xrpc::XrpcResponse<()>, crate::xrpc::XrpcError> {
+    pub async fn get_blocks(&self, params: crate::client::com::atproto::sync::get_blocks::QueryParams) -> Result<crate::xrpc::XrpcResponse<Vec<u8>>, crate::xrpc::XrpcError> {
         crate::client::com::atproto::sync::get_blocks::get_blocks(&*self.client, params).await
     }
 
     /// Call crate::client::com::atproto::sync::get_checkout::get_checkout
-    pub async fn get_checkout(&self, params: crate::client::com::atproto::sync::get_checkout::QueryParams) -> Result<crate::xrpc::XrpcResponse<()>, crate::xrpc::XrpcError> {
+    pub async fn get_checkout(&self, params: crate::client::com::atproto::sync::get_checkout::QueryParams) -> Result<crate::xrpc::XrpcResponse<Vec<u8>>, crate::xrpc::XrpcError> {
         crate::client::com::atproto::sync::get_checkout::get_checkout(&*self.client, params).await
     }
 
@@ -1371,12 +1371,12 @@ impl ComAtprotoSyncNS {
     }
 
     /// Call crate::client::com::atproto::sync::get_record::get_record
-    pub async fn get_record(&self, params: crate::client::com::atproto::sync::get_record::QueryParams) -> Result<crate::xrpc::XrpcResponse<()>, crate::xrpc::XrpcError> {
+    pub async fn get_record(&self, params: crate::client::com::atproto::sync::get_record::QueryParams) -> Result<crate::xrpc::XrpcResponse<Vec<u8>>, crate::xrpc::XrpcError> {
         crate::client::com::atproto::sync::get_record::get_record(&*self.client, params).await
     }
 
     /// Call crate::client::com::atproto::sync::get_repo::get_repo
-    pub async fn get_repo(&self, params: crate::client::com::atproto::sync::get_repo::QueryParams) -> Result<crate::xrpc::XrpcResponse<()>, crate::xrpc::XrpcError> {
+    pub async fn get_repo(&self, params: crate::client::com::atproto::sync::get_repo::QueryParams) -> Result<crate::xrpc::XrpcResponse<Vec<u8>>, crate::xrpc::XrpcError> {
         crate::client::com::atproto::sync::get_repo::get_repo(&*self.client, params).await
     }
 
@@ -0,0 +1,871 @@
+//! PLC DID operations
+//!
+//! `did:plc` identities are controlled through a signed, hash-linked log of
+//! operations kept by a PLC directory (e.g. `https://plc.directory`). This
+//! module builds and signs those operations, derives the DID from a genesis
+//! operation, and validates a DID's full audit log.
+//!
+//! ## Operations
+//!
+//! - **Genesis**: the first operation (`prev: null`); its hash determines the DID
+//! - **Update**: replaces the rotation keys, verification methods, handles and
+//!   services; `prev` is the CID of the operation it builds on
+//! - **Tombstone**: permanently deactivates the DID
+//!
+//! Each operation is signed with one of the rotation keys of the operation it
+//! follows (the genesis operation is signed with one of its own).
+//!
+//! ## Recovery
+//!
+//! A rotation key can rewrite history signed by a lower-priority key (one
+//! later in the `rotationKeys` list) by submitting an operation whose `prev`
+//! points before the disputed operations. This is only allowed within
+//! [`RECOVERY_WINDOW`] of the first operation being nullified.
+//!
+//! ## Example
+//!
+//! ```no_run
+//! use atproto::crypto::{KeyAlgorithm, Keypair};
+//! use atproto::plc::{PlcClient, PlcData, UnsignedOperation};
+//!
+//! # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+//! let rotation_key = Keypair::generate(KeyAlgorithm::K256);
+//! let signing_key = Keypair::generate(KeyAlgorithm::K256);
+//!
+//! let data = PlcData::new()
+//!     .with_rotation_key(rotation_key.did_key())
+//!     .with_verification_method("atproto", signing_key.did_key())
+//!     .with_handle("alice.example.com")
+//!     .with_pds("https://pds.example.com");
+//!
+//! let genesis = UnsignedOperation::genesis(data).sign(&rotation_key)?;
+//! let did = genesis.did()?;
+//!
+//! let client = PlcClient::with_default_directory();
+//! client.submit(&did, &genesis).await?;
+//!
+//! // Later: fetch and check the whole history
+//! let current = client.verified_data(&did).await?;
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! ## References
+//!
+//! - Spec: https://web.plc.directory/spec/v0.1/did-plc
+
+use crate::crypto::{CryptoError, Keypair, PublicKey};
+use crate::did_doc::{DidDocument, Service as DidService, VerificationMethod};
+use base64::engine::general_purpose::URL_SAFE_NO_PAD;
+use base64::Engine;
+use chrono::{DateTime, Utc};
+use libipld::cid::multibase::Base;
+use libipld::cid::Cid;
+use libipld::codec::Codec;
+use libipld::multihash::Multihash;
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use std::collections::{BTreeMap, HashSet};
+use std::time::Duration;
+use thiserror::Error;
+
+/// How long a higher-priority rotation key may nullify operations
+pub const RECOVERY_WINDOW: Duration = Duration::from_secs(72 * 60 * 60);
+
+/// Number of base32 characters of the genesis hash used in a `did:plc`
+const DID_PLC_HASH_LEN: usize = 24;
+
+/// Error types for PLC operations
+#[derive(Debug, Error)]
+pub enum PlcError {
+    #[error("HTTP error: {0}")]
+    Http(#[from] reqwest::Error),
+
+    #[error("PLC directory error: {0}")]
+    Directory(String),
+
+    #[error("Serialization error: {0}")]
+    Serialization(String),
+
+    #[error("Invalid operation: {0}")]
+    InvalidOperation(String),
+
+    #[error("Operation is not signed by any allowed rotation key")]
+    InvalidSignature,
+
+    #[error("DID mismatch: expected {expected}, got {actual}")]
+    DidMismatch { expected: String, actual: String },
+
+    #[error("CID mismatch: expected {expected}, got {actual}")]
+    CidMismatch { expected: String, actual: String },
+
+    #[error("Misordered operation: {0}")]
+    MisorderedOperation(String),
+
+    #[error("Operation {0} nullifies history outside the 72 hour recovery window")]
+    LateRecovery(String),
+
+    #[error("Invalid audit log: {0}")]
+    InvalidLog(String),
+
+    #[error("Crypto error: {0}")]
+    Crypto(#[from] CryptoError),
+}
+
+/// Result type for PLC operations
+pub type Result<T> = std::result::Result<T, PlcError>;
+
+/// A service entry in a PLC operation
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PlcService {
+    /// Service type (e.g. "AtprotoPersonalDataServer")
+    #[serde(rename = "type")]
+    pub service_type: String,
+
+    /// Service URL
+    pub endpoint: String,
+}
+
+/// The identity data set by a PLC operation
+#[derive(Debug, Clone, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PlcData {
+    /// `did:key`s allowed to sign the next operation, highest priority first
+    pub rotation_keys: Vec<String>,
+
+    /// `did:key`s by verification method name (e.g. "atproto")
+    pub verification_methods: BTreeMap<String, String>,
+
+    /// Other identifiers, e.g. `at://` handles
+    pub also_known_as: Vec<String>,
+
+    /// Services by name (e.g. "atproto_pds")
+    pub services: BTreeMap<String, PlcService>,
+}
+
+impl PlcData {
+    /// Create empty identity data
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Append a rotation key (lowest priority so far)
+    pub fn with_rotation_key(mut self, did_key: impl Into<String>) -> Self {
+        self.rotation_keys.push(did_key.into());
+        self
+    }
+
+    /// Set a verification method, e.g. `("atproto", signing_key.did_key())`
+    pub fn with_verification_method(mut self, name: impl Into<String>, did_key: impl Into<String>) -> Self {
+        self.verification_methods.insert(name.into(), did_key.into());
+        self
+    }
+
+    /// Add a handle as an `at://` alias
+    pub fn with_handle(mut self, handle: &str) -> Self {
+        self.also_known_as.push(format!("at://{}", handle));
+        self
+    }
+
+    /// Set a service
+    pub fn with_service(mut self, name: impl Into<String>, service: PlcService) -> Self {
+        self.services.insert(name.into(), service);
+        self
+    }
+
+    /// Set the `atproto_pds` service
+    pub fn with_pds(self, endpoint: impl Into<String>) -> Self {
+        self.with_service(
+            "atproto_pds",
+            PlcService {
+                service_type: "AtprotoPersonalDataServer".to_string(),
+                endpoint: endpoint.into(),
+            },
+        )
+    }
+
+    /// Build the DID document this data describes
+    pub fn to_did_document(&self, did: &str) -> DidDocument {
+        let verification_method = self
+            .verification_methods
+            .iter()
+            .map(|(name, did_key)| VerificationMethod {
+                id: format!("{}#{}", did, name),
+                key_type: "Multikey".to_string(),
+                controller: did.to_string(),
+                public_key_multibase: Some(did_key.strip_prefix("did:key:").unwrap_or(did_key).to_string()),
+            })
+            .collect();
+
+        let service = self
+            .services
+            .iter()
+            .map(|(name, service)| DidService {
+                id: format!("#{}", name),
+                service_type: service.service_type.clone(),
+                service_endpoint: service.endpoint.clone(),
+            })
+            .collect();
+
+        DidDocument {
+            context: Some(serde_json::json!([
+                "https://www.w3.org/ns/did/v1",
+                "https://w3id.org/security/multikey/v1",
+            ])),
+            id: did.to_string(),
+            also_known_as: self.also_known_as.clone(),
+            service,
+            verification_method,
+        }
+    }
+}
+
+/// Legacy genesis operation format
+///
+/// Only found at the start of old `did:plc` logs; new operations always use
+/// [`UnsignedOperation::Operation`].
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LegacyCreate {
+    pub signing_key: String,
+    pub recovery_key: String,
+    pub handle: String,
+    pub service: String,
+    pub prev: Option<String>,
+}
+
+/// A PLC operation before signing
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "type")]
+pub enum UnsignedOperation {
+    /// Genesis or update operation
+    #[serde(rename = "plc_operation")]
+    Operation {
+        #[serde(flatten)]
+        data: PlcData,
+        /// CID of the previous operation, None for genesis
+        prev: Option<String>,
+    },
+
+    /// Permanently deactivates the DID
+    #[serde(rename = "plc_tombstone")]
+    Tombstone {
+        /// CID of the previous operation
+        prev: String,
+    },
+
+    /// Legacy genesis operation
+    #[serde(rename = "create")]
+    LegacyCreate(LegacyCreate),
+}
+
+impl UnsignedOperation {
+    /// Create a genesis operation
+    pub fn genesis(data: PlcData) -> Self {
+        UnsignedOperation::Operation { data, prev: None }
+    }
+
+    /// Create an operation replacing the data set by `prev`
+    pub fn update(prev: &Cid, data: PlcData) -> Self {
+        UnsignedOperation::Operation {
+            data,
+            prev: Some(prev.to_string()),
+        }
+    }
+
+    /// Create a tombstone following `prev`
+    pub fn tombstone(prev: &Cid) -> Self {
+        UnsignedOperation::Tombstone {
+            prev: prev.to_string(),
+        }
+    }
+
+    /// CID of the previous operation, None for genesis operations
+    pub fn prev(&self) -> Option<&str> {
+        match self {
+            UnsignedOperation::Operation { prev, .. } => prev.as_deref(),
+            UnsignedOperation::Tombstone { prev } => Some(prev),
+            UnsignedOperation::LegacyCreate(create) => create.prev.as_deref(),
+        }
+    }
+
+    /// The identity data after this operation, None for tombstones
+    ///
+    /// Legacy create operations are normalized to the current format.
+    pub fn data(&self) -> Option<PlcData> {
+        match self {
+            UnsignedOperation::Operation { data, .. } => Some(data.clone()),
+            UnsignedOperation::Tombstone { .. } => None,
+            UnsignedOperation::LegacyCreate(create) => {
+                let handle = create
+                    .handle
+                    .trim_start_matches("http://")
+                    .trim_start_matches("https://")
+                    .trim_start_matches("at://");
+                let endpoint = if create.service.starts_with("http://") || create.service.starts_with("https://") {
+                    create.service.clone()
+                } else {
+                    format!("https://{}", create.service)
+                };
+
+                Some(
+                    PlcData::new()
+                        .with_rotation_key(create.recovery_key.clone())
+                        .with_rotation_key(create.signing_key.clone())
+                        .with_verification_method("atproto", create.signing_key.clone())
+                        .with_handle(handle)
+                        .with_pds(endpoint),
+                )
+            }
+        }
+    }
+
+    /// Serialize to DAG-CBOR bytes (the signed payload)
+    pub fn to_cbor(&self) -> Result<Vec<u8>> {
+        encode_dag_cbor(self)
+    }
+
+    /// Sign with a rotation key
+    pub fn sign(self, key: &Keypair) -> Result<SignedOperation> {
+        let sig = key.sign(&self.to_cbor()?);
+        Ok(SignedOperation {
+            operation: self,
+            sig: URL_SAFE_NO_PAD.encode(sig),
+        })
+    }
+}
+
+/// A signed PLC operation, as submitted to and returned by a PLC directory
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SignedOperation {
+    /// The operation
+    #[serde(flatten)]
+    pub operation: UnsignedOperation,
+
+    /// base64url signature over the DAG-CBOR of `operation`
+    pub sig: String,
+}
+
+impl SignedOperation {
+    /// Serialize to DAG-CBOR bytes
+    pub fn to_cbor(&self) -> Result<Vec<u8>> {
+        encode_dag_cbor(self)
+    }
+
+    /// CID of the operation, referenced by the next operation's `prev`
+    pub fn cid(&self) -> Result<Cid> {
+        let hash = Sha256::digest(self.to_cbor()?);
+        let multihash = Multihash::wrap(0x12, &hash)
+            .map_err(|e| PlcError::Serialization(format!("Failed to create multihash: {}", e)))?;
+        Ok(Cid::new_v1(0x71, multihash))
+    }
+
+    /// The `did:plc` created by this genesis operation
+    pub fn did(&self) -> Result<String> {
+        if self.operation.prev().is_some() {
+            return Err(PlcError::InvalidOperation("Not a genesis operation".to_string()));
+        }
+
+        let hash = Sha256::digest(self.to_cbor()?);
+        let encoded = Base::Base32Lower.encode(hash);
+        Ok(format!("did:plc:{}", &encoded[..DID_PLC_HASH_LEN]))
+    }
+
+    /// Check the signature against `rotation_keys`
+    ///
+    /// Returns the index of the key that signed the operation. Keys that can't
+    /// be parsed are skipped.
+    pub fn verify(&self, rotation_keys: &[String]) -> Result<usize> {
+        let sig = URL_SAFE_NO_PAD
+            .decode(&self.sig)
+            .map_err(|_| PlcError::InvalidSignature)?;
+        let payload = self.operation.to_cbor()?;
+
+        rotation_keys
+            .iter()
+            .position(|did_key| {
+                PublicKey::from_did_key(did_key)
+                    .map(|key| key.verify(&payload, &sig).is_ok())
+                    .unwrap_or(false)
+            })
+            .ok_or(PlcError::InvalidSignature)
+    }
+
+    /// Serialize to a JSON value, e.g. for `plc_op` when creating an account
+    pub fn to_json(&self) -> Result<serde_json::Value> {
+        serde_json::to_value(self).map_err(|e| PlcError::Serialization(e.to_string()))
+    }
+}
+
+/// An entry of a DID's `/log/audit`
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AuditEntry {
+    /// The DID the operation applies to
+    pub did: String,
+
+    /// The signed operation
+    pub operation: SignedOperation,
+
+    /// CID of the operation
+    pub cid: String,
+
+    /// Whether the operation was undone by a recovery operation
+    pub nullified: bool,
+
+    /// When the directory accepted the operation
+    pub created_at: DateTime<Utc>,
+}
+
+/// Validate a DID's full audit log
+///
+/// Replays every operation in order, checking that:
+///
+/// - the genesis operation hashes to `did` and is self-signed
+/// - each operation's CID matches and its `prev` points into the history
+/// - each operation is signed by a rotation key of the operation it follows
+/// - operations that fork history are signed by a higher-priority key than the
+///   first nullified operation, within [`RECOVERY_WINDOW`]
+/// - nothing follows a tombstone
+/// - the `nullified` flags match the replayed history
+///
+/// Returns the current identity data, or None if the DID is tombstoned.
+pub fn validate_audit_log(did: &str, log: &[AuditEntry]) -> Result<Option<PlcData>> {
+    let Some(genesis) = log.first() else {
+        return Err(PlcError::InvalidLog("Audit log is empty".to_string()));
+    };
+
+    // Operations currently in the canonical history
+    let mut history: Vec<&AuditEntry> = Vec::new();
+    let mut nullified: HashSet<&str> = HashSet::new();
+
+    for entry in log {
+        if entry.did != did {
+            return Err(PlcError::DidMismatch {
+                expected: did.to_string(),
+                actual: entry.did.clone(),
+            });
+        }
+
+        let cid = entry.operation.cid()?.to_string();
+        if cid != entry.cid {
+            return Err(PlcError::CidMismatch {
+                expected: entry.cid.clone(),
+                actual: cid,
+            });
+        }
+
+        if let Some(last) = history.last() {
+            if entry.created_at < last.created_at {
+                return Err(PlcError::MisorderedOperation(format!(
+                    "{} is older than the operation before it",
+                    entry.cid
+                )));
+            }
+        }
+
+        if std::ptr::eq(entry, genesis) {
+            validate_genesis(did, entry)?;
+            history.push(entry);
+            continue;
+        }
+
+        let prev = entry.operation.operation.prev().ok_or_else(|| {
+            PlcError::MisorderedOperation(format!("{} is a second genesis operation", entry.cid))
+        })?;
+        let prev_index = history.iter().position(|op| op.cid == prev).ok_or_else(|| {
+            PlcError::MisorderedOperation(format!("{} builds on unknown operation {}", entry.cid, prev))
+        })?;
+
+        let prev_data = history[prev_index].operation.operation.data().ok_or_else(|| {
+            PlcError::MisorderedOperation(format!("{} follows a tombstone", entry.cid))
+        })?;
+
+        let disputed = history.split_off(prev_index + 1);
+        match disputed.first() {
+            None => {
+                entry.operation.verify(&prev_data.rotation_keys)?;
+            }
+            Some(first_disputed) => {
+                // Only keys ranked above the one that signed the disputed op may fork
+                let disputed_signer = first_disputed.operation.verify(&prev_data.rotation_keys)?;
+                entry.operation.verify(&prev_data.rotation_keys[..disputed_signer])?;
+
+                let elapsed = (entry.created_at - first_disputed.created_at)
+                    .to_std()
+                    .unwrap_or_default();
+                if elapsed > RECOVERY_WINDOW {
+                    return Err(PlcError::LateRecovery(entry.cid.clone()));
+                }
+
+                nullified.extend(disputed.iter().map(|op| op.cid.as_str()));
+            }
+        }
+
+        history.push(entry);
+    }
+
+    for entry in log {
+        if entry.nullified != nullified.contains(entry.cid.as_str()) {
+            return Err(PlcError::InvalidLog(format!(
+                "{} has nullified={} but the history says otherwise",
+                entry.cid, entry.nullified
+            )));
+        }
+    }
+
+    Ok(history.last().and_then(|entry| entry.operation.operation.data()))
+}
+
+/// Check that the first operation created `did`
+fn validate_genesis(did: &str, entry: &AuditEntry) -> Result<()> {
+    let data = entry
+        .operation
+        .operation
+        .data()
+        .ok_or_else(|| PlcError::InvalidOperation("Genesis operation is a tombstone".to_string()))?;
+
+    let actual = entry.operation.did()?;
+    if actual != did {
+        return Err(PlcError::DidMismatch {
+            expected: did.to_string(),
+            actual,
+        });
+    }
+
+    entry.operation.verify(&data.rotation_keys)?;
+    Ok(())
+}
+
+/// Client for a PLC directory
+pub struct PlcClient {
+    client: reqwest::Client,
+    directory: String,
+}
+
+impl PlcClient {
+    /// Create a client for a PLC directory (e.g. "https://plc.directory")
+    pub fn new(directory: String) -> Self {
+        Self {
+            client: reqwest::Client::new(),
+            directory: directory.trim_end_matches('/').to_string(),
+        }
+    }
+
+    /// Create a client for `https://plc.directory`
+    pub fn with_default_directory() -> Self {
+        Self::new("https://plc.directory".to_string())
+    }
+
+    /// Fetch a DID's full operation history, including nullified operations
+    pub async fn audit_log(&self, did: &str) -> Result<Vec<AuditEntry>> {
+        let url = format!("{}/{}/log/audit", self.directory, did);
+        let response = self.client.get(&url).send().await?;
+
+        if !response.status().is_success() {
+            return Err(PlcError::Directory(format!(
+                "HTTP {} fetching audit log for {}",
+                response.status(),
+                did
+            )));
+        }
+
+        Ok(response.json().await?)
+    }
+
+    /// Fetch and validate a DID's audit log, returning its current data
+    ///
+    /// Returns None if the DID is tombstoned.
+    pub async fn verified_data(&self, did: &str) -> Result<Option<PlcData>> {
+        let log = self.audit_log(did).await?;
+        validate_audit_log(did, &log)
+    }
+
+    /// Submit a signed operation for a DID
+    pub async fn submit(&self, did: &str, operation: &SignedOperation) -> Result<()> {
+        let url = format!("{}/{}", self.directory, did);
+        let response = self.client.post(&url).json(operation).send().await?;
+
+        if !response.status().is_success() {
+            let status = response.status();
+            let body = response.text().await.unwrap_or_default();
+            return Err(PlcError::Directory(format!("HTTP {}: {}", status, body)));
+        }
+
+        Ok(())
+    }
+}
+
+/// Encode a serializable value as DAG-CBOR
+fn encode_dag_cbor<T: Serialize>(value: &T) -> Result<Vec<u8>> {
+    let ipld = libipld::serde::to_ipld(value).map_err(|e| PlcError::Serialization(e.to_string()))?;
+    libipld_cbor::DagCborCodec
+        .encode(&ipld)
+        .map_err(|e| PlcError::Serialization(e.to_string()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::crypto::KeyAlgorithm;
+    use chrono::TimeZone;
+
+    struct Keys {
+        recovery: Keypair,
+        rotation: Keypair,
+        signing: Keypair,
+    }
+
+    fn keys() -> Keys {
+        Keys {
+            recovery: Keypair::generate(KeyAlgorithm::K256),
+            rotation: Keypair::generate(KeyAlgorithm::P256),
+            signing: Keypair::generate(KeyAlgorithm::K256),
+        }
+    }
+
+    fn data(keys: &Keys, handle: &str) -> PlcData {
+        PlcData::new()
+            .with_rotation_key(keys.recovery.did_key())
+            .with_rotation_key(keys.rotation.did_key())
+            .with_verification_method("atproto", keys.signing.did_key())
+            .with_handle(handle)
+            .with_pds("https://pds.example.com")
+    }
+
+    fn entry(did: &str, operation: SignedOperation, hours: i64) -> AuditEntry {
+        AuditEntry {
+            did: did.to_string(),
+            cid: operation.cid().unwrap().to_string(),
+            operation,
+            nullified: false,
+            created_at: Utc.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap() + chrono::Duration::hours(hours),
+        }
+    }
+
+    fn cid(entry: &AuditEntry) -> Cid {
+        Cid::try_from(entry.cid.as_str()).unwrap()
+    }
+
+    /// Audit log signed and hashed by a separate DAG-CBOR and secp256k1
+    /// implementation, so the expected CIDs and DID don't come from this module
+    const AUDIT_LOG_VECTOR: &str = r#"[
+        {"did":"did:plc:wkraasb4jjumwgmqgyosj746","operation":{"type":"plc_operation","rotationKeys":["did:key:zQ3shNzsgB2NQ97cdDZz6ejRx7VpFkRCzupFVWNQ9RjovEqLA"],"verificationMethods":{"atproto":"did:key:zQ3shrypcLJG7Tdb9pBw6vEVdMzrtcnbMzNSdk2qKbjPjxfov"},"alsoKnownAs":["at://alice.example.com"],"services":{"atproto_pds":{"type":"AtprotoPersonalDataServer","endpoint":"https://pds.example.com"}},"prev":null,"sig":"wjYUIeEGiEaszOtfJb3vRewBT0LFZbyj6ZPgUS78jLQvhMK6TggRmhkTVbR-K1PAmdWbO5aPhvgWV4Ydq8sZPw"},"cid":"bafyreifsuiaeqpckndfrtebwdusp7hqbiwp2ig6qlcgmvkinl4or4igdhe","nullified":false,"createdAt":"2024-01-01T00:00:00.000Z"},
+        {"did":"did:plc:wkraasb4jjumwgmqgyosj746","operation":{"type":"plc_operation","rotationKeys":["did:key:zQ3shNzsgB2NQ97cdDZz6ejRx7VpFkRCzupFVWNQ9RjovEqLA"],"verificationMethods":{"atproto":"did:key:zQ3shrypcLJG7Tdb9pBw6vEVdMzrtcnbMzNSdk2qKbjPjxfov"},"alsoKnownAs":["at://alice.example.org"],"services":{"atproto_pds":{"type":"AtprotoPersonalDataServer","endpoint":"https://pds.example.com"}},"prev":"bafyreifsuiaeqpckndfrtebwdusp7hqbiwp2ig6qlcgmvkinl4or4igdhe","sig":"iEBFCoYBVKAdzOr1jGhPcban05bN6GTe83PF5HlPAwV0DwXGOXyaFXX-FKPMHnVvHSzXP1_3wCuyEUINPkxxNw"},"cid":"bafyreidpq457kva2227fmdp3lk4q6g45ifkzogm5j56gms2zeytecpvtke","nullified":false,"createdAt":"2024-01-02T00:00:00.000Z"}
+    ]"#;
+
+    #[test]
+    fn test_genesis_did() {
+        let keys = keys();
+        let genesis = UnsignedOperation::genesis(data(&keys, "alice.test"))
+            .sign(&keys.rotation)
+            .unwrap();
+
+        let did = genesis.did().unwrap();
+        assert!(did.starts_with("did:plc:"));
+        assert_eq!(did.len(), "did:plc:".len() + 24);
+        assert!(crate::types::Did::new(did.as_str()).is_ok());
+
+        // Signed by the second rotation key
+        assert_eq!(genesis.verify(&data(&keys, "alice.test").rotation_keys).unwrap(), 1);
+
+        let update = UnsignedOperation::update(&genesis.cid().unwrap(), data(&keys, "alice.test"))
+            .sign(&keys.rotation)
+            .unwrap();
+        assert!(matches!(update.did(), Err(PlcError::InvalidOperation(_))));
+    }
+
+    #[test]
+    fn test_audit_log_vector() {
+        let log: Vec<AuditEntry> = serde_json::from_str(AUDIT_LOG_VECTOR).unwrap();
+        let genesis = &log[0].operation;
+
+        assert_eq!(genesis.did().unwrap(), "did:plc:wkraasb4jjumwgmqgyosj746");
+        assert_eq!(genesis.cid().unwrap().to_string(), "bafyreifsuiaeqpckndfrtebwdusp7hqbiwp2ig6qlcgmvkinl4or4igdhe");
+        assert_eq!(
+            log[1].operation.cid().unwrap().to_string(),
+            "bafyreidpq457kva2227fmdp3lk4q6g45ifkzogm5j56gms2zeytecpvtke"
+        );
+
+        let rotation_keys = genesis.operation.data().unwrap().rotation_keys;
+        assert_eq!(genesis.verify(&rotation_keys).unwrap(), 0);
+        assert_eq!(log[1].operation.verify(&rotation_keys).unwrap(), 0);
+
+        let data = validate_audit_log("did:plc:wkraasb4jjumwgmqgyosj746", &log).unwrap().unwrap();
+        assert_eq!(data.also_known_as, vec!["at://alice.example.org"]);
+    }
+
+    #[test]
+    fn test_operation_json() {
+        let keys = keys();
+        let genesis = UnsignedOperation::genesis(data(&keys, "alice.test"))
+            .sign(&keys.rotation)
+            .unwrap();
+
+        let json = genesis.to_json().unwrap();
+        assert_eq!(json["type"], "plc_operation");
+        assert_eq!(json["prev"], serde_json::Value::Null);
+        assert_eq!(json["alsoKnownAs"][0], "at://alice.test");
+        assert_eq!(json["services"]["atproto_pds"]["type"], "AtprotoPersonalDataServer");
+        assert_eq!(json["verificationMethods"]["atproto"], keys.signing.did_key());
+        assert!(json["sig"].is_string());
+
+        let parsed: SignedOperation = serde_json::from_value(json).unwrap();
+        assert_eq!(parsed, genesis);
+        assert_eq!(parsed.cid().unwrap(), genesis.cid().unwrap());
+
+        let tombstone = UnsignedOperation::tombstone(&genesis.cid().unwrap())
+            .sign(&keys.rotation)
+            .unwrap();
+        let json = tombstone.to_json().unwrap();
+        assert_eq!(json["type"], "plc_tombstone");
+        assert_eq!(serde_json::from_value::<SignedOperation>(json).unwrap(), tombstone);
+    }
+
+    #[test]
+    fn test_legacy_create_normalization() {
+        let op = UnsignedOperation::LegacyCreate(LegacyCreate {
+            signing_key: "did:key:signing".to_string(),
+            recovery_key: "did:key:recovery".to_string(),
+            handle: "alice.bsky.social".to_string(),
+            service: "bsky.social".to_string(),
+            prev: None,
+        });
+
+        let data = op.data().unwrap();
+        assert_eq!(data.rotation_keys, vec!["did:key:recovery", "did:key:signing"]);
+        assert_eq!(data.verification_methods["atproto"], "did:key:signing");
+        assert_eq!(data.also_known_as, vec!["at://alice.bsky.social"]);
+        assert_eq!(data.services["atproto_pds"].endpoint, "https://bsky.social");
+    }
+
+    #[test]
+    fn test_to_did_document() {
+        let keys = keys();
+        let doc = data(&keys, "alice.test").to_did_document("did:plc:abc");
+
+        assert_eq!(doc.get_handle().as_deref(), Some("alice.test"));
+        assert_eq!(doc.get_pds_endpoint().unwrap(), "https://pds.example.com");
+        let key = PublicKey::from_verification_method(doc.get_signing_key().unwrap()).unwrap();
+        assert_eq!(key.to_did_key(), keys.signing.did_key());
+    }
+
+    #[test]
+    fn test_validate_audit_log() {
+        let keys = keys();
+        let genesis = UnsignedOperation::genesis(data(&keys, "alice.test"))
+            .sign(&keys.rotation)
+            .unwrap();
+        let did = genesis.did().unwrap();
+
+        let e0 = entry(&did, genesis, 0);
+        let update = UnsignedOperation::update(&cid(&e0), data(&keys, "alice2.test"))
+            .sign(&keys.rotation)
+            .unwrap();
+        let e1 = entry(&did, update, 1);
+
+        let current = validate_audit_log(&did, &[e0.clone(), e1.clone()]).unwrap().unwrap();
+        assert_eq!(current.also_known_as, vec!["at://alice2.test"]);
+
+        // Tombstoned
+        let tombstone = UnsignedOperation::tombstone(&cid(&e1)).sign(&keys.recovery).unwrap();
+        let e2 = entry(&did, tombstone, 2);
+        assert!(validate_audit_log(&did, &[e0.clone(), e1.clone(), e2.clone()]).unwrap().is_none());
+
+        let after = UnsignedOperation::update(&cid(&e2), data(&keys, "alice3.test"))
+            .sign(&keys.recovery)
+            .unwrap();
+        assert!(matches!(
+            validate_audit_log(&did, &[e0, e1, e2, entry(&did, after, 3)]),
+            Err(PlcError::MisorderedOperation(_))
+        ));
+    }
+
+    #[test]
+    fn test_validate_audit_log_rejects_bad_operations() {
+        let keys = keys();
+        let genesis = UnsignedOperation::genesis(data(&keys, "alice.test"))
+            .sign(&keys.rotation)
+            .unwrap();
+        let did = genesis.did().unwrap();
+        let e0 = entry(&did, genesis, 0);
+
+        let other_did = "did:plc:aaaaaaaaaaaaaaaaaaaaaaaa";
+        assert!(matches!(
+            validate_audit_log(other_did, &[entry(other_did, e0.operation.clone(), 0)]),
+            Err(PlcError::DidMismatch { .. })
+        ));
+
+        // Signed by the atproto signing key, which is not a rotation key
+        let update = UnsignedOperation::update(&cid(&e0), data(&keys, "evil.test"))
+            .sign(&keys.signing)
+            .unwrap();
+        assert!(matches!(
+            validate_audit_log(&did, &[e0.clone(), entry(&did, update, 1)]),
+            Err(PlcError::InvalidSignature)
+        ));
+
+        // Tampered after signing
+        let mut update = UnsignedOperation::update(&cid(&e0), data(&keys, "alice2.test"))
+            .sign(&keys.rotation)
+            .unwrap();
+        if let UnsignedOperation::Operation { data, .. } = &mut update.operation {
+            data.also_known_as = vec!["at://evil.test".to_string()];
+        }
+        assert!(matches!(
+            validate_audit_log(&did, &[e0.clone(), entry(&did, update, 1)]),
+            Err(PlcError::InvalidSignature)
+        ));
+
+        let mut bad_cid = entry(&did, e0.operation.clone(), 0);
+        bad_cid.cid = cid(&e0).to_string().replace('a', "b");
+        assert!(matches!(validate_audit_log(&did, &[bad_cid]), Err(PlcError::CidMismatch { .. })));
+    }
+
+    #[test]
+    fn test_validate_audit_log_recovery() {
+        let keys = keys();
+        let genesis = UnsignedOperation::genesis(data(&keys, "alice.test"))
+            .sign(&keys.rotation)
+            .unwrap();
+        let did = genesis.did().unwrap();
+        let e0 = entry(&did, genesis, 0);
+
+        // The lower-priority rotation key is compromised and used to hijack the handle
+        let hijack = UnsignedOperation::update(&cid(&e0), data(&keys, "evil.test"))
+            .sign(&keys.rotation)
+            .unwrap();
+        let mut e1 = entry(&did, hijack, 10);
+
+        // The recovery key forks history from genesis within 72 hours
+        let recover = UnsignedOperation::update(&cid(&e0), data(&keys, "alice.test"))
+            .sign(&keys.recovery)
+            .unwrap();
+        let e2 = entry(&did, recover.clone(), 10 + 71);
+
+        // The directory must have flagged the hijack as nullified
+        assert!(matches!(
+            validate_audit_log(&did, &[e0.clone(), e1.clone(), e2.clone()]),
+            Err(PlcError::InvalidLog(_))
+        ));
+        e1.nullified = true;
+        let current = validate_audit_log(&did, &[e0.clone(), e1.clone(), e2]).unwrap().unwrap();
+        assert_eq!(current.also_known_as, vec!["at://alice.test"]);
+
+        // Too late
+        let late = entry(&did, recover, 10 + 73);
+        assert!(matches!(
+            validate_audit_log(&did, &[e0.clone(), e1.clone(), late]),
+            Err(PlcError::LateRecovery(_))
+        ));
+
+        // A key of equal priority can't fork history
+        let fork = UnsignedOperation::update(&cid(&e0), data(&keys, "other.test"))
+            .sign(&keys.rotation)
+            .unwrap();
+        assert!(matches!(
+            validate_audit_log(&did, &[e0, e1, entry(&did, fork, 11)]),
+            Err(PlcError::InvalidSignature)
+        ));
+    }
+}
@@ -4,7 +4,8 @@
 //! - Commit creation and signing
 //! - Repository versioning
 //! - MST integration
-//! - CAR export
+//! - CAR import and export
+//! - Commit signature verification
 //!
 //! # Repository Structure
 //!
@@ -43,7 +44,9 @@
 //! // let commit_cid = repo.commit(signing_key)?;
 //! ```
 
-use crate::car::CarWriter;
+use crate::car::{CarReader, CarWriter};
+use crate::crypto::{CryptoError, PublicKey};
+use crate::did_doc::DidDocument;
 use crate::mst::{Mst, MstError};
 use crate::tid::Tid;
 use crate::types::Did;
@@ -84,6 +87,21 @@ pub enum RepoError {
 
     #[error("Invalid commit: {0}")]
     InvalidCommit(String),
+
+    #[error("Block {0} does not match its CID")]
+    BlockHashMismatch(String),
+
+    #[error("Commit DID {commit} does not match DID document {document}")]
+    DidMismatch { commit: String, document: String },
+
+    #[error("Invalid signing key: {0}")]
+    InvalidSigningKey(String),
+
+    #[error("Malformed commit signature")]
+    MalformedSignature,
+
+    #[error("Commit signature does not match the signing key of {0}")]
+    SignatureMismatch(String),
 }
 
 /// Result type for repository operations
@@ -170,6 +188,51 @@ impl SignedCommit {
         Ipld::Map(map)
     }
 
+    /// Create from IPLD representation
+    fn from_ipld(ipld: &Ipld) -> Result<Self> {
+        let Ipld::Map(map) = ipld else {
+            return Err(RepoError::InvalidCommit("Commit is not a map".to_string()));
+        };
+
+        let string_field = |name: &str| match map.get(name) {
+            Some(Ipld::String(s)) => Ok(s.clone()),
+            _ => Err(RepoError::InvalidCommit(format!("Missing or invalid {} field", name))),
+        };
+
+        let version = match map.get("version") {
+            Some(Ipld::Integer(v)) => u32::try_from(*v)
+                .map_err(|_| RepoError::InvalidCommit(format!("Invalid version: {}", v)))?,
+            _ => return Err(RepoError::InvalidCommit("Missing or invalid version field".to_string())),
+        };
+
+        let data = match map.get("data") {
+            Some(Ipld::Link(cid)) => *cid,
+            _ => return Err(RepoError::InvalidCommit("Missing or invalid data field".to_string())),
+        };
+
+        let prev = match map.get("prev") {
+            Some(Ipld::Link(cid)) => Some(*cid),
+            Some(Ipld::Null) | None => None,
+            _ => return Err(RepoError::InvalidCommit("Invalid prev field".to_string())),
+        };
+
+        let sig = match map.get("sig") {
+            Some(Ipld::Bytes(sig)) => sig.clone(),
+            _ => return Err(RepoError::InvalidCommit("Missing or invalid sig field".to_string())),
+        };
+
+        Ok(Self {
+            commit: UnsignedCommit {
+                did: string_field("did")?,
+                version,
+                data,
+                rev: string_field("rev")?,
+                prev,
+            },
+            sig,
+        })
+    }
+
     /// Serialize to DAG-CBOR bytes
     pub fn to_cbor(&self) -> Result<Vec<u8>> {
         let ipld = self.to_ipld();
@@ -177,6 +240,13 @@ impl SignedCommit {
             .map_err(|e| RepoError::Serialization(format!("Failed to encode signed commit: {}", e)))
     }
 
+    /// Deserialize a signed commit from DAG-CBOR bytes
+    pub fn from_cbor(bytes: &[u8]) -> Result<Self> {
+        let ipld = libipld_cbor::DagCborCodec.decode(bytes)
+            .map_err(|e| RepoError::Serialization(format!("Failed to decode signed commit: {}", e)))?;
+        Self::from_ipld(&ipld)
+    }
+
     /// Calculate the CID of this commit
     pub fn to_cid(&self) -> Result<Cid> {
         let cbor_bytes = self.to_cbor()?;
@@ -187,6 +257,58 @@ impl SignedCommit {
 
         Ok(Cid::new_v1(0x71, multihash)) // dag-cbor codec
     }
+
+    /// Verify the signature against the author's DID document
+    ///
+    /// See [`verify_commit`].
+    pub fn verify(&self, did_doc: &DidDocument) -> Result<()> {
+        verify_commit(self, did_doc)
+    }
+}
+
+/// Verify a signed commit against the DID document of its author
+///
+/// Checks that the commit belongs to `did_doc`, then re-derives the signing
+/// hash and verifies `sig` with the document's `#atproto` key. Works on commits
+/// from [`Repository::from_car`] as well as live firehose commits.
+///
+/// # Errors
+///
+/// - [`RepoError::DidMismatch`] if the commit DID is not the document's DID
+/// - [`RepoError::InvalidSigningKey`] if the document has no usable `#atproto` key
+/// - [`RepoError::MalformedSignature`] if `sig` is not a 64-byte compact signature
+/// - [`RepoError::SignatureMismatch`] if the signature was not made by the key
+///
+/// # Example
+///
+/// ```no_run
+/// # use atproto::did_doc::DidDocument;
+/// # use atproto::repo::{verify_commit, Repository};
+/// # fn example(car_bytes: &[u8], did_doc: &DidDocument) -> atproto::repo::Result<()> {
+/// let repo = Repository::from_car(car_bytes)?;
+/// verify_commit(repo.head_commit().unwrap(), did_doc)?;
+/// # Ok(())
+/// # }
+/// ```
+pub fn verify_commit(commit: &SignedCommit, did_doc: &DidDocument) -> Result<()> {
+    if commit.commit.did != did_doc.id {
+        return Err(RepoError::DidMismatch {
+            commit: commit.commit.did.clone(),
+            document: did_doc.id.clone(),
+        });
+    }
+
+    let method = did_doc
+        .get_signing_key()
+        .ok_or_else(|| RepoError::InvalidSigningKey(format!("{} has no #atproto key", did_doc.id)))?;
+    let key = PublicKey::from_verification_method(method)
+        .map_err(|e| RepoError::InvalidSigningKey(e.to_string()))?;
+
+    let hash = commit.commit.signing_hash()?;
+    key.verify_hash(&hash, &commit.sig).map_err(|e| match e {
+        CryptoError::MalformedSignature => RepoError::MalformedSignature,
+        _ => RepoError::SignatureMismatch(did_doc.id.clone()),
+    })
 }
 
 /// Repository manager
@@ -209,6 +331,22 @@ pub struct Repository {
     current_rev: Option<Tid>,
 }
 
+/// A record stored in a repository
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RepoRecord<'a> {
+    /// Collection NSID
+    pub collection: String,
+
+    /// Record key
+    pub rkey: String,
+
+    /// CID of the record block
+    pub cid: Cid,
+
+    /// Record data as DAG-CBOR bytes
+    pub data: &'a [u8],
+}
+
 impl Repository {
     /// Create a new repository for a DID
     ///
@@ -235,6 +373,69 @@ impl Repository {
         }
     }
 
+    /// Load a repository from a CAR file
+    ///
+    /// The CAR's first root must be a signed commit. Every block is checked
+    /// against the SHA-256 hash in its CID, and the MST is rebuilt directly
+    /// from its node blocks. Blocks not reachable from the commit are ignored.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use atproto::repo::Repository;
+    /// # let car_bytes: Vec<u8> = Vec::new();
+    /// let repo = Repository::from_car(&car_bytes).unwrap();
+    /// for record in repo.records() {
+    ///     println!("{}/{} ({} bytes)", record.collection, record.rkey, record.data.len());
+    /// }
+    /// ```
+    pub fn from_car(bytes: &[u8]) -> Result<Self> {
+        let reader = CarReader::new(bytes)
+            .map_err(|e| RepoError::Car(e.to_string()))?;
+
+        let head = *reader
+            .roots()
+            .first()
+            .ok_or_else(|| RepoError::Car("CAR file has no root".to_string()))?;
+
+        let mut blocks = BTreeMap::new();
+        for block in reader.blocks() {
+            let (cid, data) = block.map_err(|e| RepoError::Car(e.to_string()))?;
+            verify_block(&cid, &data)?;
+            blocks.insert(cid, data);
+        }
+
+        let commit_bytes = blocks
+            .get(&head)
+            .ok_or_else(|| RepoError::Car(format!("Missing commit block: {}", head)))?;
+        let commit = SignedCommit::from_cbor(commit_bytes)?;
+
+        if commit.commit.version != REPO_VERSION {
+            return Err(RepoError::InvalidCommit(format!(
+                "Unsupported repository version: {}",
+                commit.commit.version
+            )));
+        }
+
+        let did = Did::new(commit.commit.did.as_str())
+            .map_err(|e| RepoError::InvalidDid(e.to_string()))?;
+        let rev = commit.commit.rev.parse::<Tid>()
+            .map_err(|e| RepoError::InvalidCommit(format!("Invalid rev {}: {}", commit.commit.rev, e)))?;
+
+        let mst = Mst::from_blocks(commit.commit.data, &blocks)?;
+
+        let mut commits = HashMap::new();
+        commits.insert(head, commit);
+
+        Ok(Self {
+            did,
+            mst,
+            commits,
+            head: Some(head),
+            current_rev: Some(rev),
+        })
+    }
+
     /// Get the repository DID
     pub fn did(&self) -> &Did {
         &self.did
@@ -335,12 +536,13 @@ impl Repository {
     /// # Example
     ///
     /// ```no_run
-    /// # use atproto::repo::Repository;
+    /// # use atproto::crypto::{KeyAlgorithm, Keypair};
+    /// # use atproto::repo::{RepoError, Repository};
     /// # use atproto::types::Did;
     /// # let mut repo = Repository::create(Did::new("did:plc:test").unwrap());
+    /// let keypair = Keypair::generate(KeyAlgorithm::K256);
     /// let commit_cid = repo.commit(|hash| {
-    ///     // Sign the hash with your signing key
-    ///     Ok(vec![0u8; 64]) // Dummy signature
+    ///     keypair.sign_hash(hash).map_err(|e| RepoError::Signing(e.to_string()))
     /// }).unwrap();
     /// ```
     pub fn commit<F>(&mut self, sign_fn: F) -> Result<Cid>
@@ -391,8 +593,31 @@ impl Repository {
         self.commits.get(cid)
     }
 
+    /// Get the current head commit
+    pub fn head_commit(&self) -> Option<&SignedCommit> {
+        self.head.as_ref().and_then(|cid| self.commits.get(cid))
+    }
+
+    /// Iterate over all records in key order
+    pub fn records(&self) -> impl Iterator<Item = RepoRecord<'_>> + '_ {
+        self.mst.list_entries().into_iter().filter_map(move |(key, cid)| {
+            let (collection, rkey) = key.split_once('/')?;
+            Some(RepoRecord {
+                collection: collection.to_string(),
+                rkey: rkey.to_string(),
+                cid,
+                data: self.mst.get_leaf(&cid)?,
+            })
+        })
+    }
+
     /// Export repository to CAR file
     ///
+    /// The CAR has the head commit as its single root and contains the commit
+    /// block followed by every MST node and record block, in the order given
+    /// by [`Mst::blocks`]. The repository must not have changes made since the
+    /// last commit, since the exported tree has to match the commit's `data`.
+    ///
     /// # Returns
     ///
     /// Returns CAR file bytes containing the repository
@@ -406,15 +631,21 @@ impl Repository {
 
             // Write head commit
             if let Some(commit) = self.commits.get(head_cid) {
+                if self.mst.root_cid()? != commit.commit.data {
+                    return Err(RepoError::Car(
+                        "Repository has uncommitted changes; commit before exporting".to_string(),
+                    ));
+                }
+
                 let commit_bytes = commit.to_cbor()?;
                 writer.write_block(head_cid, &commit_bytes)
                     .map_err(|e| RepoError::Car(e.to_string()))?;
 
-                // Write MST root
-                let mst_root_cid = commit.commit.data;
-                let mst_root_bytes = self.mst.root_cid()?.to_string().into_bytes(); // Placeholder
-                writer.write_block(&mst_root_cid, &mst_root_bytes)
-                    .map_err(|e| RepoError::Car(e.to_string()))?;
+                // Write MST nodes and records
+                for (cid, bytes) in self.mst.blocks()? {
+                    writer.write_block(&cid, &bytes)
+                        .map_err(|e| RepoError::Car(e.to_string()))?;
+                }
             }
         }
 
@@ -438,9 +669,24 @@ impl Repository {
     }
 }
 
+/// Check that a block's bytes hash to its CID
+fn verify_block(cid: &Cid, data: &[u8]) -> Result<()> {
+    let hash = cid.hash();
+    if hash.code() != 0x12 {
+        return Err(RepoError::Cid(format!("Unsupported hash function 0x{:x} in {}", hash.code(), cid)));
+    }
+
+    if hash.digest() != Sha256::digest(data).as_slice() {
+        return Err(RepoError::BlockHashMismatch(cid.to_string()));
+    }
+
+    Ok(())
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::car::CarReader;
 
     fn create_test_repo() -> Repository {
         let did = Did::new("did:plc:test123").unwrap();
@@ -610,4 +856,204 @@ mod tests {
         let reader = CarReader::new(&car_bytes[..]);
         assert!(reader.is_ok());
     }
+
+    #[test]
+    fn test_export_car_round_trip() {
+        let mut repo = create_test_repo();
+
+        repo.put_record("app.bsky.feed.post", "key1", b"data1".to_vec()).unwrap();
+        repo.put_record("app.bsky.feed.post", "key2", b"data2".to_vec()).unwrap();
+        repo.put_record("app.bsky.feed.like", "key3", b"data3".to_vec()).unwrap();
+        let head = repo.commit(dummy_signer).unwrap();
+
+        let car_bytes = repo.export_car().unwrap();
+        let reader = CarReader::new(&car_bytes[..]).unwrap();
+        assert_eq!(reader.roots(), &[head]);
+
+        let blocks: Vec<(Cid, Vec<u8>)> = reader.blocks().collect::<std::result::Result<_, _>>().unwrap();
+
+        // Every CID matches the hash of its bytes
+        for (cid, bytes) in &blocks {
+            let hash = Sha256::digest(bytes);
+            let multihash = libipld::multihash::Multihash::wrap(0x12, &hash).unwrap();
+            assert_eq!(*cid, Cid::new_v1(cid.codec(), multihash));
+        }
+
+        // Commit first, then the MST root it points to
+        assert_eq!(blocks[0].0, head);
+        let commit = repo.get_commit(&head).unwrap();
+        assert_eq!(blocks[1].0, commit.commit.data);
+        assert!(crate::mst::MstNode::from_cbor(&blocks[1].1).is_ok());
+
+        // All record blocks are present
+        for (collection, rkey) in [("app.bsky.feed.post", "key1"), ("app.bsky.feed.post", "key2"), ("app.bsky.feed.like", "key3")] {
+            let record = repo.get_record(collection, rkey).unwrap();
+            assert!(blocks.iter().any(|(_, bytes)| bytes == record));
+        }
+        assert_eq!(blocks.len(), repo.mst().blocks().unwrap().len() + 1);
+    }
+
+    #[test]
+    fn test_from_car() {
+        let mut repo = create_test_repo();
+
+        repo.put_record("app.bsky.feed.post", "key1", b"data1".to_vec()).unwrap();
+        repo.put_record("app.bsky.feed.post", "key2", b"data2".to_vec()).unwrap();
+        repo.put_record("app.bsky.feed.like", "key3", b"data3".to_vec()).unwrap();
+        let head = repo.commit(dummy_signer).unwrap();
+
+        let loaded = Repository::from_car(&repo.export_car().unwrap()).unwrap();
+
+        assert_eq!(loaded.did(), repo.did());
+        assert_eq!(loaded.head(), Some(&head));
+        assert_eq!(loaded.rev(), repo.rev());
+        assert_eq!(loaded.head_commit().unwrap().sig, vec![0u8; 64]);
+        assert_eq!(loaded.mst().root_cid().unwrap(), repo.mst().root_cid().unwrap());
+
+        let records: Vec<RepoRecord> = loaded.records().collect();
+        assert_eq!(records, repo.records().collect::<Vec<_>>());
+        assert_eq!(records.len(), 3);
+        assert_eq!(records[0].collection, "app.bsky.feed.like");
+        assert_eq!(records[0].rkey, "key3");
+        assert_eq!(records[0].data, b"data3");
+
+        assert_eq!(loaded.get_record("app.bsky.feed.post", "key2"), Some(&b"data2"[..]));
+        assert_eq!(loaded.list_records("app.bsky.feed.post"), vec!["key1", "key2"]);
+    }
+
+    #[test]
+    fn test_from_car_rejects_tampered_block() {
+        use crate::car::CarWriter;
+
+        let mut repo = create_test_repo();
+        repo.put_record("app.bsky.feed.post", "key1", b"data1".to_vec()).unwrap();
+        let head = repo.commit(dummy_signer).unwrap();
+
+        let car_bytes = repo.export_car().unwrap();
+        let reader = CarReader::new(&car_bytes[..]).unwrap();
+
+        let mut writer = CarWriter::with_roots(Vec::new(), vec![head]);
+        for block in reader.blocks() {
+            let (cid, mut data) = block.unwrap();
+            if data == b"data1" {
+                data = b"evil1".to_vec();
+            }
+            writer.write_block(&cid, &data).unwrap();
+        }
+        let tampered = writer.finish().unwrap();
+
+        assert!(matches!(
+            Repository::from_car(&tampered),
+            Err(RepoError::BlockHashMismatch(_))
+        ));
+    }
+
+    #[test]
+    fn test_from_car_missing_block() {
+        use crate::car::CarWriter;
+
+        let mut repo = create_test_repo();
+        repo.put_record("app.bsky.feed.post", "key1", b"data1".to_vec()).unwrap();
+        let head = repo.commit(dummy_signer).unwrap();
+
+        let car_bytes = repo.export_car().unwrap();
+        let reader = CarReader::new(&car_bytes[..]).unwrap();
+
+        // Drop the record block
+        let mut writer = CarWriter::with_roots(Vec::new(), vec![head]);
+        for block in reader.blocks() {
+            let (cid, data) = block.unwrap();
+            if data != b"data1" {
+                writer.write_block(&cid, &data).unwrap();
+            }
+        }
+        let partial = writer.finish().unwrap();
+
+        assert!(matches!(
+            Repository::from_car(&partial),
+            Err(RepoError::Mst(MstError::MissingBlock(_)))
+        ));
+    }
+
+    #[test]
+    fn test_export_car_uncommitted_changes() {
+        let mut repo = create_test_repo();
+
+        repo.put_record("app.bsky.feed.post", "key1", b"data1".to_vec()).unwrap();
+        repo.commit(dummy_signer).unwrap();
+        repo.put_record("app.bsky.feed.post", "key2", b"data2".to_vec()).unwrap();
+
+        assert!(matches!(repo.export_car(), Err(RepoError::Car(_))));
+    }
+
+    fn did_doc_with_key(did: &str, key: &crate::crypto::Keypair) -> DidDocument {
+        DidDocument::from_json(&format!(
+            r#"{{
+                "id": "{did}",
+                "verificationMethod": [{{
+                    "id": "{did}#atproto",
+                    "type": "Multikey",
+                    "controller": "{did}",
+                    "publicKeyMultibase": "{}"
+                }}]
+            }}"#,
+            key.public_key().to_multibase()
+        ))
+        .unwrap()
+    }
+
+    fn signed_repo(key: &crate::crypto::Keypair) -> Repository {
+        let mut repo = create_test_repo();
+        repo.put_record("app.bsky.feed.post", "key1", b"data1".to_vec()).unwrap();
+        repo.commit(|hash| key.sign_hash(hash).map_err(|e| RepoError::Signing(e.to_string())))
+            .unwrap();
+        repo
+    }
+
+    #[test]
+    fn test_verify_commit() {
+        use crate::crypto::{KeyAlgorithm, Keypair};
+
+        for algorithm in [KeyAlgorithm::K256, KeyAlgorithm::P256] {
+            let key = Keypair::generate(algorithm);
+            let repo = signed_repo(&key);
+            let doc = did_doc_with_key("did:plc:test123", &key);
+
+            assert!(verify_commit(repo.head_commit().unwrap(), &doc).is_ok());
+
+            // Also after a CAR round trip
+            let loaded = Repository::from_car(&repo.export_car().unwrap()).unwrap();
+            assert!(loaded.head_commit().unwrap().verify(&doc).is_ok());
+        }
+    }
+
+    #[test]
+    fn test_verify_commit_errors() {
+        use crate::crypto::{KeyAlgorithm, Keypair};
+
+        let key = Keypair::generate(KeyAlgorithm::K256);
+        let repo = signed_repo(&key);
+        let commit = repo.head_commit().unwrap();
+
+        // Signed by someone else
+        let other = did_doc_with_key("did:plc:test123", &Keypair::generate(KeyAlgorithm::K256));
+        assert!(matches!(verify_commit(commit, &other), Err(RepoError::SignatureMismatch(_))));
+
+        // Document for another account
+        let wrong_did = did_doc_with_key("did:plc:other", &key);
+        assert!(matches!(verify_commit(commit, &wrong_did), Err(RepoError::DidMismatch { .. })));
+
+        // Tampered commit
+        let doc = did_doc_with_key("did:plc:test123", &key);
+        let mut tampered = commit.clone();
+        tampered.commit.rev = Tid::next().unwrap().to_string();
+        assert!(matches!(verify_commit(&tampered, &doc), Err(RepoError::SignatureMismatch(_))));
+
+        let mut truncated = commit.clone();
+        truncated.sig.truncate(10);
+        assert!(matches!(verify_commit(&truncated, &doc), Err(RepoError::MalformedSignature)));
+
+        let no_key = DidDocument::from_json(r#"{"id": "did:plc:test123"}"#).unwrap();
+        assert!(matches!(verify_commit(commit, &no_key), Err(RepoError::InvalidSigningKey(_))));
+    }
 }
@@ -0,0 +1,494 @@
+//! Feed generator service
+//!
+//! A feed generator serves `app.bsky.feed.getFeedSkeleton` for one or more
+//! feeds: it returns a list of post URIs, and the App View hydrates them into
+//! full posts. Implement [`FeedAlgorithm`] for each feed and register it with a
+//! [`FeedGenerator`], which serves:
+//!
+//! - `GET /xrpc/app.bsky.feed.getFeedSkeleton`
+//! - `GET /xrpc/app.bsky.feed.describeFeedGenerator`
+//! - `GET /.well-known/did.json` (for `did:web` services)
+//!
+//! ## Requester authentication
+//!
+//! The App View forwards a service auth token for the user viewing the feed.
+//! With [`FeedGenerator::with_service_auth`] the token is verified and the
+//! user's DID is passed to the algorithm; without it, requests are anonymous.
+//!
+//! ## Example
+//!
+//! ```no_run
+//! use async_trait::async_trait;
+//! use atproto::client::app::bsky::feed::get_feed_skeleton::{Output, QueryParams};
+//! use atproto::did_doc::DidResolver;
+//! use atproto::server::{FeedAlgorithm, FeedGenerator};
+//! use atproto::syntax::AtUri;
+//! use atproto::types::Did;
+//!
+//! struct Pinned;
+//!
+//! #[async_trait]
+//! impl FeedAlgorithm for Pinned {
+//!     async fn skeleton(&self, _params: QueryParams, _requester: Option<Did>) -> atproto::xrpc::Result<Output> {
+//!         Ok(Output {
+//!             feed: vec![serde_json::json!({ "post": "at://did:plc:alice/app.bsky.feed.post/3k2a" })],
+//!             cursor: None,
+//!             req_id: None,
+//!         })
+//!     }
+//! }
+//!
+//! # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+//! let generator = FeedGenerator::new(
+//!     Did::new("did:web:feeds.example.com")?,
+//!     "https://feeds.example.com",
+//! )
+//! .with_feed(AtUri::new("at://did:plc:alice/app.bsky.feed.generator/pinned")?, Pinned)
+//! .with_service_auth(DidResolver::with_default_plc());
+//!
+//! let listener = tokio::net::TcpListener::bind("0.0.0.0:3000").await?;
+//! axum::serve(listener, generator.into_router()).await?;
+//! # Ok(())
+//! # }
+//! ```
+
+use super::{error_response, XrpcRouter};
+use crate::client::app::bsky::feed::get_feed_skeleton::{self, Output, QueryParams};
+use crate::client::app::bsky::feed::describe_feed_generator;
+use crate::did_doc::{DidDocument, DidResolver, Service};
+use crate::service_auth::ServiceAuthVerifier;
+use crate::syntax::AtUri;
+use crate::types::Did;
+use crate::xrpc::{Result, XrpcError};
+use async_trait::async_trait;
+use axum::extract::State;
+use axum::http::{header, HeaderMap, StatusCode};
+use axum::response::{IntoResponse, Response};
+use axum::routing::get;
+use axum::{Json, Router};
+use std::collections::BTreeMap;
+use std::sync::Arc;
+
+/// NSID of the skeleton endpoint, also the `lxm` of requester tokens
+pub const GET_FEED_SKELETON: &str = "app.bsky.feed.getFeedSkeleton";
+
+/// NSID of the describe endpoint
+pub const DESCRIBE_FEED_GENERATOR: &str = "app.bsky.feed.describeFeedGenerator";
+
+/// Page size used when the request has no `limit`
+pub const DEFAULT_LIMIT: i64 = 50;
+
+/// Largest page size a client may request
+pub const MAX_LIMIT: i64 = 100;
+
+/// A custom feed
+///
+/// `params.limit` is always set, defaulted and bounds-checked against the
+/// lexicon (1-100). `params.cursor` is whatever the previous page returned in
+/// [`Output::cursor`]; the format is up to the algorithm.
+#[async_trait]
+pub trait FeedAlgorithm: Send + Sync {
+    /// Produce one page of the feed for `requester` (`None` if anonymous)
+    ///
+    /// Return `XrpcError::InvalidRequest` for a malformed cursor.
+    async fn skeleton(&self, params: QueryParams, requester: Option<Did>) -> Result<Output>;
+}
+
+/// A feed generator service hosting one or more feeds
+pub struct FeedGenerator {
+    did: Did,
+    service_endpoint: String,
+    feeds: BTreeMap<String, Arc<dyn FeedAlgorithm>>,
+    verifier: Option<ServiceAuthVerifier>,
+    require_auth: bool,
+    privacy_policy: Option<String>,
+    terms_of_service: Option<String>,
+}
+
+impl FeedGenerator {
+    /// Create a feed generator
+    ///
+    /// `did` is the service DID that feed records point to, and
+    /// `service_endpoint` is the public URL it is reachable at.
+    pub fn new(did: Did, service_endpoint: impl Into<String>) -> Self {
+        Self {
+            did,
+            service_endpoint: service_endpoint.into(),
+            feeds: BTreeMap::new(),
+            verifier: None,
+            require_auth: false,
+            privacy_policy: None,
+            terms_of_service: None,
+        }
+    }
+
+    /// Register a feed under the URI of its `app.bsky.feed.generator` record
+    pub fn with_feed(mut self, uri: AtUri, algorithm: impl FeedAlgorithm + 'static) -> Self {
+        self.feeds.insert(uri.to_string(), Arc::new(algorithm));
+        self
+    }
+
+    /// Verify requester tokens, resolving issuers with `resolver`
+    ///
+    /// Tokens must be addressed to this service's DID and bound to
+    /// `app.bsky.feed.getFeedSkeleton`.
+    pub fn with_service_auth(mut self, resolver: DidResolver) -> Self {
+        self.verifier = Some(ServiceAuthVerifier::new(self.did.as_str(), resolver));
+        self
+    }
+
+    /// Reject requests without a requester token
+    ///
+    /// Only takes effect together with [`FeedGenerator::with_service_auth`].
+    pub fn with_required_auth(mut self) -> Self {
+        self.require_auth = true;
+        self
+    }
+
+    /// Link to a privacy policy in `describeFeedGenerator`
+    pub fn with_privacy_policy(mut self, url: impl Into<String>) -> Self {
+        self.privacy_policy = Some(url.into());
+        self
+    }
+
+    /// Link to terms of service in `describeFeedGenerator`
+    pub fn with_terms_of_service(mut self, url: impl Into<String>) -> Self {
+        self.terms_of_service = Some(url.into());
+        self
+    }
+
+    /// The service DID
+    pub fn did(&self) -> &Did {
+        &self.did
+    }
+
+    /// Build the router serving this feed generator
+    pub fn into_router(self) -> Router {
+        let generator = Arc::new(self);
+        let skeleton = generator.clone();
+        let describe = generator.clone();
+
+        XrpcRouter::new()
+            .method::<get_feed_skeleton::Endpoint, _, _>(move |ctx, params, ()| {
+                let generator = skeleton.clone();
+                async move { generator.feed_skeleton(&ctx.headers, params).await }
+            })
+            .method::<describe_feed_generator::Endpoint, _, _>(move |_ctx, (), ()| {
+                let generator = describe.clone();
+                async move { Ok(generator.describe()) }
+            })
+            .merge(
+                Router::new()
+                    .route("/.well-known/did.json", get(did_document))
+                    .with_state(generator),
+            )
+            .into_router()
+    }
+
+    /// Handle a `getFeedSkeleton` request
+    ///
+    /// Of the request `headers`, only `Authorization` is used.
+    pub async fn feed_skeleton(&self, headers: &HeaderMap, mut params: QueryParams) -> Result<Output> {
+        let limit = params.limit.unwrap_or(DEFAULT_LIMIT);
+        if !(1..=MAX_LIMIT).contains(&limit) {
+            return Err(XrpcError::InvalidRequest(format!(
+                "limit must be between 1 and {}",
+                MAX_LIMIT
+            )));
+        }
+        params.limit = Some(limit);
+        params.cursor = params.cursor.filter(|cursor| !cursor.is_empty());
+
+        let algorithm = self.feeds.get(&params.feed.to_string()).ok_or_else(|| XrpcError::Xrpc {
+            error: "UnknownFeed".to_string(),
+            message: format!("Unknown feed: {}", params.feed),
+        })?;
+
+        let requester = self.requester(headers).await?;
+        algorithm.skeleton(params, requester).await
+    }
+
+    /// The `describeFeedGenerator` output
+    pub fn describe(&self) -> describe_feed_generator::Output {
+        let feeds = self
+            .feeds
+            .keys()
+            .map(|uri| serde_json::json!({ "uri": uri }))
+            .collect::<Vec<_>>();
+
+        let mut links = serde_json::Map::new();
+        if let Some(url) = &self.privacy_policy {
+            links.insert("privacyPolicy".to_string(), url.clone().into());
+        }
+        if let Some(url) = &self.terms_of_service {
+            links.insert("termsOfService".to_string(), url.clone().into());
+        }
+
+        describe_feed_generator::Output {
+            links: (!links.is_empty()).then_some(links.into()),
+            feeds,
+            did: self.did.clone(),
+        }
+    }
+
+    /// The DID document of a `did:web` service, advertising the feed generator endpoint
+    pub fn did_document(&self) -> Option<DidDocument> {
+        if !self.did.as_str().starts_with("did:web:") {
+            return None;
+        }
+
+        Some(DidDocument {
+            context: Some(serde_json::json!(["https://www.w3.org/ns/did/v1"])),
+            id: self.did.to_string(),
+            also_known_as: Vec::new(),
+            service: vec![Service {
+                id: "#bsky_fg".to_string(),
+                service_type: "BskyFeedGenerator".to_string(),
+                service_endpoint: self.service_endpoint.clone(),
+            }],
+            verification_method: Vec::new(),
+        })
+    }
+
+    async fn requester(&self, headers: &HeaderMap) -> Result<Option<Did>> {
+        let Some(verifier) = &self.verifier else {
+            return Ok(None);
+        };
+
+        match headers.get(header::AUTHORIZATION) {
+            Some(value) => {
+                let value = value.to_str().map_err(|_| auth_error("Malformed authorization header"))?;
+                let did = verifier
+                    .verify_bearer(value, Some(GET_FEED_SKELETON))
+                    .await
+                    .map_err(|e| auth_error(e.to_string()))?;
+                Ok(Some(did))
+            }
+            None if self.require_auth => Err(auth_error("Authentication required")),
+            None => Ok(None),
+        }
+    }
+}
+
+fn auth_error(message: impl Into<String>) -> XrpcError {
+    XrpcError::Xrpc {
+        error: "AuthenticationRequired".to_string(),
+        message: message.into(),
+    }
+}
+
+async fn did_document(State(generator): State<Arc<FeedGenerator>>) -> Response {
+    match generator.did_document() {
+        Some(doc) => Json(doc).into_response(),
+        None => error_response(StatusCode::NOT_FOUND, "NotFound", "Not a did:web service"),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::crypto::{KeyAlgorithm, Keypair};
+    use crate::service_auth::ServiceJwt;
+
+    const SERVICE_DID: &str = "did:web:feeds.example.com";
+    const FEED_URI: &str = "at://did:plc:alice/app.bsky.feed.generator/numbers";
+
+    /// Pages through post URIs numbered 0..total, with the next index as the cursor
+    struct Numbers {
+        total: i64,
+    }
+
+    #[async_trait]
+    impl FeedAlgorithm for Numbers {
+        async fn skeleton(&self, params: QueryParams, requester: Option<Did>) -> Result<Output> {
+            let start = match params.cursor {
+                Some(cursor) => cursor
+                    .parse::<i64>()
+                    .map_err(|_| XrpcError::InvalidRequest("Malformed cursor".to_string()))?,
+                None => 0,
+            };
+            let end = (start + params.limit.unwrap()).min(self.total);
+            let feed = (start..end)
+                .map(|i| serde_json::json!({ "post": format!("at://did:plc:bob/app.bsky.feed.post/{}", i) }))
+                .collect::<Vec<_>>();
+
+            Ok(Output {
+                feed,
+                cursor: (end < self.total).then(|| end.to_string()),
+                req_id: requester.map(|did| did.to_string()),
+            })
+        }
+    }
+
+    fn generator() -> FeedGenerator {
+        FeedGenerator::new(Did::new(SERVICE_DID).unwrap(), "https://feeds.example.com")
+            .with_feed(AtUri::new(FEED_URI).unwrap(), Numbers { total: 120 })
+    }
+
+    fn params(feed: &str, limit: Option<i64>, cursor: Option<&str>) -> QueryParams {
+        QueryParams {
+            cursor: cursor.map(str::to_string),
+            feed: AtUri::new(feed).unwrap(),
+            limit,
+        }
+    }
+
+    fn bearer(token: &str) -> HeaderMap {
+        let mut headers = HeaderMap::new();
+        headers.insert(header::AUTHORIZATION, format!("Bearer {}", token).parse().unwrap());
+        headers
+    }
+
+    #[tokio::test]
+    async fn test_feed_skeleton_pagination() {
+        let generator = generator();
+        let headers = HeaderMap::new();
+
+        let page = generator.feed_skeleton(&headers, params(FEED_URI, None, None)).await.unwrap();
+        assert_eq!(page.feed.len(), DEFAULT_LIMIT as usize);
+        assert_eq!(page.cursor.as_deref(), Some("50"));
+
+        let page = generator
+            .feed_skeleton(&headers, params(FEED_URI, Some(100), Some("50")))
+            .await
+            .unwrap();
+        assert_eq!(page.feed.len(), 70);
+        assert_eq!(page.feed[0]["post"], "at://did:plc:bob/app.bsky.feed.post/50");
+        assert_eq!(page.cursor, None);
+
+        // An empty cursor is the first page
+        let page = generator
+            .feed_skeleton(&headers, params(FEED_URI, Some(1), Some("")))
+            .await
+            .unwrap();
+        assert_eq!(page.feed[0]["post"], "at://did:plc:bob/app.bsky.feed.post/0");
+    }
+
+    #[tokio::test]
+    async fn test_feed_skeleton_errors() {
+        let generator = generator();
+        let headers = HeaderMap::new();
+
+        for (limit, cursor) in [(Some(0), None), (Some(101), None), (None, Some("garbage"))] {
+            assert!(
+                matches!(
+                    generator.feed_skeleton(&headers, params(FEED_URI, limit, cursor)).await,
+                    Err(XrpcError::InvalidRequest(_))
+                ),
+                "{:?} {:?}",
+                limit,
+                cursor
+            );
+        }
+
+        let unknown = generator
+            .feed_skeleton(&headers, params("at://did:plc:alice/app.bsky.feed.generator/other", None, None))
+            .await;
+        assert!(matches!(unknown, Err(XrpcError::Xrpc { error, .. }) if error == "UnknownFeed"));
+    }
+
+    #[tokio::test]
+    async fn test_feed_skeleton_service_auth() {
+        let keypair = Keypair::generate(KeyAlgorithm::K256);
+        let caller = keypair.did_key();
+        let generator = generator().with_service_auth(DidResolver::with_default_plc());
+        let params = params(FEED_URI, Some(1), None);
+
+        // Anonymous requests are allowed
+        let page = generator.feed_skeleton(&HeaderMap::new(), params.clone()).await.unwrap();
+        assert_eq!(page.req_id, None);
+
+        let token = ServiceJwt::new(&caller, SERVICE_DID)
+            .with_lxm(GET_FEED_SKELETON)
+            .sign(&keypair)
+            .unwrap();
+        let page = generator.feed_skeleton(&bearer(&token), params.clone()).await.unwrap();
+        assert_eq!(page.req_id.as_deref(), Some(caller.as_str()));
+
+        // Wrong audience or method
+        for token in [
+            ServiceJwt::new(&caller, "did:web:other.example.com")
+                .with_lxm(GET_FEED_SKELETON)
+                .sign(&keypair)
+                .unwrap(),
+            ServiceJwt::new(&caller, SERVICE_DID)
+                .with_lxm("app.bsky.feed.getTimeline")
+                .sign(&keypair)
+                .unwrap(),
+        ] {
+            let result = generator.feed_skeleton(&bearer(&token), params.clone()).await;
+            assert!(matches!(result, Err(XrpcError::Xrpc { error, .. }) if error == "AuthenticationRequired"));
+        }
+
+        let generator = generator.with_required_auth();
+        let result = generator.feed_skeleton(&HeaderMap::new(), params.clone()).await;
+        assert!(matches!(result, Err(XrpcError::Xrpc { error, .. }) if error == "AuthenticationRequired"));
+    }
+
+    #[tokio::test]
+    async fn test_router() {
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let url = format!("http://{}", listener.local_addr().unwrap());
+        let router = generator()
+            .with_privacy_policy("https://feeds.example.com/privacy")
+            .into_router();
+        tokio::spawn(async move { axum::serve(listener, router).await });
+
+        let client = reqwest::Client::new();
+
+        let describe: serde_json::Value = client
+            .get(format!("{}/xrpc/{}", url, DESCRIBE_FEED_GENERATOR))
+            .send()
+            .await
+            .unwrap()
+            .json()
+            .await
+            .unwrap();
+        assert_eq!(describe["did"], SERVICE_DID);
+        assert_eq!(describe["feeds"][0]["uri"], FEED_URI);
+        assert_eq!(describe["links"]["privacyPolicy"], "https://feeds.example.com/privacy");
+
+        let response = client
+            .get(format!("{}/xrpc/{}", url, GET_FEED_SKELETON))
+            .query(&[("feed", FEED_URI), ("limit", "2")])
+            .send()
+            .await
+            .unwrap();
+        assert_eq!(response.status(), 200);
+        let page: serde_json::Value = response.json().await.unwrap();
+        assert_eq!(page["feed"].as_array().unwrap().len(), 2);
+        assert_eq!(page["cursor"], "2");
+
+        let response = client
+            .get(format!("{}/xrpc/{}", url, GET_FEED_SKELETON))
+            .query(&[("feed", "at://did:plc:alice/app.bsky.feed.generator/other")])
+            .send()
+            .await
+            .unwrap();
+        assert_eq!(response.status(), 400);
+        let error: serde_json::Value = response.json().await.unwrap();
+        assert_eq!(error["error"], "UnknownFeed");
+
+        let response = client
+            .get(format!("{}/xrpc/{}", url, GET_FEED_SKELETON))
+            .query(&[("feed", "not-a-uri")])
+            .send()
+            .await
+            .unwrap();
+        assert_eq!(response.status(), 400);
+        let error: serde_json::Value = response.json().await.unwrap();
+        assert_eq!(error["error"], "InvalidRequest");
+
+        let doc: DidDocument = client
+            .get(format!("{}/.well-known/did.json", url))
+            .send()
+            .await
+            .unwrap()
+            .json()
+            .await
+            .unwrap();
+        assert_eq!(doc.id, SERVICE_DID);
+        assert_eq!(doc.service[0].id, "#bsky_fg");
+        assert_eq!(doc.service[0].service_endpoint, "https://feeds.example.com");
+    }
+}
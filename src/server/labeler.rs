@@ -0,0 +1,404 @@
+//! Labeler service
+//!
+//! Serves the labels of a [`Labeler`]:
+//!
+//! - `GET /xrpc/com.atproto.label.queryLabels`
+//! - `GET /xrpc/com.atproto.label.subscribeLabels` (WebSocket)
+//!
+//! ## Subscriptions
+//!
+//! Each label is sent as a `#labels` frame carrying its sequence number.
+//! Without a `cursor`, subscribers only receive new labels. With one, labels
+//! after it are replayed from the store first, then the stream continues
+//! live; a cursor ahead of the log is answered with a `FutureCursor` error.
+//! Subscribers that fall too far behind are disconnected with
+//! `ConsumerTooSlow` and can reconnect from their last cursor.
+//!
+//! ## Example
+//!
+//! ```no_run
+//! use atproto::crypto::Keypair;
+//! use atproto::labeler::Labeler;
+//! use atproto::server::LabelerService;
+//! use atproto::types::Did;
+//! use std::sync::Arc;
+//!
+//! # async fn example(keypair: Keypair) -> Result<(), Box<dyn std::error::Error>> {
+//! let labeler = Arc::new(Labeler::new(Did::new("did:plc:labeler")?, keypair));
+//!
+//! let listener = tokio::net::TcpListener::bind("0.0.0.0:3000").await?;
+//! let router = LabelerService::new(labeler.clone()).into_router();
+//! tokio::spawn(async move { axum::serve(listener, router).await });
+//!
+//! // Emitted labels are now served to clients
+//! # Ok(())
+//! # }
+//! ```
+
+use super::{error_response, params, XrpcRouter};
+use crate::client::com::atproto::label::{query_labels, subscribe_labels};
+use crate::label_stream::LabelEvent;
+use crate::labeler::{LabelQuery, Labeler, LabelerError, SequencedLabel};
+use crate::xrpc::{Result, XrpcError};
+use axum::extract::ws::{Message, WebSocket, WebSocketUpgrade};
+use axum::extract::{RawQuery, State};
+use axum::http::StatusCode;
+use axum::response::Response;
+use axum::routing::get;
+use axum::Router;
+use libipld::codec::Codec;
+use libipld::Ipld;
+use std::collections::BTreeMap;
+use std::sync::Arc;
+use tokio::sync::broadcast::error::RecvError;
+
+/// NSID of the subscription endpoint
+pub const SUBSCRIBE_LABELS: &str = "com.atproto.label.subscribeLabels";
+
+/// Page size used when a query has no `limit`
+pub const DEFAULT_QUERY_LIMIT: i64 = 50;
+
+/// Largest page size a query may request
+pub const MAX_QUERY_LIMIT: i64 = 250;
+
+/// Labels read from the store at a time while backfilling a subscriber
+const BACKFILL_BATCH: usize = 500;
+
+/// Serves a labeler's labels over XRPC
+#[derive(Clone)]
+pub struct LabelerService {
+    labeler: Arc<Labeler>,
+}
+
+impl LabelerService {
+    /// Create a service for `labeler`
+    pub fn new(labeler: Arc<Labeler>) -> Self {
+        Self { labeler }
+    }
+
+    /// Build the router serving `queryLabels` and `subscribeLabels`
+    pub fn into_router(self) -> Router {
+        let service = self.clone();
+
+        XrpcRouter::new()
+            .method::<query_labels::Endpoint, _, _>(move |_ctx, params, ()| {
+                let service = service.clone();
+                async move { service.query_labels(params).await }
+            })
+            .merge(
+                Router::new()
+                    .route(&format!("/xrpc/{}", SUBSCRIBE_LABELS), get(subscribe))
+                    .with_state(self.labeler),
+            )
+            .into_router()
+    }
+
+    /// Handle a `queryLabels` request
+    pub async fn query_labels(&self, params: query_labels::QueryParams) -> Result<query_labels::Output> {
+        let limit = params.limit.unwrap_or(DEFAULT_QUERY_LIMIT);
+        if !(1..=MAX_QUERY_LIMIT).contains(&limit) {
+            return Err(XrpcError::InvalidRequest(format!(
+                "limit must be between 1 and {}",
+                MAX_QUERY_LIMIT
+            )));
+        }
+        let cursor = params
+            .cursor
+            .map(|cursor| cursor.parse::<i64>())
+            .transpose()
+            .map_err(|_| XrpcError::InvalidRequest("Malformed cursor".to_string()))?;
+
+        let query = LabelQuery {
+            uri_patterns: params.uri_patterns,
+            sources: params
+                .sources
+                .unwrap_or_default()
+                .iter()
+                .map(|did| did.as_str().to_string())
+                .collect(),
+            cursor,
+            limit: limit as usize,
+        };
+
+        let entries = self.labeler.store().query(&query).await.map_err(internal_error)?;
+        let cursor = match entries.last() {
+            Some(last) if entries.len() == query.limit => Some(last.seq.to_string()),
+            _ => None,
+        };
+        let labels = entries
+            .iter()
+            .map(|entry| serde_json::to_value(&entry.label))
+            .collect::<std::result::Result<Vec<_>, _>>()?;
+
+        Ok(query_labels::Output { labels, cursor })
+    }
+}
+
+fn internal_error(e: LabelerError) -> XrpcError {
+    XrpcError::ServerError {
+        status: 500,
+        message: e.to_string(),
+    }
+}
+
+async fn subscribe(State(labeler): State<Arc<Labeler>>, RawQuery(query): RawQuery, ws: WebSocketUpgrade) -> Response {
+    let params: subscribe_labels::QueryParams = match params::from_query(query.as_deref().unwrap_or("")) {
+        Ok(params) => params,
+        Err(e) => return error_response(StatusCode::BAD_REQUEST, "InvalidRequest", e.to_string()),
+    };
+
+    ws.on_upgrade(move |mut socket| async move {
+        match stream_labels(&mut socket, &labeler, params.cursor).await {
+            Err(StreamError::Closed(error, message)) => {
+                let _ = socket.send(Message::Binary(error_frame(error, &message))).await;
+            }
+            Err(StreamError::Labeler(e)) => {
+                let _ = socket
+                    .send(Message::Binary(error_frame("InternalServerError", &e.to_string())))
+                    .await;
+            }
+            Ok(()) | Err(StreamError::Disconnected) => {}
+        }
+        let _ = socket.close().await;
+    })
+}
+
+/// Why a subscription ended
+enum StreamError {
+    /// The client went away
+    Disconnected,
+    /// The server ends the stream with an error frame
+    Closed(&'static str, String),
+    Labeler(LabelerError),
+}
+
+impl From<LabelerError> for StreamError {
+    fn from(e: LabelerError) -> Self {
+        StreamError::Labeler(e)
+    }
+}
+
+impl From<axum::Error> for StreamError {
+    fn from(_: axum::Error) -> Self {
+        StreamError::Disconnected
+    }
+}
+
+async fn stream_labels(
+    socket: &mut WebSocket,
+    labeler: &Labeler,
+    cursor: Option<i64>,
+) -> std::result::Result<(), StreamError> {
+    // Subscribe before backfilling so nothing emitted in between is missed
+    let mut live = labeler.subscribe();
+    let mut last_seq = 0;
+
+    if let Some(cursor) = cursor {
+        let latest = labeler.store().latest_seq().await?.unwrap_or(0);
+        if cursor > latest {
+            return Err(StreamError::Closed("FutureCursor", "Cursor in the future.".to_string()));
+        }
+
+        last_seq = cursor;
+        loop {
+            let batch = labeler.store().after(last_seq, BACKFILL_BATCH).await?;
+            if batch.is_empty() {
+                break;
+            }
+            for entry in batch {
+                last_seq = entry.seq;
+                socket.send(Message::Binary(labels_frame(entry)?)).await?;
+            }
+        }
+    }
+
+    loop {
+        tokio::select! {
+            event = live.recv() => match event {
+                Ok(entry) if entry.seq <= last_seq => {}
+                Ok(entry) => {
+                    last_seq = entry.seq;
+                    socket.send(Message::Binary(labels_frame(entry)?)).await?;
+                }
+                Err(RecvError::Lagged(_)) => {
+                    return Err(StreamError::Closed(
+                        "ConsumerTooSlow",
+                        "Stream consumer too slow".to_string(),
+                    ));
+                }
+                Err(RecvError::Closed) => return Ok(()),
+            },
+            message = socket.recv() => match message {
+                None | Some(Err(_)) | Some(Ok(Message::Close(_))) => return Err(StreamError::Disconnected),
+                Some(Ok(_)) => {}
+            },
+        }
+    }
+}
+
+/// A `#labels` message frame for one label
+fn labels_frame(entry: SequencedLabel) -> std::result::Result<Vec<u8>, LabelerError> {
+    let event = LabelEvent::Labels {
+        seq: entry.seq,
+        labels: vec![entry.label],
+    };
+    let (message_type, body) = event.encode()?;
+
+    let mut header = BTreeMap::new();
+    header.insert("op".to_string(), Ipld::Integer(1));
+    header.insert("t".to_string(), Ipld::String(message_type));
+
+    let mut frame = encode_cbor(header);
+    frame.extend(body);
+    Ok(frame)
+}
+
+/// An error frame (`op = -1`), which ends the stream
+fn error_frame(error: &str, message: &str) -> Vec<u8> {
+    let mut header = BTreeMap::new();
+    header.insert("op".to_string(), Ipld::Integer(-1));
+
+    let mut body = BTreeMap::new();
+    body.insert("error".to_string(), Ipld::String(error.to_string()));
+    body.insert("message".to_string(), Ipld::String(message.to_string()));
+
+    let mut frame = encode_cbor(header);
+    frame.extend(encode_cbor(body));
+    frame
+}
+
+fn encode_cbor(map: BTreeMap<String, Ipld>) -> Vec<u8> {
+    libipld_cbor::DagCborCodec
+        .encode(&Ipld::Map(map))
+        .expect("maps of strings and integers always encode")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::crypto::{KeyAlgorithm, Keypair};
+    use crate::label_stream::{self, LabelVerifier};
+    use crate::labeler::NewLabel;
+    use crate::types::Did;
+    use crate::xrpc_subscription::SubscriptionClient;
+    use futures::StreamExt;
+
+    const ALICE_POST: &str = "at://did:plc:alice/app.bsky.feed.post/3k2a";
+
+    async fn serve() -> (Arc<Labeler>, String) {
+        let labeler = Arc::new(Labeler::new(
+            Did::new("did:plc:labeler").unwrap(),
+            Keypair::generate(KeyAlgorithm::K256),
+        ));
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let url = format!("http://{}", listener.local_addr().unwrap());
+        let router = LabelerService::new(labeler.clone()).into_router();
+        tokio::spawn(async move { axum::serve(listener, router).await });
+        (labeler, url)
+    }
+
+    #[tokio::test]
+    async fn test_query_labels() {
+        let (labeler, url) = serve().await;
+        labeler.emit(NewLabel::new(ALICE_POST, "spam")).await.unwrap();
+        labeler.emit(NewLabel::new("at://did:plc:alice/app.bsky.feed.post/3k2b", "rude")).await.unwrap();
+        labeler.emit(NewLabel::new("at://did:plc:bob/app.bsky.feed.post/3k2c", "spam")).await.unwrap();
+
+        let client = reqwest::Client::new();
+        let endpoint = format!("{}/xrpc/com.atproto.label.queryLabels", url);
+
+        let output: query_labels::Output = client
+            .get(&endpoint)
+            .query(&[("uriPatterns", "at://did:plc:alice/*"), ("uriPatterns", "at://did:plc:bob/app.bsky.feed.post/3k2c")])
+            .send()
+            .await
+            .unwrap()
+            .json()
+            .await
+            .unwrap();
+        let labels: Vec<crate::client::com::atproto::label::defs::Label> = output
+            .labels
+            .into_iter()
+            .map(|label| serde_json::from_value(label).unwrap())
+            .collect();
+        assert_eq!(labels.len(), 3);
+        assert!(output.cursor.is_none());
+
+        // Labels are served with verifiable signatures
+        let verifier = LabelVerifier::new().with_key("did:plc:labeler", labeler.public_key());
+        for label in &labels {
+            verifier.verify(label).unwrap();
+        }
+
+        let page: query_labels::Output = client
+            .get(&endpoint)
+            .query(&[("uriPatterns", "at://did:plc:alice/*"), ("limit", "1")])
+            .send()
+            .await
+            .unwrap()
+            .json()
+            .await
+            .unwrap();
+        assert_eq!(page.labels[0]["uri"], ALICE_POST);
+        let cursor = page.cursor.unwrap();
+
+        let page: query_labels::Output = client
+            .get(&endpoint)
+            .query(&[("uriPatterns", "at://did:plc:alice/*"), ("limit", "1"), ("cursor", cursor.as_str())])
+            .send()
+            .await
+            .unwrap()
+            .json()
+            .await
+            .unwrap();
+        assert_eq!(page.labels[0]["val"], "rude");
+
+        let response = client
+            .get(&endpoint)
+            .query(&[("uriPatterns", "*"), ("limit", "1000")])
+            .send()
+            .await
+            .unwrap();
+        assert_eq!(response.status(), 400);
+    }
+
+    #[tokio::test]
+    async fn test_subscribe_backfill_then_live() {
+        let (labeler, url) = serve().await;
+        labeler.emit(NewLabel::new(ALICE_POST, "spam")).await.unwrap();
+        labeler.emit(NewLabel::new(ALICE_POST, "rude")).await.unwrap();
+
+        let client = SubscriptionClient::new(url);
+        let verifier = LabelVerifier::new().with_key("did:plc:labeler", labeler.public_key());
+        let events = label_stream::subscribe_labels(&client, subscribe_labels::QueryParams { cursor: Some(1) })
+            .await
+            .unwrap();
+        let mut events = label_stream::verified_label_events(events, verifier);
+
+        let first = events.next().await.unwrap().unwrap();
+        assert_eq!(first.seq(), Some(2));
+
+        labeler.emit(NewLabel::new(ALICE_POST, "spam").negated()).await.unwrap();
+        let LabelEvent::Labels { seq, labels } = events.next().await.unwrap().unwrap() else {
+            panic!("expected labels");
+        };
+        assert_eq!(seq, 3);
+        assert_eq!(labels[0].neg, Some(true));
+    }
+
+    #[tokio::test]
+    async fn test_subscribe_future_cursor() {
+        let (labeler, url) = serve().await;
+        labeler.emit(NewLabel::new(ALICE_POST, "spam")).await.unwrap();
+
+        let client = SubscriptionClient::new(url);
+        let mut events = label_stream::subscribe_labels(&client, subscribe_labels::QueryParams { cursor: Some(5) })
+            .await
+            .unwrap();
+
+        match events.next().await.unwrap() {
+            Err(XrpcError::Xrpc { error, .. }) => assert_eq!(error, "FutureCursor"),
+            other => panic!("expected FutureCursor, got {:?}", other),
+        }
+    }
+}
@@ -0,0 +1,64 @@
+//! XRPC services
+//!
+//! Building blocks for running atproto services over HTTP. This module is only
+//! available with the `server` feature, which pulls in [axum](https://docs.rs/axum).
+//!
+//! - [`router`]: serve any lexicon method with typed handlers via [`XrpcRouter`]
+//! - [`feed_generator`]: serve custom feeds by implementing [`FeedAlgorithm`]
+//! - [`labeler`]: serve a [`Labeler`](crate::labeler::Labeler)'s labels with [`LabelerService`]
+//!
+//! Handlers report failures as [`XrpcError`], which is turned into the standard
+//! XRPC error body:
+//!
+//! ```json
+//! { "error": "InvalidRequest", "message": "..." }
+//! ```
+
+pub mod feed_generator;
+pub mod labeler;
+mod params;
+pub mod router;
+
+pub use feed_generator::{FeedAlgorithm, FeedGenerator};
+pub use labeler::LabelerService;
+pub use router::{XrpcContext, XrpcRouter};
+
+use crate::xrpc::XrpcError;
+use axum::http::StatusCode;
+use axum::response::{IntoResponse, Response};
+use axum::Json;
+
+/// Build an XRPC error response
+pub(crate) fn error_response(status: StatusCode, error: &str, message: impl Into<String>) -> Response {
+    let body = serde_json::json!({
+        "error": error,
+        "message": message.into(),
+    });
+    (status, Json(body)).into_response()
+}
+
+/// Convert a handler error into an XRPC error response
+///
+/// Named errors are client errors, except the authentication errors, which are 401s.
+pub(crate) fn xrpc_error_response(err: XrpcError) -> Response {
+    match err {
+        XrpcError::Xrpc { error, message } => {
+            let status = match error.as_str() {
+                "AuthenticationRequired" | "InvalidToken" | "ExpiredToken" => StatusCode::UNAUTHORIZED,
+                _ => StatusCode::BAD_REQUEST,
+            };
+            error_response(status, &error, message)
+        }
+        XrpcError::InvalidRequest(message) => error_response(StatusCode::BAD_REQUEST, "InvalidRequest", message),
+        XrpcError::ServerError { status, message } => {
+            let status = StatusCode::from_u16(status).unwrap_or(StatusCode::INTERNAL_SERVER_ERROR);
+            let error = if status.is_client_error() {
+                "InvalidRequest"
+            } else {
+                "InternalServerError"
+            };
+            error_response(status, error, message)
+        }
+        other => error_response(StatusCode::INTERNAL_SERVER_ERROR, "InternalServerError", other.to_string()),
+    }
+}
@@ -0,0 +1,184 @@
+//! Decoding of XRPC query parameters
+//!
+//! Query strings carry every value as text, and arrays as repeated keys
+//! (`?uris=a&uris=b`). This deserializer parses numbers and booleans on demand
+//! and collects repeated keys into sequences, so generated `QueryParams`
+//! structs can be decoded directly.
+
+use serde::de::value::{Error, MapDeserializer, SeqDeserializer};
+use serde::de::{self, DeserializeOwned, IntoDeserializer, Visitor};
+use serde::forward_to_deserialize_any;
+
+/// Decode a raw query string into `T`
+pub(crate) fn from_query<T: DeserializeOwned>(query: &str) -> Result<T, Error> {
+    let mut params: Vec<(String, Vec<String>)> = Vec::new();
+    for (key, value) in url::form_urlencoded::parse(query.as_bytes()) {
+        match params.iter_mut().find(|(k, _)| *k == key) {
+            Some((_, values)) => values.push(value.into_owned()),
+            None => params.push((key.into_owned(), vec![value.into_owned()])),
+        }
+    }
+
+    T::deserialize(ParamsDeserializer { params })
+}
+
+struct ParamsDeserializer {
+    params: Vec<(String, Vec<String>)>,
+}
+
+impl<'de> de::Deserializer<'de> for ParamsDeserializer {
+    type Error = Error;
+
+    fn deserialize_any<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Error> {
+        let entries = self.params.into_iter().map(|(key, values)| (key, ParamValue(values)));
+        visitor.visit_map(MapDeserializer::new(entries))
+    }
+
+    /// Methods without parameters ignore the query string
+    fn deserialize_unit<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Error> {
+        visitor.visit_unit()
+    }
+
+    forward_to_deserialize_any! {
+        bool i8 i16 i32 i64 i128 u8 u16 u32 u64 u128 f32 f64 char str string
+        bytes byte_buf option unit_struct newtype_struct seq tuple
+        tuple_struct map struct enum identifier ignored_any
+    }
+}
+
+/// All values given for one key
+struct ParamValue(Vec<String>);
+
+impl ParamValue {
+    fn single(self) -> Result<String, Error> {
+        let mut values = self.0;
+        if values.len() != 1 {
+            return Err(de::Error::custom("expected a single value"));
+        }
+        Ok(values.remove(0))
+    }
+
+    fn parse<T: std::str::FromStr>(self, expected: &str) -> Result<T, Error> {
+        let value = self.single()?;
+        value
+            .parse()
+            .map_err(|_| de::Error::custom(format!("expected {}, found {:?}", expected, value)))
+    }
+}
+
+impl<'de> IntoDeserializer<'de, Error> for ParamValue {
+    type Deserializer = Self;
+
+    fn into_deserializer(self) -> Self {
+        self
+    }
+}
+
+macro_rules! deserialize_parsed {
+    ($($method:ident => $visit:ident: $ty:ty, $expected:literal;)*) => {
+        $(
+            fn $method<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Error> {
+                visitor.$visit(self.parse::<$ty>($expected)?)
+            }
+        )*
+    };
+}
+
+impl<'de> de::Deserializer<'de> for ParamValue {
+    type Error = Error;
+
+    fn deserialize_any<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Error> {
+        if self.0.len() == 1 {
+            visitor.visit_string(self.single()?)
+        } else {
+            self.deserialize_seq(visitor)
+        }
+    }
+
+    deserialize_parsed! {
+        deserialize_bool => visit_bool: bool, "a boolean";
+        deserialize_i8 => visit_i8: i8, "an integer";
+        deserialize_i16 => visit_i16: i16, "an integer";
+        deserialize_i32 => visit_i32: i32, "an integer";
+        deserialize_i64 => visit_i64: i64, "an integer";
+        deserialize_u8 => visit_u8: u8, "an integer";
+        deserialize_u16 => visit_u16: u16, "an integer";
+        deserialize_u32 => visit_u32: u32, "an integer";
+        deserialize_u64 => visit_u64: u64, "an integer";
+        deserialize_f32 => visit_f32: f32, "a number";
+        deserialize_f64 => visit_f64: f64, "a number";
+    }
+
+    fn deserialize_option<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Error> {
+        visitor.visit_some(self)
+    }
+
+    fn deserialize_newtype_struct<V: Visitor<'de>>(self, _name: &'static str, visitor: V) -> Result<V::Value, Error> {
+        visitor.visit_newtype_struct(self)
+    }
+
+    fn deserialize_seq<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Error> {
+        let values = self.0.into_iter().map(|value| ParamValue(vec![value]));
+        visitor.visit_seq(SeqDeserializer::new(values))
+    }
+
+    fn deserialize_enum<V: Visitor<'de>>(
+        self,
+        _name: &'static str,
+        _variants: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value, Error> {
+        visitor.visit_enum(self.single()?.into_deserializer())
+    }
+
+    forward_to_deserialize_any! {
+        i128 u128 char str string bytes byte_buf unit unit_struct tuple
+        tuple_struct map struct identifier ignored_any
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::syntax::AtUri;
+    use crate::types::Did;
+    use serde::Deserialize;
+
+    #[derive(Debug, Deserialize)]
+    struct Params {
+        actor: Did,
+        uri: Option<AtUri>,
+        limit: Option<i64>,
+        reverse: Option<bool>,
+        #[serde(default)]
+        tags: Vec<String>,
+    }
+
+    #[test]
+    fn test_from_query() {
+        let params: Params = from_query(
+            "actor=did%3Aplc%3Aalice&uri=at%3A%2F%2Fdid%3Aplc%3Aalice%2Fapp.bsky.feed.post%2F3k2a&limit=25&reverse=true&tags=a&tags=b",
+        )
+        .unwrap();
+        assert_eq!(params.actor.as_str(), "did:plc:alice");
+        assert_eq!(params.uri.unwrap().rkey(), Some("3k2a"));
+        assert_eq!(params.limit, Some(25));
+        assert_eq!(params.reverse, Some(true));
+        assert_eq!(params.tags, vec!["a", "b"]);
+
+        let params: Params = from_query("actor=did:plc:bob&tags=only&unknown=1").unwrap();
+        assert_eq!(params.uri, None);
+        assert_eq!(params.limit, None);
+        assert_eq!(params.tags, vec!["only"]);
+
+        let _: () = from_query("ignored=1").unwrap();
+    }
+
+    #[test]
+    fn test_from_query_errors() {
+        assert!(from_query::<Params>("limit=10").is_err());
+        assert!(from_query::<Params>("actor=did:plc:bob&limit=ten").is_err());
+        assert!(from_query::<Params>("actor=did:plc:bob&limit=1&limit=2").is_err());
+        assert!(from_query::<Params>("actor=did:plc:bob&uri=https://example.com").is_err());
+    }
+}
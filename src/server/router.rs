@@ -0,0 +1,427 @@
+//! Typed XRPC routing
+//!
+//! [`XrpcRouter`] maps NSIDs to async handlers using the `Endpoint` types
+//! generated for every query and procedure. For each request it:
+//!
+//! - checks the HTTP method (GET for queries, POST for procedures)
+//! - decodes the query string into the method's `QueryParams`
+//! - checks the `Content-Type` against the lexicon input encoding and decodes
+//!   the body (JSON into `Input`, anything else as raw bytes)
+//! - encodes the handler's `Output` with the lexicon output encoding
+//!
+//! Errors use the standard XRPC body. Unregistered methods under `/xrpc/`
+//! answer `501 MethodNotImplemented`.
+//!
+//! ## Example
+//!
+//! ```no_run
+//! use atproto::client::com::atproto::identity::resolve_handle;
+//! use atproto::server::XrpcRouter;
+//! use atproto::types::Did;
+//!
+//! # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+//! let router = XrpcRouter::new().method::<resolve_handle::Endpoint, _, _>(|_ctx, params, ()| async move {
+//!     let did = lookup(params.handle.as_str())?;
+//!     Ok(resolve_handle::Output { did })
+//! });
+//!
+//! let listener = tokio::net::TcpListener::bind("0.0.0.0:3000").await?;
+//! axum::serve(listener, router.into_router()).await?;
+//! # Ok(())
+//! # }
+//! # fn lookup(handle: &str) -> atproto::xrpc::Result<Did> {
+//! #     Did::new(format!("did:web:{}", handle))
+//! #         .map_err(|e| atproto::xrpc::XrpcError::InvalidRequest(e.to_string()))
+//! # }
+//! ```
+
+use super::{error_response, params, xrpc_error_response};
+use crate::xrpc::{Result, XrpcBody, XrpcEndpoint, XrpcError, XrpcMethod};
+use axum::body::Bytes;
+use axum::extract::RawQuery;
+use axum::http::{header, HeaderMap, StatusCode, Uri};
+use axum::response::{IntoResponse, Response};
+use axum::routing::{get, post, MethodRouter};
+use axum::Router;
+use std::future::Future;
+
+/// Request details available to handlers
+#[derive(Debug, Clone)]
+pub struct XrpcContext {
+    /// The request headers
+    pub headers: HeaderMap,
+}
+
+impl XrpcContext {
+    /// The `Authorization` header, if present and valid UTF-8
+    pub fn authorization(&self) -> Option<&str> {
+        self.headers.get(header::AUTHORIZATION)?.to_str().ok()
+    }
+
+    /// The `Content-Type` of the request body
+    pub fn content_type(&self) -> Option<&str> {
+        self.headers.get(header::CONTENT_TYPE)?.to_str().ok()
+    }
+}
+
+/// Router mapping XRPC methods to typed handlers
+#[derive(Default)]
+pub struct XrpcRouter {
+    router: Router,
+}
+
+impl XrpcRouter {
+    /// Create an empty router
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Serve the XRPC method `E` with `handler`
+    ///
+    /// The handler receives the request context, the decoded parameters and
+    /// the decoded input, and returns the output or an [`XrpcError`].
+    pub fn method<E, H, Fut>(mut self, handler: H) -> Self
+    where
+        E: XrpcEndpoint,
+        H: Fn(XrpcContext, E::Params, E::Input) -> Fut + Clone + Send + Sync + 'static,
+        Fut: Future<Output = Result<E::Output>> + Send + 'static,
+    {
+        let route = move |headers: HeaderMap, RawQuery(query): RawQuery, body: Bytes| {
+            let handler = handler.clone();
+            async move { handle::<E, _, _>(handler, headers, query, body).await }
+        };
+
+        let method_router: MethodRouter = match E::METHOD {
+            XrpcMethod::Query => get(route),
+            XrpcMethod::Procedure => post(route),
+        };
+        let method_router = method_router.fallback(|| async {
+            error_response(StatusCode::METHOD_NOT_ALLOWED, "InvalidRequest", "Incorrect HTTP method")
+        });
+
+        self.router = self.router.route(&format!("/xrpc/{}", E::NSID), method_router);
+        self
+    }
+
+    /// Add the routes of an axum router, e.g. for non-XRPC paths
+    pub fn merge(mut self, router: Router) -> Self {
+        self.router = self.router.merge(router);
+        self
+    }
+
+    /// Build the axum router
+    pub fn into_router(self) -> Router {
+        self.router.fallback(|uri: Uri| async move {
+            if uri.path().starts_with("/xrpc/") {
+                error_response(StatusCode::NOT_IMPLEMENTED, "MethodNotImplemented", "Method Not Implemented")
+            } else {
+                error_response(StatusCode::NOT_FOUND, "NotFound", "Not Found")
+            }
+        })
+    }
+}
+
+async fn handle<E, H, Fut>(handler: H, headers: HeaderMap, query: Option<String>, body: Bytes) -> Response
+where
+    E: XrpcEndpoint,
+    H: Fn(XrpcContext, E::Params, E::Input) -> Fut,
+    Fut: Future<Output = Result<E::Output>>,
+{
+    let params: E::Params = match params::from_query(query.as_deref().unwrap_or("")) {
+        Ok(params) => params,
+        Err(e) => return error_response(StatusCode::BAD_REQUEST, "InvalidRequest", e.to_string()),
+    };
+
+    let ctx = XrpcContext { headers };
+    let input = match decode_input::<E>(&ctx, body) {
+        Ok(input) => input,
+        Err(e) => return xrpc_error_response(e),
+    };
+
+    let output = match handler(ctx, params, input).await.and_then(XrpcBody::into_bytes) {
+        Ok(output) => output,
+        Err(e) => return xrpc_error_response(e),
+    };
+
+    match E::OUTPUT_ENCODING {
+        Some(encoding) => {
+            // Wildcard encodings (e.g. getBlob's */*) can't be sent as a Content-Type
+            let content_type = if encoding.contains('*') {
+                "application/octet-stream"
+            } else {
+                encoding
+            };
+            ([(header::CONTENT_TYPE, content_type)], output).into_response()
+        }
+        None => StatusCode::OK.into_response(),
+    }
+}
+
+fn decode_input<E: XrpcEndpoint>(ctx: &XrpcContext, body: Bytes) -> Result<E::Input> {
+    if let Some(expected) = E::INPUT_ENCODING {
+        let content_type = ctx.content_type().ok_or_else(|| {
+            XrpcError::InvalidRequest("Request encoding (Content-Type) required but not provided".to_string())
+        })?;
+        if !encoding_matches(expected, content_type) {
+            return Err(XrpcError::InvalidRequest(format!(
+                "Wrong request encoding (Content-Type): {}",
+                content_type
+            )));
+        }
+    }
+
+    E::Input::from_bytes(body.to_vec()).map_err(|e| XrpcError::InvalidRequest(format!("Invalid input: {}", e)))
+}
+
+/// Whether a Content-Type satisfies a lexicon encoding, which may be a wildcard
+fn encoding_matches(expected: &str, content_type: &str) -> bool {
+    let actual = content_type.split(';').next().unwrap_or("").trim().to_ascii_lowercase();
+    match expected.strip_suffix("/*") {
+        Some("*") => true,
+        Some(prefix) => actual.split('/').next() == Some(prefix),
+        None => actual == expected,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::client::app::bsky::feed::{get_feed_skeleton, get_posts};
+    use crate::client::com::atproto::repo::{create_record, upload_blob};
+    use crate::client::com::atproto::server::describe_server;
+    use crate::client::com::atproto::sync::get_blob;
+    use crate::syntax::AtUri;
+    use crate::types::{CidLink, Did};
+    use crate::xrpc::XrpcClientImpl;
+
+    async fn serve(router: XrpcRouter) -> String {
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let url = format!("http://{}", listener.local_addr().unwrap());
+        tokio::spawn(async move { axum::serve(listener, router.into_router()).await });
+        url
+    }
+
+    fn router() -> XrpcRouter {
+        XrpcRouter::new()
+            .method::<get_feed_skeleton::Endpoint, _, _>(|_ctx, params, ()| async move {
+                if params.feed.rkey() == Some("missing") {
+                    return Err(XrpcError::Xrpc {
+                        error: "UnknownFeed".to_string(),
+                        message: "No such feed".to_string(),
+                    });
+                }
+                Ok(get_feed_skeleton::Output {
+                    feed: Vec::new(),
+                    cursor: params.limit.map(|limit| limit.to_string()),
+                    req_id: None,
+                })
+            })
+            .method::<create_record::Endpoint, _, _>(|ctx, (), input| async move {
+                Ok(create_record::Output {
+                    uri: AtUri::new(format!("at://{}/{}/3k2a", input.repo, input.collection)).unwrap(),
+                    cid: CidLink::new_unchecked("bafyreie5737gdxlw5i64vzichcalba3z2v5n6icifvx5xytvske7mr3hpm"),
+                    commit: None,
+                    validation_status: ctx.authorization().map(str::to_string),
+                })
+            })
+            .method::<upload_blob::Endpoint, _, _>(|ctx, (), input| async move {
+                Ok(upload_blob::Output {
+                    blob: serde_json::json!({
+                        "mimeType": ctx.content_type(),
+                        "size": input.len(),
+                    }),
+                })
+            })
+            .method::<get_posts::Endpoint, _, _>(|_ctx, params, ()| async move {
+                Ok(get_posts::Output {
+                    posts: params.uris.iter().map(|uri| serde_json::json!({ "uri": uri.to_string() })).collect(),
+                })
+            })
+            .method::<get_blob::Endpoint, _, _>(|_ctx, params, ()| async move {
+                Ok(params.cid.as_str().as_bytes().to_vec())
+            })
+    }
+
+    #[test]
+    fn test_encoding_matches() {
+        assert!(encoding_matches("*/*", "image/png"));
+        assert!(encoding_matches("application/json", "application/json; charset=utf-8"));
+        assert!(encoding_matches("image/*", "image/jpeg"));
+        assert!(!encoding_matches("image/*", "video/mp4"));
+        assert!(!encoding_matches("application/json", "text/plain"));
+    }
+
+    #[tokio::test]
+    async fn test_query() {
+        let url = serve(router()).await;
+        let client = reqwest::Client::new();
+        let endpoint = format!("{}/xrpc/app.bsky.feed.getFeedSkeleton", url);
+
+        let response = client
+            .get(&endpoint)
+            .query(&[("feed", "at://did:plc:alice/app.bsky.feed.generator/cats"), ("limit", "10")])
+            .send()
+            .await
+            .unwrap();
+        assert_eq!(response.status(), 200);
+        assert_eq!(response.headers()[header::CONTENT_TYPE], "application/json");
+        let output: get_feed_skeleton::Output = response.json().await.unwrap();
+        assert_eq!(output.cursor.as_deref(), Some("10"));
+
+        let response = client
+            .get(&endpoint)
+            .query(&[("feed", "at://did:plc:alice/app.bsky.feed.generator/missing")])
+            .send()
+            .await
+            .unwrap();
+        assert_eq!(response.status(), 400);
+        let error: serde_json::Value = response.json().await.unwrap();
+        assert_eq!(error, serde_json::json!({ "error": "UnknownFeed", "message": "No such feed" }));
+
+        // Missing and malformed parameters
+        for query in [vec![], vec![("feed", "at://did:plc:alice/app.bsky.feed.generator/cats"), ("limit", "x")]] {
+            let response = client.get(&endpoint).query(&query).send().await.unwrap();
+            assert_eq!(response.status(), 400);
+            let error: serde_json::Value = response.json().await.unwrap();
+            assert_eq!(error["error"], "InvalidRequest");
+        }
+
+        let response = client.post(&endpoint).send().await.unwrap();
+        assert_eq!(response.status(), 405);
+    }
+
+    #[tokio::test]
+    async fn test_procedure() {
+        let url = serve(router()).await;
+        let client = reqwest::Client::new();
+        let endpoint = format!("{}/xrpc/com.atproto.repo.createRecord", url);
+        let input = serde_json::json!({
+            "repo": "did:plc:alice",
+            "collection": "app.bsky.feed.post",
+            "record": { "text": "hello" },
+        });
+
+        let response = client
+            .post(&endpoint)
+            .header(header::AUTHORIZATION, "Bearer token")
+            .json(&input)
+            .send()
+            .await
+            .unwrap();
+        assert_eq!(response.status(), 200);
+        let output: create_record::Output = response.json().await.unwrap();
+        assert_eq!(output.uri.to_string(), "at://did:plc:alice/app.bsky.feed.post/3k2a");
+        assert_eq!(output.validation_status.as_deref(), Some("Bearer token"));
+
+        let response = client
+            .post(&endpoint)
+            .header(header::CONTENT_TYPE, "text/plain")
+            .body(input.to_string())
+            .send()
+            .await
+            .unwrap();
+        assert_eq!(response.status(), 400);
+
+        let response = client
+            .post(&endpoint)
+            .header(header::CONTENT_TYPE, "application/json")
+            .body("{}")
+            .send()
+            .await
+            .unwrap();
+        assert_eq!(response.status(), 400);
+        let error: serde_json::Value = response.json().await.unwrap();
+        assert_eq!(error["error"], "InvalidRequest");
+    }
+
+    #[tokio::test]
+    async fn test_binary_input() {
+        let url = serve(router()).await;
+
+        let response = reqwest::Client::new()
+            .post(format!("{}/xrpc/com.atproto.repo.uploadBlob", url))
+            .header(header::CONTENT_TYPE, "image/png")
+            .body(vec![0x89, b'P', b'N', b'G'])
+            .send()
+            .await
+            .unwrap();
+        assert_eq!(response.status(), 200);
+        let output: upload_blob::Output = response.json().await.unwrap();
+        assert_eq!(output.blob, serde_json::json!({ "mimeType": "image/png", "size": 4 }));
+    }
+
+    #[tokio::test]
+    async fn test_array_params_client() {
+        let url = serve(router()).await;
+        let client = XrpcClientImpl::new(url);
+        let uris = [
+            "at://did:plc:alice/app.bsky.feed.post/3k2a",
+            "at://did:plc:bob/app.bsky.feed.post/3k2b",
+        ];
+
+        let params = get_posts::QueryParams {
+            uris: uris.iter().map(|uri| AtUri::new(*uri).unwrap()).collect(),
+        };
+        let response = get_posts::get_posts(&client, params).await.unwrap();
+        let returned: Vec<_> = response.data.posts.iter().map(|post| post["uri"].as_str().unwrap()).collect();
+        assert_eq!(returned, uris);
+    }
+
+    #[tokio::test]
+    async fn test_binary_client() {
+        let url = serve(router()).await;
+        let client = XrpcClientImpl::new(url);
+
+        let response = upload_blob::upload_blob(&client, vec![0xFF, 0xD8, 0xFF], "image/jpeg").await.unwrap();
+        assert_eq!(response.data.blob, serde_json::json!({ "mimeType": "image/jpeg", "size": 3 }));
+
+        let cid = "bafyreie5737gdxlw5i64vzichcalba3z2v5n6icifvx5xytvske7mr3hpm";
+        let params = get_blob::QueryParams {
+            did: Did::new("did:plc:alice").unwrap(),
+            cid: CidLink::new_unchecked(cid),
+        };
+        let response = get_blob::get_blob(&client, params).await.unwrap();
+        assert_eq!(response.data, cid.as_bytes());
+        assert_eq!(response.headers[header::CONTENT_TYPE], "application/octet-stream");
+    }
+
+    #[tokio::test]
+    async fn test_json_client() {
+        use crate::xrpc::{XrpcClient, XrpcParams};
+
+        #[derive(serde::Deserialize)]
+        struct Skeleton {
+            cursor: Option<String>,
+        }
+
+        let url = serve(router()).await;
+        let client = XrpcClientImpl::new(url);
+
+        let params: XrpcParams = [("feed", "at://did:plc:alice/app.bsky.feed.generator/cats"), ("limit", "10")]
+            .into_iter()
+            .collect();
+        let response = client
+            .query::<Skeleton>("app.bsky.feed.getFeedSkeleton", params)
+            .await
+            .unwrap();
+        assert_eq!(response.data.cursor.as_deref(), Some("10"));
+    }
+
+    #[tokio::test]
+    async fn test_unknown_method() {
+        let url = serve(router()).await;
+        let client = reqwest::Client::new();
+
+        let response = client
+            .get(format!("{}/xrpc/{}", url, <describe_server::Endpoint as XrpcEndpoint>::NSID))
+            .send()
+            .await
+            .unwrap();
+        assert_eq!(response.status(), 501);
+        let error: serde_json::Value = response.json().await.unwrap();
+        assert_eq!(error["error"], "MethodNotImplemented");
+
+        let response = client.get(format!("{}/other", url)).send().await.unwrap();
+        assert_eq!(response.status(), 404);
+    }
+}
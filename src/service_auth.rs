@@ -0,0 +1,464 @@
+//! Inter-service authentication
+//!
+//! Atproto services authenticate to each other with short-lived JWTs signed by
+//! the caller's atproto signing key. A PDS mints them for its users through
+//! `com.atproto.server.getServiceAuth`; feed generators, labelers and other
+//! services verify them from the `Authorization: Bearer` header.
+//!
+//! # Claims
+//!
+//! - `iss`: the caller's DID, optionally with a service fragment (`#atproto_labeler`)
+//! - `aud`: the DID of the service being called
+//! - `lxm`: the XRPC method the token is bound to (optional)
+//! - `exp`: expiry, as a Unix timestamp
+//!
+//! Tokens are signed with ES256K (K-256) or ES256 (P-256).
+//!
+//! # Example
+//!
+//! ```no_run
+//! use atproto::crypto::{KeyAlgorithm, Keypair};
+//! use atproto::did_doc::DidResolver;
+//! use atproto::service_auth::{ServiceAuthVerifier, ServiceJwt};
+//!
+//! # async fn example(keypair: Keypair) -> Result<(), Box<dyn std::error::Error>> {
+//! // Calling another service
+//! let token = ServiceJwt::new("did:plc:caller", "did:web:feed.example.com")
+//!     .with_lxm("app.bsky.feed.getFeedSkeleton")
+//!     .sign(&keypair)?;
+//!
+//! // Receiving a call
+//! let verifier = ServiceAuthVerifier::new("did:web:feed.example.com", DidResolver::with_default_plc());
+//! let caller = verifier
+//!     .verify_bearer(&format!("Bearer {}", token), Some("app.bsky.feed.getFeedSkeleton"))
+//!     .await?;
+//! println!("Request from {}", caller);
+//! # Ok(())
+//! # }
+//! ```
+
+use crate::crypto::{CryptoError, Keypair, PublicKey};
+use crate::did_doc::{DidDocError, DidResolver};
+use crate::types::Did;
+use base64::engine::general_purpose::URL_SAFE_NO_PAD;
+use base64::Engine;
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+
+/// Default token lifetime in seconds
+pub const DEFAULT_EXPIRY_SECS: i64 = 60;
+
+/// Error types for service auth
+#[derive(Debug, Error)]
+pub enum ServiceAuthError {
+    #[error("Malformed token: {0}")]
+    MalformedToken(String),
+
+    #[error("Unsupported JWT algorithm: {0}")]
+    UnsupportedAlgorithm(String),
+
+    #[error("Token has expired")]
+    Expired,
+
+    #[error("Token audience {actual} does not match {expected}")]
+    InvalidAudience { expected: String, actual: String },
+
+    #[error("Token is bound to {actual:?}, not {expected}")]
+    InvalidMethod { expected: String, actual: Option<String> },
+
+    #[error("Invalid issuer: {0}")]
+    InvalidIssuer(String),
+
+    #[error("Token signature is invalid")]
+    InvalidSignature,
+
+    #[error("DID resolution error: {0}")]
+    DidResolution(#[from] DidDocError),
+
+    #[error("Crypto error: {0}")]
+    Crypto(#[from] CryptoError),
+}
+
+/// Result type for service auth
+pub type Result<T> = std::result::Result<T, ServiceAuthError>;
+
+/// JWT header
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct Header {
+    alg: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    typ: Option<String>,
+}
+
+/// Service auth JWT claims
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ServiceAuthClaims {
+    /// Caller DID, optionally with a `#service` fragment
+    pub iss: String,
+
+    /// DID of the service being called
+    pub aud: String,
+
+    /// Expiry (Unix timestamp, seconds)
+    pub exp: i64,
+
+    /// Issued at (Unix timestamp, seconds)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub iat: Option<i64>,
+
+    /// XRPC method the token is bound to
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub lxm: Option<String>,
+
+    /// Unique token identifier
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub jti: Option<String>,
+}
+
+/// Builder for outbound service auth tokens
+#[derive(Debug, Clone)]
+pub struct ServiceJwt {
+    iss: String,
+    aud: String,
+    lxm: Option<String>,
+    expires_in: i64,
+}
+
+impl ServiceJwt {
+    /// Create a token from `iss` to `aud`, valid for [`DEFAULT_EXPIRY_SECS`]
+    pub fn new(iss: impl Into<String>, aud: impl Into<String>) -> Self {
+        Self {
+            iss: iss.into(),
+            aud: aud.into(),
+            lxm: None,
+            expires_in: DEFAULT_EXPIRY_SECS,
+        }
+    }
+
+    /// Bind the token to a single XRPC method
+    pub fn with_lxm(mut self, lxm: impl Into<String>) -> Self {
+        self.lxm = Some(lxm.into());
+        self
+    }
+
+    /// Set the token lifetime in seconds
+    pub fn with_expires_in(mut self, seconds: i64) -> Self {
+        self.expires_in = seconds;
+        self
+    }
+
+    /// Sign the token with the issuer's atproto signing key
+    pub fn sign(self, keypair: &Keypair) -> Result<String> {
+        let now = chrono::Utc::now().timestamp();
+        let claims = ServiceAuthClaims {
+            iss: self.iss,
+            aud: self.aud,
+            exp: now + self.expires_in,
+            iat: Some(now),
+            lxm: self.lxm,
+            jti: Some(random_jti()),
+        };
+        sign_claims(&claims, keypair)
+    }
+}
+
+/// Sign arbitrary claims as a service auth JWT
+pub fn sign_claims(claims: &ServiceAuthClaims, keypair: &Keypair) -> Result<String> {
+    let header = Header {
+        alg: keypair.algorithm().jwt_alg().to_string(),
+        typ: Some("JWT".to_string()),
+    };
+
+    let header_b64 = URL_SAFE_NO_PAD.encode(to_json(&header)?);
+    let claims_b64 = URL_SAFE_NO_PAD.encode(to_json(claims)?);
+    let signing_input = format!("{}.{}", header_b64, claims_b64);
+
+    let signature = keypair.sign(signing_input.as_bytes());
+    Ok(format!("{}.{}", signing_input, URL_SAFE_NO_PAD.encode(signature)))
+}
+
+/// Check a token's signature, audience, method binding and expiry
+///
+/// `lxm` of None accepts tokens bound to any method. The signature is checked
+/// with `key`, which must belong to the token's issuer.
+pub fn verify_token(token: &str, key: &PublicKey, audience: &str, lxm: Option<&str>) -> Result<ServiceAuthClaims> {
+    let parsed = ParsedToken::parse(token)?;
+    parsed.check_claims(audience, lxm)?;
+    parsed.check_signature(key)?;
+    Ok(parsed.claims)
+}
+
+/// Verifies inbound service auth tokens for one service
+///
+/// The issuer's signing key is looked up through its DID document.
+pub struct ServiceAuthVerifier {
+    audience: String,
+    resolver: DidResolver,
+}
+
+impl ServiceAuthVerifier {
+    /// Create a verifier for tokens addressed to `audience` (this service's DID)
+    pub fn new(audience: impl Into<String>, resolver: DidResolver) -> Self {
+        Self {
+            audience: audience.into(),
+            resolver,
+        }
+    }
+
+    /// Verify a token, returning the caller's DID
+    ///
+    /// Claims are checked before the issuer is resolved, so expired or
+    /// misaddressed tokens don't cause network requests.
+    pub async fn verify(&self, token: &str, lxm: Option<&str>) -> Result<Did> {
+        let parsed = ParsedToken::parse(token)?;
+        parsed.check_claims(&self.audience, lxm)?;
+
+        let (did, service) = match parsed.claims.iss.split_once('#') {
+            Some((did, service)) => (did, Some(service)),
+            None => (parsed.claims.iss.as_str(), None),
+        };
+        let caller = Did::new(did).map_err(|_| ServiceAuthError::InvalidIssuer(parsed.claims.iss.clone()))?;
+
+        let doc = self.resolver.resolve(did).await?;
+        // Labelers sign with their label key
+        let method = match service {
+            Some("atproto_labeler") => doc.get_label_key(),
+            _ => doc.get_signing_key(),
+        }
+        .ok_or_else(|| ServiceAuthError::InvalidIssuer(format!("{} has no signing key", did)))?;
+
+        let key = PublicKey::from_verification_method(method)?;
+        parsed.check_signature(&key)?;
+
+        Ok(caller)
+    }
+
+    /// Verify the value of an `Authorization: Bearer <token>` header
+    pub async fn verify_bearer(&self, authorization: &str, lxm: Option<&str>) -> Result<Did> {
+        let token = authorization
+            .strip_prefix("Bearer ")
+            .ok_or_else(|| ServiceAuthError::MalformedToken("Expected a Bearer token".to_string()))?;
+        self.verify(token.trim(), lxm).await
+    }
+}
+
+/// A decoded but not yet verified token
+struct ParsedToken<'a> {
+    header: Header,
+    claims: ServiceAuthClaims,
+    signing_input: &'a str,
+    signature: Vec<u8>,
+}
+
+impl<'a> ParsedToken<'a> {
+    fn parse(token: &'a str) -> Result<Self> {
+        let (signing_input, signature_b64) = token
+            .rsplit_once('.')
+            .ok_or_else(|| ServiceAuthError::MalformedToken("Expected three segments".to_string()))?;
+        let (header_b64, claims_b64) = signing_input
+            .split_once('.')
+            .ok_or_else(|| ServiceAuthError::MalformedToken("Expected three segments".to_string()))?;
+
+        let header: Header = from_json_b64(header_b64)?;
+        let claims: ServiceAuthClaims = from_json_b64(claims_b64)?;
+        let signature = URL_SAFE_NO_PAD
+            .decode(signature_b64)
+            .map_err(|e| ServiceAuthError::MalformedToken(e.to_string()))?;
+
+        // Session and DPoP tokens must never be accepted as service auth
+        if let Some(typ) = &header.typ {
+            if matches!(typ.as_str(), "at+jwt" | "refresh+jwt" | "dpop+jwt") {
+                return Err(ServiceAuthError::MalformedToken(format!("Invalid token type: {}", typ)));
+            }
+        }
+
+        Ok(Self {
+            header,
+            claims,
+            signing_input,
+            signature,
+        })
+    }
+
+    fn check_claims(&self, audience: &str, lxm: Option<&str>) -> Result<()> {
+        if chrono::Utc::now().timestamp() > self.claims.exp {
+            return Err(ServiceAuthError::Expired);
+        }
+
+        if self.claims.aud != audience {
+            return Err(ServiceAuthError::InvalidAudience {
+                expected: audience.to_string(),
+                actual: self.claims.aud.clone(),
+            });
+        }
+
+        if let Some(expected) = lxm {
+            if self.claims.lxm.as_deref() != Some(expected) {
+                return Err(ServiceAuthError::InvalidMethod {
+                    expected: expected.to_string(),
+                    actual: self.claims.lxm.clone(),
+                });
+            }
+        }
+
+        Ok(())
+    }
+
+    fn check_signature(&self, key: &PublicKey) -> Result<()> {
+        if self.header.alg != key.algorithm().jwt_alg() {
+            return Err(ServiceAuthError::UnsupportedAlgorithm(self.header.alg.clone()));
+        }
+
+        key.verify(self.signing_input.as_bytes(), &self.signature)
+            .map_err(|_| ServiceAuthError::InvalidSignature)
+    }
+}
+
+fn to_json<T: Serialize>(value: &T) -> Result<String> {
+    serde_json::to_string(value).map_err(|e| ServiceAuthError::MalformedToken(e.to_string()))
+}
+
+fn from_json_b64<T: serde::de::DeserializeOwned>(segment: &str) -> Result<T> {
+    let bytes = URL_SAFE_NO_PAD
+        .decode(segment)
+        .map_err(|e| ServiceAuthError::MalformedToken(e.to_string()))?;
+    serde_json::from_slice(&bytes).map_err(|e| ServiceAuthError::MalformedToken(e.to_string()))
+}
+
+/// Random 128-bit hex token id
+fn random_jti() -> String {
+    use rand::RngCore;
+    let mut bytes = [0u8; 16];
+    rand::rngs::OsRng.fill_bytes(&mut bytes);
+    bytes.iter().map(|b| format!("{:02x}", b)).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::crypto::KeyAlgorithm;
+
+    const AUD: &str = "did:web:feed.example.com";
+    const LXM: &str = "app.bsky.feed.getFeedSkeleton";
+
+    #[test]
+    fn test_sign_and_verify() {
+        for algorithm in [KeyAlgorithm::K256, KeyAlgorithm::P256] {
+            let keypair = Keypair::generate(algorithm);
+            let token = ServiceJwt::new("did:plc:caller", AUD)
+                .with_lxm(LXM)
+                .sign(&keypair)
+                .unwrap();
+
+            let claims = verify_token(&token, &keypair.public_key(), AUD, Some(LXM)).unwrap();
+            assert_eq!(claims.iss, "did:plc:caller");
+            assert_eq!(claims.lxm.as_deref(), Some(LXM));
+            assert_eq!(claims.exp - claims.iat.unwrap(), DEFAULT_EXPIRY_SECS);
+
+            let header: Header = from_json_b64(token.split('.').next().unwrap()).unwrap();
+            assert_eq!(header.alg, algorithm.jwt_alg());
+        }
+    }
+
+    #[test]
+    fn test_verify_rejects_bad_claims() {
+        let keypair = Keypair::generate(KeyAlgorithm::K256);
+        let key = keypair.public_key();
+        let token = ServiceJwt::new("did:plc:caller", AUD).with_lxm(LXM).sign(&keypair).unwrap();
+
+        assert!(matches!(
+            verify_token(&token, &key, "did:web:other.example.com", Some(LXM)),
+            Err(ServiceAuthError::InvalidAudience { .. })
+        ));
+        assert!(matches!(
+            verify_token(&token, &key, AUD, Some("com.atproto.repo.createRecord")),
+            Err(ServiceAuthError::InvalidMethod { .. })
+        ));
+        // Any method is fine when none is required
+        assert!(verify_token(&token, &key, AUD, None).is_ok());
+
+        let unbound = ServiceJwt::new("did:plc:caller", AUD).sign(&keypair).unwrap();
+        assert!(matches!(
+            verify_token(&unbound, &key, AUD, Some(LXM)),
+            Err(ServiceAuthError::InvalidMethod { actual: None, .. })
+        ));
+
+        let expired = ServiceJwt::new("did:plc:caller", AUD)
+            .with_expires_in(-10)
+            .sign(&keypair)
+            .unwrap();
+        assert!(matches!(verify_token(&expired, &key, AUD, None), Err(ServiceAuthError::Expired)));
+    }
+
+    #[test]
+    fn test_verify_rejects_bad_signatures() {
+        let keypair = Keypair::generate(KeyAlgorithm::K256);
+        let token = ServiceJwt::new("did:plc:caller", AUD).sign(&keypair).unwrap();
+
+        let other = Keypair::generate(KeyAlgorithm::K256);
+        assert!(matches!(
+            verify_token(&token, &other.public_key(), AUD, None),
+            Err(ServiceAuthError::InvalidSignature)
+        ));
+
+        // Claims swapped after signing
+        let forged_claims = ServiceAuthClaims {
+            iss: "did:plc:admin".to_string(),
+            aud: AUD.to_string(),
+            exp: chrono::Utc::now().timestamp() + 60,
+            iat: None,
+            lxm: None,
+            jti: None,
+        };
+        let parts: Vec<&str> = token.split('.').collect();
+        let forged = format!(
+            "{}.{}.{}",
+            parts[0],
+            URL_SAFE_NO_PAD.encode(serde_json::to_string(&forged_claims).unwrap()),
+            parts[2]
+        );
+        assert!(matches!(
+            verify_token(&forged, &keypair.public_key(), AUD, None),
+            Err(ServiceAuthError::InvalidSignature)
+        ));
+
+        // Algorithm must match the key
+        let p256 = Keypair::generate(KeyAlgorithm::P256);
+        assert!(matches!(
+            verify_token(&token, &p256.public_key(), AUD, None),
+            Err(ServiceAuthError::UnsupportedAlgorithm(_))
+        ));
+
+        assert!(matches!(
+            verify_token("not-a-jwt", &keypair.public_key(), AUD, None),
+            Err(ServiceAuthError::MalformedToken(_))
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_verifier_resolves_issuer() {
+        let keypair = Keypair::generate(KeyAlgorithm::P256);
+        let iss = keypair.did_key();
+        let verifier = ServiceAuthVerifier::new(AUD, DidResolver::with_default_plc());
+
+        let token = ServiceJwt::new(iss.clone(), AUD).with_lxm(LXM).sign(&keypair).unwrap();
+        let caller = verifier
+            .verify_bearer(&format!("Bearer {}", token), Some(LXM))
+            .await
+            .unwrap();
+        assert_eq!(caller.as_str(), iss);
+
+        // Token claiming to be from that DID but signed by someone else
+        let impostor = Keypair::generate(KeyAlgorithm::P256);
+        let token = ServiceJwt::new(iss, AUD).sign(&impostor).unwrap();
+        assert!(matches!(
+            verifier.verify(&token, None).await,
+            Err(ServiceAuthError::InvalidSignature)
+        ));
+
+        assert!(matches!(
+            verifier.verify_bearer(&token, None).await,
+            Err(ServiceAuthError::MalformedToken(_))
+        ));
+    }
+}
@@ -56,7 +56,9 @@ pub type Result<T> = std::result::Result<T, SyntaxError>;
 /// let uri = AtUri::new("at://did:plc:abc123/app.bsky.feed.post").unwrap();
 /// assert_eq!(uri.rkey(), None);
 /// ```
-#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+///
+/// AT URIs serialize as strings, as they appear in lexicon data.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct AtUri {
     /// The authority (typically a DID)
     hostname: String,
@@ -290,6 +292,49 @@ impl fmt::Display for AtUri {
     }
 }
 
+impl Serialize for AtUri {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for AtUri {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> std::result::Result<Self, D::Error> {
+        deserializer.deserialize_any(AtUriVisitor)
+    }
+}
+
+/// Accepts the string form, and the component map older versions serialized
+struct AtUriVisitor;
+
+impl<'de> serde::de::Visitor<'de> for AtUriVisitor {
+    type Value = AtUri;
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("an AT URI")
+    }
+
+    fn visit_str<E: serde::de::Error>(self, value: &str) -> std::result::Result<AtUri, E> {
+        AtUri::new(value).map_err(E::custom)
+    }
+
+    fn visit_map<A: serde::de::MapAccess<'de>>(self, map: A) -> std::result::Result<AtUri, A::Error> {
+        #[derive(Deserialize)]
+        struct Components {
+            hostname: String,
+            collection: String,
+            rkey: Option<String>,
+        }
+
+        let components = Components::deserialize(serde::de::value::MapAccessDeserializer::new(map))?;
+        Ok(AtUri {
+            hostname: components.hostname,
+            collection: components.collection,
+            rkey: components.rkey,
+        })
+    }
+}
+
 impl TryFrom<String> for AtUri {
     type Error = SyntaxError;
 
@@ -551,6 +596,16 @@ mod tests {
         assert!(json.contains("123"));
     }
 
+    #[test]
+    fn test_at_uri_string_serde() {
+        let uri = AtUri::new("at://did:plc:abc123/app.bsky.feed.post/123").unwrap();
+        let json = serde_json::to_string(&uri).unwrap();
+        assert_eq!(json, r#""at://did:plc:abc123/app.bsky.feed.post/123""#);
+        assert_eq!(serde_json::from_str::<AtUri>(&json).unwrap(), uri);
+
+        assert!(serde_json::from_str::<AtUri>(r#""https://example.com""#).is_err());
+    }
+
     #[test]
     fn test_at_uri_deserialization() {
         let json = r#"{"hostname":"did:plc:abc123","collection":"app.bsky.feed.post","rkey":"123"}"#;
@@ -0,0 +1,98 @@
+//! Stub network servers shared by unit tests
+
+use std::net::SocketAddr;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+
+/// Start a UDP DNS server answering TXT queries from `zone`
+pub(crate) async fn stub_dns_server(zone: Vec<(&'static str, Vec<&'static str>)>) -> SocketAddr {
+    use hickory_proto::op::{Message, MessageType, ResponseCode};
+    use hickory_proto::rr::rdata::TXT;
+    use hickory_proto::rr::{Name, RData, Record};
+
+    let socket = tokio::net::UdpSocket::bind("127.0.0.1:0").await.unwrap();
+    let addr = socket.local_addr().unwrap();
+
+    tokio::spawn(async move {
+        let mut buf = [0u8; 512];
+        loop {
+            let Ok((len, peer)) = socket.recv_from(&mut buf).await else {
+                return;
+            };
+            let Ok(request) = Message::from_vec(&buf[..len]) else {
+                continue;
+            };
+
+            let mut response = Message::new();
+            response
+                .set_id(request.id())
+                .set_message_type(MessageType::Response)
+                .set_op_code(request.op_code())
+                .set_recursion_desired(request.recursion_desired())
+                .set_recursion_available(true)
+                .add_queries(request.queries().to_vec());
+
+            let query = &request.queries()[0];
+            let name = query.name().to_ascii();
+            let answers: Vec<Record> = zone
+                .iter()
+                .filter(|(zone_name, _)| *zone_name == name.as_str())
+                .flat_map(|(_, records)| records.iter())
+                .map(|value| {
+                    Record::from_rdata(
+                        Name::from_ascii(&name).unwrap(),
+                        300,
+                        RData::TXT(TXT::new(vec![value.to_string()])),
+                    )
+                })
+                .collect();
+
+            if answers.is_empty() {
+                response.set_response_code(ResponseCode::NXDomain);
+            }
+            response.add_answers(answers);
+
+            let bytes = response.to_vec().unwrap();
+            let _ = socket.send_to(&bytes, peer).await;
+        }
+    });
+
+    addr
+}
+
+/// Serve GET requests on `listener`, answering each path in `routes` with
+/// its body and anything else with 404
+///
+/// Returns a counter of the requests received.
+pub(crate) fn stub_http_server(
+    listener: tokio::net::TcpListener,
+    routes: Vec<(String, String)>,
+) -> Arc<AtomicUsize> {
+    use tokio::io::{AsyncReadExt, AsyncWriteExt};
+
+    let hits = Arc::new(AtomicUsize::new(0));
+    let counter = hits.clone();
+
+    tokio::spawn(async move {
+        while let Ok((mut socket, _)) = listener.accept().await {
+            let mut buf = vec![0u8; 4096];
+            let len = socket.read(&mut buf).await.unwrap_or(0);
+            let request = String::from_utf8_lossy(&buf[..len]);
+            let path = request.split_whitespace().nth(1).unwrap_or("");
+            counter.fetch_add(1, Ordering::SeqCst);
+
+            let response = match routes.iter().find(|(route, _)| route == path) {
+                Some((_, body)) => format!(
+                    "HTTP/1.1 200 OK\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+                    body.len(),
+                    body
+                ),
+                None => "HTTP/1.1 404 Not Found\r\nContent-Length: 0\r\nConnection: close\r\n\r\n"
+                    .to_string(),
+            };
+            let _ = socket.write_all(response.as_bytes()).await;
+        }
+    });
+
+    hits
+}
@@ -250,6 +250,19 @@ impl std::str::FromStr for Tid {
     }
 }
 
+impl serde::Serialize for Tid {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&self.0)
+    }
+}
+
+impl<'de> serde::Deserialize<'de> for Tid {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let s = String::deserialize(deserializer)?;
+        Tid::parse_tid(&s).map_err(serde::de::Error::custom)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -391,4 +404,14 @@ mod tests {
         let tid2 = tid1.clone();
         assert_eq!(tid1, tid2);
     }
+
+    #[test]
+    fn test_tid_serde() {
+        let tid = Tid::from_timestamp(1000000, 100).unwrap();
+        let json = serde_json::to_string(&tid).unwrap();
+        assert_eq!(json, format!("\"{}\"", tid));
+        assert_eq!(serde_json::from_str::<Tid>(&json).unwrap(), tid);
+
+        assert!(serde_json::from_str::<Tid>("\"not-a-tid\"").is_err());
+    }
 }
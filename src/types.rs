@@ -2,12 +2,16 @@
 //!
 //! This module contains foundational types used throughout the SDK including:
 //! - DID (Decentralized Identifier) types and validation
+//! - Lexicon string formats (handle, NSID, record key, datetime, language, CID, URI)
 //! - Session management types
 //! - User preferences
 //! - Service type definitions
 
-use serde::{Deserialize, Serialize};
+use lazy_static::lazy_static;
+use regex::Regex;
+use serde::{Deserialize, Deserializer, Serialize};
 use std::fmt;
+use std::str::FromStr;
 use thiserror::Error;
 
 /// Error types for type validation
@@ -18,6 +22,27 @@ pub enum TypeError {
 
     #[error("Invalid DID reference: {0} (must be of the form did:example:alice#service)")]
     InvalidAtprotoProxy(String),
+
+    #[error("Invalid handle: {0}")]
+    InvalidHandle(String),
+
+    #[error("Invalid NSID: {0}")]
+    InvalidNsid(String),
+
+    #[error("Invalid record key: {0}")]
+    InvalidRecordKey(String),
+
+    #[error("Invalid datetime: {0}")]
+    InvalidDatetime(String),
+
+    #[error("Invalid language tag: {0}")]
+    InvalidLanguage(String),
+
+    #[error("Invalid CID: {0}")]
+    InvalidCid(String),
+
+    #[error("Invalid URI: {0}")]
+    InvalidUri(String),
 }
 
 /// Result type for type operations
@@ -213,6 +238,293 @@ pub fn as_atproto_proxy(input: impl AsRef<str>) -> Result<AtprotoProxy> {
     AtprotoProxy::parse(input.as_ref())
 }
 
+/// Defines a string newtype for a lexicon string format
+///
+/// `$parse` checks a string and returns the form to store. The generated
+/// `Deserialize` goes through it, so malformed values fail at the serde
+/// boundary.
+macro_rules! string_format {
+    ($(#[$meta:meta])* $name:ident, $parse:path) => {
+        $(#[$meta])*
+        #[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize)]
+        #[serde(transparent)]
+        pub struct $name(String);
+
+        impl $name {
+            /// Validates and creates a new value
+            pub fn new(input: impl Into<String>) -> Result<Self> {
+                $parse(input.into()).map($name)
+            }
+
+            /// Creates a value without validation (use with caution)
+            pub fn new_unchecked(input: impl Into<String>) -> Self {
+                $name(input.into())
+            }
+
+            pub fn as_str(&self) -> &str {
+                &self.0
+            }
+        }
+
+        impl fmt::Display for $name {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                write!(f, "{}", self.0)
+            }
+        }
+
+        impl AsRef<str> for $name {
+            fn as_ref(&self) -> &str {
+                &self.0
+            }
+        }
+
+        impl FromStr for $name {
+            type Err = TypeError;
+
+            fn from_str(s: &str) -> Result<Self> {
+                $name::new(s)
+            }
+        }
+
+        impl TryFrom<String> for $name {
+            type Error = TypeError;
+
+            fn try_from(value: String) -> Result<Self> {
+                $name::new(value)
+            }
+        }
+
+        impl TryFrom<&str> for $name {
+            type Error = TypeError;
+
+            fn try_from(value: &str) -> Result<Self> {
+                $name::new(value)
+            }
+        }
+
+        impl<'de> Deserialize<'de> for $name {
+            fn deserialize<D: Deserializer<'de>>(deserializer: D) -> std::result::Result<Self, D::Error> {
+                let s = String::deserialize(deserializer)?;
+                $name::new(s).map_err(serde::de::Error::custom)
+            }
+        }
+    };
+}
+
+string_format!(
+    /// Handle, e.g. `alice.bsky.social`
+    ///
+    /// Handles are case-insensitive and stored lowercased.
+    Handle,
+    parse_handle
+);
+
+fn parse_handle(s: String) -> Result<String> {
+    crate::handle::normalize_handle(&s).map_err(|_| TypeError::InvalidHandle(s))
+}
+
+string_format!(
+    /// Namespaced identifier, e.g. `app.bsky.feed.post`
+    Nsid,
+    parse_nsid
+);
+
+fn parse_nsid(s: String) -> Result<String> {
+    if crate::syntax::is_valid_nsid(&s) {
+        Ok(s)
+    } else {
+        Err(TypeError::InvalidNsid(s))
+    }
+}
+
+string_format!(
+    /// Record key, e.g. a TID like `3jui7kd54zh2y` or `self`
+    RecordKey,
+    parse_record_key
+);
+
+fn parse_record_key(s: String) -> Result<String> {
+    if is_valid_record_key(&s) {
+        Ok(s)
+    } else {
+        Err(TypeError::InvalidRecordKey(s))
+    }
+}
+
+/// Validates if a string is a valid record key
+///
+/// Checks:
+/// - Length between 1 and 512 characters
+/// - Only ASCII letters, digits and `.`, `-`, `_`, `:`, `~`
+/// - Not `.` or `..`
+pub fn is_valid_record_key(input: &str) -> bool {
+    (1..=512).contains(&input.len())
+        && input != "."
+        && input != ".."
+        && input
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || matches!(c, '.' | '-' | '_' | ':' | '~'))
+}
+
+string_format!(
+    /// Datetime, e.g. `2024-01-15T10:00:00.000Z`
+    ///
+    /// The original string is kept so values round-trip unchanged.
+    Datetime,
+    parse_datetime
+);
+
+fn parse_datetime(s: String) -> Result<String> {
+    if is_valid_datetime(&s) {
+        Ok(s)
+    } else {
+        Err(TypeError::InvalidDatetime(s))
+    }
+}
+
+/// Validates if a string is a valid lexicon datetime
+///
+/// Checks:
+/// - Parses as RFC 3339, with an uppercase `T` separator
+/// - Has a timezone, which isn't the unknown-offset `-00:00`
+pub fn is_valid_datetime(input: &str) -> bool {
+    input.contains('T')
+        && !input.ends_with("-00:00")
+        && chrono::DateTime::parse_from_rfc3339(input).is_ok()
+}
+
+impl Datetime {
+    /// The current time, with millisecond precision in UTC
+    pub fn now() -> Self {
+        chrono::Utc::now().into()
+    }
+
+    /// Parse into a chrono datetime
+    ///
+    /// Only fails for values built with `new_unchecked`.
+    pub fn as_chrono(&self) -> Result<chrono::DateTime<chrono::FixedOffset>> {
+        chrono::DateTime::parse_from_rfc3339(&self.0).map_err(|_| TypeError::InvalidDatetime(self.0.clone()))
+    }
+}
+
+impl<Tz: chrono::TimeZone> From<chrono::DateTime<Tz>> for Datetime {
+    fn from(datetime: chrono::DateTime<Tz>) -> Self {
+        Datetime(
+            datetime
+                .with_timezone(&chrono::Utc)
+                .to_rfc3339_opts(chrono::SecondsFormat::Millis, true),
+        )
+    }
+}
+
+string_format!(
+    /// BCP 47 language tag, e.g. `en` or `pt-BR`
+    Language,
+    parse_language
+);
+
+fn parse_language(s: String) -> Result<String> {
+    if is_valid_language(&s) {
+        Ok(s)
+    } else {
+        Err(TypeError::InvalidLanguage(s))
+    }
+}
+
+lazy_static! {
+    /// BCP 47 grammar from RFC 5646, matching the atproto reference validator
+    static ref LANGUAGE_REGEX: Regex = Regex::new(concat!(
+        r"(?i)^(?:",
+        // Grandfathered tags
+        r"en-GB-oed|i-ami|i-bnn|i-default|i-enochian|i-hak|i-klingon|i-lux|i-mingo|i-navajo|i-pwn|i-tao|i-tay|i-tsu",
+        r"|sgn-BE-FR|sgn-BE-NL|sgn-CH-DE",
+        r"|art-lojban|cel-gaulish|no-bok|no-nyn|zh-guoyu|zh-hakka|zh-min|zh-min-nan|zh-xiang",
+        // language, optionally with extlang
+        r"|(?:[a-z]{2,3}(?:-[a-z]{3}(?:-[a-z]{3}){0,2})?|[a-z]{4}|[a-z]{5,8})",
+        // script, region, variants, extensions and private use
+        r"(?:-[a-z]{4})?",
+        r"(?:-(?:[a-z]{2}|[0-9]{3}))?",
+        r"(?:-(?:[a-z0-9]{5,8}|[0-9][a-z0-9]{3}))*",
+        r"(?:-[0-9a-wyz](?:-[a-z0-9]{2,8})+)*",
+        r"(?:-x(?:-[a-z0-9]{1,8})+)?",
+        // Private use only
+        r"|x(?:-[a-z0-9]{1,8})+",
+        r")$",
+    ))
+    .unwrap();
+}
+
+/// Validates if a string is a valid language tag
+///
+/// Follows the BCP 47 grammar case-insensitively: a 2-8 letter primary
+/// subtag with optional extlang, script, region, variant, extension and
+/// private use subtags, a private use tag such as `x-private`, or one of the
+/// grandfathered tags like `i-klingon`.
+pub fn is_valid_language(input: &str) -> bool {
+    LANGUAGE_REGEX.is_match(input)
+}
+
+string_format!(
+    /// CID link in its string form, e.g. `bafyreie5737gdxlw5i64vzichcalba3z2v5n6icifvx5xytvske7mr3hpm`
+    CidLink,
+    parse_cid
+);
+
+fn parse_cid(s: String) -> Result<String> {
+    match libipld::Cid::from_str(&s) {
+        Ok(_) => Ok(s),
+        Err(_) => Err(TypeError::InvalidCid(s)),
+    }
+}
+
+impl CidLink {
+    /// Parse into a CID
+    ///
+    /// Only fails for values built with `new_unchecked`.
+    pub fn to_cid(&self) -> Result<libipld::Cid> {
+        libipld::Cid::from_str(&self.0).map_err(|_| TypeError::InvalidCid(self.0.clone()))
+    }
+}
+
+impl From<libipld::Cid> for CidLink {
+    fn from(cid: libipld::Cid) -> Self {
+        CidLink(cid.to_string())
+    }
+}
+
+string_format!(
+    /// Generic URI, e.g. `https://example.com/avatar.jpg`
+    Uri,
+    parse_uri
+);
+
+fn parse_uri(s: String) -> Result<String> {
+    if is_valid_uri(&s) {
+        Ok(s)
+    } else {
+        Err(TypeError::InvalidUri(s))
+    }
+}
+
+/// Validates if a string is a valid URI
+///
+/// Checks:
+/// - Starts with a scheme (a letter, then letters, digits, `+`, `-` or `.`) and `:`
+/// - Has something after the scheme, and no whitespace
+/// - At most 8192 characters
+pub fn is_valid_uri(input: &str) -> bool {
+    let Some((scheme, rest)) = input.split_once(':') else {
+        return false;
+    };
+
+    let scheme_ok = scheme.starts_with(|c: char| c.is_ascii_alphabetic())
+        && scheme
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || matches!(c, '+' | '-' | '.'));
+
+    scheme_ok && !rest.is_empty() && input.len() <= 8192 && !input.chars().any(char::is_whitespace)
+}
+
 /// Events that occur during session lifecycle
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(rename_all = "kebab-case")]
@@ -838,4 +1150,129 @@ mod tests {
         let result = as_did("invalid");
         assert!(result.is_err());
     }
+
+    #[test]
+    fn test_handle_format() {
+        let handle = Handle::new("Alice.Bsky.Social").unwrap();
+        assert_eq!(handle.as_str(), "alice.bsky.social");
+
+        assert!(matches!(Handle::new("invalid..handle"), Err(TypeError::InvalidHandle(_))));
+        assert!(Handle::new("nodots").is_err());
+    }
+
+    #[test]
+    fn test_nsid_format() {
+        assert!(Nsid::new("app.bsky.feed.post").is_ok());
+        assert!(matches!(Nsid::new("not an nsid"), Err(TypeError::InvalidNsid(_))));
+        assert!(Nsid::new("app.bsky..post").is_err());
+    }
+
+    #[test]
+    fn test_record_key_format() {
+        assert!(RecordKey::new("self").is_ok());
+        assert!(RecordKey::new("3jui7kd54zh2y").is_ok());
+        assert!(RecordKey::new("example.com:a~b_c-d").is_ok());
+
+        assert!(RecordKey::new("").is_err());
+        assert!(RecordKey::new(".").is_err());
+        assert!(RecordKey::new("..").is_err());
+        assert!(RecordKey::new("has/slash").is_err());
+        assert!(RecordKey::new("a".repeat(513)).is_err());
+    }
+
+    #[test]
+    fn test_datetime_format() {
+        assert!(Datetime::new("2024-01-15T10:00:00.000Z").is_ok());
+        assert!(Datetime::new("2024-01-15T10:00:00+05:30").is_ok());
+
+        assert!(Datetime::new("2024-01-15").is_err());
+        assert!(Datetime::new("2024-01-15 10:00:00Z").is_err());
+        assert!(Datetime::new("2024-01-15T10:00:00").is_err());
+        assert!(Datetime::new("2024-01-15T10:00:00-00:00").is_err());
+
+        let now = Datetime::now();
+        assert!(now.as_str().ends_with('Z'));
+        assert_eq!(Datetime::new(now.as_str()).unwrap(), now);
+        assert_eq!(Datetime::from(now.as_chrono().unwrap()), now);
+
+        let unchecked = Datetime::new_unchecked("yesterday");
+        assert!(matches!(unchecked.as_chrono(), Err(TypeError::InvalidDatetime(_))));
+    }
+
+    #[test]
+    fn test_language_format() {
+        assert!(Language::new("en").is_ok());
+        assert!(Language::new("pt-BR").is_ok());
+        assert!(Language::new("i-klingon").is_ok());
+        assert!(Language::new("zh-Hant-TW").is_ok());
+
+        assert!(Language::new("EN").is_ok());
+        assert!(Language::new("english").is_ok());
+        assert!(Language::new("x-private").is_ok());
+        assert!(Language::new("zh-yue-HK").is_ok());
+        assert!(Language::new("sl-rozaj-biske").is_ok());
+        assert!(Language::new("de-DE-u-co-phonebk").is_ok());
+        assert!(Language::new("en-US-x-twain").is_ok());
+        assert!(Language::new("es-419").is_ok());
+        assert!(Language::new("art-lojban").is_ok());
+        assert!(Language::new("EN-gb-OED").is_ok());
+
+        assert!(Language::new("").is_err());
+        assert!(Language::new("e").is_err());
+        assert!(Language::new("en-").is_err());
+        assert!(Language::new("toolonglang").is_err());
+        assert!(Language::new("en_US").is_err());
+        assert!(Language::new("x").is_err());
+        assert!(Language::new("en-a").is_err());
+    }
+
+    #[test]
+    fn test_cid_format() {
+        let cid = CidLink::new("bafyreie5737gdxlw5i64vzichcalba3z2v5n6icifvx5xytvske7mr3hpm").unwrap();
+        assert_eq!(CidLink::from(cid.to_cid().unwrap()), cid);
+
+        assert!(matches!(CidLink::new("bafynotacid"), Err(TypeError::InvalidCid(_))));
+        assert!(matches!(CidLink::new_unchecked("bafynotacid").to_cid(), Err(TypeError::InvalidCid(_))));
+    }
+
+    #[test]
+    fn test_uri_format() {
+        assert!(Uri::new("https://example.com/avatar.jpg").is_ok());
+        assert!(Uri::new("at://did:plc:test/app.bsky.feed.post/3jui7kd54zh2y").is_ok());
+        assert!(Uri::new("did:plc:test").is_ok());
+
+        assert!(Uri::new("example.com").is_err());
+        assert!(Uri::new("https:").is_err());
+        assert!(Uri::new("1http://example.com").is_err());
+        assert!(Uri::new("https://example.com/has space").is_err());
+    }
+
+    #[test]
+    fn test_string_format_serde() {
+        let nsid: Nsid = serde_json::from_str("\"app.bsky.feed.post\"").unwrap();
+        assert_eq!(serde_json::to_string(&nsid).unwrap(), "\"app.bsky.feed.post\"");
+
+        let handle: Handle = serde_json::from_str("\"Alice.Test\"").unwrap();
+        assert_eq!(handle.as_str(), "alice.test");
+
+        let err = serde_json::from_str::<Datetime>("\"yesterday\"").unwrap_err();
+        assert!(err.to_string().contains("Invalid datetime"));
+    }
+
+    #[test]
+    fn test_generated_struct_rejects_bad_formats() {
+        use crate::client::com::atproto::repo::strong_ref::Main as StrongRef;
+
+        let valid = serde_json::json!({
+            "uri": "at://did:plc:test/app.bsky.feed.post/3jui7kd54zh2y",
+            "cid": "bafyreie5737gdxlw5i64vzichcalba3z2v5n6icifvx5xytvske7mr3hpm"
+        });
+        assert!(serde_json::from_value::<StrongRef>(valid).is_ok());
+
+        let invalid = serde_json::json!({
+            "uri": "at://did:plc:test/app.bsky.feed.post/3jui7kd54zh2y",
+            "cid": "not-a-cid"
+        });
+        assert!(serde_json::from_value::<StrongRef>(invalid).is_err());
+    }
 }
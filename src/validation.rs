@@ -5,18 +5,19 @@
 //!
 //! - Required fields
 //! - String length constraints (minLength, maxLength)
-//! - String grapheme constraints (maxGraphemes)
+//! - String grapheme constraints (minGraphemes, maxGraphemes)
 //! - Format validation (did, handle, uri, datetime, etc.)
 //! - Numeric constraints (minimum, maximum)
-//! - Enum constraints (knownValues)
+//! - Enum constraints (knownValues, enum)
+//! - Const constraints (const)
 //! - Array constraints (minLength, maxLength)
-//! - Reference type validation
-//! - Union type validation
+//! - Reference type validation, across lexicons with a [`LexiconRegistry`]
+//! - Union type validation (by `$type`)
 //!
 //! # Example
 //!
 //! ```no_run
-//! use atproto::validation::{Validator, LexiconSchema};
+//! use atproto::validation::LexiconSchema;
 //! use serde_json::json;
 //!
 //! // Load schema
@@ -134,6 +135,25 @@ pub enum ValidationError {
 
     #[error("Invalid $type: expected {expected}, got {actual}")]
     InvalidTypeProperty { expected: String, actual: String },
+
+    #[error(
+        "Too few graphemes: '{field}' must be at least {min} graphemes, got {actual}"
+    )]
+    TooFewGraphemes {
+        field: String,
+        min: usize,
+        actual: usize,
+    },
+
+    #[error("Invalid const value: '{field}' must be {expected}, got {actual}")]
+    InvalidConstValue {
+        field: String,
+        expected: String,
+        actual: String,
+    },
+
+    #[error("Lexicon not found: {0}")]
+    LexiconNotFound(String),
 }
 
 /// Result type for validation
@@ -149,6 +169,7 @@ pub enum StringFormat {
     AtUri,
     Nsid,
     RecordKey,
+    Tid,
     Cid,
     Datetime,
     AtIdentifier,
@@ -161,28 +182,21 @@ impl StringFormat {
         match self {
             StringFormat::Did => Did::new(value).is_ok(),
             StringFormat::Handle => crate::handle::is_valid_handle(value),
-            StringFormat::Uri | StringFormat::AtUri => {
+            StringFormat::Uri => crate::types::is_valid_uri(value),
+            StringFormat::AtUri => {
                 // Basic URI validation
                 value.contains(':')
             }
             StringFormat::Nsid => is_valid_nsid(value),
-            StringFormat::RecordKey => {
-                // Record keys are typically TIDs or custom strings
-                !value.is_empty() && value.len() <= 512
-            }
+            StringFormat::RecordKey => crate::types::is_valid_record_key(value),
+            StringFormat::Tid => crate::tid::Tid::is_valid(value),
             StringFormat::Cid => Cid::from_str(value).is_ok(),
-            StringFormat::Datetime => {
-                // Basic ISO 8601 datetime validation
-                chrono::DateTime::parse_from_rfc3339(value).is_ok()
-            }
+            StringFormat::Datetime => crate::types::is_valid_datetime(value),
             StringFormat::AtIdentifier => {
                 // Can be either DID or handle
                 Did::new(value).is_ok() || crate::handle::is_valid_handle(value)
             }
-            StringFormat::Language => {
-                // BCP 47 language tag validation (simplified)
-                !value.is_empty() && value.len() <= 16
-            }
+            StringFormat::Language => crate::types::is_valid_language(value),
         }
     }
 }
@@ -196,12 +210,18 @@ pub enum LexiconType {
         min_length: Option<usize>,
         #[serde(rename = "maxLength", skip_serializing_if = "Option::is_none")]
         max_length: Option<usize>,
+        #[serde(rename = "minGraphemes", skip_serializing_if = "Option::is_none")]
+        min_graphemes: Option<usize>,
         #[serde(rename = "maxGraphemes", skip_serializing_if = "Option::is_none")]
         max_graphemes: Option<usize>,
         #[serde(skip_serializing_if = "Option::is_none")]
         format: Option<StringFormat>,
         #[serde(rename = "knownValues", skip_serializing_if = "Option::is_none")]
         known_values: Option<Vec<String>>,
+        #[serde(rename = "enum", skip_serializing_if = "Option::is_none")]
+        enum_values: Option<Vec<String>>,
+        #[serde(rename = "const", skip_serializing_if = "Option::is_none")]
+        const_value: Option<String>,
         #[serde(skip_serializing_if = "Option::is_none")]
         default: Option<String>,
     },
@@ -210,10 +230,16 @@ pub enum LexiconType {
         minimum: Option<i64>,
         #[serde(skip_serializing_if = "Option::is_none")]
         maximum: Option<i64>,
+        #[serde(rename = "enum", skip_serializing_if = "Option::is_none")]
+        enum_values: Option<Vec<i64>>,
+        #[serde(rename = "const", skip_serializing_if = "Option::is_none")]
+        const_value: Option<i64>,
         #[serde(skip_serializing_if = "Option::is_none")]
         default: Option<i64>,
     },
     Boolean {
+        #[serde(rename = "const", skip_serializing_if = "Option::is_none")]
+        const_value: Option<bool>,
         #[serde(skip_serializing_if = "Option::is_none")]
         default: Option<bool>,
     },
@@ -238,38 +264,70 @@ pub enum LexiconType {
     },
     Union {
         refs: Vec<String>,
+        /// Closed unions reject `$type`s not listed in `refs`
+        #[serde(skip_serializing_if = "Option::is_none")]
+        closed: Option<bool>,
     },
     Unknown,
+    Token,
+    Bytes {
+        #[serde(rename = "minLength", skip_serializing_if = "Option::is_none")]
+        min_length: Option<usize>,
+        #[serde(rename = "maxLength", skip_serializing_if = "Option::is_none")]
+        max_length: Option<usize>,
+    },
+    #[serde(rename = "cid-link")]
+    CidLink,
     Blob {
         #[serde(skip_serializing_if = "Option::is_none")]
         accept: Option<Vec<String>>,
         #[serde(rename = "maxSize", skip_serializing_if = "Option::is_none")]
         max_size: Option<usize>,
     },
+    Params {
+        #[serde(skip_serializing_if = "Option::is_none")]
+        required: Option<Vec<String>>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        properties: Option<HashMap<String, LexiconType>>,
+    },
     Record {
         key: String,
         record: Box<LexiconType>,
     },
+    Query {
+        #[serde(skip_serializing_if = "Option::is_none")]
+        parameters: Option<Box<LexiconType>>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        output: Option<Box<LexiconOutput>>,
+    },
     Procedure {
+        #[serde(skip_serializing_if = "Option::is_none")]
+        parameters: Option<Box<LexiconType>>,
         #[serde(skip_serializing_if = "Option::is_none")]
         input: Option<Box<LexiconInput>>,
         #[serde(skip_serializing_if = "Option::is_none")]
         output: Option<Box<LexiconOutput>>,
     },
+    Subscription {
+        #[serde(skip_serializing_if = "Option::is_none")]
+        parameters: Option<Box<LexiconType>>,
+    },
 }
 
 /// Lexicon input definition
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct LexiconInput {
     pub encoding: String,
-    pub schema: LexiconType,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub schema: Option<LexiconType>,
 }
 
 /// Lexicon output definition
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct LexiconOutput {
     pub encoding: String,
-    pub schema: LexiconType,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub schema: Option<LexiconType>,
 }
 
 /// Lexicon definition
@@ -304,6 +362,9 @@ impl LexiconSchema {
     }
 
     /// Validate a value against this schema
+    ///
+    /// Refs to other lexicons can't be resolved from a single schema and fail
+    /// with [`ValidationError::ReferenceNotFound`]; use a [`LexiconRegistry`] for those.
     pub fn validate(&self, value: &Value) -> ValidationResult<()> {
         // Check for $type property
         if let Some(type_prop) = value.get("$type") {
@@ -330,14 +391,154 @@ impl LexiconSchema {
 
         // Validate against main definition
         if let Some(main_def) = self.get_def("main") {
-            self.validate_type(&main_def.def_type, value, "$root")
+            Scope { schema: self, registry: None }.validate_type(&main_def.def_type, value, "$root")
         } else {
             Err(ValidationError::SchemaError(
                 "No main definition found".to_string(),
             ))
         }
     }
+}
+
+/// A set of lexicon schemas that can reference each other
+///
+/// Refs and union members may point into any schema in the registry, e.g.
+/// `app.bsky.embed.images#image` from within `app.bsky.feed.post`.
+///
+/// # Example
+///
+/// ```
+/// use atproto::validation::LexiconRegistry;
+/// use serde_json::json;
+///
+/// let registry = LexiconRegistry::bundled();
+///
+/// let post = json!({
+///     "$type": "app.bsky.feed.post",
+///     "text": "Hello world!",
+///     "createdAt": "2025-01-15T10:00:00.000Z"
+/// });
+/// assert!(registry.validate_record("app.bsky.feed.post", &post).is_ok());
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct LexiconRegistry {
+    schemas: HashMap<String, LexiconSchema>,
+}
+
+lazy_static::lazy_static! {
+    static ref BUNDLED: LexiconRegistry = {
+        let mut registry = LexiconRegistry::new();
+        for json in crate::client::lexicons::LEXICONS {
+            registry.add_json(json).expect("bundled lexicons are valid");
+        }
+        registry
+    };
+}
+
+impl LexiconRegistry {
+    /// Create an empty registry
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// The lexicons bundled with this crate (the ones `client` is generated from)
+    ///
+    /// Parsed on first use.
+    pub fn bundled() -> &'static LexiconRegistry {
+        &BUNDLED
+    }
+
+    /// Add a schema, replacing any existing schema with the same id
+    pub fn add(&mut self, schema: LexiconSchema) {
+        self.schemas.insert(schema.id.clone(), schema);
+    }
+
+    /// Parse a schema from JSON and add it
+    pub fn add_json(&mut self, json: &str) -> ValidationResult<()> {
+        self.add(LexiconSchema::from_json(json)?);
+        Ok(())
+    }
+
+    /// Get a schema by NSID
+    pub fn get(&self, nsid: &str) -> Option<&LexiconSchema> {
+        self.schemas.get(nsid)
+    }
+
+    /// Number of schemas in the registry
+    pub fn len(&self) -> usize {
+        self.schemas.len()
+    }
+
+    /// Whether the registry has no schemas
+    pub fn is_empty(&self) -> bool {
+        self.schemas.is_empty()
+    }
+
+    /// Validate a value against a definition, e.g. `app.bsky.feed.defs#postView`
+    ///
+    /// A bare NSID refers to the `main` definition.
+    pub fn validate(&self, reference: &str, value: &Value) -> ValidationResult<()> {
+        let (nsid, _) = split_ref(reference);
+        let schema = self
+            .get(nsid)
+            .ok_or_else(|| ValidationError::LexiconNotFound(nsid.to_string()))?;
+
+        Scope { schema, registry: Some(self) }.validate_ref(reference, value, "$root")
+    }
+
+    /// Validate a record for `collection`, as sent to `createRecord` or `putRecord`
+    ///
+    /// The record's `$type`, if present, must match the collection.
+    pub fn validate_record(&self, collection: &str, record: &Value) -> ValidationResult<()> {
+        let schema = self
+            .get(collection)
+            .ok_or_else(|| ValidationError::LexiconNotFound(collection.to_string()))?;
+
+        let record_def = match schema.get_def("main") {
+            Some(def @ LexiconDef { def_type: LexiconType::Record { .. }, .. }) => def,
+            _ => {
+                return Err(ValidationError::SchemaError(format!(
+                    "{} is not a record type",
+                    collection
+                )))
+            }
+        };
 
+        if let Some(type_prop) = record.get("$type") {
+            let type_str = type_prop
+                .as_str()
+                .ok_or(ValidationError::MissingTypeProperty)?;
+            if type_str != collection {
+                return Err(ValidationError::InvalidTypeProperty {
+                    expected: collection.to_string(),
+                    actual: type_str.to_string(),
+                });
+            }
+        }
+
+        Scope { schema, registry: Some(self) }.validate_type(&record_def.def_type, record, "$root")
+    }
+}
+
+/// Split a ref (`lex:nsid#def`, `nsid#def`, `nsid` or `#def`) into NSID and def name
+///
+/// The NSID is empty for local refs; a missing def name means `main`.
+fn split_ref(ref_path: &str) -> (&str, &str) {
+    let ref_path = ref_path.strip_prefix("lex:").unwrap_or(ref_path);
+    match ref_path.split_once('#') {
+        Some((nsid, def)) => (nsid, def),
+        None => (ref_path, "main"),
+    }
+}
+
+/// The schema that local refs resolve against, and the registry for the rest
+#[derive(Clone, Copy)]
+struct Scope<'a> {
+    schema: &'a LexiconSchema,
+    registry: Option<&'a LexiconRegistry>,
+}
+
+impl<'a> Scope<'a> {
     /// Validate a value against a specific type
     fn validate_type(
         &self,
@@ -349,9 +550,12 @@ impl LexiconSchema {
             LexiconType::String {
                 min_length,
                 max_length,
+                min_graphemes,
                 max_graphemes,
                 format,
                 known_values,
+                enum_values,
+                const_value,
                 ..
             } => {
                 let s = value.as_str().ok_or(ValidationError::InvalidType {
@@ -382,14 +586,27 @@ impl LexiconSchema {
                 }
 
                 // Check grapheme constraints
-                if let Some(max) = max_graphemes {
+                if min_graphemes.is_some() || max_graphemes.is_some() {
                     let grapheme_count = UnicodeString::from(s.to_string()).grapheme_len();
-                    if grapheme_count > *max {
-                        return Err(ValidationError::TooManyGraphemes {
-                            field: field_path.to_string(),
-                            max: *max,
-                            actual: grapheme_count,
-                        });
+
+                    if let Some(min) = min_graphemes {
+                        if grapheme_count < *min {
+                            return Err(ValidationError::TooFewGraphemes {
+                                field: field_path.to_string(),
+                                min: *min,
+                                actual: grapheme_count,
+                            });
+                        }
+                    }
+
+                    if let Some(max) = max_graphemes {
+                        if grapheme_count > *max {
+                            return Err(ValidationError::TooManyGraphemes {
+                                field: field_path.to_string(),
+                                max: *max,
+                                actual: grapheme_count,
+                            });
+                        }
                     }
                 }
 
@@ -405,8 +622,8 @@ impl LexiconSchema {
                 }
 
                 // Check enum values
-                if let Some(allowed) = known_values {
-                    if !allowed.contains(&s.to_string()) {
+                for allowed in [known_values, enum_values].into_iter().flatten() {
+                    if !allowed.iter().any(|v| v == s) {
                         return Err(ValidationError::InvalidEnumValue {
                             field: field_path.to_string(),
                             allowed: allowed.clone(),
@@ -415,11 +632,25 @@ impl LexiconSchema {
                     }
                 }
 
+                if let Some(expected) = const_value {
+                    if s != expected {
+                        return Err(ValidationError::InvalidConstValue {
+                            field: field_path.to_string(),
+                            expected: expected.clone(),
+                            actual: s.to_string(),
+                        });
+                    }
+                }
+
                 Ok(())
             }
 
             LexiconType::Integer {
-                minimum, maximum, ..
+                minimum,
+                maximum,
+                enum_values,
+                const_value,
+                ..
             } => {
                 let num = value.as_i64().ok_or(ValidationError::InvalidType {
                     field: field_path.to_string(),
@@ -447,15 +678,46 @@ impl LexiconSchema {
                     }
                 }
 
+                if let Some(allowed) = enum_values {
+                    if !allowed.contains(&num) {
+                        return Err(ValidationError::InvalidEnumValue {
+                            field: field_path.to_string(),
+                            allowed: allowed.iter().map(i64::to_string).collect(),
+                            value: num.to_string(),
+                        });
+                    }
+                }
+
+                if let Some(expected) = const_value {
+                    if num != *expected {
+                        return Err(ValidationError::InvalidConstValue {
+                            field: field_path.to_string(),
+                            expected: expected.to_string(),
+                            actual: num.to_string(),
+                        });
+                    }
+                }
+
                 Ok(())
             }
 
-            LexiconType::Boolean { .. } => {
-                value.as_bool().ok_or(ValidationError::InvalidType {
+            LexiconType::Boolean { const_value, .. } => {
+                let b = value.as_bool().ok_or(ValidationError::InvalidType {
                     field: field_path.to_string(),
                     expected: "boolean".to_string(),
                     actual: format!("{:?}", value),
                 })?;
+
+                if let Some(expected) = const_value {
+                    if b != *expected {
+                        return Err(ValidationError::InvalidConstValue {
+                            field: field_path.to_string(),
+                            expected: expected.to_string(),
+                            actual: b.to_string(),
+                        });
+                    }
+                }
+
                 Ok(())
             }
 
@@ -503,70 +765,49 @@ impl LexiconSchema {
                 required,
                 nullable,
                 properties,
-            } => {
-                let obj = value.as_object().ok_or(ValidationError::InvalidType {
-                    field: field_path.to_string(),
-                    expected: "object".to_string(),
-                    actual: format!("{:?}", value),
-                })?;
-
-                // Check required fields
-                if let Some(req_fields) = required {
-                    for field_name in req_fields {
-                        if !obj.contains_key(field_name) {
-                            return Err(ValidationError::MissingRequiredField(
-                                format!("{}.{}", field_path, field_name),
-                            ));
-                        }
-                    }
-                }
-
-                // Validate properties
-                if let Some(props) = properties {
-                    for (prop_name, prop_type) in props {
-                        if let Some(prop_value) = obj.get(prop_name) {
-                            // Check if null is allowed
-                            if prop_value.is_null() {
-                                if let Some(nullable_fields) = nullable {
-                                    if !nullable_fields.contains(prop_name) {
-                                        return Err(ValidationError::InvalidType {
-                                            field: format!("{}.{}", field_path, prop_name),
-                                            expected: "non-null value".to_string(),
-                                            actual: "null".to_string(),
-                                        });
-                                    }
-                                }
-                                continue;
-                            }
-
-                            let prop_path = if field_path == "$root" {
-                                prop_name.clone()
-                            } else {
-                                format!("{}.{}", field_path, prop_name)
-                            };
-                            self.validate_type(prop_type, prop_value, &prop_path)?;
-                        }
-                    }
-                }
+            } => self.validate_object(required, nullable, properties, value, field_path),
 
-                Ok(())
-            }
+            LexiconType::Params {
+                required,
+                properties,
+            } => self.validate_object(required, &None, properties, value, field_path),
 
             LexiconType::Ref { ref_path } => {
                 // Resolve reference
                 self.validate_ref(ref_path, value, field_path)
             }
 
-            LexiconType::Union { refs } => {
-                // Try each union member - at least one must succeed
+            LexiconType::Union { refs, closed } => {
+                if !value.is_object() {
+                    return Err(ValidationError::InvalidType {
+                        field: field_path.to_string(),
+                        expected: "object".to_string(),
+                        actual: format!("{:?}", value),
+                    });
+                }
+                let type_str = value
+                    .get("$type")
+                    .and_then(Value::as_str)
+                    .ok_or(ValidationError::MissingTypeProperty)?;
+
+                // The member is picked by $type
+                let (type_nsid, type_def) = split_ref(type_str);
                 for ref_path in refs {
-                    if self.validate_ref(ref_path, value, field_path).is_ok() {
-                        return Ok(());
+                    let (nsid, def) = split_ref(ref_path);
+                    let nsid = if nsid.is_empty() { self.schema.id.as_str() } else { nsid };
+                    if nsid == type_nsid && def == type_def {
+                        return self.validate_ref(ref_path, value, field_path);
                     }
                 }
-                Err(ValidationError::UnionValidationFailed {
-                    field: field_path.to_string(),
-                })
+
+                // Open unions may contain types this lexicon doesn't know about
+                if closed.unwrap_or(false) {
+                    Err(ValidationError::UnionValidationFailed {
+                        field: field_path.to_string(),
+                    })
+                } else {
+                    Ok(())
+                }
             }
 
             LexiconType::Unknown => {
@@ -574,6 +815,61 @@ impl LexiconSchema {
                 Ok(())
             }
 
+            LexiconType::Token => Err(ValidationError::SchemaError(format!(
+                "'{}' refers to a token, which has no values",
+                field_path
+            ))),
+
+            LexiconType::Bytes {
+                min_length,
+                max_length,
+            } => {
+                // Bytes are encoded as {"$bytes": "<base64>"} in JSON
+                let encoded = value
+                    .get("$bytes")
+                    .and_then(Value::as_str)
+                    .ok_or(ValidationError::InvalidType {
+                        field: field_path.to_string(),
+                        expected: "bytes".to_string(),
+                        actual: format!("{:?}", value),
+                    })?;
+                let len = encoded.trim_end_matches('=').len() * 3 / 4;
+
+                if let Some(min) = min_length {
+                    if len < *min {
+                        return Err(ValidationError::StringTooShort {
+                            field: field_path.to_string(),
+                            min: *min,
+                            actual: len,
+                        });
+                    }
+                }
+
+                if let Some(max) = max_length {
+                    if len > *max {
+                        return Err(ValidationError::StringTooLong {
+                            field: field_path.to_string(),
+                            max: *max,
+                            actual: len,
+                        });
+                    }
+                }
+
+                Ok(())
+            }
+
+            LexiconType::CidLink => {
+                // CID links are encoded as {"$link": "<cid>"} in JSON
+                match value.get("$link").and_then(Value::as_str) {
+                    Some(link) if Cid::from_str(link).is_ok() => Ok(()),
+                    _ => Err(ValidationError::InvalidType {
+                        field: field_path.to_string(),
+                        expected: "cid-link".to_string(),
+                        actual: format!("{:?}", value),
+                    }),
+                }
+            }
+
             LexiconType::Blob { .. } => {
                 // Blob validation would check accept types and max size
                 // For now, accept any object with $type: "blob"
@@ -594,36 +890,97 @@ impl LexiconSchema {
                 self.validate_type(record, value, field_path)
             }
 
-            LexiconType::Procedure { .. } => {
-                // Procedures are validated differently (input/output)
+            LexiconType::Query { .. }
+            | LexiconType::Procedure { .. }
+            | LexiconType::Subscription { .. } => {
+                // Endpoints are validated differently (params/input/output)
                 Ok(())
             }
         }
     }
 
+    /// Validate an object's required fields and properties
+    fn validate_object(
+        &self,
+        required: &Option<Vec<String>>,
+        nullable: &Option<Vec<String>>,
+        properties: &Option<HashMap<String, LexiconType>>,
+        value: &Value,
+        field_path: &str,
+    ) -> ValidationResult<()> {
+        let obj = value.as_object().ok_or(ValidationError::InvalidType {
+            field: field_path.to_string(),
+            expected: "object".to_string(),
+            actual: format!("{:?}", value),
+        })?;
+
+        // Check required fields
+        if let Some(req_fields) = required {
+            for field_name in req_fields {
+                if !obj.contains_key(field_name) {
+                    return Err(ValidationError::MissingRequiredField(
+                        format!("{}.{}", field_path, field_name),
+                    ));
+                }
+            }
+        }
+
+        // Validate properties
+        if let Some(props) = properties {
+            for (prop_name, prop_type) in props {
+                if let Some(prop_value) = obj.get(prop_name) {
+                    // Check if null is allowed
+                    if prop_value.is_null() {
+                        if let Some(nullable_fields) = nullable {
+                            if !nullable_fields.contains(prop_name) {
+                                return Err(ValidationError::InvalidType {
+                                    field: format!("{}.{}", field_path, prop_name),
+                                    expected: "non-null value".to_string(),
+                                    actual: "null".to_string(),
+                                });
+                            }
+                        }
+                        continue;
+                    }
+
+                    let prop_path = if field_path == "$root" {
+                        prop_name.clone()
+                    } else {
+                        format!("{}.{}", field_path, prop_name)
+                    };
+                    self.validate_type(prop_type, prop_value, &prop_path)?;
+                }
+            }
+        }
+
+        Ok(())
+    }
+
     /// Validate a reference type
+    ///
+    /// Local refs resolve against the current schema; others need the registry.
     fn validate_ref(
         &self,
         ref_path: &str,
         value: &Value,
         field_path: &str,
     ) -> ValidationResult<()> {
-        // Parse reference: "lex:namespace#def" or "#def"
-        let def_name = if ref_path.starts_with("lex:") {
-            // Cross-namespace reference - not supported yet
-            return Err(ValidationError::ReferenceNotFound(ref_path.to_string()));
-        } else if let Some(hash_pos) = ref_path.rfind('#') {
-            &ref_path[hash_pos + 1..]
+        let (nsid, def_name) = split_ref(ref_path);
+
+        let schema = if nsid.is_empty() || nsid == self.schema.id {
+            self.schema
         } else {
-            ref_path
+            self.registry
+                .and_then(|registry| registry.get(nsid))
+                .ok_or_else(|| ValidationError::ReferenceNotFound(ref_path.to_string()))?
         };
 
         // Get the referenced definition
-        let def = self
+        let def = schema
             .get_def(def_name)
             .ok_or_else(|| ValidationError::ReferenceNotFound(ref_path.to_string()))?;
 
-        self.validate_type(&def.def_type, value, field_path)
+        Scope { schema, registry: self.registry }.validate_type(&def.def_type, value, field_path)
     }
 }
 
@@ -907,4 +1264,193 @@ mod tests {
             Err(ValidationError::TooManyGraphemes { .. })
         ));
     }
+
+    #[test]
+    fn test_bundled_registry() {
+        let registry = LexiconRegistry::bundled();
+        assert_eq!(registry.len(), crate::client::lexicons::LEXICONS.len());
+        assert!(registry.get("app.bsky.feed.post").is_some());
+        assert!(registry.get("com.atproto.repo.strongRef").is_some());
+    }
+
+    #[test]
+    fn test_registry_cross_document_refs() {
+        let registry = LexiconRegistry::bundled();
+
+        let post = json!({
+            "$type": "app.bsky.feed.post",
+            "text": "Look at this",
+            "createdAt": "2025-01-15T10:00:00.000Z",
+            "embed": {
+                "$type": "app.bsky.embed.images",
+                "images": [{
+                    "alt": "A red square",
+                    "image": {
+                        "$type": "blob",
+                        "ref": {"$link": "bafyreie5737gdxlw5i64vzichcalba3z2v5n6icifvx5xytvske7mr3hpm"},
+                        "mimeType": "image/png",
+                        "size": 1024
+                    }
+                }]
+            },
+            "facets": [{
+                "index": {"byteStart": 0, "byteEnd": 4},
+                "features": [{"$type": "app.bsky.richtext.facet#tag", "tag": "look"}]
+            }]
+        });
+        assert!(registry.validate_record("app.bsky.feed.post", &post).is_ok());
+
+        // app.bsky.embed.images#image requires alt
+        let mut missing_alt = post.clone();
+        missing_alt["embed"]["images"][0].as_object_mut().unwrap().remove("alt");
+        assert!(matches!(
+            registry.validate_record("app.bsky.feed.post", &missing_alt),
+            Err(ValidationError::MissingRequiredField(_))
+        ));
+
+        // Refs also resolve when validating a def directly
+        let strong_ref = json!({
+            "uri": "at://did:plc:test/app.bsky.feed.post/3jui7kd54zh2y",
+            "cid": "not-a-cid"
+        });
+        assert!(matches!(
+            registry.validate("com.atproto.repo.strongRef", &strong_ref),
+            Err(ValidationError::InvalidFormat { .. })
+        ));
+    }
+
+    #[test]
+    fn test_registry_constraints() {
+        let registry = LexiconRegistry::bundled();
+
+        // maxGraphemes
+        let long_post = json!({
+            "text": "🎉".repeat(301),
+            "createdAt": "2025-01-15T10:00:00.000Z"
+        });
+        assert!(matches!(
+            registry.validate_record("app.bsky.feed.post", &long_post),
+            Err(ValidationError::TooManyGraphemes { max: 300, actual: 301, .. })
+        ));
+
+        // knownValues, through a ref into another lexicon
+        let list = json!({
+            "name": "Friends",
+            "purpose": "app.bsky.graph.defs#partylist",
+            "createdAt": "2025-01-15T10:00:00.000Z"
+        });
+        assert!(matches!(
+            registry.validate_record("app.bsky.graph.list", &list),
+            Err(ValidationError::InvalidEnumValue { .. })
+        ));
+
+        // const
+        let not_found = json!({
+            "uri": "at://did:plc:test/app.bsky.feed.post/3jui7kd54zh2y",
+            "notFound": false
+        });
+        assert!(matches!(
+            registry.validate("app.bsky.feed.defs#notFoundPost", &not_found),
+            Err(ValidationError::InvalidConstValue { .. })
+        ));
+    }
+
+    #[test]
+    fn test_registry_record_type() {
+        let registry = LexiconRegistry::bundled();
+        let like = json!({
+            "$type": "app.bsky.feed.post",
+            "subject": {
+                "uri": "at://did:plc:test/app.bsky.feed.post/3jui7kd54zh2y",
+                "cid": "bafyreie5737gdxlw5i64vzichcalba3z2v5n6icifvx5xytvske7mr3hpm"
+            },
+            "createdAt": "2025-01-15T10:00:00.000Z"
+        });
+
+        assert!(matches!(
+            registry.validate_record("app.bsky.feed.like", &like),
+            Err(ValidationError::InvalidTypeProperty { .. })
+        ));
+        assert!(matches!(
+            registry.validate_record("com.example.unknown", &like),
+            Err(ValidationError::LexiconNotFound(_))
+        ));
+        assert!(matches!(
+            registry.validate_record("app.bsky.feed.getTimeline", &like),
+            Err(ValidationError::SchemaError(_))
+        ));
+    }
+
+    #[test]
+    fn test_registry_unions() {
+        let mut registry = LexiconRegistry::new();
+        registry
+            .add_json(
+                &json!({
+                    "lexicon": 1,
+                    "id": "com.example.shape",
+                    "defs": {
+                        "main": {
+                            "type": "object",
+                            "required": ["open", "closed"],
+                            "properties": {
+                                "open": {"type": "union", "refs": ["#circle", "lex:com.example.defs#square"]},
+                                "closed": {"type": "union", "refs": ["#circle"], "closed": true}
+                            }
+                        },
+                        "circle": {
+                            "type": "object",
+                            "required": ["radius"],
+                            "properties": {"radius": {"type": "integer", "minimum": 1}}
+                        }
+                    }
+                })
+                .to_string(),
+            )
+            .unwrap();
+        registry
+            .add_json(
+                &json!({
+                    "lexicon": 1,
+                    "id": "com.example.defs",
+                    "defs": {
+                        "square": {
+                            "type": "object",
+                            "required": ["side"],
+                            "properties": {"side": {"type": "integer"}}
+                        }
+                    }
+                })
+                .to_string(),
+            )
+            .unwrap();
+
+        let circle = json!({"$type": "com.example.shape#circle", "radius": 2});
+        let square = json!({"$type": "com.example.defs#square", "side": 3});
+        let triangle = json!({"$type": "com.example.defs#triangle"});
+
+        let shape = |open: &Value, closed: &Value| json!({"open": open, "closed": closed});
+
+        assert!(registry.validate("com.example.shape", &shape(&square, &circle)).is_ok());
+
+        // Open unions accept unknown types, closed unions don't
+        assert!(registry.validate("com.example.shape", &shape(&triangle, &circle)).is_ok());
+        assert!(matches!(
+            registry.validate("com.example.shape", &shape(&circle, &square)),
+            Err(ValidationError::UnionValidationFailed { .. })
+        ));
+
+        // The member matching $type is validated
+        let bad_square = json!({"$type": "com.example.defs#square", "side": "three"});
+        assert!(matches!(
+            registry.validate("com.example.shape", &shape(&bad_square, &circle)),
+            Err(ValidationError::InvalidType { .. })
+        ));
+
+        // Union values need a $type
+        assert!(matches!(
+            registry.validate("com.example.shape", &shape(&json!({"side": 3}), &circle)),
+            Err(ValidationError::MissingTypeProperty)
+        ));
+    }
 }
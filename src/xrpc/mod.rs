@@ -14,7 +14,6 @@
 use async_trait::async_trait;
 use reqwest::{header::HeaderMap, Method, Request, Response};
 use serde::{de::DeserializeOwned, Serialize};
-use std::collections::HashMap;
 use std::sync::Arc;
 use std::time::Duration;
 use thiserror::Error;
@@ -194,12 +193,105 @@ pub enum XrpcMethod {
     Procedure,
 }
 
+/// Query parameters of an XRPC request
+///
+/// A key may have several values: arrays are sent as repeated keys
+/// (`?uris=a&uris=b`), in the order they were added.
+///
+/// # Examples
+///
+/// ```
+/// use atproto::xrpc::XrpcParams;
+///
+/// let mut params = XrpcParams::new();
+/// params.append("actors", "alice.bsky.social");
+/// params.append("actors", "bob.bsky.social");
+/// params.insert("limit", "10");
+///
+/// assert_eq!(params.get("actors"), Some("alice.bsky.social"));
+/// assert_eq!(params.get_all("actors").count(), 2);
+/// assert_eq!(params.to_query_string(), "actors=alice.bsky.social&actors=bob.bsky.social&limit=10");
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct XrpcParams {
+    pairs: Vec<(String, String)>,
+}
+
+impl XrpcParams {
+    /// Creates an empty parameter list
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds a value for a key, keeping any existing ones
+    pub fn append(&mut self, key: impl Into<String>, value: impl Into<String>) {
+        self.pairs.push((key.into(), value.into()));
+    }
+
+    /// Sets the only value for a key, replacing any existing ones
+    pub fn insert(&mut self, key: impl Into<String>, value: impl Into<String>) {
+        let key = key.into();
+        self.remove(&key);
+        self.pairs.push((key, value.into()));
+    }
+
+    /// Removes all values for a key
+    pub fn remove(&mut self, key: &str) {
+        self.pairs.retain(|(k, _)| k != key);
+    }
+
+    /// Gets the first value for a key
+    pub fn get(&self, key: &str) -> Option<&str> {
+        self.pairs.iter().find(|(k, _)| k == key).map(|(_, v)| v.as_str())
+    }
+
+    /// Gets all values for a key, in order
+    pub fn get_all<'a>(&'a self, key: &'a str) -> impl Iterator<Item = &'a str> + 'a {
+        self.pairs
+            .iter()
+            .filter(move |(k, _)| k == key)
+            .map(|(_, v)| v.as_str())
+    }
+
+    /// Iterates over all key-value pairs, in order
+    pub fn iter(&self) -> impl Iterator<Item = (&str, &str)> {
+        self.pairs.iter().map(|(k, v)| (k.as_str(), v.as_str()))
+    }
+
+    /// Number of key-value pairs
+    pub fn len(&self) -> usize {
+        self.pairs.len()
+    }
+
+    /// Whether there are no parameters
+    pub fn is_empty(&self) -> bool {
+        self.pairs.is_empty()
+    }
+
+    /// Encodes the parameters as a URL query string, without the leading `?`
+    pub fn to_query_string(&self) -> String {
+        self.pairs
+            .iter()
+            .map(|(k, v)| format!("{}={}", urlencoding::encode(k), urlencoding::encode(v)))
+            .collect::<Vec<_>>()
+            .join("&")
+    }
+}
+
+impl<K: Into<String>, V: Into<String>> FromIterator<(K, V)> for XrpcParams {
+    fn from_iter<I: IntoIterator<Item = (K, V)>>(iter: I) -> Self {
+        Self {
+            pairs: iter.into_iter().map(|(k, v)| (k.into(), v.into())).collect(),
+        }
+    }
+}
+
 /// XRPC request parameters
 #[derive(Debug, Clone)]
 pub struct XrpcRequest {
     pub method: XrpcMethod,
     pub nsid: String,
-    pub params: HashMap<String, String>,
+    pub params: XrpcParams,
     pub data: Option<serde_json::Value>,
     pub binary_data: Option<Vec<u8>>,
     pub headers: HeaderMap,
@@ -211,7 +303,7 @@ impl XrpcRequest {
         Self {
             method: XrpcMethod::Query,
             nsid: nsid.into(),
-            params: HashMap::new(),
+            params: XrpcParams::new(),
             data: None,
             binary_data: None,
             headers: HeaderMap::new(),
@@ -223,7 +315,7 @@ impl XrpcRequest {
         Self {
             method: XrpcMethod::Procedure,
             nsid: nsid.into(),
-            params: HashMap::new(),
+            params: XrpcParams::new(),
             data: None,
             binary_data: None,
             headers: HeaderMap::new(),
@@ -231,8 +323,11 @@ impl XrpcRequest {
     }
 
     /// Adds a query parameter
+    ///
+    /// Adding the same key again appends another value, which is how arrays
+    /// are encoded.
     pub fn param(mut self, key: impl Into<String>, value: impl Into<String>) -> Self {
-        self.params.insert(key.into(), value.into());
+        self.params.append(key, value);
         self
     }
 
@@ -282,20 +377,120 @@ impl<T> XrpcResponse<T> {
     }
 }
 
+/// Static description of an XRPC method
+///
+/// Code generation emits an `Endpoint` type implementing this for every query
+/// and procedure, tying the NSID to its parameter, input and output types.
+/// Servers use it to route and decode requests (see `server::XrpcRouter`).
+pub trait XrpcEndpoint {
+    /// The method NSID, e.g. `app.bsky.feed.getFeedSkeleton`
+    const NSID: &'static str;
+
+    /// Whether this is a query (GET) or a procedure (POST)
+    const METHOD: XrpcMethod;
+
+    /// MIME type of the request body, if the method takes one
+    const INPUT_ENCODING: Option<&'static str>;
+
+    /// MIME type of the response body, if the method returns one
+    const OUTPUT_ENCODING: Option<&'static str>;
+
+    /// Query parameters, `()` if there are none
+    type Params: DeserializeOwned + Send + 'static;
+
+    /// Request body, `()` if there is none
+    type Input: XrpcBody + Send + 'static;
+
+    /// Response body, `()` if there is none
+    type Output: XrpcBody + Send + 'static;
+}
+
+/// A request or response body
+///
+/// JSON bodies implement this through [`JsonBody`], binary bodies are `Vec<u8>`
+/// and empty bodies are `()`.
+pub trait XrpcBody: Sized {
+    /// Decode a body
+    fn from_bytes(bytes: Vec<u8>) -> Result<Self>;
+
+    /// Encode a body
+    fn into_bytes(self) -> Result<Vec<u8>>;
+}
+
+/// Marker for bodies encoded as `application/json`
+pub trait JsonBody: Serialize + DeserializeOwned {}
+
+impl<T: JsonBody> XrpcBody for T {
+    fn from_bytes(bytes: Vec<u8>) -> Result<Self> {
+        Ok(serde_json::from_slice(&bytes)?)
+    }
+
+    fn into_bytes(self) -> Result<Vec<u8>> {
+        Ok(serde_json::to_vec(&self)?)
+    }
+}
+
+impl JsonBody for serde_json::Value {}
+
+impl XrpcBody for Vec<u8> {
+    fn from_bytes(bytes: Vec<u8>) -> Result<Self> {
+        Ok(bytes)
+    }
+
+    fn into_bytes(self) -> Result<Vec<u8>> {
+        Ok(self)
+    }
+}
+
+impl XrpcBody for () {
+    fn from_bytes(_bytes: Vec<u8>) -> Result<Self> {
+        Ok(())
+    }
+
+    fn into_bytes(self) -> Result<Vec<u8>> {
+        Ok(Vec::new())
+    }
+}
+
 /// Trait for XRPC client implementations
+///
+/// Implementors only provide [`request_bytes`](Self::request_bytes); the
+/// decoding methods are built on top of it.
 #[async_trait]
 pub trait XrpcClient: Send + Sync {
-    /// Executes an XRPC request
+    /// Executes an XRPC request, returning the raw response body
+    async fn request_bytes(
+        &self,
+        req: XrpcRequest,
+    ) -> Result<XrpcResponse<Vec<u8>>>;
+
+    /// Executes an XRPC request, decoding the response as JSON
     async fn request<T: DeserializeOwned>(
         &self,
         req: XrpcRequest,
-    ) -> Result<XrpcResponse<T>>;
+    ) -> Result<XrpcResponse<T>> {
+        let resp = self.request_bytes(req).await?;
+        Ok(XrpcResponse::new(serde_json::from_slice(&resp.data)?, resp.headers))
+    }
+
+    /// Executes an XRPC request, decoding the response according to `T`
+    ///
+    /// JSON types go through [`JsonBody`], raw bytes are `Vec<u8>`, and `()`
+    /// ignores the body. Generated client functions use this so binary
+    /// endpoints can return bytes.
+    async fn request_body<T: XrpcBody>(
+        &self,
+        req: XrpcRequest,
+    ) -> Result<XrpcResponse<T>> {
+        let resp = self.request_bytes(req).await?;
+        Ok(XrpcResponse::new(T::from_bytes(resp.data)?, resp.headers))
+    }
 
     /// Executes an XRPC query
     async fn query<T: DeserializeOwned>(
         &self,
         nsid: impl Into<String> + Send,
-        params: HashMap<String, String>,
+        params: XrpcParams,
     ) -> Result<XrpcResponse<T>> {
         let mut req = XrpcRequest::query(nsid);
         req.params = params;
@@ -407,16 +602,12 @@ impl XrpcClientImpl {
     }
 
     /// Builds the URL for an XRPC request
-    fn build_url(&self, nsid: &str, params: &HashMap<String, String>) -> String {
+    fn build_url(&self, nsid: &str, params: &XrpcParams) -> String {
         let mut url = format!("{}/xrpc/{}", self.base_url.trim_end_matches('/'), nsid);
 
         if !params.is_empty() {
             url.push('?');
-            let param_str: Vec<String> = params
-                .iter()
-                .map(|(k, v)| format!("{}={}", urlencoding::encode(k), urlencoding::encode(v)))
-                .collect();
-            url.push_str(&param_str.join("&"));
+            url.push_str(&params.to_query_string());
         }
 
         url
@@ -456,12 +647,13 @@ impl XrpcClientImpl {
     }
 
     /// Parses an HTTP response into an XRPC response
-    async fn parse_response<T: DeserializeOwned>(&self, resp: Response) -> Result<XrpcResponse<T>> {
+    async fn parse_response<T: XrpcBody>(&self, resp: Response) -> Result<XrpcResponse<T>> {
         let headers = resp.headers().clone();
         let status = resp.status();
 
         if status.is_success() {
-            let data = resp.json::<T>().await?;
+            let bytes = resp.bytes().await?;
+            let data = T::from_bytes(bytes.to_vec())?;
             Ok(XrpcResponse::new(data, headers))
         } else {
             // Try to parse XRPC error
@@ -529,7 +721,7 @@ impl XrpcClientImpl {
     }
 
     /// Execute a request with retry logic
-    async fn execute_with_retry<T: DeserializeOwned>(
+    async fn execute_with_retry<T: XrpcBody>(
         &self,
         req: &XrpcRequest,
     ) -> Result<XrpcResponse<T>> {
@@ -581,10 +773,10 @@ impl XrpcClientImpl {
 
 #[async_trait]
 impl XrpcClient for XrpcClientImpl {
-    async fn request<T: DeserializeOwned>(
+    async fn request_bytes(
         &self,
         req: XrpcRequest,
-    ) -> Result<XrpcResponse<T>> {
+    ) -> Result<XrpcResponse<Vec<u8>>> {
         self.execute_with_retry(&req).await
     }
 }
@@ -622,8 +814,33 @@ mod tests {
             .param("cursor", "abc123");
 
         assert_eq!(req.params.len(), 2);
-        assert_eq!(req.params.get("limit"), Some(&"10".to_string()));
-        assert_eq!(req.params.get("cursor"), Some(&"abc123".to_string()));
+        assert_eq!(req.params.get("limit"), Some("10"));
+        assert_eq!(req.params.get("cursor"), Some("abc123"));
+    }
+
+    #[test]
+    fn test_xrpc_request_with_repeated_params() {
+        let req = XrpcRequest::query("app.bsky.actor.getProfiles")
+            .param("actors", "alice.bsky.social")
+            .param("actors", "bob.bsky.social");
+
+        assert_eq!(req.params.len(), 2);
+        assert_eq!(
+            req.params.get_all("actors").collect::<Vec<_>>(),
+            vec!["alice.bsky.social", "bob.bsky.social"]
+        );
+    }
+
+    #[test]
+    fn test_xrpc_params_insert_replaces() {
+        let mut params: XrpcParams = [("cursor", "1"), ("cursor", "2"), ("limit", "5")].into_iter().collect();
+        params.insert("cursor", "3");
+
+        assert_eq!(params.get_all("cursor").collect::<Vec<_>>(), vec!["3"]);
+        assert_eq!(params.get("limit"), Some("5"));
+
+        params.remove("limit");
+        assert_eq!(params.len(), 1);
     }
 
     #[test]
@@ -680,14 +897,14 @@ mod tests {
     #[test]
     fn test_build_url_no_params() {
         let client = XrpcClientImpl::new("https://bsky.social");
-        let url = client.build_url("com.atproto.repo.getRecord", &HashMap::new());
+        let url = client.build_url("com.atproto.repo.getRecord", &XrpcParams::new());
         assert_eq!(url, "https://bsky.social/xrpc/com.atproto.repo.getRecord");
     }
 
     #[test]
     fn test_build_url_with_params() {
         let client = XrpcClientImpl::new("https://bsky.social");
-        let mut params = HashMap::new();
+        let mut params = XrpcParams::new();
         params.insert("repo".to_string(), "did:plc:test".to_string());
         params.insert("collection".to_string(), "app.bsky.feed.post".to_string());
 
@@ -698,10 +915,30 @@ mod tests {
         assert!(url.contains("collection=app.bsky.feed.post"));
     }
 
+    #[test]
+    fn test_build_url_with_repeated_params() {
+        let client = XrpcClientImpl::new("https://bsky.social");
+        let params: XrpcParams = [
+            ("uris", "at://did:plc:a/app.bsky.feed.post/1"),
+            ("uris", "at://did:plc:b/app.bsky.feed.post/2"),
+        ]
+        .into_iter()
+        .collect();
+
+        let url = client.build_url("app.bsky.feed.getPosts", &params);
+
+        assert_eq!(
+            url,
+            "https://bsky.social/xrpc/app.bsky.feed.getPosts?\
+             uris=at%3A%2F%2Fdid%3Aplc%3Aa%2Fapp.bsky.feed.post%2F1&\
+             uris=at%3A%2F%2Fdid%3Aplc%3Ab%2Fapp.bsky.feed.post%2F2"
+        );
+    }
+
     #[test]
     fn test_build_url_trailing_slash() {
         let client = XrpcClientImpl::new("https://bsky.social/");
-        let url = client.build_url("com.atproto.repo.getRecord", &HashMap::new());
+        let url = client.build_url("com.atproto.repo.getRecord", &XrpcParams::new());
         assert_eq!(url, "https://bsky.social/xrpc/com.atproto.repo.getRecord");
     }
 
@@ -4,16 +4,29 @@
 
 use futures::{stream::Stream, StreamExt};
 use std::pin::Pin;
-use std::time::Duration;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
 use tokio::time::sleep;
 use tokio_tungstenite::{connect_async, tungstenite::Message};
 
+use crate::cursor_store::{CheckpointConfig, CursorStore};
 use crate::xrpc::{XrpcError, XrpcRequest};
 
 /// Result type for subscription operations
 pub type SubscriptionResult<T> = Result<T, XrpcError>;
 
+/// Stream of subscription events
+pub type SubscriptionStream = Pin<Box<dyn Stream<Item = SubscriptionResult<SubscriptionEvent>> + Send>>;
+
+/// WebSocket connection used by the subscription client
+type WsStream = tokio_tungstenite::WebSocketStream<tokio_tungstenite::MaybeTlsStream<tokio::net::TcpStream>>;
+
+/// Error name a server sends before dropping a consumer that fell behind
+const CONSUMER_TOO_SLOW: &str = "ConsumerTooSlow";
+
 /// Frame header for subscription messages
+///
+/// Encoded as the first of the two DAG-CBOR objects in every frame.
 #[derive(Debug, Clone, serde::Deserialize, serde::Serialize)]
 pub struct FrameHeader {
     #[serde(rename = "op")]
@@ -38,6 +51,29 @@ pub enum SubscriptionEvent {
     },
     /// Connection closed
     Closed,
+    /// Connection was re-established by a resilient subscription
+    Reconnected {
+        /// Reconnection attempt that succeeded (1 = first retry)
+        attempt: u32,
+        /// Cursor the stream resumed from
+        cursor: Option<i64>,
+    },
+    /// A reconnection attempt by a resilient subscription failed; it will
+    /// retry until its reconnect attempts run out
+    ReconnectFailed {
+        /// Reconnection attempt that failed (1 = first retry)
+        attempt: u32,
+        /// Why the connection could not be made
+        error: String,
+    },
+    /// Saving the cursor to the configured `CursorStore` failed; the save is
+    /// retried at the next checkpoint
+    CheckpointFailed {
+        /// Cursor that could not be saved
+        cursor: i64,
+        /// Error returned by the store
+        error: String,
+    },
 }
 
 /// Configuration for subscription reconnection
@@ -84,12 +120,19 @@ impl ReconnectConfig {
 }
 
 /// WebSocket subscription client
+#[derive(Clone)]
 pub struct SubscriptionClient {
     /// Base URL for WebSocket connections
     base_url: String,
 
     /// Reconnection configuration
     reconnect_config: ReconnectConfig,
+
+    /// Where resilient subscriptions persist their cursor
+    cursor_store: Option<Arc<dyn CursorStore>>,
+
+    /// How often the cursor is checkpointed
+    checkpoint_config: CheckpointConfig,
 }
 
 impl SubscriptionClient {
@@ -102,6 +145,8 @@ impl SubscriptionClient {
         Self {
             base_url,
             reconnect_config: ReconnectConfig::default(),
+            cursor_store: None,
+            checkpoint_config: CheckpointConfig::default(),
         }
     }
 
@@ -111,6 +156,17 @@ impl SubscriptionClient {
         self
     }
 
+    /// Persist the cursor of resilient subscriptions to a store
+    ///
+    /// [`subscribe_resilient`](Self::subscribe_resilient) resumes from the
+    /// stored cursor when the request has no explicit `cursor` param, and
+    /// checkpoints the `seq` of each event once the consumer has moved past it.
+    pub fn with_cursor_store(mut self, store: Arc<dyn CursorStore>, config: CheckpointConfig) -> Self {
+        self.cursor_store = Some(store);
+        self.checkpoint_config = config;
+        self
+    }
+
     /// Subscribe to an XRPC endpoint
     ///
     /// # Arguments
@@ -123,12 +179,69 @@ impl SubscriptionClient {
     pub async fn subscribe(
         &self,
         request: XrpcRequest,
-    ) -> SubscriptionResult<Pin<Box<dyn Stream<Item = SubscriptionResult<SubscriptionEvent>> + Send>>> {
+    ) -> SubscriptionResult<SubscriptionStream> {
         let url = self.build_websocket_url(&request)?;
 
-        let stream = self.connect_with_retry(url).await?;
+        let ws_stream = self.connect_with_retry(url).await?;
 
-        Ok(Box::pin(stream))
+        Ok(Box::pin(Self::process_stream(ws_stream)))
+    }
+
+    /// Subscribe to an XRPC endpoint, reconnecting when the connection drops
+    ///
+    /// The `seq` of every message is remembered, and reconnects resume from it
+    /// via the `cursor` parameter using the configured backoff. Each successful
+    /// reconnect is reported as [`SubscriptionEvent::Reconnected`]. The stream
+    /// ends after an error frame (other than `ConsumerTooSlow`, which triggers a
+    /// reconnect) or once `max_attempts` consecutive reconnects have failed.
+    ///
+    /// If a cursor store is configured, the stream starts from the stored
+    /// cursor and checkpoints to it as events are consumed.
+    ///
+    /// # Arguments
+    ///
+    /// * `request` - XRPC request for the subscription; a `cursor` param is used
+    ///   as the starting point and takes precedence over a stored cursor
+    pub async fn subscribe_resilient(
+        &self,
+        mut request: XrpcRequest,
+    ) -> SubscriptionResult<SubscriptionStream> {
+        let mut cursor = request.params.get("cursor").and_then(|c| c.parse::<i64>().ok());
+
+        if cursor.is_none() {
+            if let Some(store) = &self.cursor_store {
+                cursor = store.load_cursor().await
+                    .map_err(|e| XrpcError::InvalidRequest(format!("Failed to load cursor: {}", e)))?;
+
+                if let Some(stored) = cursor {
+                    request.params.insert("cursor".to_string(), stored.to_string());
+                }
+            }
+        }
+
+        let checkpoint = self.cursor_store.as_ref().map(|store| Checkpoint {
+            store: store.clone(),
+            config: self.checkpoint_config.clone(),
+            pending: None,
+            latest: None,
+            unsaved: 0,
+            last_saved: Instant::now(),
+        });
+
+        let url = self.build_websocket_url(&request)?;
+        let ws_stream = self.connect_with_retry(url).await?;
+
+        let state = ResilientState {
+            client: self.clone(),
+            request,
+            cursor,
+            ws: Some(ws_stream),
+            attempt: 0,
+            finished: false,
+            checkpoint,
+        };
+
+        Ok(Box::pin(futures::stream::unfold(state, ResilientState::next_event)))
     }
 
     /// Build WebSocket URL from XRPC request
@@ -149,28 +262,20 @@ impl SubscriptionClient {
 
         // Add query parameters
         if !request.params.is_empty() {
-            let params: Vec<String> = request.params
-                .iter()
-                .map(|(k, v)| format!("{}={}", k, urlencoding::encode(v)))
-                .collect();
-
-            url = format!("{}?{}", url, params.join("&"));
+            url = format!("{}?{}", url, request.params.to_query_string());
         }
 
         Ok(url)
     }
 
     /// Connect to WebSocket with automatic retry
-    async fn connect_with_retry(
-        &self,
-        url: String,
-    ) -> SubscriptionResult<impl Stream<Item = SubscriptionResult<SubscriptionEvent>>> {
+    async fn connect_with_retry(&self, url: String) -> SubscriptionResult<WsStream> {
         let mut attempt = 0;
 
         loop {
             match connect_async(&url).await {
                 Ok((ws_stream, _)) => {
-                    return Ok(Self::process_stream(ws_stream));
+                    return Ok(ws_stream);
                 }
                 Err(e) if self.should_retry(attempt) => {
                     let delay = self.reconnect_config.calculate_delay(attempt);
@@ -193,7 +298,7 @@ impl SubscriptionClient {
 
     /// Process WebSocket stream into subscription events
     fn process_stream(
-        ws_stream: tokio_tungstenite::WebSocketStream<tokio_tungstenite::MaybeTlsStream<tokio::net::TcpStream>>,
+        ws_stream: WsStream,
     ) -> impl Stream<Item = SubscriptionResult<SubscriptionEvent>> {
         ws_stream.filter_map(|msg_result| async move {
             match msg_result {
@@ -212,6 +317,11 @@ impl SubscriptionClient {
     }
 
     /// Parse a subscription frame
+    ///
+    /// Each binary frame is two concatenated DAG-CBOR objects: a [`FrameHeader`]
+    /// (`{op, t}`) followed by the message body. Message frames (`op = 1`) carry
+    /// the body through untouched; error frames (`op = -1`) have their
+    /// `{error, message}` body decoded.
     fn parse_frame(data: &[u8]) -> SubscriptionResult<SubscriptionEvent> {
         if data.is_empty() {
             return Err(XrpcError::Deserialization(
@@ -219,65 +329,258 @@ impl SubscriptionClient {
             ));
         }
 
-        // Read operation code (first byte)
-        let op = data[0] as i32;
+        let (header, body) = Self::split_frame(data)?;
 
-        match op {
+        match header.operation {
             1 => {
-                // Message frame
-                // Format: [op:1][type_len:varint][type:string][body:bytes]
-
-                if data.len() < 2 {
-                    return Err(XrpcError::Deserialization(
-                        "Invalid message frame: too short".to_string()
-                    ));
-                }
-
-                // Read type length (simple varint - just use u8 for now)
-                let type_len = data[1] as usize;
-
-                if data.len() < 2 + type_len {
-                    return Err(XrpcError::Deserialization(
-                        "Invalid message frame: type too short".to_string()
-                    ));
-                }
-
-                // Read message type
-                let message_type = String::from_utf8(data[2..2 + type_len].to_vec())
-                    .map_err(|e| XrpcError::Deserialization(format!("Invalid UTF-8 in message type: {}", e)))?;
-
-                // Body is the rest
-                let body = data[2 + type_len..].to_vec();
+                // Message frame: the header names the message type (e.g. "#commit")
+                let message_type = header.message_type.ok_or_else(|| {
+                    XrpcError::Deserialization(
+                        "Invalid message frame: missing type in header".to_string()
+                    )
+                })?;
 
                 Ok(SubscriptionEvent::Message {
                     message_type,
-                    body
+                    body: body.to_vec(),
                 })
             }
             -1 => {
-                // Error frame
-                // Try to parse as JSON
-                let error_data: serde_json::Value = serde_json::from_slice(&data[1..])
+                // Error frame: body is a DAG-CBOR {error, message} object
+                let error_body: ErrorFrameBody = serde_cbor::from_slice(body)
                     .map_err(|e| XrpcError::Deserialization(format!("Failed to parse error frame: {}", e)))?;
 
-                let error = error_data.get("error")
-                    .and_then(|v| v.as_str())
-                    .unwrap_or("Unknown error")
-                    .to_string();
-
-                let message = error_data.get("message")
-                    .and_then(|v| v.as_str())
-                    .map(|s| s.to_string());
-
-                Ok(SubscriptionEvent::Error { error, message })
+                Ok(SubscriptionEvent::Error {
+                    error: error_body.error,
+                    message: error_body.message,
+                })
             }
-            _ => {
+            op => {
                 Err(XrpcError::Deserialization(
                     format!("Unknown operation code: {}", op)
                 ))
             }
         }
     }
+
+    /// Split a frame into its decoded header and the raw body bytes
+    fn split_frame(data: &[u8]) -> SubscriptionResult<(FrameHeader, &[u8])> {
+        let mut deserializer = serde_cbor::Deserializer::from_slice(data);
+
+        let header: FrameHeader = serde::Deserialize::deserialize(&mut deserializer)
+            .map_err(|e| XrpcError::Deserialization(format!("Failed to decode frame header: {}", e)))?;
+
+        let body = &data[deserializer.byte_offset()..];
+
+        if body.is_empty() {
+            return Err(XrpcError::Deserialization(
+                "Invalid frame: missing body".to_string()
+            ));
+        }
+
+        Ok((header, body))
+    }
+}
+
+/// State for a resilient subscription stream
+struct ResilientState {
+    client: SubscriptionClient,
+    request: XrpcRequest,
+
+    /// Last seen `seq`, used as the cursor on reconnect
+    cursor: Option<i64>,
+
+    /// Current connection (None while reconnecting)
+    ws: Option<WsStream>,
+
+    /// Consecutive reconnection attempts since the last received message
+    attempt: u32,
+
+    finished: bool,
+
+    /// Cursor persistence, if a store is configured
+    checkpoint: Option<Checkpoint>,
+}
+
+impl ResilientState {
+    async fn next_event(mut self) -> Option<(SubscriptionResult<SubscriptionEvent>, Self)> {
+        // Being polled again means the consumer is done with the previous event
+        if let Some(checkpoint) = self.checkpoint.as_mut() {
+            if let Some(failed) = checkpoint.mark_processed().await {
+                return Some((Ok(failed), self));
+            }
+        }
+
+        if self.finished {
+            // Flush once; a failure is reported and the stream ends on the next poll
+            if let Some(mut checkpoint) = self.checkpoint.take() {
+                if let Some(failed) = checkpoint.flush().await {
+                    return Some((Ok(failed), self));
+                }
+            }
+            return None;
+        }
+
+        loop {
+            let Some(ws) = self.ws.as_mut() else {
+                match self.reconnect().await {
+                    Ok(event) => return Some((Ok(event), self)),
+                    Err(e) => {
+                        self.finished = true;
+                        return Some((Err(e), self));
+                    }
+                }
+            };
+
+            match ws.next().await {
+                Some(Ok(Message::Binary(data))) => {
+                    self.attempt = 0;
+                    let event = SubscriptionClient::parse_frame(&data);
+
+                    match &event {
+                        Ok(SubscriptionEvent::Message { body, .. }) => {
+                            if let Some(seq) = extract_seq(body) {
+                                self.cursor = Some(seq);
+
+                                if let Some(checkpoint) = self.checkpoint.as_mut() {
+                                    checkpoint.pending = Some(seq);
+                                }
+                            }
+                        }
+                        Ok(SubscriptionEvent::Error { error, .. }) if error == CONSUMER_TOO_SLOW => {
+                            self.ws = None;
+                        }
+                        Ok(SubscriptionEvent::Error { .. }) => {
+                            self.finished = true;
+                        }
+                        _ => {}
+                    }
+
+                    return Some((event, self));
+                }
+                Some(Ok(Message::Close(_))) | Some(Err(_)) | None => {
+                    // Connection dropped; reconnect on the next iteration
+                    self.ws = None;
+                }
+                Some(Ok(_)) => {} // Ignore text, ping, pong
+            }
+        }
+    }
+
+    /// Make one reconnection attempt
+    ///
+    /// Returns `Reconnected` on success, `ReconnectFailed` if the attempt
+    /// failed and will be retried, or an error once attempts are exhausted.
+    async fn reconnect(&mut self) -> SubscriptionResult<SubscriptionEvent> {
+        if !self.client.should_retry(self.attempt) {
+            return Err(XrpcError::Network(format!(
+                "Subscription connection lost after {} reconnection attempts",
+                self.attempt
+            )));
+        }
+
+        sleep(self.client.reconnect_config.calculate_delay(self.attempt)).await;
+        self.attempt += 1;
+
+        let mut request = self.request.clone();
+        if let Some(cursor) = self.cursor {
+            request.params.insert("cursor".to_string(), cursor.to_string());
+        }
+        let url = self.client.build_websocket_url(&request)?;
+
+        match connect_async(&url).await {
+            Ok((ws_stream, _)) => {
+                self.ws = Some(ws_stream);
+                Ok(SubscriptionEvent::Reconnected {
+                    attempt: self.attempt,
+                    cursor: self.cursor,
+                })
+            }
+            Err(e) => Ok(SubscriptionEvent::ReconnectFailed {
+                attempt: self.attempt,
+                error: e.to_string(),
+            }),
+        }
+    }
+}
+
+/// Checkpointing state for a resilient subscription
+struct Checkpoint {
+    store: Arc<dyn CursorStore>,
+    config: CheckpointConfig,
+
+    /// Seq of the event most recently handed to the consumer
+    pending: Option<i64>,
+
+    /// Seq of the latest event the consumer has finished with
+    latest: Option<i64>,
+
+    /// Processed events since the last save
+    unsaved: u64,
+
+    last_saved: Instant,
+}
+
+impl Checkpoint {
+    /// Record that the pending event was processed, saving if a threshold is hit
+    ///
+    /// Returns a `CheckpointFailed` event if the save failed.
+    async fn mark_processed(&mut self) -> Option<SubscriptionEvent> {
+        let seq = self.pending.take()?;
+
+        self.latest = Some(seq);
+        self.unsaved += 1;
+
+        let count_reached = self.config.every_events > 0 && self.unsaved >= self.config.every_events;
+        if count_reached || self.last_saved.elapsed() >= self.config.every_interval {
+            return self.flush().await;
+        }
+        None
+    }
+
+    /// Save the latest processed cursor if it hasn't been saved yet
+    ///
+    /// Returns a `CheckpointFailed` event if the save failed.
+    async fn flush(&mut self) -> Option<SubscriptionEvent> {
+        let seq = self.latest?;
+
+        if self.unsaved == 0 {
+            return None;
+        }
+
+        match self.store.save_cursor(seq).await {
+            Ok(()) => {
+                self.unsaved = 0;
+                self.last_saved = Instant::now();
+                None
+            }
+            Err(e) => Some(SubscriptionEvent::CheckpointFailed {
+                cursor: seq,
+                error: e.to_string(),
+            }),
+        }
+    }
+}
+
+/// Message body fields needed to track the stream position
+#[derive(Debug, serde::Deserialize)]
+struct SeqOnly {
+    #[serde(default)]
+    seq: Option<i64>,
+}
+
+/// Extract the `seq` field from a message body, if present
+fn extract_seq(body: &[u8]) -> Option<i64> {
+    serde_cbor::from_slice::<SeqOnly>(body).ok().and_then(|m| m.seq)
+}
+
+/// Body of an error frame (`op = -1`)
+#[derive(Debug, Clone, serde::Deserialize)]
+struct ErrorFrameBody {
+    error: String,
+
+    #[serde(default)]
+    message: Option<String>,
 }
 
 #[cfg(test)]
@@ -326,4 +629,366 @@ mod tests {
         let url = client.build_websocket_url(&request).unwrap();
         assert!(url.contains("?cursor=123"));
     }
+
+    // Captured `#info` frame: header {t: "#info", op: 1}, body {name: "OutdatedCursor"}
+    const INFO_FRAME: &[u8] = &[
+        0xa2, 0x61, 0x74, 0x65, 0x23, 0x69, 0x6e, 0x66, 0x6f, 0x62, 0x6f, 0x70, 0x01,
+        0xa1, 0x64, 0x6e, 0x61, 0x6d, 0x65, 0x6e, 0x4f, 0x75, 0x74, 0x64, 0x61, 0x74,
+        0x65, 0x64, 0x43, 0x75, 0x72, 0x73, 0x6f, 0x72,
+    ];
+
+    // Captured error frame: header {op: -1}, body {error: "FutureCursor", message: "Cursor in the future."}
+    const ERROR_FRAME: &[u8] = &[
+        0xa1, 0x62, 0x6f, 0x70, 0x20,
+        0xa2, 0x65, 0x65, 0x72, 0x72, 0x6f, 0x72, 0x6c, 0x46, 0x75, 0x74, 0x75, 0x72,
+        0x65, 0x43, 0x75, 0x72, 0x73, 0x6f, 0x72, 0x67, 0x6d, 0x65, 0x73, 0x73, 0x61,
+        0x67, 0x65, 0x75, 0x43, 0x75, 0x72, 0x73, 0x6f, 0x72, 0x20, 0x69, 0x6e, 0x20,
+        0x74, 0x68, 0x65, 0x20, 0x66, 0x75, 0x74, 0x75, 0x72, 0x65, 0x2e,
+    ];
+
+    #[test]
+    fn test_parse_message_frame() {
+        let event = SubscriptionClient::parse_frame(INFO_FRAME).unwrap();
+
+        match event {
+            SubscriptionEvent::Message { message_type, body } => {
+                assert_eq!(message_type, "#info");
+                assert_eq!(body, &INFO_FRAME[13..]);
+
+                let decoded: serde_json::Value = serde_cbor::from_slice(&body).unwrap();
+                assert_eq!(decoded["name"], "OutdatedCursor");
+            }
+            other => panic!("Expected message event, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_parse_error_frame() {
+        let event = SubscriptionClient::parse_frame(ERROR_FRAME).unwrap();
+
+        match event {
+            SubscriptionEvent::Error { error, message } => {
+                assert_eq!(error, "FutureCursor");
+                assert_eq!(message.as_deref(), Some("Cursor in the future."));
+            }
+            other => panic!("Expected error event, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_parse_frame_missing_body() {
+        let result = SubscriptionClient::parse_frame(&INFO_FRAME[..13]);
+        assert!(matches!(result, Err(XrpcError::Deserialization(_))));
+    }
+
+    #[test]
+    fn test_parse_frame_unknown_op() {
+        // Header {op: 2} followed by an empty map body
+        let frame = [0xa1, 0x62, 0x6f, 0x70, 0x02, 0xa0];
+        let result = SubscriptionClient::parse_frame(&frame);
+        assert!(matches!(result, Err(XrpcError::Deserialization(_))));
+    }
+
+    #[test]
+    fn test_parse_frame_invalid_header() {
+        let result = SubscriptionClient::parse_frame(&[0xff, 0x00, 0x01]);
+        assert!(result.is_err());
+    }
+
+    /// Encode a `#info`-style message frame carrying a `seq` field
+    fn seq_frame(seq: u8) -> Vec<u8> {
+        // Header {t: "#test", op: 1}, body {seq: <seq>}
+        let mut frame = vec![0xa2, 0x61, 0x74, 0x65, 0x23, 0x74, 0x65, 0x73, 0x74, 0x62, 0x6f, 0x70, 0x01];
+        frame.extend_from_slice(&[0xa1, 0x63, 0x73, 0x65, 0x71, 0x18, seq]);
+        frame
+    }
+
+    #[test]
+    fn test_extract_seq() {
+        assert_eq!(extract_seq(&seq_frame(42)[13..]), Some(42));
+        assert_eq!(extract_seq(&INFO_FRAME[13..]), None);
+    }
+
+    #[test]
+    fn test_extract_seq_skips_cid_links() {
+        use libipld::codec::Codec;
+        use libipld::Ipld;
+        use sha2::{Digest, Sha256};
+
+        let hash = Sha256::digest(b"commit");
+        let multihash = libipld::multihash::Multihash::wrap(0x12, &hash).unwrap();
+        let cid = libipld::cid::Cid::new_v1(0x71, multihash);
+
+        let mut map = std::collections::BTreeMap::new();
+        map.insert("commit".to_string(), Ipld::Link(cid));
+        map.insert("blocks".to_string(), Ipld::Bytes(vec![1, 2, 3]));
+        map.insert("seq".to_string(), Ipld::Integer(123456789));
+        let body = libipld_cbor::DagCborCodec.encode(&Ipld::Map(map)).unwrap();
+
+        assert_eq!(extract_seq(&body), Some(123456789));
+    }
+
+    #[tokio::test]
+    async fn test_subscribe_resilient_resumes_from_cursor() {
+        use tokio::net::TcpListener;
+        use tokio_tungstenite::tungstenite::handshake::server::{Request, Response};
+
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+
+        let server = tokio::spawn(async move {
+            let mut request_uris = Vec::new();
+
+            // First connection sends two events then drops; second sends one more
+            for seqs in [vec![1u8, 2], vec![3]] {
+                let (tcp, _) = listener.accept().await.unwrap();
+                let mut uri = String::new();
+                let mut ws = tokio_tungstenite::accept_hdr_async(tcp, |req: &Request, resp: Response| {
+                    uri = req.uri().to_string();
+                    Ok(resp)
+                })
+                .await
+                .unwrap();
+                request_uris.push(uri);
+
+                for seq in seqs {
+                    futures::SinkExt::send(&mut ws, Message::Binary(seq_frame(seq))).await.unwrap();
+                }
+                drop(ws);
+            }
+
+            request_uris
+        });
+
+        let client = SubscriptionClient::new(format!("ws://{}", addr)).with_reconnect_config(ReconnectConfig {
+            max_attempts: 3,
+            initial_delay: Duration::from_millis(10),
+            max_delay: Duration::from_millis(50),
+            backoff_multiplier: 2.0,
+        });
+
+        let request = XrpcRequest::query("com.atproto.sync.subscribeRepos");
+        let stream = client.subscribe_resilient(request).await.unwrap();
+        let events: Vec<_> = stream.take(4).collect().await;
+
+        let seqs: Vec<Option<i64>> = events
+            .iter()
+            .map(|e| match e {
+                Ok(SubscriptionEvent::Message { body, .. }) => extract_seq(body),
+                _ => None,
+            })
+            .collect();
+        assert_eq!(seqs, vec![Some(1), Some(2), None, Some(3)]);
+
+        assert!(matches!(
+            events[2],
+            Ok(SubscriptionEvent::Reconnected { attempt: 1, cursor: Some(2) })
+        ));
+
+        let uris = server.await.unwrap();
+        assert!(!uris[0].contains("cursor"));
+        assert!(uris[1].contains("cursor=2"));
+    }
+
+    #[tokio::test]
+    async fn test_subscribe_resilient_gives_up_after_max_attempts() {
+        use tokio::net::TcpListener;
+
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+
+        let server = tokio::spawn(async move {
+            let (tcp, _) = listener.accept().await.unwrap();
+            let ws = tokio_tungstenite::accept_async(tcp).await.unwrap();
+            drop(ws);
+            // Listener is dropped here, so reconnects are refused
+        });
+
+        let client = SubscriptionClient::new(format!("ws://{}", addr)).with_reconnect_config(ReconnectConfig {
+            max_attempts: 2,
+            initial_delay: Duration::from_millis(5),
+            max_delay: Duration::from_millis(10),
+            backoff_multiplier: 2.0,
+        });
+
+        let stream = client
+            .subscribe_resilient(XrpcRequest::query("com.atproto.sync.subscribeRepos"))
+            .await
+            .unwrap();
+        server.await.unwrap();
+
+        let events: Vec<_> = stream.collect().await;
+        assert_eq!(events.len(), 3);
+        assert!(matches!(events[0], Ok(SubscriptionEvent::ReconnectFailed { attempt: 1, .. })));
+        assert!(matches!(events[1], Ok(SubscriptionEvent::ReconnectFailed { attempt: 2, .. })));
+        assert!(matches!(events[2], Err(XrpcError::Network(_))));
+    }
+
+    #[tokio::test]
+    async fn test_subscribe_resilient_ends_on_error_frame() {
+        use tokio::net::TcpListener;
+
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+
+        tokio::spawn(async move {
+            let (tcp, _) = listener.accept().await.unwrap();
+            let mut ws = tokio_tungstenite::accept_async(tcp).await.unwrap();
+            futures::SinkExt::send(&mut ws, Message::Binary(ERROR_FRAME.to_vec())).await.unwrap();
+        });
+
+        let client = SubscriptionClient::new(format!("ws://{}", addr));
+        let stream = client
+            .subscribe_resilient(XrpcRequest::query("com.atproto.sync.subscribeRepos"))
+            .await
+            .unwrap();
+
+        let events: Vec<_> = stream.collect().await;
+        assert_eq!(events.len(), 1);
+        assert!(matches!(events[0], Ok(SubscriptionEvent::Error { ref error, .. }) if error == "FutureCursor"));
+    }
+
+    #[tokio::test]
+    async fn test_subscribe_resilient_checkpoints_cursor() {
+        use crate::cursor_store::MemoryCursorStore;
+        use tokio::net::TcpListener;
+        use tokio_tungstenite::tungstenite::handshake::server::{Request, Response};
+
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+
+        let server = tokio::spawn(async move {
+            let (tcp, _) = listener.accept().await.unwrap();
+            let mut uri = String::new();
+            let mut ws = tokio_tungstenite::accept_hdr_async(tcp, |req: &Request, resp: Response| {
+                uri = req.uri().to_string();
+                Ok(resp)
+            })
+            .await
+            .unwrap();
+
+            for seq in [11u8, 12, 13] {
+                futures::SinkExt::send(&mut ws, Message::Binary(seq_frame(seq))).await.unwrap();
+            }
+            futures::SinkExt::send(&mut ws, Message::Binary(ERROR_FRAME.to_vec())).await.unwrap();
+
+            uri
+        });
+
+        let store = MemoryCursorStore::with_cursor(10);
+        let client = SubscriptionClient::new(format!("ws://{}", addr)).with_cursor_store(
+            Arc::new(store.clone()),
+            CheckpointConfig {
+                every_events: 2,
+                every_interval: Duration::from_secs(3600),
+            },
+        );
+
+        let mut stream = client
+            .subscribe_resilient(XrpcRequest::query("com.atproto.sync.subscribeRepos"))
+            .await
+            .unwrap();
+
+        // Resumed from the stored cursor
+        assert!(server.await.unwrap().contains("cursor=10"));
+
+        stream.next().await.unwrap().unwrap(); // seq 11
+        stream.next().await.unwrap().unwrap(); // seq 12
+        assert_eq!(store.cursor(), Some(10));
+
+        stream.next().await.unwrap().unwrap(); // seq 13; 11 and 12 now processed
+        assert_eq!(store.cursor(), Some(12));
+
+        // Error frame ends the stream; the last processed seq is flushed
+        assert!(matches!(stream.next().await, Some(Ok(SubscriptionEvent::Error { .. }))));
+        assert!(stream.next().await.is_none());
+        assert_eq!(store.cursor(), Some(13));
+    }
+
+    #[tokio::test]
+    async fn test_subscribe_resilient_reports_checkpoint_failures() {
+        use crate::cursor_store::{CursorStoreError, Result as CursorResult};
+        use tokio::net::TcpListener;
+
+        struct FailingStore;
+
+        #[async_trait::async_trait]
+        impl CursorStore for FailingStore {
+            async fn load_cursor(&self) -> CursorResult<Option<i64>> {
+                Ok(None)
+            }
+
+            async fn save_cursor(&self, _cursor: i64) -> CursorResult<()> {
+                Err(CursorStoreError::InvalidCursor("read-only".to_string()))
+            }
+        }
+
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+
+        tokio::spawn(async move {
+            let (tcp, _) = listener.accept().await.unwrap();
+            let mut ws = tokio_tungstenite::accept_async(tcp).await.unwrap();
+            futures::SinkExt::send(&mut ws, Message::Binary(seq_frame(7))).await.unwrap();
+            futures::SinkExt::send(&mut ws, Message::Binary(ERROR_FRAME.to_vec())).await.unwrap();
+        });
+
+        let client = SubscriptionClient::new(format!("ws://{}", addr)).with_cursor_store(
+            Arc::new(FailingStore),
+            CheckpointConfig {
+                every_events: 1,
+                every_interval: Duration::from_secs(3600),
+            },
+        );
+
+        let events: Vec<_> = client
+            .subscribe_resilient(XrpcRequest::query("com.atproto.sync.subscribeRepos"))
+            .await
+            .unwrap()
+            .collect()
+            .await;
+
+        assert_eq!(events.len(), 4);
+        assert!(matches!(events[0], Ok(SubscriptionEvent::Message { .. })));
+        assert!(matches!(
+            events[1],
+            Ok(SubscriptionEvent::CheckpointFailed { cursor: 7, ref error }) if error.contains("read-only")
+        ));
+        assert!(matches!(events[2], Ok(SubscriptionEvent::Error { .. })));
+
+        // The final flush is retried once before the stream ends
+        assert!(matches!(events[3], Ok(SubscriptionEvent::CheckpointFailed { cursor: 7, .. })));
+    }
+
+    #[tokio::test]
+    async fn test_explicit_cursor_overrides_store() {
+        use crate::cursor_store::MemoryCursorStore;
+        use tokio::net::TcpListener;
+        use tokio_tungstenite::tungstenite::handshake::server::{Request, Response};
+
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+
+        let server = tokio::spawn(async move {
+            let (tcp, _) = listener.accept().await.unwrap();
+            let mut uri = String::new();
+            let _ws = tokio_tungstenite::accept_hdr_async(tcp, |req: &Request, resp: Response| {
+                uri = req.uri().to_string();
+                Ok(resp)
+            })
+            .await
+            .unwrap();
+            uri
+        });
+
+        let client = SubscriptionClient::new(format!("ws://{}", addr))
+            .with_cursor_store(Arc::new(MemoryCursorStore::with_cursor(10)), CheckpointConfig::default());
+
+        let request = XrpcRequest::query("com.atproto.sync.subscribeRepos").param("cursor", "500");
+        let _stream = client.subscribe_resilient(request).await.unwrap();
+
+        let uri = server.await.unwrap();
+        assert!(uri.contains("cursor=500"));
+        assert!(!uri.contains("cursor=10"));
+    }
 }
@@ -54,12 +54,10 @@ async fn test_basic_authentication_with_real_account() {
                     println!("      Handle: {}", response.data.handle);
                     println!("      DID: {}", response.data.did);
 
-                    // collections is a serde_json::Value that should be an array
-                    if let Some(collections) = response.data.collections.as_array() {
-                        println!("      Collections: {}", collections.len());
-                        for (i, coll) in collections.iter().take(3).enumerate() {
-                            println!("         {}. {}", i + 1, coll);
-                        }
+                    let collections = &response.data.collections;
+                    println!("      Collections: {}", collections.len());
+                    for (i, coll) in collections.iter().take(3).enumerate() {
+                        println!("         {}. {}", i + 1, coll);
                     }
                 }
                 Err(e) => {